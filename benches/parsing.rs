@@ -0,0 +1,121 @@
+//! Parsing and deserialization benchmarks over the bundled test fixtures.
+//!
+//! Run with `cargo bench --bench parsing`; see `benches/README.md` for baseline numbers.
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use edgarkit::parsing::index::{IndexConfig, IndexParser};
+use edgarkit::parsing::intern::StringPool;
+use edgarkit::{CompanyFacts, DetailedFiling, PeriodKind, SearchResponse, Submission};
+use std::hint::black_box;
+use std::path::PathBuf;
+
+fn fixture(relative: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(relative);
+    std::fs::read_to_string(path).expect("fixture file should be readable")
+}
+
+fn index_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("index");
+    for name in ["company.idx", "xbrl.idx"] {
+        let content = fixture(&format!("indexes/{}", name));
+        let parser = IndexParser::new(IndexConfig::default());
+        group.throughput(Throughput::Bytes(content.len() as u64));
+
+        group.bench_function(format!("parse/{}", name), |b| {
+            b.iter(|| parser.parse(black_box(content.as_bytes())).unwrap())
+        });
+        group.bench_function(format!("parse_iter/{}", name), |b| {
+            b.iter(|| {
+                parser
+                    .parse_iter(black_box(content.as_bytes()))
+                    .unwrap()
+                    .count()
+            })
+        });
+        group.bench_function(format!("parse_interned/{}", name), |b| {
+            b.iter(|| {
+                let mut pool = StringPool::new();
+                parser
+                    .parse_interned(black_box(content.as_bytes()), &mut pool)
+                    .unwrap()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn submissions_conversion(c: &mut Criterion) {
+    let content = fixture("submissions/submission.json");
+    let submission: Submission = serde_json::from_str(&content).unwrap();
+    let recent = &submission.filings.recent;
+
+    let mut group = c.benchmark_group("submissions");
+    group.throughput(Throughput::Bytes(content.len() as u64));
+    group.bench_function("deserialize", |b| {
+        b.iter(|| serde_json::from_str::<Submission>(black_box(&content)).unwrap())
+    });
+    group.throughput(Throughput::Elements(recent.accession_number.len() as u64));
+    group.bench_function("detailed_filings", |b| {
+        b.iter(|| {
+            (0..recent.accession_number.len())
+                .map(|idx| DetailedFiling::try_from((black_box(recent), idx)).unwrap())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+}
+
+fn facts_deserialization(c: &mut Criterion) {
+    let content = fixture("tickers/companyfacts.json");
+    let facts: CompanyFacts = serde_json::from_str(&content).unwrap();
+
+    let mut group = c.benchmark_group("company_facts");
+    group.sample_size(20);
+    group.throughput(Throughput::Bytes(content.len() as u64));
+    group.bench_function("deserialize", |b| {
+        b.iter(|| serde_json::from_str::<CompanyFacts>(black_box(&content)).unwrap())
+    });
+    group.finish();
+
+    c.bench_function("financials/annual", |b| {
+        b.iter(|| black_box(&facts).financials(PeriodKind::Annual))
+    });
+}
+
+/// Per-page work of `search_all`/`search_stream`: decode one EFTS response and pick out the
+/// identifiers needed to fetch each matched document.
+fn search_pagination(c: &mut Criterion) {
+    let pages = [
+        fixture("search/search-index.json"),
+        fixture("search/search-s-1.json"),
+    ];
+
+    let mut group = c.benchmark_group("search");
+    group.throughput(Throughput::Elements(pages.len() as u64));
+    group.bench_function("pages", |b| {
+        b.iter(|| {
+            pages
+                .iter()
+                .flat_map(|page| {
+                    serde_json::from_str::<SearchResponse>(black_box(page))
+                        .unwrap()
+                        .hits
+                        .hits
+                })
+                .filter_map(|hit| Some((hit.primary_cik()?, hit.accession().to_string())))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    index_parsing,
+    submissions_conversion,
+    facts_deserialization,
+    search_pagination
+);
+criterion_main!(benches);
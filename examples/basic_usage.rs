@@ -32,8 +32,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Display some available taxonomies
     println!("\nAvailable data taxonomies:");
-    println!("- US-GAAP facts: {} items", facts.taxonomies.us_gaap.len());
-    println!("- DEI facts: {} items", facts.taxonomies.dei.len());
+    println!(
+        "- US-GAAP facts: {} items",
+        facts.taxonomies.us_gaap().map_or(0, |f| f.len())
+    );
+    println!(
+        "- DEI facts: {} items",
+        facts.taxonomies.dei().map_or(0, |f| f.len())
+    );
 
     println!("\n✓ Basic usage example completed successfully!");
 
@@ -19,7 +19,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("=== EdgarKit Filing Download Example ===\n");
 
     // Apple's CIK
-    let cik = "320193";
+    let cik = 320193;
 
     // Example 1: Get recent 10-K filings
     println!("1. Fetching recent 10-K filings for CIK {}...", cik);
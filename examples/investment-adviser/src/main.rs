@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
 use clap::{ArgGroup, Parser};
 use console::style;
-use edgarkit::{CompanyOperations, Edgar, FilingOperations};
+use edgarkit::parsing::html::to_markdown;
+use edgarkit::{Edgar, EntityId, FilingOperations};
 use indicatif::{ProgressBar, ProgressStyle};
 use rig::client::CompletionClient;
 use rig::completion::Prompt;
@@ -43,11 +44,18 @@ async fn main() -> Result<()> {
 
     let edgar = Edgar::new(&args.user_agent).context("failed to create Edgar client")?;
 
-    let cik = resolve_cik(&edgar, args.ticker.as_deref(), args.cik.as_deref()).await?;
+    let entity = match (args.cik, args.ticker) {
+        (Some(cik), _) => EntityId::Cik(cik.parse().context("invalid --cik (expected digits)")?),
+        (None, ticker) => EntityId::ticker(ticker.context("missing --ticker")?),
+    };
+    let cik = edgar
+        .resolve_entity(entity)
+        .await
+        .context("failed to resolve company to CIK")?;
     let cik_str = cik.to_string();
 
     let submission = edgar
-        .submissions(&cik_str)
+        .submissions(cik)
         .await
         .context("failed to fetch company submissions")?;
 
@@ -76,12 +84,11 @@ async fn main() -> Result<()> {
     );
 
     let html = edgar
-        .get_latest_filing_content(&cik_str, &["10-Q", "10-K"])
+        .get_latest_filing_content(cik, &["10-Q", "10-K"])
         .await
         .context("failed to fetch latest 10-Q/10-K content")?;
 
-    let markdown = html_to_markdown_rs::convert(&html, None);
-    let content = markdown.unwrap_or(html);
+    let content = to_markdown(&html);
 
     println!("{} {}", style("Analyzing with").bold(), style(&args.model).magenta());
 
@@ -152,22 +159,6 @@ fn print_banner() {
     println!("{}", style(banner).cyan());
 }
 
-async fn resolve_cik(edgar: &Edgar, ticker: Option<&str>, cik: Option<&str>) -> Result<u64> {
-    if let Some(cik) = cik {
-        let cik = cik.trim_start_matches('0');
-        return cik
-            .parse::<u64>()
-            .context("invalid --cik (expected digits)");
-    }
-
-    let ticker = ticker.context("missing --ticker")?;
-    let cik = edgar
-        .company_cik(ticker)
-        .await
-        .with_context(|| format!("failed to resolve ticker '{ticker}' to CIK"))?;
-    Ok(cik)
-}
-
 fn build_prompt(company_name: &str, cik: &str, sic: &str, sic_description: &str) -> String {
     format!(
         r#"You are a senior, conservative investment adviser.
@@ -7,8 +7,8 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use edgarkit::{
-    DetailedFiling, Edgar, EdgarDay, EdgarError, FilingOperations, FilingOptions, IndexOperations,
-    Submission,
+    DetailedFiling, Edgar, EdgarDay, FilingOperations, FilingOptions, IndexOperations,
+    Submission, format_human,
 };
 use ratatui::{
     Terminal,
@@ -110,45 +110,32 @@ async fn fetch_rows(edgar: &Edgar, start: NaiveDate, end: NaiveDate) -> Result<V
         .with_form_type("S-1")
         .with_include_amendments(false);
 
-    let mut day = start;
-    while day <= end {
-        let edgar_day = EdgarDay::new(day.year(), day.month(), day.day())?;
-        match edgar.get_daily_filings(edgar_day, Some(opts.clone())).await {
-            Ok(entries) => {
-                for entry in entries {
-                    // Defensive: keep only exact S-1.
-                    if entry.form_type.trim() != "S-1" {
-                        continue;
-                    }
-
-                    // SEC “browse company” page expects a zero-padded 10-digit CIK.
-                    let index_html = format!("https://www.sec.gov/edgar/browse/?CIK={:010}", entry.cik);
+    let start = EdgarDay::new(start.year(), start.month(), start.day())?;
+    let end = EdgarDay::new(end.year(), end.month(), end.day())?;
+    for entry in edgar.get_filings_between(start, end, Some(opts)).await? {
+        // Defensive: keep only exact S-1.
+        if entry.form_type.trim() != "S-1" {
+            continue;
+        }
 
-                    if !seen.insert(index_html.clone()) {
-                        continue;
-                    }
+        // SEC “browse company” page expects a zero-padded 10-digit CIK.
+        let index_html = format!("https://www.sec.gov/edgar/browse/?CIK={:010}", entry.cik);
 
-                    let date_filed = NaiveDate::parse_from_str(&entry.date_filed, "%Y%m%d")
-                        .with_context(|| format!("invalid date_filed: {}", entry.date_filed))?;
+        if !seen.insert(index_html.clone()) {
+            continue;
+        }
 
-                    let when = human_age(Utc::now().date_naive(), date_filed);
+        let date_filed = entry.date_filed;
 
-                    rows.push(IpoRow {
-                        company: normalize_company_name(&entry.company_name),
-                        cik: entry.cik,
-                        date_filed,
-                        when,
-                        index_html,
-                    });
-                }
-            }
-            Err(EdgarError::NotFound) => {
-                // Weekends/holidays can be missing.
-            }
-            Err(e) => return Err(e.into()),
-        }
+        let when = human_age(Utc::now().date_naive(), date_filed);
 
-        day += Duration::days(1);
+        rows.push(IpoRow {
+            company: normalize_company_name(&entry.company_name),
+            cik: entry.cik,
+            date_filed,
+            when,
+            index_html,
+        });
     }
 
     rows.sort_by(|a, b| b.date_filed.cmp(&a.date_filed));
@@ -312,8 +299,8 @@ async fn run_tui(edgar: &Edgar, rows: Vec<IpoRow>) -> Result<()> {
                             .to_string();
                         Row::new([
                             Cell::from(f.form.clone()),
-                            Cell::from(f.filing_date.clone()),
-                            Cell::from(format_bytes(f.size as i64)),
+                            Cell::from(f.filing_date.to_string()),
+                            Cell::from(format_human(f.size)),
                             Cell::from(desc),
                         ])
                     });
@@ -473,7 +460,7 @@ fn table_up(state: &mut TableState, row_count: usize) {
 }
 
 async fn fetch_company_filings(edgar: &Edgar, cik: u64) -> Result<Vec<DetailedFiling>> {
-    let submission: Submission = edgar.submissions(&cik.to_string()).await?;
+    let submission: Submission = edgar.submissions(cik).await?;
     let recent = &submission.filings.recent;
 
     let mut out = Vec::new();
@@ -486,23 +473,3 @@ async fn fetch_company_filings(edgar: &Edgar, cik: u64) -> Result<Vec<DetailedFi
     // SEC usually returns newest-first already.
     Ok(out)
 }
-
-fn format_bytes(bytes: i64) -> String {
-    if bytes < 0 {
-        return "".to_string();
-    }
-    let b = bytes as f64;
-    if b < 1024.0 {
-        return format!("{} B", bytes);
-    }
-    let kb = b / 1024.0;
-    if kb < 1024.0 {
-        return format!("{:.1} KB", kb);
-    }
-    let mb = kb / 1024.0;
-    if mb < 1024.0 {
-        return format!("{:.1} MB", mb);
-    }
-    let gb = mb / 1024.0;
-    format!("{:.1} GB", gb)
-}
@@ -32,10 +32,10 @@ async fn main() -> Result<(), Box<dyn Error>> {
     // Example 2: Get company-specific feed with options
     println!("\n2. Fetching company-specific feed for Apple (CIK: 320193)...");
     let feed_options = FeedOptions::new(None)
-        .with_param("count", "10")
-        .with_param("type", "10-K");
+        .with_count(10)?
+        .with_form_type("10-K");
 
-    let company_feed = edgar.company_feed("320193", Some(feed_options)).await?;
+    let company_feed = edgar.company_feed(320193, Some(feed_options)).await?;
     println!("✓ Company: {}", company_feed.title);
 
     if let Some(company_info) = &company_feed.company_info {
@@ -0,0 +1,125 @@
+//! Accession numbers.
+//!
+//! Every EDGAR submission gets an accession number `FFFFFFFFFF-YY-NNNNNN`: the CIK of the
+//! entity that submitted it (often a filing agent rather than the company), the two-digit
+//! year, and a sequence number within that year. Archive folders use the same digits
+//! without dashes.
+//!
+//! ```rust
+//! use edgarkit::{AccessionNumber, Cik};
+//!
+//! let accession: AccessionNumber = "0001104659-21-077166".parse().unwrap();
+//! assert_eq!(accession, "000110465921077166".parse().unwrap());
+//! assert_eq!(accession.filer_id(), Cik::new(1104659));
+//! assert_eq!(accession.year(), 2021);
+//! assert_eq!(accession.folder(), "000110465921077166");
+//! assert_eq!(accession.to_string(), "0001104659-21-077166");
+//! ```
+
+use super::cik::Cik;
+use super::error::{EdgarError, Result};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+/// An EDGAR accession number.
+///
+/// Displays and serializes in the dashed form; parses either form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AccessionNumber {
+    filer: u64,
+    year: u8,
+    sequence: u32,
+}
+
+impl AccessionNumber {
+    /// CIK of the entity that made the submission.
+    pub fn filer_id(self) -> Cik {
+        Cik::new(self.filer)
+    }
+
+    /// Year the submission was made. EDGAR numbers start in 1993, so two-digit years from
+    /// 93 on are 19xx.
+    pub fn year(self) -> u16 {
+        let year = u16::from(self.year);
+        if year >= 93 { 1900 + year } else { 2000 + year }
+    }
+
+    /// Sequence number within the filer's year.
+    pub fn sequence(self) -> u32 {
+        self.sequence
+    }
+
+    /// The 18 digits without dashes, as used for archive folder names.
+    pub fn folder(self) -> String {
+        format!("{:010}{:02}{:06}", self.filer, self.year, self.sequence)
+    }
+}
+
+/// Parses `0001104659-21-077166` or `000110465921077166`, ignoring surrounding whitespace.
+impl FromStr for AccessionNumber {
+    type Err = EdgarError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let digits: String = match trimmed.split('-').collect::<Vec<_>>().as_slice() {
+            [filer, year, sequence]
+                if filer.len() == 10 && year.len() == 2 && sequence.len() == 6 =>
+            {
+                [*filer, *year, *sequence].concat()
+            }
+            [digits] => digits.to_string(),
+            _ => String::new(),
+        };
+        if digits.len() != 18 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EdgarError::InvalidFormat(format!(
+                "{:?} is not an accession number",
+                s
+            )));
+        }
+        Ok(Self {
+            filer: digits[..10].parse().expect("ten ASCII digits"),
+            year: digits[10..12].parse().expect("two ASCII digits"),
+            sequence: digits[12..].parse().expect("six ASCII digits"),
+        })
+    }
+}
+
+impl fmt::Display for AccessionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:010}-{:02}-{:06}",
+            self.filer, self.year, self.sequence
+        )
+    }
+}
+
+impl Serialize for AccessionNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccessionNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let accession = String::deserialize(deserializer)?;
+        accession.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_accession() {
+        let accession: AccessionNumber = " 0000950170-98-000123 ".parse().unwrap();
+        assert_eq!(accession.year(), 1998);
+        assert_eq!(accession.sequence(), 123);
+        assert!("0000950170-98-00012".parse::<AccessionNumber>().is_err());
+        assert!("000095017098000123X".parse::<AccessionNumber>().is_err());
+        assert!("00009501709-8-000123".parse::<AccessionNumber>().is_err());
+        assert!("".parse::<AccessionNumber>().is_err());
+    }
+}
@@ -0,0 +1,582 @@
+//! Synchronous facade over the async client.
+//!
+//! [`Edgar`] owns a single-threaded tokio runtime and drives the async client on it, so
+//! scripts and notebooks can call EDGAR without setting up a runtime of their own. It offers
+//! the methods of [`FilingOperations`](crate::FilingOperations),
+//! [`CompanyOperations`](crate::CompanyOperations),
+//! [`FundOperations`](crate::FundOperations),
+//! [`IndexOperations`](crate::IndexOperations), and
+//! [`SearchOperations`](crate::SearchOperations) as plain methods with the same names and
+//! arguments; streams become iterators that fetch as they are advanced.
+//!
+//! Rate limits, retries, and the ticker cache behave exactly as in the async client, and
+//! clones share them along with the runtime.
+//!
+//! The blocking client must not be used from inside an async runtime: blocking on a future
+//! there panics. Use the async client in async code instead.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::FilingOptions;
+//! use edgarkit::blocking::Edgar;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let edgar = Edgar::new("MyApp contact@example.com")?;
+//!     let options = FilingOptions::new().with_form_type("10-K").with_limit(5);
+//!     for filing in edgar.filings(320193, Some(options))? {
+//!         println!("{} filed {}", filing.form, filing.filing_date);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::config::{EdgarConfig, RequestOptions};
+use super::error::{EdgarError, Result};
+#[cfg(all(feature = "company", feature = "search"))]
+use crate::CompanyMetadata;
+#[cfg(any(feature = "filings", feature = "index"))]
+use crate::FilingOptions;
+#[cfg(feature = "filings")]
+use crate::parsing::filing_summary::FinancialReport;
+#[cfg(feature = "filings")]
+use crate::parsing::form_d::FormD;
+#[cfg(feature = "index")]
+use crate::parsing::index::IndexEntry;
+#[cfg(any(feature = "company", feature = "filings"))]
+use crate::parsing::json::ParseReport;
+#[cfg(feature = "funds")]
+use crate::parsing::nport::NportReport;
+#[cfg(feature = "filings")]
+use crate::parsing::ownership::BeneficialOwnership;
+#[cfg(feature = "filings")]
+use crate::parsing::sections::{Item, Section};
+#[cfg(feature = "filings")]
+use crate::parsing::submission::SubmissionDocument;
+#[cfg(feature = "filings")]
+use crate::{
+    AmendmentChain, DetailedFiling, DirectoryResponse, FilingManifest, FilingOperations,
+    ProxyStatement, RecentFilings, Submission,
+};
+use crate::{Cik, EntityId};
+#[cfg(feature = "company")]
+use crate::{
+    CikCandidate, CompanyConcept, CompanyFacts, CompanyOperations, CompanyTicker,
+    CompanyTickerExchange, Frame, FramePanel, FramePeriod, MutualFundTicker,
+};
+#[cfg(feature = "index")]
+use crate::{
+    EdgarDay, EdgarPeriod, IndexCoverage, IndexDay, IndexOperations, IndexResponse, IndexScope,
+};
+#[cfg(feature = "search")]
+use crate::{EntityMatch, Hit, SearchOperations, SearchOptions, SearchResponse};
+#[cfg(feature = "funds")]
+use crate::{FundClass, FundOperations, FundSeries};
+#[cfg(any(feature = "index", feature = "search"))]
+use futures_util::StreamExt;
+#[cfg(feature = "filings")]
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+/// Blocking EDGAR client.
+#[derive(Debug, Clone)]
+pub struct Edgar {
+    inner: crate::Edgar,
+    runtime: Arc<Runtime>,
+}
+
+impl Edgar {
+    /// Creates a blocking client with the defaults of [`crate::Edgar::new`].
+    pub fn new(user_agent: &str) -> Result<Self> {
+        Self::from_async(crate::Edgar::new(user_agent)?)
+    }
+
+    /// Creates a blocking client from a custom configuration.
+    pub fn with_config(config: EdgarConfig) -> Result<Self> {
+        Self::from_async(crate::Edgar::with_config(config)?)
+    }
+
+    /// Wraps an existing async client, sharing its rate limiters and caches.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::ConfigError` if the runtime cannot be started.
+    pub fn from_async(inner: crate::Edgar) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| EdgarError::ConfigError(format!("Failed to start runtime: {}", e)))?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this facade drives, for APIs without a blocking counterpart.
+    pub fn as_async(&self) -> &crate::Edgar {
+        &self.inner
+    }
+
+    /// Runs `future` to completion on the facade's runtime.
+    ///
+    /// Useful for async-only APIs reached through [`as_async`](Self::as_async).
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Fetches text content from a URL; see [`crate::Edgar::get`].
+    pub fn get(&self, url: &str) -> Result<String> {
+        self.block_on(self.inner.get(url))
+    }
+
+    /// Fetches binary content from a URL; see [`crate::Edgar::get_bytes`].
+    pub fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        self.block_on(self.inner.get_bytes(url))
+    }
+
+    /// Fetches several URLs with bounded concurrency; see [`crate::Edgar::get_many`].
+    pub fn get_many<I>(&self, urls: I, max_concurrency: usize) -> Vec<Result<String>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.block_on(self.inner.get_many(urls, max_concurrency))
+    }
+
+    /// Returns a client that applies `options` to every request; see
+    /// [`crate::Edgar::with_request_options`].
+    pub fn with_request_options(&self, options: RequestOptions) -> Self {
+        Self {
+            inner: self.inner.with_request_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Streams the body of `url` to `path`; see [`crate::Edgar::download_to_file`].
+    pub fn download_to_file(&self, url: &str, path: impl AsRef<Path>) -> Result<u64> {
+        self.block_on(self.inner.download_to_file(url, path))
+    }
+
+    /// Resolves a CIK, ticker, or company name; see [`crate::Edgar::resolve_entity`].
+    pub fn resolve_entity(&self, id: impl Into<EntityId>) -> Result<Cik> {
+        self.block_on(self.inner.resolve_entity(id))
+    }
+}
+
+/// Blocking counterparts of [`FilingOperations`].
+#[cfg(feature = "filings")]
+impl Edgar {
+    pub fn submissions(&self, cik: impl Into<EntityId> + Send) -> Result<Submission> {
+        self.block_on(self.inner.submissions(cik))
+    }
+
+    pub fn submissions_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(Submission, ParseReport)> {
+        self.block_on(self.inner.submissions_with_report(cik))
+    }
+
+    pub fn get_submission_file(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        name: &str,
+    ) -> Result<RecentFilings> {
+        self.block_on(self.inner.get_submission_file(cik, name))
+    }
+
+    pub fn get_recent_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>> {
+        self.block_on(self.inner.get_recent_filings(cik))
+    }
+
+    pub fn all_filings(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<DetailedFiling>> {
+        self.block_on(self.inner.all_filings(cik))
+    }
+
+    pub fn first_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>> {
+        self.block_on(self.inner.first_filing(cik, form))
+    }
+
+    pub fn latest_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>> {
+        self.block_on(self.inner.latest_filing(cik, form))
+    }
+
+    pub fn filing_count_by_form(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<BTreeMap<String, usize>> {
+        self.block_on(self.inner.filing_count_by_form(cik))
+    }
+
+    pub fn filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<DetailedFiling>> {
+        self.block_on(self.inner.filings(cik, opts))
+    }
+
+    pub fn latest_across<I>(
+        &self,
+        ciks: I,
+        form_types: &[&str],
+        limit: usize,
+    ) -> Result<Vec<(Cik, DetailedFiling)>>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        self.block_on(self.inner.latest_across(ciks, form_types, limit))
+    }
+
+    pub fn filings_for_ciks<I>(
+        &self,
+        ciks: I,
+        opts: Option<FilingOptions>,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<Vec<DetailedFiling>>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        self.block_on(self.inner.filings_for_ciks(ciks, opts, max_concurrency))
+    }
+
+    pub fn filing_directory(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<DirectoryResponse> {
+        self.block_on(self.inner.filing_directory(cik, accession_number))
+    }
+
+    pub fn download_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        dest_dir: impl AsRef<Path> + Send,
+        extensions: &[&str],
+    ) -> Result<FilingManifest> {
+        self.block_on(
+            self.inner
+                .download_filing(cik, accession_number, dest_dir, extensions),
+        )
+    }
+
+    pub fn entity_directory(&self, cik: impl Into<EntityId> + Send) -> Result<DirectoryResponse> {
+        self.block_on(self.inner.entity_directory(cik))
+    }
+
+    pub fn get_filing_url_from_id(
+        &self,
+        cik: impl Into<EntityId>,
+        filing_id: &str,
+    ) -> Result<String> {
+        self.inner.get_filing_url_from_id(cik, filing_id)
+    }
+
+    pub fn get_filing_content_by_id(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        filing_id: &str,
+    ) -> Result<String> {
+        self.block_on(self.inner.get_filing_content_by_id(cik, filing_id))
+    }
+
+    pub fn get_latest_filing_content(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form_types: &[&str],
+    ) -> Result<String> {
+        self.block_on(self.inner.get_latest_filing_content(cik, form_types))
+    }
+
+    pub fn get_text_filing_links(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<(DetailedFiling, String, String)>> {
+        self.block_on(self.inner.get_text_filing_links(cik, opts))
+    }
+
+    pub fn get_sgml_header_links(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<(DetailedFiling, String, String)>> {
+        self.block_on(self.inner.get_sgml_header_links(cik, opts))
+    }
+
+    pub fn get_submission_documents(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<SubmissionDocument>> {
+        self.block_on(self.inner.get_submission_documents(cik, accession_number))
+    }
+
+    pub fn get_filing_section(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        item: Item,
+    ) -> Result<Section> {
+        self.block_on(self.inner.get_filing_section(cik, accession_number, item))
+    }
+
+    pub fn get_latest_proxy(&self, cik: impl Into<EntityId> + Send) -> Result<ProxyStatement> {
+        self.block_on(self.inner.get_latest_proxy(cik))
+    }
+
+    pub fn get_beneficial_ownership_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>> {
+        self.block_on(self.inner.get_beneficial_ownership_filings(cik))
+    }
+
+    pub fn get_beneficial_ownership(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<BeneficialOwnership> {
+        self.block_on(self.inner.get_beneficial_ownership(cik, accession_number))
+    }
+
+    pub fn get_form_d(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<FormD> {
+        self.block_on(self.inner.get_form_d(cik, accession_number))
+    }
+
+    pub fn amendment_chain(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<AmendmentChain> {
+        self.block_on(self.inner.amendment_chain(cik, accession_number))
+    }
+
+    pub fn get_financial_reports(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<FinancialReport>> {
+        self.block_on(self.inner.get_financial_reports(cik, accession_number))
+    }
+
+    pub fn get_financial_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        name: &str,
+    ) -> Result<String> {
+        self.block_on(self.inner.get_financial_report(cik, accession_number, name))
+    }
+}
+
+/// Blocking counterparts of [`FundOperations`].
+#[cfg(feature = "funds")]
+impl Edgar {
+    pub fn get_nport_holdings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<NportReport> {
+        self.block_on(self.inner.get_nport_holdings(cik, accession_number))
+    }
+
+    pub fn fund_series(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<FundSeries>> {
+        self.block_on(self.inner.fund_series(cik))
+    }
+
+    pub fn fund_class(&self, class_id: &str) -> Result<FundClass> {
+        self.block_on(self.inner.fund_class(class_id))
+    }
+}
+
+/// Blocking counterparts of [`CompanyOperations`].
+#[cfg(feature = "company")]
+impl Edgar {
+    pub fn company_tickers(&self) -> Result<Vec<CompanyTicker>> {
+        self.block_on(self.inner.company_tickers())
+    }
+
+    pub fn company_cik(&self, ticker: &str) -> Result<u64> {
+        self.block_on(self.inner.company_cik(ticker))
+    }
+
+    pub fn company_cik_by_name(&self, name: &str) -> Result<Vec<CikCandidate>> {
+        self.block_on(self.inner.company_cik_by_name(name))
+    }
+
+    pub fn refresh_tickers(&self) -> Result<usize> {
+        self.block_on(self.inner.refresh_tickers())
+    }
+
+    pub fn mutual_fund_cik(&self, ticker: &str) -> Result<u64> {
+        self.block_on(self.inner.mutual_fund_cik(ticker))
+    }
+
+    pub fn company_tickers_with_exchange(&self) -> Result<Vec<CompanyTickerExchange>> {
+        self.block_on(self.inner.company_tickers_with_exchange())
+    }
+
+    pub fn mutual_fund_tickers(&self) -> Result<Vec<MutualFundTicker>> {
+        self.block_on(self.inner.mutual_fund_tickers())
+    }
+
+    pub fn company_facts(&self, cik: impl Into<EntityId> + Send) -> Result<CompanyFacts> {
+        self.block_on(self.inner.company_facts(cik))
+    }
+
+    pub fn company_facts_for_ciks<I>(
+        &self,
+        ciks: I,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<CompanyFacts>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        self.block_on(self.inner.company_facts_for_ciks(ciks, max_concurrency))
+    }
+
+    pub fn company_facts_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(CompanyFacts, ParseReport)> {
+        self.block_on(self.inner.company_facts_with_report(cik))
+    }
+
+    pub fn company_concept(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        taxonomy: &str,
+        tag: &str,
+    ) -> Result<CompanyConcept> {
+        self.block_on(self.inner.company_concept(cik, taxonomy, tag))
+    }
+
+    pub fn frames(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        period: FramePeriod,
+    ) -> Result<Frame> {
+        self.block_on(self.inner.frames(taxonomy, tag, unit, period))
+    }
+
+    pub fn frames_range(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        from_period: FramePeriod,
+        to_period: FramePeriod,
+    ) -> Result<FramePanel> {
+        self.block_on(
+            self.inner
+                .frames_range(taxonomy, tag, unit, from_period, to_period),
+        )
+    }
+
+    #[cfg(feature = "search")]
+    pub fn peers_by_sic(&self, sic_code: &str) -> Result<Vec<CompanyMetadata>> {
+        self.block_on(self.inner.peers_by_sic(sic_code))
+    }
+}
+
+/// Blocking counterparts of [`IndexOperations`].
+#[cfg(feature = "index")]
+impl Edgar {
+    pub fn full_index(&self, period: Option<EdgarPeriod>) -> Result<IndexResponse> {
+        self.block_on(self.inner.full_index(period))
+    }
+
+    pub fn daily_index(&self, period: Option<EdgarPeriod>) -> Result<IndexResponse> {
+        self.block_on(self.inner.daily_index(period))
+    }
+
+    pub fn get_daily_filings(
+        &self,
+        day: EdgarDay,
+        options: Option<FilingOptions>,
+    ) -> Result<Vec<IndexEntry>> {
+        self.block_on(self.inner.get_daily_filings(day, options))
+    }
+
+    pub fn get_period_filings(
+        &self,
+        period: EdgarPeriod,
+        options: Option<FilingOptions>,
+    ) -> Result<Vec<IndexEntry>> {
+        self.block_on(self.inner.get_period_filings(period, options))
+    }
+
+    /// Iterates the quarterly index for a period, parsing and filtering entries lazily.
+    pub fn stream_period_filings(
+        &self,
+        period: EdgarPeriod,
+        options: Option<FilingOptions>,
+    ) -> Result<impl Iterator<Item = Result<IndexEntry>> + '_> {
+        let mut stream = self.block_on(self.inner.stream_period_filings(period, options))?;
+        Ok(std::iter::from_fn(move || self.block_on(stream.next())))
+    }
+
+    pub fn index_coverage(&self, start: EdgarDay, end: EdgarDay) -> Result<IndexCoverage> {
+        self.block_on(self.inner.index_coverage(start, end))
+    }
+
+    pub fn available_days(&self, scope: impl Into<IndexScope> + Send) -> Result<Vec<IndexDay>> {
+        self.block_on(self.inner.available_days(scope))
+    }
+
+    pub fn get_filings_between(
+        &self,
+        start: EdgarDay,
+        end: EdgarDay,
+        options: Option<FilingOptions>,
+    ) -> Result<Vec<IndexEntry>> {
+        self.block_on(self.inner.get_filings_between(start, end, options))
+    }
+}
+
+/// Blocking counterparts of [`SearchOperations`].
+#[cfg(feature = "search")]
+impl Edgar {
+    pub fn search(&self, options: SearchOptions) -> Result<SearchResponse> {
+        self.block_on(self.inner.search(options))
+    }
+
+    pub fn search_all(&self, options: SearchOptions) -> Result<Vec<Hit>> {
+        self.block_on(self.inner.search_all(options))
+    }
+
+    /// Iterates every matching hit, fetching the next page when the current one runs out.
+    pub fn search_stream(&self, options: SearchOptions) -> impl Iterator<Item = Result<Hit>> + '_ {
+        let mut stream = self.inner.search_stream(options);
+        std::iter::from_fn(move || self.block_on(stream.next()))
+    }
+
+    pub fn entity_search(&self, name: &str) -> Result<Vec<EntityMatch>> {
+        self.block_on(self.inner.entity_search(name))
+    }
+}
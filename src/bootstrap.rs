@@ -0,0 +1,207 @@
+//! Warm-start bundle of the global ticker datasets.
+//!
+//! Resolving a ticker, listing exchanges, or mapping a fund symbol each needs one of the
+//! small SEC-wide mapping files. Fetched lazily, the first of those calls in an interactive
+//! app pays for a download while the user waits. [`Edgar::bootstrap`] downloads all of them
+//! concurrently up front and returns a [`Bootstrap`] that answers lookups from memory.
+//!
+//! The company ticker map also lands in the client's ticker cache, so `company_cik`,
+//! `resolve_cik`, and `Edgar::company` on the same client (or its clones) stop making
+//! requests until the cache expires.
+//!
+//! ```ignore
+//! use edgarkit::Edgar;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let boot = edgar.bootstrap().await?;
+//! let cik = boot.resolve("AAPL")?;
+//! println!("{} trades on {:?}", cik, boot.exchange("AAPL"));
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Edgar;
+use super::cik::Cik;
+use super::company::{CachedTickers, CompanyTicker, CompanyTickerExchange, MutualFundTicker};
+use super::error::{EdgarError, Result};
+use super::symbols::SymbolConventions;
+use super::traits::CompanyOperations;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// In-memory copy of the ticker, exchange, and mutual fund mappings.
+///
+/// Created by [`Edgar::bootstrap`]. Ticker lookups are case-insensitive and go through the
+/// client's [`SymbolConventions`], so `BRK.B` finds `BRK-B`.
+#[derive(Debug)]
+pub struct Bootstrap {
+    tickers: Arc<CachedTickers>,
+    exchanges: Vec<CompanyTickerExchange>,
+    mutual_funds: Vec<MutualFundTicker>,
+    exchange_by_ticker: HashMap<String, usize>,
+    fund_by_symbol: HashMap<String, usize>,
+    symbols: Arc<SymbolConventions>,
+}
+
+impl Bootstrap {
+    fn new(
+        tickers: Arc<CachedTickers>,
+        exchanges: Vec<CompanyTickerExchange>,
+        mutual_funds: Vec<MutualFundTicker>,
+        symbols: Arc<SymbolConventions>,
+    ) -> Self {
+        let exchange_by_ticker = exchanges
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (t.ticker.to_uppercase(), i))
+            .collect();
+        let fund_by_symbol = mutual_funds
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (f.symbol.to_uppercase(), i))
+            .collect();
+        Self {
+            tickers,
+            exchanges,
+            mutual_funds,
+            exchange_by_ticker,
+            fund_by_symbol,
+            symbols,
+        }
+    }
+
+    /// Operating company tickers (`company_tickers.json`).
+    pub fn tickers(&self) -> &[CompanyTicker] {
+        &self.tickers.tickers
+    }
+
+    /// Tickers with their listing exchange (`company_tickers_exchange.json`).
+    pub fn tickers_with_exchange(&self) -> &[CompanyTickerExchange] {
+        &self.exchanges
+    }
+
+    /// Mutual fund share classes (`company_tickers_mf.json`).
+    pub fn mutual_fund_tickers(&self) -> &[MutualFundTicker] {
+        &self.mutual_funds
+    }
+
+    /// Looks up the CIK for a company ticker or mutual fund symbol.
+    pub fn cik(&self, ticker: &str) -> Option<Cik> {
+        self.symbols
+            .candidates(ticker)
+            .iter()
+            .find_map(|key| {
+                self.tickers
+                    .ciks
+                    .get(key)
+                    .copied()
+                    .or_else(|| {
+                        self.exchange_by_ticker
+                            .get(key)
+                            .map(|&i| self.exchanges[i].cik)
+                    })
+                    .or_else(|| {
+                        self.fund_by_symbol
+                            .get(key)
+                            .map(|&i| self.mutual_funds[i].cik)
+                    })
+            })
+            .map(Cik::from)
+    }
+
+    /// Exchange the ticker is listed on, such as `"Nasdaq"` or `"NYSE"`.
+    pub fn exchange(&self, ticker: &str) -> Option<&str> {
+        self.symbols
+            .candidates(ticker)
+            .iter()
+            .find_map(|key| self.exchange_by_ticker.get(key))
+            .map(|&i| self.exchanges[i].exchange.as_str())
+    }
+
+    /// The mutual fund share class for a fund symbol.
+    pub fn mutual_fund(&self, symbol: &str) -> Option<&MutualFundTicker> {
+        self.fund_by_symbol
+            .get(&symbol.trim().to_uppercase())
+            .map(|&i| &self.mutual_funds[i])
+    }
+
+    /// Resolves a CIK string or a ticker/fund symbol without making a request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::TickerNotFound` if `id` is neither a CIK nor a known symbol.
+    pub fn resolve(&self, id: &str) -> Result<Cik> {
+        id.parse()
+            .or_else(|_| self.cik(id).ok_or(EdgarError::TickerNotFound))
+    }
+}
+
+impl Edgar {
+    /// Downloads the ticker, exchange, and mutual fund mappings concurrently.
+    ///
+    /// The company ticker map is taken from the client's cache when it is still fresh and
+    /// stored there otherwise.
+    pub async fn bootstrap(&self) -> Result<Bootstrap> {
+        let (tickers, exchanges, mutual_funds) = tokio::try_join!(
+            self.cached_tickers(),
+            self.company_tickers_with_exchange(),
+            self.mutual_fund_tickers(),
+        )?;
+        Ok(Bootstrap::new(
+            tickers,
+            exchanges,
+            mutual_funds,
+            self.symbols.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let tickers = Arc::new(CachedTickers::new(vec![
+            CompanyTicker {
+                cik: 320193,
+                ticker: "AAPL".to_string(),
+                title: "Apple Inc.".to_string(),
+            },
+            CompanyTicker {
+                cik: 1067983,
+                ticker: "BRK-B".to_string(),
+                title: "BERKSHIRE HATHAWAY INC".to_string(),
+            },
+        ]));
+        let boot = Bootstrap::new(
+            tickers,
+            vec![CompanyTickerExchange {
+                cik: 320193,
+                ticker: "AAPL".to_string(),
+                name: "Apple Inc.".to_string(),
+                exchange: "Nasdaq".to_string(),
+            }],
+            vec![MutualFundTicker {
+                cik: 36405,
+                series_id: "S000002839".to_string(),
+                class_id: "C000007774".to_string(),
+                symbol: "VFIAX".to_string(),
+            }],
+            Arc::new(SymbolConventions::default()),
+        );
+
+        assert_eq!(boot.resolve("aapl").unwrap(), Cik::from(320193));
+        assert_eq!(boot.resolve("vfiax").unwrap(), Cik::from(36405));
+        assert_eq!(boot.resolve("BRK.B").unwrap(), Cik::from(1067983));
+        assert_eq!(boot.resolve("brkb").unwrap(), Cik::from(1067983));
+        assert_eq!(boot.resolve("0000789019").unwrap(), Cik::from(789019));
+        assert!(matches!(
+            boot.resolve("NOPE"),
+            Err(EdgarError::TickerNotFound)
+        ));
+        assert_eq!(boot.exchange("AAPL"), Some("Nasdaq"));
+        assert_eq!(boot.mutual_fund("VFIAX").unwrap().class_id, "C000007774");
+    }
+}
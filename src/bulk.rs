@@ -0,0 +1,328 @@
+//! Bulk, index-driven filing downloads.
+//!
+//! Backfilling years of EDGAR data means walking quarterly full indices and downloading every
+//! filing they list. [`BulkDownloader`] does that walk for a range of [`EdgarPeriod`]s: for each
+//! quarter it fetches the index via `get_period_filings`, applies the usual [`FilingOptions`]
+//! filters, and downloads the matching `.txt` submissions with bounded concurrency.
+//!
+//! The downloader holds a clone of the [`Edgar`] client, so every request goes through the same
+//! rate limiter as the rest of your application. Concurrency only controls how many downloads
+//! are in flight; the SEC request rate is still capped by the client.
+//!
+//! Downloads can either be written to a directory (`<dir>/<cik>/<accession>.txt`), consumed
+//! as a stream, or pushed into a [`Sink`](crate::Sink) that applies backpressure. Directory
+//! downloads are resumable: files are written under a `.part` name and renamed once complete,
+//! and files that already exist are skipped on the next run.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{BulkDownloader, Edgar, EdgarPeriod, FilingOptions, Quarter};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let edgar = Edgar::new("MyApp contact@example.com")?;
+//!
+//!     let downloader = BulkDownloader::new(
+//!         &edgar,
+//!         EdgarPeriod::new(2020, Quarter::Q1)?,
+//!         EdgarPeriod::new(2023, Quarter::Q4)?,
+//!     )?
+//!     .with_options(FilingOptions::new().with_form_type("10-K"))
+//!     .with_concurrency(4);
+//!
+//!     let summary = downloader.download_to_dir("./filings").await?;
+//!     println!("{} downloaded, {} skipped", summary.downloaded, summary.skipped);
+//!     Ok(())
+//! }
+//! ```
+
+use super::Edgar;
+use super::error::{EdgarError, Result};
+use super::index::EdgarPeriod;
+use super::options::FilingOptions;
+use super::sink::Sink;
+use super::traits::IndexOperations;
+use crate::parsing::index::{CompactIndexEntry, IndexEntry};
+use crate::parsing::intern::StringPool;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// A filing downloaded by [`BulkDownloader::stream`].
+#[derive(Debug, Clone)]
+pub struct BulkFiling {
+    /// Index entry the filing was downloaded from.
+    pub entry: IndexEntry,
+
+    /// Raw bytes of the complete submission file.
+    pub content: Vec<u8>,
+}
+
+/// Outcome of a [`BulkDownloader::download_to_dir`] run.
+#[derive(Debug, Default)]
+pub struct BulkSummary {
+    /// Number of filings written during this run.
+    pub downloaded: usize,
+
+    /// Number of filings skipped because they were already on disk.
+    pub skipped: usize,
+
+    /// Filings that could not be downloaded or written, with the reason.
+    ///
+    /// Failures do not stop the run; re-running the same downloader retries only these.
+    pub failed: Vec<(IndexEntry, EdgarError)>,
+}
+
+/// Downloads every filing listed in the full indices for a range of quarters.
+///
+/// Build one with [`BulkDownloader::new`] and configure it with the `with_*` methods. The range
+/// is inclusive on both ends and processed oldest quarter first; within a quarter, up to
+/// `concurrency` downloads run at once.
+#[derive(Debug, Clone)]
+pub struct BulkDownloader {
+    edgar: Edgar,
+    start: EdgarPeriod,
+    end: EdgarPeriod,
+    options: Option<FilingOptions>,
+    concurrency: usize,
+}
+
+impl BulkDownloader {
+    /// Creates a downloader for the quarters from `start` through `end`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if `start` is after `end`.
+    pub fn new(edgar: &Edgar, start: EdgarPeriod, end: EdgarPeriod) -> Result<Self> {
+        if start.key() > end.key() {
+            return Err(EdgarError::InvalidFormat(format!(
+                "bulk range start {}Q{} is after end {}Q{}",
+                start.year(),
+                start.quarter().as_i32(),
+                end.year(),
+                end.quarter().as_i32()
+            )));
+        }
+
+        Ok(Self {
+            edgar: edgar.clone(),
+            start,
+            end,
+            options: None,
+            concurrency: DEFAULT_CONCURRENCY,
+        })
+    }
+
+    /// Filters each quarter's index entries (form types, CIKs, offset, limit).
+    ///
+    /// Offset and limit apply per quarter, not to the range as a whole.
+    pub fn with_options(mut self, options: FilingOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Sets the maximum number of downloads in flight (minimum 1).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Returns every quarter in the range, oldest first.
+    pub fn periods(&self) -> Vec<EdgarPeriod> {
+        let mut periods = Vec::new();
+        let mut current = self.start;
+        while current.key() <= self.end.key() {
+            periods.push(current);
+            current = current.next();
+        }
+        periods
+    }
+
+    /// Returns the path a filing is written to under `dir`: `<dir>/<cik>/<filename>`.
+    pub fn path_for(dir: &Path, entry: &IndexEntry) -> PathBuf {
+        let filename = entry.url.rsplit('/').next().unwrap_or_default();
+        dir.join(entry.cik.to_string()).join(filename)
+    }
+
+    /// Fetches the filtered index entries for a single quarter.
+    pub async fn period_entries(&self, period: EdgarPeriod) -> Result<Vec<IndexEntry>> {
+        self.edgar
+            .get_period_filings(period, self.options.clone())
+            .await
+    }
+
+    /// Fetches a quarter's filtered entries with repeated fields interned in `pool`.
+    ///
+    /// Use this when holding the entries of many quarters in memory; passing the same pool
+    /// for every quarter stores each form type, date, and company name once for the range.
+    pub async fn period_entries_interned(
+        &self,
+        period: EdgarPeriod,
+        pool: &mut StringPool,
+    ) -> Result<Vec<CompactIndexEntry>> {
+        let entries = self.period_entries(period).await?;
+        Ok(entries
+            .into_iter()
+            .map(|entry| CompactIndexEntry::from_entry(entry, pool))
+            .collect())
+    }
+
+    /// Downloads every filing in the range into `dir`.
+    ///
+    /// Existing files are skipped, so an interrupted run can be restarted with the same
+    /// arguments and only the missing filings are fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a quarter's index cannot be retrieved. Failures for individual
+    /// filings are collected in [`BulkSummary::failed`] instead.
+    pub async fn download_to_dir(&self, dir: impl AsRef<Path>) -> Result<BulkSummary> {
+        let dir = dir.as_ref();
+        let mut summary = BulkSummary::default();
+
+        for period in self.periods() {
+            let entries = self.period_entries(period).await?;
+
+            let mut pending = Vec::with_capacity(entries.len());
+            for entry in entries {
+                if tokio::fs::try_exists(Self::path_for(dir, &entry)).await? {
+                    summary.skipped += 1;
+                } else {
+                    pending.push(entry);
+                }
+            }
+
+            let mut results = stream::iter(pending)
+                .map(|entry| async move {
+                    let result = self.save(dir, &entry).await;
+                    (entry, result)
+                })
+                .buffer_unordered(self.concurrency);
+
+            while let Some((entry, result)) = results.next().await {
+                match result {
+                    Ok(()) => summary.downloaded += 1,
+                    Err(e) => summary.failed.push((entry, e)),
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Streams every filing in the range instead of writing it to disk.
+    ///
+    /// Items within a quarter arrive in completion order. An error fetching a quarter's index
+    /// is yielded as an item and the stream moves on to the next quarter.
+    pub fn stream(&self) -> impl Stream<Item = Result<BulkFiling>> + '_ {
+        stream::iter(self.periods())
+            .then(move |period| self.period_entries(period))
+            .flat_map(move |result| match result {
+                Ok(entries) => stream::iter(entries)
+                    .map(move |entry| self.fetch(entry))
+                    .buffer_unordered(self.concurrency)
+                    .left_stream(),
+                Err(e) => stream::once(async move { Err(e) }).right_stream(),
+            })
+    }
+
+    /// Pushes every filing in the range into `sink`, waiting whenever the sink is full.
+    ///
+    /// Per-item errors (including a quarter's index failing) are delivered to the sink like
+    /// any other record. Stops early, without error, if the sink is closed.
+    pub async fn send_to<S>(&self, sink: &mut S) -> Result<()>
+    where
+        S: Sink<Result<BulkFiling>>,
+    {
+        let mut filings = std::pin::pin!(self.stream());
+        while let Some(item) = filings.next().await {
+            match sink.send(item).await {
+                Ok(()) => {}
+                Err(EdgarError::SinkClosed) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    async fn fetch(&self, entry: IndexEntry) -> Result<BulkFiling> {
+        let content = self.edgar.get_bytes(&entry.url).await?;
+        Ok(BulkFiling { entry, content })
+    }
+
+    async fn save(&self, dir: &Path, entry: &IndexEntry) -> Result<()> {
+        let path = Self::path_for(dir, entry);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let content = self.edgar.get_bytes(&entry.url).await?;
+
+        // Write under a temporary name so an interrupted write is never mistaken for a
+        // finished download when resuming.
+        let mut partial = path.clone().into_os_string();
+        partial.push(".part");
+        tokio::fs::write(&partial, content).await?;
+        tokio::fs::rename(&partial, &path).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quarter::Quarter;
+    use chrono::NaiveDate;
+
+    fn edgar() -> Edgar {
+        Edgar::new("test_agent example@example.com").unwrap()
+    }
+
+    #[test]
+    fn test_periods_span_year_boundary() {
+        let downloader = BulkDownloader::new(
+            &edgar(),
+            EdgarPeriod::new(2022, Quarter::Q3).unwrap(),
+            EdgarPeriod::new(2023, Quarter::Q2).unwrap(),
+        )
+        .unwrap();
+
+        let periods: Vec<_> = downloader
+            .periods()
+            .into_iter()
+            .map(EdgarPeriod::key)
+            .collect();
+        assert_eq!(periods, vec![(2022, 3), (2022, 4), (2023, 1), (2023, 2)]);
+    }
+
+    #[test]
+    fn test_reversed_range_is_rejected() {
+        let result = BulkDownloader::new(
+            &edgar(),
+            EdgarPeriod::new(2023, Quarter::Q1).unwrap(),
+            EdgarPeriod::new(2022, Quarter::Q4).unwrap(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_path_for_entry() {
+        let entry = IndexEntry {
+            company_name: "3J LLC".to_string(),
+            form_type: "D".to_string(),
+            cik: 1975393,
+            date_filed: NaiveDate::from_ymd_opt(2023, 7, 3).unwrap(),
+            date_filed_raw: "2023-07-03".to_string(),
+            url: "https://www.sec.gov/Archives/edgar/data/1975393/0001975393-23-000001.txt"
+                .to_string(),
+        };
+
+        let path = BulkDownloader::path_for(Path::new("out"), &entry);
+        assert_eq!(
+            path,
+            Path::new("out/1975393/0001975393-23-000001.txt").to_path_buf()
+        );
+    }
+}
@@ -0,0 +1,229 @@
+//! Nightly bulk archives of company facts and submissions.
+//!
+//! The SEC rebuilds two large ZIP archives every night:
+//! - `companyfacts.zip` — one `CIK##########.json` company facts document per filer.
+//! - `submissions.zip` — one `CIK##########.json` submissions document per filer, plus
+//!   `CIK##########-submissions-NNN.json` pages holding older filing history.
+//!
+//! For market-wide analysis these replace millions of per-company API calls. The archives are
+//! several gigabytes, so [`Edgar::download_bulk_archive`] streams the response straight to disk
+//! and [`BulkArchiveReader`] decodes one entry at a time, never holding more than a single
+//! company's document in memory.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{BulkArchive, BulkArchiveReader, Edgar};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let edgar = Edgar::new("MyApp contact@example.com")?;
+//!     edgar
+//!         .download_bulk_archive(BulkArchive::CompanyFacts, "companyfacts.zip")
+//!         .await?;
+//!
+//!     let mut reader = BulkArchiveReader::company_facts("companyfacts.zip")?;
+//!     for facts in reader.iter() {
+//!         let facts = facts?;
+//!         println!("{} reports {} us-gaap concepts", facts.entity_name, facts.taxonomies.us_gaap().map_or(0, |facts| facts.len()));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::Edgar;
+use super::company::CompanyFacts;
+use super::error::{EdgarError, Result};
+use super::filings::Submission;
+use serde::de::DeserializeOwned;
+use std::fs::File;
+use std::io::BufReader;
+use std::marker::PhantomData;
+use std::path::Path;
+use zip::ZipArchive;
+
+/// The bulk archives published by the SEC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkArchive {
+    /// `companyfacts.zip` — XBRL company facts for every filer.
+    CompanyFacts,
+
+    /// `submissions.zip` — submissions history for every filer.
+    Submissions,
+}
+
+impl BulkArchive {
+    /// Path of the archive relative to the EDGAR archives base URL.
+    fn path(&self) -> &'static str {
+        match self {
+            BulkArchive::CompanyFacts => "daily-index/xbrl/companyfacts.zip",
+            BulkArchive::Submissions => "daily-index/bulkdata/submissions.zip",
+        }
+    }
+}
+
+impl Edgar {
+    /// Returns the download URL for a bulk archive.
+    pub fn bulk_archive_url(&self, archive: BulkArchive) -> String {
+        format!("{}/{}", self.edgar_archives_url, archive.path())
+    }
+
+    /// Streams a bulk archive to `path`, returning the number of bytes written.
+    ///
+    /// See [`Edgar::download_to_file`]: an interrupted or cancelled download never leaves a
+    /// truncated archive at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a non-success HTTP status, a network failure mid-download, or
+    /// if the file cannot be written.
+    pub async fn download_bulk_archive(
+        &self,
+        archive: BulkArchive,
+        path: impl AsRef<Path>,
+    ) -> Result<u64> {
+        self.download_to_file(&self.bulk_archive_url(archive), path)
+            .await
+    }
+}
+
+/// Reads documents out of a downloaded bulk archive one entry at a time.
+///
+/// Open a reader with [`BulkArchiveReader::company_facts`] or
+/// [`BulkArchiveReader::submissions`], then walk it with [`BulkArchiveReader::iter`].
+/// Entries are decoded lazily, so a malformed document only fails its own item.
+pub struct BulkArchiveReader<T> {
+    archive: ZipArchive<BufReader<File>>,
+    include: fn(&str) -> bool,
+    _marker: PhantomData<T>,
+}
+
+impl BulkArchiveReader<CompanyFacts> {
+    /// Opens a `companyfacts.zip` archive.
+    pub fn company_facts(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open(path, |name| name.ends_with(".json"))
+    }
+}
+
+impl BulkArchiveReader<Submission> {
+    /// Opens a `submissions.zip` archive.
+    ///
+    /// Only the main `CIK##########.json` documents are yielded; the supplementary
+    /// `-submissions-NNN.json` history pages are skipped because they are not
+    /// [`Submission`] documents.
+    pub fn submissions(path: impl AsRef<Path>) -> Result<Self> {
+        Self::open(path, |name| {
+            name.ends_with(".json") && !name.contains("-submissions-")
+        })
+    }
+}
+
+impl<T: DeserializeOwned> BulkArchiveReader<T> {
+    fn open(path: impl AsRef<Path>, include: fn(&str) -> bool) -> Result<Self> {
+        let file = File::open(path)?;
+        let archive = ZipArchive::new(BufReader::new(file))
+            .map_err(|e| EdgarError::ArchiveError(e.to_string()))?;
+        Ok(Self {
+            archive,
+            include,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Total number of files in the archive, including any that [`iter`](Self::iter) skips.
+    pub fn len(&self) -> usize {
+        self.archive.len()
+    }
+
+    /// Returns true if the archive contains no files.
+    pub fn is_empty(&self) -> bool {
+        self.archive.is_empty()
+    }
+
+    /// Iterates over the archive's documents in archive order.
+    pub fn iter(&mut self) -> impl Iterator<Item = Result<T>> + '_ {
+        let include = self.include;
+        let archive = &mut self.archive;
+        (0..archive.len()).filter_map(move |index| {
+            let entry = match archive.by_index(index) {
+                Ok(entry) => entry,
+                Err(e) => return Some(Err(EdgarError::ArchiveError(e.to_string()))),
+            };
+            if !include(entry.name()) {
+                return None;
+            }
+            Some(serde_json::from_reader(BufReader::new(entry)).map_err(EdgarError::from))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::{SimpleFileOptions, ZipWriter};
+
+    fn write_archive(path: &Path, entries: &[(&str, &str)]) {
+        let mut writer = ZipWriter::new(File::create(path).unwrap());
+        for (name, body) in entries {
+            writer
+                .start_file(*name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(body.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_bulk_archive_urls() {
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        assert_eq!(
+            edgar.bulk_archive_url(BulkArchive::CompanyFacts),
+            "https://www.sec.gov/Archives/edgar/daily-index/xbrl/companyfacts.zip"
+        );
+        assert_eq!(
+            edgar.bulk_archive_url(BulkArchive::Submissions),
+            "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip"
+        );
+    }
+
+    #[test]
+    fn test_iterate_company_facts_archive() {
+        let path = std::env::temp_dir().join(format!("edgarkit-facts-{}.zip", std::process::id()));
+        write_archive(
+            &path,
+            &[
+                (
+                    "CIK0000000001.json",
+                    r#"{"cik": 1, "entityName": "First Co", "facts": {"dei": {}}}"#,
+                ),
+                ("CIK0000000002.json", "not json"),
+            ],
+        );
+
+        let mut reader = BulkArchiveReader::company_facts(&path).unwrap();
+        let results: Vec<_> = reader.iter().collect();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().entity_name, "First Co");
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_submissions_archive_skips_history_pages() {
+        let path = std::env::temp_dir().join(format!("edgarkit-subs-{}.zip", std::process::id()));
+        write_archive(
+            &path,
+            &[(
+                "CIK0000000001-submissions-001.json",
+                r#"{"accessionNumber": []}"#,
+            )],
+        );
+
+        let mut reader = BulkArchiveReader::<Submission>::submissions(&path).unwrap();
+        assert_eq!(reader.len(), 1);
+        assert_eq!(reader.iter().count(), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
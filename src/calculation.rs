@@ -0,0 +1,469 @@
+//! Calculation linkbase validation for XBRL statement values.
+//!
+//! XBRL filings ship a *calculation linkbase* (`*_cal.xml`) describing how line items roll
+//! up into totals: `Assets` is the weighted sum of `AssetsCurrent` and `AssetsNoncurrent`,
+//! `GrossProfit` is `Revenues` minus `CostOfRevenue`, and so on. Each relationship is a
+//! *calculation arc* from a parent (total) concept to a child concept with a weight of
+//! `1` or `-1`.
+//!
+//! This module uses those arcs to check that extracted values are internally consistent.
+//! An inconsistency usually points at an extraction error (wrong context, wrong period,
+//! mis-scaled value) or at an unusual filer extension that the standard arcs don't know
+//! about.
+//!
+//! You can either validate against the filer's own linkbase (see
+//! [`CalculationLinkbase::parse`]) or against a small built-in set of us-gaap identities
+//! (see [`CalculationLinkbase::standard`]).
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgarkit::CalculationLinkbase;
+//! use std::collections::HashMap;
+//!
+//! let linkbase = CalculationLinkbase::standard();
+//! let values = HashMap::from([
+//!     ("Assets".to_string(), 100.0),
+//!     ("LiabilitiesAndStockholdersEquity".to_string(), 90.0),
+//! ]);
+//!
+//! let issues = linkbase.validate(&values);
+//! assert_eq!(issues.len(), 1);
+//! assert_eq!(issues[0].parent, "Assets");
+//! ```
+
+use super::company::CompanyFacts;
+use super::error::{EdgarError, Result};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use std::collections::{BTreeMap, HashMap};
+
+/// Arc role identifying summation relationships in a calculation linkbase.
+const SUMMATION_ITEM_ARCROLE: &str = "http://www.xbrl.org/2003/arcrole/summation-item";
+
+/// A single parent → child relationship from a calculation linkbase.
+///
+/// us-gaap concepts are identified by their local tag name (e.g. `Assets`), matching how
+/// `CompanyFacts` keys its facts. Concepts from other taxonomies keep their prefix
+/// (`aapl:Assets`), so a filer extension never collides with the us-gaap concept of the
+/// same name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculationArc {
+    /// The total concept (e.g. `Assets`).
+    pub parent: String,
+
+    /// The contributing concept (e.g. `AssetsCurrent`).
+    pub child: String,
+
+    /// Contribution weight, typically `1.0` or `-1.0`.
+    pub weight: f64,
+}
+
+impl CalculationArc {
+    pub fn new(parent: impl Into<String>, child: impl Into<String>, weight: f64) -> Self {
+        Self {
+            parent: parent.into(),
+            child: child.into(),
+            weight,
+        }
+    }
+}
+
+/// A set of calculation arcs plus the tolerances used when comparing totals.
+///
+/// Reported XBRL values are rounded (usually to thousands or millions), so sums rarely
+/// match to the dollar. A parent is considered consistent when the difference between the
+/// reported total and the weighted sum of its children is within `absolute_tolerance` or
+/// within `relative_tolerance` of the reported total, whichever is larger.
+#[derive(Debug, Clone)]
+pub struct CalculationLinkbase {
+    arcs: Vec<CalculationArc>,
+
+    /// Allowed absolute difference (in the unit of the values).
+    pub absolute_tolerance: f64,
+
+    /// Allowed difference relative to the reported total (e.g. `0.001` = 0.1%).
+    pub relative_tolerance: f64,
+
+    /// When true (default), a total is only checked if every child is present.
+    /// When false, missing children are treated as zero.
+    pub require_all_children: bool,
+}
+
+/// A total whose reported value disagrees with the weighted sum of its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalculationInconsistency {
+    /// The total concept that failed validation.
+    pub parent: String,
+
+    /// The value reported for the total.
+    pub reported: f64,
+
+    /// The weighted sum of the children.
+    pub computed: f64,
+
+    /// Children that contributed to `computed`.
+    pub children: Vec<String>,
+
+    /// Children defined by the arcs but absent from the values (only populated when
+    /// `require_all_children` is false).
+    pub missing_children: Vec<String>,
+
+    /// Accession number of the filing the values came from, when validating facts.
+    pub accession_number: Option<String>,
+
+    /// Period start (duration facts only), when validating facts.
+    pub start: Option<String>,
+
+    /// Period end, when validating facts.
+    pub end: Option<String>,
+}
+
+impl CalculationInconsistency {
+    /// Returns `reported - computed`.
+    pub fn difference(&self) -> f64 {
+        self.reported - self.computed
+    }
+}
+
+impl Default for CalculationLinkbase {
+    fn default() -> Self {
+        Self {
+            arcs: Vec::new(),
+            absolute_tolerance: 1.0,
+            relative_tolerance: 0.001,
+            require_all_children: true,
+        }
+    }
+}
+
+impl CalculationLinkbase {
+    /// Creates a linkbase from an explicit list of arcs.
+    pub fn new(arcs: Vec<CalculationArc>) -> Self {
+        Self {
+            arcs,
+            ..Default::default()
+        }
+    }
+
+    /// Returns a small set of us-gaap identities that hold for most filers.
+    ///
+    /// These cover the balance sheet equation and the top of the income statement. They
+    /// are intentionally conservative; use a filer's own linkbase for complete coverage.
+    pub fn standard() -> Self {
+        Self::new(vec![
+            CalculationArc::new("Assets", "LiabilitiesAndStockholdersEquity", 1.0),
+            CalculationArc::new("Liabilities", "LiabilitiesCurrent", 1.0),
+            CalculationArc::new("Liabilities", "LiabilitiesNoncurrent", 1.0),
+            CalculationArc::new("LiabilitiesAndStockholdersEquity", "Liabilities", 1.0),
+            CalculationArc::new(
+                "LiabilitiesAndStockholdersEquity",
+                "StockholdersEquity",
+                1.0,
+            ),
+            CalculationArc::new("GrossProfit", "Revenues", 1.0),
+            CalculationArc::new("GrossProfit", "CostOfRevenue", -1.0),
+            CalculationArc::new("OperatingIncomeLoss", "GrossProfit", 1.0),
+            CalculationArc::new("OperatingIncomeLoss", "OperatingExpenses", -1.0),
+        ])
+    }
+
+    /// Parses an XBRL calculation linkbase document (`*_cal.xml`).
+    ///
+    /// Only `summation-item` arcs are kept. Locator labels are resolved per extended link.
+    /// us-gaap concept names are reduced to their local tag (`us-gaap_Assets` → `Assets`);
+    /// others are qualified with their prefix (`aapl_Assets` → `aapl:Assets`).
+    /// Arcs repeated across statement roles are deduplicated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::XmlError` if the document is not well-formed XML.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(content);
+        reader.config_mut().trim_text(true);
+
+        let mut locators: HashMap<String, String> = HashMap::new();
+        let mut raw_arcs: Vec<(String, String, f64)> = Vec::new();
+        let mut arcs: Vec<CalculationArc> = Vec::new();
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(e)) | Ok(Event::Empty(e)) => match e.local_name().as_ref() {
+                    b"calculationLink" => {
+                        locators.clear();
+                    }
+                    b"loc" => {
+                        let mut label = None;
+                        let mut href = None;
+                        for attr in e.attributes().flatten() {
+                            let value = attr
+                                .unescape_value()
+                                .map_err(|e| EdgarError::XmlError(e.to_string()))?
+                                .into_owned();
+                            match attr.key.local_name().as_ref() {
+                                b"label" => label = Some(value),
+                                b"href" => href = Some(value),
+                                _ => {}
+                            }
+                        }
+                        if let (Some(label), Some(href)) = (label, href) {
+                            locators.insert(label, concept_from_href(&href));
+                        }
+                    }
+                    b"calculationArc" => {
+                        let mut from = None;
+                        let mut to = None;
+                        let mut weight = 1.0;
+                        let mut arcrole = None;
+                        for attr in e.attributes().flatten() {
+                            let value = attr
+                                .unescape_value()
+                                .map_err(|e| EdgarError::XmlError(e.to_string()))?
+                                .into_owned();
+                            match attr.key.local_name().as_ref() {
+                                b"from" => from = Some(value),
+                                b"to" => to = Some(value),
+                                b"weight" => weight = value.parse().unwrap_or(1.0),
+                                b"arcrole" => arcrole = Some(value),
+                                _ => {}
+                            }
+                        }
+                        let is_summation = arcrole
+                            .as_deref()
+                            .is_none_or(|role| role == SUMMATION_ITEM_ARCROLE);
+                        if let (Some(from), Some(to), true) = (from, to, is_summation) {
+                            raw_arcs.push((from, to, weight));
+                        }
+                    }
+                    _ => {}
+                },
+                Ok(Event::End(e)) if e.local_name().as_ref() == b"calculationLink" => {
+                    // Locator labels are only meaningful inside their extended link.
+                    for (from, to, weight) in raw_arcs.drain(..) {
+                        if let (Some(parent), Some(child)) =
+                            (locators.get(&from), locators.get(&to))
+                        {
+                            let arc = CalculationArc::new(parent.clone(), child.clone(), weight);
+                            if !arcs.contains(&arc) {
+                                arcs.push(arc);
+                            }
+                        }
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(EdgarError::XmlError(e.to_string())),
+                _ => {}
+            }
+        }
+
+        Ok(Self::new(arcs))
+    }
+
+    /// Returns the arcs in this linkbase.
+    pub fn arcs(&self) -> &[CalculationArc] {
+        &self.arcs
+    }
+
+    /// Sets the absolute and relative tolerances used when comparing totals.
+    pub fn with_tolerance(mut self, absolute: f64, relative: f64) -> Self {
+        self.absolute_tolerance = absolute;
+        self.relative_tolerance = relative;
+        self
+    }
+
+    /// Sets whether totals with missing children are skipped (true) or checked with the
+    /// missing children treated as zero (false).
+    pub fn with_require_all_children(mut self, require: bool) -> Self {
+        self.require_all_children = require;
+        self
+    }
+
+    /// Validates a single set of values (one statement, one period).
+    ///
+    /// `values` maps concept names to reported values. Totals that are absent, or that have
+    /// no children present, are skipped.
+    pub fn validate(&self, values: &HashMap<String, f64>) -> Vec<CalculationInconsistency> {
+        let mut groups: BTreeMap<&str, Vec<&CalculationArc>> = BTreeMap::new();
+        for arc in &self.arcs {
+            groups.entry(arc.parent.as_str()).or_default().push(arc);
+        }
+
+        let mut issues = Vec::new();
+        for (parent, arcs) in groups {
+            let Some(&reported) = values.get(parent) else {
+                continue;
+            };
+
+            let mut computed = 0.0;
+            let mut children = Vec::new();
+            let mut missing_children = Vec::new();
+            for arc in arcs {
+                match values.get(&arc.child) {
+                    Some(value) => {
+                        computed += value * arc.weight;
+                        children.push(arc.child.clone());
+                    }
+                    None => missing_children.push(arc.child.clone()),
+                }
+            }
+
+            if children.is_empty() || (self.require_all_children && !missing_children.is_empty()) {
+                continue;
+            }
+
+            let allowed = self
+                .absolute_tolerance
+                .max(self.relative_tolerance * reported.abs());
+            if (reported - computed).abs() > allowed {
+                issues.push(CalculationInconsistency {
+                    parent: parent.to_string(),
+                    reported,
+                    computed,
+                    children,
+                    missing_children,
+                    accession_number: None,
+                    start: None,
+                    end: None,
+                });
+            }
+        }
+
+        issues
+    }
+
+    /// Validates every filing/period combination found in a company's us-gaap facts.
+    ///
+    /// Data points in `unit` (typically `"USD"`) are grouped by accession number and period
+    /// (start/end), which corresponds to one statement column in one filing. Each group is
+    /// validated independently and the returned inconsistencies carry the accession number
+    /// and period they came from.
+    pub fn validate_facts(
+        &self,
+        facts: &CompanyFacts,
+        unit: &str,
+    ) -> Vec<CalculationInconsistency> {
+        type Context = (String, Option<String>, String);
+        let mut contexts: BTreeMap<Context, HashMap<String, f64>> = BTreeMap::new();
+
+        for (tag, fact) in facts.taxonomies.us_gaap().into_iter().flatten() {
+            let Some(points) = fact.units.get(unit) else {
+                continue;
+            };
+            for point in points {
+                let Some(value) = point.val.as_f64() else {
+                    continue;
+                };
+                contexts
+                    .entry((point.accn.clone(), point.start.clone(), point.end.clone()))
+                    .or_default()
+                    .insert(tag.clone(), value);
+            }
+        }
+
+        let mut issues = Vec::new();
+        for ((accn, start, end), values) in contexts {
+            for mut issue in self.validate(&values) {
+                issue.accession_number = Some(accn.clone());
+                issue.start = start.clone();
+                issue.end = Some(end.clone());
+                issues.push(issue);
+            }
+        }
+        issues
+    }
+}
+
+/// Extracts the concept name from a locator href.
+///
+/// Hrefs look like `https://xbrl.fasb.org/us-gaap/2023/elts/us-gaap-2023.xsd#us-gaap_Assets`
+/// or `aapl-20230930.xsd#aapl_ProductMember`; the fragment is `<prefix>_<LocalName>`.
+fn concept_from_href(href: &str) -> String {
+    let fragment = href.rsplit('#').next().unwrap_or(href);
+    match fragment.split_once('_') {
+        Some(("us-gaap", local)) => local.to_string(),
+        Some((prefix, local)) => format!("{}:{}", prefix, local),
+        None => fragment.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(pairs: &[(&str, f64)]) -> HashMap<String, f64> {
+        pairs.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_consistent_balance_sheet() {
+        let linkbase = CalculationLinkbase::standard();
+        let issues = linkbase.validate(&values(&[
+            ("Assets", 1000.0),
+            ("LiabilitiesAndStockholdersEquity", 1000.0),
+            ("Liabilities", 600.0),
+            ("StockholdersEquity", 400.0),
+        ]));
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_flags_inconsistent_total() {
+        let linkbase = CalculationLinkbase::standard();
+        let issues = linkbase.validate(&values(&[
+            ("GrossProfit", 500.0),
+            ("Revenues", 1000.0),
+            ("CostOfRevenue", 400.0),
+        ]));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].parent, "GrossProfit");
+        assert_eq!(issues[0].computed, 600.0);
+        assert_eq!(issues[0].difference(), -100.0);
+    }
+
+    #[test]
+    fn test_incomplete_children_are_skipped() {
+        let linkbase = CalculationLinkbase::standard();
+        let inputs = values(&[("Liabilities", 600.0), ("LiabilitiesCurrent", 200.0)]);
+        assert!(linkbase.validate(&inputs).is_empty());
+
+        let lenient = CalculationLinkbase::standard().with_require_all_children(false);
+        let issues = lenient.validate(&inputs);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].missing_children, vec!["LiabilitiesNoncurrent"]);
+    }
+
+    #[test]
+    fn test_parse_calculation_linkbase() {
+        let xml = r##"<?xml version="1.0" encoding="utf-8"?>
+<link:linkbase xmlns:link="http://www.xbrl.org/2003/linkbase" xmlns:xlink="http://www.w3.org/1999/xlink">
+  <link:calculationLink xlink:role="http://example.com/role/BalanceSheet" xlink:type="extended">
+    <link:loc xlink:type="locator" xlink:href="https://xbrl.fasb.org/us-gaap/2023/elts/us-gaap-2023.xsd#us-gaap_Assets" xlink:label="loc_Assets"/>
+    <link:loc xlink:type="locator" xlink:href="https://xbrl.fasb.org/us-gaap/2023/elts/us-gaap-2023.xsd#us-gaap_AssetsCurrent" xlink:label="loc_AssetsCurrent"/>
+    <link:loc xlink:type="locator" xlink:href="aapl-20230930.xsd#aapl_OtherAssetsSpecial" xlink:label="loc_Other"/>
+    <link:calculationArc xlink:type="arc" xlink:arcrole="http://www.xbrl.org/2003/arcrole/summation-item" xlink:from="loc_Assets" xlink:to="loc_AssetsCurrent" weight="1.0" order="1"/>
+    <link:calculationArc xlink:type="arc" xlink:arcrole="http://www.xbrl.org/2003/arcrole/summation-item" xlink:from="loc_Assets" xlink:to="loc_Other" weight="1.0" order="2"/>
+  </link:calculationLink>
+</link:linkbase>"##;
+
+        let linkbase = CalculationLinkbase::parse(xml).unwrap();
+        assert_eq!(
+            linkbase.arcs(),
+            &[
+                CalculationArc::new("Assets", "AssetsCurrent", 1.0),
+                CalculationArc::new("Assets", "aapl:OtherAssetsSpecial", 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extension_concepts_keep_their_prefix() {
+        assert_eq!(
+            concept_from_href("us-gaap-2023.xsd#us-gaap_Assets"),
+            "Assets"
+        );
+        assert_eq!(
+            concept_from_href("aapl-20230930.xsd#aapl_Assets"),
+            "aapl:Assets"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! The Central Index Key (CIK) identifier.
+//!
+//! EDGAR identifies every filer by a CIK of up to ten digits. Different endpoints want it in
+//! different shapes: the submissions and XBRL APIs use the zero-padded form
+//! (`CIK0000320193.json`), archive paths use the bare number (`/data/320193/`), and payloads
+//! return it as either a number or a padded string. [`Cik`] stores the number once and
+//! produces whichever form is needed, so API methods accept `impl Into<Cik>` and callers can
+//! pass a `u64` or a parsed `Cik` interchangeably. Use [`Cik::checked`] for numbers that may
+//! be longer than EDGAR's ten digits.
+//!
+//! ```rust
+//! use edgarkit::Cik;
+//!
+//! let cik: Cik = "CIK0000320193".parse()?;
+//! assert_eq!(cik, Cik::from(320193));
+//! assert!(Cik::checked(12_345_678_901).is_err());
+//! assert_eq!(cik.padded(), "0000320193");
+//! assert_eq!(cik.to_string(), "320193");
+//! # Ok::<(), edgarkit::EdgarError>(())
+//! ```
+
+use super::error::{EdgarError, Result};
+use serde::{Deserialize, Deserializer, Serialize, de};
+use std::fmt;
+use std::str::FromStr;
+
+/// Largest value that fits in EDGAR's ten-digit CIK.
+const MAX_CIK: u64 = 9_999_999_999;
+
+/// A filer's Central Index Key.
+///
+/// Displays without padding; use [`Cik::padded`] for the ten-digit form. Deserializes from
+/// either a number or a (possibly zero-padded) string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct Cik(u64);
+
+impl Cik {
+    /// Wraps `cik` without checking it; use [`Cik::checked`] for untrusted numbers.
+    pub const fn new(cik: u64) -> Self {
+        Self(cik)
+    }
+
+    /// Wraps `cik`, failing with `EdgarError::InvalidFormat` if it is longer than ten digits.
+    pub fn checked(cik: u64) -> Result<Self> {
+        if cik > MAX_CIK {
+            return Err(EdgarError::InvalidFormat(format!(
+                "{} is longer than ten digits",
+                cik
+            )));
+        }
+        Ok(Self(cik))
+    }
+
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// The ten-digit, zero-padded form used in data API URLs (`0000320193`).
+    pub fn padded(self) -> String {
+        format!("{:010}", self.0)
+    }
+}
+
+/// Debug builds assert that `cik` fits in ten digits; see [`Cik::checked`].
+impl From<u64> for Cik {
+    fn from(cik: u64) -> Self {
+        debug_assert!(cik <= MAX_CIK, "{} is longer than ten digits", cik);
+        Self::new(cik)
+    }
+}
+
+impl From<&Cik> for Cik {
+    fn from(cik: &Cik) -> Self {
+        *cik
+    }
+}
+
+impl From<Cik> for u64 {
+    fn from(cik: Cik) -> Self {
+        cik.0
+    }
+}
+
+/// Parses `320193`, `0000320193`, or `CIK0000320193`, ignoring surrounding whitespace.
+impl FromStr for Cik {
+    type Err = EdgarError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let digits = trimmed
+            .strip_prefix("CIK")
+            .or_else(|| trimmed.strip_prefix("cik"))
+            .unwrap_or(trimmed);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EdgarError::InvalidFormat(format!(
+                "{:?} is not a numeric CIK",
+                s
+            )));
+        }
+        match digits.parse::<u64>() {
+            Ok(cik) if cik <= MAX_CIK => Ok(Self(cik)),
+            _ => Err(EdgarError::InvalidFormat(format!(
+                "{:?} is longer than ten digits",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Cik {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cik {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = Cik;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a CIK as a number or string")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Cik, E>
+            where
+                E: de::Error,
+            {
+                Cik::checked(v).map_err(E::custom)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Cik, E>
+            where
+                E: de::Error,
+            {
+                let v = u64::try_from(v).map_err(E::custom)?;
+                Cik::checked(v).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<Cik, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cik() {
+        assert_eq!("320193".parse::<Cik>().unwrap(), Cik(320193));
+        assert_eq!(" 0000320193 ".parse::<Cik>().unwrap(), Cik(320193));
+        assert_eq!("CIK0000320193".parse::<Cik>().unwrap(), Cik(320193));
+        assert_eq!("0".parse::<Cik>().unwrap(), Cik(0));
+        assert!("AAPL".parse::<Cik>().is_err());
+        assert!("".parse::<Cik>().is_err());
+        assert!("12345678901".parse::<Cik>().is_err());
+        assert_eq!(Cik::checked(MAX_CIK).unwrap(), Cik(MAX_CIK));
+        assert!(Cik::checked(MAX_CIK + 1).is_err());
+        assert_eq!(Cik::from(320193), Cik(320193));
+    }
+
+    #[test]
+    fn test_deserialize_number_or_string() {
+        let ciks: Vec<Cik> = serde_json::from_str(r#"[320193, "0000789019"]"#).unwrap();
+        assert_eq!(ciks, vec![Cik(320193), Cik(789019)]);
+        assert_eq!(serde_json::to_string(&ciks[1]).unwrap(), "789019");
+        assert!(serde_json::from_str::<Cik>("12345678901").is_err());
+    }
+}
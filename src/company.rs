@@ -11,11 +11,24 @@
 
 use super::CompanyOperations;
 use super::Edgar;
+use super::cik::Cik;
+use super::core::fan_out;
+#[cfg(feature = "search")]
+use super::enrich::CompanyMetadata;
+use super::entity::EntityId;
 use super::error::{EdgarError, Result};
+use super::names::{CikCandidate, NameMatcher};
+use super::parsing::json::ParseReport;
+use super::quarter::Quarter;
+use super::rt::Instant;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
-use serde_json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 /// Mapping between stock ticker symbols and company CIKs.
 ///
@@ -23,7 +36,7 @@ use std::collections::HashMap;
 /// (CIK) and official title. The SEC maintains this mapping to help users discover
 /// company identifiers for EDGAR queries. Note that companies can have multiple tickers
 /// across different exchanges.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CompanyTicker {
     #[serde(rename = "cik_str")]
     pub cik: u64,
@@ -81,11 +94,83 @@ pub struct CompanyFacts {
 /// The SEC's XBRL data uses different taxonomies for different types of information.
 /// US-GAAP (Generally Accepted Accounting Principles) contains financial statement data,
 /// while DEI (Document and Entity Information) contains metadata about the company and filing.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TaxonomyGroups {
-    #[serde(rename = "us-gaap")]
-    pub us_gaap: HashMap<String, Fact>,
-    pub dei: HashMap<String, Fact>,
+/// Foreign private issuers report under `ifrs-full`, and payloads may also carry `srt`,
+/// `invest`, or a filer's own extension prefix. Every taxonomy in the payload is kept, keyed
+/// by its prefix, with accessors for the common ones.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TaxonomyGroups(HashMap<String, HashMap<String, Fact>>);
+
+impl TaxonomyGroups {
+    /// Concepts reported under `taxonomy` (e.g. "us-gaap", "ifrs-full"), keyed by tag.
+    pub fn get(&self, taxonomy: &str) -> Option<&HashMap<String, Fact>> {
+        self.0.get(taxonomy)
+    }
+
+    /// One concept, e.g. `fact("ifrs-full", "Revenue")`.
+    pub fn fact(&self, taxonomy: &str, tag: &str) -> Option<&Fact> {
+        self.get(taxonomy)?.get(tag)
+    }
+
+    /// US-GAAP concepts.
+    pub fn us_gaap(&self) -> Option<&HashMap<String, Fact>> {
+        self.get("us-gaap")
+    }
+
+    /// Document and Entity Information concepts.
+    pub fn dei(&self) -> Option<&HashMap<String, Fact>> {
+        self.get("dei")
+    }
+
+    /// IFRS concepts, reported by foreign private issuers.
+    pub fn ifrs_full(&self) -> Option<&HashMap<String, Fact>> {
+        self.get("ifrs-full")
+    }
+
+    /// SEC Reporting Taxonomy concepts.
+    pub fn srt(&self) -> Option<&HashMap<String, Fact>> {
+        self.get("srt")
+    }
+
+    /// Investment company concepts.
+    pub fn invest(&self) -> Option<&HashMap<String, Fact>> {
+        self.get("invest")
+    }
+
+    /// Iterates `(taxonomy, concepts)` pairs in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &HashMap<String, Fact>)> {
+        self.0
+            .iter()
+            .map(|(taxonomy, facts)| (taxonomy.as_str(), facts))
+    }
+
+    /// Taxonomy prefixes present, sorted.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.0.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Adds or replaces the concepts of one taxonomy.
+    pub fn insert(&mut self, taxonomy: impl Into<String>, facts: HashMap<String, Fact>) {
+        self.0.insert(taxonomy.into(), facts);
+    }
+
+    /// Number of taxonomies present.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the payload has no facts at all.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(String, HashMap<String, Fact>)> for TaxonomyGroups {
+    fn from_iter<I: IntoIterator<Item = (String, HashMap<String, Fact>)>>(iter: I) -> Self {
+        TaxonomyGroups(iter.into_iter().collect())
+    }
 }
 
 /// A single XBRL concept with its data points across different units of measure.
@@ -110,14 +195,14 @@ pub struct Fact {
 /// data points are instantaneous (balance sheet items) while others span a period (income
 /// statement items), which is reflected in the optional `start` field.
 ///
-/// The `val` field can contain either a number or a string, as some XBRL concepts are
-/// non-numeric (like descriptive text fields).
+/// The `val` field is a [`FactValue`]: most concepts report integers, but some report
+/// decimals (per-share amounts, ratios) or text (descriptive DEI fields).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataPoint {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start: Option<String>,
     pub end: String,
-    pub val: serde_json::Value, // Can be number or string
+    pub val: FactValue,
     pub accn: String,
     #[serde(default)]
     pub fy: Option<i32>,
@@ -129,6 +214,238 @@ pub struct DataPoint {
     pub frame: Option<String>,
 }
 
+/// A reported XBRL value.
+///
+/// The SEC APIs return values as JSON numbers for numeric concepts and as strings for
+/// text concepts. Integers are kept exact; anything with a fractional part (or too large
+/// for `i64`) becomes `Decimal`. Deserialization accepts numbers, strings, booleans, and
+/// `null` (as empty text), and serialization writes the same JSON shape back out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FactValue {
+    Integer(i64),
+    Decimal(f64),
+    Text(String),
+}
+
+impl FactValue {
+    /// Returns the value as `f64`, parsing numeric text.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            FactValue::Integer(i) => Some(*i as f64),
+            FactValue::Decimal(d) => Some(*d),
+            FactValue::Text(t) => t.trim().parse().ok(),
+        }
+    }
+
+    /// Returns the value as `i64` if it is integral, parsing numeric text.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            FactValue::Integer(i) => Some(*i),
+            FactValue::Decimal(d) if d.fract() == 0.0 && d.abs() < i64::MAX as f64 => {
+                Some(*d as i64)
+            }
+            FactValue::Decimal(_) => None,
+            FactValue::Text(t) => t.trim().parse().ok(),
+        }
+    }
+
+    /// Returns the text for `Text` values.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FactValue::Text(t) => Some(t),
+            _ => None,
+        }
+    }
+
+    /// Returns true for `Integer` and `Decimal` values.
+    pub fn is_number(&self) -> bool {
+        !matches!(self, FactValue::Text(_))
+    }
+}
+
+impl std::fmt::Display for FactValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FactValue::Integer(i) => write!(f, "{}", i),
+            FactValue::Decimal(d) => write!(f, "{}", d),
+            FactValue::Text(t) => f.write_str(t),
+        }
+    }
+}
+
+impl From<FactValue> for serde_json::Value {
+    fn from(value: FactValue) -> Self {
+        match value {
+            FactValue::Integer(i) => i.into(),
+            FactValue::Decimal(d) => d.into(),
+            FactValue::Text(t) => t.into(),
+        }
+    }
+}
+
+// Comparisons with plain numbers keep assertions like `point.val == 1512000000` working.
+macro_rules! fact_value_eq {
+    ($($t:ty),*) => {$(
+        impl PartialEq<$t> for FactValue {
+            fn eq(&self, other: &$t) -> bool {
+                self.as_f64() == Some(*other as f64) && self.is_number()
+            }
+        }
+    )*};
+}
+
+fact_value_eq!(i32, i64, u64, f64);
+
+impl Serialize for FactValue {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            FactValue::Integer(i) => serializer.serialize_i64(*i),
+            FactValue::Decimal(d) => serializer.serialize_f64(*d),
+            FactValue::Text(t) => serializer.serialize_str(t),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FactValue {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct FactValueVisitor;
+
+        impl serde::de::Visitor<'_> for FactValueVisitor {
+            type Value = FactValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a number or a string")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<FactValue, E> {
+                Ok(FactValue::Integer(v))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<FactValue, E> {
+                Ok(i64::try_from(v)
+                    .map(FactValue::Integer)
+                    .unwrap_or(FactValue::Decimal(v as f64)))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> std::result::Result<FactValue, E> {
+                Ok(FactValue::Decimal(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<FactValue, E> {
+                Ok(FactValue::Text(v.to_string()))
+            }
+
+            fn visit_bool<E>(self, v: bool) -> std::result::Result<FactValue, E> {
+                Ok(FactValue::Text(v.to_string()))
+            }
+
+            fn visit_unit<E>(self) -> std::result::Result<FactValue, E> {
+                Ok(FactValue::Text(String::new()))
+            }
+        }
+
+        deserializer.deserialize_any(FactValueVisitor)
+    }
+}
+
+impl CompanyFacts {
+    /// Iterates every data point as a flat `(taxonomy, tag, unit, point)` tuple.
+    ///
+    /// Order follows the underlying maps and is not stable between runs; use
+    /// [`to_records`](Self::to_records) for sorted output.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let facts = edgar.company_facts(320193).await?;
+    /// let usd = facts.iter_facts().filter(|(_, _, unit, _)| *unit == "USD").count();
+    /// ```
+    pub fn iter_facts(&self) -> impl Iterator<Item = (&str, &str, &str, &DataPoint)> + '_ {
+        self.taxonomies.iter().flat_map(|(taxonomy, facts)| {
+            facts.iter().flat_map(move |(tag, fact)| {
+                fact.units.iter().flat_map(move |(unit, points)| {
+                    points
+                        .iter()
+                        .map(move |point| (taxonomy, tag.as_str(), unit.as_str(), point))
+                })
+            })
+        })
+    }
+
+    /// Flattens the facts into long-format records, one per data point.
+    ///
+    /// Records are sorted by taxonomy, tag, unit, and period, so the same facts always produce
+    /// the same rows. Each record serializes as a flat object, ready for JSON lines or a
+    /// database bulk load.
+    pub fn to_records(&self) -> Vec<FactRecord> {
+        let mut records: Vec<FactRecord> = self
+            .iter_facts()
+            .map(|(taxonomy, tag, unit, point)| FactRecord {
+                cik: self.cik,
+                taxonomy: taxonomy.to_string(),
+                tag: tag.to_string(),
+                unit: unit.to_string(),
+                start: point.start.clone(),
+                end: point.end.clone(),
+                val: point.val.clone(),
+                accn: point.accn.clone(),
+                fy: point.fy,
+                fp: point.fp.clone(),
+                form: point.form.clone(),
+                filed: point.filed.clone(),
+                frame: point.frame.clone(),
+            })
+            .collect();
+        records.sort_by(|a, b| {
+            (
+                &a.taxonomy,
+                &a.tag,
+                &a.unit,
+                &a.end,
+                &a.start,
+                &a.filed,
+                &a.accn,
+            )
+                .cmp(&(
+                    &b.taxonomy,
+                    &b.tag,
+                    &b.unit,
+                    &b.end,
+                    &b.start,
+                    &b.filed,
+                    &b.accn,
+                ))
+        });
+        records
+    }
+}
+
+/// One company fact data point in long format.
+///
+/// Produced by [`CompanyFacts::to_records`]: the `DataPoint` fields plus the company,
+/// taxonomy, tag, and unit it was nested under.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactRecord {
+    pub cik: u64,
+    pub taxonomy: String,
+    pub tag: String,
+    pub unit: String,
+    pub start: Option<String>,
+    pub end: String,
+    pub val: FactValue,
+    pub accn: String,
+    pub fy: Option<i32>,
+    pub fp: Option<String>,
+    pub form: String,
+    pub filed: String,
+    pub frame: Option<String>,
+}
+
 /// Historical data for a single XBRL concept across a company's filings.
 ///
 /// Similar to a `Fact` from `CompanyFacts`, but retrieved individually for targeted
@@ -147,6 +464,93 @@ pub struct CompanyConcept {
     pub units: HashMap<String, Vec<DataPoint>>,
 }
 
+/// Which data points to keep when building a time series.
+///
+/// Duration facts (income statement, cash flow) carry a `start` date and are classified by
+/// the length of the period they cover; instant facts (balance sheet) have no `start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodKind {
+    /// Duration facts covering roughly a fiscal year (350–380 days).
+    Annual,
+    /// Duration facts covering roughly a fiscal quarter (80–100 days).
+    Quarterly,
+    /// Point-in-time facts.
+    Instant,
+}
+
+impl PeriodKind {
+    pub(crate) fn matches(&self, point: &DataPoint) -> Option<NaiveDate> {
+        let end = NaiveDate::parse_from_str(&point.end, "%Y-%m-%d").ok()?;
+        let start = point
+            .start
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+
+        let keep = match (self, start) {
+            (PeriodKind::Instant, None) => true,
+            (PeriodKind::Annual, Some(start)) => (350..=380).contains(&(end - start).num_days()),
+            (PeriodKind::Quarterly, Some(start)) => (80..=100).contains(&(end - start).num_days()),
+            _ => false,
+        };
+        keep.then_some(end)
+    }
+}
+
+impl CompanyConcept {
+    /// Builds a sorted, deduplicated `(period_end, value)` series for one unit.
+    ///
+    /// The same period is usually reported several times: in the original 10-Q or 10-K, as a
+    /// comparative figure in later filings, and in restatements. For each period the value from
+    /// the most recently filed data point wins, so restatements replace original figures.
+    ///
+    /// # Arguments
+    ///
+    /// * `unit` - Unit of measure to read (e.g. "USD", "shares", "USD/shares")
+    /// * `period_kind` - Which periods to keep (annual, quarterly, or instant)
+    ///
+    /// # Returns
+    ///
+    /// Pairs sorted by period end, oldest first. Empty if the unit is not reported.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let concept = edgar.company_concept(320193, "us-gaap", "Revenues").await?;
+    /// for (end, value) in concept.time_series("USD", PeriodKind::Annual) {
+    ///     println!("{end}: {value}");
+    /// }
+    /// ```
+    pub fn time_series(&self, unit: &str, period_kind: PeriodKind) -> Vec<(NaiveDate, FactValue)> {
+        let Some(points) = self.units.get(unit) else {
+            return Vec::new();
+        };
+
+        // Keyed by (start, end) so an annual and a quarterly value ending on the same date
+        // never collide, even though only one kind is kept per call.
+        let mut latest: HashMap<(Option<&str>, NaiveDate), &DataPoint> = HashMap::new();
+        for point in points {
+            let Some(end) = period_kind.matches(point) else {
+                continue;
+            };
+            latest
+                .entry((point.start.as_deref(), end))
+                .and_modify(|current| {
+                    if point.filed >= current.filed {
+                        *current = point;
+                    }
+                })
+                .or_insert(point);
+        }
+
+        let mut series: Vec<(NaiveDate, FactValue)> = latest
+            .into_iter()
+            .map(|((_, end), point)| (end, point.val.clone()))
+            .collect();
+        series.sort_by_key(|(end, _)| *end);
+        series
+    }
+}
+
 /// Aggregated data for a specific concept across all companies for a time period.
 ///
 /// Frames provide a "cross-sectional" view of XBRL data - instead of one company over
@@ -180,12 +584,249 @@ pub struct FrameDataPoint {
     #[serde(rename = "entityName")]
     pub entity_name: String,
     pub cik: u64,
-    pub val: u64,
+    pub val: FactValue,
     pub accn: String,
     pub loc: String,
     pub end: String,
 }
 
+/// Frames for a run of consecutive periods, keyed by period and CIK.
+///
+/// Built by [`CompanyOperations::frames_range`]. Periods are the frame identifiers
+/// (`CY2019Q1I`), so a company's values line up across the whole range.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FramePanel {
+    pub taxonomy: String,
+    pub tag: String,
+    pub unit: String,
+
+    /// Every period in the range, oldest first.
+    pub periods: Vec<String>,
+
+    /// Periods the SEC has no frame for, such as quarters not reported yet.
+    pub missing: Vec<String>,
+
+    /// Data points by period, then CIK.
+    pub data: BTreeMap<String, BTreeMap<u64, FrameDataPoint>>,
+}
+
+impl FramePanel {
+    pub fn get(&self, period: &str, cik: impl Into<Cik>) -> Option<&FrameDataPoint> {
+        self.data.get(period)?.get(&cik.into().as_u64())
+    }
+
+    /// One company's values, oldest period first. Periods it did not report are skipped.
+    pub fn series(&self, cik: impl Into<Cik>) -> Vec<(&str, &FrameDataPoint)> {
+        let cik = cik.into().as_u64();
+        self.periods
+            .iter()
+            .filter_map(|period| Some((period.as_str(), self.data.get(period)?.get(&cik)?)))
+            .collect()
+    }
+
+    /// Every company with a value in at least one period.
+    pub fn ciks(&self) -> BTreeSet<u64> {
+        self.data
+            .values()
+            .flat_map(|points| points.keys().copied())
+            .collect()
+    }
+
+    /// Total number of data points across all periods.
+    pub fn len(&self) -> usize {
+        self.data.values().map(BTreeMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Requests in flight while fetching a range of frames; the client's rate limiter still
+/// applies to each of them.
+const FRAMES_CONCURRENCY: usize = 4;
+
+/// Period of an XBRL frame: a calendar year, a calendar quarter, or a quarter end.
+///
+/// Renders the identifiers the frames API expects (`CY2023`, `CY2024Q1`, `CY2024Q1I`) and
+/// checks them before a request is made, so a malformed period fails with a clear error
+/// instead of a 404.
+///
+/// ```rust
+/// use edgarkit::{FramePeriod, Quarter};
+///
+/// let period = FramePeriod::quarterly(2024, Quarter::Q1).instantaneous();
+/// assert_eq!(period.to_string(), "CY2024Q1I");
+/// assert_eq!(FramePeriod::annual(2023).to_string(), "CY2023");
+/// assert_eq!("CY2024Q1I".parse::<FramePeriod>()?, period);
+/// # Ok::<(), edgarkit::EdgarError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FramePeriod {
+    year: i32,
+    quarter: Option<Quarter>,
+    instant: bool,
+}
+
+impl FramePeriod {
+    /// Facts with a duration of about one year, ending in calendar year `year`.
+    pub fn annual(year: i32) -> Self {
+        Self {
+            year,
+            quarter: None,
+            instant: false,
+        }
+    }
+
+    /// Facts with a duration of about three months, ending in the given calendar quarter.
+    pub fn quarterly(year: i32, quarter: Quarter) -> Self {
+        Self {
+            year,
+            quarter: Some(quarter),
+            instant: false,
+        }
+    }
+
+    /// Point-in-time facts (balance sheet values) at the end of the quarter.
+    ///
+    /// Only quarterly periods have an instantaneous form; [`FramePeriod::validate`] rejects
+    /// an instantaneous annual period.
+    pub fn instantaneous(mut self) -> Self {
+        self.instant = true;
+        self
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn quarter(&self) -> Option<Quarter> {
+        self.quarter
+    }
+
+    pub fn is_instantaneous(&self) -> bool {
+        self.instant
+    }
+
+    /// Checks that the period exists in the frames API.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidXBRLYear` for years before 2005 and
+    /// `EdgarError::InvalidFormat` for an instantaneous annual period.
+    pub fn validate(&self) -> Result<()> {
+        if self.year < 2005 {
+            return Err(EdgarError::InvalidXBRLYear);
+        }
+        if self.instant && self.quarter.is_none() {
+            return Err(EdgarError::InvalidFormat(format!(
+                "frame period CY{}I: annual periods have no instantaneous form, use the Q4 instant",
+                self.year
+            )));
+        }
+        Ok(())
+    }
+
+    /// The following period of the same kind.
+    fn next(self) -> Self {
+        let (year, quarter) = match self.quarter {
+            None => (self.year + 1, None),
+            Some(Quarter::Q1) => (self.year, Some(Quarter::Q2)),
+            Some(Quarter::Q2) => (self.year, Some(Quarter::Q3)),
+            Some(Quarter::Q3) => (self.year, Some(Quarter::Q4)),
+            Some(Quarter::Q4) => (self.year + 1, Some(Quarter::Q1)),
+        };
+        Self {
+            year,
+            quarter,
+            ..self
+        }
+    }
+
+    fn key(self) -> (i32, i32) {
+        (self.year, self.quarter.map_or(0, |q| q.as_i32()))
+    }
+}
+
+impl std::fmt::Display for FramePeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CY{}", self.year)?;
+        if let Some(quarter) = self.quarter {
+            write!(f, "Q{}", quarter.as_i32())?;
+        }
+        if self.instant {
+            f.write_str("I")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses `CY2023`, `CY2024Q1`, or `CY2024Q1I` and validates the result.
+impl FromStr for FramePeriod {
+    type Err = EdgarError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || {
+            EdgarError::InvalidFormat(format!(
+                "invalid frame period {:?}: expected CY####, CY####Q# or CY####Q#I",
+                s
+            ))
+        };
+        let rest = s.trim().strip_prefix("CY").ok_or_else(invalid)?;
+        let (rest, instant) = match rest.strip_suffix('I') {
+            Some(rest) => (rest, true),
+            None => (rest, false),
+        };
+        let (year, quarter) = match rest.split_once('Q') {
+            Some((year, quarter)) => (year, Some(quarter)),
+            None => (rest, None),
+        };
+        if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let quarter = match quarter {
+            None => None,
+            Some("1") => Some(Quarter::Q1),
+            Some("2") => Some(Quarter::Q2),
+            Some("3") => Some(Quarter::Q3),
+            Some("4") => Some(Quarter::Q4),
+            Some(_) => return Err(EdgarError::InvalidQuarter),
+        };
+        let period = Self {
+            year: year.parse()?,
+            quarter,
+            instant,
+        };
+        period.validate()?;
+        Ok(period)
+    }
+}
+
+/// Every frame period from `from` to `to`, inclusive. Both ends must be of the same kind.
+fn frame_periods(from: FramePeriod, to: FramePeriod) -> Result<Vec<FramePeriod>> {
+    from.validate()?;
+    to.validate()?;
+    if from.quarter.is_some() != to.quarter.is_some() || from.instant != to.instant {
+        return Err(EdgarError::InvalidFormat(format!(
+            "frame periods {} and {} are of different kinds",
+            from, to
+        )));
+    }
+    if from.key() > to.key() {
+        return Err(EdgarError::InvalidFormat(format!(
+            "frame period {} is after {}",
+            from, to
+        )));
+    }
+    let mut periods = vec![from];
+    let mut period = from;
+    while period != to {
+        period = period.next();
+        periods.push(period);
+    }
+    Ok(periods)
+}
+
 #[derive(Debug)]
 enum CompanyUrlType {
     CompanyTickers,
@@ -240,6 +881,78 @@ impl Edgar {
     }
 }
 
+/// Parsed `company_tickers.json`, shared by every clone of a client.
+///
+/// Entries older than the configured TTL are refetched on the next lookup; a zero TTL turns
+/// caching off. Concurrent misses may each download the file, which is harmless.
+#[derive(Debug)]
+pub(crate) struct TickerCache {
+    ttl: Duration,
+    entry: RwLock<Option<Arc<CachedTickers>>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct CachedTickers {
+    fetched_at: Instant,
+    pub(crate) tickers: Vec<CompanyTicker>,
+    /// Upper-cased ticker to CIK
+    pub(crate) ciks: HashMap<String, u64>,
+}
+
+impl CachedTickers {
+    pub(crate) fn new(tickers: Vec<CompanyTicker>) -> Self {
+        let mut ciks = HashMap::with_capacity(tickers.len());
+        for t in &tickers {
+            ciks.entry(t.ticker.to_uppercase()).or_insert(t.cik);
+        }
+        Self {
+            fetched_at: Instant::now(),
+            tickers,
+            ciks,
+        }
+    }
+}
+
+impl TickerCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entry: RwLock::new(None),
+        }
+    }
+
+    fn fresh(&self) -> Option<Arc<CachedTickers>> {
+        let entry = self.entry.read().unwrap_or_else(|e| e.into_inner());
+        entry
+            .as_ref()
+            .filter(|cached| cached.fetched_at.elapsed() < self.ttl)
+            .cloned()
+    }
+
+    fn store(&self, tickers: Vec<CompanyTicker>) -> Arc<CachedTickers> {
+        let cached = Arc::new(CachedTickers::new(tickers));
+        *self.entry.write().unwrap_or_else(|e| e.into_inner()) = Some(cached.clone());
+        cached
+    }
+}
+
+impl Edgar {
+    /// Returns the ticker map, downloading it when the cache is empty or stale.
+    pub(crate) async fn cached_tickers(&self) -> Result<Arc<CachedTickers>> {
+        match self.ticker_cache.fresh() {
+            Some(cached) => Ok(cached),
+            None => self.fetch_tickers().await,
+        }
+    }
+
+    async fn fetch_tickers(&self) -> Result<Arc<CachedTickers>> {
+        let url = self.build_company_url(CompanyUrlType::CompanyTickers, &[])?;
+        let response = self.get(&url).await?;
+        let map: HashMap<String, CompanyTicker> = serde_json::from_str(&response)?;
+        Ok(self.ticker_cache.store(map.into_values().collect()))
+    }
+}
+
 /// A trait for parsing JSON content into a collection of structured data.
 trait JsonParser {
     fn parse_json_array<T, F>(
@@ -436,7 +1149,8 @@ impl FieldExtractor {
 /// - Parse errors for malformed JSON responses
 /// - Not found errors for invalid tickers or CIKs
 /// - Invalid response errors for unexpected API responses
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl CompanyOperations for Edgar {
     /// Retrieves a list of company tickers from the SEC EDGAR database.
     ///
@@ -456,16 +1170,14 @@ impl CompanyOperations for Edgar {
     /// * `EdgarError::NotFound` - If the company_tickers.json file was not found.
     /// * `EdgarError::InvalidResponse` - If the response couldn't be parsed as expected.
     async fn company_tickers(&self) -> Result<Vec<CompanyTicker>> {
-        let url = self.build_company_url(CompanyUrlType::CompanyTickers, &[])?;
-        let response = self.get(&url).await?;
-        let map: HashMap<String, CompanyTicker> = serde_json::from_str(&response)?;
-        Ok(map.into_values().collect())
+        Ok(self.cached_tickers().await?.tickers.clone())
     }
 
     /// Retrieves the Central Index Key (CIK) for a given company ticker symbol.
     ///
-    /// This function searches for a company's CIK using its ticker symbol. It first fetches
-    /// all company tickers and then finds the matching ticker, returning its associated CIK.
+    /// This function searches for a company's CIK using its ticker symbol. The ticker map is
+    /// downloaded once and cached on the client (see `EdgarConfig::ticker_cache_ttl`), so
+    /// resolving many tickers in a batch costs a single request.
     ///
     /// # Arguments
     ///
@@ -479,15 +1191,45 @@ impl CompanyOperations for Edgar {
     /// # Errors
     ///
     /// Returns `EdgarError::TickerNotFound` if the provided ticker symbol is not found.
+    ///
+    /// Class shares, units, and warrants are matched through the client's
+    /// [`SymbolConventions`](crate::SymbolConventions), so `BRK.B` and `BRKB` find `BRK-B`, and a SPAC warrant that SEC
+    /// does not list resolves to its issuer.
     async fn company_cik(&self, ticker: &str) -> Result<u64> {
-        let tickers = self.company_tickers().await?;
+        let cached = self.cached_tickers().await?;
 
-        let company = tickers
+        self.symbols
+            .candidates(ticker)
             .iter()
-            .find(|t| t.ticker == ticker.to_uppercase())
-            .ok_or(EdgarError::TickerNotFound)?;
+            .find_map(|key| cached.ciks.get(key))
+            .copied()
+            .ok_or(EdgarError::TickerNotFound)
+    }
 
-        Ok(company.cik.clone())
+    /// Finds likely CIKs for a company name.
+    ///
+    /// Names are compared after case folding and stripping punctuation, state markers like
+    /// `/DE/`, and corporate suffixes, then by edit distance. Use [`NameMatcher`] directly
+    /// to tune the threshold or to rank an already downloaded ticker list.
+    ///
+    /// # Returns
+    ///
+    /// Up to ten candidates ordered by score; empty when nothing is close enough.
+    async fn company_cik_by_name(&self, name: &str) -> Result<Vec<CikCandidate>> {
+        let cached = self.cached_tickers().await?;
+        Ok(NameMatcher::default().rank(name, &cached.tickers))
+    }
+
+    /// Downloads the ticker file again, replacing any cached copy.
+    ///
+    /// Ticker lookups are served from a cache that expires after
+    /// `EdgarConfig::ticker_cache_ttl`; call this to pick up new listings sooner.
+    ///
+    /// # Returns
+    ///
+    /// The number of tickers now cached.
+    async fn refresh_tickers(&self) -> Result<usize> {
+        Ok(self.fetch_tickers().await?.tickers.len())
     }
 
     /// Retrieves the Central Index Key (CIK) for a given mutual fund ticker symbol.
@@ -515,7 +1257,7 @@ impl CompanyOperations for Edgar {
             .find(|t| t.symbol == ticker.to_uppercase())
             .ok_or(EdgarError::TickerNotFound)?;
 
-        Ok(fund.cik.clone())
+        Ok(fund.cik)
     }
 
     /// Retrieves a list of company tickers with their associated exchange information from the SEC EDGAR database.
@@ -545,7 +1287,10 @@ impl CompanyOperations for Edgar {
             &["cik", "name", "ticker", "exchange"],
             |extractor, row| {
                 Some(CompanyTickerExchange {
-                    cik: extractor.extract_value(row, "cik", |v| v.as_str()?.parse().ok())?,
+                    // The SEC serves CIKs as numbers here; accept strings too.
+                    cik: extractor.extract_value(row, "cik", |v| {
+                        v.as_u64().or_else(|| v.as_str()?.trim().parse().ok())
+                    })?,
                     name: extractor.extract_value(row, "name", |v| v.as_str().map(String::from))?,
                     ticker: extractor
                         .extract_value(row, "ticker", |v| v.as_str().map(String::from))?,
@@ -617,10 +1362,62 @@ impl CompanyOperations for Edgar {
     /// * There's a network issue while fetching the data
     /// * The SEC EDGAR API returns an unexpected response
     /// * The response cannot be parsed into the `CompanyFacts` structure
-    async fn company_facts(&self, cik: u64) -> Result<CompanyFacts> {
-        let url = self.build_company_url(CompanyUrlType::CompanyFacts, &[&cik.to_string()])?;
+    async fn company_facts(&self, cik: impl Into<EntityId> + Send) -> Result<CompanyFacts> {
+        Ok(self.company_facts_with_report(cik).await?.0)
+    }
+
+    /// Fetches company facts for several companies, at most `max_concurrency` at a time.
+    ///
+    /// Results are paired with their CIK and returned in the order of `ciks`, each with its
+    /// own error; companies that file no XBRL come back as `EdgarError::NotFound` while the
+    /// rest succeed. Every request still goes through the client's rate limiter.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let ciks = [320193u64, 789019, 1652044];
+    /// for (cik, facts) in edgar.company_facts_for_ciks(ciks, 4).await {
+    ///     if let Ok(facts) = facts {
+    ///         println!("{} {}", cik, facts.entity_name);
+    ///     }
+    /// }
+    /// ```
+    async fn company_facts_for_ciks<I>(
+        &self,
+        ciks: I,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<CompanyFacts>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        let ciks: Vec<Cik> = ciks.into_iter().map(Into::into).collect();
+        fan_out(ciks, max_concurrency, |cik| async move {
+            (cik, self.company_facts(cik).await)
+        })
+        .await
+    }
+
+    /// Fetches company facts along with what lenient parsing repaired in them.
+    ///
+    /// Parsing is strict unless lenient mode is enabled with
+    /// [`EdgarConfig::with_parse_mode`](crate::EdgarConfig::with_parse_mode). In lenient mode
+    /// facts with values that could not be repaired are dropped and listed in the report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails, the response is not valid JSON, or the payload
+    /// cannot be repaired (or does not match in strict mode).
+    async fn company_facts_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(CompanyFacts, ParseReport)> {
+        let url = self.build_company_url(
+            CompanyUrlType::CompanyFacts,
+            &[&self.resolve_entity(cik).await?.padded()],
+        )?;
         let response = self.get(&url).await?;
-        Ok(serde_json::from_str(&response)?)
+        self.parse_json(&url, &response)
     }
 
     /// Fetches and parses company-specific financial concepts for a given company identified by its Central Index Key (CIK).
@@ -635,10 +1432,15 @@ impl CompanyOperations for Edgar {
     ///
     /// * `Result<CompanyConcept>` - On success, returns a `CompanyConcept` struct containing the parsed financial concept data.
     ///   On failure, returns an `Err` containing an `EdgarError` describing what went wrong.
-    async fn company_concept(&self, cik: u64, taxonomy: &str, tag: &str) -> Result<CompanyConcept> {
+    async fn company_concept(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        taxonomy: &str,
+        tag: &str,
+    ) -> Result<CompanyConcept> {
         let url = self.build_company_url(
             CompanyUrlType::CompanyConcept,
-            &[&cik.to_string(), taxonomy, tag],
+            &[&self.resolve_entity(cik).await?.padded(), taxonomy, tag],
         )?;
         let response = self.get(&url).await?;
         Ok(serde_json::from_str(&response)?)
@@ -652,23 +1454,223 @@ impl CompanyOperations for Edgar {
     /// * `taxonomy` - A string representing the financial taxonomy, such as "us-gaap" or "ifrs".
     /// * `tag` - A string representing the specific financial concept within the taxonomy.
     /// * `unit` - A string representing the unit of measurement for the financial concept, such as "USD" or "EUR".
-    /// * `period` - A string representing the financial period, such as "CY2019Q1I" or "FY2020".
+    /// * `period` - The calendar period, e.g. `FramePeriod::quarterly(2019, Quarter::Q1).instantaneous()`
+    ///   for `CY2019Q1I`. Invalid periods are rejected before any request is made.
     ///
     /// # Returns
     ///
     /// * `Result<Frame>` - On success, returns a `Result` containing a `Frame` struct representing the parsed financial frames.
     ///   On failure, returns an `Err` containing an `EdgarError` describing what went wrong.
-    async fn frames(&self, taxonomy: &str, tag: &str, unit: &str, period: &str) -> Result<Frame> {
-        let url = self.build_company_url(CompanyUrlType::Frames, &[taxonomy, tag, unit, period])?;
+    async fn frames(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        period: FramePeriod,
+    ) -> Result<Frame> {
+        period.validate()?;
+        let period = period.to_string();
+        let url =
+            self.build_company_url(CompanyUrlType::Frames, &[taxonomy, tag, unit, &period])?;
         let response = self.get(&url).await?;
         Ok(serde_json::from_str(&response)?)
     }
+
+    /// Fetches frames for every period from `from_period` to `to_period` and combines them
+    /// into a panel keyed by period and CIK.
+    ///
+    /// Both periods must be of the same kind: annual, quarterly, or quarter-end instants.
+    /// Frames are fetched a few at a time through the
+    /// client's rate limiter. Periods the SEC has no frame for are listed in
+    /// `FramePanel::missing` instead of failing the whole range.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from [`FramePeriod::validate`] for an invalid period,
+    /// `EdgarError::InvalidFormat` for periods of different kinds or a range whose start is
+    /// after its end, and the first error other than `NotFound` from fetching a frame.
+    #[tracing::instrument(skip(self))]
+    async fn frames_range(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        from_period: FramePeriod,
+        to_period: FramePeriod,
+    ) -> Result<FramePanel> {
+        let periods = frame_periods(from_period, to_period)?;
+        let mut results = stream::iter(periods.clone())
+            .map(|period| async move {
+                let frame = self.frames(taxonomy, tag, unit, period).await;
+                (period.to_string(), frame)
+            })
+            .buffered(FRAMES_CONCURRENCY);
+
+        let mut panel = FramePanel {
+            taxonomy: taxonomy.to_string(),
+            tag: tag.to_string(),
+            unit: unit.to_string(),
+            periods: periods.iter().map(FramePeriod::to_string).collect(),
+            ..FramePanel::default()
+        };
+        while let Some((period, frame)) = results.next().await {
+            match frame {
+                Ok(frame) => {
+                    let points = frame
+                        .data_points
+                        .into_iter()
+                        .map(|point| (point.cik, point))
+                        .collect();
+                    panel.data.insert(period, points);
+                }
+                Err(EdgarError::NotFound(_)) => panel.missing.push(period),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(panel)
+    }
+
+    /// Lists companies that filed a 10-K, 20-F, or 40-F under `sic_code` in roughly the
+    /// last 18 months, ordered by name.
+    ///
+    /// Filers are found with the full-text search `sic` filter, so only companies that
+    /// file electronically and have filed an annual report recently are included. Names,
+    /// tickers, and exchanges come from the ticker mapping, falling back to the name shown
+    /// in search results for companies without a listed ticker.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if `sic_code` is not four digits.
+    #[cfg(feature = "search")]
+    async fn peers_by_sic(&self, sic_code: &str) -> Result<Vec<CompanyMetadata>> {
+        super::enrich::peers_by_sic(self, sic_code).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_flatten_company_facts() {
+        let facts: CompanyFacts = serde_json::from_str(
+            r#"{"cik": 320193, "entityName": "Apple Inc.", "facts": {
+                "us-gaap": {"Assets": {"units": {"USD": [
+                    {"end": "2023-09-30", "val": 352583000000, "accn": "0000320193-23-000106",
+                     "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"},
+                    {"end": "2022-09-24", "val": 352755000000, "accn": "0000320193-22-000108",
+                     "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2022-10-28"}
+                ]}}},
+                "dei": {"EntityCommonStockSharesOutstanding": {"units": {"shares": [
+                    {"end": "2023-10-20", "val": 15552752000, "accn": "0000320193-23-000106",
+                     "form": "10-K", "filed": "2023-11-03"}
+                ]}}},
+                "srt": {"ProductInformationLineItems": {"units": {"pure": [
+                    {"end": "2023-09-30", "val": 0.5, "accn": "0000320193-23-000106",
+                     "form": "10-K", "filed": "2023-11-03"}
+                ]}}}
+            }}"#,
+        )
+        .unwrap();
+
+        assert_eq!(facts.iter_facts().count(), 4);
+        assert_eq!(facts.taxonomies.names(), vec!["dei", "srt", "us-gaap"]);
+        assert!(
+            facts
+                .taxonomies
+                .fact("srt", "ProductInformationLineItems")
+                .is_some()
+        );
+        assert!(facts.taxonomies.ifrs_full().is_none());
+
+        let records = facts.to_records();
+        let keys: Vec<(&str, &str)> = records
+            .iter()
+            .map(|record| (record.taxonomy.as_str(), record.end.as_str()))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                ("dei", "2023-10-20"),
+                ("srt", "2023-09-30"),
+                ("us-gaap", "2022-09-24"),
+                ("us-gaap", "2023-09-30"),
+            ]
+        );
+        assert_eq!(records[1].val, FactValue::Decimal(0.5));
+
+        let json = serde_json::to_value(&records[3]).unwrap();
+        assert_eq!(json["tag"], "Assets");
+        assert_eq!(json["val"], 352583000000i64);
+    }
+
+    #[test]
+    fn test_frame_periods() {
+        let periods = frame_periods(
+            FramePeriod::quarterly(2019, Quarter::Q3).instantaneous(),
+            FramePeriod::quarterly(2020, Quarter::Q1).instantaneous(),
+        )
+        .unwrap();
+        let names: Vec<String> = periods.iter().map(FramePeriod::to_string).collect();
+        assert_eq!(names, vec!["CY2019Q3I", "CY2019Q4I", "CY2020Q1I"]);
+        assert_eq!(
+            frame_periods(FramePeriod::annual(2019), FramePeriod::annual(2021))
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(
+            frame_periods(
+                FramePeriod::quarterly(2019, Quarter::Q1),
+                FramePeriod::quarterly(2019, Quarter::Q2).instantaneous(),
+            )
+            .is_err()
+        );
+        assert!(frame_periods(FramePeriod::annual(2020), FramePeriod::annual(2019)).is_err());
+    }
+
+    #[test]
+    fn test_frame_period_validation() {
+        assert!(
+            FramePeriod::annual(2023)
+                .instantaneous()
+                .validate()
+                .is_err()
+        );
+        assert!(matches!(
+            FramePeriod::annual(2001).validate(),
+            Err(EdgarError::InvalidXBRLYear)
+        ));
+        assert!(matches!(
+            "CY2019Q5".parse::<FramePeriod>(),
+            Err(EdgarError::InvalidQuarter)
+        ));
+        assert!("FY2020".parse::<FramePeriod>().is_err());
+        assert_eq!(
+            "CY2019Q1".parse::<FramePeriod>().unwrap(),
+            FramePeriod::quarterly(2019, Quarter::Q1)
+        );
+    }
+
+    #[test]
+    fn test_ticker_cache_ttl() {
+        let ticker = CompanyTicker {
+            cik: 320193,
+            ticker: "aapl".to_string(),
+            title: "Apple Inc.".to_string(),
+        };
+
+        let cache = TickerCache::new(Duration::from_secs(60));
+        assert!(cache.fresh().is_none());
+        cache.store(vec![ticker.clone()]);
+        let cached = cache.fresh().expect("entry should be fresh");
+        assert_eq!(cached.ciks.get("AAPL"), Some(&320193));
+
+        let disabled = TickerCache::new(Duration::ZERO);
+        disabled.store(vec![ticker]);
+        assert!(disabled.fresh().is_none());
+    }
+
     #[test]
     fn test_parse_invalid_json() {
         let edgar = Edgar::new("test_agent").unwrap();
@@ -702,4 +1704,57 @@ mod tests {
         assert!(fact.description.is_none());
         assert!(!fact.units.is_empty());
     }
+
+    #[test]
+    fn test_fact_value_deserialization() {
+        let values: Vec<FactValue> =
+            serde_json::from_str(r#"[1512000000, -3.25, "0000320193", null]"#).unwrap();
+
+        assert_eq!(values[0], FactValue::Integer(1512000000));
+        assert_eq!(values[1].as_f64(), Some(-3.25));
+        assert_eq!(values[1].as_i64(), None);
+        assert_eq!(values[2].as_str(), Some("0000320193"));
+        assert_eq!(values[2].as_i64(), Some(320193));
+        assert!(!values[2].is_number());
+        assert_eq!(values[3], FactValue::Text(String::new()));
+
+        assert_eq!(
+            serde_json::to_string(&values[..3]).unwrap(),
+            r#"[1512000000,-3.25,"0000320193"]"#
+        );
+    }
+
+    #[test]
+    fn test_time_series_latest_filing_wins() {
+        let json = r#"{
+            "cik": 1, "taxonomy": "us-gaap", "tag": "Revenues", "label": null, "description": null,
+            "units": {"USD": [
+                {"start": "2022-01-01", "end": "2022-12-31", "val": 100, "accn": "a", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-01"},
+                {"start": "2022-10-01", "end": "2022-12-31", "val": 30, "accn": "a", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-01"},
+                {"start": "2021-01-01", "end": "2021-12-31", "val": 80, "accn": "b", "fy": 2021, "fp": "FY", "form": "10-K", "filed": "2022-02-01"},
+                {"start": "2021-01-01", "end": "2021-12-31", "val": 85, "accn": "a", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-01"},
+                {"end": "2022-12-31", "val": 5, "accn": "a", "fy": 2022, "fp": "FY", "form": "10-K", "filed": "2023-02-01"}
+            ]}
+        }"#;
+        let concept: CompanyConcept = serde_json::from_str(json).unwrap();
+        let date = |s| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+
+        let annual = concept.time_series("USD", PeriodKind::Annual);
+        assert_eq!(
+            annual,
+            vec![
+                (date("2021-12-31"), FactValue::Integer(85)),
+                (date("2022-12-31"), FactValue::Integer(100)),
+            ]
+        );
+
+        let quarterly = concept.time_series("USD", PeriodKind::Quarterly);
+        assert_eq!(
+            quarterly,
+            vec![(date("2022-12-31"), FactValue::Integer(30))]
+        );
+
+        assert_eq!(concept.time_series("USD", PeriodKind::Instant).len(), 1);
+        assert!(concept.time_series("EUR", PeriodKind::Annual).is_empty());
+    }
 }
@@ -0,0 +1,66 @@
+//! Deprecated string-based signatures, kept for migration.
+//!
+//! `frames` now takes a [`FramePeriod`](crate::FramePeriod) instead of a `CY2019Q1I`
+//! string. The trait here keeps the old signature compiling: import it in place of the
+//! crate-root trait of the same name and existing call sites keep working, with a
+//! deprecation warning pointing at the new API. Migrate one module at a time by switching
+//! the import back.
+//!
+//! ```ignore
+//! // Before: use edgarkit::CompanyOperations;
+//! use edgarkit::compat::CompanyOperations;
+//!
+//! let frame = edgar.frames("us-gaap", "Assets", "USD", "CY2019Q1I").await?; // warns, still works
+//! ```
+//!
+//! CIK arguments need no shim: filing, feed, and company methods take
+//! `impl Into<EntityId>`, which still accepts the `&str` CIKs (and tickers) they used to
+//! take. Importing a compat trait and its crate-root counterpart in the same scope makes
+//! the shared method names ambiguous. The shims will be kept for at least one minor release
+//! after the typed signatures.
+
+#[cfg(feature = "company")]
+use super::Edgar;
+#[cfg(feature = "company")]
+use super::company::{Frame, FramePeriod};
+#[cfg(feature = "company")]
+use super::error::Result;
+#[cfg(feature = "company")]
+use async_trait::async_trait;
+
+/// [`crate::CompanyOperations::frames`] with a period string such as `CY2019Q1I`.
+#[cfg(feature = "company")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait CompanyOperations {
+    /// The period is parsed with `FramePeriod::from_str`, so malformed periods fail before
+    /// any request is made.
+    #[deprecated(note = "use edgarkit::CompanyOperations::frames with a FramePeriod")]
+    async fn frames(&self, taxonomy: &str, tag: &str, unit: &str, period: &str) -> Result<Frame>;
+}
+
+#[cfg(feature = "company")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl CompanyOperations for Edgar {
+    async fn frames(&self, taxonomy: &str, tag: &str, unit: &str, period: &str) -> Result<Frame> {
+        let period: FramePeriod = period.parse()?;
+        crate::CompanyOperations::frames(self, taxonomy, tag, unit, period).await
+    }
+}
+
+#[cfg(all(test, feature = "company"))]
+#[allow(deprecated)]
+mod tests {
+    use super::*;
+    use crate::EdgarError;
+
+    #[tokio::test]
+    async fn test_string_signatures_delegate() {
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        assert!(matches!(
+            CompanyOperations::frames(&edgar, "us-gaap", "Assets", "USD", "CY2019Q5I").await,
+            Err(EdgarError::InvalidQuarter)
+        ));
+    }
+}
@@ -1,12 +1,29 @@
 //! Configuration types for customizing Edgar client behavior.
 //!
 //! The configuration system allows you to control rate limiting, HTTP timeouts,
-//! base URLs, and user agent strings. Most users can rely on the defaults provided
+//! retry behavior, redirects, proxies, TLS roots, error previews, base URLs, and user agent strings. Most users can rely on the
+//! defaults provided
 //! by `Edgar::new()`, but custom configurations are useful for testing, research
 //! applications with specific performance requirements, or compliance scenarios.
 
+use crate::metrics::Metrics;
+use crate::parsing::json::ParseMode;
+#[cfg(feature = "company")]
+use crate::symbols::SymbolConventions;
+use crate::transport::Transport;
+use std::fmt;
+#[cfg(feature = "mirror")]
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// `efts.sec.gov` starts answering with 429s and 500s well before the 10 req/s allowed on
+/// `www.sec.gov`.
+const DEFAULT_SEARCH_RATE_LIMIT: u32 = 5;
+
+/// The SEC regenerates `company_tickers.json` about once a day.
+const DEFAULT_TICKER_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
 /// Configuration settings for the Edgar HTTP client.
 ///
 /// This struct contains all the settings needed to customize how the Edgar client
@@ -41,11 +58,569 @@ pub struct EdgarConfig {
     /// Rate limit in requests per second (default: 10)
     pub rate_limit: u32,
 
+    /// Rate limit for the full-text search host, which has its own, stricter quota and does
+    /// not count against `rate_limit` (default: 5)
+    pub search_rate_limit: u32,
+
     /// HTTP request timeout duration
     pub timeout: Duration,
 
     /// Base URLs for different EDGAR services
     pub base_urls: EdgarUrls,
+
+    /// How failed requests are retried
+    pub retry: RetryPolicy,
+
+    /// How 403 rate-threshold pages and repeated throttles are handled
+    pub throttle: ThrottlePolicy,
+
+    /// Accept ticker symbols wherever filing APIs expect a CIK (default: true)
+    pub resolve_tickers: bool,
+
+    /// Record downloaded submissions in the client's corporate history (default: false)
+    pub corporate_history: bool,
+
+    /// How JSON payloads that do not match the expected shape are handled (default: strict)
+    pub parse_mode: ParseMode,
+
+    /// How response bodies are summarized in errors
+    pub error_preview: PreviewPolicy,
+
+    /// How long the parsed ticker map is reused before it is downloaded again
+    /// (default: 24 hours, zero disables caching)
+    pub ticker_cache_ttl: Duration,
+
+    /// Which HTTP redirects are followed
+    pub redirect: RedirectPolicy,
+
+    /// How ticker symbols are normalized before they are looked up
+    #[cfg(feature = "company")]
+    pub symbols: SymbolConventions,
+
+    /// Local mirror directory to read from instead of making HTTP requests
+    #[cfg(feature = "mirror")]
+    pub mirror: Option<PathBuf>,
+
+    /// Transport that answers requests instead of HTTP; takes precedence over `mirror`
+    pub transport: Option<Arc<dyn Transport>>,
+
+    /// Receives request, retry, and latency events for monitoring
+    pub metrics: Option<Arc<dyn Metrics>>,
+
+    /// HTTP(S) proxy every request is sent through; without one, the `HTTP_PROXY`,
+    /// `HTTPS_PROXY`, and `NO_PROXY` environment variables are honored
+    pub proxy: Option<ProxyConfig>,
+
+    /// Extra PEM-encoded root certificates to trust, such as a corporate TLS-inspection CA.
+    /// Each entry may hold several certificates.
+    pub root_certificates: Vec<Vec<u8>>,
+
+    /// Trust the bundled Mozilla root certificates (default: true). Disable to trust only
+    /// `root_certificates`.
+    pub built_in_roots: bool,
+
+    /// Ask for gzip- or deflate-compressed responses and decompress them transparently
+    /// (default: true). Index files and submissions JSON shrink by roughly 80% in transit.
+    pub compression: bool,
+
+    /// Prebuilt HTTP client to use instead of one built from this configuration
+    ///
+    /// The client keeps its own timeout, redirect, proxy, TLS, and compression settings, so
+    /// `timeout`, `redirect`, `proxy`, `root_certificates`, `built_in_roots`, and
+    /// `compression` are ignored. The user agent is still added to every request.
+    pub http_client: Option<reqwest::Client>,
+}
+
+/// Proxy settings for the HTTP client.
+///
+/// The proxy URL may use the `http`, `https`, or `socks5` scheme (the latter requires
+/// reqwest's `socks` feature). Credentials given with [`with_basic_auth`](Self::with_basic_auth)
+/// are sent as `Proxy-Authorization`; they can also be embedded in the URL. The password is
+/// never printed by the `Debug` implementation.
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::{EdgarConfig, ProxyConfig};
+/// let proxy = ProxyConfig::new("http://proxy.corp.example:3128")
+///     .with_basic_auth("svc-edgar", "hunter2")
+///     .with_no_proxy("localhost,.corp.example");
+/// let config = EdgarConfig::default().with_proxy(proxy);
+/// assert!(!format!("{:?}", config.proxy).contains("hunter2"));
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ProxyConfig {
+    /// Proxy URL, such as `http://proxy.example.com:8080`
+    pub url: String,
+
+    /// Username and password for proxy authentication
+    pub basic_auth: Option<(String, String)>,
+
+    /// Comma-separated hosts, domains, and IP ranges that bypass the proxy, in `NO_PROXY`
+    /// syntax
+    pub no_proxy: Option<String>,
+}
+
+impl fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field(
+                "basic_auth",
+                &self.basic_auth.as_ref().map(|(user, _)| (user, "..")),
+            )
+            .field("no_proxy", &self.no_proxy)
+            .finish()
+    }
+}
+
+impl ProxyConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            basic_auth: None,
+            no_proxy: None,
+        }
+    }
+
+    pub fn with_basic_auth(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.basic_auth = Some((username.into(), password.into()));
+        self
+    }
+
+    pub fn with_no_proxy(mut self, no_proxy: impl Into<String>) -> Self {
+        self.no_proxy = Some(no_proxy.into());
+        self
+    }
+}
+
+/// Rewrites a response body before it is embedded in an error.
+pub type Redactor = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Controls how much of an error response body ends up in `EdgarError` values.
+///
+/// Errors for unexpected statuses and content types include a short preview of the body.
+/// Those bodies can be HTML noise or pages from corporate proxies that should not end up in
+/// logs, so the preview is passed through an optional redactor and then capped at
+/// `max_chars`. Set `max_chars` to 0 to leave previews out entirely.
+///
+/// With `keep_body` enabled the complete, unredacted body is also attached to the error and
+/// can be read with [`EdgarError::response_body`](crate::EdgarError::response_body). It is
+/// never part of the error's `Display` output.
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::PreviewPolicy;
+/// let policy = PreviewPolicy::default()
+///     .with_max_chars(80)
+///     .with_redactor(|body| body.replace("internal-proxy", "[redacted]"));
+/// assert_eq!(policy.preview("via internal-proxy"), "via [redacted]");
+/// ```
+#[derive(Clone)]
+pub struct PreviewPolicy {
+    /// Maximum number of characters of the (redacted) body kept in the error message
+    pub max_chars: usize,
+
+    /// Attach the full response body to errors for debugging
+    pub keep_body: bool,
+
+    redactor: Option<Redactor>,
+}
+
+impl Default for PreviewPolicy {
+    fn default() -> Self {
+        Self {
+            max_chars: 200,
+            keep_body: false,
+            redactor: None,
+        }
+    }
+}
+
+impl fmt::Debug for PreviewPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreviewPolicy")
+            .field("max_chars", &self.max_chars)
+            .field("keep_body", &self.keep_body)
+            .field("redactor", &self.redactor.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+impl PreviewPolicy {
+    pub fn with_max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = max_chars;
+        self
+    }
+
+    pub fn with_keep_body(mut self, keep_body: bool) -> Self {
+        self.keep_body = keep_body;
+        self
+    }
+
+    /// Sets a function applied to the body before it is truncated into a preview.
+    pub fn with_redactor(
+        mut self,
+        redactor: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) -> Self {
+        self.redactor = Some(Arc::new(redactor));
+        self
+    }
+
+    /// Builds the preview embedded in error messages.
+    ///
+    /// Redaction runs on the whole body first, so a secret straddling the cut-off point is
+    /// still caught.
+    pub fn preview(&self, body: &str) -> String {
+        let redacted = match &self.redactor {
+            Some(redact) => redact(body),
+            None => body.to_string(),
+        };
+        redacted.chars().take(self.max_chars).collect()
+    }
+
+    /// Returns the body to attach to an error, if `keep_body` is enabled.
+    pub(crate) fn attached(&self, body: &str) -> Option<String> {
+        self.keep_body.then(|| body.to_string())
+    }
+}
+
+/// Statuses [`RetryPolicy::default`] retries: rate limiting and transient server errors.
+pub const DEFAULT_RETRY_STATUSES: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// Controls how the client retries rate-limited and failed requests.
+///
+/// Retries wait `base_backoff × 2^attempt`, capped at `max_backoff`, with random jitter of
+/// up to `jitter` (as a fraction) in either direction. A `Retry-After` header on a 429
+/// or 503 response takes precedence over the computed delay. Network errors are retried as well
+/// as the listed status codes.
+///
+/// The default retries 429 responses and the 5xx statuses sec.gov answers with when it is
+/// briefly overloaded (500, 502, 503, 504) up to 5 times starting at one second. 403 and 404
+/// are never retried, even if listed: a missing document stays missing, and 403 rate-threshold
+/// pages are handled by [`ThrottlePolicy`]. Use [`RetryPolicy::none`] for latency-sensitive
+/// callers that would rather fail fast.
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::RetryPolicy;
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::default()
+///     .with_max_retries(3)
+///     .with_max_backoff(Duration::from_secs(10))
+///     .with_retry_statuses(vec![429, 503]);
+/// assert!(policy.should_retry_status(503));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt (0 disables retries)
+    pub max_retries: u32,
+
+    /// Delay before the first retry
+    pub base_backoff: Duration,
+
+    /// Upper bound for any single delay
+    pub max_backoff: Duration,
+
+    /// Random jitter as a fraction of the delay, between 0.0 and 1.0
+    pub jitter: f64,
+
+    /// HTTP status codes that trigger a retry
+    pub retry_statuses: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            jitter: 0.2,
+            retry_statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn with_base_backoff(mut self, base_backoff: Duration) -> Self {
+        self.base_backoff = base_backoff;
+        self
+    }
+
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the jitter fraction, clamped to `0.0..=1.0`.
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_retry_statuses(mut self, statuses: Vec<u16>) -> Self {
+        self.retry_statuses = statuses;
+        self
+    }
+
+    /// Retries only 429 responses, failing fast on server errors.
+    pub fn rate_limits_only(self) -> Self {
+        self.with_retry_statuses(vec![429])
+    }
+
+    /// Returns true if a response with this status should be retried.
+    ///
+    /// Always false for 403 and 404.
+    pub fn should_retry_status(&self, status: u16) -> bool {
+        self.max_retries > 0
+            && !matches!(status, 403 | 404)
+            && self.retry_statuses.contains(&status)
+    }
+
+    /// Delay before retry number `attempt` (0-indexed), including jitter.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.checked_pow(attempt).unwrap_or(u32::MAX);
+        let backoff = self
+            .base_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        let jitter = self.jitter.clamp(0.0, 1.0) * (fastrand::f64() * 2.0 - 1.0);
+        backoff.mul_f64(1.0 + jitter)
+    }
+}
+
+/// Per-call overrides for timeouts and retries.
+///
+/// Apply them to a single request with [`Edgar::get_with_options`] or
+/// [`Edgar::get_bytes_with_options`], or to every call made through a derived client with
+/// [`Edgar::with_request_options`], which also covers the high-level `*Operations` methods.
+///
+/// `timeout` bounds each HTTP attempt, from sending the request to reading the last byte of
+/// the body, and replaces [`EdgarConfig::timeout`]. Retries get a fresh timeout. To bound a
+/// whole call including retries and rate-limit waits, wrap the future in
+/// `tokio::time::timeout`; dropping a request future cancels it cleanly.
+///
+/// [`Edgar::get_with_options`]: crate::Edgar::get_with_options
+/// [`Edgar::get_bytes_with_options`]: crate::Edgar::get_bytes_with_options
+/// [`Edgar::with_request_options`]: crate::Edgar::with_request_options
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::{RequestOptions, RetryPolicy};
+/// # use std::time::Duration;
+/// let options = RequestOptions::new()
+///     .with_timeout(Duration::from_secs(5))
+///     .with_retry_policy(RetryPolicy::none());
+/// assert_eq!(options.timeout, Some(Duration::from_secs(5)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RequestOptions {
+    /// Timeout for each HTTP attempt; `None` keeps the client's timeout
+    pub timeout: Option<Duration>,
+
+    /// Retry policy for the call; `None` keeps the client's policy
+    pub retry: Option<RetryPolicy>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = Some(retry);
+        self
+    }
+}
+
+/// Controls how the client reacts when SEC.gov throttles it.
+///
+/// Besides 429 responses, SEC.gov soft-blocks clients that exceed its fair-access limit with
+/// a 403 "Request Rate Threshold Exceeded" page, and keeps answering that way for about ten
+/// minutes. When the client sees that page it pauses every request (including those of its
+/// clones) for `cooldown` and then retries, within the limits of the [`RetryPolicy`]; a
+/// request that runs out of retries fails with `EdgarError::RateLimitExceeded`.
+///
+/// With `adaptive` enabled, `threshold` throttles (429s or 403 blocks) within `window` halve
+/// the request rate, down to `min_rate`, for `recovery`. Further throttles while slowed down
+/// halve it again. Disabled by default.
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::ThrottlePolicy;
+/// # use std::time::Duration;
+/// let policy = ThrottlePolicy::adaptive()
+///     .with_cooldown(Duration::from_secs(600))
+///     .with_threshold(2);
+/// assert!(policy.adaptive);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThrottlePolicy {
+    /// How long all requests pause after a 403 rate-threshold page (default: 10 minutes)
+    pub cooldown: Duration,
+
+    /// Whether repeated throttles reduce the request rate
+    pub adaptive: bool,
+
+    /// Throttles within `window` that trigger a rate reduction (default: 3)
+    pub threshold: u32,
+
+    /// Period over which throttles are counted (default: 1 minute)
+    pub window: Duration,
+
+    /// How long a reduced rate stays in effect (default: 10 minutes)
+    pub recovery: Duration,
+
+    /// Lowest rate adaptive mode reduces to, in requests per second (default: 1)
+    pub min_rate: u32,
+}
+
+impl Default for ThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            cooldown: Duration::from_secs(600),
+            adaptive: false,
+            threshold: 3,
+            window: Duration::from_secs(60),
+            recovery: Duration::from_secs(600),
+            min_rate: 1,
+        }
+    }
+}
+
+impl ThrottlePolicy {
+    /// The default policy with adaptive rate reduction enabled.
+    pub fn adaptive() -> Self {
+        Self {
+            adaptive: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    pub fn with_adaptive(mut self, adaptive: bool) -> Self {
+        self.adaptive = adaptive;
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: u32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    pub fn with_recovery(mut self, recovery: Duration) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    /// Sets the lowest adaptive rate; zero is treated as one.
+    pub fn with_min_rate(mut self, min_rate: u32) -> Self {
+        self.min_rate = min_rate.max(1);
+        self
+    }
+}
+
+/// Controls which HTTP redirects the client follows.
+///
+/// EDGAR occasionally redirects between `www.sec.gov` and `efts.sec.gov`, and misconfigured
+/// proxies or outages can bounce requests to unrelated error pages. Redirects are followed
+/// only to hosts on the allow-list (the SEC hosts by default, plus the hosts of the configured
+/// base URLs) and at most `max_redirects` times. A redirect to any other host is not followed
+/// and surfaces as `EdgarError::RedirectBlocked` with both URLs.
+///
+/// # Example
+///
+/// ```rust
+/// # use edgarkit::RedirectPolicy;
+/// let policy = RedirectPolicy::default()
+///     .with_max_redirects(3)
+///     .with_allowed_host("mirror.example.com");
+/// assert!(policy.allows("efts.sec.gov"));
+/// assert!(policy.allows("MIRROR.example.com"));
+/// assert!(!policy.allows("example.com"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RedirectPolicy {
+    /// Maximum number of redirects followed for one request (0 follows none)
+    pub max_redirects: usize,
+
+    /// Hosts redirects may lead to, compared case-insensitively
+    pub allowed_hosts: Vec<String>,
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self {
+            max_redirects: 10,
+            allowed_hosts: ["www.sec.gov", "sec.gov", "efts.sec.gov", "data.sec.gov"]
+                .iter()
+                .map(|host| host.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl RedirectPolicy {
+    /// A policy that never follows redirects.
+    pub fn none() -> Self {
+        Self {
+            max_redirects: 0,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_redirects(mut self, max_redirects: usize) -> Self {
+        self.max_redirects = max_redirects;
+        self
+    }
+
+    /// Adds a host to the allow-list.
+    pub fn with_allowed_host(mut self, host: impl Into<String>) -> Self {
+        self.allowed_hosts.push(host.into());
+        self
+    }
+
+    /// Returns true if redirects to `host` may be followed.
+    pub fn allows(&self, host: &str) -> bool {
+        self.allowed_hosts
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(host))
+    }
 }
 
 /// Base URLs for the different SEC EDGAR service endpoints.
@@ -66,7 +641,8 @@ pub struct EdgarUrls {
     /// Files base URL (company tickers, etc.)
     pub files: String,
 
-    /// Search API base URL
+    /// Full-text search base URL; requests to it are limited by
+    /// [`EdgarConfig::search_rate_limit`] instead of `rate_limit`
     pub search: String,
 }
 
@@ -75,7 +651,27 @@ impl Default for EdgarConfig {
         Self {
             user_agent: "edgarkit/0.1.0".to_string(),
             rate_limit: 10,
+            search_rate_limit: DEFAULT_SEARCH_RATE_LIMIT,
             timeout: Duration::from_secs(30),
+            retry: RetryPolicy::default(),
+            throttle: ThrottlePolicy::default(),
+            resolve_tickers: true,
+            corporate_history: false,
+            parse_mode: ParseMode::default(),
+            error_preview: PreviewPolicy::default(),
+            ticker_cache_ttl: DEFAULT_TICKER_CACHE_TTL,
+            redirect: RedirectPolicy::default(),
+            #[cfg(feature = "company")]
+            symbols: SymbolConventions::default(),
+            #[cfg(feature = "mirror")]
+            mirror: None,
+            transport: None,
+            metrics: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            built_in_roots: true,
+            compression: true,
+            http_client: None,
             base_urls: EdgarUrls {
                 archives: "https://www.sec.gov/Archives/edgar".to_string(),
                 data: "https://data.sec.gov".to_string(),
@@ -111,10 +707,161 @@ impl EdgarConfig {
         Self {
             user_agent: user_agent.into(),
             rate_limit,
+            search_rate_limit: DEFAULT_SEARCH_RATE_LIMIT,
             timeout,
             base_urls: base_urls.unwrap_or_default(),
+            retry: RetryPolicy::default(),
+            throttle: ThrottlePolicy::default(),
+            resolve_tickers: true,
+            corporate_history: false,
+            parse_mode: ParseMode::default(),
+            error_preview: PreviewPolicy::default(),
+            ticker_cache_ttl: DEFAULT_TICKER_CACHE_TTL,
+            redirect: RedirectPolicy::default(),
+            #[cfg(feature = "company")]
+            symbols: SymbolConventions::default(),
+            #[cfg(feature = "mirror")]
+            mirror: None,
+            transport: None,
+            metrics: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            built_in_roots: true,
+            compression: true,
+            http_client: None,
         }
     }
+
+    /// Sets the requests per second allowed against the full-text search host.
+    pub fn with_search_rate_limit(mut self, search_rate_limit: u32) -> Self {
+        self.search_rate_limit = search_rate_limit;
+        self
+    }
+
+    /// Replaces the retry policy.
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Replaces the throttle policy.
+    pub fn with_throttle_policy(mut self, throttle: ThrottlePolicy) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    /// Enables or disables ticker resolution in filing APIs.
+    ///
+    /// When disabled, passing anything other than a numeric CIK to those APIs fails with
+    /// `EdgarError::InvalidFormat` instead of triggering a ticker lookup.
+    pub fn with_ticker_resolution(mut self, enabled: bool) -> Self {
+        self.resolve_tickers = enabled;
+        self
+    }
+
+    /// Enables or disables recording submissions in the client's corporate history.
+    ///
+    /// When enabled, every submissions payload the client downloads is compared with the
+    /// last one seen for that company, so `Edgar::corporate_events` can report ticker and
+    /// exchange changes. The history keeps a snapshot per company and is never trimmed, so
+    /// leave this off for clients that walk many companies and record the companies you
+    /// track with `CorporateHistory::record` instead.
+    pub fn with_corporate_history(mut self, enabled: bool) -> Self {
+        self.corporate_history = enabled;
+        self
+    }
+
+    /// Sets how submissions and company facts payloads that do not match the expected shape
+    /// are handled.
+    ///
+    /// [`ParseMode::Strict`], the default, fails on the first mismatch. In
+    /// [`ParseMode::Lenient`] mismatched values are converted, replaced, or dropped and
+    /// logged; the `*_with_report` methods return what was changed.
+    pub fn with_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
+    /// Replaces the error preview policy.
+    pub fn with_error_preview(mut self, error_preview: PreviewPolicy) -> Self {
+        self.error_preview = error_preview;
+        self
+    }
+
+    /// Replaces the redirect policy.
+    pub fn with_redirect_policy(mut self, redirect: RedirectPolicy) -> Self {
+        self.redirect = redirect;
+        self
+    }
+
+    /// Serves every request from a local mirror of sec.gov rooted at `root`.
+    ///
+    /// See the `mirror` module docs for the expected layout.
+    #[cfg(feature = "mirror")]
+    pub fn with_mirror(mut self, root: impl Into<PathBuf>) -> Self {
+        self.mirror = Some(root.into());
+        self
+    }
+
+    /// Answers every request with `transport` instead of HTTP.
+    ///
+    /// See the `transport` module docs for how its responses are handled.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Reports request events to `metrics`; see the `metrics` module docs.
+    pub fn with_metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Sends every request through `proxy`.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Trusts the PEM-encoded root certificate(s) in `pem` in addition to the built-in roots.
+    pub fn with_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    /// Enables or disables the bundled root certificates.
+    pub fn with_built_in_roots(mut self, enabled: bool) -> Self {
+        self.built_in_roots = enabled;
+        self
+    }
+
+    /// Enables or disables response compression.
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Uses `client` for HTTP instead of building one; see [`EdgarConfig::http_client`] for
+    /// which settings it replaces.
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Replaces the ticker symbol conventions used by `company_cik` and [`Bootstrap`].
+    ///
+    /// [`Bootstrap`]: crate::Bootstrap
+    #[cfg(feature = "company")]
+    pub fn with_symbol_conventions(mut self, symbols: SymbolConventions) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets how long the ticker map is cached. `Duration::ZERO` fetches it on every lookup.
+    pub fn with_ticker_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.ticker_cache_ttl = ttl;
+        self
+    }
 }
 
 impl Default for EdgarUrls {
@@ -127,3 +874,65 @@ impl Default for EdgarUrls {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let policy = RetryPolicy::default()
+            .with_jitter(0.0)
+            .with_max_backoff(Duration::from_secs(5));
+        assert_eq!(policy.backoff(0), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff(3), Duration::from_secs(5));
+        assert_eq!(policy.backoff(40), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_preview_redacts_before_truncating() {
+        let policy = PreviewPolicy::default()
+            .with_max_chars(10)
+            .with_redactor(|body| body.replace("token=abc123", "token=***"));
+        assert_eq!(policy.preview("Xtoken=abc123 tail"), "Xtoken=***");
+        assert_eq!(policy.attached("body"), None);
+        assert_eq!(
+            policy.with_keep_body(true).attached("body").as_deref(),
+            Some("body")
+        );
+    }
+
+    #[test]
+    fn test_proxy_debug_hides_password() {
+        let proxy = ProxyConfig::new("http://proxy:3128").with_basic_auth("user", "s3cret");
+        let debug = format!("{:?}", proxy);
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("s3cret"));
+    }
+
+    #[test]
+    fn test_none_policy_never_retries() {
+        let policy = RetryPolicy::none();
+        assert!(!policy.should_retry_status(429));
+        assert!(RetryPolicy::default().should_retry_status(429));
+    }
+
+    #[test]
+    fn test_default_policy_retries_server_errors() {
+        let policy = RetryPolicy::default();
+        for status in [429, 500, 502, 503, 504] {
+            assert!(policy.should_retry_status(status));
+        }
+        assert!(!policy.should_retry_status(501));
+
+        let listed = RetryPolicy::default().with_retry_statuses(vec![403, 404, 503]);
+        assert!(!listed.should_retry_status(403));
+        assert!(!listed.should_retry_status(404));
+        assert!(listed.should_retry_status(503));
+
+        let rate_limits = RetryPolicy::default().rate_limits_only();
+        assert!(rate_limits.should_retry_status(429));
+        assert!(!rate_limits.should_retry_status(500));
+    }
+}
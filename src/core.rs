@@ -1,20 +1,38 @@
-use governor::{
-    Quota, RateLimiter, clock::DefaultClock, middleware::NoOpMiddleware, state::InMemoryState,
-    state::NotKeyed,
-};
+use futures_util::stream::{self, StreamExt};
+use governor::{Quota, RateLimiter};
 use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use std::future::Future;
 use std::num::NonZeroU32;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::time::sleep;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::AsyncWriteExt;
+use tracing::{Instrument, Span, field};
 
-use super::config::{EdgarConfig, EdgarUrls};
-use super::error::{EdgarError, Result};
-
-const MAX_RETRIES: u32 = 5;
-const INITIAL_BACKOFF_MS: u64 = 1000; // 1 second
-
-type Governor = RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+#[cfg(feature = "company")]
+use super::company::TickerCache;
+#[cfg(not(target_arch = "wasm32"))]
+use super::config::RedirectPolicy;
+use super::config::{EdgarConfig, EdgarUrls, PreviewPolicy, RequestOptions, RetryPolicy};
+use super::error::{EdgarError, ErrorContext, Result};
+#[cfg(feature = "filings")]
+use super::events::CorporateHistory;
+use super::inflight::InFlight;
+use super::limiter::Governor;
+#[cfg(feature = "process-limiter")]
+use super::limiter::ProcessLimiter;
+use super::metrics::{Metrics, endpoint_of};
+#[cfg(feature = "mirror")]
+use super::mirror::Mirror;
+#[cfg(any(feature = "filings", feature = "company"))]
+use super::parsing::json::{ParseMode, ParseReport, from_str_with_report};
+use super::rt::{Instant, sleep};
+#[cfg(feature = "company")]
+use super::symbols::SymbolConventions;
+use super::throttle::{ThrottleState, is_rate_threshold_page};
+use super::transport::Transport;
 
 #[derive(Debug, Clone)]
 pub struct Edgar {
@@ -24,6 +42,14 @@ pub struct Edgar {
     /// Token bucket rate limiter for SEC compliance
     pub(crate) rate_limiter: Arc<Governor>,
 
+    /// Limiter shared with other processes, consulted after the token bucket
+    #[cfg(feature = "process-limiter")]
+    pub(crate) process_limiter: Option<Arc<ProcessLimiter>>,
+
+    /// Separate token bucket for the full-text search host
+    #[cfg(feature = "search")]
+    pub(crate) search_rate_limiter: Arc<Governor>,
+
     /// Base URL for EDGAR archives
     pub(crate) edgar_archives_url: String,
 
@@ -35,6 +61,62 @@ pub struct Edgar {
 
     /// Base URL for EDGAR search endpoint
     pub(crate) edgar_search_url: String,
+
+    /// Retry behavior for failed requests
+    pub(crate) retry_policy: RetryPolicy,
+
+    /// User agent added to each request when the HTTP client was supplied by the caller
+    user_agent: Option<HeaderValue>,
+
+    /// Per-attempt timeout overriding the HTTP client's, set through [`RequestOptions`]
+    pub(crate) request_timeout: Option<Duration>,
+
+    /// Pause and adaptive slowdown after throttling, shared by clones of this client
+    pub(crate) throttle_state: Arc<ThrottleState>,
+
+    /// Whether filing APIs resolve ticker symbols to CIKs
+    #[cfg(feature = "company")]
+    pub(crate) resolve_tickers: bool,
+
+    /// How JSON payloads that do not match the expected shape are handled
+    #[cfg(any(feature = "filings", feature = "company"))]
+    pub(crate) parse_mode: ParseMode,
+
+    /// How response bodies are summarized in errors
+    pub(crate) error_preview: PreviewPolicy,
+
+    /// Parsed ticker map shared by clones of this client
+    #[cfg(feature = "company")]
+    pub(crate) ticker_cache: Arc<TickerCache>,
+
+    /// How ticker symbols are normalized before lookup
+    #[cfg(feature = "company")]
+    pub(crate) symbols: Arc<SymbolConventions>,
+
+    /// Whether downloaded submissions are recorded in `history`
+    #[cfg(feature = "filings")]
+    pub(crate) record_history: bool,
+
+    /// Corporate events seen in downloaded submissions, shared by clones of this client
+    #[cfg(feature = "filings")]
+    pub(crate) history: Arc<std::sync::Mutex<CorporateHistory>>,
+
+    /// Local mirror that replaces HTTP when configured
+    #[cfg(feature = "mirror")]
+    pub(crate) mirror: Option<Arc<Mirror>>,
+
+    /// Transport that replaces HTTP when configured
+    pub(crate) transport: Option<Arc<dyn Transport>>,
+
+    /// Receives request, retry, and latency events
+    pub(crate) metrics: Option<Arc<dyn Metrics>>,
+
+    /// Text requests in progress, shared by clones of this client so identical concurrent
+    /// GETs made with the same options make one request
+    in_flight: Arc<InFlight<(String, String)>>,
+
+    /// Binary requests in progress, shared like `in_flight`
+    in_flight_bytes: Arc<InFlight<Vec<u8>>>,
 }
 
 /// HTTP client for accessing the SEC EDGAR API with built-in rate limiting and retry logic.
@@ -83,13 +165,15 @@ pub struct Edgar {
 /// With custom configuration:
 ///
 /// ```rust
-/// # use edgarkit::{Edgar, EdgarConfig, EdgarUrls};
+/// # use edgarkit::{Edgar, EdgarConfig, EdgarUrls, RetryPolicy};
 /// # use std::time::Duration;
 /// let config = EdgarConfig {
 ///     user_agent: "custom_app/2.0".to_string(),
 ///     rate_limit: 5,
 ///     timeout: Duration::from_secs(60),
 ///     base_urls: EdgarUrls::default(),
+///     retry: RetryPolicy::default(),
+///     ..EdgarConfig::default()
 /// };
 /// let edgar = Edgar::with_config(config)?;
 /// # Ok::<(), edgarkit::EdgarError>(())
@@ -125,6 +209,7 @@ impl Edgar {
             rate_limit: 10,
             timeout: Duration::from_secs(30),
             base_urls: EdgarUrls::default(),
+            ..EdgarConfig::default()
         };
         Self::with_config(config)
     }
@@ -138,8 +223,8 @@ impl Edgar {
     /// # Arguments
     ///
     /// * `config` - An `EdgarConfig` struct containing your custom settings including user
-    ///   agent, rate limit (requests per second), HTTP timeout, and base URLs for the
-    ///   various EDGAR services.
+    ///   agent, rate limit (requests per second), HTTP timeout, retry policy, and base URLs
+    ///   for the various EDGAR services.
     ///
     /// # Errors
     ///
@@ -149,7 +234,7 @@ impl Edgar {
     /// # Example
     ///
     /// ```ignore
-    /// use edgarkit::{Edgar, EdgarConfig, EdgarUrls};
+    /// use edgarkit::{Edgar, EdgarConfig, EdgarUrls, RetryPolicy};
     /// use std::time::Duration;
     ///
     /// let config = EdgarConfig {
@@ -157,6 +242,8 @@ impl Edgar {
     ///     rate_limit: 5,  // More conservative rate
     ///     timeout: Duration::from_secs(60),
     ///     base_urls: EdgarUrls::default(),
+    ///     retry: RetryPolicy::default(),
+    ///     ..EdgarConfig::default()
     /// };
     /// let edgar = Edgar::with_config(config)?;
     /// ```
@@ -168,11 +255,10 @@ impl Edgar {
                 .map_err(|e| EdgarError::ConfigError(format!("Invalid user agent: {}", e)))?,
         );
 
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .timeout(config.timeout)
-            .build()
-            .map_err(|e| EdgarError::ConfigError(format!("Failed to build HTTP client: {}", e)))?;
+        let (client, user_agent) = match config.http_client.clone() {
+            Some(client) => (client, headers.remove(USER_AGENT)),
+            None => (build_client(&config, headers)?, None),
+        };
 
         let rate_limiter = Arc::new(RateLimiter::direct(Quota::per_second(
             NonZeroU32::new(config.rate_limit).ok_or_else(|| {
@@ -180,24 +266,248 @@ impl Edgar {
             })?,
         )));
 
+        #[cfg(feature = "search")]
+        let search_rate_limiter = Arc::new(RateLimiter::direct(Quota::per_second(
+            NonZeroU32::new(config.search_rate_limit).ok_or_else(|| {
+                EdgarError::ConfigError("Search rate limit must be greater than zero".to_string())
+            })?,
+        )));
+
         Ok(Edgar {
             client,
             rate_limiter,
+            #[cfg(feature = "process-limiter")]
+            process_limiter: None,
+            #[cfg(feature = "search")]
+            search_rate_limiter,
             edgar_archives_url: config.base_urls.archives,
             edgar_data_url: config.base_urls.data,
             edgar_files_url: config.base_urls.files,
             edgar_search_url: config.base_urls.search,
+            retry_policy: config.retry,
+            user_agent,
+            request_timeout: None,
+            throttle_state: Arc::new(ThrottleState::new(config.throttle, config.rate_limit)),
+            #[cfg(feature = "company")]
+            resolve_tickers: config.resolve_tickers,
+            #[cfg(any(feature = "filings", feature = "company"))]
+            parse_mode: config.parse_mode,
+            error_preview: config.error_preview,
+            #[cfg(feature = "company")]
+            ticker_cache: Arc::new(TickerCache::new(config.ticker_cache_ttl)),
+            #[cfg(feature = "company")]
+            symbols: Arc::new(config.symbols),
+            #[cfg(feature = "filings")]
+            record_history: config.corporate_history,
+            #[cfg(feature = "filings")]
+            history: Arc::default(),
+            #[cfg(feature = "mirror")]
+            mirror: config.mirror.map(|root| Arc::new(Mirror::new(root))),
+            transport: config.transport,
+            metrics: config.metrics,
+            in_flight: Arc::default(),
+            in_flight_bytes: Arc::default(),
         })
     }
 
-    /// Calculates the wait duration for retry attempts using exponential backoff with jitter.
+    /// Creates a client that answers every request with `transport` instead of HTTP.
+    ///
+    /// Meant for tests: pair it with [`MockTransport`](crate::test_util::MockTransport) from
+    /// the `test-util` feature to exercise code that takes an `Edgar` offline. Keep a clone
+    /// of the `Arc` to inspect the transport afterwards.
+    ///
+    /// ```ignore
+    /// use edgarkit::Edgar;
+    /// use edgarkit::test_util::MockTransport;
+    /// use std::sync::Arc;
+    ///
+    /// let transport = Arc::new(MockTransport::new().with_response(
+    ///     "https://www.sec.gov/files/company_tickers.json",
+    ///     r#"{"0":{"cik_str":320193,"ticker":"AAPL","title":"Apple Inc."}}"#,
+    /// ));
+    /// let edgar = Edgar::with_transport(transport.clone())?;
+    /// ```
+    pub fn with_transport(transport: Arc<dyn Transport>) -> Result<Self> {
+        Self::with_config(EdgarConfig::default().with_transport(transport))
+    }
+
+    /// Returns a client that uses `policy` for retries but shares this client's HTTP
+    /// connection pool and rate limiters.
+    ///
+    /// Useful for latency-sensitive paths that should fail fast instead of backing off:
+    ///
+    /// ```rust
+    /// # use edgarkit::{Edgar, RetryPolicy};
+    /// let edgar = Edgar::new("my_app/1.0 (my@email.com)")?;
+    /// let fail_fast = edgar.with_retry_policy(RetryPolicy::none());
+    /// assert_eq!(fail_fast.retry_policy().max_retries, 0);
+    /// # Ok::<(), edgarkit::EdgarError>(())
+    /// ```
+    pub fn with_retry_policy(&self, policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: policy,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a client that draws from `limiter` instead of its own token bucket.
     ///
-    /// This implements a standard exponential backoff strategy where each retry waits longer
-    /// than the previous attempt: 1s, 2s, 4s, 8s, 16s. Random jitter (±20%) is added to
-    /// prevent the "thundering herd" problem where many clients retry simultaneously and
-    /// overwhelm the server again.
+    /// Pass the same limiter (see [`shared_limiter`](crate::shared_limiter)) to every client
+    /// in the process so that together they stay within SEC.gov's per-IP limit. Full-text
+    /// search keeps its separate limiter.
+    pub fn with_shared_limiter(&self, limiter: Arc<Governor>) -> Self {
+        Self {
+            rate_limiter: limiter,
+            ..self.clone()
+        }
+    }
+
+    /// Returns this client's token bucket, for sharing with clients built elsewhere.
+    pub fn shared_limiter(&self) -> Arc<Governor> {
+        self.rate_limiter.clone()
+    }
+
+    /// Returns a client that also waits on `limiter` before every request, coordinating
+    /// with other processes that use the same lock file.
+    #[cfg(feature = "process-limiter")]
+    pub fn with_process_limiter(&self, limiter: ProcessLimiter) -> Self {
+        Self {
+            process_limiter: Some(Arc::new(limiter)),
+            ..self.clone()
+        }
+    }
+
+    /// Waits out throttling pauses, then on `limiter`, then on the process limiter if one is
+    /// configured.
+    pub(crate) async fn throttle(&self, limiter: &Governor) -> Result<()> {
+        self.throttle_state.wait().await;
+        limiter.until_ready().await;
+        #[cfg(feature = "process-limiter")]
+        if let Some(process_limiter) = &self.process_limiter {
+            process_limiter.until_ready().await?;
+        }
+        Ok(())
+    }
+
+    /// Reports an event to the configured [`Metrics`], if any.
+    pub(crate) fn observe(&self, event: impl FnOnce(&dyn Metrics)) {
+        if let Some(metrics) = &self.metrics {
+            event(metrics.as_ref());
+        }
+    }
+
+    /// Reports a received response, counting it as rate limited on 429.
+    ///
+    /// The status is also recorded on the current request span, and 429s count towards
+    /// adaptive rate reduction.
+    pub(crate) fn observe_response(&self, endpoint: &'static str, status: u16, sent: Instant) {
+        Span::current().record("status", status);
+        if status == 429 {
+            self.throttle_state.record(None);
+        }
+        self.observe(|m| {
+            m.response_received(endpoint, status, sent.elapsed());
+            if status == 429 {
+                m.rate_limited(endpoint);
+            }
+        });
+    }
+
+    /// Reports that attempt `retries` failed and will be retried.
+    fn observe_retry(&self, endpoint: &'static str, status: Option<u16>, retries: u32) {
+        Span::current().record("retries", retries + 1);
+        self.observe(|m| m.retry_scheduled(endpoint, status));
+    }
+
+    /// Handles SEC.gov's 403 rate-threshold page.
+    ///
+    /// Pauses this client and its clones for the [`ThrottlePolicy`](crate::ThrottlePolicy)
+    /// cooldown and returns whether attempt `retries` should be retried.
+    fn rate_blocked(&self, endpoint: &'static str, retries: u32) -> bool {
+        self.throttle_state
+            .record(Some(self.throttle_state.cooldown()));
+        self.observe(|m| m.rate_limited(endpoint));
+        let retry = retries < self.retry_policy.max_retries;
+        if retry {
+            self.observe_retry(endpoint, Some(403), retries);
+        }
+        retry
+    }
+
+    /// The request rate currently in effect, in requests per second.
+    ///
+    /// This is the configured `rate_limit` unless adaptive throttling (see
+    /// [`ThrottlePolicy`](crate::ThrottlePolicy)) has temporarily reduced it.
+    pub fn effective_rate_limit(&self) -> u32 {
+        self.throttle_state.current_rate()
+    }
+
+    /// Runs `request` inside an `edgar_request` span for `url`.
+    ///
+    /// The span carries the endpoint category (see [`endpoint_of`]) and, once the request
+    /// finishes, the last HTTP status, the number of retries, and the total duration
+    /// including rate-limit waits and backoff.
+    async fn traced<T>(&self, url: &str, request: impl Future<Output = Result<T>>) -> Result<T> {
+        let span = tracing::debug_span!(
+            "edgar_request",
+            url,
+            endpoint = endpoint_of(url),
+            status = field::Empty,
+            retries = 0u32,
+            elapsed_ms = field::Empty,
+        );
+        let started = Instant::now();
+        let result = request.instrument(span.clone()).await;
+        span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+        if let Err(e) = &result {
+            span.in_scope(|| tracing::debug!(error = %e, "request failed"));
+        }
+        result
+    }
+
+    /// Returns a client that applies `options` to every request, sharing this client's HTTP
+    /// connection pool and rate limiters.
+    ///
+    /// Use it to give high-level calls their own timeout:
+    ///
+    /// ```ignore
+    /// use edgarkit::{FilingOperations, RequestOptions};
+    /// use std::time::Duration;
+    ///
+    /// let quick = edgar.with_request_options(RequestOptions::new().with_timeout(Duration::from_secs(5)));
+    /// let filings = quick.filings(320193, None).await?;
+    /// ```
+    pub fn with_request_options(&self, options: RequestOptions) -> Self {
+        Self {
+            request_timeout: options.timeout.or(self.request_timeout),
+            retry_policy: options.retry.unwrap_or_else(|| self.retry_policy.clone()),
+            ..self.clone()
+        }
+    }
+
+    /// Starts a GET request, applying the per-call timeout if one is set.
+    pub(crate) fn request(&self, url: &str) -> reqwest::RequestBuilder {
+        let mut request = self.client.get(url);
+        if let Some(user_agent) = &self.user_agent {
+            request = request.header(USER_AGENT, user_agent.clone());
+        }
+        match self.request_timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+
+    /// Returns the retry policy used by this client.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Calculates the wait duration for retry attempts using exponential backoff with jitter.
     ///
-    /// The formula is: `(2^retry × 1000ms) ± 20%`
+    /// Each retry waits longer than the previous attempt. With the default policy that is
+    /// 1s, 2s, 4s, 8s, 16s, with random jitter (±20%) added to prevent the "thundering herd"
+    /// problem where many clients retry simultaneously and overwhelm the server again. See
+    /// [`RetryPolicy::backoff`] for the exact formula.
     ///
     /// # Arguments
     ///
@@ -206,23 +516,77 @@ impl Edgar {
     /// # Returns
     ///
     /// A `Duration` indicating how long to wait before the next retry attempt.
-    fn calculate_backoff(retry: u32) -> Duration {
-        let backoff_ms = INITIAL_BACKOFF_MS * (2_u64.pow(retry));
-        // Add some jitter (±20% of the calculated backoff)
-        let jitter = (backoff_ms as f64 * 0.2 * (fastrand::f64() - 0.5)) as i64;
-        Duration::from_millis((backoff_ms as i64 + jitter) as u64)
+    fn calculate_backoff(&self, retry: u32) -> Duration {
+        self.retry_policy.backoff(retry)
+    }
+
+    /// Builds the error for a redirect that the client's [`RedirectPolicy`] did not follow.
+    pub(crate) fn redirect_error(&self, response: &reqwest::Response) -> EdgarError {
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(|location| {
+                response
+                    .url()
+                    .join(location)
+                    .map(|url| url.to_string())
+                    .unwrap_or_else(|_| location.to_string())
+            })
+            .unwrap_or_default();
+        EdgarError::RedirectBlocked {
+            status: response.status().as_u16(),
+            url: response.url().to_string(),
+            location,
+        }
+    }
+
+    /// Parses a JSON payload in the client's [`ParseMode`], logging each repair.
+    #[cfg(any(feature = "filings", feature = "company"))]
+    pub(crate) fn parse_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        json: &str,
+    ) -> Result<(T, ParseReport)> {
+        let (parsed, report) = from_str_with_report(json, self.parse_mode)?;
+        for issue in &report.repaired {
+            tracing::warn!(
+                url,
+                path = %issue.path,
+                error = %issue.error,
+                repair = ?issue.repair,
+                "repaired JSON value"
+            );
+        }
+        Ok((parsed, report))
+    }
+
+    /// Builds the error for a non-success status, applying the client's [`PreviewPolicy`].
+    pub(crate) fn status_error(
+        &self,
+        url: &str,
+        status: reqwest::StatusCode,
+        body: &str,
+    ) -> EdgarError {
+        EdgarError::UnexpectedStatus {
+            status: status.as_u16(),
+            url: url.to_string(),
+            preview: self.error_preview.preview(body),
+            body: self.error_preview.attached(body),
+        }
     }
 
     /// Fetches binary data from a URL with automatic rate limiting and retry logic.
     ///
     /// This method is designed for downloading binary files like zip archives or PDF documents
     /// from the SEC EDGAR system. It respects rate limits, automatically retries on transient
-    /// failures and rate limit responses (HTTP 429), and returns the raw bytes for further
-    /// processing by your application.
+    /// failures, rate limit responses (HTTP 429), and server errors (HTTP 5xx), and returns the
+    /// raw bytes for further processing by your application.
     ///
-    /// The method will retry up to 5 times for rate limit errors (429) or network failures,
-    /// using exponential backoff with jitter between attempts. Other HTTP errors like 404
-    /// or 403 are returned immediately without retry.
+    /// Responses whose status is listed in the client's [`RetryPolicy`] (429, 500, 502, 503,
+    /// and 504 by default) are retried up to `max_retries` times, using exponential backoff with jitter between
+    /// attempts, as are connection failures before a response arrives. Other HTTP errors like
+    /// 404 or 403 are returned immediately without retry.
     ///
     /// # Arguments
     ///
@@ -237,49 +601,264 @@ impl Edgar {
     ///
     /// * `EdgarError::NotFound` - The resource doesn't exist (HTTP 404)
     /// * `EdgarError::RateLimitExceeded` - Rate limit responses persisted after max retries
-    /// * `EdgarError::RequestError` - Network failure or other HTTP errors
-    /// * `EdgarError::InvalidResponse` - Unexpected HTTP status code
+    /// * `EdgarError::RequestError` - Network failure that persisted after max retries, or
+    ///   a failure while reading the body
+    /// * `EdgarError::UnexpectedStatus` - Unexpected HTTP status code
     pub async fn get_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        self.in_flight_bytes
+            .run(&self.in_flight_key(url), || {
+                self.traced(url, self.fetch_bytes(url))
+            })
+            .await
+    }
+
+    /// Key under which a GET joins an identical one already in progress.
+    ///
+    /// Clones made by `with_request_options` or `with_retry_policy` share the in-flight map,
+    /// so the key includes the per-call timeout and retry policy: a fail-fast call never
+    /// waits on another clone's slow, retrying request for the same URL.
+    fn in_flight_key(&self, url: &str) -> String {
+        format!("{} {:?} {:?}", url, self.request_timeout, self.retry_policy)
+    }
+
+    async fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>> {
+        if let Some(transport) = &self.transport {
+            return self
+                .get_from_transport(transport.as_ref(), url, |url, status, body| {
+                    self.status_error(url, status, body)
+                })
+                .await
+                .map(|(_, body)| body);
+        }
+
+        #[cfg(feature = "mirror")]
+        if let Some(mirror) = &self.mirror {
+            return mirror.read(url).await;
+        }
+
+        let endpoint = endpoint_of(url);
         let mut retries = 0;
 
         loop {
-            self.rate_limiter.until_ready().await;
+            self.throttle(&self.rate_limiter).await?;
 
-            let response = self
-                .client
-                .get(url)
-                .send()
-                .await
-                .map_err(EdgarError::RequestError)?;
+            self.observe(|m| m.request_sent(endpoint));
+            let sent = Instant::now();
+            let response = match self.request(url).send().await {
+                Ok(response) => response,
+                Err(e) if retries < self.retry_policy.max_retries => {
+                    let backoff_duration = self.calculate_backoff(retries);
+                    tracing::warn!(
+                        "Request failed for {}: {:?}. Attempt {}/{}. Retrying in {:?}.",
+                        url,
+                        e,
+                        retries + 1,
+                        self.retry_policy.max_retries + 1,
+                        backoff_duration
+                    );
+                    self.observe_retry(endpoint, None, retries);
+                    sleep(backoff_duration).await;
+                    retries += 1;
+                    continue;
+                }
+                Err(e) => return Err(EdgarError::RequestError(e)),
+            };
+            self.observe_response(endpoint, response.status().as_u16(), sent);
 
             match response.status() {
                 reqwest::StatusCode::OK => {
-                    return response
-                        .bytes()
-                        .await
-                        .map(|b| b.to_vec())
-                        .map_err(EdgarError::RequestError);
+                    let bytes = response.bytes().await.map_err(EdgarError::RequestError)?;
+                    self.observe(|m| m.bytes_downloaded(endpoint, bytes.len() as u64));
+                    return Ok(bytes.to_vec());
                 }
                 reqwest::StatusCode::NOT_FOUND => {
-                    return Err(EdgarError::NotFound);
+                    return Err(EdgarError::not_found(ErrorContext::for_url(
+                        response.url().as_str(),
+                        Some(404),
+                    )));
+                }
+                status if status.is_redirection() => {
+                    return Err(self.redirect_error(&response));
+                }
+                status
+                    if self.retry_policy.should_retry_status(status.as_u16())
+                        && retries < self.retry_policy.max_retries =>
+                {
+                    self.observe_retry(endpoint, Some(status.as_u16()), retries);
+                    let retry_after = honors_retry_after(status)
+                        .then(|| retry_after(response.headers()))
+                        .flatten()
+                        .unwrap_or_else(|| self.calculate_backoff(retries));
+                    sleep(retry_after).await;
+                    retries += 1;
+                    continue;
                 }
                 reqwest::StatusCode::TOO_MANY_REQUESTS => {
-                    if retries >= MAX_RETRIES {
-                        return Err(EdgarError::RateLimitExceeded);
+                    return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                        response.url().as_str(),
+                        Some(429),
+                    )));
+                }
+                reqwest::StatusCode::FORBIDDEN => {
+                    let final_url = response.url().to_string();
+                    let body = response.bytes().await.unwrap_or_default();
+                    let body = String::from_utf8_lossy(&body);
+                    if !is_rate_threshold_page(&body) {
+                        return Err(self.status_error(
+                            &final_url,
+                            reqwest::StatusCode::FORBIDDEN,
+                            &body,
+                        ));
+                    }
+                    if !self.rate_blocked(endpoint, retries) {
+                        return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                            &final_url,
+                            Some(403),
+                        )));
                     }
-                    let retry_after = Self::calculate_backoff(retries);
-                    sleep(retry_after).await;
                     retries += 1;
                     continue;
                 }
                 status => {
-                    return Err(EdgarError::InvalidResponse(format!(
-                        "Unexpected status code: {}",
-                        status
+                    let final_url = response.url().to_string();
+                    let body = response.bytes().await.unwrap_or_default();
+                    return Err(self.status_error(
+                        &final_url,
+                        status,
+                        &String::from_utf8_lossy(&body),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Like [`get_bytes`](Self::get_bytes), with a per-call timeout or retry policy.
+    pub async fn get_bytes_with_options(
+        &self,
+        url: &str,
+        options: &RequestOptions,
+    ) -> Result<Vec<u8>> {
+        self.with_request_options(options.clone())
+            .get_bytes(url)
+            .await
+    }
+
+    /// Streams the body of `url` to `path`, returning the number of bytes written.
+    ///
+    /// The body is written chunk by chunk to a `.part` file next to `path`, which is renamed
+    /// once the download completes. If the download fails, times out, or the future is
+    /// dropped before it finishes, the partial file is removed, so `path` only ever holds a
+    /// complete download.
+    ///
+    /// Until the response starts, connection failures and the statuses in the client's
+    /// [`RetryPolicy`] (429 and transient 5xx by default) are retried with backoff, and a 403
+    /// rate-threshold page pauses the client for the [`ThrottlePolicy`](crate::ThrottlePolicy)
+    /// cooldown before retrying, like [`get_bytes`](Self::get_bytes). Other 403s and 404
+    /// fail at once. A download that fails after the body has started is not retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error on a non-success HTTP status once retries are exhausted, a network
+    /// failure or timeout mid-download, or if the file cannot be written.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_to_file(&self, url: &str, path: impl AsRef<Path>) -> Result<u64> {
+        let path = path.as_ref();
+        let mut partial = PartialFile::new(path);
+
+        let offline = self.transport.is_some();
+        #[cfg(feature = "mirror")]
+        let offline = offline || self.mirror.is_some();
+        if offline {
+            let bytes = self.get_bytes(url).await?;
+            tokio::fs::write(&partial.path, &bytes).await?;
+            partial.finish(path).await?;
+            return Ok(bytes.len() as u64);
+        }
+
+        let endpoint = endpoint_of(url);
+        let mut retries = 0;
+        let mut response = loop {
+            self.throttle(&self.rate_limiter).await?;
+            self.observe(|m| m.request_sent(endpoint));
+            let sent = Instant::now();
+            let response = match self.request(url).send().await {
+                Ok(response) => response,
+                Err(e) if retries < self.retry_policy.max_retries => {
+                    tracing::warn!("Download of {} failed: {:?}. Retrying.", url, e);
+                    self.observe_retry(endpoint, None, retries);
+                    sleep(self.calculate_backoff(retries)).await;
+                    retries += 1;
+                    continue;
+                }
+                Err(e) => return Err(EdgarError::RequestError(e)),
+            };
+            self.observe_response(endpoint, response.status().as_u16(), sent);
+
+            match response.status() {
+                reqwest::StatusCode::OK => break response,
+                reqwest::StatusCode::NOT_FOUND => {
+                    return Err(EdgarError::not_found(ErrorContext::for_url(
+                        response.url().as_str(),
+                        Some(404),
                     )));
                 }
+                status if status.is_redirection() => return Err(self.redirect_error(&response)),
+                status
+                    if self.retry_policy.should_retry_status(status.as_u16())
+                        && retries < self.retry_policy.max_retries =>
+                {
+                    self.observe_retry(endpoint, Some(status.as_u16()), retries);
+                    let retry_after = honors_retry_after(status)
+                        .then(|| retry_after(response.headers()))
+                        .flatten()
+                        .unwrap_or_else(|| self.calculate_backoff(retries));
+                    sleep(retry_after).await;
+                    retries += 1;
+                }
+                reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                        response.url().as_str(),
+                        Some(429),
+                    )));
+                }
+                reqwest::StatusCode::FORBIDDEN => {
+                    let final_url = response.url().to_string();
+                    let body = response.text().await.unwrap_or_default();
+                    if !is_rate_threshold_page(&body) {
+                        return Err(self.status_error(
+                            &final_url,
+                            reqwest::StatusCode::FORBIDDEN,
+                            &body,
+                        ));
+                    }
+                    if !self.rate_blocked(endpoint, retries) {
+                        return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                            &final_url,
+                            Some(403),
+                        )));
+                    }
+                    retries += 1;
+                }
+                status => {
+                    let final_url = response.url().to_string();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(self.status_error(&final_url, status, &body));
+                }
             }
+        };
+
+        let mut file = tokio::fs::File::create(&partial.path).await?;
+        let mut written = 0u64;
+        while let Some(chunk) = response.chunk().await.map_err(EdgarError::RequestError)? {
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
         }
+        file.flush().await?;
+        drop(file);
+        self.observe(|m| m.bytes_downloaded(endpoint, written));
+
+        partial.finish(path).await?;
+        Ok(written)
     }
 
     /// Fetches text content from a URL with rate limiting, retries, and content-type validation.
@@ -289,6 +868,12 @@ impl Edgar {
     /// retries failed requests with exponential backoff, and validates content types for JSON
     /// endpoints to catch server errors early.
     ///
+    /// Concurrent calls for the same URL, on this client or any of its clones, share a single
+    /// request: the first caller fetches and the others wait for its response, so a worker
+    /// pool loading `company_tickers.json` at startup spends one rate-limit token on it.
+    /// Responses are not cached; a call made after the request finished fetches again.
+    /// [`get_bytes`](Self::get_bytes) coalesces the same way.
+    ///
     /// # Content-Type Validation
     ///
     /// For URLs ending in `.json`, the method validates that the response isn't HTML (which
@@ -299,10 +884,15 @@ impl Edgar {
     ///
     /// # Retry Behavior
     ///
-    /// - **Rate limits (429)**: Retries up to 5 times, respecting `Retry-After` headers when
-    ///   present, otherwise using exponential backoff
-    /// - **Network errors**: Retries up to 5 times with exponential backoff  
-    /// - **Other HTTP errors**: No retry, returns immediately
+    /// Retry limits and delays come from the client's [`RetryPolicy`] (5 retries by default).
+    ///
+    /// - **Rate limits (429)**: Retried, respecting `Retry-After` headers when present,
+    ///   otherwise using exponential backoff
+    /// - **Server errors (500, 502, 503, 504)**: Retried with exponential backoff; a 503's
+    ///   `Retry-After` header is respected too
+    /// - **Network errors**: Retried with exponential backoff
+    /// - **Other statuses listed in the policy**: Retried with exponential backoff
+    /// - **Other HTTP errors, including 403 and 404**: No retry, returns immediately
     /// - **Content-type mismatches**: No retry, returns immediately
     ///
     /// # Arguments
@@ -319,97 +909,251 @@ impl Edgar {
     /// * `EdgarError::NotFound` - Resource doesn't exist (HTTP 404)
     /// * `EdgarError::RateLimitExceeded` - Max retries exhausted for rate limits
     /// * `EdgarError::RequestError` - Network or HTTP errors
-    /// * `EdgarError::InvalidResponse` - Unexpected status codes with content preview
+    /// * `EdgarError::UnexpectedStatus` - Unexpected status codes with content preview
+    /// * `EdgarError::RedirectBlocked` - A redirect was not allowed by the [`RedirectPolicy`]
     pub async fn get(&self, url: &str) -> Result<String> {
+        self.get_with_url(url).await.map(|(_, body)| body)
+    }
+
+    /// Fetches several URLs, at most `max_concurrency` at a time.
+    ///
+    /// Results come back in the order of `urls`, each with its own error, so one missing
+    /// document does not lose the others. Every request still waits on the client's rate
+    /// limiter, which caps throughput regardless of `max_concurrency`; the concurrency limit
+    /// bounds how many responses are downloaded at once. A `max_concurrency` of 0 is
+    /// treated as 1.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let urls = [
+    ///     "https://data.sec.gov/submissions/CIK0000320193.json",
+    ///     "https://data.sec.gov/submissions/CIK0000789019.json",
+    /// ];
+    /// for (url, body) in urls.iter().zip(edgar.get_many(urls, 4).await) {
+    ///     match body {
+    ///         Ok(body) => println!("{}: {} bytes", url, body.len()),
+    ///         Err(e) => eprintln!("{}: {}", url, e),
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_many<I>(&self, urls: I, max_concurrency: usize) -> Vec<Result<String>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        fan_out(urls, max_concurrency, |url| async move {
+            self.get(url.as_ref()).await
+        })
+        .await
+    }
+
+    /// Like [`get`](Self::get), with a per-call timeout or retry policy.
+    pub async fn get_with_options(&self, url: &str, options: &RequestOptions) -> Result<String> {
+        self.with_request_options(options.clone()).get(url).await
+    }
+
+    /// Like [`get`](Self::get), but also returns the URL the body was served from.
+    ///
+    /// The two differ when the request was redirected, which is worth logging when an
+    /// endpoint suddenly starts returning unexpected content. Errors report the final URL
+    /// as well.
+    pub async fn get_with_url(&self, url: &str) -> Result<(String, String)> {
+        self.get_via(url, &self.rate_limiter, |url, status, body| {
+            self.status_error(url, status, body)
+        })
+        .await
+    }
+
+    /// Shared implementation of [`get_with_url`](Self::get_with_url).
+    ///
+    /// Waits on `limiter` before every attempt and turns non-retryable error statuses into
+    /// errors with `map_status`, which receives the final URL, the status, and the body.
+    /// Hosts with their own quota or error payloads (such as full-text search) pass their
+    /// own limiter and mapping.
+    pub(crate) async fn get_via<F>(
+        &self,
+        url: &str,
+        limiter: &Governor,
+        map_status: F,
+    ) -> Result<(String, String)>
+    where
+        F: Fn(&str, reqwest::StatusCode, &str) -> EdgarError,
+    {
+        self.in_flight
+            .run(&self.in_flight_key(url), || {
+                self.traced(url, self.fetch_via(url, limiter, map_status))
+            })
+            .await
+    }
+
+    async fn fetch_via<F>(
+        &self,
+        url: &str,
+        limiter: &Governor,
+        map_status: F,
+    ) -> Result<(String, String)>
+    where
+        F: Fn(&str, reqwest::StatusCode, &str) -> EdgarError,
+    {
+        if let Some(transport) = &self.transport {
+            let (final_url, body) = self
+                .get_from_transport(transport.as_ref(), url, map_status)
+                .await?;
+            return Ok((final_url, String::from_utf8_lossy(&body).into_owned()));
+        }
+
+        #[cfg(feature = "mirror")]
+        if let Some(mirror) = &self.mirror {
+            let bytes = mirror.read(url).await?;
+            return Ok((
+                url.to_string(),
+                String::from_utf8_lossy(&bytes).into_owned(),
+            ));
+        }
+
+        let endpoint = endpoint_of(url);
         let mut retries = 0;
 
         loop {
             // Wait for rate limiter
-            self.rate_limiter.until_ready().await;
+            self.throttle(limiter).await?;
 
-            let response_result = self.client.get(url).send().await;
+            self.observe(|m| m.request_sent(endpoint));
+            let sent = Instant::now();
+            let response_result = self.request(url).send().await;
 
             match response_result {
                 Ok(response) => {
                     let status = response.status();
+                    self.observe_response(endpoint, status.as_u16(), sent);
                     let headers = response.headers().clone();
+                    let final_url = response.url().to_string();
+                    if final_url != url {
+                        tracing::debug!("{} was redirected to {}", url, final_url);
+                    }
 
                     // **Primary Check: If JSON was expected but HTML is received (regardless of status for client/server errors)**
-                    if url.ends_with(".json") && status.is_success() {
-                        if let Some(ct) = headers
+                    if url.ends_with(".json")
+                        && status.is_success()
+                        && let Some(ct) = headers
                             .get(reqwest::header::CONTENT_TYPE)
                             .and_then(|val| val.to_str().ok())
+                        && ct.to_lowercase().contains("text/html")
+                    {
+                        // SEC sometimes returns JSON with text/html content-type
+                        // Try to get the body and check if it's actually JSON
+                        let body_text = response
+                            .text()
+                            .await
+                            .unwrap_or_else(|_| "Failed to read response body".to_string());
+
+                        // Try to parse as JSON - if successful, it's valid JSON despite wrong content-type
+                        if body_text.trim_start().starts_with('{')
+                            || body_text.trim_start().starts_with('[')
                         {
-                            if ct.to_lowercase().contains("text/html") {
-                                // SEC sometimes returns JSON with text/html content-type
-                                // Try to get the body and check if it's actually JSON
-                                let body_text = response
-                                    .text()
-                                    .await
-                                    .unwrap_or_else(|_| "Failed to read response body".to_string());
-
-                                // Try to parse as JSON - if successful, it's valid JSON despite wrong content-type
-                                if body_text.trim_start().starts_with('{')
-                                    || body_text.trim_start().starts_with('[')
-                                {
-                                    tracing::warn!(
-                                        "Received text/html content-type for .json URL, but content appears to be JSON: {}",
-                                        url
-                                    );
-                                    return Ok(body_text);
-                                }
-
-                                // If it's actually HTML, return error
-                                let body_preview = body_text.chars().take(200).collect::<String>();
-                                return Err(EdgarError::UnexpectedContentType {
-                                    url: url.to_string(),
-                                    expected_pattern: "application/json".to_string(),
-                                    got_content_type: ct.to_string(),
-                                    content_preview: body_preview,
-                                });
-                            }
+                            self.observe(|m| m.bytes_downloaded(endpoint, body_text.len() as u64));
+                            tracing::warn!(
+                                "Received text/html content-type for .json URL, but content appears to be JSON: {}",
+                                url
+                            );
+                            return Ok((final_url, body_text));
                         }
-                        // If content-type wasn't text/html, or header was missing, proceed to normal status handling.
-                        // This means if it's a non-200 status but the content might be a valid JSON error (e.g., from SEC API),
-                        // it will be handled by the match status block below.
+
+                        // If it's actually HTML, return error
+                        return Err(EdgarError::UnexpectedContentType {
+                            url: final_url,
+                            expected_pattern: "application/json".to_string(),
+                            got_content_type: ct.to_string(),
+                            content_preview: self.error_preview.preview(&body_text),
+                            body: self.error_preview.attached(&body_text),
+                        });
                     }
+                    // If content-type wasn't text/html, or header was missing, proceed to normal status handling.
+                    // This means if it's a non-200 status but the content might be a valid JSON error (e.g., from SEC API),
+                    // it will be handled by the match status block below.
 
                     // **Standard Status Handling**
                     match status {
                         reqwest::StatusCode::OK => {
                             // If it's a .json URL, the check above ensures Content-Type wasn't text/html.
                             // If it's not a .json URL, we just get the text.
-                            return response.text().await.map_err(EdgarError::RequestError);
+                            let body = response.text().await.map_err(EdgarError::RequestError)?;
+                            self.observe(|m| m.bytes_downloaded(endpoint, body.len() as u64));
+                            return Ok((final_url, body));
                         }
                         reqwest::StatusCode::NOT_FOUND => {
-                            return Err(EdgarError::NotFound);
+                            return Err(EdgarError::not_found(ErrorContext::for_url(
+                                &final_url,
+                                Some(404),
+                            )));
+                        }
+                        status if status.is_redirection() => {
+                            return Err(self.redirect_error(&response));
                         }
                         reqwest::StatusCode::TOO_MANY_REQUESTS => {
-                            if retries >= MAX_RETRIES {
-                                return Err(EdgarError::RateLimitExceeded);
+                            if !self.retry_policy.should_retry_status(429)
+                                || retries >= self.retry_policy.max_retries
+                            {
+                                return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                                    &final_url,
+                                    Some(429),
+                                )));
                             }
 
                             // Get retry-after header if available
-                            let retry_after_duration = headers
-                                .get("retry-after")
-                                .and_then(|h| h.to_str().ok())
-                                .and_then(|s| s.parse::<u64>().ok())
-                                .map(Duration::from_secs)
-                                .unwrap_or_else(|| Self::calculate_backoff(retries));
+                            let retry_after_duration = retry_after(&headers)
+                                .unwrap_or_else(|| self.calculate_backoff(retries));
 
                             tracing::warn!(
                                 "Rate limit hit (429) for {}. Attempt {}/{}. Waiting for {:?} before retry.",
                                 url,
                                 retries + 1,
-                                MAX_RETRIES + 1, // Display as 1/6, 2/6, ..., 6/6 for MAX_RETRIES = 5
+                                self.retry_policy.max_retries + 1, // Display as 1/6, ..., 6/6 for 5 retries
                                 retry_after_duration
                             );
+                            self.observe_retry(endpoint, Some(429), retries);
                             sleep(retry_after_duration).await;
                             retries += 1;
                             continue; // Retry the loop
                         }
+                        reqwest::StatusCode::FORBIDDEN => {
+                            let body = response.text().await.unwrap_or_default();
+                            if !is_rate_threshold_page(&body) {
+                                return Err(map_status(&final_url, status, &body));
+                            }
+                            if !self.rate_blocked(endpoint, retries) {
+                                return Err(EdgarError::rate_limited(ErrorContext::for_url(
+                                    &final_url,
+                                    Some(403),
+                                )));
+                            }
+                            retries += 1;
+                            continue;
+                        }
+                        other_status
+                            if self.retry_policy.should_retry_status(other_status.as_u16())
+                                && retries < self.retry_policy.max_retries =>
+                        {
+                            let backoff_duration = honors_retry_after(other_status)
+                                .then(|| retry_after(&headers))
+                                .flatten()
+                                .unwrap_or_else(|| self.calculate_backoff(retries));
+                            tracing::warn!(
+                                "Retryable status {} for {}. Attempt {}/{}. Waiting for {:?} before retry.",
+                                other_status,
+                                url,
+                                retries + 1,
+                                self.retry_policy.max_retries + 1,
+                                backoff_duration
+                            );
+                            self.observe_retry(endpoint, Some(other_status.as_u16()), retries);
+                            sleep(backoff_duration).await;
+                            retries += 1;
+                            continue;
+                        }
                         other_status => {
-                            // Handles other statuses like 403, 500, 503 etc.
+                            // Handles statuses that are not retried, or are out of retries.
                             // If we reached here for a .json URL, it means the Content-Type wasn't text/html (or was missing).
                             // The body might be a JSON-formatted error from SEC, or some other non-HTML error page.
                             let error_body = response
@@ -417,29 +1161,25 @@ impl Edgar {
                                 .await
                                 .unwrap_or_else(|_| "Failed to read error body".to_string());
 
-                            return Err(EdgarError::InvalidResponse(format!(
-                                "Unexpected status code: {} for URL: {}. Response preview: {}",
-                                other_status,
-                                url,
-                                error_body.chars().take(200).collect::<String>()
-                            )));
+                            return Err(map_status(&final_url, other_status, &error_body));
                         }
                     }
                 }
                 Err(e) => {
                     // Network or other reqwest error before getting a response status
-                    if retries >= MAX_RETRIES {
+                    if retries >= self.retry_policy.max_retries {
                         return Err(EdgarError::RequestError(e));
                     }
-                    let backoff_duration = Self::calculate_backoff(retries);
+                    let backoff_duration = self.calculate_backoff(retries);
                     tracing::warn!(
                         "Request failed for {}: {:?}. Attempt {}/{}. Retrying in {:?}.",
                         url,
                         e,
                         retries + 1,
-                        MAX_RETRIES + 1, // Display as 1/6, 2/6, ..., 6/6 for MAX_RETRIES = 5
+                        self.retry_policy.max_retries + 1,
                         backoff_duration
                     );
+                    self.observe_retry(endpoint, None, retries);
                     sleep(backoff_duration).await;
                     retries += 1;
                     continue; // Retry the loop
@@ -448,6 +1188,55 @@ impl Edgar {
         }
     }
 
+    /// Fetches `url` from a configured [`Transport`], mapping statuses like HTTP responses.
+    async fn get_from_transport<F>(
+        &self,
+        transport: &dyn Transport,
+        url: &str,
+        map_status: F,
+    ) -> Result<(String, Vec<u8>)>
+    where
+        F: Fn(&str, reqwest::StatusCode, &str) -> EdgarError,
+    {
+        let endpoint = endpoint_of(url);
+        self.observe(|m| m.request_sent(endpoint));
+        let sent = Instant::now();
+        let response = transport.get(url).await?;
+        self.observe_response(endpoint, response.status, sent);
+        let status = reqwest::StatusCode::from_u16(response.status).map_err(|_| {
+            EdgarError::InvalidResponse(format!(
+                "transport returned invalid status {} for {}",
+                response.status, url
+            ))
+        })?;
+        match status {
+            status if status.is_success() => {
+                self.observe(|m| m.bytes_downloaded(endpoint, response.body.len() as u64));
+                Ok((response.url, response.body))
+            }
+            reqwest::StatusCode::NOT_FOUND => Err(EdgarError::not_found(ErrorContext::for_url(
+                &response.url,
+                Some(404),
+            ))),
+            reqwest::StatusCode::TOO_MANY_REQUESTS => Err(EdgarError::rate_limited(
+                ErrorContext::for_url(&response.url, Some(429)),
+            )),
+            reqwest::StatusCode::FORBIDDEN
+                if is_rate_threshold_page(&String::from_utf8_lossy(&response.body)) =>
+            {
+                Err(EdgarError::rate_limited(ErrorContext::for_url(
+                    &response.url,
+                    Some(403),
+                )))
+            }
+            status => Err(map_status(
+                &response.url,
+                status,
+                &String::from_utf8_lossy(&response.body),
+            )),
+        }
+    }
+
     /// Returns the base URL for EDGAR archives.
     ///
     /// # Returns
@@ -485,15 +1274,184 @@ impl Edgar {
     }
 }
 
+/// Builds the HTTP client from the timeout, redirect, proxy, TLS, and compression settings in
+/// `config`.
+#[cfg(not(target_arch = "wasm32"))]
+fn build_client(config: &EdgarConfig, headers: HeaderMap) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .default_headers(headers)
+        .timeout(config.timeout)
+        .redirect(redirect_policy(config.redirect.clone(), &config.base_urls))
+        .tls_built_in_root_certs(config.built_in_roots)
+        .gzip(config.compression)
+        .deflate(config.compression);
+
+    if let Some(proxy) = &config.proxy {
+        let mut built = reqwest::Proxy::all(&proxy.url)
+            .map_err(|e| EdgarError::ConfigError(format!("Invalid proxy URL: {}", e)))?;
+        if let Some((username, password)) = &proxy.basic_auth {
+            built = built.basic_auth(username, password);
+        }
+        if let Some(no_proxy) = &proxy.no_proxy {
+            built = built.no_proxy(reqwest::NoProxy::from_string(no_proxy));
+        }
+        builder = builder.proxy(built);
+    }
+
+    for pem in &config.root_certificates {
+        let certificates = reqwest::Certificate::from_pem_bundle(pem)
+            .map_err(|e| EdgarError::ConfigError(format!("Invalid root certificate: {}", e)))?;
+        if certificates.is_empty() {
+            return Err(EdgarError::ConfigError(
+                "Invalid root certificate: no PEM certificates found".to_string(),
+            ));
+        }
+        for certificate in certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    builder
+        .build()
+        .map_err(|e| EdgarError::ConfigError(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// Builds the browser HTTP client.
+///
+/// Requests go through the browser's `fetch`, which handles redirects, TLS, compression,
+/// and proxies itself, so only the default headers apply; the client-wide timeout is set per
+/// request instead. A proxy or extra root certificates cannot be honored and are rejected.
+#[cfg(target_arch = "wasm32")]
+fn build_client(config: &EdgarConfig, headers: HeaderMap) -> Result<reqwest::Client> {
+    if config.proxy.is_some() || !config.root_certificates.is_empty() {
+        return Err(EdgarError::ConfigError(
+            "Proxies and root certificates are not supported in the browser".to_string(),
+        ));
+    }
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(|e| EdgarError::ConfigError(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// The `.part` file a download is written to, removed on drop unless the download finished.
+#[cfg(not(target_arch = "wasm32"))]
+struct PartialFile {
+    path: PathBuf,
+    finished: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PartialFile {
+    fn new(target: &Path) -> Self {
+        let mut path = target.to_path_buf().into_os_string();
+        path.push(".part");
+        Self {
+            path: path.into(),
+            finished: false,
+        }
+    }
+
+    /// Moves the completed download to `target`.
+    async fn finish(&mut self, target: &Path) -> Result<()> {
+        tokio::fs::rename(&self.path, target).await?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for PartialFile {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Runs `f` on every item, at most `max_concurrency` at a time, returning the outputs in
+/// the order of `items`.
+pub(crate) async fn fan_out<I, F, Fut>(items: I, max_concurrency: usize, f: F) -> Vec<Fut::Output>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future,
+{
+    stream::iter(items)
+        .map(f)
+        .buffered(max_concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Whether a retried response's `Retry-After` header replaces the computed backoff.
+fn honors_retry_after(status: reqwest::StatusCode) -> bool {
+    matches!(
+        status,
+        reqwest::StatusCode::TOO_MANY_REQUESTS | reqwest::StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
+/// Parses a `Retry-After` header given either in seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let at = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    (at.with_timezone(&chrono::Utc) - chrono::Utc::now())
+        .to_std()
+        .ok()
+}
+
+/// Builds the reqwest redirect policy, also allowing the hosts of the configured base URLs.
+#[cfg(not(target_arch = "wasm32"))]
+fn redirect_policy(mut policy: RedirectPolicy, base_urls: &EdgarUrls) -> reqwest::redirect::Policy {
+    for base in [
+        &base_urls.archives,
+        &base_urls.data,
+        &base_urls.files,
+        &base_urls.search,
+    ] {
+        if let Some(host) = reqwest::Url::parse(base)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            && !policy.allows(&host)
+        {
+            policy.allowed_hosts.push(host);
+        }
+    }
+
+    reqwest::redirect::Policy::custom(move |attempt| {
+        // `previous` holds every URL requested so far, starting with the original one.
+        let allowed = attempt.previous().len() <= policy.max_redirects
+            && attempt
+                .url()
+                .host_str()
+                .is_some_and(|host| policy.allows(host));
+        if allowed {
+            attempt.follow()
+        } else {
+            // Hand the redirect response back so the caller can report where it pointed.
+            attempt.stop()
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_calculate_backoff() {
-        let backoff0 = Edgar::calculate_backoff(0);
-        let backoff1 = Edgar::calculate_backoff(1);
-        let backoff2 = Edgar::calculate_backoff(2);
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        let backoff0 = edgar.calculate_backoff(0);
+        let backoff1 = edgar.calculate_backoff(1);
+        let backoff2 = edgar.calculate_backoff(2);
 
         // Check that backoff increases exponentially
         assert!(backoff0 < backoff1);
@@ -504,4 +1462,265 @@ mod tests {
         assert!(backoff1.as_millis() >= 1600 && backoff1.as_millis() <= 2400); // ±20% of 2000ms
         assert!(backoff2.as_millis() >= 3200 && backoff2.as_millis() <= 4800); // ±20% of 4000ms
     }
+
+    #[test]
+    fn test_request_options_override() {
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        let quick = edgar.with_request_options(
+            RequestOptions::new()
+                .with_timeout(Duration::from_secs(5))
+                .with_retry_policy(RetryPolicy::none()),
+        );
+        assert_eq!(quick.request_timeout, Some(Duration::from_secs(5)));
+        assert_eq!(quick.retry_policy().max_retries, 0);
+        assert_eq!(edgar.request_timeout, None);
+        assert!(Arc::ptr_eq(&quick.rate_limiter, &edgar.rate_limiter));
+    }
+
+    #[test]
+    fn test_partial_file_removed_on_drop() {
+        let target =
+            std::env::temp_dir().join(format!("edgarkit-partial-{}.bin", std::process::id()));
+        let partial = PartialFile::new(&target);
+        assert!(partial.path.to_string_lossy().ends_with(".bin.part"));
+        std::fs::write(&partial.path, b"truncated").unwrap();
+        let part_path = partial.path.clone();
+        drop(partial);
+        assert!(!part_path.exists());
+        assert!(!target.exists());
+    }
+
+    #[test]
+    fn test_client_configuration_errors() {
+        use crate::ProxyConfig;
+
+        let config = EdgarConfig::default().with_proxy(ProxyConfig::new("not a url"));
+        assert!(matches!(
+            Edgar::with_config(config),
+            Err(EdgarError::ConfigError(_))
+        ));
+
+        let config = EdgarConfig::default().with_root_certificate("not a certificate");
+        assert!(matches!(
+            Edgar::with_config(config),
+            Err(EdgarError::ConfigError(_))
+        ));
+
+        let config = EdgarConfig::default()
+            .with_proxy(ProxyConfig::new("http://proxy:3128").with_basic_auth("user", "pass"));
+        assert!(Edgar::with_config(config).unwrap().user_agent.is_none());
+
+        let config = EdgarConfig::default().with_http_client(reqwest::Client::new());
+        let edgar = Edgar::with_config(config).unwrap();
+        assert_eq!(edgar.user_agent.as_ref().unwrap(), "edgarkit/0.1.0");
+    }
+
+    /// Answers every URL with `[]` after a short delay, counting requests.
+    #[derive(Debug, Default)]
+    struct SlowTransport {
+        requests: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for SlowTransport {
+        async fn get(&self, url: &str) -> Result<crate::transport::TransportResponse> {
+            self.requests
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            sleep(Duration::from_millis(20)).await;
+            Ok(crate::transport::TransportResponse::ok(url, "[]"))
+        }
+    }
+
+    /// Answers one connection per entry of `responses` on a local port, recording the head
+    /// of each request.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn serve(responses: Vec<&'static str>) -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut head = Vec::new();
+                let mut byte = [0u8; 1];
+                while !head.ends_with(b"\r\n\r\n") && stream.read(&mut byte).unwrap() == 1 {
+                    head.push(byte[0]);
+                }
+                seen.lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&head).into_owned());
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (base, requests)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_download_to_file_retries_before_streaming() {
+        let (base, requests) = serve(vec![
+            "HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let edgar = Edgar::new("test_agent example@example.com")
+            .unwrap()
+            .with_retry_policy(
+                RetryPolicy::default()
+                    .with_base_backoff(Duration::from_millis(10))
+                    .with_jitter(0.0),
+            );
+        let target =
+            std::env::temp_dir().join(format!("edgarkit-download-{}.bin", std::process::id()));
+
+        let written = edgar
+            .download_to_file(&format!("{base}/data.bin"), &target)
+            .await
+            .unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(std::fs::read(&target).unwrap(), b"hello");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        std::fs::remove_file(&target).unwrap();
+
+        let missing = edgar
+            .download_to_file(&format!("{base}/missing.bin"), &target)
+            .await;
+        assert!(matches!(missing, Err(EdgarError::NotFound(_))));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+        assert!(!target.exists());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_download_to_file_waits_out_rate_threshold_page() {
+        const THRESHOLD: &str = "HTTP/1.1 403 Forbidden\r\nContent-Length: 61\r\nConnection: close\r\n\r\n<html><title>Request Rate Threshold Exceeded</title></html>\r\n";
+        let (base, requests) = serve(vec![
+            THRESHOLD,
+            "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello",
+            "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let config = EdgarConfig::new(
+            "test_agent example@example.com",
+            10,
+            Duration::from_secs(5),
+            None,
+        )
+        .with_throttle_policy(
+            crate::ThrottlePolicy::default().with_cooldown(Duration::from_millis(10)),
+        );
+        let edgar = Edgar::with_config(config).unwrap();
+        let target =
+            std::env::temp_dir().join(format!("edgarkit-threshold-{}.bin", std::process::id()));
+
+        let written = edgar
+            .download_to_file(&format!("{base}/data.bin"), &target)
+            .await
+            .unwrap();
+        assert_eq!(written, 5);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+        std::fs::remove_file(&target).unwrap();
+
+        // A 403 that is not the rate-threshold page is not retried.
+        let denied = edgar
+            .download_to_file(&format!("{base}/denied.bin"), &target)
+            .await;
+        assert!(matches!(
+            denied,
+            Err(EdgarError::UnexpectedStatus { status: 403, .. })
+        ));
+        assert_eq!(requests.lock().unwrap().len(), 3);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_get_bytes_retries_connection_errors() {
+        // The first connection is closed without an answer.
+        let (base, requests) = serve(vec![
+            "",
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok",
+        ]);
+        let edgar = Edgar::new("test_agent example@example.com")
+            .unwrap()
+            .with_retry_policy(
+                RetryPolicy::default()
+                    .with_base_backoff(Duration::from_millis(10))
+                    .with_jitter(0.0),
+            );
+
+        let body = edgar.get_bytes(&format!("{base}/data.bin")).await.unwrap();
+        assert_eq!(body, b"ok");
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_compression_toggles_accept_encoding() {
+        const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok";
+
+        for compression in [true, false] {
+            let (base, requests) = serve(vec![OK]);
+            let config = EdgarConfig::new(
+                "test_agent example@example.com",
+                10,
+                Duration::from_secs(5),
+                None,
+            )
+            .with_compression(compression);
+            let edgar = Edgar::with_config(config).unwrap();
+
+            assert_eq!(edgar.get(&format!("{base}/data.json")).await.unwrap(), "ok");
+            let head = requests.lock().unwrap()[0].to_ascii_lowercase();
+            assert_eq!(
+                head.contains("accept-encoding:"),
+                compression,
+                "compression {compression}: {head}"
+            );
+            if compression {
+                assert!(head.contains("gzip"));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_identical_gets_share_one_request() {
+        use std::sync::atomic::Ordering;
+
+        let transport = Arc::new(SlowTransport::default());
+        let edgar = Edgar::with_transport(transport.clone()).unwrap();
+        let other = edgar.clone();
+        let url = "https://www.sec.gov/files/company_tickers.json";
+
+        let (a, b, c) = tokio::join!(edgar.get(url), other.get(url), edgar.get_bytes(url));
+        assert_eq!(a.unwrap(), "[]");
+        assert_eq!(b.unwrap(), "[]");
+        assert_eq!(c.unwrap(), b"[]");
+        // Text and binary requests are coalesced separately.
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gets_with_different_options_are_not_coalesced() {
+        use std::sync::atomic::Ordering;
+
+        let transport = Arc::new(SlowTransport::default());
+        let edgar = Edgar::with_transport(transport.clone()).unwrap();
+        let quick = edgar
+            .with_request_options(RequestOptions::new().with_timeout(Duration::from_millis(500)));
+        let slow =
+            edgar.with_request_options(RequestOptions::new().with_timeout(Duration::from_secs(30)));
+        let url = "https://www.sec.gov/files/company_tickers.json";
+
+        let (a, b) = tokio::join!(quick.get(url), slow.get(url));
+        assert_eq!(a.unwrap(), "[]");
+        assert_eq!(b.unwrap(), "[]");
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 2);
+
+        let fail_fast = edgar.with_retry_policy(RetryPolicy::none());
+        let (a, b) = tokio::join!(fail_fast.get_bytes(url), edgar.get_bytes(url));
+        assert!(a.is_ok() && b.is_ok());
+        assert_eq!(transport.requests.load(Ordering::SeqCst), 4);
+    }
 }
@@ -0,0 +1,576 @@
+//! Financial Statement Data Sets.
+//!
+//! Every quarter the SEC publishes the numbers of all XBRL financial statements filed that
+//! quarter as one ZIP of tab-separated files:
+//! - `sub.txt` — one row per submission: filer, form, fiscal period, filing date;
+//! - `num.txt` — one row per reported value, keyed by accession number (`adsh`), tag,
+//!   version, end date, duration in quarters, unit, and segments;
+//! - `tag.txt` — the definition of every standard and custom tag used;
+//! - `pre.txt` — where each value appears in the rendered statements.
+//!
+//! The values are exactly as filed, which makes the data sets the canonical bulk source for
+//! as-reported financials. [`Edgar::download_financial_statement_dataset`] streams a
+//! quarter's ZIP to disk and [`DatasetArchive`] reads `sub.txt`, `num.txt`, and `tag.txt`
+//! row by row into [`SubRecord`], [`NumRecord`], and [`TagRecord`], or joins submissions
+//! with their values.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{DatasetArchive, Edgar, Quarter};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let edgar = Edgar::new("MyApp contact@example.com")?;
+//!     edgar
+//!         .download_financial_statement_dataset(2024, Quarter::Q1, "2024q1.zip")
+//!         .await?;
+//!
+//!     let mut archive = DatasetArchive::open("2024q1.zip")?;
+//!     for filing in archive.filings()? {
+//!         let revenue = filing.values("Revenues").find(|num| num.qtrs == 4);
+//!         println!("{} {} {:?}", filing.submission.name, filing.submission.form, revenue.and_then(|num| num.value));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use super::Edgar;
+use super::accession::AccessionNumber;
+use super::cik::Cik;
+use super::error::{EdgarError, Result};
+use super::quarter::Quarter;
+use super::sic::SicCode;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use zip::ZipArchive;
+
+/// First year the data sets cover.
+const FIRST_DATASET_YEAR: i32 = 2009;
+
+impl Edgar {
+    /// Returns the download URL of a quarter's Financial Statement Data Sets ZIP.
+    pub fn financial_statement_dataset_url(&self, year: i32, quarter: Quarter) -> String {
+        format!(
+            "{}/dera/data/financial-statement-data-sets/{}q{}.zip",
+            self.edgar_files_url,
+            year,
+            quarter.as_i32()
+        )
+    }
+
+    /// Streams a quarter's Financial Statement Data Sets ZIP to `path`, returning the number
+    /// of bytes written. Like [`Edgar::download_to_file`], a failed download leaves nothing
+    /// at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` for quarters before 2009, and the errors of
+    /// [`Edgar::download_to_file`] otherwise.
+    pub async fn download_financial_statement_dataset(
+        &self,
+        year: i32,
+        quarter: Quarter,
+        path: impl AsRef<Path>,
+    ) -> Result<u64> {
+        if year < FIRST_DATASET_YEAR {
+            return Err(EdgarError::InvalidFormat(format!(
+                "financial statement data sets start in {}, got {}",
+                FIRST_DATASET_YEAR, year
+            )));
+        }
+        let url = self.financial_statement_dataset_url(year, quarter);
+        self.download_to_file(&url, path).await
+    }
+}
+
+/// A submission from `sub.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubRecord {
+    /// Accession number, the key `num.txt` rows refer to.
+    pub adsh: AccessionNumber,
+
+    pub cik: Cik,
+
+    /// Registrant name as of the filing.
+    pub name: String,
+
+    pub sic: Option<SicCode>,
+
+    /// Business address country (ISO 3166-1 alpha-2).
+    pub country_ba: Option<String>,
+
+    /// Business address state or province.
+    pub state_ba: Option<String>,
+
+    pub city_ba: Option<String>,
+
+    /// Country of incorporation (ISO 3166-1 alpha-2).
+    pub country_inc: Option<String>,
+
+    /// State or province of incorporation.
+    pub state_inc: Option<String>,
+
+    /// Filer status: `1-LAF` (large accelerated) to `5-SML` (smaller reporting company).
+    pub afs: Option<String>,
+
+    /// Well-known seasoned issuer.
+    pub wksi: bool,
+
+    /// Fiscal year end as `MMDD`.
+    pub fye: Option<String>,
+
+    /// Form type, e.g. `10-K` or `10-Q/A`.
+    pub form: String,
+
+    /// Balance sheet date, rounded to the nearest month end.
+    pub period: Option<NaiveDate>,
+
+    /// Fiscal year the filer assigned to the report.
+    pub fy: Option<i32>,
+
+    /// Fiscal period: `FY`, `Q1`, `Q2`, `Q3`, `Q4`, `H1`, `H2`, `M9`, ...
+    pub fp: Option<String>,
+
+    pub filed: NaiveDate,
+
+    /// Acceptance time, to the second.
+    pub accepted: Option<NaiveDateTime>,
+
+    /// Whether a later filing amended or restated this one's values.
+    pub prevrpt: bool,
+
+    /// Whether the footnotes were tagged in detail.
+    pub detail: bool,
+
+    /// Name of the XBRL instance document.
+    pub instance: String,
+
+    /// Number of co-registrants included in the filing.
+    pub nciks: u32,
+
+    /// The co-registrants' CIKs.
+    pub aciks: Vec<Cik>,
+}
+
+/// A reported value from `num.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumRecord {
+    pub adsh: AccessionNumber,
+
+    pub tag: String,
+
+    /// Taxonomy the tag belongs to (`us-gaap/2023`), or the accession number for custom tags.
+    pub version: String,
+
+    /// End date of the value, rounded to the nearest month end.
+    pub ddate: NaiveDate,
+
+    /// Duration in quarters; 0 for point-in-time values such as balances.
+    pub qtrs: u32,
+
+    pub uom: String,
+
+    /// Axis and member qualifiers of a dimensional value, absent for the consolidated total.
+    pub segments: Option<String>,
+
+    /// Co-registrant the value belongs to, absent for the registrant itself.
+    pub coreg: Option<String>,
+
+    /// The value; absent when the filer reported it as nil.
+    pub value: Option<f64>,
+
+    pub footnote: Option<String>,
+}
+
+/// A tag definition from `tag.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagRecord {
+    pub tag: String,
+
+    pub version: String,
+
+    /// Whether the tag is a filer extension rather than part of a standard taxonomy.
+    pub custom: bool,
+
+    /// Whether the tag only groups others and never carries a value.
+    pub is_abstract: bool,
+
+    /// XBRL data type, e.g. `monetary` or `shares`.
+    pub datatype: Option<String>,
+
+    /// `I` for point-in-time values, `D` for durations.
+    pub iord: Option<String>,
+
+    /// `C` for credit balances, `D` for debit.
+    pub crdr: Option<String>,
+
+    /// Label.
+    pub tlabel: Option<String>,
+
+    /// Definition.
+    pub doc: Option<String>,
+}
+
+/// A submission joined with its values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatasetFiling {
+    pub submission: SubRecord,
+
+    /// The filing's rows of `num.txt`, in file order.
+    pub numbers: Vec<NumRecord>,
+}
+
+impl DatasetFiling {
+    /// Values reported for `tag`, for every date, duration, and segment.
+    pub fn values<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a NumRecord> {
+        self.numbers.iter().filter(move |num| num.tag == tag)
+    }
+}
+
+/// Reads a downloaded Financial Statement Data Sets ZIP.
+///
+/// The record iterators stream their file a line at a time. A row that does not parse
+/// fails only its own item.
+pub struct DatasetArchive {
+    archive: ZipArchive<BufReader<File>>,
+}
+
+impl DatasetArchive {
+    /// Opens a data sets ZIP, such as `2024q1.zip`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = File::open(path)?;
+        let archive = ZipArchive::new(BufReader::new(file))
+            .map_err(|e| EdgarError::ArchiveError(e.to_string()))?;
+        Ok(Self { archive })
+    }
+
+    /// Iterates `sub.txt`.
+    pub fn submissions(&mut self) -> Result<impl Iterator<Item = Result<SubRecord>> + '_> {
+        self.records("sub.txt", SubRecord::from_row)
+    }
+
+    /// Iterates `num.txt`.
+    pub fn numbers(&mut self) -> Result<impl Iterator<Item = Result<NumRecord>> + '_> {
+        self.records("num.txt", NumRecord::from_row)
+    }
+
+    /// Iterates `tag.txt`.
+    pub fn tags(&mut self) -> Result<impl Iterator<Item = Result<TagRecord>> + '_> {
+        self.records("tag.txt", TagRecord::from_row)
+    }
+
+    /// Joins every submission with its values, in `sub.txt` order.
+    ///
+    /// A quarter's `num.txt` has a few million rows, so this holds the whole quarter in
+    /// memory; use [`filing`](Self::filing) for a single submission.
+    ///
+    /// # Errors
+    ///
+    /// Fails on the first row of either file that does not parse.
+    pub fn filings(&mut self) -> Result<Vec<DatasetFiling>> {
+        let mut numbers: HashMap<AccessionNumber, Vec<NumRecord>> = HashMap::new();
+        for num in self.numbers()? {
+            let num = num?;
+            numbers.entry(num.adsh).or_default().push(num);
+        }
+        self.submissions()?
+            .map(|submission| {
+                let submission = submission?;
+                Ok(DatasetFiling {
+                    numbers: numbers.remove(&submission.adsh).unwrap_or_default(),
+                    submission,
+                })
+            })
+            .collect()
+    }
+
+    /// Joins one submission with its values, or `None` if it is not in this quarter.
+    pub fn filing(&mut self, adsh: AccessionNumber) -> Result<Option<DatasetFiling>> {
+        let mut submission = None;
+        for sub in self.submissions()? {
+            let sub = sub?;
+            if sub.adsh == adsh {
+                submission = Some(sub);
+                break;
+            }
+        }
+        let Some(submission) = submission else {
+            return Ok(None);
+        };
+        let mut numbers = Vec::new();
+        for num in self.numbers()? {
+            let num = num?;
+            if num.adsh == adsh {
+                numbers.push(num);
+            }
+        }
+        Ok(Some(DatasetFiling {
+            submission,
+            numbers,
+        }))
+    }
+
+    fn records<'a, T: 'a>(
+        &'a mut self,
+        file: &'static str,
+        parse: fn(&Row<'_>) -> Result<T>,
+    ) -> Result<impl Iterator<Item = Result<T>> + 'a> {
+        let entry = self
+            .archive
+            .by_name(file)
+            .map_err(|e| EdgarError::ArchiveError(format!("{}: {}", file, e)))?;
+        let mut lines = BufReader::new(entry).lines();
+        let header: Vec<String> = match lines.next() {
+            Some(line) => line?
+                .trim_end_matches('\r')
+                .split('\t')
+                .map(str::to_string)
+                .collect(),
+            None => Vec::new(),
+        };
+        Ok(lines.enumerate().filter_map(move |(index, line)| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                return None;
+            }
+            let row = Row {
+                header: &header,
+                fields: line.split('\t').collect(),
+            };
+            Some(parse(&row).map_err(|e| {
+                EdgarError::InvalidFormat(format!("{} line {}: {}", file, index + 2, e))
+            }))
+        }))
+    }
+}
+
+/// One line of a data sets file, with its fields looked up by column name.
+struct Row<'a> {
+    header: &'a [String],
+    fields: Vec<&'a str>,
+}
+
+impl Row<'_> {
+    /// The field under `column`, with blanks as `None`.
+    fn get(&self, column: &str) -> Option<&str> {
+        let at = self.header.iter().position(|name| name == column)?;
+        let field = self.fields.get(at)?.trim();
+        (!field.is_empty()).then_some(field)
+    }
+
+    fn text(&self, column: &str) -> Option<String> {
+        self.get(column).map(str::to_string)
+    }
+
+    fn required(&self, column: &str) -> Result<&str> {
+        self.get(column)
+            .ok_or_else(|| EdgarError::InvalidFormat(format!("missing {}", column)))
+    }
+
+    fn parse<T: std::str::FromStr>(&self, column: &str) -> Result<Option<T>> {
+        self.get(column)
+            .map(|field| {
+                field.parse().map_err(|_| {
+                    EdgarError::InvalidFormat(format!("invalid {} {:?}", column, field))
+                })
+            })
+            .transpose()
+    }
+
+    fn flag(&self, column: &str) -> bool {
+        matches!(self.get(column), Some("1"))
+    }
+
+    fn date(&self, column: &str) -> Result<Option<NaiveDate>> {
+        self.get(column)
+            .map(|field| {
+                NaiveDate::parse_from_str(field, "%Y%m%d").map_err(|_| {
+                    EdgarError::InvalidFormat(format!("invalid {} {:?}", column, field))
+                })
+            })
+            .transpose()
+    }
+
+    fn required_date(&self, column: &str) -> Result<NaiveDate> {
+        self.date(column)?
+            .ok_or_else(|| EdgarError::InvalidFormat(format!("missing {}", column)))
+    }
+}
+
+impl SubRecord {
+    fn from_row(row: &Row<'_>) -> Result<Self> {
+        let accepted = row
+            .get("accepted")
+            .and_then(|field| NaiveDateTime::parse_from_str(field, "%Y-%m-%d %H:%M:%S%.f").ok());
+        let aciks = row
+            .get("aciks")
+            .map(|ciks| {
+                ciks.split_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<Vec<Cik>>>()
+            })
+            .transpose()?
+            .unwrap_or_default();
+        Ok(Self {
+            adsh: row.required("adsh")?.parse()?,
+            cik: row.required("cik")?.parse()?,
+            name: row.required("name")?.to_string(),
+            sic: row.parse("sic")?,
+            country_ba: row.text("countryba"),
+            state_ba: row.text("stprba"),
+            city_ba: row.text("cityba"),
+            country_inc: row.text("countryinc"),
+            state_inc: row.text("stprinc"),
+            afs: row.text("afs"),
+            wksi: row.flag("wksi"),
+            fye: row.text("fye"),
+            form: row.required("form")?.to_string(),
+            period: row.date("period")?,
+            fy: row.parse("fy")?,
+            fp: row.text("fp"),
+            filed: row.required_date("filed")?,
+            accepted,
+            prevrpt: row.flag("prevrpt"),
+            detail: row.flag("detail"),
+            instance: row.text("instance").unwrap_or_default(),
+            nciks: row.parse("nciks")?.unwrap_or(1),
+            aciks,
+        })
+    }
+}
+
+impl NumRecord {
+    fn from_row(row: &Row<'_>) -> Result<Self> {
+        Ok(Self {
+            adsh: row.required("adsh")?.parse()?,
+            tag: row.required("tag")?.to_string(),
+            version: row.required("version")?.to_string(),
+            ddate: row.required_date("ddate")?,
+            qtrs: row.parse("qtrs")?.unwrap_or(0),
+            uom: row.required("uom")?.to_string(),
+            segments: row.text("segments"),
+            coreg: row.text("coreg"),
+            value: row.parse("value")?,
+            footnote: row.text("footnote"),
+        })
+    }
+}
+
+impl TagRecord {
+    fn from_row(row: &Row<'_>) -> Result<Self> {
+        Ok(Self {
+            tag: row.required("tag")?.to_string(),
+            version: row.required("version")?.to_string(),
+            custom: row.flag("custom"),
+            is_abstract: row.flag("abstract"),
+            datatype: row.text("datatype"),
+            iord: row.text("iord"),
+            crdr: row.text("crdr"),
+            tlabel: row.text("tlabel"),
+            doc: row.text("doc"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::{SimpleFileOptions, ZipWriter};
+
+    const SUB: &str = "adsh\tcik\tname\tsic\tcountryba\tstprba\tcityba\tcountryinc\tstprinc\tafs\twksi\tfye\tform\tperiod\tfy\tfp\tfiled\taccepted\tprevrpt\tdetail\tinstance\tnciks\taciks\n\
+        0000320193-24-000006\t320193\tAPPLE INC\t3571\tUS\tCA\tCUPERTINO\tUS\tCA\t1-LAF\t1\t0930\t10-Q\t20231231\t2024\tQ1\t20240202\t2024-02-01 18:03:00.0\t0\t1\taapl-20231230_htm.xml\t1\t\n\
+        0001104659-24-000001\t1000045\tNICHOLAS FINANCIAL INC\t6153\tUS\tFL\tCLEARWATER\t\t\t4-NON\t0\t0331\t10-Q\t20231231\t2024\tQ3\t20240212\t2024-02-12 16:30:00.0\t1\t0\tnick-20231231_htm.xml\t1\t\n";
+
+    const NUM: &str = "adsh\ttag\tversion\tddate\tqtrs\tuom\tsegments\tcoreg\tvalue\tfootnote\r\n\
+        0000320193-24-000006\tRevenueFromContractWithCustomerExcludingAssessedTax\tus-gaap/2023\t20231231\t1\tUSD\t\t\t119575000000.0000\t\r\n\
+        0001104659-24-000001\tAssets\tus-gaap/2023\t20231231\t0\tUSD\t\t\t\t\r\n\
+        0000320193-24-000006\tAssets\tus-gaap/2023\t20231231\t0\tUSD\t\t\t353514000000.0000\t\r\n";
+
+    const TAG: &str = "tag\tversion\tcustom\tabstract\tdatatype\tiord\tcrdr\ttlabel\tdoc\n\
+        Assets\tus-gaap/2023\t0\t0\tmonetary\tI\tD\tAssets\tSum of the carrying amounts.\n";
+
+    fn write_archive(path: &Path) {
+        let mut writer = ZipWriter::new(File::create(path).unwrap());
+        for (name, body) in [("sub.txt", SUB), ("num.txt", NUM), ("tag.txt", TAG)] {
+            writer
+                .start_file(name, SimpleFileOptions::default())
+                .unwrap();
+            writer.write_all(body.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn test_dataset_url() {
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        assert_eq!(
+            edgar.financial_statement_dataset_url(2024, Quarter::Q1),
+            "https://www.sec.gov/files/dera/data/financial-statement-data-sets/2024q1.zip"
+        );
+    }
+
+    #[test]
+    fn test_read_and_join_dataset() {
+        let path =
+            std::env::temp_dir().join(format!("edgarkit-dataset-{}.zip", std::process::id()));
+        write_archive(&path);
+        let mut archive = DatasetArchive::open(&path).unwrap();
+
+        let subs: Vec<SubRecord> = archive.submissions().unwrap().map(Result::unwrap).collect();
+        assert_eq!(subs.len(), 2);
+        assert_eq!(subs[0].cik, Cik::new(320193));
+        assert_eq!(subs[0].sic, Some(SicCode::new(3571)));
+        assert_eq!(subs[0].filed, NaiveDate::from_ymd_opt(2024, 2, 2).unwrap());
+        assert!(subs[0].wksi);
+        assert!(subs[0].accepted.is_some());
+        assert!(subs[0].aciks.is_empty());
+        assert_eq!(subs[1].state_inc, None);
+        assert!(subs[1].prevrpt);
+
+        let tags: Vec<TagRecord> = archive.tags().unwrap().map(Result::unwrap).collect();
+        assert_eq!(tags[0].iord.as_deref(), Some("I"));
+        assert!(!tags[0].custom);
+
+        let filings = archive.filings().unwrap();
+        assert_eq!(filings[0].numbers.len(), 2);
+        let assets = filings[0].values("Assets").next().unwrap();
+        assert_eq!(assets.value, Some(353_514_000_000.0));
+        assert_eq!(assets.qtrs, 0);
+        assert_eq!(filings[1].numbers[0].value, None);
+
+        let adsh: AccessionNumber = "0001104659-24-000001".parse().unwrap();
+        let filing = archive.filing(adsh).unwrap().unwrap();
+        assert_eq!(filing.submission.name, "NICHOLAS FINANCIAL INC");
+        assert_eq!(filing.numbers.len(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_bad_row_is_invalid_format() {
+        let header =
+            ["adsh", "tag", "version", "ddate", "qtrs", "uom", "value"].map(str::to_string);
+        let row = Row {
+            header: &header,
+            fields: vec![
+                "0000320193-24-000006",
+                "Assets",
+                "us-gaap/2023",
+                "2023-12-31",
+            ],
+        };
+        assert!(matches!(
+            NumRecord::from_row(&row),
+            Err(EdgarError::InvalidFormat(_))
+        ));
+    }
+}
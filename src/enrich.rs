@@ -0,0 +1,565 @@
+//! Company metadata joins for search hits and index entries.
+//!
+//! Search hits, index entries, and feed items identify companies by CIK, but almost every
+//! consumer wants a name, ticker, exchange, and industry next to each record. [`Enricher`]
+//! performs that join in bulk: it loads the SEC ticker/exchange mapping once, keeps it cached
+//! for the lifetime of the enricher, and attaches a [`CompanyMetadata`] for every CIK a record
+//! references.
+//!
+//! The ticker mapping only covers currently listed companies and carries no SIC code. When
+//! the `filings` feature is enabled, [`Enricher::with_submissions`] fills those gaps from each
+//! company's submissions payload (one extra request per CIK, cached as well). Records that
+//! already carry SIC codes, like search hits, contribute them without extra requests.
+//!
+//! With the `search` feature, `CompanyOperations::peers_by_sic` builds on the same join to
+//! list the companies in an industry.
+//!
+//! Streams of records, such as `search_stream` or the feed watchers `recent_filings_stream`
+//! and `company_filings_stream`, take an [`EnrichStream::enrich`] step that yields each
+//! record as an [`Enriched`] one.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, Enricher, SearchOperations, SearchOptions};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let hits = edgar
+//!     .search_all(SearchOptions::new().with_forms(vec!["8-K".to_string()]))
+//!     .await?;
+//!
+//! let mut enricher = Enricher::new(&edgar);
+//! for enriched in enricher.enrich(hits).await? {
+//!     for company in &enriched.companies {
+//!         println!("{} {:?} {:?}", company.cik, company.name, company.tickers);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Enriching a stream as it is consumed:
+//!
+//! ```ignore
+//! use edgarkit::{EnrichStream, Enricher, SearchOperations};
+//! use futures_util::StreamExt;
+//!
+//! let mut hits = edgar.search_stream(options).enrich(Enricher::new(&edgar));
+//! while let Some(enriched) = hits.next().await {
+//!     let enriched = enriched?;
+//!     println!("{} {:?}", enriched.record.accession(), enriched.companies);
+//! }
+//! ```
+
+use super::Edgar;
+use super::company::CompanyTickerExchange;
+#[cfg(feature = "search")]
+use super::error::EdgarError;
+use super::error::Result;
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+use super::rt::{BoxStream, IntoBoxStream};
+#[cfg(feature = "search")]
+use super::search::{Hit, SearchOptions};
+use super::traits::CompanyOperations;
+#[cfg(feature = "filings")]
+use super::traits::FilingOperations;
+#[cfg(feature = "search")]
+use super::traits::SearchOperations;
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+use futures_util::{Stream, StreamExt, stream};
+#[cfg(feature = "search")]
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+#[cfg(feature = "filings")]
+use std::collections::HashSet;
+
+/// Annual report forms; every operating company files one of them each fiscal year.
+#[cfg(feature = "search")]
+const ANNUAL_REPORT_FORMS: [&str; 3] = ["10-K", "20-F", "40-F"];
+
+/// Peer discovery looks this far back, so every annual filer appears at least once even
+/// when its report is late.
+#[cfg(feature = "search")]
+const PEER_LOOKBACK_DAYS: i64 = 550;
+
+/// Company identity and classification attached to an enriched record.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompanyMetadata {
+    /// Central Index Key.
+    pub cik: u64,
+
+    /// Company name, if the CIK could be resolved.
+    pub name: Option<String>,
+
+    /// Ticker symbols, in the order the SEC lists them.
+    pub tickers: Vec<String>,
+
+    /// Exchange for each ticker (same order as `tickers`).
+    pub exchanges: Vec<String>,
+
+    /// Standard Industrial Classification code.
+    pub sic: Option<String>,
+
+    /// Human-readable SIC description.
+    pub sic_description: Option<String>,
+}
+
+/// A record paired with metadata for every company it references.
+#[derive(Debug, Clone)]
+pub struct Enriched<T> {
+    /// The original record.
+    pub record: T,
+
+    /// Metadata for each CIK in the record, in the record's order.
+    pub companies: Vec<CompanyMetadata>,
+}
+
+/// Records that reference one or more companies by CIK.
+pub trait CikRecord {
+    /// CIKs referenced by this record.
+    fn ciks(&self) -> Vec<u64>;
+
+    /// SIC code the record itself reports for `cik`, if any.
+    fn sic_for(&self, _cik: u64) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(feature = "search")]
+impl CikRecord for super::search::Hit {
+    fn ciks(&self) -> Vec<u64> {
+        self._source.ciks.iter().map(|cik| cik.as_u64()).collect()
+    }
+
+    fn sic_for(&self, cik: u64) -> Option<String> {
+        // `sics` is only positionally aligned with `ciks` when both list every filer.
+        let source = &self._source;
+        if source.sics_raw.len() != source.ciks.len() {
+            return None;
+        }
+        let position = source.ciks.iter().position(|c| c.as_u64() == cik)?;
+        source
+            .sics_raw
+            .get(position)
+            .filter(|s| !s.is_empty())
+            .cloned()
+    }
+}
+
+#[cfg(all(feature = "feeds", feature = "filings"))]
+impl CikRecord for super::feeds::RecentFiling {
+    fn ciks(&self) -> Vec<u64> {
+        vec![self.cik.as_u64()]
+    }
+}
+
+#[cfg(feature = "feeds")]
+impl CikRecord for super::feeds::CompanyUpdate {
+    fn ciks(&self) -> Vec<u64> {
+        vec![self.cik.as_u64()]
+    }
+}
+
+#[cfg(feature = "index")]
+impl CikRecord for crate::parsing::index::IndexEntry {
+    fn ciks(&self) -> Vec<u64> {
+        vec![self.cik]
+    }
+}
+
+impl CikRecord for u64 {
+    fn ciks(&self) -> Vec<u64> {
+        vec![*self]
+    }
+}
+
+/// Batch-resolves CIKs to company metadata, caching every lookup.
+///
+/// Create one enricher per pipeline and reuse it: the ticker mapping is downloaded on the
+/// first call to [`Enricher::enrich`] and every resolved CIK is remembered afterwards.
+#[derive(Debug, Clone)]
+pub struct Enricher {
+    edgar: Edgar,
+    companies: HashMap<u64, CompanyMetadata>,
+    tickers_loaded: bool,
+    #[cfg(feature = "filings")]
+    use_submissions: bool,
+    #[cfg(feature = "filings")]
+    submissions_checked: HashSet<u64>,
+}
+
+impl Enricher {
+    pub fn new(edgar: &Edgar) -> Self {
+        Self {
+            edgar: edgar.clone(),
+            companies: HashMap::new(),
+            tickers_loaded: false,
+            #[cfg(feature = "filings")]
+            use_submissions: false,
+            #[cfg(feature = "filings")]
+            submissions_checked: HashSet::new(),
+        }
+    }
+
+    /// Looks up missing names and SIC codes from each company's submissions payload.
+    ///
+    /// Off by default because it costs one request per distinct CIK.
+    #[cfg(feature = "filings")]
+    pub fn with_submissions(mut self, enabled: bool) -> Self {
+        self.use_submissions = enabled;
+        self
+    }
+
+    /// Attaches company metadata to each record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ticker mapping cannot be downloaded, or (with submissions
+    /// enabled) if a submissions request fails for a reason other than the CIK not existing.
+    pub async fn enrich<T: CikRecord>(&mut self, records: Vec<T>) -> Result<Vec<Enriched<T>>> {
+        self.load_tickers().await?;
+
+        for record in &records {
+            for cik in record.ciks() {
+                let company = self
+                    .companies
+                    .entry(cik)
+                    .or_insert_with(|| CompanyMetadata {
+                        cik,
+                        ..Default::default()
+                    });
+                if company.sic.is_none() {
+                    company.sic = record.sic_for(cik);
+                }
+            }
+        }
+
+        #[cfg(feature = "filings")]
+        if self.use_submissions {
+            self.load_submissions(&records).await?;
+        }
+
+        Ok(records
+            .into_iter()
+            .map(|record| {
+                let companies = record
+                    .ciks()
+                    .into_iter()
+                    .filter_map(|cik| self.companies.get(&cik).cloned())
+                    .collect();
+                Enriched { record, companies }
+            })
+            .collect())
+    }
+
+    /// Returns cached metadata for a CIK without making any requests.
+    pub fn cached(&self, cik: u64) -> Option<&CompanyMetadata> {
+        self.companies.get(&cik)
+    }
+
+    async fn load_tickers(&mut self) -> Result<()> {
+        if self.tickers_loaded {
+            return Ok(());
+        }
+        let tickers = self.edgar.company_tickers_with_exchange().await?;
+        merge_tickers(&mut self.companies, tickers);
+        self.tickers_loaded = true;
+        Ok(())
+    }
+
+    #[cfg(feature = "filings")]
+    async fn load_submissions<T: CikRecord>(&mut self, records: &[T]) -> Result<()> {
+        let mut missing: Vec<u64> = records
+            .iter()
+            .flat_map(|r| r.ciks())
+            .filter(|cik| !self.submissions_checked.contains(cik))
+            .filter(|cik| {
+                self.companies
+                    .get(cik)
+                    .is_none_or(|c| c.name.is_none() || c.sic_description.is_none())
+            })
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+
+        for cik in missing {
+            self.submissions_checked.insert(cik);
+            let submission = match self.edgar.submissions(cik).await {
+                Ok(submission) => submission,
+                Err(crate::EdgarError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            };
+
+            let company = self.companies.entry(cik).or_default();
+            company.cik = cik;
+            company.name.get_or_insert(submission.name);
+            if company.tickers.is_empty() {
+                company.tickers = submission.tickers;
+                company.exchanges = submission.exchanges.into_iter().flatten().collect();
+            }
+            if !submission.sic.is_empty() {
+                company.sic = Some(submission.sic);
+                company.sic_description = Some(submission.sic_description);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Adds an enrichment step to a stream of records.
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+pub trait EnrichStream<'a, T: CikRecord>: Stream<Item = Result<T>> + Sized + 'a {
+    /// Attaches company metadata to each record as it arrives.
+    ///
+    /// `enricher` keeps its cache for the life of the stream, so the ticker mapping is
+    /// downloaded once and each CIK is resolved once. A failed lookup is yielded as an
+    /// error in place of the record; errors from the stream itself pass through.
+    fn enrich(self, enricher: Enricher) -> BoxStream<'a, Result<Enriched<T>>>;
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+impl<'a, T, S> EnrichStream<'a, T> for S
+where
+    T: CikRecord + Send + Sync + 'a,
+    S: Stream<Item = Result<T>> + Send + 'a,
+{
+    fn enrich(self, enricher: Enricher) -> BoxStream<'a, Result<Enriched<T>>> {
+        enriched(self, enricher).into_box_stream()
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+impl<'a, T, S> EnrichStream<'a, T> for S
+where
+    T: CikRecord + 'a,
+    S: Stream<Item = Result<T>> + 'a,
+{
+    fn enrich(self, enricher: Enricher) -> BoxStream<'a, Result<Enriched<T>>> {
+        enriched(self, enricher).into_box_stream()
+    }
+}
+
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+fn enriched<'a, T, S>(stream: S, enricher: Enricher) -> impl Stream<Item = Result<Enriched<T>>> + 'a
+where
+    T: CikRecord + 'a,
+    S: Stream<Item = Result<T>> + 'a,
+{
+    stream::unfold(
+        (Box::pin(stream), enricher),
+        |(mut records, mut enricher)| async move {
+            let item = match records.next().await? {
+                // One record in, one enriched record out.
+                Ok(record) => enricher
+                    .enrich(vec![record])
+                    .await
+                    .map(|mut enriched| enriched.remove(0)),
+                Err(e) => Err(e),
+            };
+            Some((item, (records, enricher)))
+        },
+    )
+}
+
+/// Companies that filed an annual report under `sic_code` within the lookback window.
+///
+/// Filers are found with full-text search's `sic` filter, which reports the SIC code each
+/// filing was made under, and joined with the ticker mapping for names and tickers.
+#[cfg(feature = "search")]
+pub(crate) async fn peers_by_sic(edgar: &Edgar, sic_code: &str) -> Result<Vec<CompanyMetadata>> {
+    let sic = sic_code.trim();
+    if sic.len() != 4 || !sic.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(EdgarError::InvalidFormat(format!(
+            "{:?} is not a four-digit SIC code",
+            sic_code
+        )));
+    }
+
+    let end = chrono::Utc::now().date_naive();
+    let start = end - chrono::Duration::days(PEER_LOOKBACK_DAYS);
+    let options = SearchOptions::new()
+        .with_sic(sic)
+        .with_forms(ANNUAL_REPORT_FORMS.map(String::from).to_vec())
+        .with_date_range(
+            start.format("%Y-%m-%d").to_string(),
+            end.format("%Y-%m-%d").to_string(),
+        );
+    let hits = edgar.search_all(options).await?;
+    let enriched = Enricher::new(edgar).enrich(hits).await?;
+    Ok(collect_peers(sic, enriched))
+}
+
+/// Keeps each filer reported under `sic` once, ordered by name.
+#[cfg(feature = "search")]
+fn collect_peers(sic: &str, enriched: Vec<Enriched<Hit>>) -> Vec<CompanyMetadata> {
+    let mut peers = BTreeMap::new();
+    for Enriched { record, companies } in enriched {
+        for mut company in companies {
+            if peers.contains_key(&company.cik)
+                || record.sic_for(company.cik).as_deref() != Some(sic)
+            {
+                continue;
+            }
+            if company.name.is_none() {
+                company.name = display_name(&record, company.cik);
+            }
+            company.sic = Some(sic.to_string());
+            peers.insert(company.cik, company);
+        }
+    }
+
+    let mut peers: Vec<CompanyMetadata> = peers.into_values().collect();
+    peers.sort_by(|a, b| a.name.cmp(&b.name));
+    peers
+}
+
+/// The company name from a hit's `NAME  (TICKERS)  (CIK ##########)` display name.
+#[cfg(feature = "search")]
+fn display_name(hit: &Hit, cik: u64) -> Option<String> {
+    let suffix = format!("(CIK {:010})", cik);
+    let display = hit
+        ._source
+        .display_names
+        .iter()
+        .find(|name| name.ends_with(&suffix))?;
+    let name = display.split("  (").next()?.trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Folds the ticker/exchange listing into per-CIK metadata (one row per ticker upstream).
+fn merge_tickers(
+    companies: &mut HashMap<u64, CompanyMetadata>,
+    tickers: Vec<CompanyTickerExchange>,
+) {
+    for row in tickers {
+        let company = companies.entry(row.cik).or_insert_with(|| CompanyMetadata {
+            cik: row.cik,
+            ..Default::default()
+        });
+        company.name.get_or_insert(row.name);
+        if !company.tickers.contains(&row.ticker) {
+            company.tickers.push(row.ticker);
+            company.exchanges.push(row.exchange);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "search")]
+    fn hit(ciks: &[&str], sics: &[&str], display_names: &[&str]) -> Hit {
+        serde_json::from_value(serde_json::json!({
+            "_index": "edgar_file",
+            "_id": "0001844419-23-000010:form10-k.htm",
+            "_source": {
+                "ciks": ciks, "display_names": display_names, "sics": sics,
+                "sequence": 1, "root_forms": ["10-K"], "file_date": "2023-03-31",
+                "biz_states": [], "form": "10-K", "adsh": "0001844419-23-000010",
+                "film_num": [], "biz_locations": [], "file_type": "10-K", "inc_states": []
+            }
+        }))
+        .unwrap()
+    }
+
+    fn row(cik: u64, ticker: &str, name: &str, exchange: &str) -> CompanyTickerExchange {
+        CompanyTickerExchange {
+            cik,
+            ticker: ticker.to_string(),
+            name: name.to_string(),
+            exchange: exchange.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_merge_tickers_groups_by_cik() {
+        let mut companies = HashMap::new();
+        merge_tickers(
+            &mut companies,
+            vec![
+                row(1652044, "GOOGL", "Alphabet Inc.", "Nasdaq"),
+                row(1652044, "GOOG", "Alphabet Inc.", "Nasdaq"),
+                row(320193, "AAPL", "Apple Inc.", "Nasdaq"),
+            ],
+        );
+
+        let alphabet = &companies[&1652044];
+        assert_eq!(alphabet.name.as_deref(), Some("Alphabet Inc."));
+        assert_eq!(alphabet.tickers, vec!["GOOGL", "GOOG"]);
+        assert_eq!(alphabet.exchanges, vec!["Nasdaq", "Nasdaq"]);
+        assert_eq!(companies.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_enrich_uses_cached_mapping() {
+        let mut enricher = Enricher::new(&Edgar::new("test_agent example@example.com").unwrap());
+        merge_tickers(
+            &mut enricher.companies,
+            vec![row(320193, "AAPL", "Apple Inc.", "Nasdaq")],
+        );
+        enricher.tickers_loaded = true;
+
+        let enriched = enricher.enrich(vec![320193u64, 999]).await.unwrap();
+        assert_eq!(enriched[0].companies[0].tickers, vec!["AAPL"]);
+        assert_eq!(enriched[1].companies[0].name, None);
+        assert!(enricher.cached(999).is_some());
+    }
+
+    #[cfg(feature = "search")]
+    #[test]
+    fn test_collect_peers_dedupes_filers() {
+        let enriched = vec![
+            Enriched {
+                record: hit(
+                    &["0001844419"],
+                    &["6770"],
+                    &["Maquia Capital Acquisition Corp  (MAQC, MAQCU)  (CIK 0001844419)"],
+                ),
+                companies: vec![CompanyMetadata {
+                    cik: 1844419,
+                    ..Default::default()
+                }],
+            },
+            Enriched {
+                record: hit(
+                    &["0000320193"],
+                    &["3571"],
+                    &["Apple Inc.  (AAPL)  (CIK 0000320193)"],
+                ),
+                companies: vec![CompanyMetadata {
+                    cik: 320193,
+                    name: Some("Apple Inc.".to_string()),
+                    ..Default::default()
+                }],
+            },
+            Enriched {
+                record: hit(
+                    &["0001844419"],
+                    &["6770"],
+                    &["Maquia Capital Acquisition Corp  (MAQC, MAQCU)  (CIK 0001844419)"],
+                ),
+                companies: vec![CompanyMetadata {
+                    cik: 1844419,
+                    ..Default::default()
+                }],
+            },
+        ];
+
+        let peers = collect_peers("6770", enriched);
+        assert_eq!(peers.len(), 1);
+        assert_eq!(
+            peers[0].name.as_deref(),
+            Some("Maquia Capital Acquisition Corp")
+        );
+        assert_eq!(peers[0].sic.as_deref(), Some("6770"));
+    }
+}
@@ -0,0 +1,185 @@
+//! Company identifiers that resolve to a CIK.
+//!
+//! The filing, company, and fund APIs take `impl Into<EntityId>`, so a company can be named
+//! the way the caller already knows it: a CIK, a ticker symbol, or a registered name.
+//! Tickers and names are looked up in the SEC ticker file (requires the `company`
+//! feature), which is downloaded once and cached for `EdgarConfig::ticker_cache_ttl`, so
+//! repeated lookups do not cost extra requests.
+//!
+//! Methods that look up one company take `impl Into<EntityId>`. Methods that key their
+//! results by CIK (the `*_for_ciks` fan-outs, `latest_across`) or never make a request
+//! (`company_by_cik`, `CorporateHistory`, `PollSchedule`) take `impl Into<Cik>`, which
+//! accepts a [`Cik`] or a `u64`.
+//!
+//! ```rust
+//! use edgarkit::{Cik, EntityId};
+//!
+//! assert_eq!(EntityId::from(320193), EntityId::Cik(Cik::new(320193)));
+//! assert_eq!(EntityId::from("0000320193"), EntityId::Cik(Cik::new(320193)));
+//! assert_eq!(EntityId::from("AAPL"), EntityId::Ticker("AAPL".to_string()));
+//! assert_eq!(
+//!     EntityId::name("Apple Inc."),
+//!     EntityId::Name("Apple Inc.".to_string())
+//! );
+//! ```
+
+use super::Edgar;
+use super::cik::Cik;
+#[cfg(feature = "company")]
+use super::error::ErrorContext;
+use super::error::{EdgarError, Result};
+#[cfg(feature = "company")]
+use super::names::CikCandidate;
+#[cfg(feature = "company")]
+use super::traits::CompanyOperations;
+use std::fmt;
+
+/// A company named by CIK, ticker symbol, or registered name.
+///
+/// Strings convert to [`EntityId::Cik`] when they parse as a CIK (`320193`, `0000320193`,
+/// `CIK0000320193`) and to [`EntityId::Ticker`] otherwise. Names have to be asked for
+/// explicitly with [`EntityId::name`], since many tickers are also plausible names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EntityId {
+    Cik(Cik),
+    /// Exchange ticker symbol, e.g. `AAPL` or `BRK-B`
+    Ticker(String),
+    /// Registered company name; resolves only to an exact or unique match in the ticker file
+    Name(String),
+}
+
+impl EntityId {
+    pub fn ticker(ticker: impl Into<String>) -> Self {
+        EntityId::Ticker(ticker.into())
+    }
+
+    pub fn name(name: impl Into<String>) -> Self {
+        EntityId::Name(name.into())
+    }
+}
+
+impl From<Cik> for EntityId {
+    fn from(cik: Cik) -> Self {
+        EntityId::Cik(cik)
+    }
+}
+
+impl From<&Cik> for EntityId {
+    fn from(cik: &Cik) -> Self {
+        EntityId::Cik(*cik)
+    }
+}
+
+/// The CIK is checked against EDGAR's ten-digit limit when the entity is resolved.
+impl From<u64> for EntityId {
+    fn from(cik: u64) -> Self {
+        EntityId::Cik(Cik::new(cik))
+    }
+}
+
+impl From<&str> for EntityId {
+    fn from(id: &str) -> Self {
+        match id.parse::<Cik>() {
+            Ok(cik) => EntityId::Cik(cik),
+            Err(_) => EntityId::Ticker(id.trim().to_string()),
+        }
+    }
+}
+
+impl From<String> for EntityId {
+    fn from(id: String) -> Self {
+        EntityId::from(id.as_str())
+    }
+}
+
+impl From<&String> for EntityId {
+    fn from(id: &String) -> Self {
+        EntityId::from(id.as_str())
+    }
+}
+
+impl fmt::Display for EntityId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityId::Cik(cik) => write!(f, "CIK {}", cik),
+            EntityId::Ticker(ticker) => write!(f, "ticker {}", ticker),
+            EntityId::Name(name) => write!(f, "{:?}", name),
+        }
+    }
+}
+
+impl Edgar {
+    /// Resolves a CIK, ticker, or company name to a [`Cik`].
+    ///
+    /// CIKs are checked against the ten-digit limit. Tickers are looked up like `company_cik`. Names are ranked
+    /// with `company_cik_by_name` and resolve to the company whose normalized title equals
+    /// the name, or to the only candidate if none does; both use the cached ticker file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` for CIKs longer than ten digits, and for tickers
+    /// and names when the `company` feature is disabled or ticker resolution was turned off
+    /// with [`EdgarConfig::with_ticker_resolution`](crate::EdgarConfig::with_ticker_resolution);
+    /// `EdgarError::TickerNotFound` for unknown tickers, `EdgarError::NotFound` for names
+    /// that match no company, and `EdgarError::AmbiguousName` for names that match several.
+    pub async fn resolve_entity(&self, id: impl Into<EntityId>) -> Result<Cik> {
+        let id = id.into();
+        let EntityId::Cik(cik) = id else {
+            return self.lookup_entity(id).await;
+        };
+        Cik::checked(cik.as_u64())
+    }
+
+    #[cfg(feature = "company")]
+    async fn lookup_entity(&self, id: EntityId) -> Result<Cik> {
+        if !self.resolve_tickers {
+            return Err(unresolvable(&id));
+        }
+        match id {
+            EntityId::Cik(cik) => Ok(cik),
+            EntityId::Ticker(ticker) => Ok(Cik::new(self.company_cik(&ticker).await?)),
+            EntityId::Name(name) => {
+                let candidates = self.company_cik_by_name(&name).await?;
+                unique_candidate(name, &candidates)
+            }
+        }
+    }
+
+    #[cfg(not(feature = "company"))]
+    async fn lookup_entity(&self, id: EntityId) -> Result<Cik> {
+        Err(unresolvable(&id))
+    }
+}
+
+/// Picks the one company whose normalized title equals `name`, or the only candidate when
+/// none does. Anything else is ambiguous.
+#[cfg(feature = "company")]
+fn unique_candidate(name: String, candidates: &[CikCandidate]) -> Result<Cik> {
+    let exact: Vec<&CikCandidate> = candidates.iter().filter(|c| c.score >= 1.0).collect();
+    let matches = if exact.is_empty() {
+        candidates.iter().collect()
+    } else {
+        exact
+    };
+    match matches.as_slice() {
+        [] => Err(EdgarError::not_found(ErrorContext::for_lookup(format!(
+            "company name {:?}",
+            name
+        )))),
+        [only] => Ok(Cik::new(only.cik)),
+        several => Err(EdgarError::AmbiguousName {
+            name,
+            candidates: several
+                .iter()
+                .map(|c| (Cik::new(c.cik), c.name.clone()))
+                .collect(),
+        }),
+    }
+}
+
+fn unresolvable(id: &EntityId) -> EdgarError {
+    EdgarError::InvalidFormat(format!(
+        "{} cannot be resolved to a CIK: ticker resolution is unavailable",
+        id
+    ))
+}
@@ -7,10 +7,128 @@
 //! Errors are designed to be informative, including context like URL previews and HTTP
 //! status codes to aid in debugging. The error types use `thiserror` for clean `Display`
 //! implementations and proper `Error` trait support.
+//!
+//! [`EdgarError::context`] collects the request URL, status, endpoint category, and the CIK
+//! and accession number involved from whichever variant carries them.
 
+use crate::cik::Cik;
+use crate::metrics::endpoint_of;
+use std::fmt;
 use std::string::FromUtf8Error;
 use thiserror::Error;
 
+/// What a failed operation was fetching.
+///
+/// Fields are `None` when they do not apply or are unknown, for example the status of an
+/// error raised before a request was sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorContext {
+    /// Requested URL, or the final URL after redirects
+    pub url: Option<String>,
+    /// HTTP status of the response
+    pub status: Option<u16>,
+    /// Endpoint category of the URL, as reported by [`endpoint_of`]
+    pub endpoint: Option<&'static str>,
+    pub cik: Option<Cik>,
+    /// Accession number in dashed form, e.g. `0000320193-23-000106`
+    pub accession: Option<String>,
+    /// What was looked up when there is no request URL, e.g. `company name "Apple"`
+    pub lookup: Option<String>,
+}
+
+impl ErrorContext {
+    /// Context for a request to `url`.
+    ///
+    /// The CIK and accession number are read from archive paths
+    /// (`/Archives/edgar/data/320193/000032019323000106/...`) and submissions file names
+    /// (`CIK0000320193.json`).
+    pub fn for_url(url: &str, status: Option<u16>) -> Self {
+        let path = url.split(['?', '#']).next().unwrap_or(url);
+        let mut segments = path.split('/');
+        let mut context = ErrorContext {
+            url: Some(url.to_string()),
+            status,
+            endpoint: Some(endpoint_of(url)),
+            ..Default::default()
+        };
+
+        while let Some(segment) = segments.next() {
+            if segment == "data"
+                && let Some(cik) = segments.next().and_then(|s| s.parse::<u64>().ok())
+            {
+                context.cik = Some(Cik::new(cik));
+                context.accession = segments.next().and_then(dashed_accession);
+            } else if let Some(cik) = segment
+                .strip_prefix("CIK")
+                .and_then(|rest| rest.get(..10))
+                .and_then(|digits| digits.parse::<u64>().ok())
+            {
+                context.cik = Some(Cik::new(cik));
+            }
+        }
+        context
+    }
+
+    /// Context for a lookup in already downloaded data, such as a name or class ID.
+    pub fn for_lookup(lookup: impl Into<String>) -> Self {
+        ErrorContext {
+            lookup: Some(lookup.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Context for an operation on one company's filing, without a request URL.
+    pub fn for_filing(cik: Cik, accession: Option<&str>) -> Self {
+        ErrorContext {
+            cik: Some(cik),
+            accession: accession.map(str::to_string),
+            ..Default::default()
+        }
+    }
+}
+
+/// Formats the non-empty fields, e.g. `404 for https://... (CIK 320193)`.
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut separator = "";
+        if let Some(status) = self.status {
+            write!(f, "{}", status)?;
+            separator = " ";
+        }
+        if let Some(url) = &self.url {
+            write!(f, "{}for {}", separator, url)?;
+            separator = " ";
+        }
+        if let Some(lookup) = &self.lookup {
+            write!(f, "{}for {}", separator, lookup)?;
+            separator = " ";
+        }
+        match (self.cik, &self.accession) {
+            (Some(cik), Some(accession)) => {
+                write!(f, "{}(CIK {}, accession {})", separator, cik, accession)
+            }
+            (Some(cik), None) => write!(f, "{}(CIK {})", separator, cik),
+            (None, Some(accession)) => write!(f, "{}(accession {})", separator, accession),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Turns an accession folder name (18 digits) into `##########-##-######`.
+fn dashed_accession(folder: &str) -> Option<String> {
+    (folder.len() == 18 && folder.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| format!("{}-{}-{}", &folder[..10], &folder[10..12], &folder[12..]))
+}
+
+fn suffix(context: &ErrorContext) -> String {
+    let text = context.to_string();
+    if text.is_empty() {
+        text
+    } else {
+        format!(": {}", text)
+    }
+}
+
 /// Comprehensive error type for all EdgarKit operations.
 ///
 /// This enum covers the various ways that operations can fail when interacting with
@@ -18,8 +136,9 @@ use thiserror::Error;
 /// HTTP status codes, parsing problems, configuration mistakes, or validation failures.
 ///
 /// Each variant includes relevant context to help diagnose issues. For example,
-/// `InvalidResponse` includes a preview of the response content, and `UnexpectedContentType`
-/// shows both the expected and actual content types along with a content preview.
+/// `UnexpectedStatus` includes a preview of the response content, and `UnexpectedContentType`
+/// shows both the expected and actual content types along with a content preview. Preview
+/// length and redaction are controlled by [`PreviewPolicy`](crate::PreviewPolicy).
 ///
 /// # Examples
 ///
@@ -28,10 +147,10 @@ use thiserror::Error;
 /// # use edgarkit::{Edgar, EdgarError, FilingOperations};
 /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
 /// # let edgar = Edgar::new("app contact@example.com")?;
-/// match edgar.get_recent_filings("0001234567").await {
+/// match edgar.get_recent_filings(1234567).await {
 ///     Ok(filings) => println!("Found {} filings", filings.len()),
-///     Err(EdgarError::NotFound) => println!("Company not found"),
-///     Err(EdgarError::RateLimitExceeded) => println!("Rate limited, try again later"),
+///     Err(EdgarError::NotFound(context)) => println!("Company not found: {:?}", context.url),
+///     Err(EdgarError::RateLimitExceeded(_)) => println!("Rate limited, try again later"),
 ///     Err(e) => println!("Error: {}", e),
 /// }
 /// # Ok(())
@@ -42,14 +161,14 @@ pub enum EdgarError {
     #[error("HTTP request failed: {0}")]
     RequestError(#[from] reqwest::Error),
 
-    #[error("Resource not found")]
-    NotFound,
+    #[error("Resource not found{}", suffix(.0))]
+    NotFound(Box<ErrorContext>),
 
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
 
-    #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    #[error("Rate limit exceeded{}", suffix(.0))]
+    RateLimitExceeded(Box<ErrorContext>),
 
     #[error("Invalid year: must be 1994 or greater")]
     InvalidYear,
@@ -69,6 +188,13 @@ pub enum EdgarError {
     #[error("Ticker not found")]
     TickerNotFound,
 
+    #[error("Company name {name:?} is ambiguous: {} companies match", candidates.len())]
+    AmbiguousName {
+        name: String,
+        /// CIKs and registered titles of the matching companies, best match first
+        candidates: Vec<(Cik, String)>,
+    },
+
     #[error("File error: {0}")]
     FileError(#[from] std::io::Error),
 
@@ -81,6 +207,16 @@ pub enum EdgarError {
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
+    #[error("Archive error: {0}")]
+    ArchiveError(String),
+
+    #[error("Sink closed: the consumer stopped receiving")]
+    SinkClosed,
+
+    #[cfg(feature = "sqlite")]
+    #[error("Database error: {0}")]
+    Database(#[from] rusqlite::Error),
+
     #[cfg(any(feature = "atom", feature = "rss"))]
     #[error("XML parsing error: {0}")]
     Xml(#[from] quick_xml::Error),
@@ -109,7 +245,198 @@ pub enum EdgarError {
         expected_pattern: String, // e.g., "application/json"
         got_content_type: String,
         content_preview: String, // Add a preview of the content
+        /// Full response body, kept only when `PreviewPolicy::keep_body` is enabled
+        body: Option<String>,
+    },
+
+    #[error(
+        "Redirect ({status}) from {url} to {location} was not followed: blocked by the redirect policy"
+    )]
+    RedirectBlocked {
+        status: u16,
+        /// URL that answered with the redirect
+        url: String,
+        /// Absolute redirect target from the `Location` header
+        location: String,
+    },
+
+    #[error("Full-text search failed ({status}) for URL: {url}: {message}")]
+    SearchError {
+        status: u16,
+        url: String,
+        /// Reason reported in the search service's JSON error payload
+        message: String,
+    },
+
+    #[error("Unexpected status code: {status} for URL: {url}. Response preview: {preview}")]
+    UnexpectedStatus {
+        status: u16,
+        url: String,
+        /// Redacted, size-capped preview of the response body
+        preview: String,
+        /// Full response body, kept only when `PreviewPolicy::keep_body` is enabled
+        body: Option<String>,
     },
 }
 
+impl EdgarError {
+    pub(crate) fn not_found(context: ErrorContext) -> Self {
+        EdgarError::NotFound(Box::new(context))
+    }
+
+    pub(crate) fn rate_limited(context: ErrorContext) -> Self {
+        EdgarError::RateLimitExceeded(Box::new(context))
+    }
+
+    /// A copy of the error for callers that waited on a request someone else made.
+    ///
+    /// Errors wrapping another crate's error cannot be cloned; they are copied as
+    /// `InvalidResponse` with the same message, except I/O errors, which keep their kind.
+    pub(crate) fn duplicate(&self) -> Self {
+        match self {
+            EdgarError::NotFound(context) => EdgarError::NotFound(context.clone()),
+            EdgarError::RateLimitExceeded(context) => {
+                EdgarError::RateLimitExceeded(context.clone())
+            }
+            EdgarError::InvalidResponse(message) => EdgarError::InvalidResponse(message.clone()),
+            EdgarError::InvalidYear => EdgarError::InvalidYear,
+            EdgarError::InvalidQuarter => EdgarError::InvalidQuarter,
+            EdgarError::InvalidMonth => EdgarError::InvalidMonth,
+            EdgarError::InvalidDay => EdgarError::InvalidDay,
+            EdgarError::InvalidXBRLYear => EdgarError::InvalidXBRLYear,
+            EdgarError::TickerNotFound => EdgarError::TickerNotFound,
+            EdgarError::AmbiguousName { name, candidates } => EdgarError::AmbiguousName {
+                name: name.clone(),
+                candidates: candidates.clone(),
+            },
+            EdgarError::FileError(e) => {
+                EdgarError::FileError(std::io::Error::new(e.kind(), e.to_string()))
+            }
+            EdgarError::XmlError(message) => EdgarError::XmlError(message.clone()),
+            EdgarError::ConfigError(message) => EdgarError::ConfigError(message.clone()),
+            EdgarError::ArchiveError(message) => EdgarError::ArchiveError(message.clone()),
+            EdgarError::SinkClosed => EdgarError::SinkClosed,
+            EdgarError::InvalidFormat(message) => EdgarError::InvalidFormat(message.clone()),
+            EdgarError::ValueConversion(message) => EdgarError::ValueConversion(message.clone()),
+            EdgarError::UnexpectedContentType {
+                url,
+                expected_pattern,
+                got_content_type,
+                content_preview,
+                body,
+            } => EdgarError::UnexpectedContentType {
+                url: url.clone(),
+                expected_pattern: expected_pattern.clone(),
+                got_content_type: got_content_type.clone(),
+                content_preview: content_preview.clone(),
+                body: body.clone(),
+            },
+            EdgarError::RedirectBlocked {
+                status,
+                url,
+                location,
+            } => EdgarError::RedirectBlocked {
+                status: *status,
+                url: url.clone(),
+                location: location.clone(),
+            },
+            EdgarError::SearchError {
+                status,
+                url,
+                message,
+            } => EdgarError::SearchError {
+                status: *status,
+                url: url.clone(),
+                message: message.clone(),
+            },
+            EdgarError::UnexpectedStatus {
+                status,
+                url,
+                preview,
+                body,
+            } => EdgarError::UnexpectedStatus {
+                status: *status,
+                url: url.clone(),
+                preview: preview.clone(),
+                body: body.clone(),
+            },
+            other => EdgarError::InvalidResponse(other.to_string()),
+        }
+    }
+
+    /// Request URL, status, endpoint, CIK, and accession number involved in the failure.
+    ///
+    /// Variants that carry none of these return an empty context.
+    pub fn context(&self) -> ErrorContext {
+        match self {
+            EdgarError::NotFound(context) | EdgarError::RateLimitExceeded(context) => {
+                (**context).clone()
+            }
+            EdgarError::UnexpectedStatus { status, url, .. }
+            | EdgarError::SearchError { status, url, .. }
+            | EdgarError::RedirectBlocked { status, url, .. } => {
+                ErrorContext::for_url(url, Some(*status))
+            }
+            EdgarError::UnexpectedContentType { url, .. } => ErrorContext::for_url(url, None),
+            EdgarError::RequestError(e) => match e.url() {
+                Some(url) => ErrorContext::for_url(url.as_str(), e.status().map(|s| s.as_u16())),
+                None => ErrorContext::default(),
+            },
+            _ => ErrorContext::default(),
+        }
+    }
+
+    /// Full response body attached to the error, if the client was configured to keep it.
+    ///
+    /// See [`PreviewPolicy`](crate::PreviewPolicy). The body is never included in the
+    /// error's `Display` output.
+    pub fn response_body(&self) -> Option<&str> {
+        match self {
+            EdgarError::UnexpectedContentType { body, .. }
+            | EdgarError::UnexpectedStatus { body, .. } => body.as_deref(),
+            _ => None,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, EdgarError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_from_url() {
+        let error = EdgarError::not_found(ErrorContext::for_url(
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/primary_doc.xml",
+            Some(404),
+        ));
+        let context = error.context();
+        assert_eq!(context.endpoint, Some("archives"));
+        assert_eq!(context.cik, Some(Cik::new(320193)));
+        assert_eq!(context.accession.as_deref(), Some("0000320193-23-000106"));
+        assert_eq!(
+            error.to_string(),
+            "Resource not found: 404 for https://www.sec.gov/Archives/edgar/data/320193/\
+             000032019323000106/primary_doc.xml (CIK 320193, accession 0000320193-23-000106)"
+        );
+
+        let context = ErrorContext::for_url(
+            "https://data.sec.gov/submissions/CIK0000789019.json",
+            Some(429),
+        );
+        assert_eq!(context.endpoint, Some("submissions"));
+        assert_eq!(context.cik, Some(Cik::new(789019)));
+        assert_eq!(context.accession, None);
+
+        assert_eq!(EdgarError::InvalidYear.context(), ErrorContext::default());
+        assert_eq!(
+            EdgarError::not_found(ErrorContext::default()).to_string(),
+            "Resource not found"
+        );
+        assert_eq!(
+            EdgarError::not_found(ErrorContext::for_lookup("class C000012345")).to_string(),
+            "Resource not found: for class C000012345"
+        );
+    }
+}
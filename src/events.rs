@@ -0,0 +1,391 @@
+//! Corporate events reconstructed from submissions metadata.
+//!
+//! A submissions payload describes a company as it is today plus the names it used before.
+//! Renames can be read straight from that list with [`Submission::corporate_events`]. Ticker
+//! and exchange changes leave no trace in the payload, so they can only be noticed by
+//! comparing it with an earlier copy.
+//!
+//! [`CorporateHistory`] does that comparison. Call [`CorporateHistory::record`] with the
+//! payloads of the companies you track, or enable
+//! [`EdgarConfig::with_corporate_history`](crate::EdgarConfig::with_corporate_history) to
+//! have the client record every submissions payload it downloads; then
+//! [`Edgar::corporate_events`] (or [`Company::corporate_events`](crate::Company::corporate_events))
+//! returns renames plus any ticker or exchange changes seen while the client was running.
+//! Recording is off by default because the client's history grows with every company it
+//! fetches. The history serializes with serde; save it with [`Edgar::corporate_history`] and
+//! load it into the next run with [`Edgar::restore_corporate_history`] to keep detecting
+//! changes across restarts.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{CorporateEventKind, Edgar};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let meta = edgar.company("META").await?;
+//! for event in meta.corporate_events().await? {
+//!     if let CorporateEventKind::Renamed { from, to } = &event.kind {
+//!         println!("{}: {} -> {}", event.date, from, to);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Edgar;
+use super::cik::Cik;
+use super::error::Result;
+use super::filings::Submission;
+use crate::parsing::utils::parse_edgar_date;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What changed about a company.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CorporateEventKind {
+    /// The conformed company name changed.
+    Renamed { from: String, to: String },
+
+    /// A ticker appeared in the submissions metadata.
+    TickerAdded {
+        ticker: String,
+        exchange: Option<String>,
+    },
+
+    /// A ticker disappeared from the submissions metadata.
+    TickerRemoved {
+        ticker: String,
+        exchange: Option<String>,
+    },
+
+    /// A ticker moved to a different exchange.
+    ExchangeChanged {
+        ticker: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+}
+
+/// A dated change to a company's name, tickers, or exchanges.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorporateEvent {
+    pub cik: Cik,
+
+    /// When the change took effect. Renames use the end date of the former name; changes
+    /// found by comparing snapshots use the date the change was first observed.
+    pub date: NaiveDate,
+
+    pub kind: CorporateEventKind,
+}
+
+/// The parts of a submissions payload that are compared between observations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompanySnapshot {
+    pub cik: Cik,
+
+    /// Date the submissions payload was downloaded.
+    pub observed: NaiveDate,
+
+    pub name: String,
+
+    /// Tickers paired with their exchange, in submissions order.
+    pub listings: Vec<(String, Option<String>)>,
+}
+
+impl CompanySnapshot {
+    /// Captures the name and listings of `submission` as observed on `observed`.
+    pub fn new(submission: &Submission, observed: NaiveDate) -> Result<Self> {
+        let listings = submission
+            .tickers
+            .iter()
+            .enumerate()
+            .map(|(i, ticker)| {
+                let exchange = submission.exchanges.get(i).cloned().flatten();
+                (ticker.clone(), exchange)
+            })
+            .collect();
+        Ok(Self {
+            cik: submission.cik.parse()?,
+            observed,
+            name: submission.name.clone(),
+            listings,
+        })
+    }
+
+    /// Events that explain the differences between this snapshot and a `newer` one.
+    pub fn diff(&self, newer: &CompanySnapshot) -> Vec<CorporateEvent> {
+        let event = |kind| CorporateEvent {
+            cik: newer.cik,
+            date: newer.observed,
+            kind,
+        };
+        let mut events = Vec::new();
+
+        if self.name != newer.name {
+            events.push(event(CorporateEventKind::Renamed {
+                from: self.name.clone(),
+                to: newer.name.clone(),
+            }));
+        }
+        for (ticker, exchange) in &self.listings {
+            match newer.listings.iter().find(|(t, _)| t == ticker) {
+                None => events.push(event(CorporateEventKind::TickerRemoved {
+                    ticker: ticker.clone(),
+                    exchange: exchange.clone(),
+                })),
+                Some((_, to)) if to != exchange => {
+                    events.push(event(CorporateEventKind::ExchangeChanged {
+                        ticker: ticker.clone(),
+                        from: exchange.clone(),
+                        to: to.clone(),
+                    }))
+                }
+                Some(_) => {}
+            }
+        }
+        for (ticker, exchange) in &newer.listings {
+            if !self.listings.iter().any(|(t, _)| t == ticker) {
+                events.push(event(CorporateEventKind::TickerAdded {
+                    ticker: ticker.clone(),
+                    exchange: exchange.clone(),
+                }));
+            }
+        }
+        events
+    }
+}
+
+impl Submission {
+    /// Renames recorded in `former_names`, oldest first.
+    ///
+    /// Each former name is followed by the next one to go out of use, and the last by the
+    /// current name. Former names without a parseable end date are skipped.
+    pub fn corporate_events(&self) -> Vec<CorporateEvent> {
+        let Ok(cik) = self.cik.parse::<Cik>() else {
+            return Vec::new();
+        };
+        let mut former: Vec<(NaiveDate, &str)> = self
+            .former_names
+            .iter()
+            .filter_map(|f| Some((parse_edgar_date(f.to.get(..10)?)?, f.name.as_str())))
+            .collect();
+        former.sort();
+
+        let successors = former
+            .iter()
+            .skip(1)
+            .map(|(_, name)| *name)
+            .chain(std::iter::once(self.name.as_str()));
+        former
+            .iter()
+            .zip(successors)
+            .filter(|((_, from), to)| from != to)
+            .map(|((date, from), to)| CorporateEvent {
+                cik,
+                date: *date,
+                kind: CorporateEventKind::Renamed {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                },
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CompanyHistory {
+    latest: CompanySnapshot,
+    events: Vec<CorporateEvent>,
+}
+
+/// Corporate events per company, built up from every submissions payload recorded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CorporateHistory {
+    companies: BTreeMap<u64, CompanyHistory>,
+}
+
+impl CorporateHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a submissions payload downloaded today. See [`record_at`](Self::record_at).
+    pub fn record(&mut self, submission: &Submission) -> Result<Vec<CorporateEvent>> {
+        self.record_at(submission, Utc::now().date_naive())
+    }
+
+    /// Records a submissions payload observed on `observed` and returns the events that
+    /// were not known before.
+    ///
+    /// The first payload for a company contributes its renames; later ones add anything
+    /// that changed since the previous observation. A rename noticed by comparison is not
+    /// reported again once it shows up in `former_names`.
+    pub fn record_at(
+        &mut self,
+        submission: &Submission,
+        observed: NaiveDate,
+    ) -> Result<Vec<CorporateEvent>> {
+        let snapshot = CompanySnapshot::new(submission, observed)?;
+        let mut candidates = submission.corporate_events();
+
+        let history = match self.companies.get_mut(&snapshot.cik.as_u64()) {
+            Some(history) => {
+                candidates.extend(history.latest.diff(&snapshot));
+                history.latest = snapshot;
+                history
+            }
+            None => self
+                .companies
+                .entry(snapshot.cik.as_u64())
+                .or_insert(CompanyHistory {
+                    latest: snapshot,
+                    events: Vec::new(),
+                }),
+        };
+
+        let mut added = Vec::new();
+        for event in candidates {
+            let known = history.events.iter().any(|e| same_change(e, &event));
+            if !known && !added.iter().any(|e| same_change(e, &event)) {
+                added.push(event);
+            }
+        }
+        history.events.extend(added.iter().cloned());
+        history.events.sort_by_key(|e| e.date);
+        Ok(added)
+    }
+
+    /// Known events for `cik`, oldest first.
+    pub fn events(&self, cik: impl Into<Cik>) -> &[CorporateEvent] {
+        self.companies
+            .get(&cik.into().as_u64())
+            .map(|history| history.events.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The most recent snapshot recorded for `cik`.
+    pub fn latest(&self, cik: impl Into<Cik>) -> Option<&CompanySnapshot> {
+        self.companies
+            .get(&cik.into().as_u64())
+            .map(|history| &history.latest)
+    }
+}
+
+/// Renames are matched on names alone, since observed and recorded dates differ.
+fn same_change(a: &CorporateEvent, b: &CorporateEvent) -> bool {
+    match (&a.kind, &b.kind) {
+        (
+            CorporateEventKind::Renamed { from, to },
+            CorporateEventKind::Renamed {
+                from: other_from,
+                to: other_to,
+            },
+        ) => from == other_from && to == other_to,
+        _ => a == b,
+    }
+}
+
+impl Edgar {
+    /// Corporate events known for `cik` from submissions this client has recorded.
+    ///
+    /// Makes no request; returns an empty list unless the company's submissions were fetched
+    /// with [`EdgarConfig::with_corporate_history`](crate::EdgarConfig::with_corporate_history)
+    /// enabled, or are part of a restored history. Use
+    /// [`Company::corporate_events`](crate::Company::corporate_events) to fetch them first.
+    pub fn corporate_events(&self, cik: impl Into<Cik>) -> Vec<CorporateEvent> {
+        self.history
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .events(cik)
+            .to_vec()
+    }
+
+    /// A copy of the client's corporate history, for persisting between runs.
+    pub fn corporate_history(&self) -> CorporateHistory {
+        self.history
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Replaces the client's corporate history, for example with one saved by a previous
+    /// run. Shared with clones of this client.
+    pub fn restore_corporate_history(&self, history: CorporateHistory) {
+        *self.history.lock().unwrap_or_else(|e| e.into_inner()) = history;
+    }
+
+    /// Records a downloaded submissions payload in the client's history, if enabled.
+    pub(crate) fn record_submission(&self, submission: &Submission) {
+        if !self.record_history {
+            return;
+        }
+        let mut history = self.history.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = history.record(submission) {
+            tracing::debug!("Not recording submissions for {}: {}", submission.cik, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(name: &str, listings: &[(&str, &str)], observed: NaiveDate) -> CompanySnapshot {
+        CompanySnapshot {
+            cik: Cik::from(1326801),
+            observed,
+            name: name.to_string(),
+            listings: listings
+                .iter()
+                .map(|(t, e)| (t.to_string(), Some(e.to_string())))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let before = snapshot(
+            "Facebook Inc",
+            &[("FB", "Nasdaq")],
+            NaiveDate::from_ymd_opt(2021, 10, 1).unwrap(),
+        );
+        let after = snapshot(
+            "Meta Platforms, Inc.",
+            &[("META", "Nasdaq")],
+            NaiveDate::from_ymd_opt(2022, 6, 10).unwrap(),
+        );
+
+        let kinds: Vec<CorporateEventKind> =
+            before.diff(&after).into_iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                CorporateEventKind::Renamed {
+                    from: "Facebook Inc".to_string(),
+                    to: "Meta Platforms, Inc.".to_string(),
+                },
+                CorporateEventKind::TickerRemoved {
+                    ticker: "FB".to_string(),
+                    exchange: Some("Nasdaq".to_string()),
+                },
+                CorporateEventKind::TickerAdded {
+                    ticker: "META".to_string(),
+                    exchange: Some("Nasdaq".to_string()),
+                },
+            ]
+        );
+
+        let moved = snapshot("Meta Platforms, Inc.", &[("META", "NYSE")], after.observed);
+        assert_eq!(
+            after.diff(&moved)[0].kind,
+            CorporateEventKind::ExchangeChanged {
+                ticker: "META".to_string(),
+                from: Some("Nasdaq".to_string()),
+                to: Some("NYSE".to_string()),
+            }
+        );
+        assert!(moved.diff(&moved).is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+//! CSV export for tabular results.
+//!
+//! Filing lists, index entries, frames, and company facts all end up in spreadsheets and
+//! DataFrames sooner or later. [`ToCsv`] writes them as RFC 4180 CSV with a header row, one
+//! row per filing, entry, or data point. Company facts are flattened to one row per reported
+//! value, sorted by taxonomy, concept, and unit so the output is stable between runs.
+//!
+//! ```ignore
+//! use edgarkit::{CompanyOperations, Edgar, ToCsv};
+//! use std::fs::File;
+//!
+//! let facts = edgar.company_facts(320193).await?;
+//! facts.to_csv(File::create("aapl-facts.csv")?)?;
+//! ```
+
+// With none of the collection features enabled there is nothing to export.
+#![cfg_attr(
+    not(any(feature = "filings", feature = "index", feature = "company")),
+    allow(dead_code)
+)]
+
+use super::error::Result;
+use std::io::Write;
+
+#[cfg(feature = "company")]
+use super::company::{CompanyFacts, DataPoint, Frame};
+#[cfg(feature = "filings")]
+use super::filings::DetailedFiling;
+#[cfg(feature = "index")]
+use crate::parsing::index::IndexEntry;
+
+/// Writes a collection as CSV.
+pub trait ToCsv {
+    /// Writes a header row and one row per record to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::FileError` if writing fails.
+    fn to_csv<W: Write>(&self, writer: W) -> Result<()>;
+
+    /// Renders the CSV into a string.
+    fn to_csv_string(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.to_csv(&mut buffer)?;
+        Ok(String::from_utf8(buffer).expect("CSV is built from UTF-8 strings"))
+    }
+}
+
+/// Writes one CSV record, quoting fields that contain separators, quotes, or line breaks.
+fn write_record<W: Write, S: AsRef<str>>(writer: &mut W, fields: &[S]) -> Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        let field = field.as_ref();
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            writer.write_all(field.as_bytes())?;
+        }
+    }
+    writer.write_all(b"\r\n")?;
+    Ok(())
+}
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(T::to_string).unwrap_or_default()
+}
+
+#[cfg(feature = "filings")]
+impl ToCsv for [DetailedFiling] {
+    fn to_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        write_record(
+            &mut writer,
+            &[
+                "accession_number",
+                "form",
+                "filing_date",
+                "report_date",
+                "acceptance_date_time",
+                "act",
+                "file_number",
+                "film_number",
+                "items",
+                "size",
+                "is_xbrl",
+                "is_inline_xbrl",
+                "primary_document",
+                "primary_doc_description",
+            ],
+        )?;
+        for filing in self {
+            write_record(
+                &mut writer,
+                &[
+                    filing.accession_number.clone(),
+                    filing.form.clone(),
+                    filing.filing_date.to_string(),
+                    optional(&filing.report_date),
+                    filing.acceptance_date_time.to_rfc3339(),
+                    optional(&filing.act),
+                    optional(&filing.file_number),
+                    optional(&filing.film_number),
+                    optional(&filing.items),
+                    filing.size.to_string(),
+                    filing.is_xbrl.to_string(),
+                    filing.is_inline_xbrl.to_string(),
+                    optional(&filing.primary_document),
+                    optional(&filing.primary_doc_description),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "index")]
+impl ToCsv for [IndexEntry] {
+    fn to_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        write_record(
+            &mut writer,
+            &["cik", "company_name", "form_type", "date_filed", "url"],
+        )?;
+        for entry in self {
+            write_record(
+                &mut writer,
+                &[
+                    entry.cik.to_string(),
+                    entry.company_name.clone(),
+                    entry.form_type.clone(),
+                    entry.date_filed.to_string(),
+                    entry.url.clone(),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "company")]
+impl ToCsv for Frame {
+    fn to_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        write_record(
+            &mut writer,
+            &[
+                "taxonomy",
+                "tag",
+                "uom",
+                "ccp",
+                "cik",
+                "entity_name",
+                "loc",
+                "end",
+                "val",
+                "accn",
+            ],
+        )?;
+        for point in &self.data_points {
+            write_record(
+                &mut writer,
+                &[
+                    self.taxonomy.clone(),
+                    self.tag.clone(),
+                    self.uom.clone(),
+                    self.ccp.clone(),
+                    point.cik.to_string(),
+                    point.entity_name.clone(),
+                    point.loc.clone(),
+                    point.end.clone(),
+                    point.val.to_string(),
+                    point.accn.clone(),
+                ],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "company")]
+impl ToCsv for CompanyFacts {
+    fn to_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        write_record(
+            &mut writer,
+            &[
+                "cik",
+                "entity_name",
+                "taxonomy",
+                "concept",
+                "label",
+                "unit",
+                "start",
+                "end",
+                "val",
+                "accn",
+                "fy",
+                "fp",
+                "form",
+                "filed",
+                "frame",
+            ],
+        )?;
+
+        let mut taxonomies: Vec<_> = self.taxonomies.iter().collect();
+        taxonomies.sort_by_key(|(name, _)| *name);
+
+        let cik = self.cik.to_string();
+        for (taxonomy, facts) in taxonomies {
+            let mut concepts: Vec<_> = facts.iter().collect();
+            concepts.sort_by_key(|(concept, _)| *concept);
+            for (concept, fact) in concepts {
+                let mut units: Vec<_> = fact.units.iter().collect();
+                units.sort_by_key(|(unit, _)| *unit);
+                for (unit, points) in units {
+                    for point in points {
+                        let DataPoint {
+                            start,
+                            end,
+                            val,
+                            accn,
+                            fy,
+                            fp,
+                            form,
+                            filed,
+                            frame,
+                        } = point;
+                        write_record(
+                            &mut writer,
+                            &[
+                                cik.as_str(),
+                                &self.entity_name,
+                                taxonomy,
+                                concept,
+                                fact.label.as_deref().unwrap_or_default(),
+                                unit,
+                                start.as_deref().unwrap_or_default(),
+                                end,
+                                &val.to_string(),
+                                accn,
+                                &optional(fy),
+                                fp.as_deref().unwrap_or_default(),
+                                form,
+                                filed,
+                                frame.as_deref().unwrap_or_default(),
+                            ],
+                        )?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_record_quotes_fields() {
+        let mut buffer = Vec::new();
+        write_record(
+            &mut buffer,
+            &["plain", "a,b", "say \"hi\"", "two\nlines", ""],
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"two\nlines\",\r\n"
+        );
+    }
+
+    #[cfg(feature = "company")]
+    #[test]
+    fn test_company_facts_csv() {
+        let facts: CompanyFacts = serde_json::from_str(
+            r#"{"cik": 320193, "entityName": "Apple Inc.", "facts": {
+                "us-gaap": {"Assets": {"label": "Assets", "units": {"USD": [
+                    {"end": "2023-09-30", "val": 352583000000, "accn": "0000320193-23-000106",
+                     "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03", "frame": "CY2023Q3I"}
+                ]}}},
+                "dei": {"EntityCommonStockSharesOutstanding": {"label": "Shares, Outstanding", "units": {"shares": [
+                    {"end": "2023-10-20", "val": 15552752000, "accn": "0000320193-23-000106",
+                     "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                ]}}}
+            }}"#,
+        )
+        .unwrap();
+
+        let csv = facts.to_csv_string().unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("cik,entity_name,taxonomy,concept,"));
+        assert_eq!(
+            lines[1],
+            "320193,Apple Inc.,dei,EntityCommonStockSharesOutstanding,\"Shares, Outstanding\",shares,,2023-10-20,15552752000,0000320193-23-000106,2023,FY,10-K,2023-11-03,"
+        );
+        assert!(lines[2].contains(",us-gaap,Assets,Assets,USD,,2023-09-30,352583000000,"));
+    }
+}
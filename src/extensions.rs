@@ -0,0 +1,300 @@
+//! Filer extension concepts and their closest standard equivalents.
+//!
+//! Besides the standard taxonomies (US-GAAP, DEI, SRT, ...), filers may define their own
+//! concepts under a company prefix such as `aapl`. Those extension concepts are kept in
+//! [`TaxonomyGroups`] next to the standard taxonomies, so this module adds:
+//! - [`ConceptKind`] to tell standard taxonomy prefixes from filer extensions.
+//! - [`TaxonomyGroups::extension_concepts`] to list every extension concept in a payload.
+//! - [`TaxonomyGroups::nearest_standard_concepts`] and [`TaxonomyGroups::map_extensions`] to
+//!   suggest US-GAAP concepts whose labels are most similar to an extension's label.
+//!
+//! Label similarity is a simple word-overlap score (Dice coefficient over lowercase words,
+//! with camel-case concept names split into words and filler words ignored). It is meant to
+//! rank candidates for review, not to produce authoritative mappings.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{CompanyOperations, Edgar};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let facts = edgar.company_facts(320193).await?;
+//!
+//! for (extension, nearest) in facts.taxonomies.map_extensions(0.5) {
+//!     match nearest {
+//!         Some(m) => println!("{}:{} ~ us-gaap:{} ({:.2})", extension.taxonomy, extension.name, m.name, m.score),
+//!         None => println!("{}:{} has no close standard concept", extension.taxonomy, extension.name),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::company::{Fact, TaxonomyGroups};
+use std::collections::HashSet;
+
+/// Taxonomy prefixes published by the SEC or FASB rather than by individual filers.
+const STANDARD_TAXONOMIES: &[&str] = &[
+    "us-gaap",
+    "dei",
+    "srt",
+    "ifrs-full",
+    "invest",
+    "ecd",
+    "cyd",
+    "country",
+    "currency",
+    "exch",
+    "naics",
+    "sic",
+    "stpr",
+    "rr",
+    "oef",
+    "cef",
+    "vip",
+    "ffd",
+    "spac",
+];
+
+/// Words that carry no meaning when comparing concept labels.
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "for", "from", "in", "of", "on", "or", "the", "to",
+];
+
+/// Whether a taxonomy prefix belongs to a standard taxonomy or a filer extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConceptKind {
+    /// A concept from a published standard taxonomy (e.g. `us-gaap`, `dei`, `srt`).
+    Standard,
+
+    /// A company-specific concept defined in the filer's own extension schema.
+    Extension,
+}
+
+impl ConceptKind {
+    /// Classifies a taxonomy prefix (case-insensitive).
+    pub fn of(taxonomy: &str) -> Self {
+        let taxonomy = taxonomy.to_ascii_lowercase();
+        if STANDARD_TAXONOMIES.contains(&taxonomy.as_str()) {
+            ConceptKind::Standard
+        } else {
+            ConceptKind::Extension
+        }
+    }
+}
+
+/// A filer-defined concept found in a company facts payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionConcept {
+    /// Extension prefix the concept is defined under (e.g. "aapl").
+    pub taxonomy: String,
+
+    /// Concept name without the prefix.
+    pub name: String,
+
+    /// Label reported with the concept, if any.
+    pub label: Option<String>,
+}
+
+/// A standard concept suggested as the closest match for a label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConceptMatch {
+    /// US-GAAP concept name.
+    pub name: String,
+
+    /// Label reported with the concept, if any.
+    pub label: Option<String>,
+
+    /// Similarity between 0.0 (no shared words) and 1.0 (same words).
+    pub score: f64,
+}
+
+impl TaxonomyGroups {
+    /// Lists every concept defined under a filer extension prefix, sorted by prefix and name.
+    pub fn extension_concepts(&self) -> Vec<ExtensionConcept> {
+        let mut concepts: Vec<ExtensionConcept> = self
+            .iter()
+            .filter(|(taxonomy, _)| ConceptKind::of(taxonomy) == ConceptKind::Extension)
+            .flat_map(|(taxonomy, facts)| {
+                facts.iter().map(move |(name, fact)| ExtensionConcept {
+                    taxonomy: taxonomy.to_string(),
+                    name: name.clone(),
+                    label: fact.label.clone(),
+                })
+            })
+            .collect();
+
+        concepts.sort_by(|a, b| (&a.taxonomy, &a.name).cmp(&(&b.taxonomy, &b.name)));
+        concepts
+    }
+
+    /// Ranks this company's US-GAAP concepts by label similarity to `label`.
+    ///
+    /// Each candidate is scored against both its reported label and its camel-case name,
+    /// keeping the better of the two. Candidates with no shared words are dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `label` - Label (or concept name) to match against
+    /// * `limit` - Maximum number of matches to return
+    pub fn nearest_standard_concepts(&self, label: &str, limit: usize) -> Vec<ConceptMatch> {
+        let target = words(label);
+        let mut matches: Vec<ConceptMatch> = self
+            .us_gaap()
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, fact)| {
+                let score = candidate_score(&target, name, fact);
+                (score > 0.0).then(|| ConceptMatch {
+                    name: name.clone(),
+                    label: fact.label.clone(),
+                    score,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        matches.truncate(limit);
+        matches
+    }
+
+    /// Pairs each extension concept with its closest US-GAAP concept.
+    ///
+    /// The match is `None` when the best candidate scores below `min_score`.
+    pub fn map_extensions(&self, min_score: f64) -> Vec<(ExtensionConcept, Option<ConceptMatch>)> {
+        self.extension_concepts()
+            .into_iter()
+            .map(|concept| {
+                let query = concept.label.as_deref().unwrap_or(&concept.name);
+                let best = self
+                    .nearest_standard_concepts(query, 1)
+                    .into_iter()
+                    .next()
+                    .filter(|m| m.score >= min_score);
+                (concept, best)
+            })
+            .collect()
+    }
+}
+
+fn candidate_score(target: &HashSet<String>, name: &str, fact: &Fact) -> f64 {
+    let by_name = dice(target, &words(name));
+    let by_label = fact
+        .label
+        .as_deref()
+        .map(|label| dice(target, &words(label)))
+        .unwrap_or(0.0);
+    by_name.max(by_label)
+}
+
+fn dice(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let shared = a.intersection(b).count();
+    (2 * shared) as f64 / (a.len() + b.len()) as f64
+}
+
+/// Splits a label or camel-case concept name into lowercase words, dropping filler words.
+fn words(text: &str) -> HashSet<String> {
+    let mut words = HashSet::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in text.chars() {
+        if !c.is_alphanumeric() {
+            push_word(&mut words, &mut current);
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            push_word(&mut words, &mut current);
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    push_word(&mut words, &mut current);
+    words
+}
+
+fn push_word(words: &mut HashSet<String>, current: &mut String) {
+    if !current.is_empty() && !STOP_WORDS.contains(&current.as_str()) {
+        words.insert(current.clone());
+    }
+    current.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn fact(label: &str) -> Fact {
+        Fact {
+            label: Some(label.to_string()),
+            description: None,
+            units: HashMap::new(),
+        }
+    }
+
+    fn groups() -> TaxonomyGroups {
+        let us_gaap = HashMap::from([
+            (
+                "ResearchAndDevelopmentExpense".to_string(),
+                fact("Research and Development Expense"),
+            ),
+            (
+                "SellingGeneralAndAdministrativeExpense".to_string(),
+                fact("Selling, General and Administrative Expense"),
+            ),
+        ]);
+        let extension = HashMap::from([(
+            "ResearchAndDevelopmentCosts".to_string(),
+            fact("Research & development costs"),
+        )]);
+        let srt = HashMap::from([("ProductOrServiceAxis".to_string(), fact("Product"))]);
+
+        TaxonomyGroups::from_iter([
+            ("us-gaap".to_string(), us_gaap),
+            ("acme".to_string(), extension),
+            ("srt".to_string(), srt),
+        ])
+    }
+
+    #[test]
+    fn test_classify_taxonomy() {
+        assert_eq!(ConceptKind::of("us-gaap"), ConceptKind::Standard);
+        assert_eq!(ConceptKind::of("SRT"), ConceptKind::Standard);
+        assert_eq!(ConceptKind::of("aapl"), ConceptKind::Extension);
+    }
+
+    #[test]
+    fn test_extension_concepts_skip_standard_taxonomies() {
+        let concepts = groups().extension_concepts();
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].taxonomy, "acme");
+        assert_eq!(concepts[0].name, "ResearchAndDevelopmentCosts");
+    }
+
+    #[test]
+    fn test_map_extension_to_nearest_standard() {
+        let mapped = groups().map_extensions(0.5);
+        let best = mapped[0].1.as_ref().unwrap();
+        assert_eq!(best.name, "ResearchAndDevelopmentExpense");
+        assert!(best.score > 0.5 && best.score < 1.0);
+
+        assert!(groups().map_extensions(0.99)[0].1.is_none());
+    }
+
+    #[test]
+    fn test_word_similarity() {
+        let similarity = |a: &str, b: &str| dice(&words(a), &words(b));
+        assert_eq!(similarity("NetIncomeLoss", "Net Income (Loss)"), 1.0);
+        assert_eq!(similarity("Assets", "Revenues"), 0.0);
+    }
+}
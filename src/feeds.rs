@@ -9,16 +9,306 @@
 //! This module implements `FeedOperations` for [`Edgar`]. Network methods return parsed
 //! `AtomDocument`/`RssDocument` values, and companion `*_from_string` helpers make it easy
 //! to test parsing against fixtures or to integrate with custom download logic.
+//!
+//! For monitoring, `sec_news_stream` polls several news feeds on an interval and merges
+//! them into a single chronological stream of [`NewsItem`]s, skipping items already seen.
+//! `company_filings_stream` does the same for company feeds, polling each CIK on a
+//! [`PollSchedule`] that adapts to how often the company files.
+//!
+//! `poll_feed` watches any other Atom or RSS feed by URL, yielding each entry once. Its
+//! cursor can be a file-backed [`SeenSet`] so a restarted monitor picks up where it stopped.
+//!
+//! A company feed returns one page of at most 100 entries; `company_feed_all` follows the
+//! `start` offset through the rest, the way `search_all` does for search.
 
 use super::Edgar;
 use super::FeedOperations;
+use super::cik::Cik;
+use super::entity::EntityId;
 use super::error::{EdgarError, Result};
-use super::options::FeedOptions;
+#[cfg(feature = "filings")]
+use super::filings::DetailedFiling;
+#[cfg(feature = "filings")]
+use super::options::CurrentFeedOptions;
+use super::options::{FEED_COUNTS, FeedOptions};
+use super::polling::{PollBudget, PollSchedule};
+use super::rt::{BoxStream, Instant, IntoBoxStream, sleep, sleep_until};
+use super::seen::SeenSet;
 use crate::parsing::{
-    atom::{AtomConfig, AtomDocument, AtomParser},
-    rss::{RssConfig, RssDocument, RssParser},
+    atom::{AtomConfig, AtomDocument, AtomEntry, AtomParser},
+    rss::{Item, RssConfig, RssDocument, RssParser},
 };
 use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use futures_util::stream;
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::time::Duration;
+
+/// Builds a `browse-edgar` URL with the options as sorted query parameters, so the same
+/// options always produce the same URL.
+fn browse_url(action: &str, opts: &FeedOptions) -> Result<String> {
+    let params: BTreeMap<_, _> = opts.params().iter().collect();
+    let query = serde_urlencoded::to_string(params)
+        .map_err(|e| EdgarError::InvalidResponse(e.to_string()))?;
+    Ok(format!(
+        "https://www.sec.gov/cgi-bin/browse-edgar?action={}&{}",
+        action, query
+    ))
+}
+
+/// Number of item identifiers remembered by `sec_news_stream` for deduplication.
+const NEWS_SEEN_CAPACITY: usize = 10_000;
+
+/// Entries fetched per poll by `recent_filings_stream` unless the options set a count.
+#[cfg(feature = "filings")]
+const CURRENT_FEED_PAGE_SIZE: u32 = 100;
+
+/// Further pages `recent_filings_stream` reads in one poll when a whole page is new.
+#[cfg(feature = "filings")]
+const CURRENT_FEED_CATCH_UP_PAGES: u32 = 4;
+
+/// SEC news feeds that can be combined with `sec_news_stream`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedKind {
+    PressReleases,
+    SpeechesAndStatements,
+    Speeches,
+    Statements,
+    Testimony,
+    LitigationReleases,
+    AdministrativeProceedings,
+    CorporationFinance,
+    InvestmentManagement,
+    InvestorAlerts,
+}
+
+impl FeedKind {
+    /// URL of the RSS feed for this kind.
+    pub fn url(&self) -> &'static str {
+        match self {
+            FeedKind::PressReleases => "https://www.sec.gov/news/pressreleases.rss",
+            FeedKind::SpeechesAndStatements => "https://www.sec.gov/news/speeches-statements.rss",
+            FeedKind::Speeches => "https://www.sec.gov/news/speeches.rss",
+            FeedKind::Statements => "https://www.sec.gov/news/statements.rss",
+            FeedKind::Testimony => "https://www.sec.gov/news/testimony.rss",
+            FeedKind::LitigationReleases => "https://www.sec.gov/rss/litigation/litreleases.xml",
+            FeedKind::AdministrativeProceedings => "https://www.sec.gov/rss/litigation/admin.xml",
+            FeedKind::CorporationFinance => "https://www.sec.gov/rss/divisions/corpfin/cfnew.xml",
+            FeedKind::InvestmentManagement => {
+                "https://www.sec.gov/rss/divisions/investment/imnews.xml"
+            }
+            FeedKind::InvestorAlerts => "https://www.sec.gov/rss/investor/alerts",
+        }
+    }
+}
+
+/// A news item from one of the SEC's RSS feeds, tagged with the feed it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewsItem {
+    /// Feed the item was published in.
+    pub kind: FeedKind,
+
+    /// Headline.
+    pub title: String,
+
+    /// Link to the full release or statement.
+    pub link: String,
+
+    /// Summary text, if the feed provides one.
+    pub description: Option<String>,
+
+    /// Identifier used for deduplication (the item's `guid`, or its link when absent).
+    pub id: String,
+
+    /// Publication time, if the feed's `pubDate` could be parsed.
+    pub published: Option<DateTime<FixedOffset>>,
+}
+
+impl NewsItem {
+    fn from_item(kind: FeedKind, item: Item) -> Self {
+        let published = item
+            .pub_date
+            .as_deref()
+            .and_then(|d| DateTime::parse_from_rfc2822(d.trim()).ok());
+        let id = item
+            .guid
+            .filter(|g| !g.trim().is_empty())
+            .unwrap_or_else(|| item.link.clone());
+        Self {
+            kind,
+            title: item.title,
+            link: item.link,
+            description: item.description,
+            id,
+            published,
+        }
+    }
+}
+
+/// Identifiers already emitted by a stream, forgetting the oldest beyond a fixed size.
+#[derive(Debug, Default)]
+struct SeenIds {
+    ids: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl SeenIds {
+    /// Records `id`, returning `false` if it was already present.
+    fn insert(&mut self, id: &str) -> bool {
+        if !self.ids.insert(id.to_string()) {
+            return false;
+        }
+        self.order.push_back(id.to_string());
+        if self.order.len() > NEWS_SEEN_CAPACITY
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.ids.remove(&oldest);
+        }
+        true
+    }
+
+    fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+}
+
+/// A filing listed in the current filings feed, yielded by `recent_filings_stream`.
+#[cfg(feature = "filings")]
+#[derive(Debug, Clone)]
+pub struct RecentFiling {
+    /// The filer the entry is listed under. Filings with several parties, such as an
+    /// insider's Form 4, are listed once per party; the stream keeps the first.
+    pub cik: Cik,
+
+    /// Name from the feed title, e.g. `Acme Corp` in `8-K - Acme Corp (0000012345) (Filer)`.
+    pub company_name: String,
+
+    /// The filing index page.
+    pub url: String,
+
+    /// Filing metadata. The current feed has no report date or documents, so those fields
+    /// are empty; see `TryFrom<&AtomEntry>` for [`DetailedFiling`].
+    pub filing: DetailedFiling,
+}
+
+#[cfg(feature = "filings")]
+impl RecentFiling {
+    fn from_entry(entry: &AtomEntry) -> Option<Self> {
+        Some(Self {
+            cik: Cik::new(entry.cik()?),
+            company_name: entry.company_name().unwrap_or_default(),
+            url: entry.get_primary_link(),
+            filing: DetailedFiling::try_from(entry).ok()?,
+        })
+    }
+}
+
+/// New filings found in one company's feed by `company_filings_stream`.
+#[derive(Debug, Clone)]
+pub struct CompanyUpdate {
+    pub cik: Cik,
+
+    /// Feed entries not seen in earlier polls, oldest first.
+    pub entries: Vec<AtomEntry>,
+}
+
+/// An entry of an Atom or RSS feed watched with `poll_feed`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedItem {
+    pub title: String,
+
+    pub link: String,
+
+    /// The entry's Atom `id` or RSS `guid`, or its link when there is neither.
+    pub id: String,
+
+    /// Atom `updated` (or `published`) or RSS `pubDate`, if it could be parsed.
+    pub updated: Option<DateTime<FixedOffset>>,
+
+    /// Summary text, if the feed provides one.
+    pub summary: Option<String>,
+}
+
+impl FeedItem {
+    fn from_entry(entry: AtomEntry) -> Self {
+        let updated = entry
+            .updated
+            .as_deref()
+            .or(entry.published.as_deref())
+            .and_then(|d| DateTime::parse_from_rfc3339(d.trim()).ok());
+        let id = if entry.id.trim().is_empty() {
+            entry.link.clone()
+        } else {
+            entry.id
+        };
+        Self {
+            title: entry.title,
+            link: entry.link,
+            id,
+            updated,
+            summary: entry.description,
+        }
+    }
+
+    fn from_item(item: Item) -> Self {
+        let updated = item
+            .pub_date
+            .as_deref()
+            .and_then(|d| DateTime::parse_from_rfc2822(d.trim()).ok());
+        let id = item
+            .guid
+            .filter(|g| !g.trim().is_empty())
+            .unwrap_or_else(|| item.link.clone());
+        Self {
+            title: item.title,
+            link: item.link,
+            id,
+            updated,
+            summary: item.description,
+        }
+    }
+
+    /// Key recorded in the cursor: the id, plus the update time when there is one, so an
+    /// entry that is republished with a new timestamp counts as new.
+    fn cursor_key(&self) -> String {
+        match self.updated {
+            Some(updated) => format!("{}@{}", self.id, updated.to_rfc3339()),
+            None => self.id.clone(),
+        }
+    }
+}
+
+/// Parses an RSS or Atom document into feed items, oldest first (undated items last).
+fn parse_feed_items(content: &str) -> Result<Vec<FeedItem>> {
+    let mut items: Vec<FeedItem> = if content.contains("<rss") {
+        RssParser::new(RssConfig::default())
+            .parse(content)?
+            .channel
+            .items
+            .into_iter()
+            .map(FeedItem::from_item)
+            .collect()
+    } else {
+        AtomParser::new(AtomConfig::default())
+            .parse(content)?
+            .entries
+            .into_iter()
+            .map(FeedItem::from_entry)
+            .collect()
+    };
+    items.sort_by_key(|item| (item.updated.is_none(), item.updated));
+    Ok(items)
+}
+
+/// Keeps unseen items and orders them oldest first (undated items last).
+fn merge_new_items(items: Vec<NewsItem>, seen: &mut SeenIds) -> Vec<NewsItem> {
+    let mut fresh: Vec<NewsItem> = items
+        .into_iter()
+        .filter(|item| seen.insert(&item.id))
+        .collect();
+    fresh.sort_by_key(|item| (item.published.is_none(), item.published));
+    fresh
+}
 
 /// Feed operations for SEC EDGAR.
 ///
@@ -33,11 +323,11 @@ use async_trait::async_trait;
 ///
 ///     // Atom: current filings (optionally parameterized).
 ///     let current = edgar.current_feed(None).await?;
-///     let opts = FeedOptions::new(None).with_param("count", "25");
+///     let opts = FeedOptions::new(None).with_count(20)?;
 ///     let current_limited = edgar.current_feed(Some(opts)).await?;
 ///
 ///     // Atom: company-specific feed.
-///     let company = edgar.company_feed("1018724", None).await?;
+///     let company = edgar.company_feed(1018724, None).await?;
 ///
 ///     // RSS: SEC news.
 ///     let press = edgar.press_release_feed().await?;
@@ -52,19 +342,12 @@ use async_trait::async_trait;
 /// * Historical XBRL feeds are only available from 2005 onwards
 /// * All feed operations require proper initialization of the Edgar client with a valid user agent
 /// * Some feeds might require proper rate limiting to comply with SEC.gov's fair access rules
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl FeedOperations for Edgar {
     /// Fetches the current feed
     async fn current_feed(&self, opts: Option<FeedOptions>) -> Result<AtomDocument> {
-        let feed_opts = FeedOptions::new(opts);
-        let query = serde_urlencoded::to_string(feed_opts.params())
-            .map_err(|e| EdgarError::InvalidResponse(e.to_string()))?;
-
-        let url = format!(
-            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&{}",
-            query
-        );
-
+        let url = browse_url("getcurrent", &FeedOptions::new(opts))?;
         let content = self.get(&url).await?;
         self.current_feed_from_string(&content)
     }
@@ -75,21 +358,58 @@ impl FeedOperations for Edgar {
         parser.parse(content)
     }
 
-    /// Fetches the company feed for a given CIK
-    async fn company_feed(&self, cik: &str, opts: Option<FeedOptions>) -> Result<AtomDocument> {
-        let feed_opts = FeedOptions::new(opts).with_param("CIK", cik);
-        let query = serde_urlencoded::to_string(feed_opts.params())
-            .map_err(|e| EdgarError::InvalidResponse(e.to_string()))?;
-
-        let url = format!(
-            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany&{}",
-            query
-        );
-
+    /// Fetches the company feed for a CIK or ticker, resolved with
+    /// [`resolve_entity`](Edgar::resolve_entity)
+    async fn company_feed(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FeedOptions>,
+    ) -> Result<AtomDocument> {
+        let cik = self.resolve_entity(cik).await?;
+        let feed_opts = FeedOptions::new(opts).with_param("CIK", cik.padded());
+        let url = browse_url("getcompany", &feed_opts)?;
         let content = self.get(&url).await?;
         self.company_feed_from_string(&content)
     }
 
+    /// Walks a company feed page by page from `start` (or the first entry) until a short
+    /// page, using the options' page size or the largest one the SEC allows.
+    ///
+    /// Entries are returned newest first, without duplicates if filings land between
+    /// pages and shift the offsets.
+    async fn company_feed_all(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FeedOptions>,
+    ) -> Result<Vec<AtomEntry>> {
+        let cik = self.resolve_entity(cik).await?;
+        let opts = FeedOptions::new(opts);
+        let param = |key: &str| opts.params().get(key).and_then(|v| v.parse::<u32>().ok());
+        let count = param("count").unwrap_or(FEED_COUNTS[FEED_COUNTS.len() - 1]);
+        let mut start = param("start").unwrap_or(0);
+
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        loop {
+            let page_opts = opts
+                .clone()
+                .with_param("count", count.to_string())
+                .with_start(start);
+            let page = self.company_feed(cik, Some(page_opts)).await?;
+            let fetched = page.entries.len();
+            let before = entries.len();
+            entries.extend(
+                page.entries
+                    .into_iter()
+                    .filter(|entry| seen.insert(entry.id.clone())),
+            );
+            if fetched < count as usize || entries.len() == before {
+                return Ok(entries);
+            }
+            start += count;
+        }
+    }
+
     /// Parses the company feed from a string
     fn company_feed_from_string(&self, content: &str) -> Result<AtomDocument> {
         let parser = AtomParser::new(AtomConfig::default());
@@ -191,7 +511,7 @@ impl FeedOperations for Edgar {
         if year < 2005 {
             return Err(EdgarError::InvalidXBRLYear);
         }
-        if month < 1 || month > 12 {
+        if !(1..=12).contains(&month) {
             return Err(EdgarError::InvalidMonth);
         }
 
@@ -201,11 +521,389 @@ impl FeedOperations for Edgar {
         );
         self.get_rss_feed(&url).await
     }
+
+    /// Polls several SEC news feeds and yields new items as one chronological stream.
+    ///
+    /// Every `interval`, each feed in `kinds` is fetched in turn. Items whose `guid` has
+    /// already been emitted are dropped, and the remaining items from all feeds are yielded
+    /// oldest first. The first poll yields everything currently in the feeds. A feed that
+    /// fails to download produces an `Err` item; the stream keeps polling the others.
+    ///
+    /// The stream never ends on its own; drop it to stop polling.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FeedKind, FeedOperations};
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let kinds = [FeedKind::PressReleases, FeedKind::LitigationReleases];
+    /// let mut news = edgar.sec_news_stream(&kinds, Duration::from_secs(300));
+    /// while let Some(item) = news.next().await {
+    ///     let item = item?;
+    ///     println!("[{:?}] {}", item.kind, item.title);
+    /// }
+    /// ```
+    fn sec_news_stream(
+        &self,
+        kinds: &[FeedKind],
+        interval: Duration,
+    ) -> BoxStream<'_, Result<NewsItem>> {
+        struct State {
+            kinds: Vec<FeedKind>,
+            seen: SeenIds,
+            pending: VecDeque<Result<NewsItem>>,
+            polled: bool,
+        }
+
+        let state = State {
+            kinds: kinds.to_vec(),
+            seen: SeenIds::default(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(next) = state.pending.pop_front() {
+                    return Some((next, state));
+                }
+                if state.polled {
+                    sleep(interval).await;
+                }
+                state.polled = true;
+
+                let mut items = Vec::new();
+                for kind in &state.kinds {
+                    match self.get_rss_feed(kind.url()).await {
+                        Ok(doc) => items.extend(
+                            doc.channel
+                                .items
+                                .into_iter()
+                                .map(|item| NewsItem::from_item(*kind, item)),
+                        ),
+                        Err(e) => state.pending.push_back(Err(e)),
+                    }
+                }
+                state
+                    .pending
+                    .extend(merge_new_items(items, &mut state.seen).into_iter().map(Ok));
+            }
+        })
+        .into_box_stream()
+    }
+
+    /// Polls an Atom or RSS feed every `interval` and yields entries not seen before,
+    /// oldest first.
+    ///
+    /// The first poll yields everything currently in the feed. Entries are recognized by
+    /// id (or guid) and update time, so an entry revised in place is yielded again. A
+    /// failed poll yields an `Err` item and the stream keeps polling; drop it to stop.
+    fn poll_feed(&self, url: &str, interval: Duration) -> BoxStream<'_, Result<FeedItem>> {
+        self.poll_feed_with_cursor(url, interval, SeenSet::in_memory())
+    }
+
+    /// Like `poll_feed`, recording yielded entries in `cursor`.
+    ///
+    /// With a file-backed set from [`SeenSet::open`], entries yielded before a restart are
+    /// skipped afterwards. An entry is recorded as it is yielded, so one the consumer was
+    /// handed just before a crash is not yielded again.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FeedOperations, SeenSet};
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let cursor = SeenSet::open("press-releases.seen")?;
+    /// let url = "https://www.sec.gov/news/pressreleases.rss";
+    /// let mut items = edgar.poll_feed_with_cursor(url, Duration::from_secs(600), cursor);
+    /// while let Some(item) = items.next().await {
+    ///     println!("{}", item?.title);
+    /// }
+    /// ```
+    fn poll_feed_with_cursor(
+        &self,
+        url: &str,
+        interval: Duration,
+        cursor: SeenSet,
+    ) -> BoxStream<'_, Result<FeedItem>> {
+        struct State {
+            url: String,
+            cursor: SeenSet,
+            pending: VecDeque<FeedItem>,
+            polled: bool,
+        }
+
+        let state = State {
+            url: url.to_string(),
+            cursor,
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.pending.pop_front() {
+                    match state.cursor.insert_if_new(&item.cursor_key()) {
+                        Ok(true) => return Some((Ok(item), state)),
+                        Ok(false) => continue,
+                        Err(e) => return Some((Err(e), state)),
+                    }
+                }
+                if state.polled {
+                    sleep(interval).await;
+                }
+                state.polled = true;
+
+                let items = match self.get(&state.url).await {
+                    Ok(content) => parse_feed_items(&content),
+                    Err(e) => Err(e),
+                };
+                match items {
+                    Ok(items) => {
+                        for item in items {
+                            match state.cursor.contains(&item.cursor_key()) {
+                                Ok(true) => {}
+                                Ok(false) => state.pending.push_back(item),
+                                Err(e) => return Some((Err(e), state)),
+                            }
+                        }
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        })
+        .into_box_stream()
+    }
+
+    /// Watches the feeds of several companies and yields their new filings.
+    ///
+    /// Each CIK is polled on an adaptive [`PollSchedule`]: companies that file often are
+    /// polled more often, and the combined request rate stays within `budget`. The first
+    /// poll of a company only records what is already in its feed; later polls yield a
+    /// [`CompanyUpdate`] when there are entries not seen before. A failed poll yields an
+    /// `Err` item and counts as a poll without new filings.
+    ///
+    /// The stream ends only if `ciks` is empty; drop it to stop polling. Call
+    /// `.enrich(enricher)` ([`EnrichStream`](crate::EnrichStream), `company` feature) on the
+    /// stream to attach company metadata to each update.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Cik, Edgar, FeedOperations, PollBudget};
+    /// use futures_util::StreamExt;
+    ///
+    /// let ciks = vec![Cik::new(320193), Cik::new(789019), Cik::new(1318605)];
+    /// let mut updates = edgar.company_filings_stream(ciks, PollBudget::default());
+    /// while let Some(update) = updates.next().await {
+    ///     let update = update?;
+    ///     println!("{}: {} new filings", update.cik, update.entries.len());
+    /// }
+    /// ```
+    fn company_filings_stream(
+        &self,
+        ciks: Vec<Cik>,
+        budget: PollBudget,
+    ) -> BoxStream<'_, Result<CompanyUpdate>> {
+        struct State {
+            schedule: PollSchedule,
+            seen: SeenIds,
+            primed: HashSet<Cik>,
+        }
+
+        let mut schedule = PollSchedule::new(budget);
+        for cik in ciks {
+            schedule.add(cik);
+        }
+        let state = State {
+            schedule,
+            seen: SeenIds::default(),
+            primed: HashSet::new(),
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                let (cik, due) = state.schedule.next_due(Instant::now())?;
+                sleep_until(due).await;
+
+                let feed = self.company_feed(cik, None).await;
+                let now = Instant::now();
+                let doc = match feed {
+                    Ok(doc) => doc,
+                    Err(e) => {
+                        state.schedule.record(cik, 0, now);
+                        return Some((Err(e), state));
+                    }
+                };
+
+                let mut entries: Vec<AtomEntry> = doc
+                    .entries
+                    .into_iter()
+                    .filter(|entry| {
+                        let id = entry.accession_number().unwrap_or_else(|| entry.id.clone());
+                        state.seen.insert(&id)
+                    })
+                    .collect();
+                entries.reverse();
+
+                if !state.primed.insert(cik) {
+                    state.schedule.record(cik, entries.len(), now);
+                    if !entries.is_empty() {
+                        return Some((Ok(CompanyUpdate { cik, entries }), state));
+                    }
+                } else {
+                    state.schedule.record(cik, 0, now);
+                }
+            }
+        })
+        .into_box_stream()
+    }
+
+    /// Polls the current filings feed every `interval` and yields filings not seen before,
+    /// oldest first.
+    ///
+    /// `options` narrows the feed by form type, company name, or ownership filings; each poll
+    /// fetches `count` entries, 100 if unset. The first poll yields everything on that page.
+    /// Later polls read up to four further pages when a whole page is new, so bursts between
+    /// polls are not lost. Filings are recognized by accession number. A failed poll yields
+    /// an `Err` item and the stream keeps polling; drop it to stop. Call `.enrich(enricher)`
+    /// ([`EnrichStream`](crate::EnrichStream), `company` feature) on the stream to attach
+    /// company metadata to each filing.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{CurrentFeedOptions, Edgar, FeedOperations};
+    /// use futures_util::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// let options = CurrentFeedOptions::new().with_form_type("8-K");
+    /// let mut filings = edgar.recent_filings_stream(options, Duration::from_secs(60));
+    /// while let Some(recent) = filings.next().await {
+    ///     let recent = recent?;
+    ///     println!("{} filed {}: {}", recent.company_name, recent.filing.form, recent.url);
+    /// }
+    /// ```
+    #[cfg(feature = "filings")]
+    fn recent_filings_stream(
+        &self,
+        options: CurrentFeedOptions,
+        interval: Duration,
+    ) -> BoxStream<'_, Result<RecentFiling>> {
+        struct State {
+            feed: FeedOptions,
+            page_size: u32,
+            seen: SeenIds,
+            pending: VecDeque<RecentFiling>,
+            polled: bool,
+        }
+
+        let page_size = options.count().unwrap_or(CURRENT_FEED_PAGE_SIZE);
+        let state = State {
+            feed: FeedOptions::from(options).with_param("count", page_size.to_string()),
+            page_size,
+            seen: SeenIds::default(),
+            pending: VecDeque::new(),
+            polled: false,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(filing) = state.pending.pop_front() {
+                    return Some((Ok(filing), state));
+                }
+                if state.polled {
+                    sleep(interval).await;
+                }
+                let first_poll = !state.polled;
+                state.polled = true;
+
+                // Pages list newest first; collect newest to oldest and yield in reverse.
+                let mut fresh = Vec::new();
+                for page in 0..=CURRENT_FEED_CATCH_UP_PAGES {
+                    let opts = state.feed.clone().with_start(page * state.page_size);
+                    let feed = match self.current_feed(Some(opts)).await {
+                        Ok(feed) => feed,
+                        Err(e) => {
+                            state.pending.extend(fresh.into_iter().rev());
+                            return Some((Err(e), state));
+                        }
+                    };
+                    let caught_up = feed.entries.iter().any(|entry| {
+                        entry
+                            .accession_number()
+                            .is_some_and(|acc| state.seen.contains(&acc))
+                    });
+                    for entry in &feed.entries {
+                        if let Some(filing) = RecentFiling::from_entry(entry)
+                            && state.seen.insert(&filing.filing.accession_number)
+                        {
+                            fresh.push(filing);
+                        }
+                    }
+                    if first_poll || caught_up || feed.entries.len() < state.page_size as usize {
+                        break;
+                    }
+                }
+                state.pending.extend(fresh.into_iter().rev());
+            }
+        })
+        .into_box_stream()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[test]
+    fn test_feed_items_from_atom_and_rss() {
+        let atom = r#"<?xml version="1.0" encoding="ISO-8859-1" ?>
+            <feed xmlns="http://www.w3.org/2005/Atom">
+                <title>Latest Filings</title>
+                <updated>2025-01-02T10:00:00-05:00</updated>
+                <entry>
+                    <title>8-K - Example Corp</title>
+                    <link href="https://www.sec.gov/b.htm" rel="alternate" type="text/html" />
+                    <updated>2025-01-02T09:00:00-05:00</updated>
+                    <id>urn:tag:sec.gov,2008:accession-number=0000000000-25-000002</id>
+                </entry>
+                <entry>
+                    <title>10-Q - Example Corp</title>
+                    <link href="https://www.sec.gov/a.htm" rel="alternate" type="text/html" />
+                    <updated>2025-01-01T09:00:00-05:00</updated>
+                    <id>urn:tag:sec.gov,2008:accession-number=0000000000-25-000001</id>
+                </entry>
+            </feed>"#;
+        let items = parse_feed_items(atom).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "10-Q - Example Corp");
+        assert_eq!(items[0].link, "https://www.sec.gov/a.htm");
+        assert_eq!(
+            items[1].cursor_key(),
+            "urn:tag:sec.gov,2008:accession-number=0000000000-25-000002@2025-01-02T09:00:00-05:00"
+        );
+
+        let rss = r#"<?xml version="1.0"?>
+            <rss version="2.0">
+                <channel>
+                    <title>News</title>
+                    <link>https://www.sec.gov</link>
+                    <description>News</description>
+                    <item>
+                        <title>Undated</title>
+                        <link>https://www.sec.gov/news/1</link>
+                    </item>
+                </channel>
+            </rss>"#;
+        let items = parse_feed_items(rss).unwrap();
+        assert_eq!(items[0].id, "https://www.sec.gov/news/1");
+        assert_eq!(items[0].cursor_key(), "https://www.sec.gov/news/1");
+    }
+
     #[test]
     fn test_empty_feed() {
         let edgar = Edgar::new("test_agent example@example.com").unwrap();
@@ -238,6 +936,41 @@ mod tests {
         assert!(matches!(result, Err(EdgarError::InvalidMonth)));
     }
 
+    #[test]
+    fn test_merge_new_items_dedupes_and_sorts() {
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        let rss = r#"<?xml version="1.0"?>
+            <rss version="2.0">
+                <channel>
+                    <title>News</title>
+                    <link>http://example.com</link>
+                    <description>News</description>
+                    <item><title>Later</title><link>http://example.com/2</link><guid>2</guid>
+                        <pubDate>Tue, 21 Jan 2025 12:09:48 -0500</pubDate></item>
+                    <item><title>Earlier</title><link>http://example.com/1</link><guid>1</guid>
+                        <pubDate>Tue, 21 Jan 2025 10:14:00 -0500</pubDate></item>
+                </channel>
+            </rss>"#;
+        let items = || {
+            edgar
+                .rss_feed_from_string(rss)
+                .unwrap()
+                .channel
+                .items
+                .into_iter()
+                .map(|item| NewsItem::from_item(FeedKind::PressReleases, item))
+                .collect::<Vec<_>>()
+        };
+
+        let mut seen = SeenIds::default();
+        let first = merge_new_items(items(), &mut seen);
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].title, "Earlier");
+        assert!(first[0].published.is_some());
+
+        assert!(merge_new_items(items(), &mut seen).is_empty());
+    }
+
     #[test]
     fn test_invalid_feed() {
         let edgar = Edgar::new("test_agent example@example.com").unwrap();
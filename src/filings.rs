@@ -14,13 +14,51 @@
 //! `10-K` + `10-K/A`).
 
 use super::Edgar;
-use super::error::{EdgarError, Result};
+use super::cik::Cik;
+use super::core::fan_out;
+use super::entity::EntityId;
+use super::error::{EdgarError, ErrorContext, Result};
+use super::geography::{StateOrCountry, parse_code};
+use super::items::ItemCode;
 use super::options::FilingOptions;
+use super::sic::SicCode;
 use super::traits::FilingOperations;
+#[cfg(feature = "atom")]
+use crate::parsing::atom::AtomEntry;
+use crate::parsing::filing_summary::{FinancialReport, find_report, parse_filing_summary};
+use crate::parsing::form_d::{FormD, parse_form_d};
+use crate::parsing::json::ParseReport;
+use crate::parsing::ownership::{BENEFICIAL_OWNERSHIP_FORMS, BeneficialOwnership};
+use crate::parsing::proxy::{CompensationRow, summary_compensation_table};
+use crate::parsing::sections::{Item, Section};
+use crate::parsing::submission::{SubmissionConfig, SubmissionDocument, SubmissionParser};
+use crate::parsing::utils::deserialize_human_size;
+use crate::parsing::utils::parse_edgar_date;
 use async_trait::async_trait;
-use chrono::{DateTime, FixedOffset};
-use serde::Deserialize;
-use serde_json;
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::path::Path;
+
+/// Submissions fetched at once by [`FilingOperations::latest_across`]; every request still
+/// goes through the client's rate limiter.
+const LATEST_ACROSS_CONCURRENCY: usize = 4;
+
+/// Files downloaded at once by [`FilingOperations::download_filing`]; every request still
+/// goes through the client's rate limiter.
+#[cfg(not(target_arch = "wasm32"))]
+const DOWNLOAD_FILING_CONCURRENCY: usize = 4;
+
+/// Name of the manifest [`FilingOperations::download_filing`] writes next to the files.
+pub const FILING_MANIFEST: &str = "manifest.json";
+
+/// Name EDGAR gives the XML document of forms filed as structured data (Form D, N-PORT).
+pub(crate) const PRIMARY_XML: &str = "primary_doc.xml";
+
+/// Name of the index of XBRL report renderings in a filing folder.
+const FILING_SUMMARY: &str = "FilingSummary.xml";
 
 /// A company's submissions payload (`/submissions/CIK##########.json`).
 ///
@@ -91,22 +129,25 @@ pub struct Submission {
     #[serde(rename = "fiscalYearEnd")]
     pub fiscal_year_end: Option<String>,
 
-    /// State code of incorporation
-    #[serde(rename = "stateOfIncorporation")]
-    pub state_of_incorporation: String,
+    /// State code of incorporation; absent for individuals
+    #[serde(rename = "stateOfIncorporation", default)]
+    pub state_of_incorporation: Option<String>,
 
     /// State full name
-    #[serde(rename = "stateOfIncorporationDescription")]
-    pub state_of_incorporation_description: String,
+    #[serde(rename = "stateOfIncorporationDescription", default)]
+    pub state_of_incorporation_description: Option<String>,
 
-    /// Mailing and business addresses
-    pub addresses: Addresses,
+    /// Mailing and business addresses; absent for some individuals and foreign filers
+    #[serde(default)]
+    pub addresses: Option<Addresses>,
 
     /// Company phone
-    pub phone: String,
+    #[serde(default)]
+    pub phone: Option<String>,
 
     /// Misc flags
-    pub flags: String,
+    #[serde(default)]
+    pub flags: Option<String>,
 
     /// Historical names
     #[serde(rename = "formerNames")]
@@ -119,21 +160,23 @@ pub struct Submission {
 /// Mailing and business addresses for an entity.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Addresses {
-    pub mailing: Address,
-    pub business: Address,
+    #[serde(default)]
+    pub mailing: Option<Address>,
+    #[serde(default)]
+    pub business: Option<Address>,
 }
 
 /// A single address record in a `Submission` payload.
 #[derive(Debug, Clone, Deserialize)]
 pub struct Address {
     /// Street line 1
-    pub street1: String,
+    pub street1: Option<String>,
 
     /// Street line 2
     pub street2: Option<String>,
 
     /// City
-    pub city: String,
+    pub city: Option<String>,
 
     /// State or country code
     #[serde(rename = "stateOrCountry")]
@@ -234,7 +277,7 @@ pub struct RecentFilings {
     pub items: Option<Vec<String>>,
 
     /// Document sizes in bytes
-    pub size: Vec<i32>,
+    pub size: Vec<u64>,
 
     /// XBRL flags (1 = has XBRL, 0 = no XBRL)
     #[serde(rename = "isXBRL")]
@@ -262,11 +305,17 @@ pub struct DetailedFiling {
     /// EDGAR accession number
     pub accession_number: String,
 
-    /// Filing date (YYYY-MM-DD)
-    pub filing_date: String,
+    /// Filing date; the acceptance date if the table's value does not parse
+    pub filing_date: NaiveDate,
+
+    /// `filing_date` as written in the source, e.g. `2024-11-01`
+    pub filing_date_raw: String,
 
-    /// Report date (if any)
-    pub report_date: Option<String>,
+    /// Period the filing reports on, if any and if it parses
+    pub report_date: Option<NaiveDate>,
+
+    /// `report_date` as written in the source; empty for filings without a reporting period
+    pub report_date_raw: Option<String>,
 
     /// EDGAR acceptance timestamp
     pub acceptance_date_time: DateTime<FixedOffset>,
@@ -287,7 +336,7 @@ pub struct DetailedFiling {
     pub items: Option<String>,
 
     /// Document size in bytes
-    pub size: i32,
+    pub size: u64,
 
     /// Contains XBRL
     pub is_xbrl: bool,
@@ -302,6 +351,83 @@ pub struct DetailedFiling {
     pub primary_doc_description: Option<String>,
 }
 
+/// A definitive proxy statement (`DEF 14A`) and the filing it belongs to.
+#[derive(Debug, Clone)]
+pub struct ProxyStatement {
+    pub filing: DetailedFiling,
+
+    /// URL of the primary document
+    pub url: String,
+
+    /// Primary document content, usually HTML
+    pub document: String,
+}
+
+impl ProxyStatement {
+    /// Parses the Summary Compensation Table; see [`summary_compensation_table`].
+    pub fn summary_compensation(&self) -> Vec<CompensationRow> {
+        summary_compensation_table(&self.document)
+    }
+}
+
+/// An original filing and its amendments, oldest first.
+///
+/// Built by [`FilingOperations::amendment_chain`] or [`AmendmentChain::resolve`]. Filings
+/// belong to one chain when they share a form family (`10-K` and `10-K/A`) and the period
+/// they report on; filings without a period, such as registration statements, are chained
+/// by file number instead.
+#[derive(Debug, Clone)]
+pub struct AmendmentChain {
+    /// Form without the `/A` suffix, e.g. "10-K"
+    pub form: String,
+
+    /// Every filing in the chain, ordered by acceptance time
+    pub filings: Vec<DetailedFiling>,
+}
+
+/// What [`FilingOperations::download_filing`] saved, also written to [`FILING_MANIFEST`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilingManifest {
+    pub cik: u64,
+    pub accession_number: String,
+
+    /// Downloaded files, sorted by name
+    pub files: Vec<ManifestFile>,
+}
+
+/// One file of a downloaded filing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    /// File name, as listed in the filing directory
+    pub name: String,
+
+    /// URL the file was downloaded from
+    pub url: String,
+
+    /// Bytes written
+    pub size: u64,
+
+    /// Last-modified time from the directory listing
+    pub last_modified: String,
+}
+
+impl FilingManifest {
+    /// Reads a manifest previously written into `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` has no manifest or it is not valid JSON.
+    pub fn read(dir: impl AsRef<Path>) -> Result<Self> {
+        let json = std::fs::read_to_string(dir.as_ref().join(FILING_MANIFEST))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Total bytes downloaded.
+    pub fn total_size(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+}
+
 /// Response wrapper for EDGAR `index.json` directory listings.
 #[derive(Debug, Clone, Deserialize)]
 pub struct DirectoryResponse {
@@ -325,7 +451,39 @@ pub struct DirectoryItem {
     pub name: String,
     #[serde(rename = "type")]
     pub type_: String,
-    pub size: String,
+    /// Size in bytes; 0 for folders and generated index pages, which the SEC lists without one.
+    #[serde(deserialize_with = "deserialize_human_size")]
+    pub size: u64,
+}
+
+impl Submission {
+    /// The company's SIC code, parsed from `sic`. `None` for filers without one, such as
+    /// individuals and most funds.
+    pub fn sic_code(&self) -> Option<SicCode> {
+        self.sic.parse().ok()
+    }
+
+    /// The state or country of incorporation, parsed from `state_of_incorporation`.
+    pub fn incorporation_state(&self) -> Option<StateOrCountry> {
+        parse_code(self.state_of_incorporation.as_deref())
+    }
+
+    /// The business address, if the filer reported one.
+    pub fn business_address(&self) -> Option<&Address> {
+        self.addresses.as_ref()?.business.as_ref()
+    }
+
+    /// The mailing address, if the filer reported one.
+    pub fn mailing_address(&self) -> Option<&Address> {
+        self.addresses.as_ref()?.mailing.as_ref()
+    }
+}
+
+impl Address {
+    /// The address's state or country, parsed from `state_or_country`.
+    pub fn state_or_country_code(&self) -> Option<StateOrCountry> {
+        parse_code(self.state_or_country.as_deref())
+    }
 }
 
 impl RecentFilings {
@@ -338,6 +496,111 @@ impl RecentFilings {
     }
 }
 
+impl DetailedFiling {
+    /// The 8-K items reported by this filing, parsed from `items`. Empty for filings
+    /// without items.
+    pub fn item_codes(&self) -> Vec<ItemCode> {
+        self.items
+            .as_deref()
+            .map(ItemCode::parse_list)
+            .unwrap_or_default()
+    }
+}
+
+/// Converts a parallel-array table into rows, skipping rows with invalid acceptance
+/// timestamps. Malformed filing and report dates do not drop a row; see [`DetailedFiling`].
+fn filing_rows(table: &RecentFilings) -> Vec<DetailedFiling> {
+    (0..table.accession_number.len())
+        .filter_map(|idx| DetailedFiling::try_from((table, idx)).ok())
+        .collect()
+}
+
+fn earliest_of_form(filings: Vec<DetailedFiling>, form: &str) -> Option<DetailedFiling> {
+    filings
+        .into_iter()
+        .filter(|filing| filing.form.trim() == form.trim())
+        .min_by_key(|filing| filing.acceptance_date_time)
+}
+
+fn latest_of_form(filings: Vec<DetailedFiling>, form: &str) -> Option<DetailedFiling> {
+    filings
+        .into_iter()
+        .filter(|filing| filing.form.trim() == form.trim())
+        .max_by_key(|filing| filing.acceptance_date_time)
+}
+
+impl AmendmentChain {
+    /// Finds the chain containing `accession_number` among `filings`.
+    ///
+    /// Accession numbers match with or without dashes. Returns `None` if no filing has that
+    /// accession number.
+    pub fn resolve(filings: &[DetailedFiling], accession_number: &str) -> Option<Self> {
+        let target = filings
+            .iter()
+            .find(|f| same_accession(&f.accession_number, accession_number))?;
+        let form = base_form(&target.form);
+
+        let mut chain: Vec<DetailedFiling> = filings
+            .iter()
+            .filter(|f| base_form(&f.form) == form)
+            .filter(
+                |f| match (target.report_date, target.file_number.as_deref()) {
+                    (Some(period), _) => f.report_date == Some(period),
+                    (None, Some(file_number)) if !file_number.is_empty() => {
+                        f.report_date.is_none() && f.file_number.as_deref() == Some(file_number)
+                    }
+                    _ => f.accession_number == target.accession_number,
+                },
+            )
+            .cloned()
+            .collect();
+        chain.sort_by_key(|f| f.acceptance_date_time);
+
+        Some(AmendmentChain {
+            form: form.to_string(),
+            filings: chain,
+        })
+    }
+
+    /// The original filing: the earliest one that is not an amendment, if it is among the
+    /// filings searched.
+    pub fn original(&self) -> Option<&DetailedFiling> {
+        self.filings.iter().find(|f| !is_amendment(&f.form))
+    }
+
+    /// The amendments, oldest first.
+    pub fn amendments(&self) -> impl Iterator<Item = &DetailedFiling> {
+        self.filings.iter().filter(|f| is_amendment(&f.form))
+    }
+
+    /// The most recently accepted filing, which supersedes the others.
+    pub fn latest(&self) -> &DetailedFiling {
+        self.filings
+            .last()
+            .expect("a chain always contains the filing it was resolved from")
+    }
+
+    /// Whether the chain contains at least one amendment.
+    pub fn is_amended(&self) -> bool {
+        self.amendments().next().is_some()
+    }
+}
+
+fn base_form(form: &str) -> &str {
+    let form = form.trim();
+    form.strip_suffix("/A").unwrap_or(form)
+}
+
+fn is_amendment(form: &str) -> bool {
+    form.trim().ends_with("/A")
+}
+
+fn same_accession(a: &str, b: &str) -> bool {
+    a.chars()
+        .filter(|c| *c != '-')
+        .eq(b.trim().chars().filter(|c| *c != '-'))
+}
+
 impl TryFrom<(&RecentFilings, usize)> for DetailedFiling {
     type Error = chrono::ParseError;
 
@@ -345,11 +608,19 @@ impl TryFrom<(&RecentFilings, usize)> for DetailedFiling {
         (recent, idx): (&RecentFilings, usize),
     ) -> std::result::Result<Self, chrono::ParseError> {
         let acceptance_date_time = DateTime::parse_from_rfc3339(&recent.acceptance_date_time[idx])?;
+        let filing_date_raw = recent.filing_date[idx].clone();
+        let filing_date =
+            parse_edgar_date(&filing_date_raw).unwrap_or_else(|| acceptance_date_time.date_naive());
+        // Filings without a reporting period carry an empty string here.
+        let report_date_raw = recent.get_vec_item_at(&recent.report_date, idx);
+        let report_date = report_date_raw.as_deref().and_then(parse_edgar_date);
 
         Ok(DetailedFiling {
             accession_number: recent.accession_number[idx].clone(),
-            filing_date: recent.filing_date[idx].clone(),
-            report_date: recent.get_vec_item_at(&recent.report_date, idx),
+            filing_date,
+            filing_date_raw,
+            report_date,
+            report_date_raw,
             acceptance_date_time,
             act: recent.get_vec_item_at(&recent.act, idx),
             form: recent.form[idx].clone(),
@@ -365,9 +636,129 @@ impl TryFrom<(&RecentFilings, usize)> for DetailedFiling {
     }
 }
 
+/// Converts a filing entry from an Atom feed (`current_feed`, `company_feed`).
+///
+/// Company feed entries carry full filing metadata in their `<content>` block; current feed
+/// entries only have a title, link, id, and update time, so the filing date falls back to the
+/// date of `updated` and the optional fields are left empty. The filer's CIK is not part of
+/// `DetailedFiling`; use [`AtomEntry::cik`] alongside the converted filing.
+///
+/// Fails with `EdgarError::InvalidFormat` if the entry has no accession number, form type,
+/// or parseable timestamp.
+#[cfg(feature = "atom")]
+impl TryFrom<&AtomEntry> for DetailedFiling {
+    type Error = EdgarError;
+
+    fn try_from(entry: &AtomEntry) -> Result<Self> {
+        let missing = |what: &str| {
+            EdgarError::InvalidFormat(format!("atom entry {} has no {}", entry.id, what))
+        };
+        let accession_number = entry
+            .accession_number()
+            .ok_or_else(|| missing("accession number"))?;
+        let form = entry.form_type().ok_or_else(|| missing("form type"))?;
+        let content = entry.content.as_ref();
+
+        let updated = entry
+            .updated
+            .as_deref()
+            .or(entry.published.as_deref())
+            .and_then(|ts| DateTime::parse_from_rfc3339(ts.trim()).ok());
+        let filing_date_raw = content.and_then(|c| c.filing_date.clone());
+        let filing_date = match filing_date_raw.as_deref() {
+            Some(date) => parse_edgar_date(date).ok_or_else(|| missing("valid filing date"))?,
+            None => updated
+                .map(|ts| ts.date_naive())
+                .ok_or_else(|| missing("filing date"))?,
+        };
+        let acceptance_date_time = match updated {
+            Some(ts) => ts,
+            None => DateTime::parse_from_rfc3339(&format!("{}T00:00:00-05:00", filing_date))
+                .map_err(|_| missing("valid filing date"))?,
+        };
+
+        Ok(DetailedFiling {
+            accession_number,
+            filing_date,
+            filing_date_raw: filing_date_raw.unwrap_or_else(|| filing_date.to_string()),
+            report_date: None,
+            report_date_raw: None,
+            acceptance_date_time,
+            act: content.and_then(|c| c.act.clone()),
+            form,
+            file_number: content.and_then(|c| c.file_number.clone()),
+            film_number: content.and_then(|c| c.film_number.clone()),
+            items: content
+                .and_then(|c| c.items_desc.as_deref())
+                .map(items_from_description)
+                .filter(|items| !items.is_empty()),
+            size: content.and_then(|c| c.size).unwrap_or_default(),
+            is_xbrl: content.is_some_and(|c| c.xbrl_href.is_some()),
+            is_inline_xbrl: false,
+            primary_document: None,
+            primary_doc_description: None,
+        })
+    }
+}
+
+/// Turns a feed's `items 1.01, 2.03, 8.01and9.01` into the submissions format
+/// `1.01,2.03,8.01,9.01`.
+#[cfg(feature = "atom")]
+fn items_from_description(description: &str) -> String {
+    description
+        .split(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .map(|item| item.trim_matches('.'))
+        .filter(|item| item.contains('.'))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// K-way merge of per-company filing lists into the `limit` most recently accepted.
+fn merge_newest(
+    per_company: Vec<(Cik, Vec<DetailedFiling>)>,
+    limit: usize,
+) -> Vec<(Cik, DetailedFiling)> {
+    let mut lists: Vec<_> = per_company
+        .into_iter()
+        .map(|(cik, mut filings)| {
+            // Submissions are newest-first already; sorting makes sure of it.
+            filings.sort_by_key(|f| Reverse(f.acceptance_date_time));
+            (cik, filings.into_iter())
+        })
+        .collect();
+
+    // Heads of every list; ties go to the company listed first.
+    let mut heads = BinaryHeap::new();
+    let mut pending: Vec<Option<DetailedFiling>> = Vec::with_capacity(lists.len());
+    for (idx, (_, filings)) in lists.iter_mut().enumerate() {
+        let head = filings.next();
+        if let Some(filing) = &head {
+            heads.push((filing.acceptance_date_time, Reverse(idx)));
+        }
+        pending.push(head);
+    }
+
+    let mut merged = Vec::with_capacity(limit.min(64));
+    while merged.len() < limit {
+        let Some((_, Reverse(idx))) = heads.pop() else {
+            break;
+        };
+        let filing = pending[idx]
+            .take()
+            .expect("heap entries have a pending filing");
+        merged.push((lists[idx].0, filing));
+        if let Some(next) = lists[idx].1.next() {
+            heads.push((next.acceptance_date_time, Reverse(idx)));
+            pending[idx] = Some(next);
+        }
+    }
+    merged
+}
+
 #[derive(Debug)]
 enum UrlType {
     Submission,
+    SubmissionFile,
     FilingDirectory,
     EntityDirectory,
     FilingContent,
@@ -377,6 +768,24 @@ enum UrlType {
 }
 
 impl Edgar {
+    /// Turns user input such as a command-line argument into a [`Cik`].
+    ///
+    /// Accepts a CIK with or without leading zeros or a `CIK` prefix (`320193`, `0000320193`,
+    /// `CIK0000320193`). Anything else is treated as a ticker symbol and looked up via
+    /// `company_cik` (requires the `company` feature), unless ticker resolution was turned
+    /// off with [`EdgarConfig::with_ticker_resolution`]. This is
+    /// [`resolve_entity`](Edgar::resolve_entity) for a string.
+    ///
+    /// [`EdgarConfig::with_ticker_resolution`]: crate::EdgarConfig::with_ticker_resolution
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` for non-numeric input when ticker resolution is
+    /// unavailable, and `EdgarError::TickerNotFound` for unknown tickers.
+    pub async fn resolve_cik(&self, id: &str) -> Result<Cik> {
+        self.resolve_entity(EntityId::from(id)).await
+    }
+
     fn build_url(&self, url_type: UrlType, params: &[&str]) -> Result<String> {
         match url_type {
             UrlType::Submission => {
@@ -386,6 +795,17 @@ impl Edgar {
                     self.edgar_data_url, cik
                 ))
             }
+            UrlType::SubmissionFile => {
+                // Older filing pages: /submissions/CIK##########-submissions-NNN.json
+                let (cik, name) = (format!("{:0>10}", params[0]), params[1]);
+                if !name.starts_with(&format!("CIK{}", cik)) || !name.ends_with(".json") {
+                    return Err(EdgarError::InvalidFormat(format!(
+                        "{} is not a submissions file for CIK {}",
+                        name, cik
+                    )));
+                }
+                Ok(format!("{}/submissions/{}", self.edgar_data_url, name))
+            }
             UrlType::FilingDirectory => {
                 let (cik, acc_no) = (params[0], params[1]);
                 let formatted_acc = acc_no.replace("-", "");
@@ -439,23 +859,51 @@ impl Edgar {
         }
     }
 
-    fn get_filing_url(&self, cik: &str, accession_number: &str, filename: &str) -> Result<String> {
-        self.build_url(UrlType::FilingContent, &[cik, accession_number, filename])
+    pub(crate) fn get_filing_url(
+        &self,
+        cik: Cik,
+        accession_number: &str,
+        filename: &str,
+    ) -> Result<String> {
+        self.build_url(
+            UrlType::FilingContent,
+            &[&cik.to_string(), accession_number, filename],
+        )
     }
 
     // Add a convenience method to get text filing URL directly
-    fn get_text_filing_url(&self, cik: &str, accession_number: &str) -> Result<String> {
-        self.build_url(UrlType::TextFiling, &[cik, accession_number])
+    pub(crate) fn get_text_filing_url(&self, cik: Cik, accession_number: &str) -> Result<String> {
+        self.build_url(UrlType::TextFiling, &[&cik.to_string(), accession_number])
     }
 
     // Add a convenience method to get original filing URL directly
-    fn get_original_filing_url(&self, cik: &str, accession_number: &str) -> Result<String> {
-        self.build_url(UrlType::OriginalFiling, &[cik, accession_number])
+    fn get_original_filing_url(&self, cik: Cik, accession_number: &str) -> Result<String> {
+        self.build_url(
+            UrlType::OriginalFiling,
+            &[&cik.to_string(), accession_number],
+        )
     }
 
     // Add a convenience method to get SGML header URL directly
-    fn get_sgml_header_url(&self, cik: &str, accession_number: &str) -> Result<String> {
-        self.build_url(UrlType::SgmlHeader, &[cik, accession_number])
+    fn get_sgml_header_url(&self, cik: Cik, accession_number: &str) -> Result<String> {
+        self.build_url(UrlType::SgmlHeader, &[&cik.to_string(), accession_number])
+    }
+
+    /// Fetches the submissions payload and returns its recent rows plus the older pages it
+    /// lists, for searches that may need the full history.
+    async fn filing_history(&self, cik: Cik) -> Result<(Vec<DetailedFiling>, Vec<FilingFile>)> {
+        let submission = self.submissions(cik).await?;
+        Ok((
+            filing_rows(&submission.filings.recent),
+            submission.filings.files,
+        ))
+    }
+
+    /// Fetches one older submissions page as rows.
+    async fn filing_page(&self, cik: Cik, page: &FilingFile) -> Result<Vec<DetailedFiling>> {
+        Ok(filing_rows(
+            &self.get_submission_file(cik, &page.name).await?,
+        ))
     }
 }
 
@@ -472,13 +920,19 @@ impl Edgar {
 /// 3) Download the primary document with `get_latest_filing_content()` or `get_filing_content_by_id()`.
 ///
 /// **Behavior notes:**
+/// - Every single-company `cik` argument is an `impl Into<EntityId>`: pass a `u64`, a
+///   [`Cik`], a ticker or CIK string, or [`EntityId::name`]. Tickers and names are resolved
+///   with [`Edgar::resolve_entity`]. `get_filing_url_from_id` builds a URL without I/O and
+///   rejects tickers and names.
 /// - `filings()` filters in-memory and returns results in the same order as the SEC provides
 ///   (typically newest-first).
-/// - When converting the SEC parallel arrays into rows, entries with invalid timestamps are
-///   skipped rather than failing the entire call.
+/// - When converting the SEC parallel arrays into rows, entries with invalid acceptance
+///   timestamps are skipped rather than failing the entire call. Malformed filing and report
+///   dates keep the row, with the raw strings in `filing_date_raw` and `report_date_raw`.
 /// - If you filter by form types, amendments can be included automatically via
 ///   [`FilingOptions::with_include_amendments`] (enabled by default).
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl FilingOperations for Edgar {
     /// Retrieves submission history for a given CIK.
     ///
@@ -487,13 +941,61 @@ impl FilingOperations for Edgar {
     ///
     /// The SEC expects a zero-padded CIK in the URL; edgarkit handles that formatting for you.
     ///
+    /// With [`EdgarConfig::with_corporate_history`](crate::EdgarConfig::with_corporate_history)
+    /// enabled, each payload is also recorded in the client's corporate history; see
+    /// [`Edgar::corporate_events`].
+    ///
     /// # Errors
     /// Returns an error if the company is not found, the response is not valid JSON, or the
     /// request fails.
-    async fn submissions(&self, cik: &str) -> Result<Submission> {
+    async fn submissions(&self, cik: impl Into<EntityId> + Send) -> Result<Submission> {
+        Ok(self.submissions_with_report(cik).await?.0)
+    }
+
+    /// Retrieves the submissions payload along with what lenient parsing repaired in it.
+    ///
+    /// Parsing is strict unless lenient mode is enabled with
+    /// [`EdgarConfig::with_parse_mode`](crate::EdgarConfig::with_parse_mode). In lenient mode
+    /// a row whose values could not be repaired is dropped from every parallel array of the
+    /// recent filings table, so the remaining rows stay aligned.
+    ///
+    /// # Errors
+    /// Returns an error if the company is not found, the response is not valid JSON, the
+    /// payload cannot be repaired (or does not match in strict mode), or the request fails.
+    async fn submissions_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(Submission, ParseReport)> {
+        let cik = &self.resolve_entity(cik).await?.to_string();
         let url = self.build_url(UrlType::Submission, &[cik])?;
         let response = self.get(&url).await?;
-        Ok(serde_json::from_str::<Submission>(&response)?)
+        let (submission, report) = self.parse_json::<Submission>(&url, &response)?;
+        self.record_submission(&submission);
+        Ok((submission, report))
+    }
+
+    /// Retrieves one of the older filing pages listed in `Submission::filings.files`.
+    ///
+    /// The submissions payload only embeds the most recent filings (at least one year, or
+    /// 1,000 rows). Anything older is split into `CIK##########-submissions-NNN.json` files,
+    /// each of which has the same parallel-array layout as the recent table. This returns
+    /// such a page as [`RecentFilings`], so the rows can be converted with
+    /// `DetailedFiling::try_from` exactly like recent ones.
+    ///
+    /// `name` is the [`FilingFile::name`] from the submissions payload.
+    ///
+    /// # Errors
+    /// Returns `EdgarError::InvalidFormat` if `name` does not belong to `cik`, and
+    /// `EdgarError::NotFound` if the page does not exist.
+    async fn get_submission_file(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        name: &str,
+    ) -> Result<RecentFilings> {
+        let cik = &self.resolve_entity(cik).await?.to_string();
+        let url = self.build_url(UrlType::SubmissionFile, &[cik, name])?;
+        let response = self.get(&url).await?;
+        Ok(serde_json::from_str::<RecentFilings>(&response)?)
     }
 
     /// Retrieves recent filings for a given CIK.
@@ -503,18 +1005,101 @@ impl FilingOperations for Edgar {
     ///
     /// If a specific row has an invalid timestamp (e.g., malformed `acceptanceDateTime`), that row is
     /// skipped; the rest of the results are returned.
-    async fn get_recent_filings(&self, cik: &str) -> Result<Vec<DetailedFiling>> {
+    async fn get_recent_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>> {
         let submission = self.submissions(cik).await?;
-        let mut detailed_filings = Vec::new();
+        Ok(filing_rows(&submission.filings.recent))
+    }
 
-        // Process recent filings
-        for idx in 0..submission.filings.recent.accession_number.len() {
-            if let Ok(filing) = DetailedFiling::try_from((&submission.filings.recent, idx)) {
-                detailed_filings.push(filing);
+    /// Retrieves every filing the company has made, newest first.
+    ///
+    /// Starts from the recent table in the submissions payload and then fetches each older
+    /// page listed in `Submission::filings.files`, so a long-lived filer costs one request per
+    /// page on top of the submissions request.
+    ///
+    /// # Errors
+    /// Returns an error if the submissions payload or any older page cannot be fetched.
+    async fn all_filings(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<DetailedFiling>> {
+        let cik = self.resolve_entity(cik).await?;
+        let (mut filings, mut pages) = self.filing_history(cik).await?;
+        pages.sort_by(|a, b| b.filing_to.cmp(&a.filing_to));
+        for page in &pages {
+            filings.extend(self.filing_page(cik, page).await?);
+        }
+        filings.sort_by_key(|filing| Reverse(filing.acceptance_date_time));
+        Ok(filings)
+    }
+
+    /// Finds the company's earliest filing of exactly `form`, searching its full history.
+    ///
+    /// Older pages are fetched oldest first and the search stops at the first page with a
+    /// match. The form must match exactly (after trimming), so `"10-K"` does not match
+    /// `"10-K/A"`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// if let Some(filing) = edgar.first_filing(320193, "10-K").await? {
+    ///     println!("first 10-K filed {}", filing.filing_date);
+    /// }
+    /// ```
+    async fn first_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>> {
+        let cik = self.resolve_entity(cik).await?;
+        let (recent, mut pages) = self.filing_history(cik).await?;
+        pages.sort_by(|a, b| a.filing_from.cmp(&b.filing_from));
+        for page in &pages {
+            if let Some(filing) = earliest_of_form(self.filing_page(cik, page).await?, form) {
+                return Ok(Some(filing));
             }
         }
+        Ok(earliest_of_form(recent, form))
+    }
 
-        Ok(detailed_filings)
+    /// Finds the company's most recent filing of exactly `form`, searching its full history.
+    ///
+    /// Unlike [`filings`](FilingOperations::filings), older pages are searched (newest
+    /// first) when the recent table has no match, which matters for forms a company stopped
+    /// filing years ago.
+    async fn latest_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>> {
+        let cik = self.resolve_entity(cik).await?;
+        let (recent, mut pages) = self.filing_history(cik).await?;
+        if let Some(filing) = latest_of_form(recent, form) {
+            return Ok(Some(filing));
+        }
+        pages.sort_by(|a, b| b.filing_to.cmp(&a.filing_to));
+        for page in &pages {
+            if let Some(filing) = latest_of_form(self.filing_page(cik, page).await?, form) {
+                return Ok(Some(filing));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Counts the company's filings by form type across its full history.
+    ///
+    /// Amendments are counted under their own form (`10-K/A` separately from `10-K`).
+    async fn filing_count_by_form(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<BTreeMap<String, usize>> {
+        let mut counts = BTreeMap::new();
+        for filing in self.all_filings(cik).await? {
+            *counts.entry(filing.form.trim().to_string()).or_insert(0) += 1;
+        }
+        Ok(counts)
     }
 
     /// Gets filings for a company, with optional filtering by form type, offset, and limit.
@@ -531,6 +1116,7 @@ impl FilingOperations for Edgar {
     /// * `opts` - Optional filters:
     ///   - `form_types`: Which form types to include
     ///   - `include_amendments`: Whether to add amendment forms automatically (default: true)
+    ///   - `items`: Keep only filings reporting one of these 8-K items
     ///   - `offset`: Skip this many filings from the start
     ///   - `limit`: Return at most this many filings
     ///
@@ -549,19 +1135,23 @@ impl FilingOperations for Edgar {
     ///
     ///     // Returns both S-1 and S-1/A filings (default behavior).
     ///     let opts = FilingOptions::new().with_form_type("S-1".to_string());
-    ///     let filings = edgar.filings("320193", Some(opts)).await?;
+    ///     let filings = edgar.filings(320193, Some(opts)).await?;
     ///
     ///     // Returns only S-1 filings, excluding amendments.
     ///     let opts = FilingOptions::new()
     ///         .with_form_type("S-1".to_string())
     ///         .with_include_amendments(false);
-    ///     let filings_no_amends = edgar.filings("320193", Some(opts)).await?;
+    ///     let filings_no_amends = edgar.filings(320193, Some(opts)).await?;
     ///
     ///     println!("with_amendments={}, without_amendments={}", filings.len(), filings_no_amends.len());
     ///     Ok(())
     /// }
     /// ```
-    async fn filings(&self, cik: &str, opts: Option<FilingOptions>) -> Result<Vec<DetailedFiling>> {
+    async fn filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<DetailedFiling>> {
         let mut all_filings = self.get_recent_filings(cik).await?;
 
         // Apply filters if provided
@@ -580,7 +1170,12 @@ impl FilingOperations for Edgar {
                 }
 
                 all_filings
-                    .retain(|filing| expanded_types.iter().any(|ft| ft == &filing.form.trim()));
+                    .retain(|filing| expanded_types.iter().any(|ft| ft == filing.form.trim()));
+            }
+
+            if let Some(ref items) = opts.items {
+                all_filings
+                    .retain(|filing| filing.item_codes().iter().any(|item| items.contains(item)));
             }
 
             // Apply offset
@@ -597,6 +1192,94 @@ impl FilingOperations for Edgar {
         Ok(all_filings)
     }
 
+    /// Gets the `limit` most recent filings across `ciks`, ordered by acceptance time with
+    /// the newest first.
+    ///
+    /// Each company's submissions are fetched (a few at a time, within the client's rate
+    /// limit), filtered to `form_types` with amendments included as in [`filings`], and
+    /// merged. An empty `form_types` keeps every form. Each filing is paired with the CIK it
+    /// was fetched for, since `DetailedFiling` does not carry one.
+    ///
+    /// [`filings`]: FilingOperations::filings
+    ///
+    /// # Errors
+    ///
+    /// Fails with the first error from fetching a company's submissions.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let latest = edgar
+    ///     .latest_across([320193u64, 789019, 1652044], &["8-K", "10-Q"], 20)
+    ///     .await?;
+    /// for (cik, filing) in latest {
+    ///     println!("{} {} {}", filing.acceptance_date_time, cik, filing.form);
+    /// }
+    /// ```
+    async fn latest_across<I>(
+        &self,
+        ciks: I,
+        form_types: &[&str],
+        limit: usize,
+    ) -> Result<Vec<(Cik, DetailedFiling)>>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        let ciks: Vec<Cik> = ciks.into_iter().map(Into::into).collect();
+        let form_types: Vec<String> = form_types.iter().map(|s| s.to_string()).collect();
+        let per_company: Vec<(Cik, Vec<DetailedFiling>)> = stream::iter(ciks)
+            .map(|cik| {
+                let mut opts = FilingOptions::new().with_limit(limit);
+                if !form_types.is_empty() {
+                    opts = opts.with_form_types(form_types.clone());
+                }
+                async move { Ok::<_, EdgarError>((cik, self.filings(cik, Some(opts)).await?)) }
+            })
+            .buffered(LATEST_ACROSS_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(merge_newest(per_company, limit))
+    }
+
+    /// Retrieves filings for several companies, at most `max_concurrency` at a time.
+    ///
+    /// Each company's submissions are fetched and filtered with `opts` as in [`filings`].
+    /// Results are paired with their CIK and returned in the order of `ciks`; a company that
+    /// fails (an unknown CIK, a network error) gets its own error without affecting the
+    /// others. Every request still goes through the client's rate limiter.
+    ///
+    /// [`filings`]: FilingOperations::filings
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let opts = FilingOptions::new().with_form_type("10-K").with_limit(1);
+    /// for (cik, filings) in edgar.filings_for_ciks([320193u64, 789019], Some(opts), 4).await {
+    ///     match filings {
+    ///         Ok(filings) => println!("{}: {:?}", cik, filings.first().map(|f| f.filing_date)),
+    ///         Err(e) => eprintln!("{}: {}", cik, e),
+    ///     }
+    /// }
+    /// ```
+    async fn filings_for_ciks<I>(
+        &self,
+        ciks: I,
+        opts: Option<FilingOptions>,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<Vec<DetailedFiling>>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>,
+    {
+        let ciks: Vec<Cik> = ciks.into_iter().map(Into::into).collect();
+        fan_out(ciks, max_concurrency, |cik| {
+            let opts = opts.clone();
+            async move { (cik, self.filings(cik, opts).await) }
+        })
+        .await
+    }
+
     /// Retrieves the filing directory for a specific filing.
     ///
     /// The filing directory is an `index.json` listing of the files that make up an accession.
@@ -620,14 +1303,103 @@ impl FilingOperations for Edgar {
     /// * Network-related errors during HTTP requests.
     async fn filing_directory(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         accession_number: &str,
     ) -> Result<DirectoryResponse> {
+        let cik = &self.resolve_entity(cik).await?.to_string();
         let url = self.build_url(UrlType::FilingDirectory, &[cik, accession_number])?;
         let response = self.get(&url).await?;
         Ok(serde_json::from_str::<DirectoryResponse>(&response)?)
     }
 
+    /// Downloads every file of a filing into `dest_dir` and writes a [`FILING_MANIFEST`].
+    ///
+    /// Files are listed from the filing's `index.json` and saved under their own names,
+    /// a few at a time through the client's rate limiter. Subdirectories are skipped. With a
+    /// non-empty `extensions` (e.g. `&["htm", "xml"]`), only files with one of those
+    /// extensions are downloaded; the comparison ignores case and a leading dot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing directory does not exist, and the errors
+    /// of [`Edgar::download_to_file`] for any file that fails. Files already written are left
+    /// in place, but no manifest is written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let manifest = edgar
+    ///     .download_filing(320193, "0000320193-23-000106", "aapl-10k", &[])
+    ///     .await?;
+    /// println!("{} files, {} bytes", manifest.files.len(), manifest.total_size());
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn download_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        dest_dir: impl AsRef<Path> + Send,
+        extensions: &[&str],
+    ) -> Result<FilingManifest> {
+        let cik = self.resolve_entity(cik).await?;
+        let dest_dir = dest_dir.as_ref();
+        let directory = self
+            .filing_directory(cik, accession_number)
+            .await?
+            .directory;
+        tokio::fs::create_dir_all(dest_dir).await?;
+
+        let wanted: Vec<String> = extensions
+            .iter()
+            .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
+            .collect();
+        let items = directory.item.into_iter().filter(|item| {
+            let name = Path::new(&item.name);
+            item.type_ != "folder.gif"
+                && name.file_name() == Some(name.as_os_str())
+                && (wanted.is_empty()
+                    || name
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| {
+                            wanted.iter().any(|wanted| wanted.eq_ignore_ascii_case(ext))
+                        }))
+        });
+
+        let mut files: Vec<ManifestFile> = stream::iter(items)
+            .map(|item| async move {
+                let url = self.get_filing_url(cik, accession_number, &item.name)?;
+                let size = self
+                    .download_to_file(&url, dest_dir.join(&item.name))
+                    .await?;
+                Ok::<_, EdgarError>(ManifestFile {
+                    name: item.name,
+                    url,
+                    size,
+                    last_modified: item.last_modified,
+                })
+            })
+            .buffer_unordered(DOWNLOAD_FILING_CONCURRENCY)
+            .try_collect()
+            .await?;
+        files.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let manifest = FilingManifest {
+            cik: cik.as_u64(),
+            accession_number: accession_number.to_string(),
+            files,
+        };
+        tokio::fs::write(
+            dest_dir.join(FILING_MANIFEST),
+            serde_json::to_vec_pretty(&manifest)?,
+        )
+        .await?;
+        Ok(manifest)
+    }
+
     /// Retrieves the entity directory for a CIK.
     ///
     /// The entity directory is an `index.json` listing at the company level. It is useful for
@@ -647,7 +1419,8 @@ impl FilingOperations for Edgar {
     /// * `EdgarError::NotFound` - If the entity directory for the given CIK is not found.
     /// * `EdgarError::InvalidResponse` - If the response data is malformed.
     /// * Network-related errors during HTTP requests.
-    async fn entity_directory(&self, cik: &str) -> Result<DirectoryResponse> {
+    async fn entity_directory(&self, cik: impl Into<EntityId> + Send) -> Result<DirectoryResponse> {
+        let cik = &self.resolve_entity(cik).await?.to_string();
         let url = self.build_url(UrlType::EntityDirectory, &[cik])?;
         let response = self.get(&url).await?;
         Ok(serde_json::from_str::<DirectoryResponse>(&response)?)
@@ -661,21 +1434,32 @@ impl FilingOperations for Edgar {
     ///
     /// # Parameters
     ///
-    /// * `cik` - A string slice representing the Central Index Key (CIK) of the company.
+    /// * `cik` - The company's CIK, as a `Cik`, number, or CIK string. Tickers and names are
+    ///   rejected, since building the URL makes no request to resolve them.
     /// * `filing_id` - A string slice representing the combined filing ID.
     ///
     /// # Returns
     ///
     /// * `Result<String>` - A `Result` containing a `String` with the URL for accessing the filing if successful.
     ///   If the filing ID format is invalid, it returns an `Err` containing an `EdgarError::InvalidResponse`.
-    fn get_filing_url_from_id(&self, cik: &str, filing_id: &str) -> Result<String> {
+    ///   Tickers, names, and CIKs longer than ten digits return `EdgarError::InvalidFormat`.
+    fn get_filing_url_from_id(&self, cik: impl Into<EntityId>, filing_id: &str) -> Result<String> {
+        let cik = match cik.into() {
+            EntityId::Cik(cik) => Cik::checked(cik.as_u64())?,
+            id => {
+                return Err(EdgarError::InvalidFormat(format!(
+                    "{} is not a CIK; resolve it with resolve_entity first",
+                    id
+                )));
+            }
+        };
         let parts: Vec<&str> = filing_id.split(":").collect();
         if parts.len() != 2 {
             return Err(EdgarError::InvalidResponse(
                 "Invalid filing ID format. Expected 'accession_number:filename'".to_string(),
             ));
         }
-        Ok(self.get_filing_url(cik, parts[0], parts[1])?)
+        self.get_filing_url(cik, parts[0], parts[1])
     }
 
     /// Retrieves the content of a specific filing based on the combined filing ID.
@@ -691,7 +1475,12 @@ impl FilingOperations for Edgar {
     ///
     /// * `Result<String>` - A `Result` containing a `String` with the content of the filing if successful.
     ///   If an error occurs during the process, it returns an `Err` containing the error.
-    async fn get_filing_content_by_id(&self, cik: &str, filing_id: &str) -> Result<String> {
+    async fn get_filing_content_by_id(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        filing_id: &str,
+    ) -> Result<String> {
+        let cik = self.resolve_entity(cik).await?;
         let url = self.get_filing_url_from_id(cik, filing_id)?;
         self.get(&url).await
     }
@@ -729,13 +1518,18 @@ impl FilingOperations for Edgar {
     ///
     ///     // Gets the latest 10-Q/10-Q-A/10-K/10-K-A filing (newest wins).
     ///     let content = edgar
-    ///         .get_latest_filing_content("320193", &["10-Q", "10-K"])
+    ///         .get_latest_filing_content(320193, &["10-Q", "10-K"])
     ///         .await?;
     ///     println!("Downloaded {} bytes", content.len());
     ///     Ok(())
     /// }
     /// ```
-    async fn get_latest_filing_content(&self, cik: &str, form_types: &[&str]) -> Result<String> {
+    async fn get_latest_filing_content(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form_types: &[&str],
+    ) -> Result<String> {
+        let cik = self.resolve_entity(cik).await?;
         if form_types.is_empty() {
             return Err(EdgarError::InvalidResponse(
                 "form_types must not be empty".to_string(),
@@ -750,7 +1544,7 @@ impl FilingOperations for Edgar {
         let filing = filings
             .iter()
             .find(|f| f.primary_document.is_some())
-            .ok_or(EdgarError::NotFound)?;
+            .ok_or_else(|| EdgarError::not_found(ErrorContext::for_filing(cik, None)))?;
 
         let primary_doc = filing
             .primary_document
@@ -772,9 +1566,10 @@ impl FilingOperations for Edgar {
     /// eagerly fetching the documents.
     async fn get_text_filing_links(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         opts: Option<FilingOptions>,
     ) -> Result<Vec<(DetailedFiling, String, String)>> {
+        let cik = self.resolve_entity(cik).await?;
         let filings = self.filings(cik, opts).await?;
 
         let mut links = Vec::new();
@@ -797,9 +1592,10 @@ impl FilingOperations for Edgar {
     /// URLs, but does not download anything.
     async fn get_sgml_header_links(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         opts: Option<FilingOptions>,
     ) -> Result<Vec<(DetailedFiling, String, String)>> {
+        let cik = self.resolve_entity(cik).await?;
         let filings = self.filings(cik, opts).await?;
 
         let mut links = Vec::new();
@@ -815,12 +1611,488 @@ impl FilingOperations for Edgar {
 
         Ok(links)
     }
+
+    /// Downloads a complete submission text file and splits it into its documents.
+    ///
+    /// The `<accession>.txt` file bundles every document in a filing, so this is the
+    /// quickest way to reach a specific exhibit (say, `EX-21.1`) without listing the filing
+    /// directory and guessing filenames.
+    ///
+    /// # Parameters
+    ///
+    /// * `cik` - The company's Central Index Key
+    /// * `accession_number` - Accession number with dashes (e.g., "0000320193-23-000106")
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the download fails or the file contains no `<DOCUMENT>` blocks.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let documents = edgar
+    ///     .get_submission_documents(320193, "0000320193-23-000106")
+    ///     .await?;
+    /// let subsidiaries = documents.iter().find(|d| d.document_type == "EX-21.1");
+    /// ```
+    async fn get_submission_documents(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<SubmissionDocument>> {
+        let url = self.get_text_filing_url(self.resolve_entity(cik).await?, accession_number)?;
+        let content = self.get(&url).await?;
+        SubmissionParser::new(SubmissionConfig::default()).parse(&content)
+    }
+
+    /// Extracts one item, such as risk factors or MD&A, from a 10-K or 10-Q.
+    ///
+    /// The filing is looked up in the company's recent submissions for its form type and
+    /// primary document, which is then split with
+    /// [`split_sections`](crate::parsing::sections::split_sections).
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing is not among the company's recent
+    /// filings, has no primary document, or does not contain the item.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    /// use edgarkit::parsing::sections::Item;
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let risks = edgar
+    ///     .get_filing_section(320193, "0000320193-23-000106", Item::RiskFactors)
+    ///     .await?;
+    /// println!("{}", risks.text);
+    /// ```
+    async fn get_filing_section(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        item: Item,
+    ) -> Result<Section> {
+        self.filing(cik, accession_number)
+            .await?
+            .section(item)
+            .await
+    }
+
+    /// Downloads the company's most recent definitive proxy statement.
+    ///
+    /// Additional soliciting materials (`DEFA14A`) and preliminary proxies (`PRE 14A`) are not
+    /// considered.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if none of the company's recent filings is a `DEF 14A`
+    /// with a primary document.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let proxy = edgar.get_latest_proxy(320193).await?;
+    /// for row in proxy.summary_compensation() {
+    ///     println!("{} {}: {:?}", row.year, row.name, row.total);
+    /// }
+    /// ```
+    async fn get_latest_proxy(&self, cik: impl Into<EntityId> + Send) -> Result<ProxyStatement> {
+        let cik = self.resolve_entity(cik).await?;
+        let opts = FilingOptions::new()
+            .with_form_type("DEF 14A")
+            .with_include_amendments(false);
+        let filing = self
+            .filings(cik, Some(opts))
+            .await?
+            .into_iter()
+            .find(|f| {
+                f.primary_document
+                    .as_deref()
+                    .is_some_and(|doc| !doc.is_empty())
+            })
+            .ok_or_else(|| EdgarError::not_found(ErrorContext::for_filing(cik, None)))?;
+
+        let primary_doc = filing.primary_document.as_deref().unwrap_or_default();
+        let url = self.get_filing_url(cik, &filing.accession_number, primary_doc)?;
+        let document = self.get(&url).await?;
+        Ok(ProxyStatement {
+            filing,
+            url,
+            document,
+        })
+    }
+
+    /// Lists the company's beneficial ownership reports, newest first.
+    ///
+    /// Includes `SC 13D`, `SC 13G`, their `SCHEDULE 13D`/`SCHEDULE 13G` successors, and
+    /// amendments. A company's submissions list both the reports it filed as an owner and
+    /// the reports others filed about its shares, so for an issuer this is its 5% holders.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// for filing in edgar.get_beneficial_ownership_filings(320193).await? {
+    ///     let report = edgar
+    ///         .get_beneficial_ownership(320193, &filing.accession_number)
+    ///         .await?;
+    ///     for person in report.reporting_persons {
+    ///         println!("{}: {:?}%", person.name, person.percent_of_class);
+    ///     }
+    /// }
+    /// ```
+    async fn get_beneficial_ownership_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>> {
+        let forms = BENEFICIAL_OWNERSHIP_FORMS
+            .iter()
+            .map(|form| form.to_string())
+            .collect();
+        self.filings(cik, Some(FilingOptions::new().with_form_types(forms)))
+            .await
+    }
+
+    /// Downloads a Schedule 13D or 13G and parses its cover pages and, for a 13D, the
+    /// purpose of the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing is not among the company's recent
+    /// filings or has no primary document, and `EdgarError::InvalidFormat` if it is not a
+    /// Schedule 13D or 13G.
+    async fn get_beneficial_ownership(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<BeneficialOwnership> {
+        self.filing(cik, accession_number)
+            .await?
+            .beneficial_ownership()
+            .await
+    }
+
+    /// Downloads a Form D notice of exempt offering and parses its XML.
+    ///
+    /// `cik` is the issuer's. The XML is fetched directly from the filing folder, so the
+    /// filing does not need to be among the company's recent submissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no `primary_doc.xml` (Form D
+    /// notices filed on paper before 2009), and `EdgarError::InvalidFormat` if the document
+    /// is not a Form D.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let form = edgar.get_form_d(1_819_404, "0001819404-24-000002").await?;
+    /// println!(
+    ///     "{} sold {:?} of {:?} under {:?}",
+    ///     form.issuer.name,
+    ///     form.offering.total_amount_sold,
+    ///     form.offering.total_offering_amount,
+    ///     form.offering.exemptions
+    /// );
+    /// ```
+    async fn get_form_d(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<FormD> {
+        let url = self.get_filing_url(
+            self.resolve_entity(cik).await?,
+            accession_number,
+            PRIMARY_XML,
+        )?;
+        let xml = self.get(&url).await?;
+        parse_form_d(&xml)
+    }
+
+    /// Finds a filing's original and amendments among the company's filings.
+    ///
+    /// Pass either the original or any amendment; the whole chain comes back ordered by
+    /// acceptance time, so [`AmendmentChain::latest`] is the authoritative version. Only the
+    /// filings listed in the company's submissions are searched, so an amendment of a very
+    /// old filing may come back without its original.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing is not among the company's filings.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let chain = edgar.amendment_chain(320193, "0000320193-23-000106").await?;
+    /// if chain.is_amended() {
+    ///     println!("use {} instead", chain.latest().accession_number);
+    /// }
+    /// ```
+    async fn amendment_chain(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<AmendmentChain> {
+        let cik = self.resolve_entity(cik).await?;
+        let filings = self.filings(cik, None).await?;
+        AmendmentChain::resolve(&filings, accession_number).ok_or_else(|| {
+            EdgarError::not_found(ErrorContext::for_filing(cik, Some(accession_number)))
+        })
+    }
+
+    /// Lists the financial report renderings of an XBRL filing.
+    ///
+    /// EDGAR renders every statement, note, and detail table of an XBRL filing as its own
+    /// HTML page (`R1.htm`, `R2.htm`, ...). This reads `FilingSummary.xml` from the filing
+    /// folder and returns the reports in viewer order, each with the URL of its rendering.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no `FilingSummary.xml` (filings
+    /// without XBRL).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let reports = edgar.get_financial_reports(320193, "0000320193-23-000106").await?;
+    /// for report in reports.iter().filter(|report| report.is_statement()) {
+    ///     println!("{}: {}", report.file_name, report.short_name);
+    /// }
+    /// ```
+    async fn get_financial_reports(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<FinancialReport>> {
+        let cik = self.resolve_entity(cik).await?;
+        let url = self.get_filing_url(cik, accession_number, FILING_SUMMARY)?;
+        let xml = self.get(&url).await?;
+        let mut reports = parse_filing_summary(&xml)?;
+        for report in &mut reports {
+            report.url = Some(self.get_filing_url(cik, accession_number, &report.file_name)?);
+        }
+        Ok(reports)
+    }
+
+    /// Downloads the HTML rendering of one financial report, found by name.
+    ///
+    /// `name` is matched against the reports' short names as [`find_report`] does, so
+    /// `"balance sheet"` finds `CONSOLIDATED BALANCE SHEETS`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no `FilingSummary.xml` or no report
+    /// matches `name`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FilingOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// let html = edgar
+    ///     .get_financial_report(320193, "0000320193-23-000106", "balance sheet")
+    ///     .await?;
+    /// ```
+    async fn get_financial_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        name: &str,
+    ) -> Result<String> {
+        let cik = self.resolve_entity(cik).await?;
+        let reports = self.get_financial_reports(cik, accession_number).await?;
+        let url = find_report(&reports, name)
+            .and_then(|report| report.url.as_deref())
+            .ok_or_else(|| {
+                EdgarError::not_found(ErrorContext::for_filing(cik, Some(accession_number)))
+            })?;
+        self.get(url).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn filing(accession_number: &str, accepted: &str) -> DetailedFiling {
+        DetailedFiling {
+            accession_number: accession_number.to_string(),
+            filing_date: NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+            filing_date_raw: "2024-05-02".to_string(),
+            report_date: None,
+            report_date_raw: None,
+            acceptance_date_time: DateTime::parse_from_rfc3339(accepted).unwrap(),
+            act: None,
+            form: "8-K".to_string(),
+            file_number: None,
+            film_number: None,
+            items: None,
+            size: 0,
+            is_xbrl: false,
+            is_inline_xbrl: false,
+            primary_document: None,
+            primary_doc_description: None,
+        }
+    }
+
+    #[test]
+    fn test_filing_rows_keep_rows_with_bad_dates() {
+        let table: RecentFilings = serde_json::from_value(serde_json::json!({
+            "accessionNumber": ["a1", "a2", "a3", "a4"],
+            "filingDate": ["2024-05-02", "05/02/2024", "20240502", "2024-05-02"],
+            "reportDate": ["2024-03-31", "", "2024-13-45", "2024-03-31"],
+            "acceptanceDateTime": [
+                "2024-05-02T16:05:00.000Z",
+                "2024-05-02T16:06:00.000Z",
+                "2024-05-02T16:07:00.000Z",
+                "not a timestamp"
+            ],
+            "form": ["10-Q", "8-K", "10-Q", "10-Q"],
+            "size": [1, 2, 3, 4]
+        }))
+        .unwrap();
+
+        let rows = filing_rows(&table);
+        let accessions: Vec<&str> = rows.iter().map(|f| f.accession_number.as_str()).collect();
+        assert_eq!(accessions, vec!["a1", "a2", "a3"]);
+        let may_2 = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
+        assert!(rows.iter().all(|f| f.filing_date == may_2));
+        assert_eq!(rows[1].filing_date_raw, "05/02/2024");
+        assert_eq!(rows[0].report_date, NaiveDate::from_ymd_opt(2024, 3, 31));
+        assert_eq!(rows[1].report_date, None);
+        assert_eq!(rows[2].report_date, None);
+        assert_eq!(rows[2].report_date_raw.as_deref(), Some("2024-13-45"));
+    }
+
+    #[test]
+    fn test_amendment_chain() {
+        let period = NaiveDate::from_ymd_opt(2023, 12, 31);
+        let annual = |accession: &str, form: &str, accepted: &str| DetailedFiling {
+            form: form.to_string(),
+            report_date: period,
+            ..filing(accession, accepted)
+        };
+        let filings = vec![
+            annual(
+                "0000000001-24-000030",
+                "10-K/A",
+                "2024-09-01T16:00:00-04:00",
+            ),
+            annual(
+                "0000000001-24-000020",
+                "10-K/A",
+                "2024-04-29T16:00:00-04:00",
+            ),
+            filing("0000000001-24-000015", "2024-03-15T16:00:00-04:00"),
+            annual("0000000001-24-000010", "10-K", "2024-02-28T16:00:00-05:00"),
+            DetailedFiling {
+                report_date: NaiveDate::from_ymd_opt(2022, 12, 31),
+                ..annual("0000000001-23-000010", "10-K", "2023-02-28T16:00:00-05:00")
+            },
+        ];
+
+        let chain = AmendmentChain::resolve(&filings, "000000000124000020").unwrap();
+        assert_eq!(chain.form, "10-K");
+        let accessions: Vec<&str> = chain
+            .filings
+            .iter()
+            .map(|f| f.accession_number.as_str())
+            .collect();
+        assert_eq!(
+            accessions,
+            vec![
+                "0000000001-24-000010",
+                "0000000001-24-000020",
+                "0000000001-24-000030"
+            ]
+        );
+        assert_eq!(
+            chain.original().unwrap().accession_number,
+            "0000000001-24-000010"
+        );
+        assert_eq!(chain.amendments().count(), 2);
+        assert_eq!(chain.latest().accession_number, "0000000001-24-000030");
+
+        let single = AmendmentChain::resolve(&filings, "0000000001-23-000010").unwrap();
+        assert!(!single.is_amended());
+        assert!(AmendmentChain::resolve(&filings, "0000000001-24-999999").is_none());
+    }
+
+    #[test]
+    fn test_merge_newest_orders_by_acceptance() {
+        let merged = merge_newest(
+            vec![
+                (
+                    Cik::from(1),
+                    vec![
+                        filing("a2", "2024-05-02T16:05:00-04:00"),
+                        filing("a1", "2024-05-01T09:00:00-04:00"),
+                    ],
+                ),
+                (Cik::from(2), Vec::new()),
+                (
+                    Cik::from(3),
+                    vec![
+                        filing("b1", "2024-05-02T08:30:00-04:00"),
+                        filing("b2", "2024-05-02T17:00:00-04:00"),
+                    ],
+                ),
+            ],
+            3,
+        );
+        let order: Vec<(u64, &str)> = merged
+            .iter()
+            .map(|(cik, f)| (cik.as_u64(), f.accession_number.as_str()))
+            .collect();
+        assert_eq!(order, vec![(3, "b2"), (1, "a2"), (3, "b1")]);
+    }
+
+    #[tokio::test]
+    async fn test_strict_cik_rejects_tickers() {
+        let config = crate::EdgarConfig::new(
+            "test_agent example@example.com",
+            10,
+            std::time::Duration::from_secs(30),
+            None,
+        )
+        .with_ticker_resolution(false);
+        let edgar = Edgar::with_config(config).unwrap();
+        assert!(matches!(
+            edgar.resolve_cik("AAPL").await,
+            Err(EdgarError::InvalidFormat(_))
+        ));
+        assert_eq!(
+            edgar.resolve_cik("0000320193").await.unwrap(),
+            Cik::from(320193u64)
+        );
+
+        let id = "0000320193-23-000106:aapl.htm";
+        let url = "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl.htm";
+        assert_eq!(edgar.get_filing_url_from_id(320193, id).unwrap(), url);
+        assert_eq!(edgar.get_filing_url_from_id("0000320193", id).unwrap(), url);
+        assert!(matches!(
+            edgar.get_filing_url_from_id("AAPL", id),
+            Err(EdgarError::InvalidFormat(_))
+        ));
+    }
+
     #[test]
     fn test_datetime_parsing() {
         let sample_dates = vec![
@@ -830,7 +2102,7 @@ mod tests {
         ];
 
         for date in sample_dates {
-            let parsed = DateTime::parse_from_rfc3339(&date);
+            let parsed = DateTime::parse_from_rfc3339(date);
             assert!(parsed.is_ok());
         }
     }
@@ -839,7 +2111,7 @@ mod tests {
     fn test_text_filing_url_format() {
         let edgar = Edgar::new("test_agent example@example.com").unwrap();
 
-        let cik = "1889983";
+        let cik = Cik::from(1889983);
         let accession_number = "0001213900-23-009668";
 
         let url = edgar.get_text_filing_url(cik, accession_number).unwrap();
@@ -857,7 +2129,7 @@ mod tests {
     fn test_sgml_header_url_format() {
         let edgar = Edgar::new("test_agent example@example.com").unwrap();
 
-        let cik = "1889983";
+        let cik = Cik::from(1889983);
         let accession_number = "0001213900-23-009668";
 
         let url = edgar.get_sgml_header_url(cik, accession_number).unwrap();
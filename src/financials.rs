@@ -0,0 +1,706 @@
+//! Normalized financial statements built from company facts.
+//!
+//! [`CompanyFacts`] holds every us-gaap concept a company ever reported, keyed by tag. The
+//! same line item is not always reported under the same tag: Apple reported revenue as
+//! `SalesRevenueNet` until 2018 and as `RevenueFromContractWithCustomerExcludingAssessedTax`
+//! since ASC 606, and other filers use `Revenues`. This module maps the common tags (with
+//! synonyms tried in order, per period) onto fixed [`IncomeStatement`], [`BalanceSheet`],
+//! and [`CashFlowStatement`] structs, one per reporting period.
+//!
+//! Values are read in `USD` (`USD/shares` for per-share amounts). For each period the most
+//! recently filed value wins, so restatements replace the originally reported figures, as
+//! in [`CompanyConcept::time_series`](crate::CompanyConcept::time_series).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{CompanyOperations, Edgar, PeriodKind};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let facts = edgar.company_facts(320193).await?;
+//!
+//! for statement in facts.income_statements(PeriodKind::Annual) {
+//!     println!(
+//!         "FY{:?}: revenue {:?}, net income {:?}",
+//!         statement.period.fiscal_year, statement.revenue, statement.net_income
+//!     );
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::company::{CompanyFacts, DataPoint, PeriodKind};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+const USD: &str = "USD";
+const USD_PER_SHARE: &str = "USD/shares";
+const SHARES: &str = "shares";
+
+const REVENUE: &[&str] = &[
+    "Revenues",
+    "RevenueFromContractWithCustomerExcludingAssessedTax",
+    "RevenueFromContractWithCustomerIncludingAssessedTax",
+    "SalesRevenueNet",
+    "SalesRevenueGoodsNet",
+    "SalesRevenueServicesNet",
+];
+const COST_OF_REVENUE: &[&str] = &[
+    "CostOfRevenue",
+    "CostOfGoodsAndServicesSold",
+    "CostOfGoodsSold",
+    "CostOfServices",
+];
+const GROSS_PROFIT: &[&str] = &["GrossProfit"];
+const RESEARCH_AND_DEVELOPMENT: &[&str] = &[
+    "ResearchAndDevelopmentExpense",
+    "ResearchAndDevelopmentExpenseExcludingAcquiredInProcessCost",
+];
+const SELLING_GENERAL_AND_ADMINISTRATIVE: &[&str] = &["SellingGeneralAndAdministrativeExpense"];
+const OPERATING_EXPENSES: &[&str] = &["OperatingExpenses"];
+const OPERATING_INCOME: &[&str] = &["OperatingIncomeLoss"];
+const INTEREST_EXPENSE: &[&str] = &["InterestExpense", "InterestExpenseNonoperating"];
+const PRETAX_INCOME: &[&str] = &[
+    "IncomeLossFromContinuingOperationsBeforeIncomeTaxesExtraordinaryItemsNoncontrollingInterest",
+    "IncomeLossFromContinuingOperationsBeforeIncomeTaxesMinorityInterestAndIncomeLossFromEquityMethodInvestments",
+];
+const INCOME_TAX: &[&str] = &["IncomeTaxExpenseBenefit"];
+const NET_INCOME: &[&str] = &[
+    "NetIncomeLoss",
+    "ProfitLoss",
+    "NetIncomeLossAvailableToCommonStockholdersBasic",
+];
+const EPS_BASIC: &[&str] = &["EarningsPerShareBasic", "EarningsPerShareBasicAndDiluted"];
+const EPS_DILUTED: &[&str] = &["EarningsPerShareDiluted", "EarningsPerShareBasicAndDiluted"];
+const SHARES_BASIC: &[&str] = &["WeightedAverageNumberOfSharesOutstandingBasic"];
+const SHARES_DILUTED: &[&str] = &["WeightedAverageNumberOfDilutedSharesOutstanding"];
+
+const CASH: &[&str] = &[
+    "CashAndCashEquivalentsAtCarryingValue",
+    "CashCashEquivalentsRestrictedCashAndRestrictedCashEquivalents",
+    "Cash",
+];
+const SHORT_TERM_INVESTMENTS: &[&str] = &[
+    "ShortTermInvestments",
+    "MarketableSecuritiesCurrent",
+    "AvailableForSaleSecuritiesDebtSecuritiesCurrent",
+];
+const RECEIVABLES: &[&str] = &["AccountsReceivableNetCurrent", "ReceivablesNetCurrent"];
+const INVENTORY: &[&str] = &["InventoryNet"];
+const CURRENT_ASSETS: &[&str] = &["AssetsCurrent"];
+const TOTAL_ASSETS: &[&str] = &["Assets"];
+const ACCOUNTS_PAYABLE: &[&str] = &["AccountsPayableCurrent"];
+const CURRENT_LIABILITIES: &[&str] = &["LiabilitiesCurrent"];
+const LONG_TERM_DEBT: &[&str] = &["LongTermDebtNoncurrent", "LongTermDebt"];
+const TOTAL_LIABILITIES: &[&str] = &["Liabilities"];
+const STOCKHOLDERS_EQUITY: &[&str] = &[
+    "StockholdersEquity",
+    "StockholdersEquityIncludingPortionAttributableToNoncontrollingInterest",
+];
+const LIABILITIES_AND_EQUITY: &[&str] = &["LiabilitiesAndStockholdersEquity"];
+
+const OPERATING_CASH_FLOW: &[&str] = &[
+    "NetCashProvidedByUsedInOperatingActivities",
+    "NetCashProvidedByUsedInOperatingActivitiesContinuingOperations",
+];
+const INVESTING_CASH_FLOW: &[&str] = &[
+    "NetCashProvidedByUsedInInvestingActivities",
+    "NetCashProvidedByUsedInInvestingActivitiesContinuingOperations",
+];
+const FINANCING_CASH_FLOW: &[&str] = &[
+    "NetCashProvidedByUsedInFinancingActivities",
+    "NetCashProvidedByUsedInFinancingActivitiesContinuingOperations",
+];
+const CAPITAL_EXPENDITURE: &[&str] = &[
+    "PaymentsToAcquirePropertyPlantAndEquipment",
+    "PaymentsToAcquireProductiveAssets",
+];
+const DEPRECIATION: &[&str] = &[
+    "DepreciationDepletionAndAmortization",
+    "DepreciationAmortizationAndAccretionNet",
+    "DepreciationAndAmortization",
+];
+const DIVIDENDS_PAID: &[&str] = &["PaymentsOfDividends", "PaymentsOfDividendsCommonStock"];
+const SHARE_REPURCHASES: &[&str] = &["PaymentsForRepurchaseOfCommonStock"];
+
+/// The period a statement covers and how the company labelled it.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct StatementPeriod {
+    /// First day of the period; `None` for balance sheets, which are as of `end`.
+    pub start: Option<NaiveDate>,
+
+    pub end: NaiveDate,
+
+    /// Fiscal year of the filing that first reported the period.
+    pub fiscal_year: Option<i32>,
+
+    /// `FY`, `Q1`, `Q2`, `Q3`, or `Q4` (quarterly figures first reported in a 10-K).
+    pub fiscal_period: Option<String>,
+}
+
+/// The fact a line item was read from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineItemSource {
+    /// The us-gaap tag that supplied the value, which may be a synonym of the primary tag.
+    pub tag: String,
+
+    /// Accession number of the filing the value was taken from (the latest to report it).
+    pub accession_number: String,
+
+    pub form: String,
+
+    pub filed: String,
+}
+
+/// Income statement line items for one period.
+///
+/// Each field notes its primary us-gaap tag; synonyms are tried when a period has no value
+/// under it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncomeStatement {
+    pub period: StatementPeriod,
+
+    /// `Revenues`, `RevenueFromContractWithCustomerExcludingAssessedTax`, `SalesRevenueNet`, ...
+    pub revenue: Option<f64>,
+
+    /// `CostOfRevenue`, `CostOfGoodsAndServicesSold`, `CostOfGoodsSold`, ...
+    pub cost_of_revenue: Option<f64>,
+
+    /// `GrossProfit`
+    pub gross_profit: Option<f64>,
+
+    /// `ResearchAndDevelopmentExpense`
+    pub research_and_development: Option<f64>,
+
+    /// `SellingGeneralAndAdministrativeExpense`
+    pub selling_general_and_administrative: Option<f64>,
+
+    /// `OperatingExpenses`
+    pub operating_expenses: Option<f64>,
+
+    /// `OperatingIncomeLoss`
+    pub operating_income: Option<f64>,
+
+    /// `InterestExpense`
+    pub interest_expense: Option<f64>,
+
+    /// `IncomeLossFromContinuingOperationsBeforeIncomeTaxes...`
+    pub pretax_income: Option<f64>,
+
+    /// `IncomeTaxExpenseBenefit`
+    pub income_tax: Option<f64>,
+
+    /// `NetIncomeLoss`, then `ProfitLoss`
+    pub net_income: Option<f64>,
+
+    /// `EarningsPerShareBasic`
+    pub eps_basic: Option<f64>,
+
+    /// `EarningsPerShareDiluted`
+    pub eps_diluted: Option<f64>,
+
+    /// `WeightedAverageNumberOfSharesOutstandingBasic`
+    pub weighted_shares_basic: Option<f64>,
+
+    /// `WeightedAverageNumberOfDilutedSharesOutstanding`
+    pub weighted_shares_diluted: Option<f64>,
+
+    /// Where each reported line item came from, keyed by field name (`"revenue"`).
+    pub sources: BTreeMap<String, LineItemSource>,
+}
+
+impl IncomeStatement {
+    /// Reported gross profit, or revenue minus cost of revenue.
+    pub fn gross_profit_or_computed(&self) -> Option<f64> {
+        self.gross_profit
+            .or_else(|| Some(self.revenue? - self.cost_of_revenue?))
+    }
+}
+
+/// Balance sheet line items as of one date.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceSheet {
+    pub period: StatementPeriod,
+
+    /// `CashAndCashEquivalentsAtCarryingValue`
+    pub cash_and_equivalents: Option<f64>,
+
+    /// `ShortTermInvestments`, then `MarketableSecuritiesCurrent`
+    pub short_term_investments: Option<f64>,
+
+    /// `AccountsReceivableNetCurrent`
+    pub accounts_receivable: Option<f64>,
+
+    /// `InventoryNet`
+    pub inventory: Option<f64>,
+
+    /// `AssetsCurrent`
+    pub current_assets: Option<f64>,
+
+    /// `Assets`
+    pub total_assets: Option<f64>,
+
+    /// `AccountsPayableCurrent`
+    pub accounts_payable: Option<f64>,
+
+    /// `LiabilitiesCurrent`
+    pub current_liabilities: Option<f64>,
+
+    /// `LongTermDebtNoncurrent`, then `LongTermDebt`
+    pub long_term_debt: Option<f64>,
+
+    /// `Liabilities`
+    pub total_liabilities: Option<f64>,
+
+    /// `StockholdersEquity`
+    pub stockholders_equity: Option<f64>,
+
+    /// `LiabilitiesAndStockholdersEquity`
+    pub liabilities_and_equity: Option<f64>,
+
+    /// Where each reported line item came from, keyed by field name (`"revenue"`).
+    pub sources: BTreeMap<String, LineItemSource>,
+}
+
+/// Cash flow statement line items for one period.
+///
+/// 10-Q cash flow statements cover the fiscal year to date, so quarterly statements only
+/// exist for periods a company also reported as a single quarter (usually the first).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CashFlowStatement {
+    pub period: StatementPeriod,
+
+    /// `NetCashProvidedByUsedInOperatingActivities`
+    pub operating_cash_flow: Option<f64>,
+
+    /// `NetCashProvidedByUsedInInvestingActivities`
+    pub investing_cash_flow: Option<f64>,
+
+    /// `NetCashProvidedByUsedInFinancingActivities`
+    pub financing_cash_flow: Option<f64>,
+
+    /// `PaymentsToAcquirePropertyPlantAndEquipment`, reported as a positive payment
+    pub capital_expenditure: Option<f64>,
+
+    /// `DepreciationDepletionAndAmortization`
+    pub depreciation_and_amortization: Option<f64>,
+
+    /// `PaymentsOfDividends`
+    pub dividends_paid: Option<f64>,
+
+    /// `PaymentsForRepurchaseOfCommonStock`
+    pub share_repurchases: Option<f64>,
+
+    /// Where each reported line item came from, keyed by field name (`"revenue"`).
+    pub sources: BTreeMap<String, LineItemSource>,
+}
+
+impl CashFlowStatement {
+    /// Operating cash flow minus capital expenditure.
+    pub fn free_cash_flow(&self) -> Option<f64> {
+        Some(self.operating_cash_flow? - self.capital_expenditure?)
+    }
+}
+
+/// All three statements for one period kind.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Financials {
+    pub income_statements: Vec<IncomeStatement>,
+    pub balance_sheets: Vec<BalanceSheet>,
+    pub cash_flow_statements: Vec<CashFlowStatement>,
+}
+
+impl CompanyFacts {
+    /// Income, balance sheet, and cash flow statements for `kind` periods, oldest first.
+    pub fn financials(&self, kind: PeriodKind) -> Financials {
+        Financials {
+            income_statements: self.income_statements(kind),
+            balance_sheets: self.balance_sheets(kind),
+            cash_flow_statements: self.cash_flow_statements(kind),
+        }
+    }
+
+    /// Income statements for annual or quarterly periods, oldest first.
+    ///
+    /// `PeriodKind::Instant` returns nothing, since income statements cover a duration.
+    pub fn income_statements(&self, kind: PeriodKind) -> Vec<IncomeStatement> {
+        let mut columns = Columns::new(self, kind);
+        let revenue = columns.add("revenue", REVENUE, USD);
+        let cost_of_revenue = columns.add("cost_of_revenue", COST_OF_REVENUE, USD);
+        let gross_profit = columns.add("gross_profit", GROSS_PROFIT, USD);
+        let research_and_development =
+            columns.add("research_and_development", RESEARCH_AND_DEVELOPMENT, USD);
+        let sga = columns.add(
+            "selling_general_and_administrative",
+            SELLING_GENERAL_AND_ADMINISTRATIVE,
+            USD,
+        );
+        let operating_expenses = columns.add("operating_expenses", OPERATING_EXPENSES, USD);
+        let operating_income = columns.add("operating_income", OPERATING_INCOME, USD);
+        let interest_expense = columns.add("interest_expense", INTEREST_EXPENSE, USD);
+        let pretax_income = columns.add("pretax_income", PRETAX_INCOME, USD);
+        let income_tax = columns.add("income_tax", INCOME_TAX, USD);
+        let net_income = columns.add("net_income", NET_INCOME, USD);
+        let eps_basic = columns.add("eps_basic", EPS_BASIC, USD_PER_SHARE);
+        let eps_diluted = columns.add("eps_diluted", EPS_DILUTED, USD_PER_SHARE);
+        let shares_basic = columns.add("weighted_shares_basic", SHARES_BASIC, SHARES);
+        let shares_diluted = columns.add("weighted_shares_diluted", SHARES_DILUTED, SHARES);
+
+        columns
+            .periods()
+            .into_iter()
+            .map(|(key, period)| IncomeStatement {
+                period,
+                revenue: columns.get(revenue, key),
+                cost_of_revenue: columns.get(cost_of_revenue, key),
+                gross_profit: columns.get(gross_profit, key),
+                research_and_development: columns.get(research_and_development, key),
+                selling_general_and_administrative: columns.get(sga, key),
+                operating_expenses: columns.get(operating_expenses, key),
+                operating_income: columns.get(operating_income, key),
+                interest_expense: columns.get(interest_expense, key),
+                pretax_income: columns.get(pretax_income, key),
+                income_tax: columns.get(income_tax, key),
+                net_income: columns.get(net_income, key),
+                eps_basic: columns.get(eps_basic, key),
+                eps_diluted: columns.get(eps_diluted, key),
+                weighted_shares_basic: columns.get(shares_basic, key),
+                weighted_shares_diluted: columns.get(shares_diluted, key),
+                sources: columns.sources(key),
+            })
+            .collect()
+    }
+
+    /// Balance sheets, oldest first.
+    ///
+    /// `PeriodKind::Annual` keeps fiscal year-end balances (first reported in a 10-K or
+    /// similar annual report); `Quarterly` and `Instant` keep every reported date.
+    pub fn balance_sheets(&self, kind: PeriodKind) -> Vec<BalanceSheet> {
+        let mut columns = Columns::new(self, PeriodKind::Instant);
+        let cash = columns.add("cash_and_equivalents", CASH, USD);
+        let short_term_investments =
+            columns.add("short_term_investments", SHORT_TERM_INVESTMENTS, USD);
+        let receivables = columns.add("accounts_receivable", RECEIVABLES, USD);
+        let inventory = columns.add("inventory", INVENTORY, USD);
+        let current_assets = columns.add("current_assets", CURRENT_ASSETS, USD);
+        let total_assets = columns.add("total_assets", TOTAL_ASSETS, USD);
+        let accounts_payable = columns.add("accounts_payable", ACCOUNTS_PAYABLE, USD);
+        let current_liabilities = columns.add("current_liabilities", CURRENT_LIABILITIES, USD);
+        let long_term_debt = columns.add("long_term_debt", LONG_TERM_DEBT, USD);
+        let total_liabilities = columns.add("total_liabilities", TOTAL_LIABILITIES, USD);
+        let equity = columns.add("stockholders_equity", STOCKHOLDERS_EQUITY, USD);
+        let liabilities_and_equity =
+            columns.add("liabilities_and_equity", LIABILITIES_AND_EQUITY, USD);
+
+        columns
+            .periods()
+            .into_iter()
+            .filter(|(_, period)| {
+                kind != PeriodKind::Annual || period.fiscal_period.as_deref() == Some("FY")
+            })
+            .map(|(key, period)| BalanceSheet {
+                period,
+                cash_and_equivalents: columns.get(cash, key),
+                short_term_investments: columns.get(short_term_investments, key),
+                accounts_receivable: columns.get(receivables, key),
+                inventory: columns.get(inventory, key),
+                current_assets: columns.get(current_assets, key),
+                total_assets: columns.get(total_assets, key),
+                accounts_payable: columns.get(accounts_payable, key),
+                current_liabilities: columns.get(current_liabilities, key),
+                long_term_debt: columns.get(long_term_debt, key),
+                total_liabilities: columns.get(total_liabilities, key),
+                stockholders_equity: columns.get(equity, key),
+                liabilities_and_equity: columns.get(liabilities_and_equity, key),
+                sources: columns.sources(key),
+            })
+            .collect()
+    }
+
+    /// Cash flow statements for annual or quarterly periods, oldest first.
+    pub fn cash_flow_statements(&self, kind: PeriodKind) -> Vec<CashFlowStatement> {
+        let mut columns = Columns::new(self, kind);
+        let operating = columns.add("operating_cash_flow", OPERATING_CASH_FLOW, USD);
+        let investing = columns.add("investing_cash_flow", INVESTING_CASH_FLOW, USD);
+        let financing = columns.add("financing_cash_flow", FINANCING_CASH_FLOW, USD);
+        let capital_expenditure = columns.add("capital_expenditure", CAPITAL_EXPENDITURE, USD);
+        let depreciation = columns.add("depreciation_and_amortization", DEPRECIATION, USD);
+        let dividends_paid = columns.add("dividends_paid", DIVIDENDS_PAID, USD);
+        let share_repurchases = columns.add("share_repurchases", SHARE_REPURCHASES, USD);
+
+        columns
+            .periods()
+            .into_iter()
+            .map(|(key, period)| CashFlowStatement {
+                period,
+                operating_cash_flow: columns.get(operating, key),
+                investing_cash_flow: columns.get(investing, key),
+                financing_cash_flow: columns.get(financing, key),
+                capital_expenditure: columns.get(capital_expenditure, key),
+                depreciation_and_amortization: columns.get(depreciation, key),
+                dividends_paid: columns.get(dividends_paid, key),
+                share_repurchases: columns.get(share_repurchases, key),
+                sources: columns.sources(key),
+            })
+            .collect()
+    }
+}
+
+/// Start and end of a reported period.
+type PeriodKey = (Option<NaiveDate>, NaiveDate);
+
+/// A line item's value for one period.
+#[derive(Debug, Clone)]
+struct Reported {
+    value: f64,
+    source: LineItemSource,
+    period: StatementPeriod,
+}
+
+/// Line items collected per period, one named column per statement field.
+struct Columns<'a> {
+    facts: &'a CompanyFacts,
+    kind: PeriodKind,
+    columns: Vec<(&'static str, BTreeMap<PeriodKey, Reported>)>,
+}
+
+impl<'a> Columns<'a> {
+    fn new(facts: &'a CompanyFacts, kind: PeriodKind) -> Self {
+        Self {
+            facts,
+            kind,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Adds a column filled from `tags`, earlier tags taking precedence for each period.
+    fn add(&mut self, field: &'static str, tags: &[&str], unit: &str) -> usize {
+        let mut column = BTreeMap::new();
+        for tag in tags {
+            let Some(points) = self
+                .facts
+                .taxonomies
+                .fact("us-gaap", tag)
+                .and_then(|fact| fact.units.get(unit))
+            else {
+                continue;
+            };
+            for (key, reported) in tag_values(tag, points, self.kind) {
+                column.entry(key).or_insert(reported);
+            }
+        }
+        self.columns.push((field, column));
+        self.columns.len() - 1
+    }
+
+    /// Every period with at least one value, labelled by the first column that has it.
+    fn periods(&self) -> Vec<(&PeriodKey, StatementPeriod)> {
+        let mut periods: BTreeMap<&PeriodKey, &StatementPeriod> = BTreeMap::new();
+        for (_, column) in &self.columns {
+            for (key, reported) in column {
+                periods.entry(key).or_insert(&reported.period);
+            }
+        }
+        let mut periods: Vec<(&PeriodKey, StatementPeriod)> = periods
+            .into_iter()
+            .map(|(key, period)| (key, period.clone()))
+            .collect();
+        periods.sort_by_key(|((start, end), _)| (*end, std::cmp::Reverse(*start)));
+        periods
+    }
+
+    fn get(&self, column: usize, key: &PeriodKey) -> Option<f64> {
+        self.columns[column]
+            .1
+            .get(key)
+            .map(|reported| reported.value)
+    }
+
+    /// Sources of every line item reported for `key`, keyed by field name.
+    fn sources(&self, key: &PeriodKey) -> BTreeMap<String, LineItemSource> {
+        self.columns
+            .iter()
+            .filter_map(|(field, column)| {
+                let reported = column.get(key)?;
+                Some((field.to_string(), reported.source.clone()))
+            })
+            .collect()
+    }
+}
+
+/// The latest-filed value of one tag for every `kind` period.
+fn tag_values(tag: &str, points: &[DataPoint], kind: PeriodKind) -> HashMap<PeriodKey, Reported> {
+    // The latest period end in each filing identifies the period the filing is about.
+    let mut filing_end: HashMap<&str, NaiveDate> = HashMap::new();
+    for point in points {
+        if let Ok(end) = NaiveDate::parse_from_str(&point.end, "%Y-%m-%d") {
+            filing_end
+                .entry(point.accn.as_str())
+                .and_modify(|current| *current = (*current).max(end))
+                .or_insert(end);
+        }
+    }
+
+    let mut first: HashMap<PeriodKey, &DataPoint> = HashMap::new();
+    let mut latest: HashMap<PeriodKey, &DataPoint> = HashMap::new();
+    for point in points {
+        let Some(end) = kind.matches(point) else {
+            continue;
+        };
+        if point.val.as_f64().is_none() {
+            continue;
+        }
+        let start = point
+            .start
+            .as_deref()
+            .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
+        let key = (start, end);
+        first
+            .entry(key)
+            .and_modify(|current| {
+                if point.filed < current.filed {
+                    *current = point;
+                }
+            })
+            .or_insert(point);
+        latest
+            .entry(key)
+            .and_modify(|current| {
+                if point.filed >= current.filed {
+                    *current = point;
+                }
+            })
+            .or_insert(point);
+    }
+
+    latest
+        .into_iter()
+        .filter_map(|(key, point)| {
+            let original = first[&key];
+            let period = label(key, original, filing_end.get(original.accn.as_str()), kind);
+            let reported = Reported {
+                value: point.val.as_f64()?,
+                source: LineItemSource {
+                    tag: tag.to_string(),
+                    accession_number: point.accn.clone(),
+                    form: point.form.clone(),
+                    filed: point.filed.clone(),
+                },
+                period,
+            };
+            Some((key, reported))
+        })
+        .collect()
+}
+
+/// Fiscal year and period for `key`, taken from the filing that first reported it.
+///
+/// Data points carry the fiscal year and period of their filing, not of the period they
+/// describe. When the first filing was about a later period (a comparative figure from
+/// before the company's XBRL history), the year is shifted back by the whole years between
+/// the two, and the fiscal period is dropped if the gap is not a whole number of years.
+fn label(
+    key: PeriodKey,
+    original: &DataPoint,
+    filing_end: Option<&NaiveDate>,
+    kind: PeriodKind,
+) -> StatementPeriod {
+    let (start, end) = key;
+    let mut fiscal_year = original.fy;
+    let mut fiscal_period = original.fp.clone();
+
+    if let Some(filing_end) = filing_end.filter(|filing_end| **filing_end > end) {
+        let days = (*filing_end - end).num_days();
+        let years = (days as f64 / 365.25).round() as i32;
+        fiscal_year = fiscal_year.map(|fy| fy - years);
+        if (days as f64 - years as f64 * 365.25).abs() > 14.0 {
+            fiscal_period = None;
+        }
+    }
+    if kind == PeriodKind::Quarterly && fiscal_period.as_deref() == Some("FY") {
+        fiscal_period = Some("Q4".to_string());
+    }
+
+    StatementPeriod {
+        start,
+        end,
+        fiscal_year,
+        fiscal_period,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::company::{Fact, FactValue, TaxonomyGroups};
+
+    fn point(start: &str, end: &str, val: i64, accn: &str, fy: i32, fp: &str) -> DataPoint {
+        DataPoint {
+            start: Some(start.to_string()),
+            end: end.to_string(),
+            val: FactValue::Integer(val),
+            accn: accn.to_string(),
+            fy: Some(fy),
+            fp: Some(fp.to_string()),
+            form: if fp == "FY" { "10-K" } else { "10-Q" }.to_string(),
+            filed: format!("{}-11-01", fy),
+            frame: None,
+        }
+    }
+
+    fn facts(tags: Vec<(&str, Vec<DataPoint>)>) -> CompanyFacts {
+        CompanyFacts {
+            cik: 1,
+            entity_name: "Example Corp".to_string(),
+            taxonomies: TaxonomyGroups::from_iter([(
+                "us-gaap".to_string(),
+                tags.into_iter()
+                    .map(|(tag, points)| {
+                        let fact = Fact {
+                            label: None,
+                            description: None,
+                            units: HashMap::from([(USD.to_string(), points)]),
+                        };
+                        (tag.to_string(), fact)
+                    })
+                    .collect(),
+            )]),
+        }
+    }
+
+    #[test]
+    fn test_revenue_synonyms_per_period() {
+        let facts = facts(vec![
+            (
+                "SalesRevenueNet",
+                vec![
+                    point("2016-01-01", "2016-12-31", 100, "a-17", 2017, "FY"),
+                    point("2017-01-01", "2017-12-31", 110, "a-17", 2017, "FY"),
+                ],
+            ),
+            (
+                "RevenueFromContractWithCustomerExcludingAssessedTax",
+                vec![
+                    point("2017-01-01", "2017-12-31", 111, "a-18", 2018, "FY"),
+                    point("2018-01-01", "2018-12-31", 120, "a-18", 2018, "FY"),
+                ],
+            ),
+        ]);
+
+        let statements = facts.income_statements(PeriodKind::Annual);
+        let revenue: Vec<_> = statements
+            .iter()
+            .map(|s| (s.period.fiscal_year, s.revenue))
+            .collect();
+        assert_eq!(
+            revenue,
+            vec![
+                (Some(2016), Some(100.0)),
+                (Some(2017), Some(111.0)),
+                (Some(2018), Some(120.0)),
+            ]
+        );
+    }
+}
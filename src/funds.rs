@@ -0,0 +1,212 @@
+//! Registered fund reports.
+//!
+//! Mutual funds and ETFs file under the CIK of their registrant, usually a trust with many
+//! series. Their portfolio holdings are reported quarterly on Form N-PORT, whose machine
+//! readable version is the `primary_doc.xml` in each filing's archive folder.
+//!
+//! Each series has an identifier like `S000009184` and sells one or more share classes
+//! (`C000024954`), each with its own ticker. [`FundSeries`] and [`FundClass`] describe that
+//! structure, built from the SEC's mutual fund ticker file and the registrant's submissions.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, FundOperations};
+//!
+//! let edgar = Edgar::new("app contact@example.com")?;
+//! let report = edgar.get_nport_holdings(36405, "0001752724-24-270839").await?;
+//! for holding in report.holdings.iter().take(10) {
+//!     println!("{} {:?} {:?}%", holding.name, holding.cusip, holding.percent_value);
+//! }
+//! ```
+
+use super::Edgar;
+use super::cik::Cik;
+use super::company::MutualFundTicker;
+use super::entity::EntityId;
+use super::error::{EdgarError, ErrorContext, Result};
+use super::filings::PRIMARY_XML;
+use super::traits::{CompanyOperations, FilingOperations, FundOperations};
+use crate::parsing::nport::{NportReport, parse_nport};
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+/// A share class of a fund series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundClass {
+    /// Class identifier, e.g. `C000024954`
+    pub class_id: String,
+
+    /// Identifier of the series the class belongs to, e.g. `S000009184`
+    pub series_id: String,
+
+    /// CIK of the registrant the series files under
+    pub cik: Cik,
+
+    pub ticker: String,
+}
+
+impl From<&MutualFundTicker> for FundClass {
+    fn from(row: &MutualFundTicker) -> Self {
+        Self {
+            class_id: row.class_id.clone(),
+            series_id: row.series_id.clone(),
+            cik: Cik::new(row.cik),
+            ticker: row.symbol.clone(),
+        }
+    }
+}
+
+/// A fund series and its share classes.
+///
+/// Only classes with a ticker are listed; the ticker file leaves out classes that have none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundSeries {
+    /// Series identifier, e.g. `S000009184`
+    pub series_id: String,
+
+    /// CIK of the registrant the series files under
+    pub cik: Cik,
+
+    /// Name of the registrant, usually the trust, e.g. `COLUMBIA ACORN TRUST`. EDGAR's
+    /// ticker and submissions files do not carry the names of individual series.
+    pub registrant_name: String,
+
+    /// Share classes, in ticker file order
+    pub classes: Vec<FundClass>,
+}
+
+impl FundSeries {
+    /// Tickers of the series' share classes.
+    pub fn tickers(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|class| class.ticker.as_str())
+    }
+}
+
+/// Groups a registrant's ticker file rows into series, ordered by series ID.
+fn group_series(cik: Cik, registrant_name: &str, rows: &[MutualFundTicker]) -> Vec<FundSeries> {
+    let mut series: BTreeMap<&str, FundSeries> = BTreeMap::new();
+    for row in rows.iter().filter(|row| row.cik == cik.as_u64()) {
+        series
+            .entry(row.series_id.as_str())
+            .or_insert_with(|| FundSeries {
+                series_id: row.series_id.clone(),
+                cik,
+                registrant_name: registrant_name.to_string(),
+                classes: Vec::new(),
+            })
+            .classes
+            .push(FundClass::from(row));
+    }
+    series.into_values().collect()
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl FundOperations for Edgar {
+    /// Downloads an N-PORT filing's `primary_doc.xml` and parses the fund information and
+    /// schedule of investments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no `primary_doc.xml`, and
+    /// `EdgarError::InvalidFormat` if the document is not an N-PORT report.
+    async fn get_nport_holdings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<NportReport> {
+        let url = self.get_filing_url(
+            self.resolve_entity(cik).await?,
+            accession_number,
+            PRIMARY_XML,
+        )?;
+        let xml = self.get(&url).await?;
+        parse_nport(&xml)
+    }
+
+    /// Lists a registrant's fund series with their share classes and tickers.
+    ///
+    /// Series and classes come from `company_tickers_mf.json`; the registrant name comes from
+    /// its submissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the ticker file lists no series for the CIK, which
+    /// is also the case for registrants whose classes have no tickers.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{Edgar, FundOperations};
+    ///
+    /// let edgar = Edgar::new("app contact@example.com")?;
+    /// for series in edgar.fund_series(2110).await? {
+    ///     let tickers: Vec<_> = series.tickers().collect();
+    ///     println!("{} ({}): {:?}", series.series_id, series.registrant_name, tickers);
+    /// }
+    /// ```
+    async fn fund_series(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<FundSeries>> {
+        let cik = self.resolve_entity(cik).await?;
+        let (rows, submission) =
+            tokio::try_join!(self.mutual_fund_tickers(), self.submissions(cik))?;
+        let series = group_series(cik, &submission.name, &rows);
+        if series.is_empty() {
+            return Err(EdgarError::not_found(ErrorContext::for_filing(cik, None)));
+        }
+        Ok(series)
+    }
+
+    /// Looks up a share class by its identifier (e.g. `C000024954`), ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound`, with the class ID in the error's
+    /// [`lookup`](crate::ErrorContext::lookup), if the ticker file does not list the class.
+    async fn fund_class(&self, class_id: &str) -> Result<FundClass> {
+        let class_id = class_id.trim();
+        self.mutual_fund_tickers()
+            .await?
+            .iter()
+            .find(|row| row.class_id.eq_ignore_ascii_case(class_id))
+            .map(FundClass::from)
+            .ok_or_else(|| {
+                EdgarError::not_found(ErrorContext::for_lookup(format!("class {}", class_id)))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(cik: u64, series_id: &str, class_id: &str, symbol: &str) -> MutualFundTicker {
+        MutualFundTicker {
+            cik,
+            series_id: series_id.to_string(),
+            class_id: class_id.to_string(),
+            symbol: symbol.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_group_series_by_registrant() {
+        let rows = vec![
+            row(2110, "S000009185", "C000024958", "LAIAX"),
+            row(2110, "S000009184", "C000024954", "LACAX"),
+            row(822977, "S000009241", "C000025190", "GSDUX"),
+            row(2110, "S000009184", "C000024956", "LIACX"),
+        ];
+        let series = group_series(Cik::new(2110), "COLUMBIA ACORN TRUST", &rows);
+
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].series_id, "S000009184");
+        assert_eq!(series[0].tickers().collect::<Vec<_>>(), ["LACAX", "LIACX"]);
+        assert_eq!(series[1].classes[0].class_id, "C000024958");
+        assert!(
+            series
+                .iter()
+                .all(|s| s.registrant_name == "COLUMBIA ACORN TRUST")
+        );
+    }
+}
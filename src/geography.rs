@@ -0,0 +1,758 @@
+//! EDGAR state and country codes.
+//!
+//! Addresses and places of incorporation are coded with EDGAR's own two-character list:
+//! US postal codes for states and territories (`DE`, `PR`), `A0`-`B0` for Canadian
+//! provinces, and letter-digit pairs for everything else (`X0` is the United Kingdom, `E9`
+//! the Cayman Islands). [`StateOrCountry`] parses those codes, names them, and converts to
+//! and from ISO 3166.
+//!
+//! ```rust
+//! use edgarkit::StateOrCountry;
+//!
+//! let cayman = StateOrCountry::from("E9");
+//! assert_eq!(cayman, StateOrCountry::Country("E9"));
+//! assert_eq!(cayman.name(), Some("Cayman Islands"));
+//! assert_eq!(cayman.iso_country(), Some("KY"));
+//!
+//! let ontario = StateOrCountry::from_iso("CA-ON").unwrap();
+//! assert_eq!(ontario.edgar_code(), "A6");
+//! assert_eq!(StateOrCountry::from("DE").iso_subdivision(), Some("US-DE"));
+//! ```
+//!
+//! [`Location`] names the same places for full-text search's location filter:
+//!
+//! ```rust
+//! use edgarkit::{Location, SearchOptions};
+//!
+//! assert_eq!(Location::California.code(), "CA");
+//! assert_eq!(Location::country("Germany"), Some(Location::Foreign("2M")));
+//! let options = SearchOptions::new().with_location(Location::country("Cayman Islands").unwrap());
+//! ```
+
+use Kind::{Country as C, Province as P, State as S};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// A place coded with an EDGAR state or country code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StateOrCountry {
+    /// A US state, the District of Columbia, or a territory with a postal code (`PR`, `GU`,
+    /// `VI`)
+    UsState(&'static str),
+    /// A Canadian province or territory, `A0` (Alberta) to `B0` (Yukon)
+    CanadianProvince(&'static str),
+    /// Any other country, including the United States (`X1`) and Canada (`Z4`) as a whole
+    Country(&'static str),
+    /// A code not on EDGAR's list, kept as filed
+    Other(String),
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    State,
+    Province,
+    Country,
+}
+
+impl StateOrCountry {
+    /// The EDGAR code, e.g. `CA` or `X0`.
+    pub fn edgar_code(&self) -> &str {
+        match self {
+            StateOrCountry::UsState(code)
+            | StateOrCountry::CanadianProvince(code)
+            | StateOrCountry::Country(code) => code,
+            StateOrCountry::Other(code) => code,
+        }
+    }
+
+    /// The place name as EDGAR lists it, e.g. `Cayman Islands`.
+    pub fn name(&self) -> Option<&'static str> {
+        self.entry().map(|(_, _, name, _)| *name)
+    }
+
+    /// The ISO 3166-1 alpha-2 code of the country, e.g. `US` for `CA` (California). US
+    /// territories with their own ISO code (`PR`, `GU`, `VI`) return it.
+    pub fn iso_country(&self) -> Option<&'static str> {
+        self.entry()
+            .and_then(|(_, _, _, iso)| iso.split('-').next())
+            .filter(|iso| !iso.is_empty())
+    }
+
+    /// The ISO 3166-2 code of a state or province, e.g. `US-CA` or `CA-ON`.
+    pub fn iso_subdivision(&self) -> Option<&'static str> {
+        match self.entry()? {
+            (_, Kind::State | Kind::Province, _, iso) if iso.contains('-') => Some(*iso),
+            (code, Kind::State, _, _) => US_TERRITORIES
+                .iter()
+                .find(|(territory, _)| territory == code)
+                .map(|(_, subdivision)| *subdivision),
+            _ => None,
+        }
+    }
+
+    /// Looks up an ISO 3166-1 alpha-2 country code (`DE`) or an ISO 3166-2 US state or
+    /// Canadian province code (`US-NY`, `CA-QC`), ignoring case.
+    pub fn from_iso(iso: &str) -> Option<Self> {
+        let iso = iso.trim().to_ascii_uppercase();
+        if iso.is_empty() {
+            return None;
+        }
+        if let Some((territory, _)) = US_TERRITORIES
+            .iter()
+            .find(|(_, subdivision)| *subdivision == iso)
+        {
+            return Some(Self::from(*territory));
+        }
+        let code = match iso.as_str() {
+            "US" => "X1",
+            "CA" => "Z4",
+            _ => {
+                STATES_AND_COUNTRIES
+                    .iter()
+                    .find(|(_, _, _, entry_iso)| *entry_iso == iso)?
+                    .0
+            }
+        };
+        Some(Self::from(code))
+    }
+
+    /// Whether the place is in the United States, including its territories.
+    pub fn is_us(&self) -> bool {
+        matches!(self, StateOrCountry::UsState(_)) || self.edgar_code() == "X1"
+    }
+
+    fn entry(&self) -> Option<&'static (&'static str, Kind, &'static str, &'static str)> {
+        let code = self.edgar_code();
+        STATES_AND_COUNTRIES
+            .iter()
+            .find(|(entry, _, _, _)| *entry == code)
+    }
+}
+
+impl From<&str> for StateOrCountry {
+    fn from(code: &str) -> Self {
+        let code = code.trim();
+        match STATES_AND_COUNTRIES
+            .iter()
+            .find(|(entry, _, _, _)| entry.eq_ignore_ascii_case(code))
+        {
+            Some((code, Kind::State, _, _)) => StateOrCountry::UsState(code),
+            Some((code, Kind::Province, _, _)) => StateOrCountry::CanadianProvince(code),
+            Some((code, Kind::Country, _, _)) => StateOrCountry::Country(code),
+            None => StateOrCountry::Other(code.to_string()),
+        }
+    }
+}
+
+impl FromStr for StateOrCountry {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Infallible> {
+        Ok(Self::from(s))
+    }
+}
+
+impl AsRef<str> for StateOrCountry {
+    fn as_ref(&self) -> &str {
+        self.edgar_code()
+    }
+}
+
+impl fmt::Display for StateOrCountry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.edgar_code())
+    }
+}
+
+impl Serialize for StateOrCountry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.edgar_code())
+    }
+}
+
+impl<'de> Deserialize<'de> for StateOrCountry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from(code.as_str()))
+    }
+}
+
+/// A place to filter full-text search by, as a business address or, with
+/// `SearchOptions::with_incorporated_location`, a place of incorporation.
+///
+/// US states and territories have their own variants. Other countries and Canadian
+/// provinces are [`Location::Foreign`] with their EDGAR code; look them up by name with
+/// [`Location::country`] rather than writing the code by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Alabama,
+    Alaska,
+    Arizona,
+    Arkansas,
+    California,
+    Colorado,
+    Connecticut,
+    Delaware,
+    DistrictOfColumbia,
+    Florida,
+    Georgia,
+    Hawaii,
+    Idaho,
+    Illinois,
+    Indiana,
+    Iowa,
+    Kansas,
+    Kentucky,
+    Louisiana,
+    Maine,
+    Maryland,
+    Massachusetts,
+    Michigan,
+    Minnesota,
+    Mississippi,
+    Missouri,
+    Montana,
+    Nebraska,
+    Nevada,
+    NewHampshire,
+    NewJersey,
+    NewMexico,
+    NewYork,
+    NorthCarolina,
+    NorthDakota,
+    Ohio,
+    Oklahoma,
+    Oregon,
+    Pennsylvania,
+    RhodeIsland,
+    SouthCarolina,
+    SouthDakota,
+    Tennessee,
+    Texas,
+    Utah,
+    Vermont,
+    Virginia,
+    Washington,
+    WestVirginia,
+    Wisconsin,
+    Wyoming,
+    PuertoRico,
+    Guam,
+    UsVirginIslands,
+    /// A Canadian province or any other country, by EDGAR code (`A6`, `2M`)
+    Foreign(&'static str),
+}
+
+/// The state and territory variants of [`Location`], in the order of their codes in
+/// `STATES_AND_COUNTRIES`.
+const US_LOCATIONS: [Location; 54] = [
+    Location::Alabama,
+    Location::Alaska,
+    Location::Arizona,
+    Location::Arkansas,
+    Location::California,
+    Location::Colorado,
+    Location::Connecticut,
+    Location::Delaware,
+    Location::DistrictOfColumbia,
+    Location::Florida,
+    Location::Georgia,
+    Location::Hawaii,
+    Location::Idaho,
+    Location::Illinois,
+    Location::Indiana,
+    Location::Iowa,
+    Location::Kansas,
+    Location::Kentucky,
+    Location::Louisiana,
+    Location::Maine,
+    Location::Maryland,
+    Location::Massachusetts,
+    Location::Michigan,
+    Location::Minnesota,
+    Location::Mississippi,
+    Location::Missouri,
+    Location::Montana,
+    Location::Nebraska,
+    Location::Nevada,
+    Location::NewHampshire,
+    Location::NewJersey,
+    Location::NewMexico,
+    Location::NewYork,
+    Location::NorthCarolina,
+    Location::NorthDakota,
+    Location::Ohio,
+    Location::Oklahoma,
+    Location::Oregon,
+    Location::Pennsylvania,
+    Location::RhodeIsland,
+    Location::SouthCarolina,
+    Location::SouthDakota,
+    Location::Tennessee,
+    Location::Texas,
+    Location::Utah,
+    Location::Vermont,
+    Location::Virginia,
+    Location::Washington,
+    Location::WestVirginia,
+    Location::Wisconsin,
+    Location::Wyoming,
+    Location::PuertoRico,
+    Location::Guam,
+    Location::UsVirginIslands,
+];
+
+impl Location {
+    /// The EDGAR code the search API filters on, e.g. `CA` or `X0`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Location::Foreign(code) => code,
+            state => {
+                let at = US_LOCATIONS
+                    .iter()
+                    .position(|location| *location == state)
+                    .expect("every state variant is listed");
+                STATES_AND_COUNTRIES[at].0
+            }
+        }
+    }
+
+    /// The place name as EDGAR lists it, e.g. `New York` or `Ontario, Canada`.
+    pub fn name(self) -> Option<&'static str> {
+        StateOrCountry::from(self.code()).name()
+    }
+
+    /// Looks up a country or Canadian province by name (`Germany`, `Ontario`) or ISO
+    /// 3166-1 alpha-2 code (`DE`), ignoring case. `United States` is the country as a
+    /// whole; use the state variants for states.
+    pub fn country(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let by_name = STATES_AND_COUNTRIES.iter().find(|(_, kind, entry, _)| {
+            !matches!(kind, Kind::State)
+                && (entry.eq_ignore_ascii_case(name)
+                    || entry
+                        .strip_suffix(", Canada")
+                        .is_some_and(|province| province.eq_ignore_ascii_case(name)))
+        });
+        if let Some((code, _, _, _)) = by_name {
+            return Some(Location::Foreign(code));
+        }
+        match StateOrCountry::from_iso(name)? {
+            StateOrCountry::Country(code) => Some(Location::Foreign(code)),
+            _ => None,
+        }
+    }
+
+    /// Looks up any EDGAR state or country code, ignoring case.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match StateOrCountry::from(code) {
+            StateOrCountry::UsState(code) => {
+                let at = STATES_AND_COUNTRIES
+                    .iter()
+                    .position(|(entry, _, _, _)| *entry == code)?;
+                Some(US_LOCATIONS[at])
+            }
+            StateOrCountry::CanadianProvince(code) | StateOrCountry::Country(code) => {
+                Some(Location::Foreign(code))
+            }
+            StateOrCountry::Other(_) => None,
+        }
+    }
+}
+
+impl From<Location> for StateOrCountry {
+    fn from(location: Location) -> Self {
+        StateOrCountry::from(location.code())
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Parses an optional code field, treating blanks as absent.
+#[cfg(feature = "filings")]
+pub(crate) fn parse_code(code: Option<&str>) -> Option<StateOrCountry> {
+    code.map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(StateOrCountry::from)
+}
+
+/// US territories coded as states, with their ISO 3166-2 subdivision codes.
+const US_TERRITORIES: [(&str, &str); 3] = [("PR", "US-PR"), ("GU", "US-GU"), ("VI", "US-VI")];
+
+/// EDGAR state and country codes (code, kind, name, ISO 3166 code).
+#[rustfmt::skip]
+static STATES_AND_COUNTRIES: &[(&str, Kind, &str, &str)] = &[
+    ("AL", S, "Alabama", "US-AL"),
+    ("AK", S, "Alaska", "US-AK"),
+    ("AZ", S, "Arizona", "US-AZ"),
+    ("AR", S, "Arkansas", "US-AR"),
+    ("CA", S, "California", "US-CA"),
+    ("CO", S, "Colorado", "US-CO"),
+    ("CT", S, "Connecticut", "US-CT"),
+    ("DE", S, "Delaware", "US-DE"),
+    ("DC", S, "District of Columbia", "US-DC"),
+    ("FL", S, "Florida", "US-FL"),
+    ("GA", S, "Georgia", "US-GA"),
+    ("HI", S, "Hawaii", "US-HI"),
+    ("ID", S, "Idaho", "US-ID"),
+    ("IL", S, "Illinois", "US-IL"),
+    ("IN", S, "Indiana", "US-IN"),
+    ("IA", S, "Iowa", "US-IA"),
+    ("KS", S, "Kansas", "US-KS"),
+    ("KY", S, "Kentucky", "US-KY"),
+    ("LA", S, "Louisiana", "US-LA"),
+    ("ME", S, "Maine", "US-ME"),
+    ("MD", S, "Maryland", "US-MD"),
+    ("MA", S, "Massachusetts", "US-MA"),
+    ("MI", S, "Michigan", "US-MI"),
+    ("MN", S, "Minnesota", "US-MN"),
+    ("MS", S, "Mississippi", "US-MS"),
+    ("MO", S, "Missouri", "US-MO"),
+    ("MT", S, "Montana", "US-MT"),
+    ("NE", S, "Nebraska", "US-NE"),
+    ("NV", S, "Nevada", "US-NV"),
+    ("NH", S, "New Hampshire", "US-NH"),
+    ("NJ", S, "New Jersey", "US-NJ"),
+    ("NM", S, "New Mexico", "US-NM"),
+    ("NY", S, "New York", "US-NY"),
+    ("NC", S, "North Carolina", "US-NC"),
+    ("ND", S, "North Dakota", "US-ND"),
+    ("OH", S, "Ohio", "US-OH"),
+    ("OK", S, "Oklahoma", "US-OK"),
+    ("OR", S, "Oregon", "US-OR"),
+    ("PA", S, "Pennsylvania", "US-PA"),
+    ("RI", S, "Rhode Island", "US-RI"),
+    ("SC", S, "South Carolina", "US-SC"),
+    ("SD", S, "South Dakota", "US-SD"),
+    ("TN", S, "Tennessee", "US-TN"),
+    ("TX", S, "Texas", "US-TX"),
+    ("UT", S, "Utah", "US-UT"),
+    ("VT", S, "Vermont", "US-VT"),
+    ("VA", S, "Virginia", "US-VA"),
+    ("WA", S, "Washington", "US-WA"),
+    ("WV", S, "West Virginia", "US-WV"),
+    ("WI", S, "Wisconsin", "US-WI"),
+    ("WY", S, "Wyoming", "US-WY"),
+    ("PR", S, "Puerto Rico", "PR"),
+    ("GU", S, "Guam", "GU"),
+    ("VI", S, "Virgin Islands, U.S.", "VI"),
+    ("A0", P, "Alberta, Canada", "CA-AB"),
+    ("A1", P, "British Columbia, Canada", "CA-BC"),
+    ("A2", P, "Manitoba, Canada", "CA-MB"),
+    ("A3", P, "New Brunswick, Canada", "CA-NB"),
+    ("A4", P, "Newfoundland, Canada", "CA-NL"),
+    ("A5", P, "Nova Scotia, Canada", "CA-NS"),
+    ("A6", P, "Ontario, Canada", "CA-ON"),
+    ("A7", P, "Prince Edward Island, Canada", "CA-PE"),
+    ("A8", P, "Quebec, Canada", "CA-QC"),
+    ("A9", P, "Saskatchewan, Canada", "CA-SK"),
+    ("B0", P, "Yukon, Canada", "CA-YT"),
+    ("X1", C, "United States", "US"),
+    ("Z4", C, "Canada (Federal Level)", "CA"),
+    ("B2", C, "Afghanistan", "AF"),
+    ("Y6", C, "Aland Islands", "AX"),
+    ("B3", C, "Albania", "AL"),
+    ("B4", C, "Algeria", "DZ"),
+    ("B5", C, "American Samoa", "AS"),
+    ("B6", C, "Andorra", "AD"),
+    ("B7", C, "Angola", "AO"),
+    ("1A", C, "Anguilla", "AI"),
+    ("B8", C, "Antarctica", "AQ"),
+    ("B9", C, "Antigua and Barbuda", "AG"),
+    ("C1", C, "Argentina", "AR"),
+    ("1B", C, "Armenia", "AM"),
+    ("1C", C, "Aruba", "AW"),
+    ("C3", C, "Australia", "AU"),
+    ("C4", C, "Austria", "AT"),
+    ("1D", C, "Azerbaijan", "AZ"),
+    ("C5", C, "Bahamas", "BS"),
+    ("C6", C, "Bahrain", "BH"),
+    ("C7", C, "Bangladesh", "BD"),
+    ("C8", C, "Barbados", "BB"),
+    ("1F", C, "Belarus", "BY"),
+    ("C9", C, "Belgium", "BE"),
+    ("D1", C, "Belize", "BZ"),
+    ("G6", C, "Benin", "BJ"),
+    ("D0", C, "Bermuda", "BM"),
+    ("D2", C, "Bhutan", "BT"),
+    ("D3", C, "Bolivia", "BO"),
+    ("1E", C, "Bosnia and Herzegovina", "BA"),
+    ("B1", C, "Botswana", "BW"),
+    ("D4", C, "Bouvet Island", "BV"),
+    ("D5", C, "Brazil", "BR"),
+    ("D6", C, "British Indian Ocean Territory", "IO"),
+    ("D9", C, "Brunei Darussalam", "BN"),
+    ("E0", C, "Bulgaria", "BG"),
+    ("X2", C, "Burkina Faso", "BF"),
+    ("E2", C, "Burundi", "BI"),
+    ("E3", C, "Cambodia", "KH"),
+    ("E4", C, "Cameroon", "CM"),
+    ("E8", C, "Cape Verde", "CV"),
+    ("E9", C, "Cayman Islands", "KY"),
+    ("F0", C, "Central African Republic", "CF"),
+    ("F2", C, "Chad", "TD"),
+    ("F3", C, "Chile", "CL"),
+    ("F4", C, "China", "CN"),
+    ("F6", C, "Christmas Island", "CX"),
+    ("F7", C, "Cocos (Keeling) Islands", "CC"),
+    ("F8", C, "Colombia", "CO"),
+    ("F9", C, "Comoros", "KM"),
+    ("G0", C, "Congo", "CG"),
+    ("Y3", C, "Congo, the Democratic Republic of the", "CD"),
+    ("G1", C, "Cook Islands", "CK"),
+    ("G2", C, "Costa Rica", "CR"),
+    ("L7", C, "Cote D'ivoire", "CI"),
+    ("1M", C, "Croatia", "HR"),
+    ("G3", C, "Cuba", "CU"),
+    ("G4", C, "Cyprus", "CY"),
+    ("2N", C, "Czech Republic", "CZ"),
+    ("G7", C, "Denmark", "DK"),
+    ("1G", C, "Djibouti", "DJ"),
+    ("G9", C, "Dominica", "DM"),
+    ("G8", C, "Dominican Republic", "DO"),
+    ("H1", C, "Ecuador", "EC"),
+    ("H2", C, "Egypt", "EG"),
+    ("H3", C, "El Salvador", "SV"),
+    ("H4", C, "Equatorial Guinea", "GQ"),
+    ("1J", C, "Eritrea", "ER"),
+    ("1H", C, "Estonia", "EE"),
+    ("H5", C, "Ethiopia", "ET"),
+    ("H7", C, "Falkland Islands (Malvinas)", "FK"),
+    ("H6", C, "Faroe Islands", "FO"),
+    ("H8", C, "Fiji", "FJ"),
+    ("H9", C, "Finland", "FI"),
+    ("I0", C, "France", "FR"),
+    ("I3", C, "French Guiana", "GF"),
+    ("I4", C, "French Polynesia", "PF"),
+    ("2C", C, "French Southern Territories", "TF"),
+    ("I5", C, "Gabon", "GA"),
+    ("I6", C, "Gambia", "GM"),
+    ("2Q", C, "Georgia", "GE"),
+    ("2M", C, "Germany", "DE"),
+    ("J0", C, "Ghana", "GH"),
+    ("J1", C, "Gibraltar", "GI"),
+    ("J3", C, "Greece", "GR"),
+    ("J4", C, "Greenland", "GL"),
+    ("J5", C, "Grenada", "GD"),
+    ("J6", C, "Guadeloupe", "GP"),
+    ("J8", C, "Guatemala", "GT"),
+    ("Y7", C, "Guernsey", "GG"),
+    ("J9", C, "Guinea", "GN"),
+    ("S0", C, "Guinea-bissau", "GW"),
+    ("K0", C, "Guyana", "GY"),
+    ("K1", C, "Haiti", "HT"),
+    ("K4", C, "Heard Island and Mcdonald Islands", "HM"),
+    ("X4", C, "Holy See (Vatican City State)", "VA"),
+    ("K2", C, "Honduras", "HN"),
+    ("K3", C, "Hong Kong", "HK"),
+    ("K5", C, "Hungary", "HU"),
+    ("K6", C, "Iceland", "IS"),
+    ("K7", C, "India", "IN"),
+    ("K8", C, "Indonesia", "ID"),
+    ("K9", C, "Iran, Islamic Republic of", "IR"),
+    ("L0", C, "Iraq", "IQ"),
+    ("L2", C, "Ireland", "IE"),
+    ("Y8", C, "Isle of Man", "IM"),
+    ("L3", C, "Israel", "IL"),
+    ("L6", C, "Italy", "IT"),
+    ("L8", C, "Jamaica", "JM"),
+    ("M0", C, "Japan", "JP"),
+    ("Y9", C, "Jersey", "JE"),
+    ("M2", C, "Jordan", "JO"),
+    ("1P", C, "Kazakstan", "KZ"),
+    ("M3", C, "Kenya", "KE"),
+    ("J2", C, "Kiribati", "KI"),
+    ("M4", C, "Korea, Democratic People's Republic of", "KP"),
+    ("M5", C, "Korea, Republic of", "KR"),
+    ("M6", C, "Kuwait", "KW"),
+    ("1N", C, "Kyrgyzstan", "KG"),
+    ("M7", C, "Lao People's Democratic Republic", "LA"),
+    ("1R", C, "Latvia", "LV"),
+    ("M8", C, "Lebanon", "LB"),
+    ("M9", C, "Lesotho", "LS"),
+    ("N0", C, "Liberia", "LR"),
+    ("N1", C, "Libyan Arab Jamahiriya", "LY"),
+    ("N2", C, "Liechtenstein", "LI"),
+    ("1Q", C, "Lithuania", "LT"),
+    ("N4", C, "Luxembourg", "LU"),
+    ("N5", C, "Macau", "MO"),
+    ("1U", C, "Macedonia, the Former Yugoslav Republic of", "MK"),
+    ("N6", C, "Madagascar", "MG"),
+    ("N7", C, "Malawi", "MW"),
+    ("N8", C, "Malaysia", "MY"),
+    ("N9", C, "Maldives", "MV"),
+    ("O0", C, "Mali", "ML"),
+    ("O1", C, "Malta", "MT"),
+    ("1T", C, "Marshall Islands", "MH"),
+    ("O2", C, "Martinique", "MQ"),
+    ("O3", C, "Mauritania", "MR"),
+    ("O4", C, "Mauritius", "MU"),
+    ("2P", C, "Mayotte", "YT"),
+    ("O5", C, "Mexico", "MX"),
+    ("1K", C, "Micronesia, Federated States of", "FM"),
+    ("1S", C, "Moldova, Republic of", "MD"),
+    ("O9", C, "Monaco", "MC"),
+    ("P0", C, "Mongolia", "MN"),
+    ("Z5", C, "Montenegro", "ME"),
+    ("P1", C, "Montserrat", "MS"),
+    ("P2", C, "Morocco", "MA"),
+    ("P3", C, "Mozambique", "MZ"),
+    ("E1", C, "Myanmar", "MM"),
+    ("T6", C, "Namibia", "NA"),
+    ("P5", C, "Nauru", "NR"),
+    ("P6", C, "Nepal", "NP"),
+    ("P7", C, "Netherlands", "NL"),
+    ("P8", C, "Netherlands Antilles", "AN"),
+    ("1W", C, "New Caledonia", "NC"),
+    ("Q2", C, "New Zealand", "NZ"),
+    ("Q3", C, "Nicaragua", "NI"),
+    ("Q4", C, "Niger", "NE"),
+    ("Q5", C, "Nigeria", "NG"),
+    ("Q6", C, "Niue", "NU"),
+    ("Q7", C, "Norfolk Island", "NF"),
+    ("1V", C, "Northern Mariana Islands", "MP"),
+    ("Q8", C, "Norway", "NO"),
+    ("P4", C, "Oman", "OM"),
+    ("R0", C, "Pakistan", "PK"),
+    ("1Y", C, "Palau", "PW"),
+    ("1X", C, "Palestinian Territory, Occupied", "PS"),
+    ("R1", C, "Panama", "PA"),
+    ("R2", C, "Papua New Guinea", "PG"),
+    ("R4", C, "Paraguay", "PY"),
+    ("R5", C, "Peru", "PE"),
+    ("R6", C, "Philippines", "PH"),
+    ("R8", C, "Pitcairn", "PN"),
+    ("R9", C, "Poland", "PL"),
+    ("S1", C, "Portugal", "PT"),
+    ("S3", C, "Qatar", "QA"),
+    ("S4", C, "Reunion", "RE"),
+    ("S5", C, "Romania", "RO"),
+    ("1Z", C, "Russian Federation", "RU"),
+    ("S6", C, "Rwanda", "RW"),
+    ("Z0", C, "Saint Barthelemy", "BL"),
+    ("U8", C, "Saint Helena", "SH"),
+    ("U7", C, "Saint Kitts and Nevis", "KN"),
+    ("U9", C, "Saint Lucia", "LC"),
+    ("Z1", C, "Saint Martin", "MF"),
+    ("V0", C, "Saint Pierre and Miquelon", "PM"),
+    ("V1", C, "Saint Vincent and the Grenadines", "VC"),
+    ("Y0", C, "Samoa", "WS"),
+    ("S8", C, "San Marino", "SM"),
+    ("S9", C, "Sao Tome and Principe", "ST"),
+    ("T0", C, "Saudi Arabia", "SA"),
+    ("T1", C, "Senegal", "SN"),
+    ("Z2", C, "Serbia", "RS"),
+    ("T2", C, "Seychelles", "SC"),
+    ("T8", C, "Sierra Leone", "SL"),
+    ("U0", C, "Singapore", "SG"),
+    ("2B", C, "Slovakia", "SK"),
+    ("2A", C, "Slovenia", "SI"),
+    ("D7", C, "Solomon Islands", "SB"),
+    ("U1", C, "Somalia", "SO"),
+    ("T3", C, "South Africa", "ZA"),
+    ("1L", C, "South Georgia and the South Sandwich Islands", "GS"),
+    ("U3", C, "Spain", "ES"),
+    ("F1", C, "Sri Lanka", "LK"),
+    ("V2", C, "Sudan", "SD"),
+    ("V3", C, "Suriname", "SR"),
+    ("L9", C, "Svalbard and Jan Mayen", "SJ"),
+    ("V6", C, "Swaziland", "SZ"),
+    ("V7", C, "Sweden", "SE"),
+    ("V8", C, "Switzerland", "CH"),
+    ("V9", C, "Syrian Arab Republic", "SY"),
+    ("F5", C, "Taiwan, Province of China", "TW"),
+    ("2D", C, "Tajikistan", "TJ"),
+    ("W0", C, "Tanzania, United Republic of", "TZ"),
+    ("W1", C, "Thailand", "TH"),
+    ("Z3", C, "Timor-leste", "TL"),
+    ("W2", C, "Togo", "TG"),
+    ("W3", C, "Tokelau", "TK"),
+    ("W4", C, "Tonga", "TO"),
+    ("W5", C, "Trinidad and Tobago", "TT"),
+    ("W6", C, "Tunisia", "TN"),
+    ("W8", C, "Turkey", "TR"),
+    ("2E", C, "Turkmenistan", "TM"),
+    ("W7", C, "Turks and Caicos Islands", "TC"),
+    ("2G", C, "Tuvalu", "TV"),
+    ("W9", C, "Uganda", "UG"),
+    ("2H", C, "Ukraine", "UA"),
+    ("C0", C, "United Arab Emirates", "AE"),
+    ("X0", C, "United Kingdom", "GB"),
+    ("2J", C, "United States Minor Outlying Islands", "UM"),
+    ("X3", C, "Uruguay", "UY"),
+    ("2K", C, "Uzbekistan", "UZ"),
+    ("2L", C, "Vanuatu", "VU"),
+    ("X5", C, "Venezuela", "VE"),
+    ("Q1", C, "Viet Nam", "VN"),
+    ("D8", C, "Virgin Islands, British", "VG"),
+    ("X8", C, "Wallis and Futuna", "WF"),
+    ("U5", C, "Western Sahara", "EH"),
+    ("T7", C, "Yemen", "YE"),
+    ("Y4", C, "Zambia", "ZM"),
+    ("Y5", C, "Zimbabwe", "ZW"),
+    ("XX", C, "Unknown", ""),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_are_unique() {
+        let mut codes: Vec<_> = STATES_AND_COUNTRIES.iter().map(|entry| entry.0).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), STATES_AND_COUNTRIES.len());
+    }
+
+    #[test]
+    fn test_location_codes() {
+        for (at, location) in US_LOCATIONS.iter().enumerate() {
+            assert!(matches!(STATES_AND_COUNTRIES[at].1, Kind::State));
+            assert_eq!(Location::from_code(location.code()), Some(*location));
+        }
+        assert_eq!(Location::NewYork.name(), Some("New York"));
+        assert_eq!(Location::UsVirginIslands.code(), "VI");
+        assert_eq!(Location::country("ontario"), Some(Location::Foreign("A6")));
+        assert_eq!(Location::country("GB"), Some(Location::Foreign("X0")));
+        assert_eq!(Location::country("Texas"), None);
+        assert_eq!(Location::from_code("x0"), Some(Location::Foreign("X0")));
+    }
+
+    #[test]
+    fn test_parse_and_iso() {
+        assert_eq!(StateOrCountry::from(" ny "), StateOrCountry::UsState("NY"));
+        assert_eq!(
+            StateOrCountry::from("a8"),
+            StateOrCountry::CanadianProvince("A8")
+        );
+        assert_eq!(
+            StateOrCountry::from("Q9"),
+            StateOrCountry::Other("Q9".to_string())
+        );
+
+        let puerto_rico = StateOrCountry::from("PR");
+        assert!(puerto_rico.is_us());
+        assert_eq!(puerto_rico.iso_country(), Some("PR"));
+        assert_eq!(puerto_rico.iso_subdivision(), Some("US-PR"));
+        assert_eq!(StateOrCountry::from_iso("us-pr"), Some(puerto_rico));
+
+        assert_eq!(
+            StateOrCountry::from_iso("GB"),
+            Some(StateOrCountry::Country("X0"))
+        );
+        assert_eq!(
+            StateOrCountry::from_iso("US"),
+            Some(StateOrCountry::Country("X1"))
+        );
+        assert_eq!(StateOrCountry::from("X1").iso_subdivision(), None);
+        assert_eq!(StateOrCountry::from("XX").iso_country(), None);
+        assert_eq!(StateOrCountry::from_iso("ZZ"), None);
+    }
+}
@@ -0,0 +1,430 @@
+//! Object-style handles over companies and filings.
+//!
+//! The lower-level API hands out metadata structs and leaves it to the caller to thread CIK
+//! strings, accession numbers, and filenames through every call. A [`Company`] keeps a
+//! resolved CIK, and a [`Filing`] keeps the pieces of one submission, each together with a
+//! clone of the client, so related data can be reached with method calls:
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, FilingOptions};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let opts = FilingOptions::new().with_form_type("10-K").with_limit(1);
+//! let apple = edgar.company("AAPL").await?;
+//! for filing in apple.filings(Some(opts)).await? {
+//!     let html = filing.primary_document().await?;
+//!     for exhibit in filing.exhibits().await? {
+//!         println!("{} {:?}", exhibit.document_type, exhibit.filename);
+//!     }
+//!     if let Some(instance) = filing.xbrl().await? {
+//!         println!("XBRL instance: {} bytes", instance.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Nothing is downloaded until a method needs it. The directory listing and the complete
+//! submission text are fetched at most once per [`Filing`] and shared by later calls;
+//! [`Company`] methods fetch fresh data on every call.
+
+use super::Edgar;
+use super::cik::Cik;
+#[cfg(feature = "company")]
+use super::company::{CompanyConcept, CompanyFacts};
+use super::entity::EntityId;
+use super::error::{EdgarError, ErrorContext, Result};
+use super::events::CorporateEvent;
+use super::filings::{DetailedFiling, Directory, DirectoryItem, Submission};
+#[cfg(feature = "feeds")]
+use super::options::FeedOptions;
+use super::options::FilingOptions;
+#[cfg(feature = "company")]
+use super::traits::CompanyOperations;
+#[cfg(feature = "feeds")]
+use super::traits::FeedOperations;
+use super::traits::FilingOperations;
+#[cfg(feature = "feeds")]
+use crate::parsing::atom::AtomDocument;
+use crate::parsing::index::IndexEntry;
+use crate::parsing::ownership::{BeneficialOwnership, Schedule, parse_beneficial_ownership};
+use crate::parsing::sections::{Item, Section, extract_section, split_sections};
+use crate::parsing::submission::{SubmissionConfig, SubmissionDocument, SubmissionParser};
+use tokio::sync::OnceCell;
+
+/// Linkbase and summary files that sit next to an XBRL instance but are not the instance.
+const XBRL_SUPPORT_SUFFIXES: &[&str] = &["_cal.xml", "_def.xml", "_lab.xml", "_pre.xml"];
+
+/// A company identified by its CIK.
+///
+/// Create one with [`Edgar::company`] (CIK, ticker, or name) or [`Edgar::company_by_cik`].
+#[derive(Debug, Clone)]
+pub struct Company {
+    edgar: Edgar,
+    cik: Cik,
+}
+
+impl Company {
+    pub fn cik(&self) -> Cik {
+        self.cik
+    }
+
+    /// The company's submissions payload: entity metadata plus recent filings.
+    pub async fn submissions(&self) -> Result<Submission> {
+        self.edgar.submissions(self.cik).await
+    }
+
+    /// Renames, ticker changes, and exchange moves known for the company, oldest first.
+    ///
+    /// Downloads the submissions payload, so renames are always included. Ticker and
+    /// exchange changes appear once the client has recorded the company's submissions
+    /// before, which needs
+    /// [`EdgarConfig::with_corporate_history`](crate::EdgarConfig::with_corporate_history).
+    /// See the `events` module docs.
+    pub async fn corporate_events(&self) -> Result<Vec<CorporateEvent>> {
+        let submission = self.submissions().await?;
+        if !self.edgar.record_history {
+            return Ok(submission.corporate_events());
+        }
+        Ok(self.edgar.corporate_events(self.cik))
+    }
+
+    /// Recent filings matching `opts`, newest first.
+    pub async fn filings(&self, opts: Option<FilingOptions>) -> Result<Vec<Filing>> {
+        self.edgar.filing_handles(self.cik, opts).await
+    }
+
+    /// The most recent filing of `form` (amendments included), if any.
+    pub async fn latest(&self, form: &str) -> Result<Option<Filing>> {
+        let opts = FilingOptions::new().with_form_type(form).with_limit(1);
+        Ok(self.filings(Some(opts)).await?.into_iter().next())
+    }
+
+    /// All XBRL facts the company has reported.
+    #[cfg(feature = "company")]
+    pub async fn facts(&self) -> Result<CompanyFacts> {
+        self.edgar.company_facts(self.cik).await
+    }
+
+    /// One concept's reported values.
+    ///
+    /// `tag` is a US-GAAP tag such as `"Revenues"`, or `taxonomy:tag` for other taxonomies
+    /// (`"dei:EntityCommonStockSharesOutstanding"`).
+    #[cfg(feature = "company")]
+    pub async fn concept(&self, tag: &str) -> Result<CompanyConcept> {
+        let (taxonomy, tag) = tag.split_once(':').unwrap_or(("us-gaap", tag));
+        self.edgar.company_concept(self.cik, taxonomy, tag).await
+    }
+
+    /// The company's Atom filing feed.
+    #[cfg(feature = "feeds")]
+    pub async fn feed(&self, opts: Option<FeedOptions>) -> Result<AtomDocument> {
+        self.edgar.company_feed(self.cik, opts).await
+    }
+}
+
+/// A single filing with lazy access to its documents.
+///
+/// Create one with [`Edgar::filing`], [`Edgar::filing_handles`], or [`Filing::new`] when you
+/// already hold the metadata.
+#[derive(Debug, Clone)]
+pub struct Filing {
+    edgar: Edgar,
+    cik: Cik,
+    metadata: DetailedFiling,
+    directory: OnceCell<Directory>,
+    text: OnceCell<String>,
+}
+
+impl Filing {
+    /// Wraps filing metadata for the company identified by `cik`.
+    pub fn new(edgar: &Edgar, cik: impl Into<Cik>, metadata: DetailedFiling) -> Self {
+        Self {
+            edgar: edgar.clone(),
+            cik: cik.into(),
+            metadata,
+            directory: OnceCell::new(),
+            text: OnceCell::new(),
+        }
+    }
+
+    /// CIK of the filer.
+    pub fn cik(&self) -> Cik {
+        self.cik
+    }
+
+    pub fn accession_number(&self) -> &str {
+        &self.metadata.accession_number
+    }
+
+    pub fn form(&self) -> &str {
+        &self.metadata.form
+    }
+
+    /// Metadata from the submissions table.
+    pub fn metadata(&self) -> &DetailedFiling {
+        &self.metadata
+    }
+
+    /// Lists the files in the filing's archive folder.
+    pub async fn directory(&self) -> Result<&Directory> {
+        self.directory
+            .get_or_try_init(|| async {
+                let response = self
+                    .edgar
+                    .filing_directory(self.cik, &self.metadata.accession_number)
+                    .await?;
+                Ok(response.directory)
+            })
+            .await
+    }
+
+    fn not_found(&self) -> EdgarError {
+        EdgarError::not_found(ErrorContext::for_filing(
+            self.cik,
+            Some(&self.metadata.accession_number),
+        ))
+    }
+
+    /// Downloads a file from the filing's archive folder by name.
+    pub async fn document(&self, name: &str) -> Result<String> {
+        let url = self
+            .edgar
+            .get_filing_url(self.cik, &self.metadata.accession_number, name)?;
+        self.edgar.get(&url).await
+    }
+
+    /// Downloads the primary document named in the submissions table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no primary document, which is the
+    /// case for some older and paper-derived filings.
+    pub async fn primary_document(&self) -> Result<String> {
+        let name = self
+            .metadata
+            .primary_document
+            .as_deref()
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| self.not_found())?;
+        self.document(name).await
+    }
+
+    /// Splits the primary document of a 10-K or 10-Q into its items.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no primary document.
+    pub async fn sections(&self) -> Result<Vec<Section>> {
+        let document = self.primary_document().await?;
+        Ok(split_sections(&document, &self.metadata.form))
+    }
+
+    /// Extracts one item of a 10-K or 10-Q from the primary document.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the filing has no primary document or the item is
+    /// not in it.
+    pub async fn section(&self, item: Item) -> Result<Section> {
+        let document = self.primary_document().await?;
+        extract_section(&document, &self.metadata.form, &item).ok_or_else(|| self.not_found())
+    }
+
+    /// Parses the cover pages of a Schedule 13D or 13G; see [`parse_beneficial_ownership`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if the filing is not a Schedule 13D or 13G, and
+    /// `EdgarError::NotFound` if it has no primary document.
+    pub async fn beneficial_ownership(&self) -> Result<BeneficialOwnership> {
+        if Schedule::from_form(&self.metadata.form).is_none() {
+            return Err(EdgarError::InvalidFormat(format!(
+                "{} is not a Schedule 13D or 13G",
+                self.metadata.form
+            )));
+        }
+        let document = self.primary_document().await?;
+        Ok(parse_beneficial_ownership(&document))
+    }
+
+    /// Returns the complete submission text file (`<accession>.txt`).
+    ///
+    /// The file bundles the SGML header and every document in the filing.
+    pub async fn text(&self) -> Result<&str> {
+        let text = self
+            .text
+            .get_or_try_init(|| async {
+                let url = self
+                    .edgar
+                    .get_text_filing_url(self.cik, &self.metadata.accession_number)?;
+                self.edgar.get(&url).await
+            })
+            .await?;
+        Ok(text)
+    }
+
+    /// Splits the submission text file into its documents.
+    pub async fn documents(&self) -> Result<Vec<SubmissionDocument>> {
+        SubmissionParser::new(SubmissionConfig::default()).parse(self.text().await?)
+    }
+
+    /// Returns the exhibits (`EX-*` documents) of the filing, in filing order.
+    pub async fn exhibits(&self) -> Result<Vec<SubmissionDocument>> {
+        Ok(self
+            .documents()
+            .await?
+            .into_iter()
+            .filter(|doc| doc.document_type.starts_with("EX-"))
+            .collect())
+    }
+
+    /// Downloads the XBRL instance document, or `None` if the filing has no XBRL.
+    ///
+    /// For inline XBRL filings this is the instance extracted by EDGAR (`*_htm.xml`); for
+    /// traditional XBRL filings it is the standalone instance file.
+    pub async fn xbrl(&self) -> Result<Option<String>> {
+        if !self.metadata.is_xbrl && !self.metadata.is_inline_xbrl {
+            return Ok(None);
+        }
+        match xbrl_instance_name(&self.directory().await?.item) {
+            Some(name) => self.document(name).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Picks the XBRL instance from a filing folder listing.
+fn xbrl_instance_name(items: &[DirectoryItem]) -> Option<&str> {
+    let candidates: Vec<&str> = items
+        .iter()
+        .map(|item| item.name.as_str())
+        .filter(|name| name.ends_with(".xml"))
+        .filter(|name| !XBRL_SUPPORT_SUFFIXES.iter().any(|s| name.ends_with(s)))
+        .filter(|name| *name != "FilingSummary.xml" && *name != "primary_doc.xml")
+        .collect();
+
+    candidates
+        .iter()
+        .find(|name| name.ends_with("_htm.xml"))
+        .or_else(|| candidates.first())
+        .copied()
+}
+
+fn index_accession(entry: &IndexEntry) -> Result<&str> {
+    entry
+        .accession_number()
+        .ok_or_else(|| EdgarError::InvalidFormat(format!("No accession number in {}", entry.url)))
+}
+
+impl Edgar {
+    /// Returns a handle for a company given a CIK, ticker, or name.
+    ///
+    /// The company is resolved through [`Edgar::resolve_entity`].
+    pub async fn company(&self, id: impl Into<EntityId> + Send) -> Result<Company> {
+        Ok(self.company_by_cik(self.resolve_entity(id).await?))
+    }
+
+    /// Returns a handle for a company with a known CIK. Makes no request.
+    pub fn company_by_cik(&self, cik: impl Into<Cik>) -> Company {
+        Company {
+            edgar: self.clone(),
+            cik: cik.into(),
+        }
+    }
+
+    /// Returns a handle for one filing from the company's recent submissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` if the accession number is not among the company's
+    /// recent filings.
+    pub async fn filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Filing> {
+        let cik = self.resolve_entity(cik).await?;
+        let metadata = self
+            .get_recent_filings(cik)
+            .await?
+            .into_iter()
+            .find(|f| f.accession_number == accession_number)
+            .ok_or_else(|| {
+                EdgarError::not_found(ErrorContext::for_filing(cik, Some(accession_number)))
+            })?;
+        Ok(Filing::new(self, cik, metadata))
+    }
+
+    /// Returns a handle for the filing an index entry points to.
+    ///
+    /// The entry's accession number is looked up in the filer's recent submissions, so the
+    /// handle carries full metadata, including the primary document name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if the entry's URL has no accession number and
+    /// `EdgarError::NotFound` if the filing is not among the filer's recent filings. Older
+    /// filings can still be listed with [`index_entry_directory`](Self::index_entry_directory).
+    pub async fn filing_from_index_entry(&self, entry: &IndexEntry) -> Result<Filing> {
+        self.filing(entry.cik, index_accession(entry)?).await
+    }
+
+    /// Lists the archive folder of the filing an index entry points to.
+    ///
+    /// Unlike [`filing_from_index_entry`](Self::filing_from_index_entry) this needs no
+    /// submissions lookup and works for filings of any age.
+    pub async fn index_entry_directory(&self, entry: &IndexEntry) -> Result<Directory> {
+        let response = self
+            .filing_directory(entry.cik, index_accession(entry)?)
+            .await?;
+        Ok(response.directory)
+    }
+
+    /// Like [`filings`](FilingOperations::filings), but returns [`Filing`] handles.
+    pub async fn filing_handles(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<Filing>> {
+        let cik = self.resolve_entity(cik).await?;
+        Ok(self
+            .filings(cik, opts)
+            .await?
+            .into_iter()
+            .map(|metadata| Filing::new(self, cik, metadata))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(name: &str) -> DirectoryItem {
+        DirectoryItem {
+            last_modified: "2024-11-01 06:01:36".to_string(),
+            name: name.to_string(),
+            type_: "text.gif".to_string(),
+            size: 0,
+        }
+    }
+
+    #[test]
+    fn test_xbrl_instance_name() {
+        let inline = [
+            item("FilingSummary.xml"),
+            item("aapl-20240928.htm"),
+            item("aapl-20240928_cal.xml"),
+            item("aapl-20240928_htm.xml"),
+            item("aapl-20240928_lab.xml"),
+        ];
+        assert_eq!(xbrl_instance_name(&inline), Some("aapl-20240928_htm.xml"));
+
+        let traditional = [item("abc-20101231_pre.xml"), item("abc-20101231.xml")];
+        assert_eq!(xbrl_instance_name(&traditional), Some("abc-20101231.xml"));
+
+        assert_eq!(xbrl_instance_name(&[item("primary_doc.xml")]), None);
+    }
+}
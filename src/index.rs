@@ -10,6 +10,12 @@
 //! - Downloads the selected index file (`.idx` or `.gz`) from the EDGAR archives.
 //! - Parses it using the `parsers::index` parser into [`IndexEntry`] records.
 //! - Optionally applies [`FilingOptions`] filters (`form_types`, `ciks`, `offset`, `limit`).
+//!   With `form_types` set, the form index (`form.idx`) is read instead of the master index,
+//!   which lets the parser skip unwanted forms without parsing their rows.
+//!
+//! Before a large backfill, `index_coverage` checks which business days in a range have a
+//! daily index at all (one directory listing per quarter), so gaps in the SEC's data can be
+//! told apart from days that simply had no filings of interest.
 //!
 //! The SEC directory listing uses human-readable sizes and a custom timestamp format
 //! (`MM/DD/YYYY HH:MM:SS AM/PM`), which is handled by `edgar_date_format`.
@@ -45,16 +51,20 @@
 //! ```
 
 use super::Edgar;
-use super::error::{EdgarError, Result};
+use super::error::{EdgarError, ErrorContext, Result};
 use super::options::FilingOptions;
+use super::quarter::Quarter;
+use super::rt::{BoxStream, IntoBoxStream};
 use super::traits::IndexOperations;
 use crate::parsing::index::{IndexConfig, IndexEntry, IndexParser, IndexType};
+use crate::parsing::utils::deserialize_human_size;
 use async_trait::async_trait;
-use chrono::{Datelike, NaiveDateTime};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
 use flate2::read::GzDecoder;
+use futures_util::stream;
 use serde::{Deserialize, Serialize};
-use serde_json;
-use std::io::Read;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{BufRead, BufReader, Cursor, Read};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -103,8 +113,10 @@ pub struct DirectoryItem {
     /// Relative URL path (joined with the corresponding archives prefix).
     pub href: String,
 
-    /// File size (human-readable, as provided by the SEC listing).
-    pub size: String,
+    /// File size in bytes (the SEC listing shows e.g. `52453 KB`; see
+    /// [`format_human`](crate::format_human) to render it back).
+    #[serde(deserialize_with = "deserialize_human_size")]
+    pub size: u64,
 }
 
 /// Serde helpers for EDGAR date format (`MM/DD/YYYY HH:MM:SS AM/PM`).
@@ -130,48 +142,6 @@ mod edgar_date_format {
     }
 }
 
-/// Fiscal quarter (Q1-Q4).
-///
-/// EDGAR index directories are grouped by quarter (e.g., `QTR1` .. `QTR4`).
-///
-/// Each quarter maps to specific months:
-/// - Q1: January through March
-/// - Q2: April through June
-/// - Q3: July through September
-/// - Q4: October through December
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Quarter {
-    Q1 = 1,
-    Q2 = 2,
-    Q3 = 3,
-    Q4 = 4,
-}
-
-impl Quarter {
-    /// Creates a Quarter from a month number (1-12)
-    ///
-    /// # Arguments
-    /// * `month` - Month number (1-12)
-    ///
-    /// # Returns
-    /// * `Ok(Quarter)` if month is valid
-    /// * `Err(EdgarError::InvalidMonth)` if month is invalid
-    pub fn from_month(month: u32) -> Result<Self> {
-        match month {
-            1..=3 => Ok(Quarter::Q1),
-            4..=6 => Ok(Quarter::Q2),
-            7..=9 => Ok(Quarter::Q3),
-            10..=12 => Ok(Quarter::Q4),
-            _ => Err(EdgarError::InvalidMonth),
-        }
-    }
-
-    /// Converts the quarter to its integer representation (1-4).
-    pub fn as_i32(&self) -> i32 {
-        *self as i32
-    }
-}
-
 /// A specific day in EDGAR's system (must be 1994 or later).
 #[derive(Debug, Clone, Copy)]
 pub struct EdgarDay {
@@ -214,10 +184,10 @@ impl EdgarDay {
         if year < 1994 {
             return Err(EdgarError::InvalidYear);
         }
-        if month < 1 || month > 12 {
+        if !(1..=12).contains(&month) {
             return Err(EdgarError::InvalidMonth);
         }
-        if day < 1 || day > 31 {
+        if !(1..=31).contains(&day) {
             return Err(EdgarError::InvalidDay);
         }
         Ok(Self { year, month, day })
@@ -239,6 +209,177 @@ impl EdgarDay {
     }
 }
 
+impl EdgarDay {
+    /// Converts to a calendar date.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidDay` if the day does not exist in that month (e.g. February 30).
+    pub fn to_naive_date(&self) -> Result<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day).ok_or(EdgarError::InvalidDay)
+    }
+}
+
+/// Which business days in a range have a daily index.
+///
+/// Returned by [`IndexOperations::index_coverage`].
+///
+/// Only weekdays are considered. EDGAR is also closed on federal holidays, so those show up
+/// in `missing` as well; callers that need an exact calendar should filter them out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexCoverage {
+    /// First day of the checked range.
+    pub start: NaiveDate,
+
+    /// Last day of the checked range (inclusive).
+    pub end: NaiveDate,
+
+    /// Business days with at least one daily index file.
+    pub available: Vec<NaiveDate>,
+
+    /// Business days with no daily index file.
+    pub missing: Vec<NaiveDate>,
+}
+
+impl IndexCoverage {
+    /// Builds a coverage report for `start..=end` from the set of days known to have an index.
+    fn from_available(start: NaiveDate, end: NaiveDate, indexed: &BTreeSet<NaiveDate>) -> Self {
+        let (available, missing) = business_days(start, end).partition(|day| indexed.contains(day));
+        Self {
+            start,
+            end,
+            available,
+            missing,
+        }
+    }
+
+    /// Returns true if every business day in the range has an index.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Groups missing days into inclusive runs of consecutive business days.
+    pub fn gaps(&self) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut gaps: Vec<(NaiveDate, NaiveDate)> = Vec::new();
+        for &day in &self.missing {
+            match gaps.last_mut() {
+                Some((_, last)) if next_business_day(*last) == day => *last = day,
+                _ => gaps.push((day, day)),
+            }
+        }
+        gaps
+    }
+}
+
+/// A year or a single quarter of daily indices, for [`IndexOperations::available_days`].
+///
+/// Converts from a year (`2023`) or an [`EdgarPeriod`].
+#[derive(Debug, Clone, Copy)]
+pub enum IndexScope {
+    Year(i32),
+    Quarter(EdgarPeriod),
+}
+
+impl IndexScope {
+    /// The `(year, quarter)` directories this scope covers.
+    fn quarters(self) -> Vec<(i32, i32)> {
+        match self {
+            IndexScope::Year(year) => (1..=4).map(|quarter| (year, quarter)).collect(),
+            IndexScope::Quarter(period) => vec![(period.year(), period.quarter().as_i32())],
+        }
+    }
+}
+
+impl From<i32> for IndexScope {
+    fn from(year: i32) -> Self {
+        IndexScope::Year(year)
+    }
+}
+
+impl From<EdgarPeriod> for IndexScope {
+    fn from(period: EdgarPeriod) -> Self {
+        IndexScope::Quarter(period)
+    }
+}
+
+/// A day with published daily index files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexDay {
+    pub date: NaiveDate,
+
+    /// The day's index files (`company`, `form`, `master`, ...), sorted by name.
+    pub files: Vec<DailyIndexFile>,
+}
+
+impl IndexDay {
+    /// The file of one index type, e.g. `master` for `master.20230103.idx`.
+    pub fn file(&self, index_type: &str) -> Option<&DailyIndexFile> {
+        self.files.iter().find(|file| {
+            file.name
+                .split_once('.')
+                .is_some_and(|(prefix, _)| prefix.eq_ignore_ascii_case(index_type))
+        })
+    }
+}
+
+/// A daily index file from a quarter's directory listing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyIndexFile {
+    /// File name, e.g. `form.20230103.idx`.
+    pub name: String,
+
+    /// Absolute URL of the file.
+    pub url: String,
+
+    /// Size in bytes, as listed.
+    pub size: u64,
+
+    pub last_modified: NaiveDateTime,
+}
+
+/// Weekdays in `start..=end`.
+fn business_days(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    start
+        .iter_days()
+        .take_while(move |day| *day <= end)
+        .filter(|day| !matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+}
+
+/// The `(year, quarter)` pairs touched by `start..=end`, in order.
+fn quarters_between(start: NaiveDate, end: NaiveDate) -> Result<Vec<(i32, i32)>> {
+    let mut quarters = Vec::new();
+    for day in start.iter_days().take_while(|day| *day <= end) {
+        let quarter = (day.year(), Quarter::from_month(day.month())?.as_i32());
+        if quarters.last() != Some(&quarter) {
+            quarters.push(quarter);
+        }
+    }
+    Ok(quarters)
+}
+
+fn next_business_day(day: NaiveDate) -> NaiveDate {
+    let mut next = day.succ_opt().unwrap_or(day);
+    while matches!(next.weekday(), Weekday::Sat | Weekday::Sun) {
+        next = next.succ_opt().unwrap_or(next);
+    }
+    next
+}
+
+/// Extracts the date from a daily index filename such as `company.20230815.idx` or the
+/// older two-digit-year form `company.941003.idx`.
+fn daily_index_date(filename: &str) -> Option<NaiveDate> {
+    filename.split('.').find_map(|part| {
+        if !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        match part.len() {
+            8 => NaiveDate::parse_from_str(part, "%Y%m%d").ok(),
+            6 => NaiveDate::parse_from_str(part, "%y%m%d").ok(),
+            _ => None,
+        }
+    })
+}
+
 /// A fiscal period (year + quarter) used to locate quarterly index directories.
 ///
 /// Quarterly indices live under paths like `.../full-index/<YEAR>/QTR<1-4>/` (and similarly
@@ -290,6 +431,25 @@ impl EdgarPeriod {
     pub fn quarter(&self) -> Quarter {
         self.quarter
     }
+
+    /// The following quarter.
+    #[cfg(any(feature = "bulk", feature = "pipeline"))]
+    pub(crate) fn next(self) -> EdgarPeriod {
+        let (year, quarter) = match self.quarter {
+            Quarter::Q1 => (self.year, Quarter::Q2),
+            Quarter::Q2 => (self.year, Quarter::Q3),
+            Quarter::Q3 => (self.year, Quarter::Q4),
+            Quarter::Q4 => (self.year + 1, Quarter::Q1),
+        };
+        // Moving forward from a valid period can never produce a year before 1994.
+        EdgarPeriod { year, quarter }
+    }
+
+    /// `(year, quarter)` for ordering periods.
+    #[cfg(any(feature = "bulk", feature = "pipeline"))]
+    pub(crate) fn key(self) -> (i32, i32) {
+        (self.year, self.quarter.as_i32())
+    }
 }
 
 impl Edgar {
@@ -327,23 +487,61 @@ impl Edgar {
         }
     }
 
+    /// Downloads an index file and returns a reader over its text.
+    ///
+    /// Gzipped files are decompressed while they are read, so only the compressed bytes are
+    /// held in memory.
+    async fn open_index_file(&self, url: &str, file_name: &str) -> Result<Box<dyn BufRead + Send>> {
+        let bytes = self.get_bytes(url).await?;
+        if Self::is_archive(file_name) {
+            Ok(Box::new(BufReader::new(GzDecoder::new(Cursor::new(bytes)))))
+        } else {
+            Ok(Box::new(Cursor::new(bytes)))
+        }
+    }
+
     async fn download_and_parse_index(
         &self,
         url: &str,
         file_name: &str,
-        index_type: Option<IndexType>,
+        config: IndexConfig,
     ) -> Result<Vec<IndexEntry>> {
         let is_archive = Self::is_archive(file_name);
         let content = self.download_file(url, is_archive).await?;
 
-        // If no index_type provided, the parser will try to guess it
+        let parser = IndexParser::new(config);
+        parser.parse(content.as_bytes())
+    }
+
+    /// Picks the index file to download and how to parse it.
+    ///
+    /// When `options` filter by form type the form index (`form.idx`) is preferred: its rows
+    /// are grouped by form, so the parser skips other forms without parsing them and stops
+    /// after the last wanted group. Otherwise, or if the listing has no form index, the
+    /// default index type is used.
+    fn select_index_file<'a>(
+        items: &'a [DirectoryItem],
+        date: EdgarDate,
+        options: Option<&FilingOptions>,
+    ) -> Option<(&'a DirectoryItem, IndexConfig)> {
+        let form_types = options.and_then(|opts| opts.form_types.clone());
+        let form_index = form_types
+            .as_ref()
+            .and_then(|_| Self::find_index_file(items, date, IndexType::Form))
+            .map(|item| (item, IndexType::Form));
+        let (item, index) = match form_index {
+            Some(found) => found,
+            None => {
+                let index = IndexType::default();
+                (Self::find_index_file(items, date, index)?, index)
+            }
+        };
         let config = IndexConfig {
-            index_type,
+            index_type: Some(index),
+            form_types,
             ..Default::default()
         };
-
-        let parser = IndexParser::new(config);
-        Ok(parser.parse(content.as_bytes())?)
+        Some((item, config))
     }
 
     /// Picks the most appropriate index file from a directory listing.
@@ -352,11 +550,11 @@ impl Edgar {
     /// For quarterly indices, the filename is stable within a quarter folder (e.g., `company.idx`).
     ///
     /// When both `.gz` and `.idx` are present, we prefer `.gz` first.
-    fn find_index_file<'a>(
-        items: &'a [DirectoryItem],
+    fn find_index_file(
+        items: &[DirectoryItem],
         date: impl Into<EdgarDate>,
         index_type: IndexType,
-    ) -> Option<&'a DirectoryItem> {
+    ) -> Option<&DirectoryItem> {
         let prefix = index_type.as_str();
         let extensions = ["gz", "idx"]; // Priority order
 
@@ -446,7 +644,7 @@ impl Edgar {
     ) -> Result<IndexResponse> {
         match (year, quarter) {
             (Some(y), _) if y < 1994 => Err(EdgarError::InvalidYear),
-            (_, Some(q)) if q < 1 || q > 4 => Err(EdgarError::InvalidQuarter),
+            (_, Some(q)) if !(1..=4).contains(&q) => Err(EdgarError::InvalidQuarter),
             _ => {
                 let url = self.build_index_url(index_type, year, quarter)?;
                 let response = self.get(&url).await?;
@@ -455,6 +653,15 @@ impl Edgar {
         }
     }
 
+    /// The error for a quarter directory that has no suitable index file.
+    fn missing_index(&self, index_type: &str, year: i32, quarter: i32) -> EdgarError {
+        let context = self
+            .build_index_url(index_type, Some(year), Some(quarter))
+            .map(|url| ErrorContext::for_url(&url, None))
+            .unwrap_or_default();
+        EdgarError::not_found(context)
+    }
+
     /// Applies `FilingOptions` filters to parsed index entries.
     ///
     /// This filter stage is intentionally simple: it operates on already-parsed `IndexEntry` values,
@@ -462,7 +669,7 @@ impl Edgar {
     fn apply_filters(&self, mut entries: Vec<IndexEntry>, opts: &FilingOptions) -> Vec<IndexEntry> {
         // Filter by form types if specified
         if let Some(ref form_types) = opts.form_types {
-            entries.retain(|entry| form_types.iter().any(|ft| ft == &entry.form_type.trim()));
+            entries.retain(|entry| form_types.iter().any(|ft| ft == entry.form_type.trim()));
         }
 
         // Filter by CIK if specified
@@ -513,7 +720,8 @@ impl Edgar {
 ///     Ok(())
 /// }
 /// ```
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl IndexOperations for Edgar {
     /// Retrieves filings for a specific day
     ///
@@ -528,18 +736,19 @@ impl IndexOperations for Edgar {
     /// * `EdgarError::InvalidYear` if year < 1994
     /// * `EdgarError::NotFound` if no index file exists
     /// * `EdgarError::RequestError` for network issues
+    #[tracing::instrument(skip(self, options), fields(entries = tracing::field::Empty))]
     async fn get_daily_filings(
         &self,
         day: EdgarDay,
         options: Option<FilingOptions>,
     ) -> Result<Vec<IndexEntry>> {
-        let index = IndexType::default();
         let response = self
             .fetch_index("daily", Some(day.year()), Some(day.quarter().as_i32()))
             .await?;
 
-        let index_file = Self::find_index_file(&response.directory.item, day, index)
-            .ok_or_else(|| EdgarError::NotFound)?;
+        let (index_file, config) =
+            Self::select_index_file(&response.directory.item, day.into(), options.as_ref())
+                .ok_or_else(|| self.missing_index("daily", day.year(), day.quarter().as_i32()))?;
 
         let url = format!(
             "{}/daily-index/{}/QTR{}/{}",
@@ -550,7 +759,7 @@ impl IndexOperations for Edgar {
         );
 
         let mut entries = self
-            .download_and_parse_index(&url, &index_file.name, Some(index))
+            .download_and_parse_index(&url, &index_file.name, config)
             .await?;
 
         // Apply filters if provided
@@ -558,9 +767,72 @@ impl IndexOperations for Edgar {
             entries = self.apply_filters(entries, &opts);
         }
 
+        tracing::Span::current().record("entries", entries.len());
         Ok(entries)
     }
 
+    /// Streams filings for a specific quarter without collecting them first.
+    ///
+    /// The index file is downloaded up front, but entries are parsed as the stream is
+    /// polled, and `options` (form types, CIKs, offset, limit) are applied to each entry
+    /// before it is yielded. Peak memory is the downloaded file (compressed, for `.gz`
+    /// indices) rather than every parsed entry, which suits pipelines that write entries
+    /// out as they go.
+    ///
+    /// # Errors
+    /// The same as [`get_period_filings`](IndexOperations::get_period_filings) for the
+    /// listing and download; a line that cannot be parsed is yielded as an `Err` item.
+    ///
+    /// # Example
+    /// ```ignore
+    /// use futures_util::StreamExt;
+    ///
+    /// let period = EdgarPeriod::new(2023, Quarter::Q1)?;
+    /// let opts = FilingOptions::new().with_form_type("10-K");
+    /// let mut entries = edgar.stream_period_filings(period, Some(opts)).await?;
+    /// while let Some(entry) = entries.next().await {
+    ///     println!("{}", entry?.company_name);
+    /// }
+    /// ```
+    #[tracing::instrument(skip(self, options))]
+    async fn stream_period_filings(
+        &self,
+        period: EdgarPeriod,
+        options: Option<FilingOptions>,
+    ) -> Result<BoxStream<'static, Result<IndexEntry>>> {
+        let response = self
+            .fetch_index("full", Some(period.year()), Some(period.quarter().as_i32()))
+            .await?;
+
+        let (index_file, config) =
+            Self::select_index_file(&response.directory.item, period.into(), options.as_ref())
+                .ok_or_else(|| {
+                    self.missing_index("full", period.year(), period.quarter().as_i32())
+                })?;
+
+        let url = format!(
+            "{}/full-index/{}/QTR{}/{}",
+            self.edgar_archives_url,
+            period.year(),
+            period.quarter().as_i32(),
+            index_file.href
+        );
+
+        let reader = self.open_index_file(&url, &index_file.name).await?;
+        let entries = IndexParser::new(config).parse_iter(reader)?;
+
+        let opts = options.unwrap_or_default();
+        let ciks = opts.ciks;
+        let entries = entries
+            .filter(move |entry| match (entry, &ciks) {
+                (Ok(entry), Some(ciks)) => ciks.contains(&entry.cik),
+                _ => true,
+            })
+            .skip(opts.offset.unwrap_or(0))
+            .take(opts.limit.unwrap_or(usize::MAX));
+        Ok(stream::iter(entries).into_box_stream())
+    }
+
     /// Retrieves filings for a specific quarter
     ///
     /// This downloads the quarterly “full index” file for the given period and returns the
@@ -574,18 +846,21 @@ impl IndexOperations for Edgar {
     /// * `EdgarError::InvalidYear` if year < 1994
     /// * `EdgarError::InvalidQuarter` if quarter is invalid
     /// * `EdgarError::NotFound` if no index file exists
+    #[tracing::instrument(skip(self, options), fields(entries = tracing::field::Empty))]
     async fn get_period_filings(
         &self,
         period: EdgarPeriod,
         options: Option<FilingOptions>,
     ) -> Result<Vec<IndexEntry>> {
-        let index = IndexType::default();
         let response = self
             .fetch_index("full", Some(period.year()), Some(period.quarter().as_i32()))
             .await?;
 
-        let index_file = Self::find_index_file(&response.directory.item, period, index)
-            .ok_or_else(|| EdgarError::NotFound)?;
+        let (index_file, config) =
+            Self::select_index_file(&response.directory.item, period.into(), options.as_ref())
+                .ok_or_else(|| {
+                    self.missing_index("full", period.year(), period.quarter().as_i32())
+                })?;
 
         let url = format!(
             "{}/full-index/{}/QTR{}/{}",
@@ -596,7 +871,7 @@ impl IndexOperations for Edgar {
         );
 
         let mut entries = self
-            .download_and_parse_index(&url, &index_file.name, Some(index))
+            .download_and_parse_index(&url, &index_file.name, config)
             .await?;
 
         // Apply filters if provided
@@ -604,6 +879,170 @@ impl IndexOperations for Edgar {
             entries = self.apply_filters(entries, &opts);
         }
 
+        tracing::Span::current().record("entries", entries.len());
+        Ok(entries)
+    }
+
+    /// Reports which business days between `start` and `end` have a daily index.
+    ///
+    /// Only the quarterly `daily-index` directory listings are fetched (one request per
+    /// quarter in the range), not the index files themselves. A quarter whose listing does
+    /// not exist yet counts every one of its days as missing.
+    ///
+    /// # Errors
+    /// * `EdgarError::InvalidDay` if either date does not exist or `start` is after `end`
+    /// * `EdgarError::RequestError` for network issues
+    async fn index_coverage(&self, start: EdgarDay, end: EdgarDay) -> Result<IndexCoverage> {
+        let first = start.to_naive_date()?;
+        let last = end.to_naive_date()?;
+        if first > last {
+            return Err(EdgarError::InvalidDay);
+        }
+
+        let mut indexed = BTreeSet::new();
+        for (year, quarter) in quarters_between(first, last)? {
+            match self.fetch_index("daily", Some(year), Some(quarter)).await {
+                Ok(listing) => indexed.extend(
+                    listing
+                        .directory
+                        .item
+                        .iter()
+                        .filter(|item| item.type_ == ItemType::File)
+                        .filter_map(|item| daily_index_date(&item.name)),
+                ),
+                Err(EdgarError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(IndexCoverage::from_available(first, last, &indexed))
+    }
+
+    /// Lists the days of a year or quarter that have daily index files, with those files.
+    ///
+    /// Only the quarterly `daily-index` directory listings are fetched. Weekends, holidays,
+    /// and days not yet published are simply absent; so are quarters whose listing does not
+    /// exist yet.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use edgarkit::{EdgarPeriod, IndexOperations, Quarter};
+    ///
+    /// let days = edgar.available_days(EdgarPeriod::new(2023, Quarter::Q1)?).await?;
+    /// for day in &days {
+    ///     if let Some(master) = day.file("master") {
+    ///         println!("{} {}", day.date, master.url);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// * `EdgarError::InvalidYear` for years before 1994
+    /// * `EdgarError::RequestError` for network issues
+    async fn available_days(&self, scope: impl Into<IndexScope> + Send) -> Result<Vec<IndexDay>> {
+        let mut days: BTreeMap<NaiveDate, Vec<DailyIndexFile>> = BTreeMap::new();
+        for (year, quarter) in scope.into().quarters() {
+            let listing = match self.fetch_index("daily", Some(year), Some(quarter)).await {
+                Ok(listing) => listing,
+                Err(EdgarError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            };
+            for item in listing.directory.item {
+                if item.type_ != ItemType::File {
+                    continue;
+                }
+                let Some(date) = daily_index_date(&item.name) else {
+                    continue;
+                };
+                days.entry(date).or_default().push(DailyIndexFile {
+                    url: format!(
+                        "{}/daily-index/{}/QTR{}/{}",
+                        self.edgar_archives_url, year, quarter, item.href
+                    ),
+                    name: item.name,
+                    size: item.size,
+                    last_modified: item.last_modified,
+                });
+            }
+        }
+
+        Ok(days
+            .into_iter()
+            .map(|(date, mut files)| {
+                files.sort_by(|a, b| a.name.cmp(&b.name));
+                IndexDay { date, files }
+            })
+            .collect())
+    }
+
+    /// Retrieves filings for every business day between `start` and `end`, inclusive.
+    ///
+    /// Weekends are skipped without a request, and days without a daily index (holidays, or
+    /// days not yet published) are skipped silently, so the loop never has to treat
+    /// `NotFound` as "probably a weekend". Each quarter's directory listing is fetched once.
+    ///
+    /// Entries are returned in day order with duplicates (same CIK and filing URL) removed.
+    /// `options` are applied to the combined list, so `offset` and `limit` count across the
+    /// whole range.
+    ///
+    /// # Errors
+    /// * `EdgarError::InvalidDay` if either date does not exist or `start` is after `end`
+    /// * `EdgarError::RequestError` for network issues
+    #[tracing::instrument(skip(self, options), fields(entries = tracing::field::Empty))]
+    async fn get_filings_between(
+        &self,
+        start: EdgarDay,
+        end: EdgarDay,
+        options: Option<FilingOptions>,
+    ) -> Result<Vec<IndexEntry>> {
+        let first = start.to_naive_date()?;
+        let last = end.to_naive_date()?;
+        if first > last {
+            return Err(EdgarError::InvalidDay);
+        }
+
+        let mut seen = HashSet::new();
+        let mut entries = Vec::new();
+        for (year, quarter) in quarters_between(first, last)? {
+            let listing = match self.fetch_index("daily", Some(year), Some(quarter)).await {
+                Ok(listing) => listing,
+                Err(EdgarError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            };
+
+            let days = business_days(first, last)
+                .filter(|day| day.year() == year && (day.month0() / 3 + 1) as i32 == quarter);
+            for date in days {
+                let day = EdgarDay::new(date.year(), date.month(), date.day())?;
+                let Some((index_file, config)) =
+                    Self::select_index_file(&listing.directory.item, day.into(), options.as_ref())
+                else {
+                    tracing::debug!("No daily index for {}, skipping", date);
+                    continue;
+                };
+
+                let url = format!(
+                    "{}/daily-index/{}/QTR{}/{}",
+                    self.edgar_archives_url, year, quarter, index_file.href
+                );
+                let daily = self
+                    .download_and_parse_index(&url, &index_file.name, config)
+                    .await?;
+                tracing::debug!("Loaded {} entries for {}", daily.len(), date);
+                entries.extend(
+                    daily
+                        .into_iter()
+                        .filter(|entry| seen.insert((entry.cik, entry.url.clone()))),
+                );
+            }
+        }
+
+        if let Some(opts) = options {
+            entries = self.apply_filters(entries, &opts);
+        }
+
+        tracing::Span::current().record("entries", entries.len());
         Ok(entries)
     }
 
@@ -645,7 +1084,7 @@ mod tests {
                 name: "company.20230815.idx".to_string(),
                 type_: ItemType::File,
                 href: "company.20230815.idx".to_string(),
-                size: "1000".to_string(),
+                size: 1000,
             },
             DirectoryItem {
                 last_modified: NaiveDateTime::parse_from_str(
@@ -656,7 +1095,7 @@ mod tests {
                 name: "company.idx".to_string(),
                 type_: ItemType::File,
                 href: "company.idx".to_string(),
-                size: "2000".to_string(),
+                size: 2000,
             },
         ];
 
@@ -664,17 +1103,54 @@ mod tests {
         let file = Edgar::find_index_file(&items, day, IndexType::Company).unwrap();
         assert_eq!(file.name, "company.20230815.idx");
         assert_eq!(file.href, "company.20230815.idx");
-        assert_eq!(file.size, "1000");
+        assert_eq!(file.size, 1000);
         assert_eq!(file.type_, ItemType::File);
 
         let period = EdgarPeriod::new(2023, Quarter::Q3).unwrap();
         let file = Edgar::find_index_file(&items, period, IndexType::Company).unwrap();
         assert_eq!(file.name, "company.idx");
         assert_eq!(file.href, "company.idx");
-        assert_eq!(file.size, "2000");
+        assert_eq!(file.size, 2000);
         assert_eq!(file.type_, ItemType::File);
     }
 
+    #[test]
+    fn test_daily_index_date() {
+        assert_eq!(
+            daily_index_date("company.20230815.idx"),
+            NaiveDate::from_ymd_opt(2023, 8, 15)
+        );
+        assert_eq!(
+            daily_index_date("form.941003.idx"),
+            NaiveDate::from_ymd_opt(1994, 10, 3)
+        );
+        assert_eq!(daily_index_date("sitemap.quarterlyindex1.xml"), None);
+    }
+
+    #[test]
+    fn test_index_coverage_gaps() {
+        let date = |d| NaiveDate::from_ymd_opt(2023, 8, d).unwrap();
+        // Mon 14 .. Tue 22; Thu 17, Fri 18 and Mon 21 have no index.
+        let indexed: BTreeSet<_> = [14, 15, 16, 22].into_iter().map(date).collect();
+
+        let coverage = IndexCoverage::from_available(date(14), date(22), &indexed);
+        assert_eq!(coverage.available.len(), 4);
+        assert_eq!(coverage.missing, vec![date(17), date(18), date(21)]);
+        assert_eq!(coverage.gaps(), vec![(date(17), date(21))]);
+        assert!(!coverage.is_complete());
+    }
+
+    #[test]
+    fn test_quarters_between() {
+        let d = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            quarters_between(d(2023, 12, 29), d(2024, 4, 2)).unwrap(),
+            vec![(2023, 4), (2024, 1), (2024, 2)]
+        );
+        let days: Vec<_> = business_days(d(2023, 12, 29), d(2024, 1, 2)).collect();
+        assert_eq!(days, vec![d(2023, 12, 29), d(2024, 1, 1), d(2024, 1, 2)]);
+    }
+
     #[test]
     fn test_daily_index_invalid_year() {
         let period = EdgarPeriod::new(1993, Quarter::Q1);
@@ -0,0 +1,138 @@
+//! Coalescing of concurrent identical requests.
+//!
+//! Worker pools tend to ask for the same document at the same moment: every worker loads
+//! `company_tickers.json` on startup, or several tasks look up the same company's
+//! submissions. [`InFlight`] lets the first caller for a URL make the request while later
+//! callers wait for its result, so the burst costs one rate-limit token and one download.
+//!
+//! Nothing is cached. Once the request completes its entry is dropped, and the next caller
+//! for the URL starts a new request. If the caller making the request is cancelled, one of
+//! the waiting callers takes over.
+
+use super::error::Result;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+/// Requests in progress, keyed by URL and the options they are made with.
+pub(crate) struct InFlight<T> {
+    calls: Mutex<HashMap<String, Arc<OnceCell<Result<T>>>>>,
+}
+
+impl<T> fmt::Debug for InFlight<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("InFlight")
+            .field("keys", &calls.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<T> Default for InFlight<T> {
+    fn default() -> Self {
+        Self {
+            calls: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> InFlight<T> {
+    /// Runs `fetch` for `key`, or waits for the call already running for it.
+    ///
+    /// The caller that ran `fetch` gets its error as is; waiting callers get a
+    /// [duplicate](crate::EdgarError::duplicate) of it.
+    pub(crate) async fn run<F, Fut>(&self, key: &str, fetch: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let call = self
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(key.to_string())
+            .or_default()
+            .clone();
+
+        let mut original = None;
+        let slot = &mut original;
+        let shared = call
+            .get_or_init(move || async move {
+                fetch().await.map_err(|error| {
+                    let copy = error.duplicate();
+                    *slot = Some(error);
+                    copy
+                })
+            })
+            .await;
+
+        let mut calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        if calls
+            .get(key)
+            .is_some_and(|current| Arc::ptr_eq(current, &call))
+        {
+            calls.remove(key);
+        }
+        drop(calls);
+
+        match (shared, original) {
+            (_, Some(error)) => Err(error),
+            (Ok(value), None) => Ok(value.clone()),
+            (Err(error), None) => Err(error.duplicate()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EdgarError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_concurrent_calls_share_one_fetch() {
+        let in_flight = InFlight::<String>::default();
+        let fetches = AtomicUsize::new(0);
+        let fetch = || async {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok("body".to_string())
+        };
+
+        let (a, b, c) = tokio::join!(
+            in_flight.run("https://www.sec.gov/a", fetch),
+            in_flight.run("https://www.sec.gov/a", fetch),
+            in_flight.run("https://www.sec.gov/a", fetch),
+        );
+        for result in [a, b, c] {
+            assert_eq!(result.unwrap(), "body");
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(in_flight.calls.lock().unwrap().is_empty());
+
+        in_flight.run("https://www.sec.gov/a", fetch).await.unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_waiting_callers_share_the_error() {
+        let in_flight = InFlight::<String>::default();
+        let fetch = || async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Err(EdgarError::InvalidResponse("bad gateway".to_string()))
+        };
+
+        let (a, b) = tokio::join!(
+            in_flight.run("https://www.sec.gov/a", fetch),
+            in_flight.run("https://www.sec.gov/a", fetch),
+        );
+        for result in [a, b] {
+            assert!(
+                matches!(result, Err(EdgarError::InvalidResponse(message)) if message == "bad gateway")
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Form 8-K item codes.
+//!
+//! A current report lists the events it discloses as item numbers: `2.02` for results of
+//! operations, `5.02` for officer and director changes, and so on. The submissions API
+//! returns them as one comma-separated string per filing (`"2.02,9.01"`), and company feeds
+//! as a free-text description. [`ItemCode`] names the items of the current Form 8-K and
+//! keeps anything else, such as the numeric items of pre-2004 filings, as
+//! [`ItemCode::Other`].
+//!
+//! ```rust
+//! use edgarkit::ItemCode;
+//!
+//! let items = ItemCode::parse_list("2.02, 9.01");
+//! assert_eq!(items, vec![ItemCode::ResultsOfOperations, ItemCode::FinancialStatementsAndExhibits]);
+//! assert_eq!(items[0].as_str(), "2.02");
+//! assert_eq!("7".parse::<ItemCode>().unwrap(), ItemCode::Other("7".to_string()));
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Alias of [`ItemCode`] for Form 8-K items, as used with `FilingOptions::with_items`.
+pub type Item8K = ItemCode;
+
+/// An item reported on Form 8-K.
+///
+/// Also available as [`Item8K`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ItemCode {
+    /// 1.01 Entry into a Material Definitive Agreement
+    EntryIntoMaterialAgreement,
+    /// 1.02 Termination of a Material Definitive Agreement
+    TerminationOfMaterialAgreement,
+    /// 1.03 Bankruptcy or Receivership
+    BankruptcyOrReceivership,
+    /// 1.04 Mine Safety - Reporting of Shutdowns and Patterns of Violations
+    MineSafety,
+    /// 1.05 Material Cybersecurity Incidents
+    CybersecurityIncident,
+    /// 2.01 Completion of Acquisition or Disposition of Assets
+    AcquisitionOrDisposition,
+    /// 2.02 Results of Operations and Financial Condition
+    ResultsOfOperations,
+    /// 2.03 Creation of a Direct Financial Obligation or an Off-Balance Sheet Arrangement
+    DirectFinancialObligation,
+    /// 2.04 Triggering Events That Accelerate or Increase a Direct Financial Obligation
+    TriggeringEvent,
+    /// 2.05 Costs Associated with Exit or Disposal Activities
+    ExitCosts,
+    /// 2.06 Material Impairments
+    MaterialImpairment,
+    /// 3.01 Notice of Delisting or Failure to Satisfy a Continued Listing Rule or Standard
+    DelistingNotice,
+    /// 3.02 Unregistered Sales of Equity Securities
+    UnregisteredEquitySale,
+    /// 3.03 Material Modification to Rights of Security Holders
+    SecurityHolderRightsModification,
+    /// 4.01 Changes in Registrant's Certifying Accountant
+    AccountantChange,
+    /// 4.02 Non-Reliance on Previously Issued Financial Statements
+    NonReliance,
+    /// 5.01 Changes in Control of Registrant
+    ChangeInControl,
+    /// 5.02 Departure or Election of Directors or Officers; Compensatory Arrangements
+    OfficerOrDirectorChange,
+    /// 5.03 Amendments to Articles of Incorporation or Bylaws; Change in Fiscal Year
+    ArticlesOrBylawsAmendment,
+    /// 5.04 Temporary Suspension of Trading Under Registrant's Employee Benefit Plans
+    BenefitPlanTradingSuspension,
+    /// 5.05 Amendment to or Waiver of a Provision of the Code of Ethics
+    CodeOfEthicsChange,
+    /// 5.06 Change in Shell Company Status
+    ShellCompanyStatusChange,
+    /// 5.07 Submission of Matters to a Vote of Security Holders
+    SecurityHolderVote,
+    /// 5.08 Shareholder Director Nominations
+    DirectorNominations,
+    /// 6.01 ABS Informational and Computational Material
+    AbsInformationalMaterial,
+    /// 6.02 Change of Servicer or Trustee
+    ServicerOrTrusteeChange,
+    /// 6.03 Change in Credit Enhancement or Other External Support
+    CreditEnhancementChange,
+    /// 6.04 Failure to Make a Required Distribution
+    FailureToDistribute,
+    /// 6.05 Securities Act Updating Disclosure
+    SecuritiesActUpdate,
+    /// 6.06 Static Pool
+    StaticPool,
+    /// 7.01 Regulation FD Disclosure
+    RegulationFd,
+    /// 8.01 Other Events
+    OtherEvents,
+    /// 9.01 Financial Statements and Exhibits
+    FinancialStatementsAndExhibits,
+    /// Any other code, kept as reported.
+    Other(String),
+}
+
+impl ItemCode {
+    /// Parses a comma-separated list such as `"1.01,2.03"`, skipping empty entries.
+    pub fn parse_list(items: &str) -> Vec<Self> {
+        items
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(Self::from)
+            .collect()
+    }
+
+    /// The item number as EDGAR writes it (`"2.02"`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::EntryIntoMaterialAgreement => "1.01",
+            Self::TerminationOfMaterialAgreement => "1.02",
+            Self::BankruptcyOrReceivership => "1.03",
+            Self::MineSafety => "1.04",
+            Self::CybersecurityIncident => "1.05",
+            Self::AcquisitionOrDisposition => "2.01",
+            Self::ResultsOfOperations => "2.02",
+            Self::DirectFinancialObligation => "2.03",
+            Self::TriggeringEvent => "2.04",
+            Self::ExitCosts => "2.05",
+            Self::MaterialImpairment => "2.06",
+            Self::DelistingNotice => "3.01",
+            Self::UnregisteredEquitySale => "3.02",
+            Self::SecurityHolderRightsModification => "3.03",
+            Self::AccountantChange => "4.01",
+            Self::NonReliance => "4.02",
+            Self::ChangeInControl => "5.01",
+            Self::OfficerOrDirectorChange => "5.02",
+            Self::ArticlesOrBylawsAmendment => "5.03",
+            Self::BenefitPlanTradingSuspension => "5.04",
+            Self::CodeOfEthicsChange => "5.05",
+            Self::ShellCompanyStatusChange => "5.06",
+            Self::SecurityHolderVote => "5.07",
+            Self::DirectorNominations => "5.08",
+            Self::AbsInformationalMaterial => "6.01",
+            Self::ServicerOrTrusteeChange => "6.02",
+            Self::CreditEnhancementChange => "6.03",
+            Self::FailureToDistribute => "6.04",
+            Self::SecuritiesActUpdate => "6.05",
+            Self::StaticPool => "6.06",
+            Self::RegulationFd => "7.01",
+            Self::OtherEvents => "8.01",
+            Self::FinancialStatementsAndExhibits => "9.01",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl From<&str> for ItemCode {
+    fn from(code: &str) -> Self {
+        match code.trim() {
+            "1.01" => Self::EntryIntoMaterialAgreement,
+            "1.02" => Self::TerminationOfMaterialAgreement,
+            "1.03" => Self::BankruptcyOrReceivership,
+            "1.04" => Self::MineSafety,
+            "1.05" => Self::CybersecurityIncident,
+            "2.01" => Self::AcquisitionOrDisposition,
+            "2.02" => Self::ResultsOfOperations,
+            "2.03" => Self::DirectFinancialObligation,
+            "2.04" => Self::TriggeringEvent,
+            "2.05" => Self::ExitCosts,
+            "2.06" => Self::MaterialImpairment,
+            "3.01" => Self::DelistingNotice,
+            "3.02" => Self::UnregisteredEquitySale,
+            "3.03" => Self::SecurityHolderRightsModification,
+            "4.01" => Self::AccountantChange,
+            "4.02" => Self::NonReliance,
+            "5.01" => Self::ChangeInControl,
+            "5.02" => Self::OfficerOrDirectorChange,
+            "5.03" => Self::ArticlesOrBylawsAmendment,
+            "5.04" => Self::BenefitPlanTradingSuspension,
+            "5.05" => Self::CodeOfEthicsChange,
+            "5.06" => Self::ShellCompanyStatusChange,
+            "5.07" => Self::SecurityHolderVote,
+            "5.08" => Self::DirectorNominations,
+            "6.01" => Self::AbsInformationalMaterial,
+            "6.02" => Self::ServicerOrTrusteeChange,
+            "6.03" => Self::CreditEnhancementChange,
+            "6.04" => Self::FailureToDistribute,
+            "6.05" => Self::SecuritiesActUpdate,
+            "6.06" => Self::StaticPool,
+            "7.01" => Self::RegulationFd,
+            "8.01" => Self::OtherEvents,
+            "9.01" => Self::FinancialStatementsAndExhibits,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// Never fails; unknown codes become [`ItemCode::Other`].
+impl FromStr for ItemCode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Infallible> {
+        Ok(Self::from(s))
+    }
+}
+
+impl AsRef<str> for ItemCode {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for ItemCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ItemCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::from(code.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for code in ["1.01", "1.05", "2.02", "5.02", "6.06", "9.01", "12"] {
+            assert_eq!(ItemCode::from(code).as_str(), code);
+        }
+        assert_eq!(
+            ItemCode::parse_list("1.01,,2.03 "),
+            vec![
+                ItemCode::EntryIntoMaterialAgreement,
+                ItemCode::DirectFinancialObligation
+            ]
+        );
+        assert!(ItemCode::parse_list("").is_empty());
+    }
+}
@@ -10,13 +10,70 @@
 //! - **Company information** - Retrieve company facts, tickers, and metadata
 //! - **Search capabilities** - Find filings with customizable search criteria
 //! - **Feed operations** - Access Atom and RSS feeds for filings and news
+//! - **Latest filings stream** - Poll the current filings feed by form type, company, or ownership and get each new filing once
 //! - **Index operations** - Retrieve and parse daily and quarterly filing indices
+//! - **Company and filing handles** - Reach a company's filings and facts, or a filing's documents, by method call
+//! - **Bounded fan-out** - Fetch many URLs, filings, or company facts at a set concurrency with per-item results in input order
+//! - **Backpressure** - Bounded sinks with lag metrics between producers and slow consumers
+//! - **Bulk downloads** - Backfill filings across quarters with bounded concurrency (`bulk` feature)
+//! - **Bulk archives** - Stream `companyfacts.zip` / `submissions.zip` to disk and iterate them (`bulk-data` feature)
+//! - **Financial statement data sets** - Read the SEC's quarterly `sub`/`num`/`tag` files and join values to filings (`datasets` feature)
+//! - **CSV export** - Write filings, index entries, frames, and flattened company facts as CSV (`export` feature)
+//! - **Metrics** - Request, retry, 429, and per-endpoint latency counters, optionally exported via the `metrics` crate
+//! - **Shared rate limits** - One token bucket across clients, or a lock-file limiter across processes (`process-limiter` feature)
+//! - **Blocking client** - Synchronous facade for scripts that don't run an async runtime (`blocking` feature)
+//! - **Offline testing** - Plug in a custom transport, or a fixture-backed mock (`test-util` feature)
+//! - **Local mirrors** - Read from an on-disk copy of sec.gov and follow index updates (`mirror` feature)
+//! - **Pipelines** - Backfill, watch, enrich, and store filings end to end (`pipeline` feature, SQLite sink with `sqlite`)
+//! - **Exactly-once processing** - Persistent seen-accession sets for watchers and backfills (SQLite backend with the `sqlite` feature)
+//! - **Local store** - Keep submissions, filings, index entries, and facts in SQLite and sync only what's new (`store` feature)
+//! - **Financial statements** - Normalized income statements, balance sheets, cash flows, and ratios with provenance from company facts
+//! - **Inline XBRL** - Extract tagged facts with their periods, dimensions, and units straight from primary documents
+//! - **Financial report renderings** - List the `R` pages named in `FilingSummary.xml` and fetch a statement's HTML by name
+//! - **Calculation validation** - Check XBRL totals against calculation linkbase arcs
+//! - **Metadata enrichment** - Join names, tickers, exchanges, and SIC codes onto CIK-keyed records
+//! - **State and country codes** - Name EDGAR location codes and convert them to ISO 3166
+//! - **SIC codes** - Look up industry titles, reviewing offices, and divisions for SIC codes
+//! - **Search queries** - Build full-text queries from phrases, alternatives, exclusions, and prefixes
+//! - **Search facets** - Count matches per form type, filer, SIC code, and state without paging through hits
+//! - **Industry peers** - List the companies filing annual reports under a SIC code (`search` feature)
+//! - **Name resolution** - Rank likely CIKs for a free-text company name
+//! - **Extension concepts** - Find filer-specific XBRL tags and their nearest US-GAAP equivalents
+//! - **Report sections** - Pull risk factors, MD&A, and other items out of 10-K and 10-Q documents
+//! - **Readable documents** - Render filing HTML and legacy text filings as plain text or Markdown
+//! - **Proxy statements** - Fetch the latest DEF 14A and parse its Summary Compensation Table
+//! - **Beneficial ownership** - List Schedule 13D/13G filings and parse reporting persons,
+//!   voting power, and percent owned
+//! - **Exempt offerings** - Parse Form D issuers, exemptions, amounts sold, and investor counts
+//! - **Fund holdings** - Parse N-PORT portfolio reports into fund totals and per-security holdings (`funds` feature)
+//! - **Fund series and classes** - List a fund registrant's series, share classes, and tickers, or look up a class ID (`funds` feature)
+//! - **Lenient parsing** - Opt in to repairing or dropping mismatched values in submissions and
+//!   company facts JSON and get a report of what changed; strict mode, the default, fails instead
+//! - **Flexible company identifiers** - Pass a CIK, ticker, or company name to filing, company, and fund APIs
+//! - **Runtime choice** - Wait on a runtime-independent timer for async-std and smol applications (`async-std` feature)
+//! - **Browser builds** - Compile for `wasm32-unknown-unknown` and query EDGAR from a web page (`wasm` feature)
 //!
 //! ## Requirements
 //!
 //! EdgarKit is an async-first library and requires an async runtime. We recommend
 //! [tokio](https://tokio.rs), which is the most widely used async runtime in the Rust ecosystem.
 //!
+//! With the `async-std` feature the client's own waits (rate limiting, retry backoff, feed
+//! polling) use a timer that needs no tokio runtime, so it can be embedded in async-std or
+//! smol applications. The default reqwest transport still expects a tokio reactor; enter
+//! one (for example with `async-compat`) or plug in a [`Transport`] built on your runtime's
+//! HTTP client. File downloads and the `bulk` and `mirror` features use tokio's file APIs.
+//!
+//! ### Browser builds
+//!
+//! With the `wasm` feature the crate builds for `wasm32-unknown-unknown`, sending requests
+//! through the browser's `fetch` and waiting on `setTimeout`, so it runs under
+//! `wasm-bindgen-futures` without tokio. The default features work there; file downloads
+//! and the `bulk`, `bulk-data`, `mirror`, `sqlite`, and `blocking` features need a
+//! filesystem or threads and are native-only. Browsers do not let pages set `User-Agent`
+//! and enforce CORS on cross-origin requests, so in practice [`EdgarUrls`] points at a proxy
+//! that adds the header and forwards to sec.gov.
+//!
 //! ## Basic Usage
 //!
 //! ```ignore
@@ -32,7 +89,7 @@
 //!         .with_form_type("10-K")
 //!         .with_limit(5);
 //!     
-//!     let filings = edgar.filings("320193", Some(options)).await?;
+//!     let filings = edgar.filings(320193, Some(options)).await?;
 //!     
 //!     for filing in filings {
 //!         println!("Filing: {} on {}", filing.form, filing.filing_date);
@@ -42,10 +99,35 @@
 //! }
 //! ```
 
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("building for wasm32 requires the `wasm` feature");
+
+mod accession;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod cik;
+pub mod compat;
 mod config;
 mod core;
+mod entity;
 mod error;
+mod geography;
+mod inflight;
+mod items;
+mod limiter;
+mod metrics;
 pub mod parsing;
+mod quarter;
+mod rt;
+mod seen;
+mod sic;
+mod sink;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+mod throttle;
+mod transport;
 
 // Conditionally include modules
 #[cfg(any(feature = "filings", feature = "index", feature = "feeds"))]
@@ -62,38 +144,164 @@ mod traits;
 
 // Public modules
 #[cfg(feature = "company")]
+mod bootstrap;
+#[cfg(feature = "bulk")]
+mod bulk;
+#[cfg(feature = "bulk-data")]
+mod bulk_data;
+#[cfg(feature = "company")]
+mod calculation;
+#[cfg(feature = "company")]
 mod company;
+#[cfg(feature = "datasets")]
+mod datasets;
+#[cfg(feature = "company")]
+mod enrich;
+#[cfg(feature = "filings")]
+mod events;
+#[cfg(feature = "export")]
+mod export;
+#[cfg(feature = "company")]
+mod extensions;
 #[cfg(feature = "feeds")]
 mod feeds;
 #[cfg(feature = "filings")]
 mod filings;
+#[cfg(feature = "company")]
+mod financials;
+#[cfg(feature = "funds")]
+mod funds;
+#[cfg(feature = "filings")]
+mod handles;
 #[cfg(feature = "index")]
 mod index;
+#[cfg(feature = "mirror")]
+mod mirror;
+#[cfg(feature = "company")]
+mod names;
+#[cfg(feature = "pipeline")]
+mod pipeline;
+#[cfg(feature = "feeds")]
+mod polling;
+#[cfg(feature = "search")]
+mod query;
+#[cfg(feature = "company")]
+mod ratios;
 #[cfg(feature = "search")]
 mod search;
+#[cfg(feature = "store")]
+mod store;
+#[cfg(feature = "company")]
+mod symbols;
 
 // Core Edgar functionality (always available)
-pub use config::{EdgarConfig, EdgarUrls};
+pub use accession::AccessionNumber;
+pub use cik::Cik;
+pub use config::{
+    DEFAULT_RETRY_STATUSES, EdgarConfig, EdgarUrls, PreviewPolicy, ProxyConfig, Redactor,
+    RedirectPolicy, RequestOptions, RetryPolicy, ThrottlePolicy,
+};
 pub use core::Edgar;
-pub use error::{EdgarError, Result};
+pub use entity::EntityId;
+pub use error::{EdgarError, ErrorContext, Result};
+pub use geography::{Location, StateOrCountry};
+pub use items::{Item8K, ItemCode};
+#[cfg(feature = "process-limiter")]
+pub use limiter::ProcessLimiter;
+pub use limiter::{Governor, shared_limiter};
+#[cfg(feature = "metrics")]
+pub use metrics::MetricsExporter;
+pub use metrics::{
+    EndpointMetrics, LATENCY_BUCKETS_MS, LatencyHistogram, Metrics, MetricsRegistry,
+    MetricsSnapshot, endpoint_of,
+};
+pub use parsing::json::{ParseIssue, ParseMode, ParseReport, Repair};
+pub use parsing::utils::{format_human, parse_edgar_date, parse_human_size};
+pub use quarter::Quarter;
+pub use seen::SeenSet;
+pub use sic::{IndustryGroup, SicCode, SicOffice};
+pub use sink::{Sink, SinkMetrics, SinkReceiver, SinkSender, bounded_sink};
+pub use transport::{Transport, TransportResponse};
 
 // Conditionally export options
-#[cfg(feature = "feeds")]
-pub use options::FeedOptions;
 #[cfg(any(feature = "filings", feature = "index"))]
 pub use options::FilingOptions;
+#[cfg(feature = "feeds")]
+pub use options::{CurrentFeedOptions, FEED_COUNTS, FeedOptions, OwnerFilter};
 
 // Re-export core types and traits for a clean API
 #[cfg(feature = "company")]
+pub use bootstrap::Bootstrap;
+#[cfg(feature = "bulk")]
+pub use bulk::{BulkDownloader, BulkFiling, BulkSummary};
+#[cfg(feature = "bulk-data")]
+pub use bulk_data::{BulkArchive, BulkArchiveReader};
+#[cfg(feature = "company")]
+pub use calculation::{CalculationArc, CalculationInconsistency, CalculationLinkbase};
+#[cfg(feature = "company")]
 pub use company::{
-    CompanyConcept, CompanyFacts, CompanyTicker, CompanyTickerExchange, Frame, MutualFundTicker,
+    CompanyConcept, CompanyFacts, CompanyTicker, CompanyTickerExchange, FactRecord, FactValue,
+    Frame, FramePanel, FramePeriod, MutualFundTicker, PeriodKind, TaxonomyGroups,
 };
+#[cfg(feature = "datasets")]
+pub use datasets::{DatasetArchive, DatasetFiling, NumRecord, SubRecord, TagRecord};
+#[cfg(all(
+    feature = "company",
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+pub use enrich::EnrichStream;
+#[cfg(feature = "company")]
+pub use enrich::{CikRecord, CompanyMetadata, Enriched, Enricher};
 #[cfg(feature = "filings")]
-pub use filings::{DetailedFiling, Directory, DirectoryItem, DirectoryResponse, Submission};
+pub use events::{CompanySnapshot, CorporateEvent, CorporateEventKind, CorporateHistory};
+#[cfg(feature = "export")]
+pub use export::ToCsv;
+#[cfg(feature = "company")]
+pub use extensions::{ConceptKind, ConceptMatch, ExtensionConcept};
+#[cfg(all(feature = "feeds", feature = "filings"))]
+pub use feeds::RecentFiling;
+#[cfg(feature = "feeds")]
+pub use feeds::{CompanyUpdate, FeedItem, FeedKind, NewsItem};
+#[cfg(feature = "filings")]
+pub use filings::{
+    AmendmentChain, DetailedFiling, Directory, DirectoryItem, DirectoryResponse, FILING_MANIFEST,
+    FilingFile, FilingManifest, ManifestFile, ProxyStatement, RecentFilings, Submission,
+};
+#[cfg(feature = "company")]
+pub use financials::{
+    BalanceSheet, CashFlowStatement, Financials, IncomeStatement, LineItemSource, StatementPeriod,
+};
+#[cfg(feature = "funds")]
+pub use funds::{FundClass, FundSeries};
+#[cfg(feature = "filings")]
+pub use handles::{Company, Filing};
 #[cfg(feature = "index")]
-pub use index::{EdgarDay, EdgarPeriod, IndexResponse, Quarter};
+pub use index::{
+    DailyIndexFile, EdgarDay, EdgarPeriod, IndexCoverage, IndexDay, IndexResponse, IndexScope,
+};
+#[cfg(feature = "mirror")]
+pub use mirror::{Mirror, MirrorEvent, MirrorWatcher};
+#[cfg(feature = "company")]
+pub use names::{CikCandidate, NameMatcher, normalize_company_name};
+#[cfg(all(feature = "pipeline", feature = "sqlite"))]
+pub use pipeline::SqliteSink;
+#[cfg(feature = "pipeline")]
+pub use pipeline::{FilingRecord, Pipeline, PipelineSummary};
+#[cfg(feature = "feeds")]
+pub use polling::{PollBudget, PollSchedule};
 #[cfg(feature = "search")]
-pub use search::{Hit, Hits, SearchOptions, SearchResponse, TotalHits};
+pub use query::Query;
+#[cfg(feature = "company")]
+pub use ratios::{Metric, Ratios};
+#[cfg(feature = "search")]
+pub use search::{
+    Aggregation, Aggregations, Bucket, EntityMatch, Hit, Hits, SearchOptions, SearchResponse,
+    TotalHits,
+};
+#[cfg(feature = "store")]
+pub use store::Store;
+#[cfg(feature = "company")]
+pub use symbols::{SecurityKind, Symbol, SymbolConventions, normalize_ticker};
 
 // Conditionally export traits
 #[cfg(feature = "company")]
@@ -102,6 +310,8 @@ pub use traits::CompanyOperations;
 pub use traits::FeedOperations;
 #[cfg(feature = "filings")]
 pub use traits::FilingOperations;
+#[cfg(feature = "funds")]
+pub use traits::FundOperations;
 #[cfg(feature = "index")]
 pub use traits::IndexOperations;
 #[cfg(feature = "search")]
@@ -0,0 +1,178 @@
+//! Rate limiters shared between clients and processes.
+//!
+//! SEC.gov enforces its fair-access limit per IP address, not per client. Clones of an
+//! [`Edgar`](crate::Edgar) already share one token bucket, but independently constructed
+//! clients (one per worker, or one per configuration) each get their own and together can
+//! exceed the limit. Build one bucket with [`shared_limiter`] and hand it to every client with
+//! [`Edgar::with_shared_limiter`](crate::Edgar::with_shared_limiter) to coordinate them.
+//!
+//! Workers in separate processes can coordinate through a [`ProcessLimiter`] (`process-limiter`
+//! feature), which keeps a sliding window of recent request times in a lock file that every
+//! process on the machine consults before each request.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgarkit::{Edgar, EdgarConfig, shared_limiter};
+//!
+//! let limiter = shared_limiter(10)?;
+//! let facts_client = Edgar::new("my_app/1.0 (my@email.com)")?.with_shared_limiter(limiter.clone());
+//! let strict_client = Edgar::with_config(EdgarConfig::default().with_ticker_resolution(false))?
+//!     .with_shared_limiter(limiter);
+//! # Ok::<(), edgarkit::EdgarError>(())
+//! ```
+
+use super::error::{EdgarError, Result};
+use governor::{
+    Quota, RateLimiter, clock::DefaultClock, middleware::NoOpMiddleware, state::InMemoryState,
+    state::NotKeyed,
+};
+use std::num::NonZeroU32;
+use std::sync::Arc;
+
+/// The in-process token bucket used by [`Edgar`](crate::Edgar).
+pub type Governor = RateLimiter<NotKeyed, InMemoryState, DefaultClock, NoOpMiddleware>;
+
+/// Creates a token bucket allowing `requests_per_second`, for sharing between clients.
+///
+/// # Errors
+///
+/// Returns `EdgarError::ConfigError` if `requests_per_second` is zero.
+pub fn shared_limiter(requests_per_second: u32) -> Result<Arc<Governor>> {
+    let rate = NonZeroU32::new(requests_per_second).ok_or_else(|| {
+        EdgarError::ConfigError("Rate limit must be greater than zero".to_string())
+    })?;
+    Ok(Arc::new(RateLimiter::direct(Quota::per_second(rate))))
+}
+
+#[cfg(feature = "process-limiter")]
+pub use process::ProcessLimiter;
+
+#[cfg(feature = "process-limiter")]
+mod process {
+    use super::super::error::{EdgarError, Result};
+    use std::fs::{File, OpenOptions};
+    use std::io::{Read, Seek, SeekFrom, Write};
+    use std::path::{Path, PathBuf};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    /// Length of the window the request rate is measured over.
+    const WINDOW: Duration = Duration::from_secs(1);
+
+    /// A rate limiter shared by every process that opens the same lock file.
+    ///
+    /// The file holds the send times of the last `requests_per_second` requests. A request may
+    /// go out once the oldest of them is more than a second old; the file is locked while it
+    /// is read and updated, so concurrent processes never both take the last slot. The file is
+    /// created on first use and can live anywhere the workers can all write, such as
+    /// `/tmp/edgarkit.lock`.
+    ///
+    /// Timestamps come from the system clock, so all processes must run on the same machine.
+    #[derive(Debug, Clone)]
+    pub struct ProcessLimiter {
+        path: PathBuf,
+        requests_per_second: usize,
+    }
+
+    impl ProcessLimiter {
+        /// # Errors
+        ///
+        /// Returns `EdgarError::ConfigError` if `requests_per_second` is zero.
+        pub fn new(path: impl Into<PathBuf>, requests_per_second: u32) -> Result<Self> {
+            if requests_per_second == 0 {
+                return Err(EdgarError::ConfigError(
+                    "Rate limit must be greater than zero".to_string(),
+                ));
+            }
+            Ok(Self {
+                path: path.into(),
+                requests_per_second: requests_per_second as usize,
+            })
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+
+        /// Waits until this process may send a request, then records it.
+        pub async fn until_ready(&self) -> Result<()> {
+            loop {
+                let limiter = self.clone();
+                let wait = tokio::task::spawn_blocking(move || limiter.try_acquire())
+                    .await
+                    .map_err(|e| EdgarError::FileError(std::io::Error::other(e)))??;
+                match wait {
+                    None => return Ok(()),
+                    Some(wait) => tokio::time::sleep(wait).await,
+                }
+            }
+        }
+
+        /// Takes a slot if one is free, or returns how long until the oldest one frees up.
+        fn try_acquire(&self) -> Result<Option<Duration>> {
+            let mut file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&self.path)?;
+            file.lock()?;
+            let result = self.update(&mut file);
+            file.unlock()?;
+            result
+        }
+
+        fn update(&self, file: &mut File) -> Result<Option<Duration>> {
+            let now = now_millis();
+            let window = WINDOW.as_millis() as u64;
+
+            let mut content = String::new();
+            file.read_to_string(&mut content)?;
+            let mut sent: Vec<u64> = content
+                .lines()
+                .filter_map(|line| line.trim().parse().ok())
+                .filter(|&at: &u64| at + window > now)
+                .collect();
+            sent.sort_unstable();
+
+            if sent.len() >= self.requests_per_second {
+                let oldest = sent[sent.len() - self.requests_per_second];
+                return Ok(Some(Duration::from_millis(oldest + window - now)));
+            }
+
+            sent.push(now);
+            let content: String = sent.iter().map(|at| format!("{}\n", at)).collect();
+            file.seek(SeekFrom::Start(0))?;
+            file.set_len(0)?;
+            file.write_all(content.as_bytes())?;
+            Ok(None)
+        }
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_window_fills_up() {
+            let path =
+                std::env::temp_dir().join(format!("edgarkit-limiter-{}.lock", std::process::id()));
+            let _ = std::fs::remove_file(&path);
+            let first = ProcessLimiter::new(&path, 2).unwrap();
+            let second = ProcessLimiter::new(&path, 2).unwrap();
+
+            assert_eq!(first.try_acquire().unwrap(), None);
+            assert_eq!(second.try_acquire().unwrap(), None);
+            let wait = first.try_acquire().unwrap().unwrap();
+            assert!(wait <= WINDOW);
+            std::fs::remove_file(&path).unwrap();
+        }
+    }
+}
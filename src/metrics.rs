@@ -0,0 +1,319 @@
+//! Request metrics and instrumentation hooks.
+//!
+//! A [`Metrics`] implementation set with
+//! [`EdgarConfig::with_metrics`](crate::EdgarConfig::with_metrics) is called as the client
+//! sends requests, receives responses, backs off, and reads bodies. Every callback receives
+//! the endpoint the URL belongs to (see [`endpoint_of`]), so latency and error rates can be
+//! broken down by API.
+//!
+//! [`MetricsRegistry`] keeps counters and per-endpoint latency histograms in memory and is
+//! enough for logging or a status page. With the `metrics` feature, [`MetricsExporter`]
+//! forwards the same events to the [`metrics`](https://docs.rs/metrics) facade for Prometheus
+//! and similar backends.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgarkit::{Edgar, EdgarConfig, MetricsRegistry};
+//! use std::sync::Arc;
+//!
+//! let registry = Arc::new(MetricsRegistry::new());
+//! let edgar = Edgar::with_config(EdgarConfig::default().with_metrics(registry.clone()))?;
+//!
+//! // ... run requests ...
+//! let snapshot = registry.snapshot();
+//! println!("{} requests, {} rate limited", snapshot.requests, snapshot.rate_limited);
+//! # Ok::<(), edgarkit::EdgarError>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Upper bounds of the latency histogram buckets, in milliseconds.
+pub const LATENCY_BUCKETS_MS: [u64; 8] = [50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+/// Receives request events from the client.
+///
+/// All methods default to doing nothing, so implementations only override what they track.
+/// Callbacks run inline on the request path and should return quickly.
+pub trait Metrics: fmt::Debug + Send + Sync {
+    /// A request is about to be sent, after rate limiting. Retries are sent again.
+    fn request_sent(&self, _endpoint: &'static str) {}
+
+    /// Response headers arrived `latency` after the request was sent.
+    fn response_received(&self, _endpoint: &'static str, _status: u16, _latency: Duration) {}
+
+    /// The server answered 429 Too Many Requests.
+    fn rate_limited(&self, _endpoint: &'static str) {}
+
+    /// The request will be retried after a failure; `status` is `None` for network errors.
+    fn retry_scheduled(&self, _endpoint: &'static str, _status: Option<u16>) {}
+
+    /// A response body of `bytes` bytes was read.
+    fn bytes_downloaded(&self, _endpoint: &'static str, _bytes: u64) {}
+}
+
+/// Names the EDGAR API a URL belongs to, used as the endpoint label in [`Metrics`] callbacks.
+///
+/// One of `search`, `submissions`, `companyfacts`, `companyconcept`, `frames`, `index`,
+/// `archives`, `feeds`, `files`, or `other`. Only the path is inspected, so custom base URLs
+/// and mirrors are classified the same way as sec.gov.
+pub fn endpoint_of(url: &str) -> &'static str {
+    let path = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split_once('/')
+        .map_or("", |(_, path)| path);
+
+    if url.contains("efts.sec.gov") || path.starts_with("LATEST/search-index") {
+        "search"
+    } else if path.starts_with("submissions/") {
+        "submissions"
+    } else if path.starts_with("api/xbrl/companyfacts/") {
+        "companyfacts"
+    } else if path.starts_with("api/xbrl/companyconcept/") {
+        "companyconcept"
+    } else if path.starts_with("api/xbrl/frames/") {
+        "frames"
+    } else if path.starts_with("Archives/edgar/daily-index")
+        || path.starts_with("Archives/edgar/full-index")
+    {
+        "index"
+    } else if path.starts_with("Archives/") {
+        "archives"
+    } else if path.starts_with("cgi-bin/browse-edgar") || path.ends_with(".rss") {
+        "feeds"
+    } else if path.starts_with("files/") {
+        "files"
+    } else {
+        "other"
+    }
+}
+
+/// Latency distribution over [`LATENCY_BUCKETS_MS`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    /// Responses per bucket; the last entry counts responses slower than every bound.
+    pub counts: [u64; LATENCY_BUCKETS_MS.len() + 1],
+
+    /// Sum of all recorded latencies.
+    pub total: Duration,
+}
+
+impl LatencyHistogram {
+    pub fn record(&mut self, latency: Duration) {
+        let millis = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|&bound| millis <= bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.counts[bucket] += 1;
+        self.total += latency;
+    }
+
+    /// Number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Mean latency, or `None` if nothing was recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = self.count();
+        (count > 0).then(|| self.total / count as u32)
+    }
+}
+
+/// Counters for one endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointMetrics {
+    pub requests: u64,
+    pub retries: u64,
+    pub rate_limited: u64,
+    pub bytes: u64,
+
+    /// Responses by HTTP status.
+    pub statuses: BTreeMap<u16, u64>,
+
+    pub latency: LatencyHistogram,
+}
+
+/// Point-in-time totals from a [`MetricsRegistry`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    /// Requests sent, including retries.
+    pub requests: u64,
+
+    /// Retries scheduled after failures.
+    pub retries: u64,
+
+    /// 429 responses received.
+    pub rate_limited: u64,
+
+    /// Response body bytes read.
+    pub bytes: u64,
+
+    pub endpoints: BTreeMap<&'static str, EndpointMetrics>,
+}
+
+/// In-memory [`Metrics`] implementation.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    endpoints: Mutex<BTreeMap<&'static str, EndpointMetrics>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Totals across endpoints along with the per-endpoint breakdown.
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let endpoints = self.endpoints.lock().unwrap().clone();
+        let mut snapshot = MetricsSnapshot::default();
+        for metrics in endpoints.values() {
+            snapshot.requests += metrics.requests;
+            snapshot.retries += metrics.retries;
+            snapshot.rate_limited += metrics.rate_limited;
+            snapshot.bytes += metrics.bytes;
+        }
+        snapshot.endpoints = endpoints;
+        snapshot
+    }
+
+    /// Clears all counters.
+    pub fn reset(&self) {
+        self.endpoints.lock().unwrap().clear();
+    }
+
+    fn update(&self, endpoint: &'static str, f: impl FnOnce(&mut EndpointMetrics)) {
+        f(self.endpoints.lock().unwrap().entry(endpoint).or_default());
+    }
+}
+
+impl Metrics for MetricsRegistry {
+    fn request_sent(&self, endpoint: &'static str) {
+        self.update(endpoint, |m| m.requests += 1);
+    }
+
+    fn response_received(&self, endpoint: &'static str, status: u16, latency: Duration) {
+        self.update(endpoint, |m| {
+            *m.statuses.entry(status).or_default() += 1;
+            m.latency.record(latency);
+        });
+    }
+
+    fn rate_limited(&self, endpoint: &'static str) {
+        self.update(endpoint, |m| m.rate_limited += 1);
+    }
+
+    fn retry_scheduled(&self, endpoint: &'static str, _status: Option<u16>) {
+        self.update(endpoint, |m| m.retries += 1);
+    }
+
+    fn bytes_downloaded(&self, endpoint: &'static str, bytes: u64) {
+        self.update(endpoint, |m| m.bytes += bytes);
+    }
+}
+
+/// Forwards request events to the `metrics` crate facade.
+///
+/// Records the counters `edgarkit_requests_total`, `edgarkit_responses_total` (with a
+/// `status` label), `edgarkit_rate_limited_total`, `edgarkit_retries_total`, and
+/// `edgarkit_bytes_total`, and the histogram `edgarkit_request_duration_seconds`, all labeled
+/// with `endpoint`. Install a recorder such as `metrics-exporter-prometheus` to collect them.
+#[cfg(feature = "metrics")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsExporter;
+
+#[cfg(feature = "metrics")]
+impl Metrics for MetricsExporter {
+    fn request_sent(&self, endpoint: &'static str) {
+        ::metrics::counter!("edgarkit_requests_total", "endpoint" => endpoint).increment(1);
+    }
+
+    fn response_received(&self, endpoint: &'static str, status: u16, latency: Duration) {
+        ::metrics::counter!(
+            "edgarkit_responses_total",
+            "endpoint" => endpoint,
+            "status" => status.to_string()
+        )
+        .increment(1);
+        ::metrics::histogram!("edgarkit_request_duration_seconds", "endpoint" => endpoint)
+            .record(latency.as_secs_f64());
+    }
+
+    fn rate_limited(&self, endpoint: &'static str) {
+        ::metrics::counter!("edgarkit_rate_limited_total", "endpoint" => endpoint).increment(1);
+    }
+
+    fn retry_scheduled(&self, endpoint: &'static str, _status: Option<u16>) {
+        ::metrics::counter!("edgarkit_retries_total", "endpoint" => endpoint).increment(1);
+    }
+
+    fn bytes_downloaded(&self, endpoint: &'static str, bytes: u64) {
+        ::metrics::counter!("edgarkit_bytes_total", "endpoint" => endpoint).increment(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_classification() {
+        let cases = [
+            (
+                "https://efts.sec.gov/LATEST/search-index?q=merger",
+                "search",
+            ),
+            (
+                "https://data.sec.gov/submissions/CIK0000320193.json",
+                "submissions",
+            ),
+            (
+                "https://data.sec.gov/api/xbrl/frames/us-gaap/Assets/USD/CY2019Q1I.json",
+                "frames",
+            ),
+            (
+                "https://www.sec.gov/Archives/edgar/daily-index/2023/QTR3/",
+                "index",
+            ),
+            (
+                "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl.htm",
+                "archives",
+            ),
+            (
+                "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent",
+                "feeds",
+            ),
+            ("https://www.sec.gov/files/company_tickers.json", "files"),
+        ];
+        for (url, endpoint) in cases {
+            assert_eq!(endpoint_of(url), endpoint, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_registry_snapshot() {
+        let registry = MetricsRegistry::new();
+        registry.request_sent("submissions");
+        registry.response_received("submissions", 429, Duration::from_millis(80));
+        registry.rate_limited("submissions");
+        registry.retry_scheduled("submissions", Some(429));
+        registry.request_sent("submissions");
+        registry.response_received("submissions", 200, Duration::from_millis(20_000));
+        registry.bytes_downloaded("submissions", 1_024);
+
+        let snapshot = registry.snapshot();
+        assert_eq!(snapshot.requests, 2);
+        assert_eq!(snapshot.retries, 1);
+        assert_eq!(snapshot.rate_limited, 1);
+        assert_eq!(snapshot.bytes, 1_024);
+        let latency = &snapshot.endpoints["submissions"].latency;
+        assert_eq!(latency.counts[1], 1);
+        assert_eq!(latency.counts[LATENCY_BUCKETS_MS.len()], 1);
+        assert_eq!(latency.mean(), Some(Duration::from_millis(10_040)));
+    }
+}
@@ -0,0 +1,358 @@
+//! Offline access to a local EDGAR mirror.
+//!
+//! Teams that already mirror EDGAR with external tools (`wget --mirror`, `rsync`, or an
+//! in-house crawler) can point the client at that directory with
+//! [`EdgarConfig::with_mirror`](crate::EdgarConfig::with_mirror). Every request is then
+//! answered from disk instead of HTTP, using the same path scheme as sec.gov with the host
+//! as the first directory:
+//!
+//! ```text
+//! <root>/www.sec.gov/Archives/edgar/daily-index/2023/QTR3/company.20230815.idx
+//! <root>/data.sec.gov/submissions/CIK0000320193.json
+//! <root>/www.sec.gov/files/company_tickers.json
+//! ```
+//!
+//! Directory listings (`index.json`) that the mirror tool did not save are generated from the
+//! directory contents, so the index and filing-folder APIs work against a plain file tree.
+//! URLs with a query string (full-text search, browse-edgar feeds) cannot be mirrored and
+//! fail with `EdgarError::NotFound`.
+//!
+//! [`MirrorWatcher`] follows the mirror as the external tool updates it and re-parses index
+//! files that change, which makes the mirror usable as a live, fully offline feed.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, EdgarConfig, MirrorEvent};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = EdgarConfig::default().with_mirror("/data/edgar");
+//! let edgar = Edgar::with_config(config)?;
+//!
+//! let mut watcher = edgar.watch_mirror()?;
+//! while let Some(event) = watcher.next().await {
+//!     if let MirrorEvent::Index { path, entries } = event? {
+//!         println!("{}: {} filings", path.display(), entries.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Edgar;
+use super::error::{EdgarError, ErrorContext, Result};
+use crate::parsing::index::{IndexConfig, IndexEntry, IndexParser};
+use chrono::{DateTime, Local};
+use flate2::read::GzDecoder;
+use notify::event::ModifyKind;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde_json::json;
+use std::collections::VecDeque;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// How long the watcher waits for a burst of writes to settle before reporting it.
+const DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Timestamp format of SEC `index.json` listings.
+const LISTING_DATE_FORMAT: &str = "%m/%d/%Y %I:%M:%S %p";
+
+/// A local directory laid out like sec.gov.
+#[derive(Debug, Clone)]
+pub struct Mirror {
+    root: PathBuf,
+}
+
+impl Mirror {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Maps a URL to its location in the mirror (`<root>/<host>/<path>`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::NotFound` for URLs with a query string and `InvalidFormat` for
+    /// URLs that cannot be parsed or would escape the mirror root.
+    pub fn path_for(&self, url: &str) -> Result<PathBuf> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| EdgarError::InvalidFormat(format!("{}: {}", url, e)))?;
+        if parsed.query().is_some() {
+            return Err(EdgarError::not_found(ErrorContext::for_url(url, None)));
+        }
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| EdgarError::InvalidFormat(format!("{} has no host", url)))?;
+
+        let mut path = self.root.join(host);
+        for segment in Path::new(parsed.path().trim_start_matches('/')).components() {
+            match segment {
+                Component::Normal(part) => path.push(part),
+                Component::CurDir => {}
+                _ => {
+                    return Err(EdgarError::InvalidFormat(format!(
+                        "{} points outside the mirror",
+                        url
+                    )));
+                }
+            }
+        }
+        Ok(path)
+    }
+
+    /// Reads the file for `url`, generating `index.json` listings when they are missing.
+    pub(crate) async fn read(&self, url: &str) -> Result<Vec<u8>> {
+        let path = self.path_for(url)?;
+        tokio::task::spawn_blocking(move || read_path(&path))
+            .await
+            .map_err(|e| EdgarError::FileError(std::io::Error::other(e)))?
+            .map_err(|e| match e {
+                EdgarError::NotFound(_) => EdgarError::not_found(ErrorContext::for_url(url, None)),
+                e => e,
+            })
+    }
+}
+
+fn read_path(path: &Path) -> Result<Vec<u8>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(bytes),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => match path.parent() {
+            Some(dir) if path.file_name() == Some("index.json".as_ref()) && dir.is_dir() => {
+                directory_listing(dir)
+            }
+            _ => Err(EdgarError::not_found(ErrorContext::default())),
+        },
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Builds an `index.json` listing for a mirror directory.
+///
+/// Timestamps use the index listing format and sizes are plain byte counts, which both the
+/// index and filing-folder listing types accept.
+fn directory_listing(dir: &Path) -> Result<Vec<u8>> {
+    let mut items = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let modified: DateTime<Local> = metadata.modified()?.into();
+        let (kind, href) = if metadata.is_dir() {
+            ("dir", format!("{}/", name))
+        } else {
+            ("file", name.clone())
+        };
+        items.push(json!({
+            "last-modified": modified.format(LISTING_DATE_FORMAT).to_string(),
+            "name": name,
+            "type": kind,
+            "href": href,
+            "size": if metadata.is_dir() { 0 } else { metadata.len() },
+        }));
+    }
+    items.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let name = dir
+        .file_name()
+        .map(|name| format!("{}/", name.to_string_lossy()))
+        .unwrap_or_default();
+    let listing = json!({
+        "directory": {
+            "item": items,
+            "name": name,
+            "parent-dir": "../",
+        }
+    });
+    Ok(serde_json::to_vec(&listing)?)
+}
+
+/// A change in the mirror reported by [`MirrorWatcher`].
+#[derive(Debug, Clone)]
+pub enum MirrorEvent {
+    /// An index file (`*.idx`, or gzipped `*.gz`, under `daily-index` or `full-index`) was
+    /// created or rewritten, and was parsed again.
+    Index {
+        path: PathBuf,
+        entries: Vec<IndexEntry>,
+    },
+
+    /// Any other file was created or rewritten.
+    File { path: PathBuf },
+}
+
+/// Follows changes to a mirror directory.
+///
+/// Create one with [`Edgar::watch_mirror`]. Changes are debounced briefly and deduplicated,
+/// so a file written in several chunks is reported once. Removals are not reported.
+pub struct MirrorWatcher {
+    _watcher: RecommendedWatcher,
+    changes: mpsc::UnboundedReceiver<PathBuf>,
+    pending: VecDeque<PathBuf>,
+}
+
+impl MirrorWatcher {
+    /// Starts watching `mirror` recursively.
+    pub fn new(mirror: &Mirror) -> Result<Self> {
+        let (tx, changes) = mpsc::unbounded_channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<_>| {
+            let event: notify::Event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    tracing::warn!("Mirror watch error: {}", e);
+                    return;
+                }
+            };
+            let written = matches!(
+                event.kind,
+                EventKind::Create(_)
+                    | EventKind::Modify(
+                        ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any
+                    )
+            );
+            if written {
+                for path in event.paths {
+                    // The receiver is gone once the watcher is dropped.
+                    let _ = tx.send(path);
+                }
+            }
+        })
+        .map_err(watch_error)?;
+        watcher
+            .watch(mirror.root(), RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Waits for the next change. Returns `None` if the watcher stopped.
+    ///
+    /// Index files are parsed before they are reported; a parse failure is returned as an
+    /// error and the watcher keeps running.
+    pub async fn next(&mut self) -> Option<Result<MirrorEvent>> {
+        loop {
+            if let Some(path) = self.pending.pop_front() {
+                if !path.is_file() {
+                    continue;
+                }
+                return Some(event_for(path).await);
+            }
+
+            let first = self.changes.recv().await?;
+            self.pending.push_back(first);
+            tokio::time::sleep(DEBOUNCE).await;
+            while let Ok(path) = self.changes.try_recv() {
+                if !self.pending.contains(&path) {
+                    self.pending.push_back(path);
+                }
+            }
+        }
+    }
+}
+
+fn watch_error(e: notify::Error) -> EdgarError {
+    EdgarError::ConfigError(format!("Failed to watch mirror: {}", e))
+}
+
+/// Returns true for quarterly and daily index files.
+fn is_index_file(path: &Path) -> bool {
+    let in_index_dir = path.components().any(
+        |c| matches!(c, Component::Normal(part) if part == "daily-index" || part == "full-index"),
+    );
+    let is_index = matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("idx" | "gz")
+    );
+    in_index_dir && is_index
+}
+
+async fn event_for(path: PathBuf) -> Result<MirrorEvent> {
+    if !is_index_file(&path) {
+        return Ok(MirrorEvent::File { path });
+    }
+    tokio::task::spawn_blocking(move || {
+        let bytes = std::fs::read(&path)?;
+        let entries = parse_index(&path, &bytes)?;
+        Ok(MirrorEvent::Index { path, entries })
+    })
+    .await
+    .map_err(|e| EdgarError::FileError(std::io::Error::other(e)))?
+}
+
+fn parse_index(path: &Path, bytes: &[u8]) -> Result<Vec<IndexEntry>> {
+    let parser = IndexParser::new(IndexConfig::default());
+    if path.extension().is_some_and(|ext| ext == "gz") {
+        let mut content = Vec::new();
+        GzDecoder::new(bytes).read_to_end(&mut content)?;
+        parser.parse(content.as_slice())
+    } else {
+        parser.parse(bytes)
+    }
+}
+
+impl Edgar {
+    /// The mirror this client reads from, if one is configured.
+    pub fn mirror(&self) -> Option<&Mirror> {
+        self.mirror.as_deref()
+    }
+
+    /// Starts watching the configured mirror for changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::ConfigError` if no mirror is configured or it cannot be watched.
+    pub fn watch_mirror(&self) -> Result<MirrorWatcher> {
+        let mirror = self
+            .mirror()
+            .ok_or_else(|| EdgarError::ConfigError("No mirror configured".to_string()))?;
+        MirrorWatcher::new(mirror)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_for() {
+        let mirror = Mirror::new("/data/edgar");
+        assert_eq!(
+            mirror
+                .path_for("https://www.sec.gov/Archives/edgar/daily-index/2023/QTR3/index.json")
+                .unwrap(),
+            Path::new("/data/edgar/www.sec.gov/Archives/edgar/daily-index/2023/QTR3/index.json")
+        );
+        assert_eq!(
+            mirror
+                .path_for("https://data.sec.gov/submissions/CIK0000320193.json")
+                .unwrap(),
+            Path::new("/data/edgar/data.sec.gov/submissions/CIK0000320193.json")
+        );
+        assert!(matches!(
+            mirror.path_for("https://efts.sec.gov/LATEST/search-index/?q=x"),
+            Err(EdgarError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_index_file() {
+        assert!(is_index_file(Path::new(
+            "/m/www.sec.gov/Archives/edgar/daily-index/2023/QTR3/company.20230815.idx"
+        )));
+        assert!(is_index_file(Path::new(
+            "/m/www.sec.gov/Archives/edgar/full-index/2023/QTR3/form.gz"
+        )));
+        assert!(!is_index_file(Path::new(
+            "/m/www.sec.gov/Archives/edgar/data/320193/0000320193-24-000123.txt"
+        )));
+    }
+}
@@ -0,0 +1,271 @@
+//! Fuzzy company name to CIK resolution.
+//!
+//! The SEC ticker file lists each company under its registered title ("Apple Inc.",
+//! "BERKSHIRE HATHAWAY INC /DE/"), which rarely matches what a user types. [`NameMatcher`]
+//! normalizes both sides before comparing them:
+//! - case folding and punctuation removal,
+//! - dropping state-of-incorporation markers such as `/DE/`,
+//! - dropping a leading "the" and trailing corporate suffixes (Inc, Corp, Ltd, PLC, ...).
+//!
+//! Normalized names are then compared exactly, by word prefix ("berkshire" matches
+//! "berkshire hathaway"), and finally by edit distance up to a configurable threshold.
+//! Results are ranked candidates rather than a single answer, since short names are often
+//! ambiguous.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{CompanyOperations, Edgar};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! for candidate in edgar.company_cik_by_name("berkshire hathaway").await? {
+//!     println!("{:.2} {} {} {:?}", candidate.score, candidate.cik, candidate.name, candidate.tickers);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::company::CompanyTicker;
+use std::collections::HashMap;
+
+/// Words dropped from the end of a company name before comparison.
+const CORPORATE_SUFFIXES: &[&str] = &[
+    "inc",
+    "incorporated",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "ltd",
+    "limited",
+    "plc",
+    "llc",
+    "lp",
+    "llp",
+    "sa",
+    "ag",
+    "nv",
+    "se",
+];
+
+const DEFAULT_MAX_DISTANCE: usize = 2;
+const DEFAULT_LIMIT: usize = 10;
+
+/// A company that may match a searched name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CikCandidate {
+    /// Central Index Key.
+    pub cik: u64,
+
+    /// Registered company title.
+    pub name: String,
+
+    /// Every ticker listed for the company.
+    pub tickers: Vec<String>,
+
+    /// Match quality from 0.0 to 1.0; 1.0 means the normalized names are identical.
+    pub score: f64,
+}
+
+/// Ranks company titles against a free-text name.
+#[derive(Debug, Clone)]
+pub struct NameMatcher {
+    max_distance: usize,
+    limit: usize,
+}
+
+impl Default for NameMatcher {
+    fn default() -> Self {
+        Self {
+            max_distance: DEFAULT_MAX_DISTANCE,
+            limit: DEFAULT_LIMIT,
+        }
+    }
+}
+
+impl NameMatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum edit distance between normalized names for a fuzzy match (default 2).
+    ///
+    /// Set to 0 to allow only exact and prefix matches.
+    pub fn with_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Maximum number of candidates returned (default 10).
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns candidates for `name`, best first.
+    ///
+    /// Companies listed under several tickers are merged into one candidate.
+    pub fn rank(&self, name: &str, tickers: &[CompanyTicker]) -> Vec<CikCandidate> {
+        let query = normalize_company_name(name);
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut best: HashMap<u64, CikCandidate> = HashMap::new();
+        for row in tickers {
+            let entry = best.entry(row.cik).or_insert_with(|| CikCandidate {
+                cik: row.cik,
+                name: row.title.clone(),
+                tickers: Vec::new(),
+                score: 0.0,
+            });
+            if !entry.tickers.contains(&row.ticker) {
+                entry.tickers.push(row.ticker.clone());
+            }
+            if let Some(score) = self.score(&query, &normalize_company_name(&row.title)) {
+                entry.score = entry.score.max(score);
+            }
+        }
+
+        let mut candidates: Vec<CikCandidate> =
+            best.into_values().filter(|c| c.score > 0.0).collect();
+        candidates.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.len().cmp(&b.name.len()))
+                .then_with(|| a.cik.cmp(&b.cik))
+        });
+        candidates.truncate(self.limit);
+        candidates
+    }
+
+    fn score(&self, query: &str, candidate: &str) -> Option<f64> {
+        if candidate.is_empty() {
+            return None;
+        }
+        if query == candidate {
+            return Some(1.0);
+        }
+        if candidate.starts_with(query) && candidate[query.len()..].starts_with(' ') {
+            // Word prefix: closer to 1.0 the more of the candidate the query covers.
+            return Some(0.5 + 0.4 * query.len() as f64 / candidate.len() as f64);
+        }
+
+        let distance = levenshtein(query, candidate);
+        if distance > self.max_distance {
+            return None;
+        }
+        let longest = query.chars().count().max(candidate.chars().count());
+        Some(0.9 * (1.0 - distance as f64 / longest as f64))
+    }
+}
+
+/// Normalizes a company name for comparison.
+///
+/// ```rust
+/// # use edgarkit::normalize_company_name;
+/// assert_eq!(normalize_company_name("BERKSHIRE HATHAWAY INC /DE/"), "berkshire hathaway");
+/// assert_eq!(normalize_company_name("The Walt Disney Co."), "walt disney");
+/// ```
+pub fn normalize_company_name(name: &str) -> String {
+    let lowered = name.to_lowercase();
+
+    // Drop "/de/"-style state markers before punctuation is stripped.
+    let mut without_states = String::with_capacity(lowered.len());
+    let mut rest = lowered.as_str();
+    while let Some(start) = rest.find('/') {
+        without_states.push_str(&rest[..start]);
+        match rest[start + 1..].find('/') {
+            Some(end) if end <= 3 => rest = &rest[start + end + 2..],
+            _ => {
+                without_states.push(' ');
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+    without_states.push_str(rest);
+
+    let cleaned: String = without_states
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+
+    if words.len() > 1 && words[0] == "the" {
+        words.remove(0);
+    }
+    while words.len() > 1 && words.last().is_some_and(|w| CORPORATE_SUFFIXES.contains(w)) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(cik: u64, ticker: &str, title: &str) -> CompanyTicker {
+        CompanyTicker {
+            cik,
+            ticker: ticker.to_string(),
+            title: title.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_company_name() {
+        assert_eq!(normalize_company_name("Apple Inc."), "apple");
+        assert_eq!(
+            normalize_company_name("BERKSHIRE HATHAWAY INC /DE/"),
+            "berkshire hathaway"
+        );
+        assert_eq!(normalize_company_name("AT&T Inc."), "at t");
+        assert_eq!(normalize_company_name("Co"), "co");
+    }
+
+    #[test]
+    fn test_rank_exact_prefix_and_typo() {
+        let tickers = vec![
+            ticker(1067983, "BRK-B", "BERKSHIRE HATHAWAY INC"),
+            ticker(1067983, "BRK-A", "BERKSHIRE HATHAWAY INC"),
+            ticker(1585689, "BHLB", "Berkshire Hills Bancorp, Inc."),
+            ticker(320193, "AAPL", "Apple Inc."),
+        ];
+        let matcher = NameMatcher::new();
+
+        let exact = matcher.rank("Berkshire Hathaway, Inc.", &tickers);
+        assert_eq!(exact[0].cik, 1067983);
+        assert_eq!(exact[0].score, 1.0);
+        assert_eq!(exact[0].tickers, vec!["BRK-B", "BRK-A"]);
+
+        let prefix = matcher.rank("berkshire", &tickers);
+        assert_eq!(prefix.len(), 2);
+        assert_eq!(prefix[0].cik, 1067983);
+
+        let typo = matcher.rank("Appel", &tickers);
+        assert_eq!(typo[0].cik, 320193);
+        assert!(
+            NameMatcher::new()
+                .with_max_distance(0)
+                .rank("Appel", &tickers)
+                .is_empty()
+        );
+    }
+}
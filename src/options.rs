@@ -3,6 +3,10 @@
 //! This module provides builder-style option structs for customizing filing queries
 //! and feed requests. Options use the builder pattern for clean, composable configuration.
 
+#[cfg(feature = "feeds")]
+use super::error::{EdgarError, Result};
+#[cfg(any(feature = "filings", feature = "index"))]
+use super::items::ItemCode;
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -61,6 +65,10 @@ pub struct FilingOptions {
     /// Whether to automatically include amendment forms (e.g., S-1/A when S-1 is requested).
     /// Defaults to true.
     pub include_amendments: bool,
+
+    /// Keep only filings that report at least one of these 8-K items. Applies to
+    /// `filings()`; index listings carry no item codes and ignore it.
+    pub items: Option<Vec<ItemCode>>,
 }
 
 #[cfg(any(feature = "filings", feature = "index"))]
@@ -72,6 +80,7 @@ impl Default for FilingOptions {
             limit: None,
             ciks: None,
             include_amendments: true,
+            items: None,
         }
     }
 }
@@ -121,24 +130,57 @@ impl FilingOptions {
         self.include_amendments = include_amendments;
         self
     }
+
+    /// Keep only filings reporting any of the given 8-K items, as codes (`"2.02"`) or
+    /// [`Item8K`](crate::Item8K) values (an alias of [`ItemCode`]).
+    ///
+    /// ```rust
+    /// use edgarkit::{FilingOptions, Item8K};
+    ///
+    /// let opts = FilingOptions::new().with_items([Item8K::ResultsOfOperations, Item8K::from("5.02")]);
+    /// let by_code = FilingOptions::new().with_items(["2.02", "5.02"]);
+    /// ```
+    pub fn with_items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.items = Some(
+            items
+                .into_iter()
+                .map(|item| ItemCode::from(item.as_ref()))
+                .collect(),
+        );
+        self
+    }
 }
 
 /// Options for customizing SEC feed requests.
 ///
-/// Feed options use a simple key-value parameter system that maps directly to the
-/// SEC's feed query string parameters. Common parameters include `count` (number of
-/// results), `type` (form type filter), and `start` (pagination offset).
+/// Feed options map to the query string parameters of the SEC's `browse-edgar` feeds. The
+/// typed builders cover the common parameters and use the names the SEC expects:
+///
+/// | Builder           | Parameter | Meaning                                         |
+/// |-------------------|-----------|-------------------------------------------------|
+/// | `with_count`      | `count`   | Entries per page (10, 20, 40, 80 or 100)        |
+/// | `with_form_type`  | `type`    | Form type filter, e.g. `10-K`                   |
+/// | `with_start`      | `start`   | Offset of the first entry, for pagination       |
+/// | `with_owner`      | `owner`   | Whether to include insider ownership filings    |
+/// | `with_company`    | `company` | Company name filter                             |
 ///
 /// The options default to Atom output format, which is the standard for SEC feeds.
-/// You can add custom parameters using `with_param()` to access advanced feed features.
+/// Anything else can still be set with `with_param()`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// # use edgarkit::FeedOptions;
+/// # use edgarkit::{FeedOptions, OwnerFilter};
 /// let options = FeedOptions::new(None)
-///     .with_param("count", "50")
-///     .with_param("type", "10-K");
+///     .with_count(40)?
+///     .with_form_type("10-K")
+///     .with_owner(OwnerFilter::Exclude);
+/// assert_eq!(options.params()["count"], "40");
+/// # Ok::<(), edgarkit::EdgarError>(())
 /// ```
 #[cfg(feature = "feeds")]
 #[derive(Debug, Clone, Default, Serialize)]
@@ -147,6 +189,35 @@ pub struct FeedOptions {
     params: HashMap<String, String>, // Arbitrary feed parameters (e.g., count=10)
 }
 
+/// Page sizes accepted by the `browse-edgar` feeds.
+#[cfg(feature = "feeds")]
+pub const FEED_COUNTS: [u32; 5] = [10, 20, 40, 80, 100];
+
+/// Controls whether insider (Forms 3, 4, 5) filings appear in a feed.
+#[cfg(feature = "feeds")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OwnerFilter {
+    /// Include ownership filings alongside company filings.
+    Include,
+
+    /// Leave ownership filings out (the SEC's default).
+    Exclude,
+
+    /// Only show ownership filings.
+    Only,
+}
+
+#[cfg(feature = "feeds")]
+impl OwnerFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OwnerFilter::Include => "include",
+            OwnerFilter::Exclude => "exclude",
+            OwnerFilter::Only => "only",
+        }
+    }
+}
+
 #[cfg(feature = "feeds")]
 impl FeedOptions {
     fn default() -> Self {
@@ -178,7 +249,191 @@ impl FeedOptions {
         self
     }
 
+    /// Sets the number of entries per page.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::ConfigError` unless `count` is one of [`FEED_COUNTS`]; the SEC
+    /// silently falls back to its default page size for any other value.
+    pub fn with_count(self, count: u32) -> Result<Self> {
+        if !FEED_COUNTS.contains(&count) {
+            return Err(EdgarError::ConfigError(format!(
+                "feed count must be one of {:?}, got {}",
+                FEED_COUNTS, count
+            )));
+        }
+        Ok(self.with_param("count", count.to_string()))
+    }
+
+    /// Filters entries by form type (e.g. `10-K`, `8-K`).
+    pub fn with_form_type(self, form_type: impl Into<String>) -> Self {
+        self.with_param("type", form_type)
+    }
+
+    /// Skips the first `start` entries, for paging through a feed.
+    pub fn with_start(self, start: u32) -> Self {
+        self.with_param("start", start.to_string())
+    }
+
+    /// Controls whether insider ownership filings are included.
+    pub fn with_owner(self, owner: OwnerFilter) -> Self {
+        self.with_param("owner", owner.as_str())
+    }
+
+    /// Filters entries by company name.
+    pub fn with_company(self, company: impl Into<String>) -> Self {
+        self.with_param("company", company)
+    }
+
     pub fn params(&self) -> &HashMap<String, String> {
         &self.params
     }
 }
+
+/// Filters for the current filings feed (`browse-edgar?action=getcurrent`).
+///
+/// The feed lists the latest filings across all filers, newest first. Every filter is
+/// optional; without any, the feed shows the SEC's default of 40 company filings.
+///
+/// # Example
+///
+/// ```
+/// # use edgarkit::{CurrentFeedOptions, FeedOptions, OwnerFilter};
+/// let options = CurrentFeedOptions::new()
+///     .with_form_type("8-K")
+///     .with_owner(OwnerFilter::Exclude)
+///     .with_count(100)?;
+/// let params = FeedOptions::from(options).params().clone();
+/// assert_eq!(params["type"], "8-K");
+/// assert_eq!(params["count"], "100");
+/// # Ok::<(), edgarkit::EdgarError>(())
+/// ```
+#[cfg(feature = "feeds")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CurrentFeedOptions {
+    form_type: Option<String>,
+    company: Option<String>,
+    owner: Option<OwnerFilter>,
+    count: Option<u32>,
+}
+
+#[cfg(feature = "feeds")]
+impl CurrentFeedOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filters by form type. The SEC matches by prefix, so `8-K` also lists `8-K/A`.
+    pub fn with_form_type(mut self, form_type: impl Into<String>) -> Self {
+        self.form_type = Some(form_type.into());
+        self
+    }
+
+    /// Filters by company name, matched by prefix.
+    pub fn with_company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Controls whether insider ownership filings (Forms 3, 4, 5) are listed.
+    pub fn with_owner(mut self, owner: OwnerFilter) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Sets the number of entries per fetch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::ConfigError` unless `count` is one of [`FEED_COUNTS`].
+    pub fn with_count(mut self, count: u32) -> Result<Self> {
+        if !FEED_COUNTS.contains(&count) {
+            return Err(EdgarError::ConfigError(format!(
+                "feed count must be one of {:?}, got {}",
+                FEED_COUNTS, count
+            )));
+        }
+        self.count = Some(count);
+        Ok(self)
+    }
+
+    pub fn form_type(&self) -> Option<&str> {
+        self.form_type.as_deref()
+    }
+
+    pub fn company(&self) -> Option<&str> {
+        self.company.as_deref()
+    }
+
+    pub fn owner(&self) -> Option<OwnerFilter> {
+        self.owner
+    }
+
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+}
+
+#[cfg(feature = "feeds")]
+impl From<CurrentFeedOptions> for FeedOptions {
+    fn from(options: CurrentFeedOptions) -> Self {
+        let mut feed = FeedOptions::new(None);
+        if let Some(form_type) = options.form_type {
+            feed = feed.with_form_type(form_type);
+        }
+        if let Some(company) = options.company {
+            feed = feed.with_company(company);
+        }
+        if let Some(owner) = options.owner {
+            feed = feed.with_owner(owner);
+        }
+        if let Some(count) = options.count {
+            feed = feed.with_param("count", count.to_string());
+        }
+        feed
+    }
+}
+
+#[cfg(all(test, feature = "feeds"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_feed_params() {
+        let options = FeedOptions::new(None)
+            .with_count(100)
+            .unwrap()
+            .with_form_type("8-K")
+            .with_start(200)
+            .with_owner(OwnerFilter::Only)
+            .with_company("Apple");
+
+        let params = options.params();
+        assert_eq!(params["output"], "atom");
+        assert_eq!(params["count"], "100");
+        assert_eq!(params["type"], "8-K");
+        assert_eq!(params["start"], "200");
+        assert_eq!(params["owner"], "only");
+        assert_eq!(params["company"], "Apple");
+    }
+
+    #[test]
+    fn test_current_feed_options_only_set_given_filters() {
+        let params = FeedOptions::from(CurrentFeedOptions::new().with_form_type("S-1"))
+            .params()
+            .clone();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params["output"], "atom");
+        assert_eq!(params["type"], "S-1");
+
+        assert!(CurrentFeedOptions::new().with_count(25).is_err());
+    }
+
+    #[test]
+    fn test_invalid_feed_count() {
+        assert!(matches!(
+            FeedOptions::new(None).with_count(50),
+            Err(EdgarError::ConfigError(_))
+        ));
+    }
+}
@@ -4,6 +4,8 @@
 //! It supports configurable parsing options such as following links, limiting entries,
 //! and filtering by categories.
 #[cfg(feature = "atom")]
+use super::utils::deserialize_optional_human_size;
+#[cfg(feature = "atom")]
 use crate::Result;
 #[cfg(feature = "atom")]
 use quick_xml::{Reader, de::from_reader};
@@ -225,8 +227,9 @@ pub struct Content {
     #[serde(rename = "form-name")]
     pub form_name: Option<String>,
 
-    /// Size of the filing document.
-    pub size: Option<String>,
+    /// Size of the filing document in bytes (the feed shows e.g. `302 KB`).
+    #[serde(default, deserialize_with = "deserialize_optional_human_size")]
+    pub size: Option<u64>,
 
     /// Link to XBRL data if available.
     #[serde(rename = "xbrl_href")]
@@ -292,6 +295,67 @@ impl AtomEntry {
             .map(|l| l.href.clone())
             .unwrap_or_default()
     }
+
+    /// Accession number of the filing, from the entry content or its `id`
+    /// (`urn:tag:sec.gov,2008:accession-number=...`).
+    pub fn accession_number(&self) -> Option<String> {
+        self.content
+            .as_ref()
+            .and_then(|c| c.accession_number.clone())
+            .or_else(|| {
+                self.id
+                    .split_once("accession-number=")
+                    .map(|(_, acc)| acc.trim().to_string())
+            })
+            .filter(|acc| !acc.is_empty())
+    }
+
+    /// Form type, from the entry content, its category, or the start of its title.
+    pub fn form_type(&self) -> Option<String> {
+        self.content
+            .as_ref()
+            .and_then(|c| c.filing_type.clone())
+            .or_else(|| self.category.as_ref().map(|c| c.term.clone()))
+            .or_else(|| {
+                self.title
+                    .split_once(" - ")
+                    .map(|(form, _)| form.trim().to_string())
+            })
+            .filter(|form| !form.is_empty())
+    }
+
+    /// CIK of the filer, from the `/data/<cik>/` segment of the filing link or the
+    /// parenthesized CIK in current-feed titles (`8-K - Acme Corp (0000012345) (Filer)`).
+    pub fn cik(&self) -> Option<u64> {
+        let href = self
+            .content
+            .as_ref()
+            .and_then(|c| c.filing_href.as_deref())
+            .unwrap_or(&self.link);
+        href.split_once("/data/")
+            .and_then(|(_, rest)| rest.split('/').next())
+            .and_then(|cik| cik.parse().ok())
+            .or_else(|| {
+                self.title.split('(').skip(1).find_map(|part| {
+                    let (cik, _) = part.split_once(')')?;
+                    if cik.len() == 10 {
+                        cik.parse().ok()
+                    } else {
+                        None
+                    }
+                })
+            })
+    }
+
+    /// Filer name from a current-feed title such as `8-K - Acme Corp (0000012345) (Filer)`.
+    pub fn company_name(&self) -> Option<String> {
+        let (_, rest) = self.title.split_once(" - ")?;
+        let name = match rest.find(" (") {
+            Some(end) => &rest[..end],
+            None => rest,
+        };
+        Some(name.trim().to_string()).filter(|name| !name.is_empty())
+    }
 }
 
 /// Represents an Atom feed parser with configurable options.
@@ -345,9 +409,10 @@ impl AtomParser {
 
         if !self.config.filter_categories.is_empty() {
             doc.entries.retain(|entry| {
-                entry.category.as_ref().map_or(false, |cat| {
-                    self.config.filter_categories.contains(&cat.term)
-                })
+                entry
+                    .category
+                    .as_ref()
+                    .is_some_and(|cat| self.config.filter_categories.contains(&cat.term))
             });
         }
 
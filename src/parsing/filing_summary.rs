@@ -0,0 +1,220 @@
+//! XBRL financial report renderings (`FilingSummary.xml`).
+//!
+//! When a filing carries XBRL, EDGAR renders each presentation group (the cover page, each
+//! statement, each note and its tables and details) as a standalone HTML table named
+//! `R1.htm`, `R2.htm`, and so on. These are the tables behind the "Interactive Data" viewer.
+//! `FilingSummary.xml` in the filing folder lists them in order with their names and menu
+//! category. Older filings (before about 2010) render to `R1.xml` instead.
+//!
+//! [`parse_filing_summary`] reads the list into [`FinancialReport`]s.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::filing_summary::{ReportCategory, parse_filing_summary};
+//!
+//! let xml = r#"<FilingSummary><MyReports>
+//!     <Report instance="aapl-20230930.htm">
+//!         <IsDefault>false</IsDefault>
+//!         <HtmlFileName>R2.htm</HtmlFileName>
+//!         <LongName>9952152 - Statement - CONSOLIDATED STATEMENTS OF OPERATIONS</LongName>
+//!         <ShortName>CONSOLIDATED STATEMENTS OF OPERATIONS</ShortName>
+//!         <MenuCategory>Statements</MenuCategory>
+//!         <Position>2</Position>
+//!         <Role>http://www.apple.com/role/CONSOLIDATEDSTATEMENTSOFOPERATIONS</Role>
+//!     </Report>
+//! </MyReports></FilingSummary>"#;
+//!
+//! let reports = parse_filing_summary(xml).unwrap();
+//! assert_eq!(reports[0].file_name, "R2.htm");
+//! assert_eq!(reports[0].category, ReportCategory::Statements);
+//! assert_eq!(reports[0].position, Some(2));
+//! ```
+
+use crate::error::{EdgarError, Result};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde::{Deserialize, Serialize};
+
+/// The viewer menu a report is listed under.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReportCategory {
+    /// The cover page (`dei` document and entity information)
+    Cover,
+    /// Primary financial statements: balance sheet, income statement, cash flows, equity
+    Statements,
+    /// Notes to the financial statements, as text blocks
+    Notes,
+    /// Accounting policies
+    Policies,
+    /// Tables extracted from the notes
+    Tables,
+    /// Detail values tagged in the notes
+    Details,
+    /// Reports the renderer could not place, and any category it adds later
+    Other(String),
+}
+
+impl ReportCategory {
+    fn parse(name: &str) -> Self {
+        match name {
+            "Cover" => ReportCategory::Cover,
+            "Statements" => ReportCategory::Statements,
+            "Notes" => ReportCategory::Notes,
+            "Policies" => ReportCategory::Policies,
+            "Tables" => ReportCategory::Tables,
+            "Details" => ReportCategory::Details,
+            other => ReportCategory::Other(other.to_string()),
+        }
+    }
+}
+
+/// One rendered report listed in `FilingSummary.xml`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FinancialReport {
+    /// Order in the viewer menu, starting at 1
+    pub position: Option<u32>,
+
+    /// Display name, e.g. `CONSOLIDATED BALANCE SHEETS`
+    pub short_name: String,
+
+    /// Name with the role's sort code and type, e.g.
+    /// `9952153 - Statement - CONSOLIDATED BALANCE SHEETS`
+    pub long_name: String,
+
+    pub category: ReportCategory,
+
+    /// The rendered file in the filing folder, e.g. `R4.htm`
+    pub file_name: String,
+
+    /// Presentation role URI the report renders
+    pub role: Option<String>,
+
+    /// Full URL of the rendered file; set when the report was fetched with
+    /// `get_financial_reports`, `None` when parsed from a string
+    pub url: Option<String>,
+}
+
+impl FinancialReport {
+    /// Whether the report is one of the primary financial statements.
+    pub fn is_statement(&self) -> bool {
+        self.category == ReportCategory::Statements
+    }
+}
+
+/// Finds a report by name, ignoring case.
+///
+/// An exact match on the short name wins; otherwise the first statement whose name contains
+/// `name`, then the first report of any category. `"balance sheet"` thus finds
+/// `CONSOLIDATED BALANCE SHEETS` rather than its parenthetical or a note's details.
+pub fn find_report<'a>(reports: &'a [FinancialReport], name: &str) -> Option<&'a FinancialReport> {
+    let name = name.trim().to_lowercase();
+    let contains = |report: &&FinancialReport| report.short_name.to_lowercase().contains(&name);
+    reports
+        .iter()
+        .find(|report| report.short_name.to_lowercase() == name)
+        .or_else(|| {
+            reports
+                .iter()
+                .filter(|report| report.is_statement())
+                .find(contains)
+        })
+        .or_else(|| reports.iter().find(contains))
+}
+
+/// Report fields collected until the closing `</Report>`.
+#[derive(Default)]
+struct PendingReport {
+    position: Option<u32>,
+    short_name: String,
+    long_name: String,
+    category: Option<String>,
+    html_file_name: Option<String>,
+    xml_file_name: Option<String>,
+    role: Option<String>,
+}
+
+/// Parses `FilingSummary.xml` into its rendered reports, in document order.
+///
+/// Entries without a rendered file, such as the `Financial_Report.xlsx` workbook, are left
+/// out. Where a report has both, the HTML rendering is preferred.
+///
+/// # Errors
+///
+/// Returns `EdgarError::XmlError` if the document is not well-formed XML, and
+/// `EdgarError::InvalidFormat` if it is not a filing summary.
+pub fn parse_filing_summary(xml: &str) -> Result<Vec<FinancialReport>> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut reports = Vec::new();
+    let mut is_summary = false;
+    let mut pending: Option<PendingReport> = None;
+    let mut path: Vec<Vec<u8>> = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = e.local_name().as_ref().to_vec();
+                match name.as_slice() {
+                    b"FilingSummary" if path.is_empty() => is_summary = true,
+                    b"Report" => pending = Some(PendingReport::default()),
+                    _ => {}
+                }
+                path.push(name);
+            }
+            Ok(Event::Text(e)) => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| EdgarError::XmlError(e.to_string()))?;
+                if let (Some(report), [.., parent, field]) = (&mut pending, path.as_slice())
+                    && parent.as_slice() == b"Report"
+                {
+                    set_field(report, field, text.trim());
+                }
+            }
+            Ok(Event::End(e)) => {
+                if e.local_name().as_ref() == b"Report"
+                    && let Some(report) = pending.take()
+                    && let Some(file_name) = report.html_file_name.or(report.xml_file_name)
+                {
+                    reports.push(FinancialReport {
+                        position: report.position,
+                        short_name: report.short_name,
+                        long_name: report.long_name,
+                        category: ReportCategory::parse(
+                            report.category.as_deref().unwrap_or("Uncategorized"),
+                        ),
+                        file_name,
+                        role: report.role,
+                        url: None,
+                    });
+                }
+                path.pop();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(EdgarError::XmlError(e.to_string())),
+            _ => {}
+        }
+    }
+
+    if !is_summary {
+        return Err(EdgarError::InvalidFormat(
+            "document is not a FilingSummary.xml".to_string(),
+        ));
+    }
+    Ok(reports)
+}
+
+fn set_field(report: &mut PendingReport, field: &[u8], text: &str) {
+    let value = || Some(text.to_string());
+    match field {
+        b"Position" => report.position = text.parse().ok(),
+        b"ShortName" => report.short_name = text.to_string(),
+        b"LongName" => report.long_name = text.to_string(),
+        b"MenuCategory" => report.category = value(),
+        b"HtmlFileName" => report.html_file_name = value(),
+        b"XmlFileName" => report.xml_file_name = value(),
+        b"Role" => report.role = value(),
+        _ => {}
+    }
+}
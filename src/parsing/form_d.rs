@@ -0,0 +1,270 @@
+//! Form D notices of exempt offerings.
+//!
+//! Companies raising money without registering the offering (Regulation D private
+//! placements, mostly) file a Form D within 15 days of the first sale, and amend it yearly
+//! while the offering continues. The filing is XML only; its `primary_doc.xml` names the
+//! issuer and its executive officers, directors, and promoters, the exemptions claimed,
+//! and how much has been offered and sold to how many investors.
+//!
+//! [`parse_form_d`] reads it into a [`FormD`]. Amounts are `None` where the issuer declined
+//! to state them or reported them as indefinite.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::form_d::parse_form_d;
+//!
+//! let xml = r#"<edgarSubmission><submissionType>D</submissionType>
+//!     <primaryIssuer><cik>0002012345</cik><entityName>Example Robotics, Inc.</entityName>
+//!         <entityType>Corporation</entityType></primaryIssuer>
+//!     <offeringData>
+//!         <federalExemptionsExclusions><item>06b</item></federalExemptionsExclusions>
+//!         <offeringSalesAmounts><totalOfferingAmount>Indefinite</totalOfferingAmount>
+//!             <totalAmountSold>2500000</totalAmountSold></offeringSalesAmounts>
+//!         <investors><hasNonAccreditedInvestors>false</hasNonAccreditedInvestors>
+//!             <totalNumberAlreadyInvested>14</totalNumberAlreadyInvested></investors>
+//!     </offeringData></edgarSubmission>"#;
+//!
+//! let form = parse_form_d(xml).unwrap();
+//! assert_eq!(form.issuer.name, "Example Robotics, Inc.");
+//! assert_eq!(form.offering.exemptions, vec!["06b"]);
+//! assert!(form.offering.indefinite_amount);
+//! assert_eq!(form.offering.total_offering_amount, None);
+//! assert_eq!(form.offering.total_amount_sold, Some(2_500_000.0));
+//! assert_eq!(form.offering.total_investors, Some(14));
+//! ```
+
+use crate::error::{EdgarError, Result};
+use chrono::NaiveDate;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde::{Deserialize, Serialize};
+
+/// The company making the offering.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Issuer {
+    pub cik: Option<String>,
+    pub name: String,
+    /// `Corporation`, `Limited Partnership`, `Limited Liability Company`, ...
+    pub entity_type: Option<String>,
+    /// State or country of incorporation, e.g. `DELAWARE`
+    pub jurisdiction: Option<String>,
+    pub year_of_incorporation: Option<i32>,
+    pub city: Option<String>,
+    /// Two-letter state or EDGAR country code of the principal place of business
+    pub state_or_country: Option<String>,
+    pub phone: Option<String>,
+}
+
+/// An executive officer, director, or promoter of the issuer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelatedPerson {
+    pub first_name: Option<String>,
+    pub last_name: String,
+    /// `Executive Officer`, `Director`, and/or `Promoter`
+    pub relationships: Vec<String>,
+}
+
+/// Terms and progress of the offering.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Offering {
+    /// Industry group, e.g. `Pooled Investment Fund` or `Other Technology`
+    pub industry_group: Option<String>,
+    /// Revenue range, or `Decline to Disclose`
+    pub revenue_range: Option<String>,
+    /// Exemption codes claimed, e.g. `06b` (Rule 506(b)), `06c`, `04`, `3C.1`
+    pub exemptions: Vec<String>,
+    pub is_amendment: bool,
+    pub date_of_first_sale: Option<NaiveDate>,
+    pub more_than_one_year: Option<bool>,
+    pub minimum_investment: Option<f64>,
+    pub total_offering_amount: Option<f64>,
+    /// The offering amount was reported as indefinite
+    pub indefinite_amount: bool,
+    pub total_amount_sold: Option<f64>,
+    pub total_remaining: Option<f64>,
+    pub has_non_accredited_investors: bool,
+    pub non_accredited_investors: Option<u32>,
+    pub total_investors: Option<u32>,
+    pub sales_commissions: Option<f64>,
+    pub finders_fees: Option<f64>,
+}
+
+/// A parsed Form D or D/A.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormD {
+    /// `D` or `D/A`
+    pub submission_type: String,
+    pub issuer: Issuer,
+    pub related_persons: Vec<RelatedPerson>,
+    pub offering: Offering,
+}
+
+/// Parses a Form D `primary_doc.xml`.
+///
+/// Only the primary issuer is read when several issuers file together.
+///
+/// # Errors
+///
+/// Returns `EdgarError::XmlError` if the document is not well-formed XML, and
+/// `EdgarError::InvalidFormat` if it has no primary issuer.
+pub fn parse_form_d(xml: &str) -> Result<FormD> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut form = FormD::default();
+    let mut has_issuer = false;
+    let mut path: Vec<Vec<u8>> = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = e.local_name().as_ref().to_vec();
+                match name.as_slice() {
+                    b"primaryIssuer" => has_issuer = true,
+                    b"relatedPersonInfo" => form.related_persons.push(RelatedPerson::default()),
+                    _ => {}
+                }
+                path.push(name);
+            }
+            Ok(Event::Text(e)) => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| EdgarError::XmlError(e.to_string()))?;
+                let path: Vec<&[u8]> = path.iter().map(Vec::as_slice).collect();
+                set_field(&mut form, &path, text.trim());
+            }
+            Ok(Event::End(_)) => {
+                path.pop();
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(EdgarError::XmlError(e.to_string())),
+            _ => {}
+        }
+    }
+
+    if !has_issuer {
+        return Err(EdgarError::InvalidFormat(
+            "no Form D primary issuer in document".to_string(),
+        ));
+    }
+    Ok(form)
+}
+
+/// Stores the text of the element at `path` (from the root, namespace prefixes removed).
+fn set_field(form: &mut FormD, path: &[&[u8]], text: &str) {
+    let below = |parent: &[u8]| {
+        path.iter()
+            .position(|element| *element == parent)
+            .map(|at| &path[at + 1..])
+    };
+    if let Some(rest) = below(b"primaryIssuer") {
+        set_issuer(&mut form.issuer, rest, text);
+    } else if let Some(rest) = below(b"relatedPersonInfo") {
+        if let Some(person) = form.related_persons.last_mut() {
+            set_related_person(person, rest, text);
+        }
+    } else if let Some(rest) = below(b"offeringData") {
+        set_offering(&mut form.offering, rest, text);
+    } else if path.last() == Some(&b"submissionType".as_slice()) {
+        form.submission_type = text.to_string();
+    }
+}
+
+fn set_issuer(issuer: &mut Issuer, path: &[&[u8]], text: &str) {
+    let value = || Some(text.to_string());
+    match path {
+        [b"cik"] => issuer.cik = value(),
+        [b"entityName"] => issuer.name = text.to_string(),
+        [b"entityType"] => issuer.entity_type = value(),
+        [b"jurisdictionOfInc"] => issuer.jurisdiction = value(),
+        [b"yearOfInc", b"value"] => issuer.year_of_incorporation = text.parse().ok(),
+        [b"issuerAddress", b"city"] => issuer.city = value(),
+        [b"issuerAddress", b"stateOrCountry"] => issuer.state_or_country = value(),
+        [b"issuerPhoneNumber"] => issuer.phone = value(),
+        _ => {}
+    }
+}
+
+fn set_related_person(person: &mut RelatedPerson, path: &[&[u8]], text: &str) {
+    match path {
+        [b"relatedPersonName", b"firstName"] => person.first_name = Some(text.to_string()),
+        [b"relatedPersonName", b"lastName"] => person.last_name = text.to_string(),
+        [b"relatedPersonRelationshipList", b"relationship"] => {
+            person.relationships.push(text.to_string())
+        }
+        _ => {}
+    }
+}
+
+fn set_offering(offering: &mut Offering, path: &[&[u8]], text: &str) {
+    let value = || Some(text.to_string());
+    match path {
+        [b"industryGroup", b"industryGroupType"] => offering.industry_group = value(),
+        [
+            b"issuerSize",
+            b"revenueRange" | b"aggregateNetAssetValueRange",
+        ] => offering.revenue_range = value(),
+        [b"federalExemptionsExclusions", b"item"] => offering.exemptions.push(text.to_string()),
+        [b"typeOfFiling", b"newOrAmendment", b"isAmendment"] => offering.is_amendment = flag(text),
+        [b"typeOfFiling", b"dateOfFirstSale", b"value"] => {
+            offering.date_of_first_sale = NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+        }
+        [b"durationOfOffering", b"moreThanOneYear"] => {
+            offering.more_than_one_year = Some(flag(text))
+        }
+        [b"minimumInvestmentAccepted"] => offering.minimum_investment = amount(text),
+        [b"offeringSalesAmounts", field] => match *field {
+            b"totalOfferingAmount" => {
+                offering.indefinite_amount = text.eq_ignore_ascii_case("indefinite");
+                offering.total_offering_amount = amount(text);
+            }
+            b"totalAmountSold" => offering.total_amount_sold = amount(text),
+            b"totalRemaining" => offering.total_remaining = amount(text),
+            _ => {}
+        },
+        [b"investors", field] => match *field {
+            b"hasNonAccreditedInvestors" => offering.has_non_accredited_investors = flag(text),
+            b"numberNonAccreditedInvestors" => {
+                offering.non_accredited_investors = text.parse().ok()
+            }
+            b"totalNumberAlreadyInvested" => offering.total_investors = text.parse().ok(),
+            _ => {}
+        },
+        [
+            b"salesCommissionsFindersFees",
+            b"salesCommissions",
+            b"dollarAmount",
+        ] => offering.sales_commissions = amount(text),
+        [
+            b"salesCommissionsFindersFees",
+            b"findersFees",
+            b"dollarAmount",
+        ] => offering.finders_fees = amount(text),
+        _ => {}
+    }
+}
+
+fn flag(text: &str) -> bool {
+    text.eq_ignore_ascii_case("true") || text == "Y"
+}
+
+fn amount(text: &str) -> Option<f64> {
+    text.replace(',', "").parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requires_primary_issuer() {
+        assert!(matches!(
+            parse_form_d("<edgarSubmission><submissionType>D</submissionType></edgarSubmission>"),
+            Err(EdgarError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            parse_form_d("<edgarSubmission><primaryIssuer></edgarSubmission>"),
+            Err(EdgarError::XmlError(_))
+        ));
+    }
+}
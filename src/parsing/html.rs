@@ -0,0 +1,775 @@
+//! Plain text and Markdown renderings of filing documents.
+//!
+//! Primary documents are HTML generated by filing agents' word processors: every paragraph
+//! is a `<div>` full of styled `<span>`s, headings are bold text rather than `<h1>`, inline
+//! XBRL wraps numbers in `ix:` tags, and tables are used for page layout as often as for
+//! data. Filings from before the early 2000s are plain text hard-wrapped at about 70
+//! columns, with tables drawn in spaces and dashes. [`to_text`] and [`to_markdown`] turn
+//! both into readable, compact text suitable for search indexing or a language model.
+//!
+//! - Scripts, styles, the hidden inline XBRL header, and `display:none` elements are dropped;
+//!   `ix:` tags are removed but the facts they wrap are kept.
+//! - `<h1>`-`<h6>` and short paragraphs set entirely in bold become headings.
+//! - Empty layout cells are dropped, and `$`, `(`, `)`, and `%` cells are joined to the
+//!   number next to them. Tables left with one column become paragraphs.
+//! - In plain text, wrapped lines are joined back into paragraphs, short all-caps lines
+//!   become headings, and column-aligned lines are kept as preformatted blocks.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::html::{to_markdown, to_text};
+//!
+//! let html = r#"<div><span style="font-weight:700">Net Sales</span></div>
+//!     <table><tr><td>iPhone</td><td>$</td><td><ix:nonFraction name="us-gaap:Revenues">200,583</ix:nonFraction></td></tr>
+//!     <tr><td>Mac</td><td>$</td><td>29,357</td></tr></table>"#;
+//!
+//! assert_eq!(to_text(html), "Net Sales\n\niPhone | $200,583\nMac | $29,357");
+//! assert_eq!(
+//!     to_markdown(html),
+//!     "### Net Sales\n\n| iPhone | $200,583 |\n| --- | --- |\n| Mac | $29,357 |"
+//! );
+//! ```
+
+/// Longest paragraph that is treated as a heading when it is entirely bold.
+const MAX_HEADING_CHARS: usize = 120;
+
+/// Wrapped plain text lines are at least this long; shorter lines end their paragraph.
+const MIN_WRAPPED_LINE: usize = 50;
+
+/// Elements whose content is never rendered.
+const SKIPPED: &[&str] = &["head", "script", "style", "title", "ix:header"];
+
+/// Elements without a closing tag.
+const VOID: &[&str] = &[
+    "area", "base", "br", "col", "hr", "img", "input", "link", "meta", "wbr",
+];
+
+/// Converts an HTML or plain text filing document to plain text.
+///
+/// Blocks are separated by blank lines and table cells by ` | `.
+pub fn to_text(document: &str) -> String {
+    render(&blocks(document), false)
+}
+
+/// Converts an HTML or plain text filing document to GitHub-flavored Markdown.
+///
+/// Headings from `<h1>`-`<h6>` keep their level; bold headings become `##` when they are in
+/// capitals and `###` otherwise. The first row of each table is used as its header.
+pub fn to_markdown(document: &str) -> String {
+    render(&blocks(document), true)
+}
+
+/// A rendered unit of a document.
+///
+/// Table cells keep the line breaks of the paragraphs inside them, so callers that read
+/// tables can tell a name from the title printed under it; rendering joins them with spaces.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Block {
+    Heading(usize, String),
+    Paragraph(String),
+    ListItem(String),
+    Table(Vec<Vec<String>>),
+    Preformatted(String),
+}
+
+pub(crate) fn blocks(document: &str) -> Vec<Block> {
+    if looks_like_html(document) {
+        HtmlReader::default().read(document)
+    } else {
+        plain_text_blocks(document)
+    }
+}
+
+/// Plain text filings contain SGML tags such as `<PAGE>` and `<TABLE>` too, so only tags
+/// that never appear in them count as evidence of HTML.
+fn looks_like_html(document: &str) -> bool {
+    [
+        "<html", "<body", "<div", "<p>", "<p ", "<font", "<br", "<span",
+    ]
+    .iter()
+    .any(|tag| find_ignore_case(document, tag).is_some())
+}
+
+fn render(blocks: &[Block], markdown: bool) -> String {
+    let mut out = String::new();
+    let mut previous: Option<&Block> = None;
+    for block in blocks {
+        if let Some(previous) = previous {
+            let list =
+                matches!(previous, Block::ListItem(_)) && matches!(block, Block::ListItem(_));
+            out.push_str(if list { "\n" } else { "\n\n" });
+        }
+        match block {
+            Block::Heading(level, text) if markdown => {
+                out.push_str(&"#".repeat(*level));
+                out.push(' ');
+                out.push_str(text);
+            }
+            Block::Heading(_, text) | Block::Paragraph(text) => out.push_str(text),
+            Block::ListItem(text) => {
+                out.push_str("- ");
+                out.push_str(text);
+            }
+            Block::Table(rows) if markdown => render_markdown_table(&mut out, rows),
+            Block::Table(rows) => {
+                let lines: Vec<String> = rows
+                    .iter()
+                    .map(|row| row.join(" | ").replace('\n', " "))
+                    .collect();
+                out.push_str(&lines.join("\n"));
+            }
+            Block::Preformatted(text) if markdown => {
+                out.push_str("```\n");
+                out.push_str(text);
+                out.push_str("\n```");
+            }
+            Block::Preformatted(text) => out.push_str(text),
+        }
+        previous = Some(block);
+    }
+    out
+}
+
+fn render_markdown_table(out: &mut String, rows: &[Vec<String>]) {
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for (idx, row) in rows.iter().enumerate() {
+        if idx > 0 {
+            out.push('\n');
+        }
+        out.push('|');
+        for col in 0..width {
+            let cell = row.get(col).map_or("", String::as_str);
+            out.push(' ');
+            out.push_str(&cell.replace('|', "\\|").replace('\n', " "));
+            out.push_str(" |");
+        }
+        if idx == 0 {
+            out.push_str("\n|");
+            out.push_str(&" --- |".repeat(width));
+        }
+    }
+}
+
+#[derive(Default)]
+struct Table {
+    rows: Vec<Vec<String>>,
+    row: Option<Vec<String>>,
+    cell: Option<String>,
+    /// Tables nested inside a cell, rendered as part of the cell's text
+    nested: usize,
+}
+
+impl Table {
+    fn finish_cell(&mut self) {
+        if let Some(cell) = self.cell.take() {
+            self.row.get_or_insert_with(Vec::new).push(cell);
+        }
+    }
+
+    fn finish_row(&mut self) {
+        self.finish_cell();
+        if let Some(row) = self.row.take() {
+            self.rows.push(row);
+        }
+    }
+}
+
+#[derive(Default)]
+struct HtmlReader {
+    blocks: Vec<Block>,
+    text: String,
+    bold_chars: usize,
+    total_chars: usize,
+    /// Open elements and whether each sets bold text
+    open: Vec<(String, bool)>,
+    heading: Option<usize>,
+    list_item: bool,
+    pre: bool,
+    table: Option<Table>,
+}
+
+impl HtmlReader {
+    fn read(mut self, html: &str) -> Vec<Block> {
+        let mut rest = html;
+        while let Some(start) = rest.find('<') {
+            self.push_text(&rest[..start]);
+            rest = &rest[start..];
+
+            if rest.starts_with("<!--") {
+                rest = rest.find("-->").map_or("", |end| &rest[end + 3..]);
+                continue;
+            }
+            let Some(end) = rest.find('>') else {
+                rest = "";
+                break;
+            };
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+            if tag.starts_with('!') || tag.starts_with('?') {
+                continue;
+            }
+
+            let closing = tag.starts_with('/');
+            let body = tag.trim_start_matches('/');
+            let name = body
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase();
+            let style = attribute(body, "style").unwrap_or_default();
+            let self_closing = body.ends_with('/');
+
+            if !closing && !self_closing && (SKIPPED.contains(&name.as_str()) || is_hidden(&style))
+            {
+                rest = skip_element(rest, &name);
+                continue;
+            }
+            if closing {
+                self.close(&name);
+            } else {
+                self.open(&name, &style, self_closing);
+            }
+        }
+        self.push_text(rest);
+        if let Some(mut table) = self.table.take() {
+            table.finish_row();
+            self.blocks.extend(table_blocks(table.rows));
+        }
+        self.flush();
+        self.blocks
+    }
+
+    fn open(&mut self, name: &str, style: &str, self_closing: bool) {
+        if !self_closing && !VOID.contains(&name) {
+            let bold = matches!(name, "b" | "strong") || is_bold(style);
+            self.open.push((name.to_string(), bold));
+        }
+
+        if let Some(table) = &mut self.table {
+            match name {
+                "table" => table.nested += 1,
+                "tr" if table.nested == 0 => {
+                    table.finish_row();
+                    table.row = Some(Vec::new());
+                }
+                "td" | "th" if table.nested == 0 => {
+                    table.finish_cell();
+                    table.cell = Some(String::new());
+                }
+                _ if is_block(name) => {
+                    if let Some(cell) = &mut table.cell {
+                        break_line(cell);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        match name {
+            "table" => {
+                self.flush();
+                self.table = Some(Table::default());
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading = name[1..].parse().ok();
+            }
+            "li" => {
+                self.flush();
+                self.list_item = true;
+            }
+            "pre" => {
+                self.flush();
+                self.pre = true;
+            }
+            _ if is_block(name) => self.flush(),
+            _ => {}
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some(at) = self.open.iter().rposition(|(open, _)| open == name) {
+            self.open.truncate(at);
+        }
+
+        if let Some(table) = &mut self.table {
+            match name {
+                "table" if table.nested > 0 => table.nested -= 1,
+                "table" => {
+                    let mut table = self.table.take().unwrap_or_default();
+                    table.finish_row();
+                    self.blocks.extend(table_blocks(table.rows));
+                }
+                "tr" if table.nested == 0 => table.finish_row(),
+                "td" | "th" if table.nested == 0 => table.finish_cell(),
+                _ if is_block(name) => {
+                    if let Some(cell) = &mut table.cell {
+                        break_line(cell);
+                    }
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.flush();
+                self.heading = None;
+            }
+            "li" => {
+                self.flush();
+                self.list_item = false;
+            }
+            "pre" => {
+                self.blocks.extend(plain_text_blocks(&self.text));
+                self.text.clear();
+                self.pre = false;
+            }
+            _ if is_block(name) => self.flush(),
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, raw: &str) {
+        if raw.is_empty() {
+            return;
+        }
+        let text = decode_entities(raw);
+        if let Some(table) = &mut self.table {
+            // Text between cells is formatting whitespace.
+            if let Some(cell) = &mut table.cell {
+                append_collapsed(cell, &text);
+            }
+            return;
+        }
+        if self.pre {
+            self.text.push_str(&text);
+            return;
+        }
+
+        let visible = text.chars().filter(|c| !c.is_whitespace()).count();
+        self.total_chars += visible;
+        if self.open.iter().any(|(_, bold)| *bold) {
+            self.bold_chars += visible;
+        }
+        append_collapsed(&mut self.text, &text);
+    }
+
+    /// Ends the current paragraph.
+    fn flush(&mut self) {
+        if self.pre {
+            return;
+        }
+        let text = self.text.trim().to_string();
+        let all_bold = self.total_chars > 0 && self.bold_chars == self.total_chars;
+        self.text.clear();
+        self.bold_chars = 0;
+        self.total_chars = 0;
+        if text.is_empty() {
+            return;
+        }
+
+        let block = if let Some(level) = self.heading {
+            Block::Heading(level, text)
+        } else if self.list_item {
+            Block::ListItem(text)
+        } else if all_bold && text.chars().count() <= MAX_HEADING_CHARS {
+            Block::Heading(heading_level(&text), text)
+        } else {
+            Block::Paragraph(text)
+        };
+        self.blocks.push(block);
+    }
+}
+
+fn is_block(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "div"
+            | "br"
+            | "hr"
+            | "li"
+            | "ul"
+            | "ol"
+            | "dl"
+            | "dt"
+            | "dd"
+            | "blockquote"
+            | "center"
+            | "section"
+            | "article"
+            | "body"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+    )
+}
+
+/// Bold headings in capitals ("PART I", "ITEM 7.") rank above those in title case.
+fn heading_level(text: &str) -> usize {
+    if text.chars().any(char::is_lowercase) {
+        3
+    } else {
+        2
+    }
+}
+
+fn is_bold(style: &str) -> bool {
+    let style: String = style
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    ["bold", "600", "700", "800", "900"]
+        .iter()
+        .any(|weight| style.contains(&format!("font-weight:{}", weight)))
+}
+
+fn is_hidden(style: &str) -> bool {
+    let style: String = style
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    style.contains("display:none")
+}
+
+/// Reads a quoted attribute value from the inside of a tag.
+pub(crate) fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut search = tag;
+    while let Some(at) = find_ignore_case(search, name) {
+        let before = search[..at].chars().next_back();
+        let after = search[at + name.len()..].trim_start();
+        search = &search[at + name.len()..];
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next()?;
+        if quote == '"' || quote == '\'' {
+            let value = &value[1..];
+            return value.find(quote).map(|end| value[..end].to_string());
+        }
+        let end = value.find(char::is_whitespace).unwrap_or(value.len());
+        return Some(value[..end].to_string());
+    }
+    None
+}
+
+/// Returns what follows the element opened just before `rest`, skipping nested elements of
+/// the same name.
+fn skip_element<'a>(rest: &'a str, name: &str) -> &'a str {
+    let open = format!("<{}", name);
+    let close = format!("</{}", name);
+    let mut depth = 1;
+    let mut position = 0;
+    while depth > 0 {
+        let remaining = &rest[position..];
+        let next_close = find_ignore_case(remaining, &close);
+        let next_open = find_ignore_case(remaining, &open).filter(|&at| {
+            remaining[at + open.len()..].starts_with(|c: char| c.is_whitespace() || c == '>')
+        });
+        match (next_open, next_close) {
+            (Some(o), Some(c)) if o < c => {
+                depth += 1;
+                position += o + open.len();
+            }
+            (_, Some(c)) => {
+                depth -= 1;
+                position += c + close.len();
+            }
+            (_, None) => return "",
+        }
+    }
+    rest[position..]
+        .find('>')
+        .map_or("", |end| &rest[position + end + 1..])
+}
+
+/// Turns table rows into blocks, dropping layout cells and merging split numbers.
+fn table_blocks(rows: Vec<Vec<String>>) -> Vec<Block> {
+    let rows: Vec<Vec<String>> = rows
+        .into_iter()
+        .map(merge_cells)
+        .filter(|row| !row.is_empty())
+        .collect();
+    if rows.iter().all(|row| row.len() <= 1) {
+        return rows
+            .into_iter()
+            .flatten()
+            .map(|cell| Block::Paragraph(cell.replace('\n', " ")))
+            .collect();
+    }
+    vec![Block::Table(rows)]
+}
+
+/// Filers put currency signs, parentheses, and percent signs in cells of their own.
+fn merge_cells(row: Vec<String>) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::new();
+    let mut prefix = String::new();
+    for cell in row {
+        let cell = cell.trim();
+        match cell {
+            "" => {}
+            "$" | "(" | "($" | "$(" => prefix.push_str(cell),
+            ")" | "%" | ")%" | "%)" if !merged.is_empty() => {
+                if let Some(last) = merged.last_mut() {
+                    last.push_str(cell);
+                }
+            }
+            _ => merged.push(format!("{}{}", std::mem::take(&mut prefix), cell)),
+        }
+    }
+    if !prefix.is_empty() {
+        merged.push(prefix);
+    }
+    merged
+}
+
+/// Splits hard-wrapped plain text into blocks.
+fn plain_text_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut preformatted: Vec<&str> = Vec::new();
+
+    fn end_paragraph(blocks: &mut Vec<Block>, paragraph: &mut Vec<&str>) {
+        if paragraph.is_empty() {
+            return;
+        }
+        let text = paragraph.join(" ");
+        paragraph.clear();
+        let letters = text.chars().any(char::is_alphabetic);
+        if letters && text.len() <= MAX_HEADING_CHARS && !text.chars().any(char::is_lowercase) {
+            blocks.push(Block::Heading(2, text));
+        } else {
+            blocks.push(Block::Paragraph(text));
+        }
+    }
+
+    fn end_preformatted(blocks: &mut Vec<Block>, lines: &mut Vec<&str>) {
+        if lines.is_empty() {
+            return;
+        }
+        let indent = lines
+            .iter()
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        let text: Vec<&str> = lines.iter().map(|line| &line[indent..]).collect();
+        blocks.push(Block::Preformatted(text.join("\n")));
+        lines.clear();
+    }
+
+    for line in text.lines() {
+        let line = line.trim_end();
+        let trimmed = line.trim_start();
+        if is_sgml_tag(trimmed) {
+            continue;
+        }
+        if trimmed.is_empty() || is_rule(trimmed) {
+            end_paragraph(&mut blocks, &mut paragraph);
+            if trimmed.is_empty() {
+                end_preformatted(&mut blocks, &mut preformatted);
+            }
+            continue;
+        }
+        if trimmed.contains("   ") {
+            end_paragraph(&mut blocks, &mut paragraph);
+            preformatted.push(line);
+            continue;
+        }
+        end_preformatted(&mut blocks, &mut preformatted);
+        if paragraph
+            .last()
+            .is_some_and(|last| last.len() < MIN_WRAPPED_LINE)
+        {
+            end_paragraph(&mut blocks, &mut paragraph);
+        }
+        paragraph.push(trimmed);
+    }
+    end_paragraph(&mut blocks, &mut paragraph);
+    end_preformatted(&mut blocks, &mut preformatted);
+    blocks
+}
+
+/// `<PAGE>`, `<S>`, `<C>`, and the other markers of SGML text filings.
+fn is_sgml_tag(line: &str) -> bool {
+    line.starts_with('<') && line.ends_with('>') && !line.contains(' ')
+}
+
+/// Lines of dashes, underscores, or equals signs drawn under headings and table columns.
+fn is_rule(line: &str) -> bool {
+    line.len() >= 3
+        && line
+            .chars()
+            .all(|c| matches!(c, '-' | '=' | '_' | '*') || c.is_whitespace())
+}
+
+/// Starts a new line in a table cell.
+fn break_line(cell: &mut String) {
+    let trimmed = cell.trim_end_matches(' ').len();
+    cell.truncate(trimmed);
+    if !cell.is_empty() && !cell.ends_with('\n') {
+        cell.push('\n');
+    }
+}
+
+/// Appends `text` with whitespace runs collapsed to single spaces.
+fn append_collapsed(out: &mut String, text: &str) {
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !out.is_empty() && !out.ends_with([' ', '\n']) {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+pub(crate) fn find_ignore_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Decodes the character references common in EDGAR documents.
+pub(crate) fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "nbsp" => Some(' '),
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "rsquo" | "lsquo" => Some('\''),
+                "ldquo" | "rdquo" => Some('"'),
+                "mdash" => Some('\u{2014}'),
+                "ndash" => Some('\u{2013}'),
+                "bull" => Some('\u{2022}'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(if c == '\u{a0}' { ' ' } else { c });
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hidden_content_is_dropped() {
+        let html = r#"<html><head><title>aapl-20230930</title></head><body>
+            <div style="display: none"><ix:header><ix:hidden>dei</ix:hidden></ix:header></div>
+            <div>Apple&#8217;s <ix:nonNumeric name="dei:EntityRegistrantName">products</ix:nonNumeric> &amp; services</div>
+            <script>var x = "<div>";</script></body></html>"#;
+        assert_eq!(to_text(html), "Apple\u{2019}s products & services");
+    }
+
+    #[test]
+    fn test_headings_and_lists() {
+        let html = "<h2>Overview</h2><p><b>ITEM 1A. RISK FACTORS</b></p>\
+            <p>Risks include:</p><ul><li>Competition</li><li>Supply</li></ul>\
+            <p><b>Not</b> a heading</p>";
+        assert_eq!(
+            to_markdown(html),
+            "## Overview\n\n## ITEM 1A. RISK FACTORS\n\nRisks include:\n\n- Competition\n- Supply\n\nNot a heading"
+        );
+    }
+
+    #[test]
+    fn test_layout_tables_become_paragraphs() {
+        let html = "<table><tr><td></td><td><p>Item 1.</p><p>Business</p></td><td>&#160;</td></tr>\
+            <tr><td>The Company designs smartphones.</td></tr></table>";
+        assert_eq!(
+            to_text(html),
+            "Item 1. Business\n\nThe Company designs smartphones."
+        );
+        assert_eq!(
+            merge_cells(vec![
+                "Net loss".into(),
+                "$".into(),
+                "(1,234".into(),
+                ")".into(),
+                "".into(),
+                "12".into(),
+                "%".into()
+            ]),
+            vec!["Net loss", "$(1,234)", "12%"]
+        );
+    }
+
+    #[test]
+    fn test_wrapped_plain_text() {
+        let text = [
+            "<PAGE>",
+            "                         PART I",
+            "",
+            "ITEM 1.  BUSINESS",
+            "",
+            "The Company was incorporated in California in 1977 and designs,",
+            "manufactures and markets personal computers and related software.",
+            "Its fiscal year ends on the last Friday of September.",
+            "",
+            "--------------------------------------",
+            "                1996        1995",
+            "  Net sales    $9,833     $11,062",
+        ]
+        .join("\n");
+        assert_eq!(
+            to_markdown(&text),
+            [
+                "## PART I",
+                "",
+                "## ITEM 1.  BUSINESS",
+                "",
+                "The Company was incorporated in California in 1977 and designs, manufactures \
+                 and markets personal computers and related software. Its fiscal year ends on \
+                 the last Friday of September.",
+                "",
+                "```",
+                "              1996        1995",
+                "Net sales    $9,833     $11,062",
+                "```",
+            ]
+            .join("\n")
+        );
+    }
+}
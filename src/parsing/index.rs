@@ -1,14 +1,17 @@
-use super::utils::deserialize_str_to_u64;
+use super::intern::StringPool;
+use super::utils::{deserialize_edgar_date, deserialize_str_to_u64, parse_edgar_date};
 use crate::Result;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::io::BufRead;
 use std::str::FromStr;
+use std::sync::Arc;
 
 /// Configuration options for parsing EDGAR index files.
 ///
 /// Provides fine-grained control over parsing behavior including field width specifications,
 /// delimiter choices, entry limits, and explicit index type selection.
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct IndexConfig {
     /// Optional field widths for fixed-width format parsing (in characters).
     pub field_widths: Option<Vec<usize>>,
@@ -21,8 +24,13 @@ pub struct IndexConfig {
 
     /// Explicit index type to use, bypassing auto-detection.
     pub index_type: Option<IndexType>,
+
+    /// Only keep these form types. Form indices are grouped by form type, so rows of other
+    /// forms are skipped without being parsed and reading stops after the last wanted group.
+    pub form_types: Option<Vec<String>>,
 }
 
+#[derive(Debug, Clone)]
 pub struct IndexParser {
     config: IndexConfig,
 }
@@ -43,13 +51,88 @@ pub struct IndexEntry {
     #[serde(deserialize_with = "deserialize_str_to_u64")]
     pub cik: u64,
 
-    /// Date the filing was submitted (format varies by index type).
-    pub date_filed: String,
+    /// Date the filing was submitted.
+    #[serde(deserialize_with = "deserialize_edgar_date")]
+    pub date_filed: NaiveDate,
+
+    /// `date_filed` as written in the index: `20230703` in daily indices, `2023-07-03` in
+    /// quarterly ones.
+    #[serde(default)]
+    pub date_filed_raw: String,
 
     /// Full URL to the filing document on SEC EDGAR.
     pub url: String,
 }
 
+/// An [`IndexEntry`] whose repeated fields are shared through a [`StringPool`].
+///
+/// Company names, form types, and raw dates repeat across thousands of rows in a quarterly
+/// index; this form stores each distinct value once. The URL is unique per filing and stays a `String`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactIndexEntry {
+    pub company_name: Arc<str>,
+    pub form_type: Arc<str>,
+    pub cik: u64,
+    pub date_filed: NaiveDate,
+    pub date_filed_raw: Arc<str>,
+    pub url: String,
+}
+
+impl IndexEntry {
+    /// Accession number of the filing (`0000950170-23-002704`), taken from the URL.
+    ///
+    /// Works for the `.txt` paths in company, form, and master indices and the
+    /// `-index.htm` links in crawler indices. Returns `None` if the URL does not end in an
+    /// accession number.
+    pub fn accession_number(&self) -> Option<&str> {
+        accession_from_url(&self.url)
+    }
+}
+
+/// Extracts the dashed accession number from the last segment of an archive URL.
+fn accession_from_url(url: &str) -> Option<&str> {
+    let file = url.rsplit('/').next()?;
+    let accession = file.get(..20)?;
+    let bytes = accession.as_bytes();
+    let valid = bytes.iter().enumerate().all(|(i, b)| match i {
+        10 | 13 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    valid.then_some(accession)
+}
+
+impl CompactIndexEntry {
+    /// Accession number of the filing; see [`IndexEntry::accession_number`].
+    pub fn accession_number(&self) -> Option<&str> {
+        accession_from_url(&self.url)
+    }
+
+    /// Interns the repeated fields of `entry` in `pool`.
+    pub fn from_entry(entry: IndexEntry, pool: &mut StringPool) -> Self {
+        Self {
+            company_name: pool.intern(&entry.company_name),
+            form_type: pool.intern(&entry.form_type),
+            cik: entry.cik,
+            date_filed: entry.date_filed,
+            date_filed_raw: pool.intern(&entry.date_filed_raw),
+            url: entry.url,
+        }
+    }
+}
+
+impl From<CompactIndexEntry> for IndexEntry {
+    fn from(entry: CompactIndexEntry) -> Self {
+        Self {
+            company_name: entry.company_name.to_string(),
+            form_type: entry.form_type.to_string(),
+            cik: entry.cik,
+            date_filed: entry.date_filed,
+            date_filed_raw: entry.date_filed_raw.to_string(),
+            url: entry.url,
+        }
+    }
+}
+
 /// Types of EDGAR index files with different formats and use cases.
 ///
 /// Each index type uses a slightly different format and field ordering.
@@ -63,6 +146,9 @@ pub enum IndexType {
 
     /// Master index - pipe-delimited format, most reliable for parsing.
     Master,
+
+    /// Form index - grouped by form type, fixed-width format.
+    Form,
 }
 
 impl IndexType {
@@ -70,6 +156,7 @@ impl IndexType {
         ("company", IndexType::Company),
         ("crawler", IndexType::Crawler),
         ("master", IndexType::Master),
+        ("form", IndexType::Form),
     ];
 
     pub fn as_str(&self) -> &'static str {
@@ -108,6 +195,7 @@ impl Default for IndexType {
 /// - Company Index files
 /// - Crawler Index files
 /// - Master Index files
+/// - Form Index files
 ///
 /// It supports both fixed-width and delimiter-based parsing strategies, which can be configured
 /// through the `IndexConfig`.
@@ -139,11 +227,16 @@ impl Default for IndexType {
 ///
 /// The parser automatically detects the index type from the input and applies the appropriate
 /// parsing strategy:
-/// - Fixed-width parsing for Company and Crawler indices (default)
+/// - Fixed-width parsing for Company, Crawler, and Form indices, with the column
+///   boundaries taken from the header so older vintages with other widths parse too
 /// - Delimiter-based parsing for Master indices (using '|' as delimiter)
 /// - Custom parsing based on configuration
 impl IndexParser {
     const ARCHIVES_PREFIX: &'static str = "https://www.sec.gov/Archives/";
+    /// Column starts after the first in current company and crawler indices.
+    const NAME_FIRST_COLUMNS: [usize; 4] = [62, 74, 86, 98];
+    /// Column starts after the first in current form indices.
+    const FORM_FIRST_COLUMNS: [usize; 4] = [12, 74, 86, 98];
 
     /// Creates a new `IndexParser` with the specified configuration.
     ///
@@ -166,6 +259,7 @@ impl IndexParser {
     /// * `Result<IndexType>`: A result containing the detected index type.
     ///   - `Ok(IndexType::Company)`: If the first 10 lines contain the company index header.
     ///   - `Ok(IndexType::Crawler)`: If the first 10 lines contain the crawler index header.
+    ///   - `Ok(IndexType::Form)`: If the first 10 lines contain the form index header.
     ///   - `Ok(IndexType::Master)`: If the first 10 lines contain the master index header.
     ///   - `Ok(IndexType::Crawler)`: If none of the above headers are found, the default index type is crawler.
     ///   - `Err(err)`: If an error occurs while reading from the reader.
@@ -178,6 +272,10 @@ impl IndexParser {
             if line.contains("Daily Crawler Index") {
                 return Ok(IndexType::Crawler);
             }
+            // Quarterly form indices are titled "Master Index ... by Form Type".
+            if line.contains("by Form Type") {
+                return Ok(IndexType::Form);
+            }
             if line.contains("Master Index") || line.contains("XBRL Index") {
                 return Ok(IndexType::Master);
             }
@@ -196,20 +294,81 @@ impl IndexParser {
     ///
     /// * `reader` - A mutable reference to a reader that implements the `BufRead` trait.
     ///
-    fn skip_header_lines<R: BufRead>(&self, reader: &mut R) {
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The lines read, ending with the separator if one was found.
+    fn skip_header_lines<R: BufRead>(&self, reader: &mut R) -> Vec<String> {
         const MAX_TRIES: usize = 50;
+        let mut header = Vec::new();
         let mut lines = reader.lines();
 
         // Try to find separator line
         for _i in 0..MAX_TRIES {
             if let Some(Ok(line)) = lines.next() {
-                if line.contains("---") {
-                    return;
+                let is_separator = line.contains("---");
+                header.push(line);
+                if is_separator {
+                    break;
                 }
             } else {
                 break;
             }
         }
+        header
+    }
+
+    /// Finds where each column after the first starts in a fixed-width index.
+    ///
+    /// Column widths changed between index vintages, so the boundaries are read from the
+    /// separator when it is drawn as one dash run per column, or else from the positions
+    /// of the column labels in the header. Headers that wrap before the last labels yield
+    /// only the leading boundaries. Returns `None` for delimited indices and when neither
+    /// is present.
+    fn detect_columns(header: &[String], index_type: IndexType) -> Option<Vec<usize>> {
+        let labels = match index_type {
+            IndexType::Company => [
+                "Company Name",
+                "Form Type",
+                "CIK",
+                "Date Filed",
+                "File Name",
+            ],
+            IndexType::Crawler => ["Company Name", "Form Type", "CIK", "Date Filed", "URL"],
+            IndexType::Form => [
+                "Form Type",
+                "Company Name",
+                "CIK",
+                "Date Filed",
+                "File Name",
+            ],
+            IndexType::Master => return None,
+        };
+
+        if let Some(separator) = header.last().filter(|line| line.contains("---")) {
+            let runs: Vec<usize> = separator
+                .char_indices()
+                .zip(std::iter::once(' ').chain(separator.chars()))
+                .filter(|&((_, c), prev)| c == '-' && prev != '-')
+                .map(|((at, _), _)| at)
+                .take(labels.len())
+                .collect();
+            if runs.len() > 1 {
+                return Some(runs[1..].to_vec());
+            }
+        }
+
+        header.iter().find_map(|line| {
+            let mut end = line.find(labels[0])? + labels[0].len();
+            let mut columns = Vec::new();
+            for label in &labels[1..] {
+                let Some(offset) = line[end..].find(label) else {
+                    break;
+                };
+                columns.push(end + offset);
+                end += offset + label.len();
+            }
+            (!columns.is_empty()).then_some(columns)
+        })
     }
 
     /// This function reads the input, detects the index type, skips header lines,
@@ -230,7 +389,21 @@ impl IndexParser {
     /// - The index type cannot be detected
     /// - There's an I/O error while reading the input
     /// - Line parsing fails
-    pub fn parse<R: BufRead>(&self, mut reader: R) -> Result<Vec<IndexEntry>> {
+    pub fn parse<R: BufRead>(&self, reader: R) -> Result<Vec<IndexEntry>> {
+        self.parse_iter(reader)?.collect()
+    }
+
+    /// Reads the header and returns an iterator that parses the remaining lines on demand.
+    ///
+    /// Only the current line is held in memory, so a quarterly index can be filtered or
+    /// written out without materializing every entry. `form_types` and `max_entries` from
+    /// the configuration are applied while reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header cannot be read. Each item is an error if its line
+    /// cannot be read or parsed.
+    pub fn parse_iter<R: BufRead>(&self, mut reader: R) -> Result<IndexEntries<R>> {
         // Use provided type or detect
         let index_type = match self.config.index_type {
             Some(t) => t,
@@ -238,24 +411,38 @@ impl IndexParser {
         };
 
         // Skip header until a line containing only dashes ("---") found, 50 lines max
-        self.skip_header_lines(&mut reader);
-
-        let mut entries = Vec::new();
-
-        for line in reader.lines() {
-            let line = line?;
-            if !line.trim().is_empty() && !line.starts_with("---") {
-                if let Some(entry) = self.parse_line(&line, &index_type)? {
-                    entries.push(entry);
-                }
-            }
-        }
-
-        if let Some(max) = self.config.max_entries {
-            entries.truncate(max);
-        }
+        let header = self.skip_header_lines(&mut reader);
+        let columns = Self::detect_columns(&header, index_type);
+
+        Ok(IndexEntries {
+            forms: FormFilter::new(
+                self.config.form_types.clone(),
+                index_type,
+                columns
+                    .as_ref()
+                    .and_then(|columns| columns.first().copied()),
+            ),
+            columns,
+            remaining: self.config.max_entries.unwrap_or(usize::MAX),
+            parser: self.clone(),
+            lines: reader.lines(),
+            index_type,
+        })
+    }
 
-        Ok(entries)
+    /// Like [`parse`](Self::parse), but interns repeated fields in `pool` as each line is read.
+    ///
+    /// Only one line's worth of owned strings exists at a time, so peak memory stays close to
+    /// the size of the pooled result. Pass the same pool for several files to share values
+    /// between them.
+    pub fn parse_interned<R: BufRead>(
+        &self,
+        reader: R,
+        pool: &mut StringPool,
+    ) -> Result<Vec<CompactIndexEntry>> {
+        self.parse_iter(reader)?
+            .map(|entry| entry.map(|entry| CompactIndexEntry::from_entry(entry, pool)))
+            .collect()
     }
 
     /// Parses a single line from the index file into an `IndexEntry`.
@@ -264,12 +451,18 @@ impl IndexParser {
     ///
     /// * `line` - A single line from the index file
     /// * `index_type` - The type of index being parsed
+    /// * `columns` - Where each column after the first starts in a fixed-width index, if detected
     ///
     /// # Returns
     ///
     /// * `Result<Option<IndexEntry>>` - The parsed entry, or None if the line is empty
     ///
-    fn parse_line(&self, line: &str, index_type: &IndexType) -> Result<Option<IndexEntry>> {
+    fn parse_line(
+        &self,
+        line: &str,
+        index_type: &IndexType,
+        columns: Option<&[usize]>,
+    ) -> Result<Option<IndexEntry>> {
         if line.trim().is_empty() {
             return Ok(None);
         }
@@ -285,7 +478,10 @@ impl IndexParser {
             // Use different parsing strategies based on index type
             match index_type {
                 IndexType::Company | IndexType::Crawler => {
-                    self.parse_fixed_width(line, &[62, 12, 12, 12, 74])
+                    Self::parse_columns(line, columns.unwrap_or(&Self::NAME_FIRST_COLUMNS))
+                }
+                IndexType::Form => {
+                    Self::parse_columns(line, columns.unwrap_or(&Self::FORM_FIRST_COLUMNS))
                 }
                 IndexType::Master => {
                     // Split by | for master/XBRL index
@@ -321,17 +517,28 @@ impl IndexParser {
                 fields[3].clone(),
                 fields.get(4).map(|s| Self::ARCHIVES_PREFIX.to_string() + s),
             ),
+            IndexType::Form => (
+                fields[1].clone(),
+                fields[0].clone(),
+                fields[2].trim().trim_start_matches('0'),
+                fields[3].clone(),
+                fields.get(4).map(|s| Self::ARCHIVES_PREFIX.to_string() + s),
+            ),
         };
 
         let cik = cik_str
             .parse::<u64>()
             .map_err(|_| crate::EdgarError::InvalidFormat(format!("Invalid CIK: {}", cik_str)))?;
+        let date = parse_edgar_date(&date_filed).ok_or_else(|| {
+            crate::EdgarError::InvalidFormat(format!("Invalid date filed: {}", date_filed))
+        })?;
 
         Ok(Some(IndexEntry {
             company_name,
             form_type,
             cik,
-            date_filed,
+            date_filed: date,
+            date_filed_raw: date_filed,
             url: path_or_url.unwrap_or_default(),
         }))
     }
@@ -367,6 +574,158 @@ impl IndexParser {
 
         result
     }
+
+    /// Splits a fixed-width company, crawler, or form index line into fields.
+    ///
+    /// Each field is sliced at the column starts in `columns`, counted in characters so
+    /// non-ASCII company names do not shift later fields. Anything past the last known
+    /// column is split on whitespace; only the CIK, date, and file name can lie there, and
+    /// none of them contain spaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `line` - The line to parse
+    /// * `columns` - Where each column after the first starts
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<String>` - The parsed fields, or fewer than four if the line is incomplete
+    ///
+    fn parse_columns(line: &str, columns: &[usize]) -> Vec<String> {
+        let byte_at = |column: usize| {
+            line.char_indices()
+                .nth(column)
+                .map_or(line.len(), |(at, _)| at)
+        };
+
+        let mut fields = Vec::with_capacity(columns.len() + 2);
+        let mut start = 0;
+        for &column in columns {
+            let end = byte_at(column).max(start);
+            fields.push(line[start..end].trim().to_string());
+            start = end;
+        }
+        fields.extend(line[start..].split_whitespace().map(str::to_string));
+
+        while fields.last().is_some_and(String::is_empty) {
+            fields.pop();
+        }
+        fields
+    }
+}
+
+/// Entries parsed lazily from an index file; created by [`IndexParser::parse_iter`].
+pub struct IndexEntries<R> {
+    parser: IndexParser,
+    lines: std::io::Lines<R>,
+    index_type: IndexType,
+    /// Column starts after the first in fixed-width indices, when the header shows them
+    columns: Option<Vec<usize>>,
+    forms: FormFilter,
+    remaining: usize,
+}
+
+impl<R: BufRead> Iterator for IndexEntries<R> {
+    type Item = Result<IndexEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e.into())),
+            };
+            match self.forms.check(&line) {
+                FormMatch::Keep => {}
+                FormMatch::Skip => continue,
+                FormMatch::Done => break,
+            }
+            if line.trim().is_empty() || line.starts_with("---") {
+                continue;
+            }
+            match self
+                .parser
+                .parse_line(&line, &self.index_type, self.columns.as_deref())
+            {
+                Ok(Some(entry)) if self.forms.accepts(&entry) => {
+                    self.remaining -= 1;
+                    return Some(Ok(entry));
+                }
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.remaining = 0;
+        None
+    }
+}
+
+/// Outcome of [`FormFilter::check`] for a raw line.
+enum FormMatch {
+    Keep,
+    Skip,
+    Done,
+}
+
+/// Applies `IndexConfig::form_types` while reading.
+///
+/// For form indices the form type is the first column, so unwanted rows are rejected from
+/// the raw line and reading stops once every wanted group has ended. Other index types are
+/// filtered after parsing.
+struct FormFilter {
+    forms: Option<Vec<String>>,
+    grouped: bool,
+    form_width: usize,
+    current: Option<String>,
+    remaining: usize,
+}
+
+impl FormFilter {
+    fn new(forms: Option<Vec<String>>, index_type: IndexType, form_width: Option<usize>) -> Self {
+        Self {
+            remaining: forms.as_ref().map_or(0, Vec::len),
+            forms,
+            grouped: index_type == IndexType::Form,
+            form_width: form_width.unwrap_or(IndexParser::FORM_FIRST_COLUMNS[0]),
+            current: None,
+        }
+    }
+
+    fn wants(&self, form: &str) -> bool {
+        self.forms
+            .as_ref()
+            .is_none_or(|forms| forms.iter().any(|f| f.trim() == form))
+    }
+
+    fn check(&mut self, line: &str) -> FormMatch {
+        if !self.grouped || self.forms.is_none() || line.starts_with("---") {
+            return FormMatch::Keep;
+        }
+        let Some(form) = line
+            .get(..self.form_width)
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+        else {
+            return FormMatch::Skip;
+        };
+        if self.current.as_deref() != Some(form) {
+            if self.current.as_deref().is_some_and(|prev| self.wants(prev)) {
+                self.remaining = self.remaining.saturating_sub(1);
+                if self.remaining == 0 {
+                    return FormMatch::Done;
+                }
+            }
+            self.current = Some(form.to_string());
+        }
+        if self.wants(form) {
+            FormMatch::Keep
+        } else {
+            FormMatch::Skip
+        }
+    }
+
+    fn accepts(&self, entry: &IndexEntry) -> bool {
+        self.wants(entry.form_type.trim())
+    }
 }
 
 #[cfg(test)]
@@ -429,7 +788,53 @@ CIK|Company Name|Form Type|Date Filed|Filename
         assert_eq!(
             entry.url,
             "https://www.sec.gov/Archives/edgar/data/1000045/0000950170-23-002704.txt"
-        )
+        );
+        assert_eq!(entry.accession_number(), Some("0000950170-23-002704"));
+    }
+
+    #[test]
+    fn test_accession_from_url() {
+        assert_eq!(
+            accession_from_url(
+                "https://www.sec.gov/Archives/edgar/data/320193/000032019323000077/0000320193-23-000077-index.htm"
+            ),
+            Some("0000320193-23-000077")
+        );
+        assert_eq!(
+            accession_from_url("https://www.sec.gov/Archives/edgar/data/320193/"),
+            None
+        );
+        assert_eq!(accession_from_url("edgar/data/1/readme.txt-and-more"), None);
+    }
+
+    #[test]
+    fn test_parse_interned_shares_repeated_fields() {
+        let parser = IndexParser::new(IndexConfig::default());
+        let content = "Description:           Master Index of EDGAR Dissemination Feed
+CIK|Company Name|Form Type|Date Filed|Filename
+--------------------------------------------------------------------------------
+1000045|NICHOLAS FINANCIAL INC|10-Q|2023-02-14|edgar/data/1000045/0000950170-23-002704.txt
+1000045|NICHOLAS FINANCIAL INC|8-K|2023-02-14|edgar/data/1000045/0000950170-23-002705.txt
+1000097|KINGDON CAPITAL MANAGEMENT, L.L.C.|10-Q|2023-02-14|edgar/data/1000097/0000919574-23-000859.txt
+";
+        let mut pool = StringPool::new();
+        let entries = parser
+            .parse_interned(BufReader::new(content.as_bytes()), &mut pool)
+            .unwrap();
+
+        assert_eq!(entries.len(), 3);
+        assert!(Arc::ptr_eq(&entries[0].form_type, &entries[2].form_type));
+        assert!(Arc::ptr_eq(
+            &entries[0].date_filed_raw,
+            &entries[1].date_filed_raw
+        ));
+        assert_eq!(pool.len(), 5);
+        assert!(pool.stats().bytes_saved > 0);
+
+        let plain = parser.parse(BufReader::new(content.as_bytes())).unwrap();
+        let restored: IndexEntry = entries[1].clone().into();
+        assert_eq!(restored.url, plain[1].url);
+        assert_eq!(restored.form_type, plain[1].form_type);
     }
 
     #[test]
@@ -438,12 +843,15 @@ CIK|Company Name|Form Type|Date Filed|Filename
         let line = "1000045|NICHOLAS FINANCIAL INC|10-Q|2023-02-14|edgar/data/1000045/0000950170-23-002704.txt";
         let index_type = IndexType::Master;
 
-        let entry = parser.parse_line(line, &index_type).unwrap().unwrap();
+        let entry = parser.parse_line(line, &index_type, None).unwrap().unwrap();
 
         assert_eq!(entry.cik, 1000045);
         assert_eq!(entry.company_name.trim(), "NICHOLAS FINANCIAL INC");
         assert_eq!(entry.form_type.trim(), "10-Q");
-        assert_eq!(entry.date_filed, "2023-02-14");
+        assert_eq!(
+            entry.date_filed,
+            NaiveDate::from_ymd_opt(2023, 2, 14).unwrap()
+        );
         assert_eq!(
             entry.url,
             "https://www.sec.gov/Archives/edgar/data/1000045/0000950170-23-002704.txt"
@@ -456,30 +864,108 @@ CIK|Company Name|Form Type|Date Filed|Filename
         let line = "3J LLC                                                        D           1975393     20230703    edgar/data/1975393/0001975393-23-000001.txt";
         let index_type = IndexType::Company;
 
-        let entry = parser.parse_line(line, &index_type).unwrap().unwrap();
+        let entry = parser.parse_line(line, &index_type, None).unwrap().unwrap();
 
         assert_eq!(entry.company_name.trim(), "3J LLC");
         assert_eq!(entry.form_type.trim(), "D");
         assert_eq!(entry.cik, 1975393);
-        assert_eq!(entry.date_filed, "20230703");
+        assert_eq!(
+            entry.date_filed,
+            NaiveDate::from_ymd_opt(2023, 7, 3).unwrap()
+        );
+        assert_eq!(entry.date_filed_raw, "20230703");
         assert_eq!(
             entry.url,
             "https://www.sec.gov/Archives/edgar/data/1975393/0001975393-23-000001.txt"
         );
     }
 
+    #[test]
+    fn test_column_boundaries_follow_header() {
+        let parser = IndexParser::new(IndexConfig::default());
+        let company = [
+            "Description:           Daily Index of EDGAR Dissemination Feed by Company Name",
+            "",
+            "Company Name                                    Form Type  CIK        Date Filed File Name",
+            "------------------------------------------------------------------------------------------",
+            "AMERICAN ELECTRIC POWER CO INC                  10-Q       4904       19941114   edgar/data/4904/0000004904-94-000031.txt",
+            "AMERICAN EXPRESS CO                             S-8 POS    4962       19941114   edgar/data/4962/0000950123-94-001894.txt",
+        ]
+        .join("\n");
+        let entries = parser.parse(BufReader::new(company.as_bytes())).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].company_name, "AMERICAN ELECTRIC POWER CO INC");
+        assert_eq!(entries[1].form_type, "S-8 POS");
+        assert_eq!(entries[1].cik, 4962);
+        assert_eq!(
+            entries[1].url,
+            "https://www.sec.gov/Archives/edgar/data/4962/0000950123-94-001894.txt"
+        );
+
+        let form = [
+            "Description:           Master Index of EDGAR Dissemination Feed by Form Type",
+            "",
+            "Form Type    Company Name                         CIK      Date Filed  File Name",
+            "-----------  -----------------------------------  -------  ----------  ---------",
+            "10-K405      AAON INC                             824142   1995-03-30  edgar/data/824142/0000824142-95-000002.txt",
+        ]
+        .join("\n");
+        let entries = parser.parse(BufReader::new(form.as_bytes())).unwrap();
+        assert_eq!(entries[0].form_type, "10-K405");
+        assert_eq!(entries[0].company_name, "AAON INC");
+        assert_eq!(entries[0].cik, 824142);
+    }
+
+    #[test]
+    fn test_form_types_with_spaces_keep_their_columns() {
+        let parser = IndexParser::new(IndexConfig::default());
+        let form = [
+            "Description:           Master Index of EDGAR Dissemination Feed by Form Type",
+            "",
+            "Form Type   Company Name                                                  CIK         Date Filed  File Name",
+            "---------------------------------------------------------------------------------------------------------------",
+            "SC 13G/A    ACME HOLDINGS 2 LP                                            1826011     2023-07-03  edgar/data/1826011/0001193125-23-181106.txt",
+            "10-K 405    SOCIÉTÉ GÉNÉRALE                                              824142      1995-03-30  edgar/data/824142/0000824142-95-000002.txt",
+        ]
+        .join("\n");
+        let entries = parser.parse(BufReader::new(form.as_bytes())).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].form_type, "SC 13G/A");
+        assert_eq!(entries[0].company_name, "ACME HOLDINGS 2 LP");
+        assert_eq!(entries[0].cik, 1826011);
+        assert_eq!(entries[1].form_type, "10-K 405");
+        assert_eq!(entries[1].company_name, "SOCIÉTÉ GÉNÉRALE");
+        assert_eq!(entries[1].date_filed_raw, "1995-03-30");
+        assert_eq!(
+            entries[1].url,
+            "https://www.sec.gov/Archives/edgar/data/824142/0000824142-95-000002.txt"
+        );
+
+        let filtered = IndexParser::new(IndexConfig {
+            form_types: Some(vec!["10-K 405".to_string()]),
+            ..IndexConfig::default()
+        })
+        .parse(BufReader::new(form.as_bytes()))
+        .unwrap();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].cik, 824142);
+    }
+
     #[test]
     fn test_parse_crawler_index_line() {
         let parser = IndexParser::new(IndexConfig::default());
         let line = "EXAMPLE COMPANY                                               10-K        1234567     2023-07-03  https://www.sec.gov/Archives/edgar/data/1234567/000123456723000001.txt";
         let index_type = IndexType::Crawler;
 
-        let entry = parser.parse_line(line, &index_type).unwrap().unwrap();
+        let entry = parser.parse_line(line, &index_type, None).unwrap().unwrap();
 
         assert_eq!(entry.company_name.trim(), "EXAMPLE COMPANY");
         assert_eq!(entry.form_type.trim(), "10-K");
         assert_eq!(entry.cik, 1234567);
-        assert_eq!(entry.date_filed, "2023-07-03");
+        assert_eq!(
+            entry.date_filed,
+            NaiveDate::from_ymd_opt(2023, 7, 3).unwrap()
+        );
         assert_eq!(
             entry.url,
             "https://www.sec.gov/Archives/edgar/data/1234567/000123456723000001.txt"
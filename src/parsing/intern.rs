@@ -0,0 +1,108 @@
+//! String interning for bulk parsing.
+//!
+//! A quarterly full index lists hundreds of thousands of filings, but only a few hundred
+//! distinct form types, ~60 filing dates, and far fewer company names than rows. Keeping each
+//! of those as its own `String` dominates the memory of a multi-quarter load. A
+//! [`StringPool`] hands out shared `Arc<str>` handles instead, so every repeated value is
+//! stored once.
+//!
+//! Pools are opt-in: reuse one pool across quarters to share values between them, and read
+//! [`StringPool::stats`] to see how much was saved.
+//!
+//! ```rust
+//! use edgarkit::parsing::intern::StringPool;
+//! use std::sync::Arc;
+//!
+//! let mut pool = StringPool::new();
+//! let a = pool.intern("10-K");
+//! let b = pool.intern("10-K");
+//! assert!(Arc::ptr_eq(&a, &b));
+//! assert_eq!(pool.stats().bytes_saved, 4);
+//! ```
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Deduplicating store of `Arc<str>` values.
+#[derive(Debug, Default, Clone)]
+pub struct StringPool {
+    strings: HashSet<Arc<str>>,
+    stats: PoolStats,
+}
+
+/// Counters describing how effective a [`StringPool`] has been.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of `intern` calls.
+    pub lookups: usize,
+
+    /// Number of distinct strings held by the pool.
+    pub unique: usize,
+
+    /// Bytes held by the pool's distinct strings.
+    pub stored_bytes: usize,
+
+    /// String bytes that would have been allocated again without interning.
+    pub bytes_saved: usize,
+}
+
+impl StringPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared copy of `value`, adding it to the pool on first use.
+    pub fn intern(&mut self, value: &str) -> Arc<str> {
+        self.stats.lookups += 1;
+        if let Some(existing) = self.strings.get(value) {
+            self.stats.bytes_saved += value.len();
+            return existing.clone();
+        }
+
+        let shared: Arc<str> = Arc::from(value);
+        self.strings.insert(shared.clone());
+        self.stats.unique += 1;
+        self.stats.stored_bytes += value.len();
+        shared
+    }
+
+    /// Number of distinct strings in the pool.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        self.stats
+    }
+
+    /// Drops every string not referenced outside the pool.
+    ///
+    /// Useful between quarters when earlier entries have been released.
+    pub fn shrink(&mut self) {
+        self.strings.retain(|s| Arc::strong_count(s) > 1);
+        self.stats.unique = self.strings.len();
+        self.stats.stored_bytes = self.strings.iter().map(|s| s.len()).sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_keeps_referenced_strings() {
+        let mut pool = StringPool::new();
+        let kept = pool.intern("8-K");
+        pool.intern("S-1");
+        assert_eq!(pool.len(), 2);
+
+        pool.shrink();
+        assert_eq!(pool.len(), 1);
+        assert!(Arc::ptr_eq(&kept, &pool.intern("8-K")));
+        assert_eq!(pool.stats().stored_bytes, 3);
+    }
+}
@@ -0,0 +1,482 @@
+//! Inline XBRL fact extraction from primary documents.
+//!
+//! Since 2019 most 10-K, 10-Q, and 8-K primary documents are inline XBRL: ordinary HTML in
+//! which each reported value is wrapped in an `ix:nonFraction` (numbers) or `ix:nonNumeric`
+//! (text, dates, text blocks) element naming its concept and context. The contexts (period
+//! and dimensions) and units live in the hidden `ix:header`. Reading them straight from the
+//! document gives as-filed values the moment a filing is accepted, before the company facts
+//! and frames APIs pick it up.
+//!
+//! [`parse_inline_xbrl`] returns every tagged fact in document order with its context and
+//! unit resolved. Numbers are scaled (`scale="6"` turns `1,234` into `1234000000`) and
+//! signed (`sign="-"`); units are named the way the company facts API names them (`USD`,
+//! `shares`, `USD/shares`). Text facts split across `ix:continuation` elements are joined.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::ixbrl::{InlinePeriod, parse_inline_xbrl};
+//!
+//! let html = r#"<html><body>
+//!     <div style="display:none"><ix:header><ix:resources>
+//!         <xbrli:context id="c-1"><xbrli:entity><xbrli:identifier scheme="http://www.sec.gov/CIK">0000320193</xbrli:identifier></xbrli:entity>
+//!             <xbrli:period><xbrli:startDate>2023-10-01</xbrli:startDate><xbrli:endDate>2023-12-30</xbrli:endDate></xbrli:period></xbrli:context>
+//!         <xbrli:unit id="usd"><xbrli:measure>iso4217:USD</xbrli:measure></xbrli:unit>
+//!     </ix:resources></ix:header></div>
+//!     <td>Net sales</td><td>$ <ix:nonFraction name="us-gaap:Revenues" contextRef="c-1" unitRef="usd"
+//!         decimals="-6" scale="6" format="ixt:num-dot-decimal">119,575</ix:nonFraction></td>
+//! </body></html>"#;
+//!
+//! let facts = parse_inline_xbrl(html);
+//! assert_eq!(facts[0].name, "us-gaap:Revenues");
+//! assert_eq!(facts[0].value, Some(119_575_000_000.0));
+//! assert_eq!(facts[0].unit.as_deref(), Some("USD"));
+//! assert!(matches!(facts[0].period, Some(InlinePeriod::Duration { .. })));
+//! ```
+
+use super::html::{attribute, decode_entities, find_ignore_case, to_text};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// The period a fact's context refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InlinePeriod {
+    /// A point in time, for balance sheet values
+    Instant(NaiveDate),
+    /// A span of time, for flows such as revenue
+    Duration { start: NaiveDate, end: NaiveDate },
+}
+
+impl InlinePeriod {
+    /// Last day of the period.
+    pub fn end(&self) -> NaiveDate {
+        match self {
+            InlinePeriod::Instant(date) => *date,
+            InlinePeriod::Duration { end, .. } => *end,
+        }
+    }
+}
+
+/// One tagged fact from an inline XBRL document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InlineFact {
+    /// Qualified concept name, e.g. `us-gaap:Revenues`
+    pub name: String,
+
+    /// The fact's `id` attribute, if any
+    pub id: Option<String>,
+
+    pub context_ref: String,
+
+    /// Whether the fact was tagged `ix:nonFraction`
+    pub numeric: bool,
+
+    /// Numeric value after applying `scale` and `sign`; `None` for text facts, nil facts,
+    /// and numbers in a format that could not be read
+    pub value: Option<f64>,
+
+    /// Text as displayed in the document; for text blocks, the block rendered as plain text
+    pub text: String,
+
+    pub unit_ref: Option<String>,
+
+    /// Unit resolved from `unit_ref`, e.g. `USD` or `USD/shares`
+    pub unit: Option<String>,
+
+    /// The `decimals` attribute, e.g. `-6` or `INF`
+    pub decimals: Option<String>,
+
+    /// Power of ten the displayed number was scaled by
+    pub scale: i32,
+
+    /// Transformation format, e.g. `ixt:num-dot-decimal`
+    pub format: Option<String>,
+
+    /// Whether the fact was reported as nil (`xsi:nil="true"`)
+    pub is_nil: bool,
+
+    /// Period of the fact's context, if the context was found
+    pub period: Option<InlinePeriod>,
+
+    /// Dimension/member pairs of the context's segment, e.g.
+    /// `("srt:ProductOrServiceAxis", "us-gaap:ProductMember")`
+    pub dimensions: Vec<(String, String)>,
+}
+
+impl InlineFact {
+    /// Taxonomy prefix of the concept, e.g. `us-gaap`.
+    pub fn prefix(&self) -> &str {
+        self.name.split_once(':').map_or("", |(prefix, _)| prefix)
+    }
+
+    /// Concept name without its prefix, e.g. `Revenues`.
+    pub fn local_name(&self) -> &str {
+        self.name
+            .split_once(':')
+            .map_or(&self.name, |(_, name)| name)
+    }
+}
+
+#[derive(Default)]
+struct Context {
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+    instant: Option<NaiveDate>,
+    dimensions: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct Unit {
+    numerator: Vec<String>,
+    denominator: Vec<String>,
+}
+
+/// A fact element whose closing tag has not been reached yet.
+struct OpenFact<'a> {
+    local: String,
+    attributes: &'a str,
+    content_start: usize,
+    position: usize,
+}
+
+/// A fact or continuation with its raw content.
+struct RawElement<'a> {
+    attributes: &'a str,
+    content: &'a str,
+    position: usize,
+}
+
+/// Extracts every `ix:nonFraction` and `ix:nonNumeric` fact from an inline XBRL document,
+/// in document order.
+///
+/// Documents without inline XBRL return an empty list.
+pub fn parse_inline_xbrl(html: &str) -> Vec<InlineFact> {
+    let mut contexts: HashMap<String, Context> = HashMap::new();
+    let mut units: HashMap<String, Unit> = HashMap::new();
+    let mut context: Option<(String, Context)> = None;
+    let mut unit: Option<(String, Unit, bool)> = None;
+    let mut open: Vec<OpenFact> = Vec::new();
+    let mut numbers: Vec<RawElement> = Vec::new();
+    let mut texts: Vec<RawElement> = Vec::new();
+    let mut continuations: HashMap<String, RawElement> = HashMap::new();
+
+    let mut position = 0;
+    while let Some(offset) = html[position..].find('<') {
+        let start = position + offset;
+        let rest = &html[start..];
+        if rest.starts_with("<!--") {
+            position = rest.find("-->").map_or(html.len(), |end| start + end + 3);
+            continue;
+        }
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        position = start + end + 1;
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        let closing = tag.starts_with('/');
+        let body = tag.trim_start_matches('/');
+        let self_closing = body.ends_with('/');
+        let name = body
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        let local = name.rsplit(':').next().unwrap_or_default();
+
+        match (local, closing) {
+            ("nonfraction" | "nonnumeric" | "continuation", false) if self_closing => {
+                let element = RawElement {
+                    attributes: body,
+                    content: "",
+                    position: start,
+                };
+                match local {
+                    "nonfraction" => numbers.push(element),
+                    "nonnumeric" => texts.push(element),
+                    _ => {}
+                }
+            }
+            ("nonfraction" | "nonnumeric" | "continuation", false) => open.push(OpenFact {
+                local: local.to_string(),
+                attributes: body,
+                content_start: position,
+                position: start,
+            }),
+            ("nonfraction" | "nonnumeric" | "continuation", true) => {
+                let Some(at) = open.iter().rposition(|fact| fact.local == local) else {
+                    continue;
+                };
+                let fact = open.remove(at);
+                let element = RawElement {
+                    attributes: fact.attributes,
+                    content: &html[fact.content_start..start],
+                    position: fact.position,
+                };
+                match local {
+                    "nonfraction" => numbers.push(element),
+                    "nonnumeric" => texts.push(element),
+                    _ => {
+                        if let Some(id) = attribute(element.attributes, "id") {
+                            continuations.insert(id, element);
+                        }
+                    }
+                }
+            }
+            ("context", false) => {
+                context = attribute(body, "id").map(|id| (id, Context::default()));
+            }
+            ("context", true) => {
+                if let Some((id, finished)) = context.take() {
+                    contexts.insert(id, finished);
+                }
+            }
+            ("startdate" | "enddate" | "instant", false) => {
+                if let Some((_, context)) = &mut context {
+                    let date =
+                        NaiveDate::parse_from_str(text_after(html, position).trim(), "%Y-%m-%d")
+                            .ok();
+                    match local {
+                        "startdate" => context.start = date,
+                        "enddate" => context.end = date,
+                        _ => context.instant = date,
+                    }
+                }
+            }
+            ("explicitmember" | "typedmember", false) => {
+                if let (Some((_, context)), Some(dimension)) =
+                    (&mut context, attribute(body, "dimension"))
+                {
+                    let close = format!("</{}", name);
+                    let inner = find_ignore_case(&html[position..], &close)
+                        .map_or("", |end| &html[position..position + end]);
+                    context.dimensions.push((dimension, strip_tags(inner)));
+                }
+            }
+            ("unit", false) => {
+                unit = attribute(body, "id").map(|id| (id, Unit::default(), false));
+            }
+            ("unit", true) => {
+                if let Some((id, finished, _)) = unit.take() {
+                    units.insert(id, finished);
+                }
+            }
+            ("unitdenominator", false) => {
+                if let Some((_, _, denominator)) = &mut unit {
+                    *denominator = true;
+                }
+            }
+            ("measure", false) => {
+                if let Some((_, unit, denominator)) = &mut unit {
+                    let measure = text_after(html, position).trim();
+                    let measure = measure.rsplit(':').next().unwrap_or(measure).to_string();
+                    if *denominator {
+                        unit.denominator.push(measure);
+                    } else {
+                        unit.numerator.push(measure);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut facts: Vec<(usize, InlineFact)> = numbers
+        .into_iter()
+        .map(|element| (element.position, fact(&element, true, &contexts, &units)))
+        .chain(texts.into_iter().map(|element| {
+            let mut fact = fact(&element, false, &contexts, &units);
+            if let Some(next) = attribute(element.attributes, "continuedAt") {
+                append_continuations(&mut fact.text, &next, &continuations);
+            }
+            (element.position, fact)
+        }))
+        .collect();
+    facts.sort_by_key(|(position, _)| *position);
+    facts.into_iter().map(|(_, fact)| fact).collect()
+}
+
+fn fact(
+    element: &RawElement,
+    numeric: bool,
+    contexts: &HashMap<String, Context>,
+    units: &HashMap<String, Unit>,
+) -> InlineFact {
+    let attributes = element.attributes;
+    let context_ref = attribute(attributes, "contextRef").unwrap_or_default();
+    let context = contexts.get(&context_ref);
+    let unit_ref = attribute(attributes, "unitRef");
+    let format = attribute(attributes, "format");
+    let scale = attribute(attributes, "scale")
+        .and_then(|scale| scale.trim().parse().ok())
+        .unwrap_or(0);
+    let is_nil = attribute(attributes, "xsi:nil").is_some_and(|nil| nil.trim() == "true");
+
+    let text = if numeric {
+        strip_tags(element.content)
+    } else {
+        to_text(element.content)
+    };
+    let value = (numeric && !is_nil)
+        .then(|| parse_number(&text, format.as_deref()))
+        .flatten()
+        .map(|value| {
+            let value = value * 10f64.powi(scale);
+            if attribute(attributes, "sign").as_deref() == Some("-") {
+                -value
+            } else {
+                value
+            }
+        });
+
+    InlineFact {
+        name: attribute(attributes, "name").unwrap_or_default(),
+        id: attribute(attributes, "id"),
+        context_ref,
+        numeric,
+        value,
+        text,
+        unit: unit_ref
+            .as_ref()
+            .and_then(|unit_ref| units.get(unit_ref))
+            .map(unit_name),
+        unit_ref,
+        decimals: attribute(attributes, "decimals"),
+        scale,
+        format,
+        is_nil,
+        period: context.and_then(
+            |context| match (context.instant, context.start, context.end) {
+                (Some(instant), _, _) => Some(InlinePeriod::Instant(instant)),
+                (None, Some(start), Some(end)) => Some(InlinePeriod::Duration { start, end }),
+                _ => None,
+            },
+        ),
+        dimensions: context
+            .map(|context| context.dimensions.clone())
+            .unwrap_or_default(),
+    }
+}
+
+/// Names a unit the way the company facts API does: measures joined with `*`, and a
+/// denominator after `/`.
+fn unit_name(unit: &Unit) -> String {
+    let numerator = unit.numerator.join("*");
+    if unit.denominator.is_empty() {
+        numerator
+    } else {
+        format!("{}/{}", numerator, unit.denominator.join("*"))
+    }
+}
+
+/// Follows a `continuedAt` chain, appending each continuation's text as a new paragraph.
+fn append_continuations(
+    text: &mut String,
+    first: &str,
+    continuations: &HashMap<String, RawElement>,
+) {
+    let mut seen = HashSet::new();
+    let mut next = Some(first.to_string());
+    while let Some(id) = next.take() {
+        if !seen.insert(id.clone()) {
+            break;
+        }
+        let Some(continuation) = continuations.get(&id) else {
+            break;
+        };
+        let more = to_text(continuation.content);
+        if !more.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&more);
+        }
+        next = attribute(continuation.attributes, "continuedAt");
+    }
+}
+
+/// Reads a displayed number according to its `ixt:` transformation format.
+///
+/// Handles the digit formats filers use (`num-dot-decimal`, `num-comma-decimal`, and their
+/// older `numdotdecimal`/`numcommadecimal` names) and the zero formats (`fixed-zero`,
+/// `zerodash`) that display a dash. Word formats such as `num-word-en` return `None`.
+fn parse_number(text: &str, format: Option<&str>) -> Option<f64> {
+    let format = format
+        .map(|format| {
+            format
+                .rsplit(':')
+                .next()
+                .unwrap_or(format)
+                .to_ascii_lowercase()
+        })
+        .unwrap_or_default();
+    if format.contains("zero") {
+        return Some(0.0);
+    }
+    if format.contains("word") {
+        return None;
+    }
+    let comma_decimal = format.contains("comma-decimal") || format.contains("commadecimal");
+    let number: String = text
+        .chars()
+        .filter_map(|c| match c {
+            '0'..='9' => Some(c),
+            ',' if comma_decimal => Some('.'),
+            '.' if !comma_decimal => Some('.'),
+            _ => None,
+        })
+        .collect();
+    number.parse().ok()
+}
+
+/// Text up to the next tag.
+fn text_after(html: &str, position: usize) -> &str {
+    let rest = &html[position..];
+    rest.find('<').map_or(rest, |end| &rest[..end])
+}
+
+/// Removes tags, decodes entities, and collapses whitespace.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        text.push(' ');
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+    decode_entities(&text)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_number_formats() {
+        assert_eq!(
+            parse_number("1,234.5", Some("ixt:num-dot-decimal")),
+            Some(1234.5)
+        );
+        assert_eq!(
+            parse_number("1.234,5", Some("ixt:num-comma-decimal")),
+            Some(1234.5)
+        );
+        assert_eq!(
+            parse_number("1.234,5", Some("ixt:numcommadecimal")),
+            Some(1234.5)
+        );
+        assert_eq!(parse_number("\u{2014}", Some("ixt:fixed-zero")), Some(0.0));
+        assert_eq!(parse_number("-", Some("ixt-sec:zerodash")), Some(0.0));
+        assert_eq!(parse_number("twelve", Some("ixt-sec:num-word-en")), None);
+        assert_eq!(parse_number("(42)", None), Some(42.0));
+    }
+}
@@ -0,0 +1,497 @@
+//! Lenient and strict deserialization of SEC JSON payloads.
+//!
+//! The submissions and XBRL APIs change without notice: new fields appear, and fields that
+//! were always strings turn up as `null` or numbers for a handful of filers. With serde's
+//! usual all-or-nothing behavior, one such value fails the whole `Submission` or
+//! `CompanyFacts`. [`ParseMode::Lenient`] repairs the payload instead and reports what it
+//! changed in a [`ParseReport`]:
+//!
+//! - A numeric string where a number is expected is parsed (`"1024"` becomes `1024`), and a
+//!   number where a string is expected is written out (`7` becomes `"7"`).
+//! - Any other value of the wrong type is replaced with the first of `null`, an empty value
+//!   of the type its neighbors in the same array have, `""`, `0`, `false`, `[]`, or `{}` that
+//!   the target type accepts.
+//! - A missing field is filled in the same way.
+//! - If nothing fits, the field or array element (row) is removed. Arrays of the same length
+//!   in one object are treated as the columns of a table, like `filings.recent`, so the row
+//!   is removed from every one of them and the columns stay aligned.
+//!
+//! [`ParseMode::Strict`], the default, fails on the first mismatch, as plain `serde_json`
+//! does. Unknown fields never fail a parse; both modes list them in the report.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::json::{ParseMode, Repair, from_str_with_report};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Filings {
+//!     form: Vec<String>,
+//!     size: u64,
+//! }
+//!
+//! let json = r#"{"form": ["10-K", null, "8-K"], "size": "1024", "isXBRL": [1, 0, 0]}"#;
+//! let (filings, report) = from_str_with_report::<Filings>(json, ParseMode::Lenient).unwrap();
+//! assert_eq!(filings.form, vec!["10-K", "", "8-K"]);
+//! assert_eq!(filings.size, 1024);
+//! assert_eq!(report.repaired[0].path, "form[1]");
+//! assert_eq!(report.repaired[0].repair, Repair::Replaced(serde_json::json!("")));
+//! assert_eq!(report.repaired[1].repair, Repair::Replaced(serde_json::json!(1024)));
+//! assert_eq!(report.unknown_fields, vec!["isXBRL"]);
+//!
+//! assert!(from_str_with_report::<Filings>(json, ParseMode::Strict).is_err());
+//! ```
+
+use crate::error::Result;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use serde_path_to_error::Segment;
+
+/// Upper bound on re-parses while repairing one payload.
+const MAX_ATTEMPTS: usize = 256;
+
+/// How payloads that do not match the expected shape are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Repair mismatched and missing fields and report them
+    Lenient,
+    /// Fail on the first mismatch (the default)
+    #[default]
+    Strict,
+}
+
+/// What lenient parsing did to a value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Repair {
+    /// The value was replaced, or a missing field was filled in, with this value
+    Replaced(Value),
+    /// The field or array element was removed; a removed row is listed once per column
+    Removed,
+}
+
+/// One repaired value.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParseIssue {
+    /// Location in the payload, e.g. `filings.recent.reportDate[12]`
+    pub path: String,
+    /// The deserialization error the value caused
+    pub error: String,
+    pub repair: Repair,
+}
+
+/// Repairs and unknown fields found while parsing a payload.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ParseReport {
+    /// Values replaced or removed, in the order they were found
+    pub repaired: Vec<ParseIssue>,
+    /// Paths of fields in the payload that the target type does not have
+    pub unknown_fields: Vec<String>,
+}
+
+impl ParseReport {
+    /// Returns true if nothing was repaired and no unknown fields were seen.
+    pub fn is_clean(&self) -> bool {
+        self.repaired.is_empty() && self.unknown_fields.is_empty()
+    }
+}
+
+/// Parses `json` into `T`, repairing it in [`ParseMode::Lenient`].
+///
+/// # Errors
+///
+/// Returns `EdgarError::JsonError` if `json` is not valid JSON, if the payload does not
+/// match `T` in strict mode, or if lenient repairs do not make it match.
+pub fn from_str_with_report<T: DeserializeOwned>(
+    json: &str,
+    mode: ParseMode,
+) -> Result<(T, ParseReport)> {
+    let mut value: Value = serde_json::from_str(json)?;
+    let mut report = ParseReport::default();
+    // Path of the value being repaired and the candidates not yet tried there
+    let mut pending: Option<(String, Vec<Option<Value>>)> = None;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let mut unknown = Vec::new();
+        let error = match deserialize_tracked::<T>(&value, &mut unknown) {
+            Ok(parsed) => {
+                report.unknown_fields = unknown;
+                return Ok((parsed, report));
+            }
+            Err(error) => error,
+        };
+        if mode == ParseMode::Strict {
+            return Err(error.into_inner().into());
+        }
+
+        let message = error.inner().to_string();
+        let mut segments: Vec<Segment> = error.path().iter().cloned().collect();
+        let missing = missing_field(&message);
+        if let Some(field) = missing {
+            segments.push(Segment::Map {
+                key: field.to_string(),
+            });
+        }
+        let path = display_path(&segments);
+
+        let retry = pending.as_ref().is_some_and(|(at, _)| *at == path);
+        let mut remaining = match pending.take() {
+            Some((_, remaining)) if retry => remaining,
+            _ => match candidates(&value, &segments, missing.is_some()) {
+                Some(candidates) => candidates,
+                None => return Err(error.into_inner().into()),
+            },
+        };
+        if remaining.is_empty() {
+            return Err(error.into_inner().into());
+        }
+        let candidate = remaining.remove(0);
+        let Some((repair, columns)) = apply(&mut value, &segments, candidate) else {
+            return Err(error.into_inner().into());
+        };
+
+        // A later candidate for the same value supersedes the earlier one.
+        if retry {
+            report.repaired.pop();
+        }
+        report.repaired.push(ParseIssue {
+            path: path.clone(),
+            error: message,
+            repair: repair.clone(),
+        });
+        for column in columns {
+            report.repaired.push(ParseIssue {
+                path: column,
+                error: format!("row removed along with `{path}`"),
+                repair: Repair::Removed,
+            });
+        }
+        // After a removal the same path names the next element, which starts afresh.
+        if repair != Repair::Removed {
+            pending = Some((path, remaining));
+        }
+    }
+
+    // Give up with the error the payload still has.
+    let mut unknown = Vec::new();
+    deserialize_tracked::<T>(&value, &mut unknown)
+        .map(|parsed| (parsed, report))
+        .map_err(|error| error.into_inner().into())
+}
+
+fn deserialize_tracked<T: DeserializeOwned>(
+    value: &Value,
+    unknown: &mut Vec<String>,
+) -> std::result::Result<T, serde_path_to_error::Error<serde_json::Error>> {
+    let mut record = |path: serde_ignored::Path| unknown.push(path.to_string());
+    let ignored = serde_ignored::Deserializer::new(value, &mut record);
+    serde_path_to_error::deserialize(ignored)
+}
+
+/// Extracts `name` from serde's "missing field `name`" message.
+fn missing_field(message: &str) -> Option<&str> {
+    let rest = message.strip_prefix("missing field `")?;
+    rest.split('`').next()
+}
+
+fn display_path(segments: &[Segment]) -> String {
+    let mut path = String::new();
+    for segment in segments {
+        match segment {
+            Segment::Seq { index } => path.push_str(&format!("[{index}]")),
+            Segment::Map { key } | Segment::Enum { variant: key } => {
+                if !path.is_empty() {
+                    path.push('.');
+                }
+                path.push_str(key);
+            }
+            Segment::Unknown => path.push_str(".?"),
+        }
+    }
+    path
+}
+
+/// Follows `segments` to the value they name.
+fn parent_mut<'a>(value: &'a mut Value, segments: &[Segment]) -> Option<&'a mut Value> {
+    let mut parent = value;
+    for segment in segments {
+        parent = match segment {
+            Segment::Seq { index } => parent.get_mut(*index)?,
+            Segment::Map { key } | Segment::Enum { variant: key } => {
+                parent.get_mut(key.as_str())?
+            }
+            Segment::Unknown => return None,
+        };
+    }
+    Some(parent)
+}
+
+/// Replacement values to try for the value at `segments`, in order; `None` stands for
+/// removal.
+///
+/// A numeric string or a number is first converted to the other type. After that, fields
+/// get an empty value of every JSON type other than their own, and array elements get only
+/// `null` and the empty scalar their neighbors have, so a row of the wrong shape is dropped
+/// rather than filled with placeholders.
+fn candidates(value: &Value, segments: &[Segment], missing: bool) -> Option<Vec<Option<Value>>> {
+    let (last, parents) = segments.split_last()?;
+    let mut parent = value;
+    for segment in parents {
+        parent = match segment {
+            Segment::Seq { index } => parent.get(*index)?,
+            Segment::Map { key } | Segment::Enum { variant: key } => parent.get(key.as_str())?,
+            Segment::Unknown => return None,
+        };
+    }
+
+    let (current, values, removable) = match (last, parent) {
+        (Segment::Map { key }, Value::Object(object)) => {
+            let values = vec![
+                Value::Null,
+                Value::String(String::new()),
+                Value::from(0),
+                Value::Bool(false),
+                Value::Array(Vec::new()),
+                Value::Object(Map::new()),
+            ];
+            (object.get(key).filter(|_| !missing), values, !missing)
+        }
+        (Segment::Seq { index }, Value::Array(array)) => {
+            let current = array.get(*index)?;
+            let sibling = array
+                .iter()
+                .find(|item| !item.is_null() && kind(item) != kind(current))
+                .and_then(empty_scalar);
+            let values = std::iter::once(Value::Null).chain(sibling).collect();
+            (Some(current), values, true)
+        }
+        _ => return None,
+    };
+
+    let mut candidates: Vec<Option<Value>> = current
+        .and_then(coerced)
+        .into_iter()
+        .chain(values)
+        .filter(|value| current.is_none_or(|current| kind(current) != kind(value)))
+        .map(Some)
+        .collect();
+    if removable {
+        candidates.push(None);
+    }
+    Some(candidates)
+}
+
+/// Replaces the value at `segments` with `candidate`, or removes it if `None`.
+///
+/// Also returns the paths of the sibling columns a removed row was taken out of.
+fn apply(
+    value: &mut Value,
+    segments: &[Segment],
+    candidate: Option<Value>,
+) -> Option<(Repair, Vec<String>)> {
+    let (last, parents) = segments.split_last()?;
+    if let (Segment::Seq { index }, None) = (last, &candidate)
+        && let Some((Segment::Map { key }, table)) = parents.split_last()
+    {
+        return remove_row(parent_mut(value, table)?, table, key, *index);
+    }
+    let repair = match (last, parent_mut(value, parents)?) {
+        (Segment::Map { key }, Value::Object(object)) => match candidate {
+            Some(replacement) => {
+                object.insert(key.clone(), replacement.clone());
+                Repair::Replaced(replacement)
+            }
+            None => object.remove(key).map(|_| Repair::Removed)?,
+        },
+        (Segment::Seq { index }, Value::Array(array)) if *index < array.len() => match candidate {
+            Some(replacement) => {
+                array[*index] = replacement.clone();
+                Repair::Replaced(replacement)
+            }
+            None => {
+                array.remove(*index);
+                Repair::Removed
+            }
+        },
+        _ => return None,
+    };
+    Some((repair, Vec::new()))
+}
+
+/// Removes element `index` of the array at `key` in `table`, along with the same element of
+/// every other array there of the same length.
+fn remove_row(
+    table: &mut Value,
+    table_segments: &[Segment],
+    key: &str,
+    index: usize,
+) -> Option<(Repair, Vec<String>)> {
+    let Value::Object(object) = table else {
+        return None;
+    };
+    let len = match object.get(key)? {
+        Value::Array(array) if index < array.len() => array.len(),
+        _ => return None,
+    };
+    let mut columns = Vec::new();
+    for (column, column_value) in object.iter_mut() {
+        let Value::Array(array) = column_value else {
+            continue;
+        };
+        if array.len() != len {
+            continue;
+        }
+        array.remove(index);
+        if column != key {
+            let mut segments = table_segments.to_vec();
+            segments.push(Segment::Map {
+                key: column.clone(),
+            });
+            segments.push(Segment::Seq { index });
+            columns.push(display_path(&segments));
+        }
+    }
+    Some((Repair::Removed, columns))
+}
+
+fn kind(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// Converts a numeric string to a number, or a number to a string.
+fn coerced(value: &Value) -> Option<Value> {
+    match value {
+        Value::Number(number) => Some(Value::String(number.to_string())),
+        Value::String(text) => {
+            let text = text.trim();
+            if let Ok(integer) = text.parse::<i64>() {
+                Some(Value::from(integer))
+            } else if let Ok(integer) = text.parse::<u64>() {
+                Some(Value::from(integer))
+            } else {
+                text.parse::<f64>()
+                    .ok()
+                    .filter(|float| float.is_finite())
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn empty_scalar(value: &Value) -> Option<Value> {
+    match value {
+        Value::Bool(_) => Some(Value::Bool(false)),
+        Value::Number(_) => Some(Value::from(0)),
+        Value::String(_) => Some(Value::String(String::new())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Entity {
+        name: String,
+        tickers: Vec<String>,
+        #[serde(default)]
+        flags: u32,
+        rows: Vec<Row>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Row {
+        val: f64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Table {
+        form: Vec<String>,
+        size: Vec<u64>,
+        rows: Vec<Row>,
+        tickers: Vec<String>,
+    }
+
+    #[test]
+    fn test_lenient_repairs() {
+        let json =
+            r#"{"tickers": ["AAPL", 7], "flags": "x", "rows": [{"val": 1.5}, {"val": "n/a"}, 3]}"#;
+        let (entity, report) = from_str_with_report::<Entity>(json, ParseMode::Lenient).unwrap();
+        assert_eq!(entity.name, "");
+        assert_eq!(entity.tickers, vec!["AAPL", "7"]);
+        assert_eq!(entity.flags, 0);
+        assert_eq!(entity.rows.len(), 2);
+        assert_eq!(entity.rows[1].val, 0.0);
+
+        let paths: Vec<&str> = report.repaired.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["flags", "rows[1].val", "rows[2]", "tickers[1]", "name"]
+        );
+        assert_eq!(report.repaired[2].repair, Repair::Removed);
+        assert_eq!(report.repaired[4].repair, Repair::Replaced(Value::from("")));
+        assert_eq!(
+            report.repaired[3].repair,
+            Repair::Replaced(Value::from("7"))
+        );
+        assert!(report.unknown_fields.is_empty());
+    }
+
+    #[test]
+    fn test_lenient_coerces_numbers_and_keeps_columns_aligned() {
+        let json = r#"{
+            "form": ["10-K", "8-K", "10-Q"],
+            "size": ["100", 200, 300],
+            "rows": [{"val": "1.5"}, 3, {"val": 2}],
+            "tickers": ["AAPL", "MSFT"]
+        }"#;
+        let (table, report) = from_str_with_report::<Table>(json, ParseMode::Lenient).unwrap();
+        assert_eq!(table.form, vec!["10-K", "10-Q"]);
+        assert_eq!(table.size, vec![100, 300]);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[0].val, 1.5);
+        assert_eq!(table.tickers, vec!["AAPL", "MSFT"]);
+
+        let paths: Vec<&str> = report.repaired.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["rows[0].val", "rows[1]", "form[1]", "size[1]", "size[0]"]
+        );
+        assert_eq!(
+            report.repaired[0].repair,
+            Repair::Replaced(Value::from(1.5))
+        );
+        assert!(
+            report.repaired[1..4]
+                .iter()
+                .all(|i| i.repair == Repair::Removed)
+        );
+        assert_eq!(
+            report.repaired[4].repair,
+            Repair::Replaced(Value::from(100))
+        );
+    }
+
+    #[test]
+    fn test_strict_and_invalid_json() {
+        assert_eq!(ParseMode::default(), ParseMode::Strict);
+        let json = r#"{"name": "Apple", "tickers": [], "rows": [], "sic": "3571"}"#;
+        let (_, report) = from_str_with_report::<Entity>(json, ParseMode::Strict).unwrap();
+        assert_eq!(report.unknown_fields, vec!["sic"]);
+        assert!(!report.is_clean());
+
+        assert!(from_str_with_report::<Entity>("{", ParseMode::Lenient).is_err());
+        assert!(from_str_with_report::<Entity>(r#"{"name": 1}"#, ParseMode::Strict).is_err());
+    }
+}
@@ -1,4 +1,18 @@
 pub mod atom;
+#[cfg(feature = "filings")]
+pub mod filing_summary;
+#[cfg(feature = "filings")]
+pub mod form_d;
+pub mod html;
 pub mod index;
+pub mod intern;
+pub mod ixbrl;
+pub mod json;
+#[cfg(feature = "funds")]
+pub mod nport;
+pub mod ownership;
+pub mod proxy;
 pub mod rss;
+pub mod sections;
+pub mod submission;
 pub mod utils;
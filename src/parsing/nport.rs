@@ -0,0 +1,292 @@
+//! Form N-PORT portfolio holdings reports.
+//!
+//! Registered funds other than money market funds file a monthly portfolio report on
+//! Form N-PORT; the public version (`NPORT-P`) is released for the last month of each
+//! quarter. Its `primary_doc.xml` holds the fund's identity and balance sheet totals
+//! (`genInfo` and `fundInfo`) and the full schedule of investments (`invstOrSecs`), one
+//! `invstOrSec` per position.
+//!
+//! [`parse_nport`] reads those parts into a [`NportReport`]. Identifiers the form requires
+//! but that do not exist are reported as `N/A` or all zeros; they are `None` here.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::nport::parse_nport;
+//!
+//! let xml = r#"<edgarSubmission xmlns="http://www.sec.gov/edgar/nport"><formData>
+//!     <genInfo><regName>Example Funds Trust</regName><seriesName>Example 500 Index Fund</seriesName>
+//!     <repPdEnd>2024-12-31</repPdEnd><repPdDate>2024-09-30</repPdDate></genInfo>
+//!     <fundInfo><totAssets>1000000.00</totAssets><netAssets>990000.00</netAssets></fundInfo>
+//!     <invstOrSecs><invstOrSec>
+//!         <name>Apple Inc</name><lei>HWUPKR0MPOU8FGXBT394</lei><title>Apple Inc</title>
+//!         <cusip>037833100</cusip><identifiers><isin value="US0378331005"/></identifiers>
+//!         <balance>294.00000000</balance><units>NS</units><curCd>USD</curCd>
+//!         <valUSD>68088.46000000</valUSD><pctVal>6.877622</pctVal>
+//!     </invstOrSec></invstOrSecs>
+//! </formData></edgarSubmission>"#;
+//!
+//! let report = parse_nport(xml).unwrap();
+//! assert_eq!(report.fund.series_name.as_deref(), Some("Example 500 Index Fund"));
+//! assert_eq!(report.holdings[0].cusip.as_deref(), Some("037833100"));
+//! assert_eq!(report.holdings[0].isin.as_deref(), Some("US0378331005"));
+//! assert_eq!(report.holdings[0].value_usd, Some(68_088.46));
+//! ```
+
+use crate::error::{EdgarError, Result};
+use chrono::NaiveDate;
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use serde::{Deserialize, Serialize};
+
+/// Fund identity, reporting period, and balance sheet totals.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FundInfo {
+    /// Name of the registrant (usually a trust holding several series)
+    pub registrant_name: Option<String>,
+    pub registrant_cik: Option<String>,
+    pub registrant_lei: Option<String>,
+    /// Investment Company Act file number, e.g. `811-07064`
+    pub file_number: Option<String>,
+    pub series_name: Option<String>,
+    /// Series identifier, e.g. `S000002839`
+    pub series_id: Option<String>,
+    pub series_lei: Option<String>,
+    /// End of the fund's fiscal year
+    pub fiscal_year_end: Option<NaiveDate>,
+    /// Date the holdings are reported as of
+    pub period_date: Option<NaiveDate>,
+    pub total_assets: Option<f64>,
+    pub total_liabilities: Option<f64>,
+    pub net_assets: Option<f64>,
+}
+
+/// One position in the schedule of investments.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Holding {
+    /// Issuer name
+    pub name: String,
+    pub lei: Option<String>,
+    /// Title of the issue or description of the investment
+    pub title: Option<String>,
+    pub cusip: Option<String>,
+    pub isin: Option<String>,
+    /// Number of shares, principal amount, or contracts, per [`units`](Self::units)
+    pub balance: Option<f64>,
+    /// `NS` (shares), `PA` (principal amount), `NC` (contracts), or `OU` (other)
+    pub units: Option<String>,
+    pub currency: Option<String>,
+    pub value_usd: Option<f64>,
+    /// Value as a percentage of the fund's net assets
+    pub percent_value: Option<f64>,
+    /// `Long`, `Short`, or `N/A`
+    pub payoff_profile: Option<String>,
+    /// Asset category code, e.g. `EC` (equity-common) or `DBT` (debt)
+    pub asset_category: Option<String>,
+    /// Issuer category code, e.g. `CORP` or `UST`
+    pub issuer_category: Option<String>,
+    /// ISO country code of the investment
+    pub country: Option<String>,
+    pub restricted: Option<bool>,
+    /// Fair value hierarchy level, `1` to `3`
+    pub fair_value_level: Option<String>,
+}
+
+/// A parsed N-PORT report.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NportReport {
+    pub fund: FundInfo,
+    /// Holdings in filing order
+    pub holdings: Vec<Holding>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Part {
+    Other,
+    GeneralInfo,
+    FundInfo,
+    Holding,
+}
+
+/// Parses an N-PORT `primary_doc.xml`.
+///
+/// # Errors
+///
+/// Returns `EdgarError::XmlError` if the document is not well-formed XML, and
+/// `EdgarError::InvalidFormat` if it has neither fund information nor holdings.
+pub fn parse_nport(xml: &str) -> Result<NportReport> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut report = NportReport::default();
+    let mut found = false;
+    let mut part = Part::Other;
+    // Elements open below the current part, outermost first
+    let mut path: Vec<Vec<u8>> = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                let name = e.local_name().as_ref().to_vec();
+                match (part, name.as_slice()) {
+                    (Part::Other, b"genInfo") => part = Part::GeneralInfo,
+                    (Part::Other, b"fundInfo") => part = Part::FundInfo,
+                    (Part::Other, b"invstOrSec") => {
+                        part = Part::Holding;
+                        report.holdings.push(Holding::default());
+                    }
+                    (Part::Other, _) => {}
+                    _ => path.push(name),
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                if part == Part::Holding
+                    && e.local_name().as_ref() == b"isin"
+                    && path.last().is_some_and(|p| p == b"identifiers")
+                    && let Some(holding) = report.holdings.last_mut()
+                {
+                    holding.isin = attribute(&e, b"value")?.and_then(identifier);
+                }
+            }
+            Ok(Event::Text(e)) => {
+                let text = e
+                    .unescape()
+                    .map_err(|e| EdgarError::XmlError(e.to_string()))?;
+                let text = text.trim();
+                // Only direct children of the part carry the fields read here.
+                let [element] = path.as_slice() else {
+                    continue;
+                };
+                match part {
+                    Part::GeneralInfo => set_general(&mut report.fund, element, text),
+                    Part::FundInfo => set_fund(&mut report.fund, element, text),
+                    Part::Holding => {
+                        if let Some(holding) = report.holdings.last_mut() {
+                            set_holding(holding, element, text);
+                        }
+                    }
+                    Part::Other => {}
+                }
+            }
+            Ok(Event::End(_)) => {
+                let closes_part = path.pop().is_none();
+                if closes_part && part != Part::Other {
+                    found = true;
+                    part = Part::Other;
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(EdgarError::XmlError(e.to_string())),
+            _ => {}
+        }
+    }
+
+    if !found {
+        return Err(EdgarError::InvalidFormat(
+            "no N-PORT fund information or holdings in document".to_string(),
+        ));
+    }
+    Ok(report)
+}
+
+fn set_general(fund: &mut FundInfo, element: &[u8], text: &str) {
+    let value = || Some(text.to_string());
+    match element {
+        b"regName" => fund.registrant_name = value(),
+        b"regFileNumber" => fund.file_number = value(),
+        b"regCik" => fund.registrant_cik = value(),
+        b"regLei" => fund.registrant_lei = identifier(text.to_string()),
+        b"seriesName" => fund.series_name = value(),
+        b"seriesId" => fund.series_id = value(),
+        b"seriesLei" => fund.series_lei = identifier(text.to_string()),
+        b"repPdEnd" => fund.fiscal_year_end = date(text),
+        b"repPdDate" => fund.period_date = date(text),
+        _ => {}
+    }
+}
+
+fn set_fund(fund: &mut FundInfo, element: &[u8], text: &str) {
+    match element {
+        b"totAssets" => fund.total_assets = number(text),
+        b"totLiabs" => fund.total_liabilities = number(text),
+        b"netAssets" => fund.net_assets = number(text),
+        _ => {}
+    }
+}
+
+fn set_holding(holding: &mut Holding, element: &[u8], text: &str) {
+    let value = || Some(text.to_string());
+    match element {
+        b"name" => holding.name = text.to_string(),
+        b"lei" => holding.lei = identifier(text.to_string()),
+        b"title" => holding.title = value(),
+        b"cusip" => holding.cusip = identifier(text.to_string()),
+        b"balance" => holding.balance = number(text),
+        b"units" => holding.units = value(),
+        b"curCd" => holding.currency = value(),
+        b"valUSD" => holding.value_usd = number(text),
+        b"pctVal" => holding.percent_value = number(text),
+        b"payoffProfile" => holding.payoff_profile = value(),
+        b"assetCat" => holding.asset_category = value(),
+        b"issuerCat" => holding.issuer_category = value(),
+        b"invCountry" => holding.country = value(),
+        b"isRestrictedSec" => holding.restricted = Some(text == "Y"),
+        b"fairValLevel" => holding.fair_value_level = value(),
+        _ => {}
+    }
+}
+
+fn attribute(element: &BytesStart, key: &[u8]) -> Result<Option<String>> {
+    for attr in element.attributes().flatten() {
+        if attr.key.local_name().as_ref() == key {
+            let value = attr
+                .unescape_value()
+                .map_err(|e| EdgarError::XmlError(e.to_string()))?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+/// Drops the `N/A` and all-zero placeholders filed for missing identifiers.
+fn identifier(value: String) -> Option<String> {
+    let placeholder =
+        value.is_empty() || value.eq_ignore_ascii_case("N/A") || value.chars().all(|c| c == '0');
+    (!placeholder).then_some(value)
+}
+
+fn number(text: &str) -> Option<f64> {
+    text.parse().ok()
+}
+
+fn date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_elements_do_not_leak_into_holding() {
+        let xml = r#"<edgarSubmission><formData><invstOrSecs><invstOrSec>
+            <name>Example Corp 4.5% 2030</name><lei>N/A</lei><cusip>000000000</cusip>
+            <identifiers><other otherDesc="Internal" value="X1"/></identifiers>
+            <balance>1000000</balance><units>PA</units><isRestrictedSec>N</isRestrictedSec>
+            <debtSec><maturityDt>2030-06-15</maturityDt><name>ignored</name></debtSec>
+        </invstOrSec></invstOrSecs></formData></edgarSubmission>"#;
+
+        let report = parse_nport(xml).unwrap();
+        let holding = &report.holdings[0];
+        assert_eq!(holding.name, "Example Corp 4.5% 2030");
+        assert_eq!(holding.lei, None);
+        assert_eq!(holding.cusip, None);
+        assert_eq!(holding.isin, None);
+        assert_eq!(holding.balance, Some(1_000_000.0));
+        assert_eq!(holding.restricted, Some(false));
+
+        assert!(matches!(
+            parse_nport("<edgarSubmission/>"),
+            Err(EdgarError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,478 @@
+//! Beneficial ownership reports on Schedules 13D and 13G.
+//!
+//! Anyone who comes to own more than 5% of a class of a company's voting equity files a
+//! Schedule 13D, or the short-form Schedule 13G if they are a passive or institutional
+//! investor. Both open with a cover page per reporting person: numbered rows for the
+//! person's name, citizenship, sole and shared voting and dispositive power, aggregate
+//! shares owned, percent of class, and type of reporting person. A 13D also states the
+//! purpose of the transaction in Item 4, which is what activist investors announce their
+//! plans in.
+//!
+//! Filing agents lay the cover page out as HTML tables, as plain text, or (since 2024)
+//! render it from XML, so [`parse_beneficial_ownership`] matches the row captions rather
+//! than positions. It accepts a label and its value in one cell or line, or in consecutive
+//! ones, and ignores row numbers and "(see instructions)" notes.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::ownership::parse_beneficial_ownership;
+//!
+//! let html = r#"<p>Apple Inc.</p><p>(Name of Issuer)</p>
+//!     <p>037833100</p><p>(CUSIP Number)</p>
+//!     <table>
+//!     <tr><td>1</td><td>NAMES OF REPORTING PERSONS<br>Berkshire Hathaway Inc.</td></tr>
+//!     <tr><td>5</td><td>SOLE VOTING POWER</td><td>0</td></tr>
+//!     <tr><td>6</td><td>SHARED VOTING POWER</td><td>905,560,000</td></tr>
+//!     <tr><td>11</td><td>PERCENT OF CLASS REPRESENTED BY AMOUNT IN ROW (9)</td><td>5.9%</td></tr>
+//!     </table>"#;
+//!
+//! let report = parse_beneficial_ownership(html);
+//! assert_eq!(report.issuer.as_deref(), Some("Apple Inc."));
+//! assert_eq!(report.cusip.as_deref(), Some("037833100"));
+//! let person = &report.reporting_persons[0];
+//! assert_eq!(person.name, "Berkshire Hathaway Inc.");
+//! assert_eq!(person.sole_voting_power, Some(0.0));
+//! assert_eq!(person.shared_voting_power, Some(905_560_000.0));
+//! assert_eq!(person.percent_of_class, Some(5.9));
+//! ```
+
+use super::html::{Block, blocks};
+use serde::{Deserialize, Serialize};
+
+/// Base form types of beneficial ownership reports; amendments add `/A`.
+///
+/// EDGAR named the forms `SC 13D` and `SC 13G` until the structured data versions replaced
+/// them in December 2024.
+pub const BENEFICIAL_OWNERSHIP_FORMS: &[&str] =
+    &["SC 13D", "SC 13G", "SCHEDULE 13D", "SCHEDULE 13G"];
+
+/// Which schedule a beneficial ownership report was filed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Schedule {
+    /// Active ownership, including a statement of the owner's plans
+    Schedule13D,
+    /// Passive or institutional ownership
+    Schedule13G,
+}
+
+impl Schedule {
+    /// Maps a form type such as `SC 13D/A` or `SCHEDULE 13G` to its schedule.
+    pub fn from_form(form: &str) -> Option<Self> {
+        let base = form.trim().trim_end_matches("/A").to_ascii_uppercase();
+        match base.as_str() {
+            "SC 13D" | "SCHEDULE 13D" => Some(Schedule::Schedule13D),
+            "SC 13G" | "SCHEDULE 13G" => Some(Schedule::Schedule13G),
+            _ => None,
+        }
+    }
+}
+
+/// Cover page of one reporting person.
+///
+/// Share counts are as reported, so they are `f64` like other amounts in this crate.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportingPerson {
+    pub name: String,
+
+    /// Citizenship or place of organization
+    pub citizenship: Option<String>,
+
+    pub sole_voting_power: Option<f64>,
+    pub shared_voting_power: Option<f64>,
+    pub sole_dispositive_power: Option<f64>,
+    pub shared_dispositive_power: Option<f64>,
+
+    /// Aggregate shares beneficially owned
+    pub aggregate_amount: Option<f64>,
+
+    /// Percent of the class, e.g. `5.9` for 5.9%
+    pub percent_of_class: Option<f64>,
+
+    /// Type of reporting person code, e.g. `IN` (individual) or `HC` (holding company)
+    pub person_type: Option<String>,
+}
+
+/// The parsed content of a Schedule 13D or 13G.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BeneficialOwnership {
+    /// Name of the issuer whose shares are owned
+    pub issuer: Option<String>,
+
+    /// Title of the class of securities
+    pub class_title: Option<String>,
+
+    pub cusip: Option<String>,
+
+    /// One entry per cover page, in filing order
+    pub reporting_persons: Vec<ReportingPerson>,
+
+    /// Item 4 of a Schedule 13D; `None` for a 13G
+    pub purpose: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Name,
+    Citizenship,
+    SoleVoting,
+    SharedVoting,
+    SoleDispositive,
+    SharedDispositive,
+    Aggregate,
+    Percent,
+    PersonType,
+}
+
+/// Row captions, longest first where one is a prefix of another.
+const LABELS: &[(&str, Field)] = &[
+    ("names of reporting persons", Field::Name),
+    ("name of reporting persons", Field::Name),
+    ("names of reporting person", Field::Name),
+    ("name of reporting person", Field::Name),
+    ("citizenship or place of organization", Field::Citizenship),
+    ("sole voting power", Field::SoleVoting),
+    ("shared voting power", Field::SharedVoting),
+    ("sole dispositive power", Field::SoleDispositive),
+    ("shared dispositive power", Field::SharedDispositive),
+    (
+        "aggregate amount beneficially owned by each reporting person",
+        Field::Aggregate,
+    ),
+    ("aggregate amount beneficially owned", Field::Aggregate),
+    (
+        "percent of class represented by amount in row",
+        Field::Percent,
+    ),
+    ("type of reporting person", Field::PersonType),
+];
+
+impl Field {
+    fn set(self, person: &mut ReportingPerson, value: &str) {
+        let number = || parse_number(value);
+        match self {
+            Field::Name => {}
+            Field::Citizenship => set_once(&mut person.citizenship, Some(value.to_string())),
+            Field::SoleVoting => set_once(&mut person.sole_voting_power, number()),
+            Field::SharedVoting => set_once(&mut person.shared_voting_power, number()),
+            Field::SoleDispositive => set_once(&mut person.sole_dispositive_power, number()),
+            Field::SharedDispositive => set_once(&mut person.shared_dispositive_power, number()),
+            Field::Aggregate => set_once(&mut person.aggregate_amount, number()),
+            Field::Percent => set_once(&mut person.percent_of_class, number()),
+            Field::PersonType => set_once(&mut person.person_type, Some(value.to_string())),
+        }
+    }
+}
+
+/// Keeps the cover page value when the body repeats a caption in prose.
+fn set_once<T>(slot: &mut Option<T>, value: Option<T>) {
+    if slot.is_none() {
+        *slot = value;
+    }
+}
+
+/// Parses the cover pages and, for a 13D, the purpose of the transaction.
+///
+/// Accepts the HTML or plain text primary document. Fields that cannot be found are `None`;
+/// a document without cover pages yields no reporting persons.
+pub fn parse_beneficial_ownership(document: &str) -> BeneficialOwnership {
+    let lines = lines(document);
+    let mut report = BeneficialOwnership {
+        issuer: caption_value(&lines, "(name of issuer)"),
+        class_title: caption_value(&lines, "(title of class of securities)"),
+        cusip: caption_value(&lines, "(cusip number)"),
+        purpose: purpose(&lines),
+        ..BeneficialOwnership::default()
+    };
+
+    for (idx, line) in lines.iter().enumerate() {
+        let matches = find_labels(line);
+        for (n, &(_, end, field)) in matches.iter().enumerate() {
+            let next = matches.get(n + 1).map(|&(at, ..)| at);
+            let mut value = clean_value(&line[end..next.unwrap_or(line.len())]);
+            if next.is_some() {
+                value = drop_row_number(&value);
+            }
+            if value.is_empty() && next.is_none() {
+                value = value_below(&lines, idx);
+            }
+
+            if field == Field::Name {
+                if value.is_empty() {
+                    continue;
+                }
+                report.reporting_persons.push(ReportingPerson {
+                    name: value,
+                    ..ReportingPerson::default()
+                });
+            } else if let Some(person) = report.reporting_persons.last_mut()
+                && !value.is_empty()
+            {
+                field.set(person, &value);
+            }
+        }
+    }
+    report
+}
+
+/// Flattens the document into trimmed, non-empty lines, one per table cell line.
+fn lines(document: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut push = |text: &str| {
+        lines.extend(
+            text.lines()
+                .map(|line| line.trim().to_string())
+                .filter(|line| !line.is_empty()),
+        );
+    };
+    for block in blocks(document) {
+        match block {
+            Block::Heading(_, text)
+            | Block::Paragraph(text)
+            | Block::ListItem(text)
+            | Block::Preformatted(text) => push(&text),
+            Block::Table(rows) => rows.iter().flatten().for_each(|cell| push(cell)),
+        }
+    }
+    lines
+}
+
+/// Finds the captions in a line as `(start, end, field)`, left to right.
+fn find_labels(line: &str) -> Vec<(usize, usize, Field)> {
+    let lower = line.to_ascii_lowercase();
+    let mut found: Vec<(usize, usize, Field)> = Vec::new();
+    for &(label, field) in LABELS {
+        let mut from = 0;
+        while let Some(pos) = lower[from..].find(label) {
+            let start = from + pos;
+            let end = start + label.len();
+            if !found.iter().any(|&(s, e, _)| start < e && s < end) {
+                found.push((start, end, field));
+            }
+            from = end;
+        }
+    }
+    found.sort_by_key(|&(start, ..)| start);
+    found
+}
+
+/// Looks at the lines after a caption that stands alone for its value.
+fn value_below(lines: &[String], idx: usize) -> String {
+    for (offset, line) in lines.iter().enumerate().skip(idx + 1).take(3) {
+        if !find_labels(line).is_empty() {
+            break;
+        }
+        // A row number followed by the next caption means this row was left blank.
+        if is_row_number(line)
+            && lines
+                .get(offset + 1)
+                .is_some_and(|next| !find_labels(next).is_empty())
+        {
+            break;
+        }
+        let value = clean_value(line);
+        if !value.is_empty() {
+            return value;
+        }
+    }
+    String::new()
+}
+
+/// Strips instructions, row references, and leading punctuation from a value.
+fn clean_value(value: &str) -> String {
+    let mut value = value.to_string();
+    let lower = value.to_ascii_lowercase();
+    if let Some(at) = lower.find("(see instructions)") {
+        value.replace_range(at..at + "(see instructions)".len(), "");
+    }
+    let lower = value.to_ascii_lowercase();
+    if let Some(at) = lower.find("i.r.s. identification no") {
+        let end = lower
+            .find("(entities only)")
+            .map_or(value.len(), |end| end + "(entities only)".len());
+        value.replace_range(at..end.max(at), "");
+    }
+
+    let mut value = value
+        .trim_start_matches(|c: char| ".:;,-/\u{2013}\u{2014}".contains(c) || c.is_whitespace())
+        .trim();
+    // "... AMOUNT IN ROW (9)" and "... OWNED BY EACH REPORTING PERSON" leave these behind.
+    for prefix in ["(9)", "(11)", "9", "11"] {
+        if let Some(rest) = value.strip_prefix(prefix)
+            && (rest.is_empty() || rest.starts_with([' ', ':', '.']))
+        {
+            value = rest
+                .trim_start_matches(|c: char| ".:".contains(c) || c.is_whitespace())
+                .trim();
+        }
+    }
+    value.to_string()
+}
+
+/// Drops the row number that precedes the next caption on the same line.
+fn drop_row_number(value: &str) -> String {
+    match value.rsplit_once(char::is_whitespace) {
+        Some((rest, last)) if is_row_number(last) => rest.trim_end().to_string(),
+        None if is_row_number(value) => String::new(),
+        _ => value.to_string(),
+    }
+}
+
+/// `5`, `5.`, `(5)`, or `Row 5`.
+fn is_row_number(text: &str) -> bool {
+    let text = text.trim();
+    let text = text.strip_prefix("Row ").unwrap_or(text);
+    let digits = text
+        .trim_start_matches('(')
+        .trim_end_matches([')', '.', ':']);
+    !digits.is_empty() && digits.len() <= 2 && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Parses the first number in a value, skipping parenthesized references and footnotes.
+fn parse_number(value: &str) -> Option<f64> {
+    value
+        .split_whitespace()
+        .filter(|token| !token.starts_with('('))
+        .find_map(|token| {
+            let token = token.split('(').next().unwrap_or_default();
+            let digits: String = token
+                .chars()
+                .filter(|c| !matches!(c, ',' | '$' | '%' | '*'))
+                .collect();
+            let digits = digits.trim_end_matches('.');
+            digits.parse().ok()
+        })
+}
+
+/// Returns the line above a caption such as `(Name of Issuer)`.
+fn caption_value(lines: &[String], caption: &str) -> Option<String> {
+    let at = lines
+        .iter()
+        .position(|line| line.to_ascii_lowercase() == caption)?;
+    let value = lines.get(at.checked_sub(1)?)?;
+    (!value.starts_with('(')).then(|| value.clone())
+}
+
+/// Collects Item 4 of a Schedule 13D, up to the start of Item 5.
+fn purpose(lines: &[String]) -> Option<String> {
+    let starts_item = |line: &str, item: &str| {
+        let lower = line.to_ascii_lowercase();
+        lower
+            .strip_prefix("item ")
+            .is_some_and(|rest| rest.trim_start().starts_with(item))
+    };
+    let heading = lines.iter().enumerate().position(|(idx, line)| {
+        let lower = line.to_ascii_lowercase();
+        lower.contains("purpose of transaction")
+            && (starts_item(line, "4")
+                || idx
+                    .checked_sub(1)
+                    .is_some_and(|prev| starts_item(&lines[prev], "4")))
+    })?;
+
+    let heading_line = &lines[heading];
+    let lower = heading_line.to_ascii_lowercase();
+    let at = lower.find("purpose of transaction")? + "purpose of transaction".len();
+    let mut text = vec![clean_value(&heading_line[at..])];
+    text.extend(
+        lines[heading + 1..]
+            .iter()
+            .take_while(|line| !starts_item(line, "5"))
+            .cloned(),
+    );
+    let text = text
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schedule_from_form() {
+        assert_eq!(Schedule::from_form("SC 13D/A"), Some(Schedule::Schedule13D));
+        assert_eq!(
+            Schedule::from_form("SCHEDULE 13G"),
+            Some(Schedule::Schedule13G)
+        );
+        assert_eq!(Schedule::from_form("13F-HR"), None);
+    }
+
+    #[test]
+    fn test_plain_text_cover_page() {
+        let text = [
+            "SCHEDULE 13D",
+            "",
+            "Example Corp.",
+            "(Name of Issuer)",
+            "",
+            "Common Stock, par value $0.01 per share",
+            "(Title of Class of Securities)",
+            "",
+            "30161N101",
+            "(CUSIP Number)",
+            "",
+            "(1) Names of reporting persons. Activist Capital LP",
+            "(4) Citizenship or place of organization Delaware",
+            "(5) Sole voting power 0 (6) Shared voting power 12,345,678",
+            "(7) Sole dispositive power 0",
+            "(8) Shared dispositive power 12,345,678",
+            "(9) Aggregate amount beneficially owned by each reporting person 12,345,678",
+            "(11) Percent of class represented by amount in row (9) 7.2%(1)",
+            "(12) Type of reporting person (see instructions) PN",
+            "",
+            "(1) Names of reporting persons. Jane Doe",
+            "(5) Sole voting power 1,000",
+            "",
+            "Item 4. Purpose of Transaction.",
+            "",
+            "The Reporting Persons intend to seek board representation.",
+            "",
+            "Item 5. Interest in Securities of the Issuer.",
+            "",
+            "(a) Sole voting power of 1 share.",
+        ]
+        .join("\n");
+
+        let report = parse_beneficial_ownership(&text);
+        assert_eq!(report.issuer.as_deref(), Some("Example Corp."));
+        assert_eq!(report.cusip.as_deref(), Some("30161N101"));
+        assert_eq!(
+            report.class_title.as_deref(),
+            Some("Common Stock, par value $0.01 per share")
+        );
+        assert_eq!(
+            report.purpose.as_deref(),
+            Some("The Reporting Persons intend to seek board representation.")
+        );
+
+        assert_eq!(report.reporting_persons.len(), 2);
+        let fund = &report.reporting_persons[0];
+        assert_eq!(fund.name, "Activist Capital LP");
+        assert_eq!(fund.citizenship.as_deref(), Some("Delaware"));
+        assert_eq!(fund.sole_voting_power, Some(0.0));
+        assert_eq!(fund.shared_voting_power, Some(12_345_678.0));
+        assert_eq!(fund.shared_dispositive_power, Some(12_345_678.0));
+        assert_eq!(fund.aggregate_amount, Some(12_345_678.0));
+        assert_eq!(fund.percent_of_class, Some(7.2));
+        assert_eq!(fund.person_type.as_deref(), Some("PN"));
+        assert_eq!(report.reporting_persons[1].sole_voting_power, Some(1_000.0));
+    }
+
+    #[test]
+    fn test_clean_value() {
+        assert_eq!(
+            clean_value(
+                " / I.R.S. IDENTIFICATION NOS. OF ABOVE PERSONS (ENTITIES ONLY) Berkshire Hathaway Inc."
+            ),
+            "Berkshire Hathaway Inc."
+        );
+        assert_eq!(clean_value(" (11) 5.9%"), "5.9%");
+        assert_eq!(drop_row_number("0 (6)"), "0");
+        assert!(is_row_number("12."));
+        assert!(!is_row_number("905,560,000"));
+    }
+}
@@ -0,0 +1,298 @@
+//! Summary Compensation Table extraction from proxy statements.
+//!
+//! A definitive proxy statement (form `DEF 14A`) reports the pay of the named executive
+//! officers in the Summary Compensation Table required by Item 402(c) of Regulation S-K:
+//! one row per officer and fiscal year, with salary, bonus, stock and option awards,
+//! incentive pay, pension changes, other compensation, and the total.
+//!
+//! The table's layout is not standardized, so [`summary_compensation_table`] is best-effort.
+//! It looks for the first table after a "Summary Compensation Table" caption whose header
+//! names salary and total columns, maps columns by their header text, and carries an
+//! officer's name down to the rows for earlier years. Amounts shown as dashes are `None`;
+//! parenthesized amounts are negative.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::proxy::summary_compensation_table;
+//!
+//! let html = r#"<p><b>Summary Compensation Table</b></p><table>
+//!     <tr><td>Name and Principal Position</td><td>Year</td><td>Salary ($)</td><td>Bonus ($)</td><td>Total ($)</td></tr>
+//!     <tr><td><p>Tim Cook</p><p>Chief Executive Officer</p></td><td>2023</td><td>$</td><td>3,000,000</td><td>&#8212;</td><td>$</td><td>63,209,845</td></tr>
+//!     <tr><td></td><td>2022</td><td>$</td><td>3,000,000</td><td>&#8212;</td><td>$</td><td>99,420,097</td></tr>
+//! </table>"#;
+//!
+//! let rows = summary_compensation_table(html);
+//! assert_eq!(rows.len(), 2);
+//! assert_eq!(rows[0].name, "Tim Cook");
+//! assert_eq!(rows[0].title.as_deref(), Some("Chief Executive Officer"));
+//! assert_eq!(rows[1].year, 2022);
+//! assert_eq!(rows[1].bonus, None);
+//! assert_eq!(rows[1].total, Some(99_420_097.0));
+//! ```
+
+use super::html::{Block, blocks};
+use serde::{Deserialize, Serialize};
+
+/// How many blocks after the caption the table may start.
+const CAPTION_DISTANCE: usize = 5;
+
+/// One officer's compensation for one fiscal year, in dollars.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompensationRow {
+    pub name: String,
+
+    /// Principal position, when it is printed on its own line under the name
+    pub title: Option<String>,
+
+    pub year: i32,
+    pub salary: Option<f64>,
+    pub bonus: Option<f64>,
+    pub stock_awards: Option<f64>,
+    pub option_awards: Option<f64>,
+    pub non_equity_incentive: Option<f64>,
+
+    /// Change in pension value and nonqualified deferred compensation earnings
+    pub pension_and_deferred: Option<f64>,
+
+    pub all_other: Option<f64>,
+    pub total: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Column {
+    Salary,
+    Bonus,
+    StockAwards,
+    OptionAwards,
+    NonEquityIncentive,
+    PensionAndDeferred,
+    AllOther,
+    Total,
+    Unknown,
+}
+
+impl Column {
+    fn from_header(header: &str) -> Self {
+        let header = header.to_lowercase();
+        if header.contains("salary") {
+            Column::Salary
+        } else if header.contains("bonus") {
+            Column::Bonus
+        } else if header.contains("stock") {
+            Column::StockAwards
+        } else if header.contains("option") {
+            Column::OptionAwards
+        } else if header.contains("non-equity") || header.contains("incentive plan") {
+            Column::NonEquityIncentive
+        } else if header.contains("pension") || header.contains("deferred") {
+            Column::PensionAndDeferred
+        } else if header.contains("all other") {
+            Column::AllOther
+        } else if header.contains("total") {
+            Column::Total
+        } else {
+            Column::Unknown
+        }
+    }
+
+    fn set(self, row: &mut CompensationRow, value: Option<f64>) {
+        let field = match self {
+            Column::Salary => &mut row.salary,
+            Column::Bonus => &mut row.bonus,
+            Column::StockAwards => &mut row.stock_awards,
+            Column::OptionAwards => &mut row.option_awards,
+            Column::NonEquityIncentive => &mut row.non_equity_incentive,
+            Column::PensionAndDeferred => &mut row.pension_and_deferred,
+            Column::AllOther => &mut row.all_other,
+            Column::Total => &mut row.total,
+            Column::Unknown => return,
+        };
+        *field = value;
+    }
+}
+
+/// Extracts the Summary Compensation Table from a proxy statement's HTML or text.
+///
+/// Returns an empty vector if no such table is found.
+pub fn summary_compensation_table(document: &str) -> Vec<CompensationRow> {
+    let blocks = blocks(document);
+    let mut candidates = Vec::new();
+    let mut caption_at = None;
+    for (idx, block) in blocks.iter().enumerate() {
+        match block {
+            Block::Heading(_, text) | Block::Paragraph(text)
+                if text.to_lowercase().contains("summary compensation table") =>
+            {
+                caption_at = Some(idx);
+            }
+            Block::Table(rows) => {
+                if let Some(header) = header_row(rows) {
+                    let captioned = caption_at.is_some_and(|at| idx - at <= CAPTION_DISTANCE);
+                    candidates.push((captioned, rows, header));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Prefer a captioned table; the caption is sometimes missing or styled as an image.
+    let table = candidates
+        .iter()
+        .find(|(captioned, ..)| *captioned)
+        .or_else(|| candidates.first());
+    match table {
+        Some((_, rows, header)) => parse_rows(rows, *header),
+        None => Vec::new(),
+    }
+}
+
+/// Finds the header row: the one naming both salary and total columns.
+fn header_row(rows: &[Vec<String>]) -> Option<usize> {
+    rows.iter().position(|row| {
+        let text = row.join(" ").to_lowercase();
+        text.contains("salary") && text.contains("total")
+    })
+}
+
+fn parse_rows(rows: &[Vec<String>], header: usize) -> Vec<CompensationRow> {
+    let header_cells = &rows[header];
+    let year_column = header_cells
+        .iter()
+        .position(|cell| {
+            let first_line = cell.lines().next().unwrap_or_default();
+            first_line.trim().eq_ignore_ascii_case("year")
+        })
+        .unwrap_or(1);
+    let columns: Vec<Column> = header_cells[year_column + 1..]
+        .iter()
+        .map(|cell| Column::from_header(cell))
+        .collect();
+
+    let mut parsed = Vec::new();
+    let mut current: Option<(String, Option<String>)> = None;
+    for row in &rows[header + 1..] {
+        let Some(year_at) = row.iter().position(|cell| parse_year(cell).is_some()) else {
+            continue;
+        };
+        let year = parse_year(&row[year_at]).unwrap_or_default();
+
+        if year_at > 0 {
+            let label = row[..year_at].join("\n");
+            current = Some(split_name(&label));
+        }
+        let Some((name, title)) = current.clone() else {
+            continue;
+        };
+
+        let mut record = CompensationRow {
+            name,
+            title,
+            year,
+            ..CompensationRow::default()
+        };
+        // Blank cells are dropped with the layout cells, so values are aligned from the
+        // right, where the total column reliably is.
+        let values = &row[year_at + 1..];
+        let skip = columns.len().saturating_sub(values.len());
+        for (column, value) in columns.iter().skip(skip).zip(values) {
+            column.set(&mut record, parse_amount(value));
+        }
+        parsed.push(record);
+    }
+    parsed
+}
+
+fn parse_year(cell: &str) -> Option<i32> {
+    let cell = strip_footnote(cell.trim());
+    if cell.len() != 4 {
+        return None;
+    }
+    cell.parse()
+        .ok()
+        .filter(|year| (1990..=2100).contains(year))
+}
+
+/// Splits the first cell into the officer's name and the position printed beneath it.
+fn split_name(label: &str) -> (String, Option<String>) {
+    let mut lines = label
+        .lines()
+        .map(|line| strip_footnote(line.trim()))
+        .filter(|line| !line.is_empty());
+    let name = lines.next().unwrap_or_default().to_string();
+    let title: Vec<&str> = lines.collect();
+    let title = (!title.is_empty()).then(|| title.join(" "));
+    (name, title)
+}
+
+/// Parses `$ 1,234,567`, `(12,000)`, or `—` (no amount).
+fn parse_amount(cell: &str) -> Option<f64> {
+    let cell = strip_footnote(cell.trim());
+    let negative = cell.starts_with('(') || cell.starts_with("$(") || cell.starts_with("($");
+    let digits: String = cell
+        .chars()
+        .filter(|c| c.is_ascii_digit() || *c == '.')
+        .collect();
+    let value: f64 = digits.parse().ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Drops a trailing footnote marker such as `(1)` or `(a)`.
+fn strip_footnote(cell: &str) -> &str {
+    let mut cell = cell;
+    while let Some(open) = cell.rfind('(') {
+        let marker = &cell[open..];
+        let inner = marker.trim_start_matches('(').trim_end_matches(')');
+        if marker.ends_with(')') && !inner.is_empty() && inner.len() <= 2 {
+            cell = cell[..open].trim_end();
+        } else {
+            break;
+        }
+    }
+    cell
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_amount() {
+        assert_eq!(parse_amount("$3,000,000"), Some(3_000_000.0));
+        assert_eq!(parse_amount("$(12,500)"), Some(-12_500.0));
+        assert_eq!(parse_amount("1,250,000(3)"), Some(1_250_000.0));
+        assert_eq!(parse_amount("\u{2014}"), None);
+        assert_eq!(
+            split_name("Jane Doe(1)\nChief Financial\nOfficer"),
+            (
+                "Jane Doe".to_string(),
+                Some("Chief Financial Officer".to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_values_align_from_the_right() {
+        let rows = vec![
+            vec![
+                "Name".to_string(),
+                "Year".to_string(),
+                "Salary".to_string(),
+                "Bonus".to_string(),
+                "Stock Awards".to_string(),
+                "Total".to_string(),
+            ],
+            vec![
+                "Jeff Williams".to_string(),
+                "2023".to_string(),
+                "$1,000,000".to_string(),
+                "$22,000,000".to_string(),
+                "$23,000,000".to_string(),
+            ],
+        ];
+        let parsed = parse_rows(&rows, 0);
+        assert_eq!(parsed[0].salary, None);
+        assert_eq!(parsed[0].bonus, Some(1_000_000.0));
+        assert_eq!(parsed[0].stock_awards, Some(22_000_000.0));
+        assert_eq!(parsed[0].total, Some(23_000_000.0));
+    }
+}
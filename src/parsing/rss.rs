@@ -1,9 +1,17 @@
 #[cfg(feature = "rss")]
+use super::utils::deserialize_optional_human_size;
+#[cfg(feature = "rss")]
 use crate::Result;
+#[cfg(all(feature = "rss", not(target_arch = "wasm32")))]
+use crate::{Edgar, EdgarError};
 #[cfg(feature = "rss")]
 use quick_xml::{Reader, events::Event};
 #[cfg(feature = "rss")]
 use serde::Deserialize;
+#[cfg(feature = "rss")]
+use std::path::Path;
+#[cfg(all(feature = "rss", not(target_arch = "wasm32")))]
+use std::path::PathBuf;
 
 /// Configuration options for RSS feed parsing.
 ///
@@ -181,13 +189,25 @@ pub struct XbrlFile {
     #[serde(rename = "@sequence", default)]
     pub sequence: Option<String>,
 
+    /// Name of the file in the filing's archive folder.
+    #[serde(rename = "@file", default)]
+    pub file: Option<String>,
+
     /// Type of XBRL file (e.g., "EX-101.INS", "EX-101.SCH", "EX-101.CAL").
     #[serde(rename = "@type", default)]
     pub file_type: Option<String>,
 
+    /// Whether this is an inline XBRL document, which carries the instance facts itself.
+    #[serde(rename = "@inlineXBRL", default)]
+    pub inline_xbrl: bool,
+
     /// File size in bytes.
-    #[serde(rename = "@size", default)]
-    pub size: Option<String>,
+    #[serde(
+        rename = "@size",
+        default,
+        deserialize_with = "deserialize_optional_human_size"
+    )]
+    pub size: Option<u64>,
 
     /// Human-readable description of the file's purpose.
     #[serde(rename = "@description", default)]
@@ -222,6 +242,123 @@ pub struct Enclosure {
     pub enclosure_type: Option<String>,
 }
 
+#[cfg(feature = "rss")]
+impl Item {
+    /// The XBRL instance of the item's filing; see [`XbrlFiling::instance_document`].
+    pub fn instance_document(&self) -> Option<&XbrlFile> {
+        self.xbrl_filing.as_ref()?.instance_document()
+    }
+}
+
+#[cfg(feature = "rss")]
+impl XbrlFiling {
+    /// Every file listed for the filing, in sequence order.
+    pub fn files(&self) -> &[XbrlFile] {
+        self.xbrl_files
+            .as_ref()
+            .map_or(&[], |files| files.files.as_slice())
+    }
+
+    /// Files of one type, e.g. `EX-101.CAL`, ignoring case.
+    pub fn files_of_type<'a>(&'a self, file_type: &'a str) -> impl Iterator<Item = &'a XbrlFile> {
+        self.files()
+            .iter()
+            .filter(move |file| file.is_type(file_type))
+    }
+
+    /// The XBRL instance: the `EX-101.INS` file of a traditional XBRL filing, or the inline
+    /// XBRL document that carries the facts.
+    pub fn instance_document(&self) -> Option<&XbrlFile> {
+        self.files_of_type("EX-101.INS")
+            .next()
+            .or_else(|| self.files().iter().find(|file| file.inline_xbrl))
+    }
+
+    /// The taxonomy extension schema (`EX-101.SCH`).
+    pub fn schema(&self) -> Option<&XbrlFile> {
+        self.files_of_type("EX-101.SCH").next()
+    }
+
+    /// Downloads every listed file into `dir`, returning the written paths.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first file that fails; see [`XbrlFile::download`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_all(&self, edgar: &Edgar, dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+        self.download_files(edgar, dir.as_ref(), self.files().iter())
+            .await
+    }
+
+    /// Downloads the files of the given types (`EX-101.INS`, `EX-101.SCH`, ...) into `dir`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download_types(
+        &self,
+        edgar: &Edgar,
+        dir: impl AsRef<Path>,
+        file_types: &[&str],
+    ) -> Result<Vec<PathBuf>> {
+        let files = self
+            .files()
+            .iter()
+            .filter(|file| file_types.iter().any(|file_type| file.is_type(file_type)));
+        self.download_files(edgar, dir.as_ref(), files).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn download_files(
+        &self,
+        edgar: &Edgar,
+        dir: &Path,
+        files: impl Iterator<Item = &XbrlFile>,
+    ) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for file in files {
+            paths.push(file.download(edgar, dir).await?);
+        }
+        Ok(paths)
+    }
+}
+
+#[cfg(feature = "rss")]
+impl XbrlFile {
+    /// Whether the file has the given type, ignoring case.
+    pub fn is_type(&self, file_type: &str) -> bool {
+        self.file_type
+            .as_deref()
+            .is_some_and(|t| t.trim().eq_ignore_ascii_case(file_type))
+    }
+
+    /// The file's name, from the feed or else the last segment of its URL.
+    pub fn file_name(&self) -> Option<&str> {
+        let name = match self.file.as_deref() {
+            Some(file) if !file.trim().is_empty() => file,
+            _ => self.url.as_deref()?.rsplit('/').next()?,
+        };
+        Path::new(name.trim()).file_name()?.to_str()
+    }
+
+    /// Downloads the file into `dir` under its [`file_name`](Self::file_name), through the
+    /// client's rate limiter, returning the written path.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if the feed gave no URL or file name, and the
+    /// errors of [`Edgar::download_to_file`] otherwise.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn download(&self, edgar: &Edgar, dir: impl AsRef<Path>) -> Result<PathBuf> {
+        let (Some(url), Some(name)) = (self.url.as_deref(), self.file_name()) else {
+            return Err(EdgarError::InvalidFormat(format!(
+                "XBRL file {:?} has no download URL",
+                self.sequence
+            )));
+        };
+        let path = dir.as_ref().join(name);
+        edgar.download_to_file(url, &path).await?;
+        Ok(path)
+    }
+}
+
 #[cfg(feature = "rss")]
 impl RssParser {
     pub fn new(config: RssConfig) -> Self {
@@ -264,10 +401,10 @@ impl RssParser {
                     match e.name().as_ref() {
                         b"link" => {
                             // Regular link - get text content
-                            if !e.attributes().any(|a| a.unwrap().key.as_ref() == b"href") {
-                                if let Ok(Event::Text(text)) = reader.read_event_into(&mut buf) {
-                                    link = text.unescape()?.into_owned();
-                                }
+                            if !e.attributes().any(|a| a.unwrap().key.as_ref() == b"href")
+                                && let Ok(Event::Text(text)) = reader.read_event_into(&mut buf)
+                            {
+                                link = text.unescape()?.into_owned();
                             }
                         }
                         b"atom:link" => {
@@ -0,0 +1,383 @@
+//! Item sections of 10-K and 10-Q reports.
+//!
+//! Annual and quarterly reports are organized into numbered items: `Item 1A. Risk Factors`,
+//! `Item 7. Management's Discussion and Analysis`, and so on. This module converts a primary
+//! document (HTML or the plain text of older filings) to text with
+//! [`to_text`](super::html::to_text), finds the item headings, and
+//! returns the text of each item so only the relevant part of a filing needs to be read,
+//! indexed, or handed to a language model.
+//!
+//! Item headings usually appear twice, once in the table of contents and once in the body.
+//! When an item is found more than once, the longest candidate wins, which discards the
+//! table of contents entry.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::sections::{Item, extract_section};
+//!
+//! let html = "<p>PART I</p>\
+//!     <p>Item 1A. Risk Factors</p><p>Our supply chain is concentrated.</p>\
+//!     <p>Item 1B. Unresolved Staff Comments</p><p>None.</p>";
+//!
+//! let section = extract_section(html, "10-K", &Item::RiskFactors).unwrap();
+//! assert_eq!(section.code, "1A");
+//! assert_eq!(section.title, "Risk Factors");
+//! assert_eq!(section.text, "Our supply chain is concentrated.");
+//! ```
+
+use super::html::to_text;
+use std::collections::HashMap;
+
+/// Headings longer than this are sentences that merely mention an item.
+const MAX_HEADING_LEN: usize = 200;
+
+/// A titled item of a 10-K or 10-Q.
+///
+/// The same item can carry different numbers on the two forms: risk factors are Item 1A of
+/// a 10-K but Item 1A of Part II of a 10-Q, and MD&A is Item 7 of a 10-K but Item 2 of
+/// Part I of a 10-Q. Variants name the content, so one value finds it on either form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Item {
+    /// 10-K Item 1
+    Business,
+    /// 10-K Item 1A, 10-Q Part II Item 1A
+    RiskFactors,
+    /// 10-K Item 1B
+    UnresolvedStaffComments,
+    /// 10-K Item 1C
+    Cybersecurity,
+    /// 10-K Item 2
+    Properties,
+    /// 10-K Item 3, 10-Q Part II Item 1
+    LegalProceedings,
+    /// 10-K Item 4, 10-Q Part II Item 4
+    MineSafetyDisclosures,
+    /// 10-K Item 5
+    MarketForEquity,
+    /// 10-K Item 6 (selected financial data, reserved since 2021)
+    SelectedFinancialData,
+    /// 10-K Item 7, 10-Q Part I Item 2
+    ManagementDiscussion,
+    /// 10-K Item 7A, 10-Q Part I Item 3
+    MarketRisk,
+    /// 10-K Item 8, 10-Q Part I Item 1
+    FinancialStatements,
+    /// 10-K Item 9
+    ChangesInAccountants,
+    /// 10-K Item 9A, 10-Q Part I Item 4
+    ControlsAndProcedures,
+    /// 10-K Item 9B, 10-Q Part II Item 5
+    OtherInformation,
+    /// 10-K Item 9C
+    ForeignJurisdictionInspections,
+    /// 10-K Item 10
+    DirectorsAndOfficers,
+    /// 10-K Item 11
+    ExecutiveCompensation,
+    /// 10-K Item 12
+    SecurityOwnership,
+    /// 10-K Item 13
+    RelatedTransactions,
+    /// 10-K Item 14
+    AccountantFees,
+    /// 10-K Item 15, 10-Q Part II Item 6
+    Exhibits,
+    /// 10-K Item 16
+    Form10KSummary,
+    /// 10-Q Part II Item 2
+    UnregisteredSales,
+    /// 10-Q Part II Item 3
+    SeniorSecuritiesDefaults,
+    /// Any other item, by its number as printed (e.g. `"1D"`)
+    Other(String),
+}
+
+impl Item {
+    /// Names the item numbered `code` in `part` (1 to 4) of a report of type `form`.
+    ///
+    /// 10-Q numbering applies when `form` starts with `10-Q`; everything else is treated as
+    /// a 10-K, whose item numbers are unique across parts.
+    pub fn from_code(form: &str, part: Option<u8>, code: &str) -> Self {
+        let code = code.to_ascii_uppercase();
+        let item = if form.to_ascii_uppercase().starts_with("10-Q") {
+            match (part.unwrap_or(1), code.as_str()) {
+                (1, "1") => Some(Item::FinancialStatements),
+                (1, "2") => Some(Item::ManagementDiscussion),
+                (1, "3") => Some(Item::MarketRisk),
+                (1, "4") => Some(Item::ControlsAndProcedures),
+                (2, "1") => Some(Item::LegalProceedings),
+                (2, "1A") => Some(Item::RiskFactors),
+                (2, "2") => Some(Item::UnregisteredSales),
+                (2, "3") => Some(Item::SeniorSecuritiesDefaults),
+                (2, "4") => Some(Item::MineSafetyDisclosures),
+                (2, "5") => Some(Item::OtherInformation),
+                (2, "6") => Some(Item::Exhibits),
+                _ => None,
+            }
+        } else {
+            match code.as_str() {
+                "1" => Some(Item::Business),
+                "1A" => Some(Item::RiskFactors),
+                "1B" => Some(Item::UnresolvedStaffComments),
+                "1C" => Some(Item::Cybersecurity),
+                "2" => Some(Item::Properties),
+                "3" => Some(Item::LegalProceedings),
+                "4" => Some(Item::MineSafetyDisclosures),
+                "5" => Some(Item::MarketForEquity),
+                "6" => Some(Item::SelectedFinancialData),
+                "7" => Some(Item::ManagementDiscussion),
+                "7A" => Some(Item::MarketRisk),
+                "8" => Some(Item::FinancialStatements),
+                "9" => Some(Item::ChangesInAccountants),
+                "9A" => Some(Item::ControlsAndProcedures),
+                "9B" => Some(Item::OtherInformation),
+                "9C" => Some(Item::ForeignJurisdictionInspections),
+                "10" => Some(Item::DirectorsAndOfficers),
+                "11" => Some(Item::ExecutiveCompensation),
+                "12" => Some(Item::SecurityOwnership),
+                "13" => Some(Item::RelatedTransactions),
+                "14" => Some(Item::AccountantFees),
+                "15" => Some(Item::Exhibits),
+                "16" => Some(Item::Form10KSummary),
+                _ => None,
+            }
+        };
+        item.unwrap_or(Item::Other(code))
+    }
+}
+
+/// One item of a report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Section {
+    pub item: Item,
+
+    /// Part the item belongs to (1 to 4), if the document has `PART` headings
+    pub part: Option<u8>,
+
+    /// Item number as printed, upper-cased (e.g. `"7A"`)
+    pub code: String,
+
+    /// Heading text after the item number, such as `"Risk Factors"`
+    pub title: String,
+
+    /// Plain text of the item, one paragraph per line
+    pub text: String,
+}
+
+struct Heading {
+    line: usize,
+    part: Option<u8>,
+    code: String,
+    title: String,
+    body_start: usize,
+}
+
+/// Splits a 10-K or 10-Q primary document into its items, in document order.
+///
+/// `document` may be HTML (including inline XBRL) or plain text. `form` selects the item
+/// numbering; see [`Item::from_code`]. Documents without recognizable item headings yield no
+/// sections.
+pub fn split_sections(document: &str, form: &str) -> Vec<Section> {
+    let lines = text_lines(document);
+
+    let mut headings = Vec::new();
+    let mut boundaries = Vec::new();
+    let mut part = None;
+    for (idx, line) in lines.iter().enumerate() {
+        if let Some(number) = parse_part(line) {
+            part = Some(number);
+            boundaries.push(idx);
+        } else if is_signatures(line) {
+            boundaries.push(idx);
+        } else if let Some((code, title)) = parse_heading(line) {
+            let (title, body_start) = match title {
+                // The title is often in the next table cell or paragraph.
+                None => match lines.get(idx + 1) {
+                    Some(next)
+                        if next.len() <= MAX_HEADING_LEN && parse_heading(next).is_none() =>
+                    {
+                        (next.clone(), idx + 2)
+                    }
+                    _ => (String::new(), idx + 1),
+                },
+                Some(title) => (title, idx + 1),
+            };
+            boundaries.push(idx);
+            headings.push(Heading {
+                line: idx,
+                part,
+                code,
+                title,
+                body_start,
+            });
+        }
+    }
+
+    let mut best: HashMap<Item, Section> = HashMap::new();
+    let mut order: HashMap<Item, usize> = HashMap::new();
+    for heading in headings {
+        let end = boundaries
+            .iter()
+            .copied()
+            .find(|&b| b > heading.line)
+            .unwrap_or(lines.len());
+        let text = lines[heading.body_start.min(end)..end].join("\n");
+        let item = Item::from_code(form, heading.part, &heading.code);
+
+        if best.get(&item).is_some_and(|s| s.text.len() >= text.len()) {
+            continue;
+        }
+        order.insert(item.clone(), heading.line);
+        best.insert(
+            item.clone(),
+            Section {
+                item,
+                part: heading.part,
+                code: heading.code,
+                title: heading.title,
+                text,
+            },
+        );
+    }
+
+    let mut sections: Vec<Section> = best.into_values().collect();
+    sections.sort_by_key(|s| order[&s.item]);
+    sections
+}
+
+/// Returns one item of a 10-K or 10-Q primary document, if present.
+pub fn extract_section(document: &str, form: &str, item: &Item) -> Option<Section> {
+    split_sections(document, form)
+        .into_iter()
+        .find(|section| &section.item == item)
+}
+
+/// Parses `Item 7A. Quantitative and ...` into its number and title.
+///
+/// Returns `None` for lines that only mention an item ("Item 7 of this report"), and a
+/// `None` title when the heading is just the number.
+fn parse_heading(line: &str) -> Option<(String, Option<String>)> {
+    if line.len() > MAX_HEADING_LEN {
+        return None;
+    }
+    let rest = strip_prefix_ignore_case(line, "item")?;
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim_start();
+
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 || digits > 2 {
+        return None;
+    }
+    let mut code_len = digits;
+    let mut chars = rest[digits..].chars();
+    if let Some(letter) = chars.next().filter(|c| c.is_ascii_alphabetic())
+        && !chars.next().is_some_and(|c| c.is_alphanumeric())
+    {
+        code_len += letter.len_utf8();
+    }
+    let code = rest[..code_len].to_ascii_uppercase();
+
+    let after = &rest[code_len..];
+    if after.starts_with(|c: char| c.is_alphanumeric()) {
+        return None;
+    }
+    let title = after.trim_start_matches(|c: char| {
+        c.is_whitespace() || matches!(c, '.' | ':' | '|' | '-' | '\u{2013}' | '\u{2014}')
+    });
+    if title.starts_with(char::is_lowercase) {
+        return None;
+    }
+    let title =
+        title.trim_end_matches(|c: char| c.is_ascii_digit() || c.is_whitespace() || c == '|');
+    Some((code, (!title.is_empty()).then(|| title.to_string())))
+}
+
+/// Parses a `PART II` heading into its number.
+fn parse_part(line: &str) -> Option<u8> {
+    let rest = strip_prefix_ignore_case(line, "part")?;
+    let numeral = rest
+        .trim_start()
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()?;
+    let number = match numeral.to_ascii_uppercase().as_str() {
+        "I" => 1,
+        "II" => 2,
+        "III" => 3,
+        "IV" => 4,
+        _ => return None,
+    };
+    // "Part II" followed by a long sentence is a cross reference, not a heading.
+    (line.len() <= 100 && rest.starts_with(char::is_whitespace)).then_some(number)
+}
+
+fn is_signatures(line: &str) -> bool {
+    line.eq_ignore_ascii_case("signatures") || line.eq_ignore_ascii_case("signature")
+}
+
+fn strip_prefix_ignore_case<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = line.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &line[prefix.len()..])
+}
+
+/// Converts a document to trimmed, non-empty lines of text, one per block.
+fn text_lines(document: &str) -> Vec<String> {
+    to_text(document)
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_heading() {
+        assert_eq!(
+            parse_heading("ITEM 7A. QUANTITATIVE AND QUALITATIVE DISCLOSURES"),
+            Some((
+                "7A".to_string(),
+                Some("QUANTITATIVE AND QUALITATIVE DISCLOSURES".to_string())
+            ))
+        );
+        assert_eq!(
+            parse_heading("Item 1A \u{2014} Risk Factors 12"),
+            Some(("1A".to_string(), Some("Risk Factors".to_string())))
+        );
+        assert_eq!(parse_heading("Item 8."), Some(("8".to_string(), None)));
+        assert_eq!(parse_heading("Item 7 of this report discusses"), None);
+        assert_eq!(parse_heading("Items 1 and 2. Business"), None);
+        assert_eq!(parse_part("PART II \u{2014} OTHER INFORMATION"), Some(2));
+        assert_eq!(parse_part("Particulars"), None);
+    }
+
+    #[test]
+    fn test_quarterly_items_depend_on_part() {
+        let text = "PART I\nItem 2. Management's Discussion\nRevenue grew.\n\
+            PART II\nItem 1A. Risk Factors\nNo material changes.\nItem 2. Unregistered Sales\nNone.";
+        let sections = split_sections(text, "10-Q");
+        let items: Vec<_> = sections.iter().map(|s| &s.item).collect();
+        assert_eq!(
+            items,
+            vec![
+                &Item::ManagementDiscussion,
+                &Item::RiskFactors,
+                &Item::UnregisteredSales
+            ]
+        );
+        assert_eq!(sections[1].part, Some(2));
+        assert_eq!(sections[1].text, "No material changes.");
+    }
+
+    #[test]
+    fn test_entities_and_hidden_header() {
+        let html = "<ix:header><ix:hidden>dei facts</ix:hidden></ix:header>\
+            <div>Item&#160;1.&nbsp;Business</div><div>Apple&#8217;s products &amp; services</div>";
+        let section = extract_section(html, "10-K", &Item::Business).unwrap();
+        assert_eq!(section.text, "Apple\u{2019}s products & services");
+    }
+}
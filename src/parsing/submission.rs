@@ -0,0 +1,251 @@
+//! Complete submission text file parser.
+//!
+//! Every EDGAR filing is archived as a single `<accession>.txt` file that concatenates the
+//! SGML header and every document in the submission (primary document, exhibits, XBRL
+//! instance, graphics, ...). Each document is wrapped in a `<DOCUMENT>` block with a few
+//! header tags followed by a `<TEXT>` body:
+//!
+//! ```text
+//! <DOCUMENT>
+//! <TYPE>EX-21.1
+//! <SEQUENCE>3
+//! <FILENAME>a10-kexhibit2112023.htm
+//! <DESCRIPTION>EX-21.1
+//! <TEXT>
+//! ...
+//! </TEXT>
+//! </DOCUMENT>
+//! ```
+//!
+//! This module splits such a file into [`SubmissionDocument`] values so a specific exhibit
+//! can be located by type or filename without downloading the filing directory first.
+//!
+//! # Example
+//! ```
+//! use edgarkit::parsing::submission::{SubmissionConfig, SubmissionParser};
+//!
+//! let content = "<DOCUMENT>\n<TYPE>EX-99.1\n<SEQUENCE>2\n<FILENAME>ex99.htm\n<TEXT>\nPress release\n</TEXT>\n</DOCUMENT>\n";
+//! let parser = SubmissionParser::new(SubmissionConfig::default());
+//! let documents = parser.parse(content).unwrap();
+//!
+//! assert_eq!(documents[0].document_type, "EX-99.1");
+//! assert_eq!(documents[0].body, "Press release");
+//! ```
+
+use crate::error::{EdgarError, Result};
+
+const DOCUMENT_OPEN: &str = "<DOCUMENT>";
+const DOCUMENT_CLOSE: &str = "</DOCUMENT>";
+const TEXT_OPEN: &str = "<TEXT>";
+const TEXT_CLOSE: &str = "</TEXT>";
+
+/// Configuration options for submission file parsing.
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionConfig {
+    /// Optional limit on the number of documents to return
+    pub max_documents: Option<usize>,
+
+    /// Document types to keep (e.g. `"10-K"`, `"EX-21.1"`); empty keeps everything
+    pub filter_types: Vec<String>,
+}
+
+/// A single `<DOCUMENT>` block from a complete submission text file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubmissionDocument {
+    /// Document type from the `<TYPE>` tag (e.g. "10-K", "EX-31.1", "GRAPHIC").
+    pub document_type: String,
+
+    /// Position of the document within the submission, starting at 1.
+    pub sequence: Option<u32>,
+
+    /// Original filename of the document within the filing directory.
+    pub filename: Option<String>,
+
+    /// Free-form description supplied by the filer.
+    pub description: Option<String>,
+
+    /// Raw content between `<TEXT>` and `</TEXT>`.
+    ///
+    /// HTML and XML documents are returned verbatim; binary files (images, PDFs, zips)
+    /// are uuencoded by EDGAR and are left encoded here.
+    pub body: String,
+}
+
+impl SubmissionDocument {
+    /// Returns true if the body is a uuencoded binary payload rather than text.
+    pub fn is_uuencoded(&self) -> bool {
+        self.body.starts_with("begin ")
+    }
+}
+
+/// Splits complete submission text files into their individual documents.
+pub struct SubmissionParser {
+    config: SubmissionConfig,
+}
+
+impl SubmissionParser {
+    pub fn new(config: SubmissionConfig) -> Self {
+        Self { config }
+    }
+
+    /// Parses a complete submission text file into its documents.
+    ///
+    /// Documents are returned in the order they appear in the file, after applying the
+    /// configured type filter and document limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidFormat` if the content contains no `<DOCUMENT>` blocks,
+    /// if a block is not terminated, or if a block is missing its `<TYPE>` tag.
+    pub fn parse(&self, content: &str) -> Result<Vec<SubmissionDocument>> {
+        let mut documents = Vec::new();
+        let mut rest = content;
+        let mut found = false;
+
+        while let Some(start) = rest.find(DOCUMENT_OPEN) {
+            found = true;
+            let after_open = &rest[start + DOCUMENT_OPEN.len()..];
+            let end = after_open.find(DOCUMENT_CLOSE).ok_or_else(|| {
+                EdgarError::InvalidFormat("unterminated <DOCUMENT> block".to_string())
+            })?;
+
+            let document = parse_document(&after_open[..end])?;
+            rest = &after_open[end + DOCUMENT_CLOSE.len()..];
+
+            if !self.config.filter_types.is_empty()
+                && !self.config.filter_types.contains(&document.document_type)
+            {
+                continue;
+            }
+
+            documents.push(document);
+            if self
+                .config
+                .max_documents
+                .is_some_and(|max| documents.len() >= max)
+            {
+                break;
+            }
+        }
+
+        if !found {
+            return Err(EdgarError::InvalidFormat(
+                "no <DOCUMENT> blocks found in submission".to_string(),
+            ));
+        }
+
+        Ok(documents)
+    }
+}
+
+/// Parses the contents of a single `<DOCUMENT>` block (without the enclosing tags).
+fn parse_document(block: &str) -> Result<SubmissionDocument> {
+    let (header, body) = match block.find(TEXT_OPEN) {
+        Some(pos) => {
+            let text = &block[pos + TEXT_OPEN.len()..];
+            let text = match text.rfind(TEXT_CLOSE) {
+                Some(end) => &text[..end],
+                None => text,
+            };
+            (&block[..pos], text.trim().to_string())
+        }
+        None => (block, String::new()),
+    };
+
+    let mut document_type = None;
+    let mut sequence = None;
+    let mut filename = None;
+    let mut description = None;
+
+    for line in header.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("<TYPE>") {
+            document_type = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("<SEQUENCE>") {
+            sequence = value.trim().parse().ok();
+        } else if let Some(value) = line.strip_prefix("<FILENAME>") {
+            filename = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("<DESCRIPTION>") {
+            description = Some(value.trim().to_string());
+        }
+    }
+
+    let document_type = document_type
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| EdgarError::InvalidFormat("<DOCUMENT> block missing <TYPE>".to_string()))?;
+
+    Ok(SubmissionDocument {
+        document_type,
+        sequence,
+        filename,
+        description: description.filter(|d| !d.is_empty()),
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "<SEC-DOCUMENT>0000000000-24-000001.txt : 20240101\n\
+<SEC-HEADER>0000000000-24-000001.hdr.sgml : 20240101\n\
+CONFORMED SUBMISSION TYPE:\t8-K\n\
+</SEC-HEADER>\n\
+<DOCUMENT>\n\
+<TYPE>8-K\n\
+<SEQUENCE>1\n\
+<FILENAME>form8-k.htm\n\
+<DESCRIPTION>CURRENT REPORT\n\
+<TEXT>\n\
+<html><body>Item 8.01</body></html>\n\
+</TEXT>\n\
+</DOCUMENT>\n\
+<DOCUMENT>\n\
+<TYPE>GRAPHIC\n\
+<SEQUENCE>2\n\
+<FILENAME>logo.jpg\n\
+<TEXT>\n\
+begin 644 logo.jpg\n\
+M_]C_X``02D9)1@`!\n\
+end\n\
+</TEXT>\n\
+</DOCUMENT>\n\
+</SEC-DOCUMENT>\n";
+
+    #[test]
+    fn test_parse_documents() {
+        let parser = SubmissionParser::new(SubmissionConfig::default());
+        let documents = parser.parse(SAMPLE).unwrap();
+        assert_eq!(documents.len(), 2);
+
+        let primary = &documents[0];
+        assert_eq!(primary.document_type, "8-K");
+        assert_eq!(primary.sequence, Some(1));
+        assert_eq!(primary.filename.as_deref(), Some("form8-k.htm"));
+        assert_eq!(primary.description.as_deref(), Some("CURRENT REPORT"));
+        assert_eq!(primary.body, "<html><body>Item 8.01</body></html>");
+        assert!(!primary.is_uuencoded());
+
+        let graphic = &documents[1];
+        assert_eq!(graphic.description, None);
+        assert!(graphic.is_uuencoded());
+    }
+
+    #[test]
+    fn test_filter_types() {
+        let config = SubmissionConfig {
+            filter_types: vec!["GRAPHIC".to_string()],
+            ..Default::default()
+        };
+        let documents = SubmissionParser::new(config).parse(SAMPLE).unwrap();
+        assert_eq!(documents.len(), 1);
+        assert_eq!(documents[0].filename.as_deref(), Some("logo.jpg"));
+    }
+
+    #[test]
+    fn test_invalid_submission() {
+        let parser = SubmissionParser::new(SubmissionConfig::default());
+        assert!(parser.parse("not a submission").is_err());
+        assert!(parser.parse("<DOCUMENT>\n<TYPE>8-K\n<TEXT>\n").is_err());
+    }
+}
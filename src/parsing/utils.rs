@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use serde::{Deserializer, de};
 use std::fmt;
 use std::str::FromStr;
@@ -47,3 +48,197 @@ where
 
     deserializer.deserialize_any(Visitor)
 }
+
+/// Parses an EDGAR date in either form the SEC uses: `2023-07-03` (submissions, feeds,
+/// quarterly indices) or `20230703` (daily indices).
+///
+/// ```rust
+/// # use edgarkit::parse_edgar_date;
+/// # use chrono::NaiveDate;
+/// let date = NaiveDate::from_ymd_opt(2023, 7, 3);
+/// assert_eq!(parse_edgar_date("20230703"), date);
+/// assert_eq!(parse_edgar_date(" 2023-07-03 "), date);
+/// assert_eq!(parse_edgar_date("07/03/2023"), None);
+/// ```
+pub fn parse_edgar_date(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    let format = if s.len() == 8 { "%Y%m%d" } else { "%Y-%m-%d" };
+    NaiveDate::parse_from_str(s, format).ok()
+}
+
+/// Deserializes a date string accepted by [`parse_edgar_date`].
+pub fn deserialize_edgar_date<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct Visitor;
+
+    impl de::Visitor<'_> for Visitor {
+        type Value = NaiveDate;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a date as YYYY-MM-DD or YYYYMMDD")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            parse_edgar_date(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+        }
+    }
+
+    deserializer.deserialize_str(Visitor)
+}
+
+const SIZE_UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+
+/// Parses a byte count such as `"16267"`, `"302 KB"`, or `"1.2 MB"`.
+///
+/// Units are binary (1 KB = 1024 bytes), matching how EDGAR listings and feeds render sizes.
+/// Returns `None` for empty or unrecognized input.
+///
+/// ```rust
+/// # use edgarkit::parsing::utils::parse_human_size;
+/// assert_eq!(parse_human_size("16267"), Some(16267));
+/// assert_eq!(parse_human_size("302 KB"), Some(302 * 1024));
+/// assert_eq!(parse_human_size("1.5MB"), Some(1024 * 1024 * 3 / 2));
+/// assert_eq!(parse_human_size(""), None);
+/// ```
+pub fn parse_human_size(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(bytes) = value.parse::<u64>() {
+        return Some(bytes);
+    }
+
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(value.len());
+    let number: f64 = value[..split].replace(',', "").parse().ok()?;
+    let unit = value[split..].trim().to_ascii_uppercase();
+    let power = match unit.trim_end_matches("IB").trim_end_matches('B') {
+        "" | "BYTES" => 0,
+        "K" => 1,
+        "M" => 2,
+        "G" => 3,
+        "T" => 4,
+        _ => return None,
+    };
+    Some((number * 1024f64.powi(power)).round() as u64)
+}
+
+/// Formats a byte count for display, e.g. `"512 B"`, `"302.0 KB"`, `"1.2 MB"`.
+///
+/// ```rust
+/// # use edgarkit::parsing::utils::format_human;
+/// assert_eq!(format_human(512), "512 B");
+/// assert_eq!(format_human(1_258_291), "1.2 MB");
+/// ```
+pub fn format_human(bytes: u64) -> String {
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < SIZE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, SIZE_UNITS[unit])
+    }
+}
+
+/// Deserializes a size given as a number or a human-readable string; empty means 0.
+pub fn deserialize_human_size<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_optional_human_size(deserializer).map(Option::unwrap_or_default)
+}
+
+/// Like [`deserialize_human_size`], but maps empty strings and nulls to `None`.
+pub fn deserialize_optional_human_size<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct Visitor;
+
+    impl<'de> de::Visitor<'de> for Visitor {
+        type Value = Option<u64>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a byte count or a size such as \"302 KB\"")
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.trim().is_empty() {
+                return Ok(None);
+            }
+            parse_human_size(v)
+                .map(Some)
+                .ok_or_else(|| E::custom(format!("invalid size {:?}", v)))
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Some(v))
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u64::try_from(v).map(Some).map_err(E::custom)
+        }
+
+        fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Some(v.max(0.0).round() as u64))
+        }
+
+        // XML elements such as `<size>1 MB</size>` arrive as a map holding their text.
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::MapAccess<'de>,
+        {
+            let mut size = None;
+            while let Some(key) = map.next_key::<String>()? {
+                let value: String = map.next_value()?;
+                if key == "$text" || key == "$value" {
+                    size = Visitor.visit_str(&value)?;
+                }
+            }
+            Ok(size)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+    }
+
+    deserializer.deserialize_any(Visitor)
+}
@@ -0,0 +1,498 @@
+//! Filings-to-database pipeline built from the crate's own parts.
+//!
+//! Most applications that ingest EDGAR end up wiring the same steps together: backfill
+//! history from the quarterly indices, follow new filings as they arrive, attach company
+//! metadata, reshape everything into one row type, and write it somewhere without
+//! duplicates. [`Pipeline`] does exactly that and is meant as a starting point to copy from
+//! as much as a finished tool:
+//!
+//! 1. **Backfill** streams the quarterly index for each quarter in a range
+//!    ([`IndexOperations::stream_period_filings`]).
+//! 2. **Watch** polls the current filings feed ([`FeedOperations::current_feed`]).
+//! 3. **Normalize** turns index entries and feed entries into [`FilingRecord`]s.
+//! 4. **Enrich** attaches names, tickers, exchanges, and SIC codes with an [`Enricher`].
+//! 5. **Deliver** sends each record to any [`Sink`] and then marks it in a [`SeenSet`], so
+//!    with a persistent set every filing reaches the sink once across restarts. A record is
+//!    redelivered only if the process stops between the two steps.
+//!
+//! With the `sqlite` feature, [`SqliteSink`] is a reference sink that upserts records into
+//! a `filings` table.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, EdgarPeriod, FilingOptions, Pipeline, Quarter, SeenSet, SqliteSink};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let mut sink = SqliteSink::open("filings.db")?;
+//! let mut pipeline = Pipeline::new(&edgar)
+//!     .with_seen_set(SeenSet::open_sqlite("filings.db")?)
+//!     .with_options(FilingOptions::new().with_form_types(vec!["8-K".into(), "10-K".into()]));
+//!
+//! let summary = pipeline
+//!     .backfill(
+//!         EdgarPeriod::new(2024, Quarter::Q1)?,
+//!         EdgarPeriod::new(2024, Quarter::Q4)?,
+//!         &mut sink,
+//!     )
+//!     .await?;
+//! println!("backfilled {} filings", summary.delivered);
+//!
+//! // Runs until an error occurs.
+//! pipeline.watch(&mut sink).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Edgar;
+use super::enrich::{CikRecord, CompanyMetadata, Enricher};
+use super::error::Result;
+use super::index::EdgarPeriod;
+use super::options::{FeedOptions, FilingOptions};
+use super::rt::sleep;
+use super::seen::SeenSet;
+use super::sink::Sink;
+use super::traits::{FeedOperations, IndexOperations};
+use crate::parsing::atom::AtomEntry;
+use crate::parsing::index::IndexEntry;
+use crate::parsing::utils::parse_edgar_date;
+use chrono::NaiveDate;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::time::Duration;
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Largest page the current filings feed serves.
+const CURRENT_FEED_COUNT: u32 = 100;
+
+/// One filing as delivered to a pipeline sink.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FilingRecord {
+    /// Accession number in dashed form (`0000320193-23-000106`).
+    pub accession_number: String,
+
+    pub cik: u64,
+
+    /// Current company name when the CIK resolves, otherwise the name on the filing.
+    pub company_name: String,
+
+    /// Upper-cased form type (`10-K`, `8-K/A`).
+    pub form_type: String,
+
+    pub filed: NaiveDate,
+
+    /// Link to the filing (full submission text file or index page).
+    pub url: String,
+
+    pub tickers: Vec<String>,
+
+    /// Exchange for each ticker (same order as `tickers`).
+    pub exchanges: Vec<String>,
+
+    pub sic: Option<String>,
+}
+
+impl FilingRecord {
+    /// Builds a record from an index entry. Returns `None` if the entry's URL carries no
+    /// accession number.
+    pub fn from_index_entry(entry: &IndexEntry) -> Option<Self> {
+        Some(Self {
+            accession_number: entry.accession_number()?.to_string(),
+            cik: entry.cik,
+            company_name: entry.company_name.trim().to_string(),
+            form_type: entry.form_type.trim().to_uppercase(),
+            filed: entry.date_filed,
+            url: entry.url.clone(),
+            tickers: Vec::new(),
+            exchanges: Vec::new(),
+            sic: None,
+        })
+    }
+
+    /// Builds a record from a current or company feed entry. Returns `None` if the entry
+    /// lacks an accession number, CIK, form type, or filing date.
+    pub fn from_atom_entry(entry: &AtomEntry) -> Option<Self> {
+        let content = entry.content.as_ref();
+        let filed = content
+            .and_then(|c| c.filing_date.as_deref())
+            .or(entry.updated.as_deref())
+            .and_then(|date| parse_edgar_date(date.get(..10)?))?;
+        let url = content
+            .and_then(|c| c.filing_href.clone())
+            .unwrap_or_else(|| entry.get_primary_link());
+
+        Some(Self {
+            accession_number: entry.accession_number()?,
+            cik: entry.cik()?,
+            company_name: entry.company_name().unwrap_or_default(),
+            form_type: entry.form_type()?.trim().to_uppercase(),
+            filed,
+            url,
+            tickers: Vec::new(),
+            exchanges: Vec::new(),
+            sic: None,
+        })
+    }
+
+    /// Copies name, listings, and industry from resolved company metadata.
+    fn apply(&mut self, company: &CompanyMetadata) {
+        if let Some(name) = &company.name {
+            self.company_name = name.clone();
+        }
+        self.tickers = company.tickers.clone();
+        self.exchanges = company.exchanges.clone();
+        if company.sic.is_some() {
+            self.sic = company.sic.clone();
+        }
+    }
+}
+
+impl CikRecord for FilingRecord {
+    fn ciks(&self) -> Vec<u64> {
+        vec![self.cik]
+    }
+
+    fn sic_for(&self, _cik: u64) -> Option<String> {
+        self.sic.clone()
+    }
+}
+
+/// Counts from one pipeline run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PipelineSummary {
+    /// Records read from indices or feeds.
+    pub received: usize,
+
+    /// Records sent to the sink.
+    pub delivered: usize,
+
+    /// Records skipped as already delivered, filtered out, or missing an accession number.
+    pub skipped: usize,
+}
+
+impl std::ops::AddAssign for PipelineSummary {
+    fn add_assign(&mut self, other: Self) {
+        self.received += other.received;
+        self.delivered += other.delivered;
+        self.skipped += other.skipped;
+    }
+}
+
+/// Backfills and watches filings, delivering each one to a sink once.
+pub struct Pipeline {
+    edgar: Edgar,
+    enricher: Enricher,
+    seen: SeenSet,
+    options: Option<FilingOptions>,
+    batch_size: usize,
+    poll_interval: Duration,
+}
+
+impl Pipeline {
+    /// A pipeline with an in-memory seen set, no filters, and a one-minute poll interval.
+    pub fn new(edgar: &Edgar) -> Self {
+        Self {
+            edgar: edgar.clone(),
+            enricher: Enricher::new(edgar),
+            seen: SeenSet::in_memory(),
+            options: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    /// Remembers delivered accession numbers in `seen`. Use a persistent set to avoid
+    /// redelivering filings after a restart.
+    pub fn with_seen_set(mut self, seen: SeenSet) -> Self {
+        self.seen = seen;
+        self
+    }
+
+    /// Uses a preconfigured enricher, for example one with submissions lookups enabled.
+    pub fn with_enricher(mut self, enricher: Enricher) -> Self {
+        self.enricher = enricher;
+        self
+    }
+
+    /// Filters filings by form type and CIK. For backfills, offset and limit apply per
+    /// quarter.
+    pub fn with_options(mut self, options: FilingOptions) -> Self {
+        self.options = Some(options);
+        self
+    }
+
+    /// Number of records enriched and delivered together during a backfill (default: 500).
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Delay between polls of the current feed in [`watch`](Self::watch) (default: 1 minute).
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// The set of accession numbers delivered so far.
+    pub fn seen(&self) -> &SeenSet {
+        &self.seen
+    }
+
+    /// Delivers the filings of every quarter from `start` through `end`.
+    ///
+    /// # Errors
+    ///
+    /// Stops at the first failure to download an index, enrich a batch, deliver a record,
+    /// or update the seen set. Records delivered before the error stay marked as seen, so
+    /// running the backfill again resumes where it stopped.
+    pub async fn backfill<S>(
+        &mut self,
+        start: EdgarPeriod,
+        end: EdgarPeriod,
+        sink: &mut S,
+    ) -> Result<PipelineSummary>
+    where
+        S: Sink<FilingRecord>,
+    {
+        let mut summary = PipelineSummary::default();
+        let mut period = start;
+        while period.key() <= end.key() {
+            let entries = self
+                .edgar
+                .stream_period_filings(period, self.options.clone())
+                .await?;
+            let mut batches = entries.chunks(self.batch_size);
+            while let Some(batch) = batches.next().await {
+                let entries = batch.into_iter().collect::<Result<Vec<_>>>()?;
+                let received = entries.len();
+                let records: Vec<FilingRecord> = entries
+                    .iter()
+                    .filter_map(FilingRecord::from_index_entry)
+                    .collect();
+                let mut batch_summary = self.process(records, sink).await?;
+                batch_summary.skipped += received - batch_summary.received;
+                batch_summary.received = received;
+                summary += batch_summary;
+            }
+            tracing::debug!(
+                "Backfilled {}Q{}: {:?}",
+                period.year(),
+                period.quarter().as_i32(),
+                summary
+            );
+            period = period.next();
+        }
+        Ok(summary)
+    }
+
+    /// Polls the current filings feed once and delivers the filings not seen before.
+    pub async fn poll_current<S>(&mut self, sink: &mut S) -> Result<PipelineSummary>
+    where
+        S: Sink<FilingRecord>,
+    {
+        let options = FeedOptions::new(None).with_count(CURRENT_FEED_COUNT)?;
+        let feed = self.edgar.current_feed(Some(options)).await?;
+        let received = feed.entries.len();
+
+        // The feed lists newest first; deliver in filing order.
+        let records: Vec<FilingRecord> = feed
+            .entries
+            .iter()
+            .rev()
+            .filter_map(FilingRecord::from_atom_entry)
+            .filter(|record| self.wanted(record))
+            .collect();
+        let mut summary = self.process(records, sink).await?;
+        summary.skipped += received - summary.received;
+        summary.received = received;
+        Ok(summary)
+    }
+
+    /// Polls the current filings feed until an error occurs, which is returned.
+    ///
+    /// The feed only shows the latest filings, so anything filed while the watcher is down
+    /// should be recovered with a [`backfill`](Self::backfill) of the affected quarter.
+    pub async fn watch<S>(&mut self, sink: &mut S) -> Result<()>
+    where
+        S: Sink<FilingRecord>,
+    {
+        loop {
+            let summary = self.poll_current(sink).await?;
+            if summary.delivered > 0 {
+                tracing::info!("Delivered {} new filings", summary.delivered);
+            }
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Enriches and delivers records from any source, skipping those already seen.
+    ///
+    /// Each record is marked as seen right after the sink accepts it.
+    pub async fn process<S>(
+        &mut self,
+        records: Vec<FilingRecord>,
+        sink: &mut S,
+    ) -> Result<PipelineSummary>
+    where
+        S: Sink<FilingRecord>,
+    {
+        let mut summary = PipelineSummary {
+            received: records.len(),
+            ..Default::default()
+        };
+
+        let mut batch = HashSet::new();
+        let mut fresh = Vec::with_capacity(records.len());
+        for record in records {
+            if !self.seen.contains(&record.accession_number)?
+                && batch.insert(record.accession_number.clone())
+            {
+                fresh.push(record);
+            } else {
+                summary.skipped += 1;
+            }
+        }
+        if fresh.is_empty() {
+            return Ok(summary);
+        }
+
+        for enriched in self.enricher.enrich(fresh).await? {
+            let mut record = enriched.record;
+            if let Some(company) = enriched.companies.first() {
+                record.apply(company);
+            }
+            let accession_number = record.accession_number.clone();
+            sink.send(record).await?;
+            self.seen.insert_if_new(&accession_number)?;
+            summary.delivered += 1;
+        }
+        Ok(summary)
+    }
+
+    /// Applies the form type and CIK filters to a feed record.
+    fn wanted(&self, record: &FilingRecord) -> bool {
+        let Some(options) = &self.options else {
+            return true;
+        };
+        let form_ok = options.form_types.as_ref().is_none_or(|forms| {
+            forms
+                .iter()
+                .any(|form| form.trim().eq_ignore_ascii_case(&record.form_type))
+        });
+        let cik_ok = options
+            .ciks
+            .as_ref()
+            .is_none_or(|ciks| ciks.contains(&record.cik));
+        form_ok && cik_ok
+    }
+}
+
+/// Reference sink that upserts records into an SQLite `filings` table.
+///
+/// Tickers and exchanges are stored as comma-separated text. The table is keyed by
+/// accession number, so replaying records is harmless.
+#[cfg(feature = "sqlite")]
+pub struct SqliteSink {
+    conn: rusqlite::Connection,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteSink {
+    /// Opens (or creates) the database at `path` and creates the `filings` table if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::Database` if the database cannot be opened or initialized.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        Self::from_connection(rusqlite::Connection::open(path)?)
+    }
+
+    /// Uses an existing connection, creating the `filings` table if needed.
+    pub fn from_connection(conn: rusqlite::Connection) -> Result<Self> {
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS filings (
+                accession_number TEXT PRIMARY KEY NOT NULL,
+                cik INTEGER NOT NULL,
+                company_name TEXT NOT NULL,
+                form_type TEXT NOT NULL,
+                filed TEXT NOT NULL,
+                url TEXT NOT NULL,
+                tickers TEXT NOT NULL,
+                exchanges TEXT NOT NULL,
+                sic TEXT
+            );
+            CREATE INDEX IF NOT EXISTS filings_cik ON filings (cik, filed);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, for queries.
+    pub fn connection(&self) -> &rusqlite::Connection {
+        &self.conn
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[async_trait::async_trait]
+impl Sink<FilingRecord> for SqliteSink {
+    async fn send(&mut self, record: FilingRecord) -> Result<()> {
+        let mut stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO filings
+                (accession_number, cik, company_name, form_type, filed, url, tickers, exchanges, sic)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        )?;
+        stmt.execute(rusqlite::params![
+            record.accession_number,
+            record.cik as i64,
+            record.company_name,
+            record.form_type,
+            record.filed.to_string(),
+            record.url,
+            record.tickers.join(","),
+            record.exchanges.join(","),
+            record.sic,
+        ])?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn test_sqlite_sink_upserts() {
+        let mut sink =
+            SqliteSink::from_connection(rusqlite::Connection::open_in_memory().unwrap()).unwrap();
+        let mut record = FilingRecord {
+            accession_number: "0000320193-23-000106".to_string(),
+            cik: 320193,
+            company_name: "Apple Inc.".to_string(),
+            form_type: "10-K".to_string(),
+            filed: NaiveDate::from_ymd_opt(2023, 11, 3).unwrap(),
+            url: "https://www.sec.gov/Archives/edgar/data/320193/0000320193-23-000106.txt"
+                .to_string(),
+            tickers: vec!["AAPL".to_string()],
+            exchanges: vec!["Nasdaq".to_string()],
+            sic: Some("3571".to_string()),
+        };
+        sink.send(record.clone()).await.unwrap();
+        record.company_name = "Apple".to_string();
+        sink.send(record).await.unwrap();
+
+        let (count, name): (i64, String) = sink
+            .connection()
+            .query_row(
+                "SELECT COUNT(*), MAX(company_name) FROM filings",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((count, name.as_str()), (1, "Apple"));
+    }
+}
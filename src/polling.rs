@@ -0,0 +1,240 @@
+//! Adaptive polling intervals for company watchlists.
+//!
+//! Polling every company in a large watchlist at the same fixed interval wastes most
+//! requests: a handful of issuers file several times a week while most file a few times a
+//! year. [`PollSchedule`] keeps an exponentially smoothed filing rate per CIK and polls each
+//! company roughly as often as it is expected to have something new, within the bounds of a
+//! [`PollBudget`]. When the watchlist as a whole would exceed the budget's request rate,
+//! every interval is stretched by the same factor, so active filers stay ahead of quiet ones.
+//!
+//! The schedule does no I/O and takes the current time as an argument, so it can drive
+//! custom watchers as well as
+//! [`FeedOperations::company_filings_stream`](crate::FeedOperations::company_filings_stream).
+
+use super::cik::Cik;
+use super::rt::Instant;
+use std::collections::HashMap;
+use std::time::Duration;
+
+const DEFAULT_REQUESTS_PER_MINUTE: f64 = 30.0;
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+const DEFAULT_SMOOTHING: f64 = 0.3;
+
+/// Limits for an adaptive polling schedule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollBudget {
+    requests_per_minute: f64,
+    min_interval: Duration,
+    max_interval: Duration,
+    smoothing: f64,
+}
+
+impl Default for PollBudget {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: DEFAULT_REQUESTS_PER_MINUTE,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            max_interval: DEFAULT_MAX_INTERVAL,
+            smoothing: DEFAULT_SMOOTHING,
+        }
+    }
+}
+
+impl PollBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Average polls per minute across the whole watchlist (default: 30).
+    pub fn with_requests_per_minute(mut self, requests_per_minute: f64) -> Self {
+        self.requests_per_minute = requests_per_minute.max(f64::MIN_POSITIVE);
+        self
+    }
+
+    /// Shortest interval between two polls of one company (default: 1 minute).
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Longest interval between two polls of one company (default: 6 hours).
+    ///
+    /// The budget may stretch intervals past this bound when the watchlist is too large
+    /// for `requests_per_minute`.
+    pub fn with_max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Weight of the latest observation in the smoothed filing rate, from 0.0 to 1.0
+    /// (default: 0.3). Higher values react faster to bursts of filings.
+    pub fn with_smoothing(mut self, smoothing: f64) -> Self {
+        self.smoothing = smoothing.clamp(0.0, 1.0);
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompanyState {
+    /// Smoothed filings per hour; `None` until the first observation.
+    rate: Option<f64>,
+    last_poll: Option<Instant>,
+}
+
+/// Per-CIK polling intervals adapted to observed filing activity.
+#[derive(Debug, Clone)]
+pub struct PollSchedule {
+    budget: PollBudget,
+    companies: HashMap<Cik, CompanyState>,
+}
+
+impl PollSchedule {
+    pub fn new(budget: PollBudget) -> Self {
+        Self {
+            budget,
+            companies: HashMap::new(),
+        }
+    }
+
+    /// Adds `cik` to the schedule. New companies are due immediately.
+    pub fn add(&mut self, cik: impl Into<Cik>) {
+        self.companies.entry(cik.into()).or_insert(CompanyState {
+            rate: None,
+            last_poll: None,
+        });
+    }
+
+    pub fn remove(&mut self, cik: impl Into<Cik>) {
+        self.companies.remove(&cik.into());
+    }
+
+    pub fn len(&self) -> usize {
+        self.companies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.companies.is_empty()
+    }
+
+    /// Records a poll of `cik` at `now` that found `new_filings` filings.
+    ///
+    /// The first poll of a company only sets its baseline; later polls update the smoothed
+    /// rate with the filings seen since the previous poll.
+    pub fn record(&mut self, cik: impl Into<Cik>, new_filings: usize, now: Instant) {
+        let smoothing = self.budget.smoothing;
+        let Some(state) = self.companies.get_mut(&cik.into()) else {
+            return;
+        };
+        if let Some(last) = state.last_poll {
+            let hours = now.saturating_duration_since(last).as_secs_f64() / 3600.0;
+            if hours > 0.0 {
+                let observed = new_filings as f64 / hours;
+                state.rate = Some(match state.rate {
+                    Some(rate) => smoothing * observed + (1.0 - smoothing) * rate,
+                    None => observed,
+                });
+            }
+        }
+        state.last_poll = Some(now);
+    }
+
+    /// Smoothed filings per hour for `cik`, once at least two polls have been recorded.
+    pub fn rate(&self, cik: impl Into<Cik>) -> Option<f64> {
+        self.companies.get(&cik.into()).and_then(|state| state.rate)
+    }
+
+    /// Time between polls of `cik`, aiming for about one new filing per poll.
+    pub fn interval(&self, cik: impl Into<Cik>) -> Duration {
+        match self.companies.get(&cik.into()) {
+            Some(state) => self.own_interval(state).mul_f64(self.stretch()),
+            None => self.budget.max_interval,
+        }
+    }
+
+    /// The company due next and when. Returns `None` for an empty schedule.
+    pub fn next_due(&self, now: Instant) -> Option<(Cik, Instant)> {
+        let stretch = self.stretch();
+        self.companies
+            .iter()
+            .map(|(cik, state)| {
+                let due = match state.last_poll {
+                    Some(last) => last + self.own_interval(state).mul_f64(stretch),
+                    None => now,
+                };
+                (*cik, due)
+            })
+            .min_by_key(|(cik, due)| (*due, *cik))
+    }
+
+    /// Interval from the company's own rate, before the budget is applied.
+    fn own_interval(&self, state: &CompanyState) -> Duration {
+        let PollBudget {
+            min_interval,
+            max_interval,
+            ..
+        } = self.budget;
+        match state.rate {
+            // Unknown activity starts in the middle of the range.
+            None => (min_interval + max_interval) / 2,
+            Some(rate) if rate <= 0.0 => max_interval,
+            Some(rate) => Duration::from_secs_f64((3600.0 / rate).min(max_interval.as_secs_f64()))
+                .max(min_interval),
+        }
+    }
+
+    /// Factor that brings the combined poll rate within the budget (at least 1.0).
+    fn stretch(&self) -> f64 {
+        let per_minute: f64 = self
+            .companies
+            .values()
+            .map(|state| 60.0 / self.own_interval(state).as_secs_f64().max(1.0))
+            .sum();
+        (per_minute / self.budget.requests_per_minute).max(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_active_filers_polled_more_often() {
+        let budget = PollBudget::new()
+            .with_min_interval(Duration::from_secs(60))
+            .with_max_interval(Duration::from_secs(24 * 3600));
+        let mut schedule = PollSchedule::new(budget);
+        let start = Instant::now();
+        schedule.add(1u64);
+        schedule.add(2u64);
+        schedule.record(1u64, 0, start);
+        schedule.record(2u64, 0, start);
+
+        let later = start + Duration::from_secs(3600);
+        schedule.record(1u64, 4, later);
+        schedule.record(2u64, 0, later);
+
+        assert_eq!(schedule.rate(1u64), Some(4.0));
+        assert_eq!(schedule.interval(1u64), Duration::from_secs(900));
+        assert_eq!(schedule.interval(2u64), Duration::from_secs(24 * 3600));
+        assert_eq!(
+            schedule.next_due(later),
+            Some((Cik::from(1), later + Duration::from_secs(900)))
+        );
+    }
+
+    #[test]
+    fn test_budget_stretches_intervals() {
+        let budget = PollBudget::new()
+            .with_requests_per_minute(1.0)
+            .with_min_interval(Duration::from_secs(60))
+            .with_max_interval(Duration::from_secs(60));
+        let mut schedule = PollSchedule::new(budget);
+        for cik in 1..=10u64 {
+            schedule.add(cik);
+        }
+        // Ten companies every minute would be ten polls a minute; the budget allows one.
+        assert_eq!(schedule.interval(3u64), Duration::from_secs(600));
+        assert_eq!(schedule.next_due(Instant::now()).unwrap().0, Cik::from(1));
+    }
+}
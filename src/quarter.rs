@@ -0,0 +1,48 @@
+//! Calendar quarters.
+//!
+//! Quarters address both the EDGAR index directories (`QTR1` .. `QTR4`) and the XBRL frames
+//! API (`CY2024Q1`), so the type is shared by the index and company modules.
+
+use super::error::{EdgarError, Result};
+
+/// Fiscal quarter (Q1-Q4).
+///
+/// EDGAR index directories are grouped by quarter (e.g., `QTR1` .. `QTR4`).
+///
+/// Each quarter maps to specific months:
+/// - Q1: January through March
+/// - Q2: April through June
+/// - Q3: July through September
+/// - Q4: October through December
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quarter {
+    Q1 = 1,
+    Q2 = 2,
+    Q3 = 3,
+    Q4 = 4,
+}
+
+impl Quarter {
+    /// Creates a Quarter from a month number (1-12)
+    ///
+    /// # Arguments
+    /// * `month` - Month number (1-12)
+    ///
+    /// # Returns
+    /// * `Ok(Quarter)` if month is valid
+    /// * `Err(EdgarError::InvalidMonth)` if month is invalid
+    pub fn from_month(month: u32) -> Result<Self> {
+        match month {
+            1..=3 => Ok(Quarter::Q1),
+            4..=6 => Ok(Quarter::Q2),
+            7..=9 => Ok(Quarter::Q3),
+            10..=12 => Ok(Quarter::Q4),
+            _ => Err(EdgarError::InvalidMonth),
+        }
+    }
+
+    /// Converts the quarter to its integer representation (1-4).
+    pub fn as_i32(&self) -> i32 {
+        *self as i32
+    }
+}
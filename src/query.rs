@@ -0,0 +1,180 @@
+//! Full-text search query strings.
+//!
+//! EDGAR full-text search matches every word of a query by default, with quoted phrases,
+//! `OR` between alternatives, a leading `-` to exclude, and a trailing `*` for prefixes.
+//! [`Query`] builds those strings so quoting and grouping come out right, and converts into
+//! the string `SearchOptions::with_query` takes.
+//!
+//! ```rust
+//! use edgarkit::{Query, SearchOptions};
+//!
+//! let query = Query::phrase("going concern")
+//!     .and(Query::term("bankruptcy"))
+//!     .not(Query::term("prospectus"));
+//! assert_eq!(query.to_string(), r#""going concern" bankruptcy -prospectus"#);
+//!
+//! let either = Query::term("merger").or(Query::phrase("business combination"));
+//! let query = either.and(Query::prefix("terminat"));
+//! assert_eq!(query.to_string(), r#"(merger OR "business combination") terminat*"#);
+//!
+//! let options = SearchOptions::new().with_query(query);
+//! ```
+
+use std::fmt;
+
+/// A full-text search query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Query(Node);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Node {
+    Term(String),
+    Phrase(String),
+    Prefix(String),
+    All(Vec<Node>),
+    Any(Vec<Node>),
+    Not(Box<Node>),
+}
+
+impl Query {
+    /// A single word. Quotes are dropped, and words with spaces are searched as a phrase.
+    pub fn term(word: impl AsRef<str>) -> Self {
+        let word = clean(word.as_ref());
+        if word.contains(char::is_whitespace) {
+            Query(Node::Phrase(word))
+        } else {
+            Query(Node::Term(word))
+        }
+    }
+
+    /// Words that must appear together and in order.
+    pub fn phrase(words: impl AsRef<str>) -> Self {
+        Query(Node::Phrase(clean(words.as_ref())))
+    }
+
+    /// Words starting with `prefix`, e.g. `Query::prefix("restat")` for restated and
+    /// restatement.
+    pub fn prefix(prefix: impl AsRef<str>) -> Self {
+        Query(Node::Prefix(
+            clean(prefix.as_ref()).trim_end_matches('*').to_string(),
+        ))
+    }
+
+    /// Matches documents that match both queries.
+    pub fn and(self, other: Query) -> Self {
+        Query(Node::All(join(self.0, other.0, |node| match node {
+            Node::All(nodes) => Ok(nodes),
+            node => Err(node),
+        })))
+    }
+
+    /// Matches documents that match either query.
+    pub fn or(self, other: Query) -> Self {
+        Query(Node::Any(join(self.0, other.0, |node| match node {
+            Node::Any(nodes) => Ok(nodes),
+            node => Err(node),
+        })))
+    }
+
+    /// Matches documents that match this query but not `excluded`.
+    pub fn not(self, excluded: Query) -> Self {
+        self.and(Query(Node::Not(Box::new(excluded.0))))
+    }
+}
+
+/// Combines two nodes, splicing in the children of either side that is already the same
+/// operator so chains render flat.
+fn join(left: Node, right: Node, split: fn(Node) -> Result<Vec<Node>, Node>) -> Vec<Node> {
+    let mut nodes = split(left).unwrap_or_else(|node| vec![node]);
+    match split(right) {
+        Ok(more) => nodes.extend(more),
+        Err(node) => nodes.push(node),
+    }
+    nodes
+}
+
+fn clean(text: &str) -> String {
+    text.replace('"', " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Term(word) => f.write_str(word.trim_start_matches('-')),
+            Node::Phrase(words) => write!(f, "\"{}\"", words),
+            Node::Prefix(prefix) => write!(f, "{}*", prefix.trim_start_matches('-')),
+            Node::All(nodes) => {
+                for (i, node) in nodes.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    match node {
+                        Node::Any(_) => write!(f, "({})", node)?,
+                        node => write!(f, "{}", node)?,
+                    }
+                }
+                Ok(())
+            }
+            Node::Any(nodes) => {
+                for (i, node) in nodes.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" OR ")?;
+                    }
+                    match node {
+                        Node::All(_) | Node::Not(_) => write!(f, "({})", node)?,
+                        node => write!(f, "{}", node)?,
+                    }
+                }
+                Ok(())
+            }
+            Node::Not(node) => match node.as_ref() {
+                Node::All(_) | Node::Any(_) => write!(f, "-({})", node),
+                node => write!(f, "-{}", node),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<Query> for String {
+    fn from(query: Query) -> Self {
+        query.to_string()
+    }
+}
+
+impl From<&Query> for String {
+    fn from(query: &Query) -> Self {
+        query.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_queries() {
+        assert_eq!(Query::term(" \"-ebitda\" ").to_string(), "ebitda");
+        assert_eq!(Query::term("net loss").to_string(), "\"net loss\"");
+        assert_eq!(Query::prefix("restat*").to_string(), "restat*");
+
+        let query = Query::term("a").or(Query::term("b")).or(Query::term("c"));
+        assert_eq!(query.to_string(), "a OR b OR c");
+
+        let query = Query::term("a")
+            .and(Query::term("b"))
+            .or(Query::term("c").not(Query::term("d")));
+        assert_eq!(query.to_string(), "(a b) OR (c -d)");
+
+        let query = Query::term("a").not(Query::term("b").or(Query::phrase("c d")));
+        assert_eq!(query.to_string(), "a -(b OR \"c d\")");
+    }
+}
@@ -0,0 +1,341 @@
+//! Standard financial ratios with provenance.
+//!
+//! [`Ratios`] combines the statements from [`CompanyFacts::financials`] for one period into
+//! margins, liquidity and leverage ratios, returns, and free cash flow. Every figure is a
+//! [`Metric`] that lists the line items it was computed from, including the us-gaap tag
+//! and accession number behind each, so a number in a report can be traced back to the
+//! filing that supplied it.
+//!
+//! Returns on equity and assets divide by the average of the opening and closing balance
+//! when the opening balance sheet is available, and by the closing balance otherwise.
+//! Quarterly returns are not annualized.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{CompanyOperations, Edgar, PeriodKind};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let facts = edgar.company_facts(320193).await?;
+//!
+//! for ratios in facts.ratios(PeriodKind::Annual) {
+//!     if let Some(roe) = &ratios.return_on_equity {
+//!         println!("{}: ROE {:.1}%", ratios.period.end, roe.value * 100.0);
+//!         for input in &roe.inputs {
+//!             println!("  {} from {}", input.tag, input.accession_number);
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::company::{CompanyFacts, PeriodKind};
+use super::financials::{
+    BalanceSheet, CashFlowStatement, Financials, IncomeStatement, LineItemSource, StatementPeriod,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A derived figure and the line items it was computed from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metric {
+    pub value: f64,
+
+    /// Sources of the inputs, in the order they enter the formula.
+    pub inputs: Vec<LineItemSource>,
+}
+
+/// Standard ratios for one period.
+///
+/// A ratio is `None` when one of its inputs was not reported or its denominator is zero.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ratios {
+    pub period: StatementPeriod,
+
+    /// Gross profit / revenue. Gross profit is computed from cost of revenue if not reported.
+    pub gross_margin: Option<Metric>,
+
+    /// Operating income / revenue.
+    pub operating_margin: Option<Metric>,
+
+    /// Net income / revenue.
+    pub net_margin: Option<Metric>,
+
+    /// Current assets / current liabilities at period end.
+    pub current_ratio: Option<Metric>,
+
+    /// Long-term debt / stockholders' equity at period end.
+    pub debt_to_equity: Option<Metric>,
+
+    /// Net income / average stockholders' equity.
+    pub return_on_equity: Option<Metric>,
+
+    /// Net income / average total assets.
+    pub return_on_assets: Option<Metric>,
+
+    /// Operating cash flow minus capital expenditure, in USD.
+    pub free_cash_flow: Option<Metric>,
+}
+
+/// A line item value together with its source.
+type Input<'a> = (f64, &'a LineItemSource);
+
+fn input<'a>(
+    value: Option<f64>,
+    sources: &'a BTreeMap<String, LineItemSource>,
+    field: &str,
+) -> Option<Input<'a>> {
+    Some((value?, sources.get(field)?))
+}
+
+fn ratio(numerator: Input, denominator: Input) -> Option<Metric> {
+    (denominator.0 != 0.0).then(|| Metric {
+        value: numerator.0 / denominator.0,
+        inputs: vec![numerator.1.clone(), denominator.1.clone()],
+    })
+}
+
+/// `numerator` over the average of `closing` and `opening`, or over `closing` alone.
+fn return_on(numerator: Input, closing: Input, opening: Option<Input>) -> Option<Metric> {
+    let Some(opening) = opening else {
+        return ratio(numerator, closing);
+    };
+    let average = (closing.0 + opening.0) / 2.0;
+    (average != 0.0).then(|| Metric {
+        value: numerator.0 / average,
+        inputs: vec![numerator.1.clone(), closing.1.clone(), opening.1.clone()],
+    })
+}
+
+impl Ratios {
+    /// Computes ratios for the period of `income`.
+    ///
+    /// `balance` is the balance sheet at the end of the period and `opening` the one at its
+    /// start (the previous period end); `cash_flow` covers the same period as `income`.
+    pub fn new(
+        income: &IncomeStatement,
+        balance: Option<&BalanceSheet>,
+        opening: Option<&BalanceSheet>,
+        cash_flow: Option<&CashFlowStatement>,
+    ) -> Self {
+        let is = &income.sources;
+        let revenue = input(income.revenue, is, "revenue");
+        let net_income = input(income.net_income, is, "net_income");
+
+        let gross_margin = match input(income.gross_profit, is, "gross_profit") {
+            Some(gross) => revenue.and_then(|revenue| ratio(gross, revenue)),
+            None => revenue
+                .zip(input(income.cost_of_revenue, is, "cost_of_revenue"))
+                .and_then(|(revenue, cost)| {
+                    (revenue.0 != 0.0).then(|| Metric {
+                        value: (revenue.0 - cost.0) / revenue.0,
+                        inputs: vec![revenue.1.clone(), cost.1.clone()],
+                    })
+                }),
+        };
+        let operating_margin = input(income.operating_income, is, "operating_income")
+            .zip(revenue)
+            .and_then(|(operating, revenue)| ratio(operating, revenue));
+        let net_margin = net_income
+            .zip(revenue)
+            .and_then(|(net, revenue)| ratio(net, revenue));
+
+        let (mut current_ratio, mut debt_to_equity) = (None, None);
+        let (mut return_on_equity, mut return_on_assets) = (None, None);
+        if let Some(balance) = balance {
+            let bs = &balance.sources;
+            let equity = input(balance.stockholders_equity, bs, "stockholders_equity");
+            let assets = input(balance.total_assets, bs, "total_assets");
+
+            current_ratio = input(balance.current_assets, bs, "current_assets")
+                .zip(input(
+                    balance.current_liabilities,
+                    bs,
+                    "current_liabilities",
+                ))
+                .and_then(|(assets, liabilities)| ratio(assets, liabilities));
+            debt_to_equity = input(balance.long_term_debt, bs, "long_term_debt")
+                .zip(equity)
+                .and_then(|(debt, equity)| ratio(debt, equity));
+
+            let opening_equity = opening
+                .and_then(|o| input(o.stockholders_equity, &o.sources, "stockholders_equity"));
+            let opening_assets =
+                opening.and_then(|o| input(o.total_assets, &o.sources, "total_assets"));
+            return_on_equity = net_income
+                .zip(equity)
+                .and_then(|(net, equity)| return_on(net, equity, opening_equity));
+            return_on_assets = net_income
+                .zip(assets)
+                .and_then(|(net, assets)| return_on(net, assets, opening_assets));
+        }
+
+        let free_cash_flow = cash_flow.and_then(|cash_flow| {
+            let cs = &cash_flow.sources;
+            let operating = input(cash_flow.operating_cash_flow, cs, "operating_cash_flow")?;
+            let capex = input(cash_flow.capital_expenditure, cs, "capital_expenditure")?;
+            Some(Metric {
+                value: operating.0 - capex.0,
+                inputs: vec![operating.1.clone(), capex.1.clone()],
+            })
+        });
+
+        Self {
+            period: income.period.clone(),
+            gross_margin,
+            operating_margin,
+            net_margin,
+            current_ratio,
+            debt_to_equity,
+            return_on_equity,
+            return_on_assets,
+            free_cash_flow,
+        }
+    }
+}
+
+impl Financials {
+    /// Ratios for every income statement period, oldest first.
+    ///
+    /// Balance sheets are matched on the period's end date (and the day before its start
+    /// for the opening balance), cash flow statements on the exact period.
+    pub fn ratios(&self) -> Vec<Ratios> {
+        let balance_at = |date| self.balance_sheets.iter().find(|b| b.period.end == date);
+        self.income_statements
+            .iter()
+            .map(|income| {
+                let period = &income.period;
+                let opening = period.start.and_then(|start| balance_at(start.pred_opt()?));
+                let cash_flow = self
+                    .cash_flow_statements
+                    .iter()
+                    .find(|c| c.period.start == period.start && c.period.end == period.end);
+                Ratios::new(income, balance_at(period.end), opening, cash_flow)
+            })
+            .collect()
+    }
+}
+
+impl CompanyFacts {
+    /// Standard ratios for annual or quarterly periods, oldest first.
+    pub fn ratios(&self, kind: PeriodKind) -> Vec<Ratios> {
+        Financials {
+            income_statements: self.income_statements(kind),
+            // Every reported date, so opening balances of quarters are available.
+            balance_sheets: self.balance_sheets(PeriodKind::Instant),
+            cash_flow_statements: self.cash_flow_statements(kind),
+        }
+        .ratios()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn source(tag: &str) -> LineItemSource {
+        LineItemSource {
+            tag: tag.to_string(),
+            accession_number: "0000000001-24-000001".to_string(),
+            form: "10-K".to_string(),
+            filed: "2024-11-01".to_string(),
+        }
+    }
+
+    fn period(start: Option<&str>, end: &str) -> StatementPeriod {
+        StatementPeriod {
+            start: start.map(|s| s.parse().unwrap()),
+            end: end.parse::<NaiveDate>().unwrap(),
+            fiscal_year: Some(2024),
+            fiscal_period: Some("FY".to_string()),
+        }
+    }
+
+    fn balance(end: &str, equity: f64) -> BalanceSheet {
+        BalanceSheet {
+            period: period(None, end),
+            cash_and_equivalents: None,
+            short_term_investments: None,
+            accounts_receivable: None,
+            inventory: None,
+            current_assets: Some(150.0),
+            total_assets: Some(400.0),
+            accounts_payable: None,
+            current_liabilities: Some(100.0),
+            long_term_debt: Some(80.0),
+            total_liabilities: None,
+            stockholders_equity: Some(equity),
+            liabilities_and_equity: None,
+            sources: BTreeMap::from([
+                ("current_assets".to_string(), source("AssetsCurrent")),
+                ("total_assets".to_string(), source("Assets")),
+                (
+                    "current_liabilities".to_string(),
+                    source("LiabilitiesCurrent"),
+                ),
+                (
+                    "long_term_debt".to_string(),
+                    source("LongTermDebtNoncurrent"),
+                ),
+                (
+                    "stockholders_equity".to_string(),
+                    source("StockholdersEquity"),
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_ratios_from_statements() {
+        let income = IncomeStatement {
+            period: period(Some("2024-01-01"), "2024-12-31"),
+            revenue: Some(1000.0),
+            cost_of_revenue: Some(600.0),
+            gross_profit: None,
+            research_and_development: None,
+            selling_general_and_administrative: None,
+            operating_expenses: None,
+            operating_income: Some(250.0),
+            interest_expense: None,
+            pretax_income: None,
+            income_tax: None,
+            net_income: Some(50.0),
+            eps_basic: None,
+            eps_diluted: None,
+            weighted_shares_basic: None,
+            weighted_shares_diluted: None,
+            sources: BTreeMap::from([
+                ("revenue".to_string(), source("Revenues")),
+                ("cost_of_revenue".to_string(), source("CostOfRevenue")),
+                (
+                    "operating_income".to_string(),
+                    source("OperatingIncomeLoss"),
+                ),
+                ("net_income".to_string(), source("NetIncomeLoss")),
+            ]),
+        };
+        let financials = Financials {
+            income_statements: vec![income],
+            balance_sheets: vec![balance("2023-12-31", 150.0), balance("2024-12-31", 250.0)],
+            cash_flow_statements: Vec::new(),
+        };
+
+        let ratios = &financials.ratios()[0];
+        let gross = ratios.gross_margin.as_ref().unwrap();
+        assert_eq!(gross.value, 0.4);
+        let tags: Vec<&str> = gross.inputs.iter().map(|s| s.tag.as_str()).collect();
+        assert_eq!(tags, vec!["Revenues", "CostOfRevenue"]);
+
+        assert_eq!(ratios.operating_margin.as_ref().unwrap().value, 0.25);
+        assert_eq!(ratios.current_ratio.as_ref().unwrap().value, 1.5);
+        assert_eq!(ratios.debt_to_equity.as_ref().unwrap().value, 0.32);
+        // Average equity of 200 across the opening and closing balance sheets.
+        let roe = ratios.return_on_equity.as_ref().unwrap();
+        assert_eq!(roe.value, 0.25);
+        assert_eq!(roe.inputs.len(), 3);
+        assert!(ratios.free_cash_flow.is_none());
+    }
+}
@@ -0,0 +1,83 @@
+//! Timers and clocks for native and browser builds.
+//!
+//! Native builds sleep on tokio's timer and measure time with `std::time::Instant`. With the
+//! `async-std` feature they sleep on an `async-io` timer instead, which needs no tokio
+//! runtime and works under async-std, smol, or any other executor. In the browser
+//! (`wasm32-unknown-unknown` with the `wasm` feature) there is no tokio time driver and
+//! `std::time::Instant::now` panics, so `wasmtimer` stands in for both, backed by
+//! `setTimeout` and `performance.now()`. Code that waits or measures elapsed time goes
+//! through this module instead of naming either directly.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "async-std")))]
+pub(crate) use tokio::time::sleep;
+
+/// Sleeps for `duration` on the `async-io` timer.
+#[cfg(all(not(target_arch = "wasm32"), feature = "async-std"))]
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use wasmtimer::std::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasmtimer::tokio::sleep;
+
+/// Sleeps until `deadline`, returning at once if it has passed.
+#[cfg(feature = "feeds")]
+pub(crate) async fn sleep_until(deadline: Instant) {
+    sleep(deadline.saturating_duration_since(Instant::now())).await;
+}
+
+/// A boxed stream of items. `Send` on native builds; in the browser requests are tied to
+/// the JavaScript thread, so streams that make them cannot be.
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+pub use futures_util::stream::BoxStream;
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+pub use futures_util::stream::LocalBoxStream as BoxStream;
+
+/// Boxes a stream into the target's [`BoxStream`].
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+pub(crate) trait IntoBoxStream<'a>: futures_util::Stream + Sized + 'a {
+    fn into_box_stream(self) -> BoxStream<'a, Self::Item>;
+}
+
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+impl<'a, S: futures_util::Stream + Send + 'a> IntoBoxStream<'a> for S {
+    fn into_box_stream(self) -> BoxStream<'a, Self::Item> {
+        futures_util::StreamExt::boxed(self)
+    }
+}
+
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "feeds", feature = "index", feature = "search")
+))]
+impl<'a, S: futures_util::Stream + 'a> IntoBoxStream<'a> for S {
+    fn into_box_stream(self) -> BoxStream<'a, Self::Item> {
+        futures_util::StreamExt::boxed_local(self)
+    }
+}
+
+#[cfg(all(test, feature = "async-std", feature = "feeds"))]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_sleep_runs_without_tokio() {
+        let start = Instant::now();
+        async_io::block_on(sleep_until(start + Duration::from_millis(20)));
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+}
@@ -10,12 +10,13 @@
 //!
 //! # Search Capabilities
 //!
-//! - Full-text search with keyword queries
+//! - Full-text search with keyword queries, or phrases and exclusions built with `Query`
 //! - Filter by form types (10-K, 8-K, S-1, etc.)
 //! - Date range filtering
 //! - Company name or CIK filtering
 //! - SIC code and location-based filtering
 //! - Pagination with configurable page sizes
+//! - Company name lookup (`entity_search`) through the same typeahead EDGAR's search box uses
 //!
 //! # Performance
 //!
@@ -42,10 +43,23 @@
 //! ```
 
 use super::Edgar;
+use super::accession::AccessionNumber;
+use super::cik::Cik;
+use super::core::fan_out;
 use super::error::{EdgarError, Result};
+use super::geography::{Location, StateOrCountry};
+use super::items::ItemCode;
+use super::rt::{BoxStream, IntoBoxStream};
+use super::sic::SicCode;
+#[cfg(feature = "filings")]
+use super::traits::FilingOperations;
 use super::traits::SearchOperations;
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Deserializer, de};
+use serde_json::Value;
+use std::collections::HashSet;
 
 /// Response container from the EDGAR search API containing search metadata and results.
 ///
@@ -66,6 +80,71 @@ pub struct SearchResponse {
 
     /// Search results
     pub hits: Hits,
+
+    /// Match counts per form type, filer, SIC code, and business state, over every hit of
+    /// the query rather than just this page
+    #[serde(default)]
+    pub aggregations: Option<Aggregations>,
+}
+
+/// Facet counts full-text search returns alongside every page of hits.
+///
+/// Each aggregation holds the 30 largest buckets; `other_count` is the number of hits
+/// falling in the buckets left out. The API has no buckets by filing date.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Aggregations {
+    /// Hits per root form type, e.g. `8-K`
+    #[serde(rename = "form_filter", default)]
+    pub forms: Aggregation,
+
+    /// Hits per filer, keyed by display name (`NAME  (TICKERS)  (CIK ##########)`)
+    #[serde(rename = "entity_filter", default)]
+    pub entities: Aggregation,
+
+    /// Hits per SIC code
+    #[serde(rename = "sic_filter", default)]
+    pub sics: Aggregation,
+
+    /// Hits per business address state or country code
+    #[serde(rename = "biz_states_filter", default)]
+    pub biz_states: Aggregation,
+}
+
+/// The buckets of one aggregation, largest first.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Aggregation {
+    #[serde(default)]
+    pub buckets: Vec<Bucket>,
+
+    #[serde(rename = "sum_other_doc_count", default)]
+    pub other_count: u64,
+}
+
+/// A facet value and the number of hits that have it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Bucket {
+    pub key: String,
+
+    #[serde(rename = "doc_count")]
+    pub count: u64,
+}
+
+impl Aggregation {
+    /// The count for `key`, if it is among the returned buckets.
+    pub fn count(&self, key: &str) -> Option<u64> {
+        self.buckets
+            .iter()
+            .find(|bucket| bucket.key == key)
+            .map(|bucket| bucket.count)
+    }
+}
+
+impl Bucket {
+    /// The CIK at the end of a filer bucket's display name.
+    pub fn cik(&self) -> Option<Cik> {
+        let (_, cik) = self.key.rsplit_once("(CIK ")?;
+        cik.strip_suffix(')')?.parse().ok()
+    }
 }
 
 /// Information about Elasticsearch shards that processed the search query.
@@ -140,13 +219,19 @@ pub struct Hit {
 ///
 /// Many fields are arrays because a single filing can be associated with multiple entities,
 /// locations, or classification codes. For example, merger filings may list multiple CIKs.
+///
+/// CIKs, SIC codes, the filing date, and the accession number are parsed once when the hit
+/// is deserialized; the `*_raw` fields keep them as the index returned them.
 #[derive(Debug, Clone, Deserialize)]
+#[serde(try_from = "SourceRecord")]
 pub struct Source {
     /// Company CIK numbers
-    pub ciks: Vec<String>,
+    pub ciks: Vec<Cik>,
+
+    /// `ciks` as returned, zero-padded to ten digits
+    pub ciks_raw: Vec<String>,
 
     /// Period ending date (if applicable)
-    #[serde(default)]
     pub period_ending: Option<String>,
 
     /// File numbers
@@ -156,30 +241,37 @@ pub struct Source {
     pub display_names: Vec<String>,
 
     /// XSL stylesheet reference
-    #[serde(default)]
     pub xsl: Option<String>,
 
     /// Sequence number
-    #[serde(deserialize_with = "deserialize_sequence")]
     pub sequence: u32,
 
     /// Root form types
     pub root_forms: Vec<String>,
 
-    /// Filing date (YYYY-MM-DD)
-    pub file_date: String,
+    /// Filing date
+    pub file_date: NaiveDate,
+
+    /// `file_date` as returned (YYYY-MM-DD)
+    pub file_date_raw: String,
 
     /// Business states
     pub biz_states: Vec<String>,
 
-    /// SIC codes
-    pub sics: Vec<String>,
+    /// SIC codes of the filers that have one
+    pub sics: Vec<SicCode>,
+
+    /// `sics` as returned; positionally aligned with `ciks` when every filer has a code
+    pub sics_raw: Vec<String>,
 
     /// Form type (e.g., "10-K", "8-K")
     pub form: String,
 
     /// Accession number
-    pub adsh: String,
+    pub adsh: AccessionNumber,
+
+    /// `adsh` as returned (dashed)
+    pub adsh_raw: String,
 
     /// Film numbers
     pub film_num: Vec<String>,
@@ -191,7 +283,6 @@ pub struct Source {
     pub file_type: String,
 
     /// File description
-    #[serde(default)]
     pub file_description: Option<String>,
 
     /// Incorporation states
@@ -201,6 +292,216 @@ pub struct Source {
     pub items: Option<Vec<String>>,
 }
 
+/// `Source` as the search index returns it, before the typed fields are parsed.
+#[derive(Deserialize)]
+struct SourceRecord {
+    ciks: Vec<String>,
+    #[serde(default)]
+    period_ending: Option<String>,
+    file_num: Option<Vec<String>>,
+    display_names: Vec<String>,
+    #[serde(default)]
+    xsl: Option<String>,
+    #[serde(deserialize_with = "deserialize_sequence")]
+    sequence: u32,
+    root_forms: Vec<String>,
+    file_date: String,
+    biz_states: Vec<String>,
+    sics: Vec<String>,
+    form: String,
+    adsh: String,
+    film_num: Vec<String>,
+    biz_locations: Vec<String>,
+    file_type: String,
+    #[serde(default)]
+    file_description: Option<String>,
+    inc_states: Vec<String>,
+    items: Option<Vec<String>>,
+}
+
+impl TryFrom<SourceRecord> for Source {
+    type Error = EdgarError;
+
+    fn try_from(record: SourceRecord) -> Result<Self> {
+        let ciks = record
+            .ciks
+            .iter()
+            .map(|cik| cik.parse())
+            .collect::<Result<Vec<Cik>>>()?;
+        let file_date = NaiveDate::parse_from_str(&record.file_date, "%Y-%m-%d").map_err(|e| {
+            EdgarError::InvalidFormat(format!("file_date {:?}: {}", record.file_date, e))
+        })?;
+        // Filers without a SIC code are listed with a blank one.
+        let sics = record
+            .sics
+            .iter()
+            .filter(|sic| !sic.trim().is_empty())
+            .map(|sic| sic.parse())
+            .collect::<Result<Vec<SicCode>>>()?;
+        Ok(Self {
+            ciks,
+            ciks_raw: record.ciks,
+            period_ending: record.period_ending,
+            file_num: record.file_num,
+            display_names: record.display_names,
+            xsl: record.xsl,
+            sequence: record.sequence,
+            root_forms: record.root_forms,
+            file_date,
+            file_date_raw: record.file_date,
+            biz_states: record.biz_states,
+            sics,
+            sics_raw: record.sics,
+            form: record.form,
+            adsh: record.adsh.parse()?,
+            adsh_raw: record.adsh,
+            film_num: record.film_num,
+            biz_locations: record.biz_locations,
+            file_type: record.file_type,
+            file_description: record.file_description,
+            inc_states: record.inc_states,
+            items: record.items,
+        })
+    }
+}
+
+impl Source {
+    /// Accession number of the filing the matched document belongs to.
+    pub fn accession(&self) -> &str {
+        &self.adsh_raw
+    }
+
+    /// The first filer listed, which is the filing's primary entity.
+    pub fn primary_cik(&self) -> Option<Cik> {
+        self.ciks.first().copied()
+    }
+
+    /// States or countries of the filers' business addresses, parsed from `biz_states`.
+    pub fn business_states(&self) -> Vec<StateOrCountry> {
+        self.biz_states
+            .iter()
+            .map(|code| StateOrCountry::from(code.as_str()))
+            .collect()
+    }
+
+    /// States or countries the filers are incorporated in, parsed from `inc_states`.
+    pub fn incorporation_states(&self) -> Vec<StateOrCountry> {
+        self.inc_states
+            .iter()
+            .map(|code| StateOrCountry::from(code.as_str()))
+            .collect()
+    }
+
+    /// 8-K items of the filing; empty for other forms.
+    pub fn item_codes(&self) -> Vec<ItemCode> {
+        self.items
+            .iter()
+            .flatten()
+            .map(|item| ItemCode::from(item.as_str()))
+            .collect()
+    }
+}
+
+impl Hit {
+    pub fn accession(&self) -> &str {
+        self._source.accession()
+    }
+
+    pub fn primary_cik(&self) -> Option<Cik> {
+        self._source.primary_cik()
+    }
+
+    /// Name of the matched document within the filing, taken from the hit's
+    /// `accession:filename` ID.
+    pub fn filename(&self) -> Option<&str> {
+        self._id.split_once(':').map(|(_, filename)| filename)
+    }
+
+    /// Archive URL of the matched document.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidResponse` if the hit has no filer CIK or its ID is not in
+    /// `accession:filename` form.
+    #[cfg(feature = "filings")]
+    pub fn document_url(&self, edgar: &Edgar) -> Result<String> {
+        let cik = self.primary_cik().ok_or_else(|| {
+            EdgarError::InvalidResponse(format!("search hit {} has no CIK", self._id))
+        })?;
+        edgar.get_filing_url_from_id(cik, &self._id)
+    }
+
+    /// Downloads the matched document.
+    #[cfg(feature = "filings")]
+    pub async fn fetch_document(&self, edgar: &Edgar) -> Result<String> {
+        let url = self.document_url(edgar)?;
+        edgar.get(&url).await
+    }
+}
+
+/// A company or individual returned by [`SearchOperations::entity_search`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityMatch {
+    /// Central Index Key.
+    pub cik: u64,
+
+    /// Entity name as registered with the SEC.
+    pub name: String,
+
+    /// Ticker symbols, if the entity has any.
+    pub tickers: Vec<String>,
+}
+
+/// Raw typeahead response; only the fields edgarkit maps into [`EntityMatch`].
+#[derive(Debug, Deserialize)]
+struct EntityResponse {
+    hits: EntityHits,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntityHits {
+    #[serde(default)]
+    hits: Vec<EntityHit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntityHit {
+    _id: String,
+    _source: EntitySource,
+}
+
+#[derive(Debug, Deserialize)]
+struct EntitySource {
+    entity: String,
+    #[serde(default)]
+    tickers: Option<String>,
+}
+
+impl EntityHit {
+    fn into_match(self) -> Option<EntityMatch> {
+        let cik = self._id.trim().parse().ok()?;
+        // Names sometimes carry the tickers and CIK in parentheses, e.g.
+        // "BERKSHIRE HATHAWAY INC  (BRK-B, BRK-A)  (CIK 0001067983)".
+        let name = self
+            ._source
+            .entity
+            .split("  (")
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let tickers = self
+            ._source
+            .tickers
+            .unwrap_or_default()
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect();
+        Some(EntityMatch { cik, name, tickers })
+    }
+}
+
 /// Configurable options for searching SEC EDGAR filings.
 ///
 /// This builder-style struct allows you to construct complex search queries using a fluent
@@ -290,6 +591,67 @@ pub struct SearchOptions {
 
     /// Use incorporation location instead of HQ location
     pub incorporated_location: Option<bool>,
+
+    /// Only the aggregations are wanted; `search` requests a single hit and drops it
+    pub aggregations_only: Option<bool>,
+}
+
+/// Results per page when paginating (the API maximum).
+const SEARCH_PAGE_SIZE: u32 = 100;
+
+/// Full-text search serves at most this many hits per query, whatever the page.
+const EFTS_RESULT_CAP: u32 = 10_000;
+
+/// Result pages fetched at once by [`SearchOperations::search_all`]; every request still goes
+/// through the search host's rate limiter.
+const SEARCH_PAGE_CONCURRENCY: usize = 7;
+
+/// Full-text search covers filings from 2001 onwards.
+const EFTS_FIRST_DAY: (i32, u32, u32) = (2001, 1, 1);
+
+/// Whether a query matches more hits than can be paged through.
+fn exceeds_result_cap(total: &TotalHits) -> bool {
+    total.relation == "gte" || total.value > EFTS_RESULT_CAP
+}
+
+/// The query's date window, defaulting to the whole full-text search history.
+///
+/// Fails with `EdgarError::InvalidFormat` for unparseable dates and for a start date after
+/// the end date.
+fn search_window(options: &SearchOptions) -> Result<(NaiveDate, NaiveDate)> {
+    let parse = |date: &Option<String>| {
+        date.as_deref()
+            .map(|d| {
+                NaiveDate::parse_from_str(d.trim(), "%Y-%m-%d").map_err(|_| {
+                    EdgarError::InvalidFormat(format!("search date {} is not YYYY-MM-DD", d))
+                })
+            })
+            .transpose()
+    };
+    let (year, month, day) = EFTS_FIRST_DAY;
+    let start = parse(&options.start_date)?
+        .or_else(|| NaiveDate::from_ymd_opt(year, month, day))
+        .expect("valid first search date");
+    let end = parse(&options.end_date)?.unwrap_or_else(|| chrono::Utc::now().date_naive());
+    if start > end {
+        return Err(EdgarError::InvalidFormat(format!(
+            "search start date {} is after end date {}",
+            start, end
+        )));
+    }
+    Ok((start, end))
+}
+
+/// Splits an inclusive date window into an older and a newer half; `None` for a single day.
+fn split_window(
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Option<((NaiveDate, NaiveDate), (NaiveDate, NaiveDate))> {
+    if start >= end {
+        return None;
+    }
+    let mid = start + chrono::Duration::days((end - start).num_days() / 2);
+    Some(((start, mid), (mid.succ_opt()?, end)))
 }
 
 /// Custom deserializer for sequence field that can be either u32 or string
@@ -380,6 +742,26 @@ impl SearchOptions {
         self
     }
 
+    /// Filters by a company location.
+    ///
+    /// ```rust
+    /// # use edgarkit::{Location, SearchOptions};
+    /// let options = SearchOptions::new().with_location(Location::Delaware);
+    /// ```
+    pub fn with_location(self, location: Location) -> Self {
+        self.with_location_code(location.code())
+    }
+
+    /// Filters by any of several company locations.
+    pub fn with_locations(self, locations: impl IntoIterator<Item = Location>) -> Self {
+        self.with_location_codes(
+            locations
+                .into_iter()
+                .map(|location| location.code().to_string())
+                .collect(),
+        )
+    }
+
     /// Sets the page number for pagination (starting from 1)
     pub fn with_page(mut self, page: u32) -> Self {
         self.page = Some(page);
@@ -463,6 +845,23 @@ impl SearchOptions {
         self
     }
 
+    /// Asks `search` for the aggregation counts of the query without its hits.
+    ///
+    /// Aggregations cover every match, so one request answers questions like "how many
+    /// 8-Ks mention this term" that would otherwise need `search_all`. Ignored by
+    /// `search_all`.
+    ///
+    /// ```rust
+    /// # use edgarkit::SearchOptions;
+    /// let options = SearchOptions::new()
+    ///     .with_query("\"going concern\"")
+    ///     .with_aggregations_only(true);
+    /// ```
+    pub fn with_aggregations_only(mut self, aggregations_only: bool) -> Self {
+        self.aggregations_only = Some(aggregations_only);
+        self
+    }
+
     pub fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -502,7 +901,8 @@ impl SearchOptions {
             params.push(("from".to_string(), from.to_string()));
         }
 
-        if let Some(count) = self.count {
+        let aggregations_only = self.aggregations_only == Some(true);
+        if let Some(count) = self.count.or(aggregations_only.then_some(1)) {
             params.push(("count".to_string(), count.to_string()));
         }
 
@@ -585,7 +985,8 @@ impl SearchOptions {
 ///     Ok(())
 /// }
 /// ```
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl SearchOperations for Edgar {
     /// Executes a search query and returns a single page of results.
     ///
@@ -619,15 +1020,30 @@ impl SearchOperations for Edgar {
     /// println!("Found {} total matches", response.hits.total.value);
     /// println!("This page has {} results", response.hits.hits.len());
     /// ```
+    #[tracing::instrument(skip_all, fields(query = options.query.as_deref(), page = options.page))]
     async fn search(&self, options: SearchOptions) -> Result<SearchResponse> {
         let params = options.to_query_params();
         let query_string = serde_urlencoded::to_string(&params)
             .map_err(|e| EdgarError::InvalidResponse(e.to_string()))?;
 
         let url = format!("{}?{}", self.search_url(), query_string);
-        let response = self.get(&url).await?;
-
-        Ok(serde_json::from_str(&response)?)
+        let response = self.get_search(&url).await?;
+
+        let mut parsed: SearchResponse = serde_json::from_str(&response).map_err(|e| {
+            // EFTS occasionally reports query errors in a 200 response.
+            match parse_search_error(&response) {
+                Some(message) => EdgarError::SearchError {
+                    status: 200,
+                    url: url.clone(),
+                    message,
+                },
+                None => e.into(),
+            }
+        })?;
+        if options.aggregations_only == Some(true) {
+            parsed.hits.hits.clear();
+        }
+        Ok(parsed)
     }
 
     /// Fetches all matching results across multiple pages with automatic pagination.
@@ -637,6 +1053,12 @@ impl SearchOperations for Edgar {
     /// for total count, then fetching all pages in parallel batches of up to 7 concurrent
     /// requests. This provides excellent performance while respecting SEC rate limits.
     ///
+    /// Full-text search only serves the first 10,000 hits of a query. When a query matches
+    /// more, its date range (all of 2001 to today if none is set) is split in half
+    /// recursively until each piece is under the cap, and the pieces' results are merged
+    /// with duplicates removed. A single day with more than 10,000 hits is still truncated,
+    /// with a warning logged.
+    ///
     /// The method aggregates all results into a single vector of `Hit` objects, making it
     /// easy to process the complete result set. Progress and errors are logged using the
     /// `tracing` crate, so you can monitor long-running searches.
@@ -647,6 +1069,7 @@ impl SearchOperations for Edgar {
     /// - Respects rate limiting between batches
     /// - For 1000+ results, this is significantly faster than sequential pagination
     /// - Memory usage scales with result set size - consider filtering for very large queries
+    /// - Date splitting costs one extra request per window
     ///
     /// # Arguments
     ///
@@ -674,76 +1097,306 @@ impl SearchOperations for Edgar {
     ///         hit._source.file_date);
     /// }
     /// ```
+    #[tracing::instrument(skip_all, fields(query = options.query.as_deref(), hits = tracing::field::Empty))]
     async fn search_all(&self, mut options: SearchOptions) -> Result<Vec<Hit>> {
-        const BATCH_SIZE: u32 = 7; // Maximum number of concurrent requests
-        const PAGE_SIZE: u32 = 100; // Results per page
-
-        // Set defaults
-        options.count = Some(PAGE_SIZE);
+        options.aggregations_only = None;
+        options.count = Some(SEARCH_PAGE_SIZE);
         options.page = Some(1);
+        options.from = None;
         options.reverse_order = Some(false);
+        let (start, end) = search_window(&options)?;
 
-        let initial_response = self.search(options.clone()).await?;
-        let total_hits = initial_response.hits.total.value;
-
-        tracing::info!("Found {} total hits", total_hits);
+        let first = self.search(options.clone()).await?;
+        tracing::info!("Found {} total hits", first.hits.total.value);
+        if !exceeds_result_cap(&first.hits.total) {
+            return self.fetch_remaining_pages(options, first).await;
+        }
 
-        let mut all_hits = Vec::with_capacity(total_hits as usize);
-        all_hits.extend(initial_response.hits.hits);
+        // Past the cap, split the date window until every piece is reachable. Windows are
+        // processed newest first to keep the usual newest-first order. The first response
+        // already covers the whole window, so it is not requested again.
+        let mut windows = vec![(start, end, Some(first))];
+        let mut seen = HashSet::new();
+        let mut all_hits = Vec::new();
+        while let Some((start, end, response)) = windows.pop() {
+            let mut window_options = options.clone();
+            window_options.start_date = Some(start.format("%Y-%m-%d").to_string());
+            window_options.end_date = Some(end.format("%Y-%m-%d").to_string());
+            let response = match response {
+                Some(response) => response,
+                None => self.search(window_options.clone()).await?,
+            };
+
+            if exceeds_result_cap(&response.hits.total) {
+                if let Some(((older_start, older_end), (newer_start, newer_end))) =
+                    split_window(start, end)
+                {
+                    windows.push((older_start, older_end, None));
+                    windows.push((newer_start, newer_end, None));
+                    continue;
+                }
+                tracing::warn!(
+                    "More than {} hits filed from {} to {}; only the first {} are reachable",
+                    EFTS_RESULT_CAP,
+                    start,
+                    end,
+                    EFTS_RESULT_CAP
+                );
+            }
 
-        let total_pages = (total_hits + PAGE_SIZE - 1) / PAGE_SIZE;
-        let mut current_page = 1;
+            let hits = self.fetch_remaining_pages(window_options, response).await?;
+            all_hits.extend(hits.into_iter().filter(|hit| seen.insert(hit._id.clone())));
+        }
 
-        while current_page < total_pages {
-            let end_page = (current_page + BATCH_SIZE).min(total_pages);
-            let mut batch_futures = Vec::with_capacity((end_page - current_page) as usize);
+        tracing::Span::current().record("hits", all_hits.len());
+        Ok(all_hits)
+    }
 
-            for page in (current_page + 1)..=end_page {
-                let skip = (page - 1) * PAGE_SIZE;
+    /// Streams every matching hit, one page of 100 at a time.
+    ///
+    /// Unlike `search_all()`, pages are requested only as the stream is polled, so memory
+    /// stays bounded by one page and dropping the stream (for example after `take(n)` or on
+    /// the first match of interest) stops further requests. Pages are fetched sequentially.
+    ///
+    /// A failed page request is yielded as an error and ends the stream. Call
+    /// `.enrich(enricher)` ([`EnrichStream`](crate::EnrichStream), `company` feature) on the
+    /// stream to get each hit with company names, tickers, and SIC codes attached.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use futures_util::StreamExt;
+    ///
+    /// let options = SearchOptions::new().with_query("going concern");
+    /// let mut hits = edgar.search_stream(options).take(250);
+    /// while let Some(hit) = hits.next().await {
+    ///     let hit = hit?;
+    ///     println!("{} {}", hit._source.file_date, hit._source.display_names[0]);
+    /// }
+    /// ```
+    fn search_stream(&self, mut options: SearchOptions) -> BoxStream<'_, Result<Hit>> {
+        options.count = Some(SEARCH_PAGE_SIZE);
+        options.reverse_order = Some(false);
 
-                // Stop if we've gone past the total hits
-                if skip >= total_hits {
-                    break;
+        stream::unfold(Some((options, 0u32)), move |state| async move {
+            let (mut options, from) = state?;
+            options.page = Some(from / SEARCH_PAGE_SIZE + 1);
+            options.from = Some(from);
+            match self.search(options.clone()).await {
+                Ok(response) => {
+                    let hits = response.hits.hits;
+                    let next = from + hits.len() as u32;
+                    let more = !hits.is_empty() && next < response.hits.total.value;
+                    let page: Vec<Result<Hit>> = hits.into_iter().map(Ok).collect();
+                    Some((page, more.then_some((options, next))))
                 }
-
-                let mut page_options = options.clone();
-                page_options.page = Some(page);
-                page_options.from = Some(skip);
-                page_options.count = Some(PAGE_SIZE.min(total_hits - skip));
-                page_options.reverse_order = Some(false);
-
-                batch_futures.push(self.search(page_options));
+                Err(e) => Some((vec![Err(e)], None)),
             }
+        })
+        .flat_map(stream::iter)
+        .into_box_stream()
+    }
 
-            if batch_futures.is_empty() {
-                break;
-            }
+    /// Looks up companies and individuals by name, the way EDGAR's search box suggests them.
+    ///
+    /// This is a single small request against the typeahead index, so it is a much cheaper
+    /// way to turn "Berkshire Hathaway" into a CIK than downloading the full ticker file.
+    /// Matches are returned in EDGAR's ranking order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let matches = edgar.entity_search("Berkshire Hathaway").await?;
+    /// if let Some(best) = matches.first() {
+    ///     println!("{} -> CIK {} {:?}", best.name, best.cik, best.tickers);
+    /// }
+    /// ```
+    async fn entity_search(&self, name: &str) -> Result<Vec<EntityMatch>> {
+        let options = SearchOptions::new().with_keys_typed(name.trim());
+        let query_string = serde_urlencoded::to_string(options.to_query_params())
+            .map_err(|e| EdgarError::InvalidResponse(e.to_string()))?;
+
+        let url = format!("{}?{}", self.search_url(), query_string);
+        let response = self.get_search(&url).await?;
+        parse_entity_matches(&response)
+    }
+}
 
-            let results = futures_util::future::join_all(batch_futures).await;
-
-            for result in results {
-                match result {
-                    Ok(response) => {
-                        all_hits.extend(response.hits.hits);
-                    }
-                    Err(e) => {
-                        tracing::error!("Error fetching page: {}", e);
-                        return Err(e);
-                    }
+impl Edgar {
+    /// Fetches the pages after `first` for the same query, a few at a time.
+    async fn fetch_remaining_pages(
+        &self,
+        options: SearchOptions,
+        first: SearchResponse,
+    ) -> Result<Vec<Hit>> {
+        // Hits past the cap cannot be paged to.
+        let total_hits = first.hits.total.value.min(EFTS_RESULT_CAP);
+        let mut all_hits = Vec::with_capacity(total_hits as usize);
+        all_hits.extend(first.hits.hits);
+
+        let total_pages = total_hits.div_ceil(SEARCH_PAGE_SIZE);
+        let pages = (2..=total_pages).map(|page| {
+            let skip = (page - 1) * SEARCH_PAGE_SIZE;
+            let mut page_options = options.clone();
+            page_options.page = Some(page);
+            page_options.from = Some(skip);
+            page_options.count = Some(SEARCH_PAGE_SIZE.min(total_hits - skip));
+            page_options.reverse_order = Some(false);
+            page_options
+        });
+
+        let responses = fan_out(pages, SEARCH_PAGE_CONCURRENCY, |page_options| {
+            self.search(page_options)
+        })
+        .await;
+        for response in responses {
+            match response {
+                Ok(response) => all_hits.extend(response.hits.hits),
+                Err(e) => {
+                    tracing::error!("Error fetching page: {}", e);
+                    return Err(e);
                 }
             }
-
-            current_page += BATCH_SIZE;
         }
 
         Ok(all_hits)
     }
+
+    /// Fetches a full-text search URL.
+    ///
+    /// Uses the search host's own rate limiter and turns EFTS JSON error payloads into
+    /// `EdgarError::SearchError`; other error bodies are reported as usual.
+    async fn get_search(&self, url: &str) -> Result<String> {
+        let (_, body) =
+            self.get_via(url, &self.search_rate_limiter, |url, status, body| {
+                match parse_search_error(body) {
+                    Some(message) => EdgarError::SearchError {
+                        status: status.as_u16(),
+                        url: url.to_string(),
+                        message,
+                    },
+                    None => self.status_error(url, status, body),
+                }
+            })
+            .await?;
+        Ok(body)
+    }
+}
+
+/// Extracts the reason from an EFTS error payload.
+///
+/// EFTS answers bad queries either with a bare `{"error": "..."}` or with the
+/// Elasticsearch shape, `{"error": {"root_cause": [..], "reason": "..."}, "status": 400}`.
+fn parse_search_error(body: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    let error = value.get("error").or_else(|| value.get("message"))?;
+    let message = match error {
+        Value::String(message) => message.clone(),
+        Value::Object(_) => error
+            .get("root_cause")
+            .and_then(|causes| causes.get(0))
+            .and_then(|cause| cause.get("reason"))
+            .or_else(|| error.get("reason"))
+            .or_else(|| error.get("type"))
+            .and_then(Value::as_str)?
+            .to_string(),
+        _ => return None,
+    };
+    Some(message)
+}
+
+fn parse_entity_matches(content: &str) -> Result<Vec<EntityMatch>> {
+    let response: EntityResponse = serde_json::from_str(content)?;
+    Ok(response
+        .hits
+        .hits
+        .into_iter()
+        .filter_map(EntityHit::into_match)
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_entity_matches() {
+        let content = r#"{
+            "hits": {
+                "total": {"value": 2, "relation": "eq"},
+                "hits": [
+                    {"_index": "edgar_entity", "_id": "1067983", "_score": 12.5,
+                     "_source": {"entity": "BERKSHIRE HATHAWAY INC  (BRK-B, BRK-A)  (CIK 0001067983)",
+                                 "tickers": "BRK-B, BRK-A"}},
+                    {"_index": "edgar_entity", "_id": "0000949012", "_score": 8.1,
+                     "_source": {"entity": "BERKSHIRE HATHAWAY ENERGY CO"}}
+                ]
+            }
+        }"#;
+
+        let matches = parse_entity_matches(content).unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].cik, 1067983);
+        assert_eq!(matches[0].name, "BERKSHIRE HATHAWAY INC");
+        assert_eq!(matches[0].tickers, vec!["BRK-B", "BRK-A"]);
+        assert_eq!(matches[1].cik, 949012);
+        assert!(matches[1].tickers.is_empty());
+    }
+
+    #[test]
+    fn test_date_sharding() {
+        let capped = TotalHits {
+            value: 10_000,
+            relation: "gte".to_string(),
+        };
+        assert!(exceeds_result_cap(&capped));
+        assert!(!exceeds_result_cap(&TotalHits {
+            value: 146,
+            relation: "eq".to_string(),
+        }));
+
+        let day = |d: &str| NaiveDate::parse_from_str(d, "%Y-%m-%d").unwrap();
+        let (older, newer) = split_window(day("2024-01-01"), day("2024-01-10")).unwrap();
+        assert_eq!(older, (day("2024-01-01"), day("2024-01-05")));
+        assert_eq!(newer, (day("2024-01-06"), day("2024-01-10")));
+        assert_eq!(split_window(day("2024-01-01"), day("2024-01-01")), None);
+
+        let options =
+            SearchOptions::new().with_date_range("2024-01-01".into(), "2024-03-31".into());
+        assert_eq!(
+            search_window(&options).unwrap(),
+            (day("2024-01-01"), day("2024-03-31"))
+        );
+        assert_eq!(
+            search_window(&SearchOptions::new()).unwrap().0,
+            day("2001-01-01")
+        );
+        let inverted =
+            SearchOptions::new().with_date_range("2024-03-31".into(), "2024-01-01".into());
+        assert!(matches!(
+            search_window(&inverted),
+            Err(EdgarError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_search_error() {
+        assert_eq!(
+            parse_search_error(r#"{"error": "Invalid date range"}"#).as_deref(),
+            Some("Invalid date range")
+        );
+        let elastic = r#"{"error": {"root_cause": [{"type": "query_shard_exception",
+            "reason": "Failed to parse query [\"unterminated]"}],
+            "type": "search_phase_execution_exception", "reason": "all shards failed"},
+            "status": 400}"#;
+        assert_eq!(
+            parse_search_error(elastic).as_deref(),
+            Some("Failed to parse query [\"unterminated]")
+        );
+        assert_eq!(parse_search_error("<html>Bad Gateway</html>"), None);
+        assert_eq!(parse_search_error(r#"{"hits": {}}"#), None);
+    }
+
     #[test]
     fn test_search_options_builder() {
         let options = SearchOptions::new()
@@ -758,5 +1411,18 @@ mod tests {
         assert!(params.contains(&("forms".to_string(), "10-K,10-Q".to_string())));
         assert!(params.contains(&("count".to_string(), "10".to_string())));
         assert!(params.contains(&("reverse_order".to_string(), "TRUE".to_string())));
+
+        let params = SearchOptions::new()
+            .with_query("test")
+            .with_aggregations_only(true)
+            .to_query_params();
+        assert!(params.contains(&("count".to_string(), "1".to_string())));
+
+        let params = SearchOptions::new()
+            .with_location(Location::California)
+            .with_locations([Location::NewYork, Location::country("Germany").unwrap()])
+            .to_query_params();
+        assert!(params.contains(&("locationCode".to_string(), "CA".to_string())));
+        assert!(params.contains(&("locationCodes".to_string(), "NY,2M".to_string())));
     }
 }
@@ -0,0 +1,216 @@
+//! Persistent record of already processed accession numbers.
+//!
+//! Watchers and backfills that restart (after a deploy, a crash, or a rate-limit pause) see
+//! the same filings again. [`SeenSet`] remembers which identifiers were handled so each
+//! filing reaches downstream systems once. Two backends are available:
+//!
+//! - a plain text file with one identifier per line ([`SeenSet::open`]), loaded into memory
+//!   on open and appended to on every insert;
+//! - an SQLite database ([`SeenSet::open_sqlite`], `sqlite` feature) that keeps nothing in
+//!   memory and suits sets with many millions of entries.
+//!
+//! Every insert is written through before it returns, so a set reopened after a crash
+//! contains everything reported as new. Operations are blocking file I/O; they are small
+//! enough to call from async code directly.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::SeenSet;
+//!
+//! # fn example(accessions: Vec<String>) -> edgarkit::Result<()> {
+//! let mut seen = SeenSet::open("seen-accessions.txt")?;
+//! for accession in accessions {
+//!     if seen.contains(&accession)? {
+//!         continue;
+//!     }
+//!     // Deliver first, then mark, so a crash in between redelivers instead of dropping.
+//!     println!("new filing {}", accession);
+//!     seen.insert_if_new(&accession)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::error::Result;
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// A set of identifiers that survives restarts.
+#[derive(Debug)]
+pub struct SeenSet {
+    backend: Backend,
+}
+
+#[derive(Debug)]
+enum Backend {
+    Memory(HashSet<String>),
+    File {
+        ids: HashSet<String>,
+        file: File,
+    },
+    #[cfg(feature = "sqlite")]
+    Sqlite(rusqlite::Connection),
+}
+
+impl SeenSet {
+    /// A set that is not persisted, for tests and one-off runs.
+    pub fn in_memory() -> Self {
+        Self {
+            backend: Backend::Memory(HashSet::new()),
+        }
+    }
+
+    /// Opens (or creates) a set stored as a text file with one identifier per line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::FileError` if the file cannot be read or created.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut ids = HashSet::new();
+        match File::open(path) {
+            Ok(file) => {
+                for line in BufReader::new(file).lines() {
+                    let line = line?;
+                    let id = line.trim();
+                    if !id.is_empty() {
+                        ids.insert(id.to_string());
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            backend: Backend::File { ids, file },
+        })
+    }
+
+    /// Opens (or creates) a set stored in an SQLite database.
+    ///
+    /// The identifiers live in a `seen` table, so the database can be shared with other
+    /// application state.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::Database` if the database cannot be opened or initialized.
+    #[cfg(feature = "sqlite")]
+    pub fn open_sqlite(path: impl AsRef<Path>) -> Result<Self> {
+        let conn = rusqlite::Connection::open(path)?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS seen (id TEXT PRIMARY KEY NOT NULL) WITHOUT ROWID",
+            [],
+        )?;
+        Ok(Self {
+            backend: Backend::Sqlite(conn),
+        })
+    }
+
+    /// Records `id`, returning `true` if it was not in the set before.
+    ///
+    /// The identifier is persisted before this returns. Surrounding whitespace is ignored.
+    pub fn insert_if_new(&mut self, id: &str) -> Result<bool> {
+        let id = id.trim();
+        match &mut self.backend {
+            Backend::Memory(ids) => Ok(ids.insert(id.to_string())),
+            Backend::File { ids, file } => {
+                if ids.contains(id) {
+                    return Ok(false);
+                }
+                writeln!(file, "{}", id)?;
+                file.flush()?;
+                ids.insert(id.to_string());
+                Ok(true)
+            }
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(conn) => {
+                let inserted = conn.execute("INSERT OR IGNORE INTO seen (id) VALUES (?1)", [id])?;
+                Ok(inserted == 1)
+            }
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> Result<bool> {
+        let id = id.trim();
+        match &self.backend {
+            Backend::Memory(ids) | Backend::File { ids, .. } => Ok(ids.contains(id)),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(conn) => {
+                let mut stmt = conn.prepare_cached("SELECT 1 FROM seen WHERE id = ?1")?;
+                Ok(stmt.exists([id])?)
+            }
+        }
+    }
+
+    /// Number of identifiers in the set.
+    pub fn len(&self) -> Result<usize> {
+        match &self.backend {
+            Backend::Memory(ids) | Backend::File { ids, .. } => Ok(ids.len()),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite(conn) => {
+                let count: i64 =
+                    conn.query_row("SELECT COUNT(*) FROM seen", [], |row| row.get(0))?;
+                Ok(count as usize)
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Flushes file contents to disk (`fsync`). Inserts are already written to the OS; this
+    /// additionally protects them against power loss.
+    pub fn sync(&self) -> Result<()> {
+        if let Backend::File { file, .. } = &self.backend {
+            file.sync_data()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_backed_set_survives_reopen() {
+        let path = std::env::temp_dir().join(format!("edgarkit-seen-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut seen = SeenSet::open(&path).unwrap();
+        assert!(seen.insert_if_new("0000320193-23-000106").unwrap());
+        assert!(!seen.insert_if_new("0000320193-23-000106 ").unwrap());
+        assert!(seen.insert_if_new("0000320193-23-000077").unwrap());
+        drop(seen);
+
+        let mut reopened = SeenSet::open(&path).unwrap();
+        assert_eq!(reopened.len().unwrap(), 2);
+        assert!(reopened.contains("0000320193-23-000077").unwrap());
+        assert!(!reopened.insert_if_new("0000320193-23-000106").unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_backed_set() {
+        let path = std::env::temp_dir().join(format!("edgarkit-seen-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let mut seen = SeenSet::open_sqlite(&path).unwrap();
+        assert!(seen.insert_if_new("a").unwrap());
+        assert!(!seen.insert_if_new("a").unwrap());
+        drop(seen);
+
+        let reopened = SeenSet::open_sqlite(&path).unwrap();
+        assert!(reopened.contains("a").unwrap());
+        assert_eq!(reopened.len().unwrap(), 1);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
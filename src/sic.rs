@@ -0,0 +1,731 @@
+//! Standard Industrial Classification codes.
+//!
+//! EDGAR assigns every operating company a four-digit SIC code (`Submission::sic`) that
+//! decides which Corporation Finance office reviews its filings. [`SicCode`] looks a code up
+//! in the SEC's published code list, embedded here, and places it in its SIC division
+//! ([`IndustryGroup`]) so callers can branch on industry without matching raw numbers.
+//!
+//! ```rust
+//! use edgarkit::{IndustryGroup, SicCode, SicOffice};
+//!
+//! let sic: SicCode = "3571".parse().unwrap();
+//! assert_eq!(sic.description(), Some("ELECTRONIC COMPUTERS"));
+//! assert_eq!(sic.office(), Some(SicOffice::Technology));
+//! assert_eq!(sic.industry_group(), Some(IndustryGroup::Manufacturing));
+//! assert_eq!(SicCode::new(6770).to_string(), "6770");
+//! ```
+
+use super::error::{EdgarError, Result};
+use SicOffice::{
+    EnergyAndTransportation as ENERGY, Finance as FINANCE, InternationalCorpFin as INTERNATIONAL,
+    LifeSciences as LIFE_SCIENCES, Manufacturing as MANUFACTURING,
+    RealEstateAndConstruction as REAL_ESTATE, StructuredFinance as STRUCTURED,
+    Technology as TECHNOLOGY, TradeAndServices as TRADE,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use std::fmt;
+use std::str::FromStr;
+
+/// A four-digit Standard Industrial Classification code.
+///
+/// Codes outside the SEC's list are still valid values; they just have no
+/// [`description`](Self::description) or [`office`](Self::office). Displays and serializes
+/// zero-padded (`0100`), and deserializes from a number or a string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SicCode(u16);
+
+/// The Division of Corporation Finance office that reviews filings under a SIC code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SicOffice {
+    EnergyAndTransportation,
+    Finance,
+    /// Foreign governments and depositary receipts
+    InternationalCorpFin,
+    LifeSciences,
+    Manufacturing,
+    RealEstateAndConstruction,
+    /// Asset-backed securities
+    StructuredFinance,
+    Technology,
+    TradeAndServices,
+}
+
+/// The SIC division a code belongs to, fixed by its first two digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum IndustryGroup {
+    /// 01-09 Agriculture, Forestry, and Fishing
+    Agriculture,
+    /// 10-14 Mining, including oil and gas extraction
+    Mining,
+    /// 15-17
+    Construction,
+    /// 20-39
+    Manufacturing,
+    /// 40-49 Transportation, Communications, Electric, Gas, and Sanitary Services
+    TransportationAndUtilities,
+    /// 50-51
+    WholesaleTrade,
+    /// 52-59
+    RetailTrade,
+    /// 60-67 Finance, Insurance, and Real Estate
+    Finance,
+    /// 70-89
+    Services,
+    /// 91-97
+    PublicAdministration,
+    /// 99, plus the SEC's own 88xx codes for foreign filers
+    Nonclassifiable,
+}
+
+impl SicCode {
+    pub const fn new(code: u16) -> Self {
+        Self(code)
+    }
+
+    pub const fn as_u16(self) -> u16 {
+        self.0
+    }
+
+    /// The first two digits, e.g. `35` for industrial machinery and computer equipment.
+    pub const fn major_group(self) -> u8 {
+        (self.0 / 100) as u8
+    }
+
+    /// The SEC's industry title, e.g. `PHARMACEUTICAL PREPARATIONS` for 2834.
+    pub fn description(self) -> Option<&'static str> {
+        self.entry().map(|(_, _, description)| *description)
+    }
+
+    /// The office that reviews filings of companies with this code.
+    pub fn office(self) -> Option<SicOffice> {
+        self.entry().map(|(_, office, _)| *office)
+    }
+
+    /// The SIC division, or `None` for major groups no division covers (such as `00`).
+    pub fn industry_group(self) -> Option<IndustryGroup> {
+        Some(match self.major_group() {
+            1..=9 => IndustryGroup::Agriculture,
+            10..=14 => IndustryGroup::Mining,
+            15..=17 => IndustryGroup::Construction,
+            20..=39 => IndustryGroup::Manufacturing,
+            40..=49 => IndustryGroup::TransportationAndUtilities,
+            50..=51 => IndustryGroup::WholesaleTrade,
+            52..=59 => IndustryGroup::RetailTrade,
+            60..=67 => IndustryGroup::Finance,
+            70..=87 | 89 => IndustryGroup::Services,
+            88 | 99 => IndustryGroup::Nonclassifiable,
+            91..=97 => IndustryGroup::PublicAdministration,
+            _ => return None,
+        })
+    }
+
+    /// Whether the code is on the SEC's list.
+    pub fn is_known(self) -> bool {
+        self.entry().is_some()
+    }
+
+    /// Every code on the SEC's list, in ascending order.
+    pub fn all() -> impl Iterator<Item = SicCode> {
+        SIC_CODES.iter().map(|(code, _, _)| SicCode(*code))
+    }
+
+    fn entry(self) -> Option<&'static (u16, SicOffice, &'static str)> {
+        SIC_CODES
+            .binary_search_by_key(&self.0, |(code, _, _)| *code)
+            .ok()
+            .map(|at| &SIC_CODES[at])
+    }
+}
+
+impl SicOffice {
+    /// The office name as EDGAR prints it, e.g. `Office of Life Sciences`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SicOffice::EnergyAndTransportation => "Office of Energy & Transportation",
+            SicOffice::Finance => "Office of Finance",
+            SicOffice::InternationalCorpFin => "Office of International Corp Fin",
+            SicOffice::LifeSciences => "Office of Life Sciences",
+            SicOffice::Manufacturing => "Office of Manufacturing",
+            SicOffice::RealEstateAndConstruction => "Office of Real Estate & Construction",
+            SicOffice::StructuredFinance => "Office of Structured Finance",
+            SicOffice::Technology => "Office of Technology",
+            SicOffice::TradeAndServices => "Office of Trade & Services",
+        }
+    }
+}
+
+impl fmt::Display for SicOffice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<u16> for SicCode {
+    fn from(code: u16) -> Self {
+        Self(code)
+    }
+}
+
+/// Parses one to four digits (`100` and `0100` are the same code), ignoring surrounding
+/// whitespace.
+impl FromStr for SicCode {
+    type Err = EdgarError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let digits = s.trim();
+        if digits.is_empty() || digits.len() > 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EdgarError::InvalidFormat(format!(
+                "{:?} is not a four-digit SIC code",
+                s
+            )));
+        }
+        Ok(Self(digits.parse().expect("at most four ASCII digits")))
+    }
+}
+
+impl fmt::Display for SicCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl Serialize for SicCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SicCode {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = SicCode;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a SIC code as a number or string")
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<SicCode, E>
+            where
+                E: de::Error,
+            {
+                u16::try_from(v)
+                    .ok()
+                    .filter(|code| *code <= 9999)
+                    .map(SicCode)
+                    .ok_or_else(|| E::custom(format!("{} is not a four-digit SIC code", v)))
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<SicCode, E>
+            where
+                E: de::Error,
+            {
+                u64::try_from(v)
+                    .map_err(E::custom)
+                    .and_then(|v| self.visit_u64(v))
+            }
+
+            fn visit_str<E>(self, v: &str) -> std::result::Result<SicCode, E>
+            where
+                E: de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+/// The SEC's SIC code list (code, reviewing office, industry title), sorted by code.
+#[rustfmt::skip]
+static SIC_CODES: &[(u16, SicOffice, &str)] = &[
+    (100, ENERGY, "AGRICULTURAL PRODUCTION-CROPS"),
+    (200, ENERGY, "AGRICULTURAL PROD-LIVESTOCK & ANIMAL SPECIALTIES"),
+    (700, ENERGY, "AGRICULTURAL SERVICES"),
+    (800, ENERGY, "FORESTRY"),
+    (900, ENERGY, "FISHING, HUNTING AND TRAPPING"),
+    (1000, ENERGY, "METAL MINING"),
+    (1040, ENERGY, "GOLD AND SILVER ORES"),
+    (1090, ENERGY, "MISCELLANEOUS METAL ORES"),
+    (1220, ENERGY, "BITUMINOUS COAL & LIGNITE MINING"),
+    (1221, ENERGY, "BITUMINOUS COAL & LIGNITE SURFACE MINING"),
+    (1311, ENERGY, "CRUDE PETROLEUM & NATURAL GAS"),
+    (1381, ENERGY, "DRILLING OIL & GAS WELLS"),
+    (1382, ENERGY, "OIL & GAS FIELD EXPLORATION SERVICES"),
+    (1389, ENERGY, "OIL & GAS FIELD SERVICES, NEC"),
+    (1400, ENERGY, "MINING & QUARRYING OF NONMETALLIC MINERALS (NO FUELS)"),
+    (1520, REAL_ESTATE, "GENERAL BLDG CONTRACTORS - RESIDENTIAL BLDGS"),
+    (1531, REAL_ESTATE, "OPERATIVE BUILDERS"),
+    (1540, REAL_ESTATE, "GENERAL BLDG CONTRACTORS - NONRESIDENTIAL BLDGS"),
+    (1600, REAL_ESTATE, "HEAVY CONSTRUCTION OTHER THAN BLDG CONST - CONTRACTORS"),
+    (1623, REAL_ESTATE, "WATER, SEWER, PIPELINE, COMM & POWER LINE CONSTRUCTION"),
+    (1700, REAL_ESTATE, "CONSTRUCTION - SPECIAL TRADE CONTRACTORS"),
+    (1731, REAL_ESTATE, "ELECTRICAL WORK"),
+    (2000, MANUFACTURING, "FOOD AND KINDRED PRODUCTS"),
+    (2011, MANUFACTURING, "MEAT PACKING PLANTS"),
+    (2013, MANUFACTURING, "SAUSAGES & OTHER PREPARED MEAT PRODUCTS"),
+    (2015, MANUFACTURING, "POULTRY SLAUGHTERING AND PROCESSING"),
+    (2020, MANUFACTURING, "DAIRY PRODUCTS"),
+    (2024, MANUFACTURING, "ICE CREAM & FROZEN DESSERTS"),
+    (2030, MANUFACTURING, "CANNED, FROZEN & PRESERVD FRUIT, VEG & FOOD SPECIALTIES"),
+    (2033, MANUFACTURING, "CANNED, FRUITS, VEG, PRESERVES, JAMS & JELLIES"),
+    (2040, MANUFACTURING, "GRAIN MILL PRODUCTS"),
+    (2050, MANUFACTURING, "BAKERY PRODUCTS"),
+    (2052, MANUFACTURING, "COOKIES & CRACKERS"),
+    (2060, MANUFACTURING, "SUGAR & CONFECTIONERY PRODUCTS"),
+    (2070, MANUFACTURING, "FATS & OILS"),
+    (2080, MANUFACTURING, "BEVERAGES"),
+    (2082, MANUFACTURING, "MALT BEVERAGES"),
+    (2086, MANUFACTURING, "BOTTLED & CANNED SOFT DRINKS & CARBONATED WATERS"),
+    (2090, MANUFACTURING, "MISCELLANEOUS FOOD PREPARATIONS & KINDRED PRODUCTS"),
+    (2092, MANUFACTURING, "PREPARED FRESH OR FROZEN FISH & SEAFOODS"),
+    (2100, MANUFACTURING, "TOBACCO PRODUCTS"),
+    (2111, MANUFACTURING, "CIGARETTES"),
+    (2200, MANUFACTURING, "TEXTILE MILL PRODUCTS"),
+    (2211, MANUFACTURING, "BROADWOVEN FABRIC MILLS, COTTON"),
+    (2221, MANUFACTURING, "BROADWOVEN FABRIC MILLS, MAN MADE FIBER & SILK"),
+    (2250, MANUFACTURING, "KNITTING MILLS"),
+    (2253, MANUFACTURING, "KNIT OUTERWEAR MILLS"),
+    (2273, MANUFACTURING, "CARPETS & RUGS"),
+    (2300, MANUFACTURING, "APPAREL & OTHER FINISHD PRODS OF FABRICS & SIMILAR MATL"),
+    (2320, MANUFACTURING, "MEN'S & BOYS' FURNISHGS, WORK CLOTHG, & ALLIED GARMENTS"),
+    (2330, MANUFACTURING, "WOMEN'S, MISSES', AND JUNIORS OUTERWEAR"),
+    (2340, MANUFACTURING, "WOMEN'S, MISSES', CHILDREN'S & INFANTS' UNDERGARMENTS"),
+    (2390, MANUFACTURING, "MISCELLANEOUS FABRICATED TEXTILE PRODUCTS"),
+    (2400, REAL_ESTATE, "LUMBER & WOOD PRODUCTS (NO FURNITURE)"),
+    (2421, REAL_ESTATE, "SAWMILLS & PLANTING MILLS, GENERAL"),
+    (2430, REAL_ESTATE, "MILLWOOD, VENEER, PLYWOOD, & STRUCTURAL WOOD MEMBERS"),
+    (2451, REAL_ESTATE, "MOBILE HOMES"),
+    (2452, REAL_ESTATE, "PREFABRICATED WOOD BLDGS & COMPONENTS"),
+    (2510, MANUFACTURING, "HOUSEHOLD FURNITURE"),
+    (2511, MANUFACTURING, "WOOD HOUSEHOLD FURNITURE, (NO UPHOLSTERED)"),
+    (2520, MANUFACTURING, "OFFICE FURNITURE"),
+    (2522, MANUFACTURING, "OFFICE FURNITURE (NO WOOD)"),
+    (2531, MANUFACTURING, "PUBLIC BLDG & RELATED FURNITURE"),
+    (2540, MANUFACTURING, "PARTITIONS, SHELVG, LOCKERS, & OFFICE & STORE FIXTURES"),
+    (2590, MANUFACTURING, "MISCELLANEOUS FURNITURE & FIXTURES"),
+    (2600, MANUFACTURING, "PAPERS & ALLIED PRODUCTS"),
+    (2611, MANUFACTURING, "PULP MILLS"),
+    (2621, MANUFACTURING, "PAPER MILLS"),
+    (2631, MANUFACTURING, "PAPERBOARD MILLS"),
+    (2650, MANUFACTURING, "PAPERBOARD CONTAINERS & BOXES"),
+    (2670, MANUFACTURING, "CONVERTED PAPER & PAPERBOARD PRODS (NO CONTANERS/BOXES)"),
+    (2673, MANUFACTURING, "PLASTICS, FOIL & COATED PAPER BAGS"),
+    (2711, TRADE, "NEWSPAPERS: PUBLISHING OR PUBLISHING & PRINTING"),
+    (2721, TRADE, "PERIODICALS: PUBLISHING OR PUBLISHING & PRINTING"),
+    (2731, TRADE, "BOOKS: PUBLISHING OR PUBLISHING & PRINTING"),
+    (2732, TRADE, "BOOK PRINTING"),
+    (2741, TRADE, "MISCELLANEOUS PUBLISHING"),
+    (2750, TRADE, "COMMERCIAL PRINTING"),
+    (2761, TRADE, "MANIFOLD BUSINESS FORMS"),
+    (2771, TRADE, "GREETING CARDS"),
+    (2780, TRADE, "BLANKBOOKS, LOOSELEAF BINDERS & BOOKBINDG & RELATD WORK"),
+    (2790, TRADE, "SERVICE INDUSTRIES FOR THE PRINTING TRADE"),
+    (2800, MANUFACTURING, "CHEMICALS & ALLIED PRODUCTS"),
+    (2810, MANUFACTURING, "INDUSTRIAL INORGANIC CHEMICALS"),
+    (2820, MANUFACTURING, "PLASTIC MATERIAL, SYNTH RESIN/RUBBER, CELLULOS (NO GLASS)"),
+    (2821, MANUFACTURING, "PLASTIC MATERIALS, SYNTH RESINS & NONVULCAN ELASTOMERS"),
+    (2833, LIFE_SCIENCES, "MEDICINAL CHEMICALS & BOTANICAL PRODUCTS"),
+    (2834, LIFE_SCIENCES, "PHARMACEUTICAL PREPARATIONS"),
+    (2835, LIFE_SCIENCES, "IN VITRO & IN VIVO DIAGNOSTIC SUBSTANCES"),
+    (2836, LIFE_SCIENCES, "BIOLOGICAL PRODUCTS, (NO DIAGNOSTIC SUBSTANCES)"),
+    (2840, MANUFACTURING, "SOAP, DETERGENTS, CLEANG PREPARATIONS, PERFUMES, COSMETICS"),
+    (2842, MANUFACTURING, "SPECIALTY CLEANING, POLISHING AND SANITATION PREPARATIONS"),
+    (2844, MANUFACTURING, "PERFUMES, COSMETICS & OTHER TOILET PREPARATIONS"),
+    (2851, MANUFACTURING, "PAINTS, VARNISHES, LACQUERS, ENAMELS & ALLIED PRODS"),
+    (2860, MANUFACTURING, "INDUSTRIAL ORGANIC CHEMICALS"),
+    (2870, MANUFACTURING, "AGRICULTURAL CHEMICALS"),
+    (2890, MANUFACTURING, "MISCELLANEOUS CHEMICAL PRODUCTS"),
+    (2891, MANUFACTURING, "ADHESIVES & SEALANTS"),
+    (2911, ENERGY, "PETROLEUM REFINING"),
+    (2950, ENERGY, "ASPHALT PAVING & ROOFING MATERIALS"),
+    (2990, ENERGY, "MISCELLANEOUS PRODUCTS OF PETROLEUM & COAL"),
+    (3011, MANUFACTURING, "TIRES & INNER TUBES"),
+    (3021, MANUFACTURING, "RUBBER & PLASTICS FOOTWEAR"),
+    (3050, MANUFACTURING, "GASKETS, PACKG & SEALG DEVICES & RUBBER & PLASTICS HOSE"),
+    (3060, MANUFACTURING, "FABRICATED RUBBER PRODUCTS, NEC"),
+    (3080, MANUFACTURING, "MISCELLANEOUS PLASTICS PRODUCTS"),
+    (3081, MANUFACTURING, "UNSUPPORTED PLASTICS FILM & SHEET"),
+    (3086, MANUFACTURING, "PLASTICS FOAM PRODUCTS"),
+    (3089, MANUFACTURING, "PLASTICS PRODUCTS, NEC"),
+    (3100, MANUFACTURING, "LEATHER & LEATHER PRODUCTS"),
+    (3140, MANUFACTURING, "FOOTWEAR, (NO RUBBER)"),
+    (3211, MANUFACTURING, "FLAT GLASS"),
+    (3220, MANUFACTURING, "GLASS & GLASSWARE, PRESSED OR BLOWN"),
+    (3221, MANUFACTURING, "GLASS CONTAINERS"),
+    (3231, MANUFACTURING, "GLASS PRODUCTS, MADE OF PURCHASED GLASS"),
+    (3241, MANUFACTURING, "CEMENT, HYDRAULIC"),
+    (3250, MANUFACTURING, "STRUCTURAL CLAY PRODUCTS"),
+    (3260, MANUFACTURING, "POTTERY & RELATED PRODUCTS"),
+    (3270, MANUFACTURING, "CONCRETE, GYPSUM & PLASTER PRODUCTS"),
+    (3272, MANUFACTURING, "CONCRETE PRODUCTS, EXCEPT BLOCK & BRICK"),
+    (3281, MANUFACTURING, "CUT STONE & STONE PRODUCTS"),
+    (3290, MANUFACTURING, "ABRASIVE, ASBESTOS & MISC NONMETALLIC MINERAL PRODS"),
+    (3310, MANUFACTURING, "STEEL WORKS, BLAST FURNACES & ROLLING & FINISHING MILLS"),
+    (3312, MANUFACTURING, "STEEL WORKS, BLAST FURNACES & ROLLING MILLS (COKE OVENS)"),
+    (3317, MANUFACTURING, "STEEL PIPE & TUBES"),
+    (3320, MANUFACTURING, "IRON & STEEL FOUNDRIES"),
+    (3330, MANUFACTURING, "PRIMARY SMELTING & REFINING OF NONFERROUS METALS"),
+    (3334, MANUFACTURING, "PRIMARY PRODUCTION OF ALUMINUM"),
+    (3341, MANUFACTURING, "SECONDARY SMELTING & REFINING OF NONFERROUS METALS"),
+    (3350, MANUFACTURING, "ROLLING DRAWING & EXTRUDING OF NONFERROUS METALS"),
+    (3357, MANUFACTURING, "DRAWING & INSULATING OF NONFERROUS WIRE"),
+    (3360, MANUFACTURING, "NONFERROUS FOUNDRIES (CASTINGS)"),
+    (3390, MANUFACTURING, "MISCELLANEOUS PRIMARY METAL PRODUCTS"),
+    (3411, MANUFACTURING, "METAL CANS"),
+    (3412, MANUFACTURING, "METAL SHIPPING BARRELS, DRUMS, KEGS & PAILS"),
+    (3420, MANUFACTURING, "CUTLERY, HANDTOOLS & GENERAL HARDWARE"),
+    (3430, MANUFACTURING, "HEATING EQUIP, EXCEPT ELEC & WARM AIR; & PLUMBING FIXTURES"),
+    (3433, MANUFACTURING, "HEATING EQUIPMENT, EXCEPT ELECTRIC & WARM AIR FURNACES"),
+    (3440, MANUFACTURING, "FABRICATED STRUCTURAL METAL PRODUCTS"),
+    (3442, MANUFACTURING, "METAL DOORS, SASH, FRAMES, MOLDINGS & TRIM"),
+    (3443, MANUFACTURING, "FABRICATED PLATE WORK (BOILER SHOPS)"),
+    (3444, MANUFACTURING, "SHEET METAL WORK"),
+    (3448, MANUFACTURING, "PREFABRICATED METAL BUILDINGS & COMPONENTS"),
+    (3451, MANUFACTURING, "SCREW MACHINE PRODUCTS"),
+    (3452, MANUFACTURING, "BOLTS, NUTS, SCREWS, RIVETS & WASHERS"),
+    (3460, MANUFACTURING, "METAL FORGINGS & STAMPINGS"),
+    (3470, MANUFACTURING, "COATING, ENGRAVING & ALLIED SERVICES"),
+    (3480, MANUFACTURING, "ORDNANCE & ACCESSORIES, (NO VEHICLES/GUIDED MISSILES)"),
+    (3490, MANUFACTURING, "MISCELLANEOUS FABRICATED METAL PRODUCTS"),
+    (3510, MANUFACTURING, "ENGINES & TURBINES"),
+    (3523, MANUFACTURING, "FARM MACHINERY & EQUIPMENT"),
+    (3524, MANUFACTURING, "LAWN & GARDEN TRACTORS & HOME LAWN & GARDENS EQUIP"),
+    (3530, MANUFACTURING, "CONSTRUCTION, MINING & MATERIALS HANDLING MACHINERY & EQUIP"),
+    (3531, MANUFACTURING, "CONSTRUCTION MACHINERY & EQUIP"),
+    (3532, MANUFACTURING, "MINING MACHINERY & EQUIP (NO OIL & GAS FIELD MACH & EQUIP)"),
+    (3533, MANUFACTURING, "OIL & GAS FIELD MACHINERY & EQUIPMENT"),
+    (3537, MANUFACTURING, "INDUSTRIAL TRUCKS, TRACTORS, TRAILERS & STACKERS"),
+    (3540, MANUFACTURING, "METALWORKG MACHINERY & EQUIPMENT"),
+    (3541, MANUFACTURING, "MACHINE TOOLS, METAL CUTTING TYPES"),
+    (3550, MANUFACTURING, "SPECIAL INDUSTRY MACHINERY (NO METALWORKING MACHINERY)"),
+    (3555, MANUFACTURING, "PRINTING TRADES MACHINERY & EQUIPMENT"),
+    (3559, MANUFACTURING, "SPECIAL INDUSTRY MACHINERY, NEC"),
+    (3560, MANUFACTURING, "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT"),
+    (3561, MANUFACTURING, "PUMPS & PUMPING EQUIPMENT"),
+    (3562, MANUFACTURING, "BALL & ROLLER BEARINGS"),
+    (3564, MANUFACTURING, "INDUSTRIAL & COMMERCIAL FANS & BLOWERS & AIR PURIFING EQUIP"),
+    (3567, MANUFACTURING, "INDUSTRIAL PROCESS FURNACES & OVENS"),
+    (3569, MANUFACTURING, "GENERAL INDUSTRIAL MACHINERY & EQUIPMENT, NEC"),
+    (3570, TECHNOLOGY, "COMPUTER & OFFICE EQUIPMENT"),
+    (3571, TECHNOLOGY, "ELECTRONIC COMPUTERS"),
+    (3572, TECHNOLOGY, "COMPUTER STORAGE DEVICES"),
+    (3575, TECHNOLOGY, "COMPUTER TERMINALS"),
+    (3576, TECHNOLOGY, "COMPUTER COMMUNICATIONS EQUIPMENT"),
+    (3577, TECHNOLOGY, "COMPUTER PERIPHERAL EQUIPMENT, NEC"),
+    (3578, TECHNOLOGY, "CALCULATING & ACCOUNTING MACHINES (NO ELECTRONIC COMPUTERS)"),
+    (3579, TECHNOLOGY, "OFFICE MACHINES, NEC"),
+    (3580, MANUFACTURING, "REFRIGERATION & SERVICE INDUSTRY MACHINERY"),
+    (3585, MANUFACTURING, "AIR-COND & WARM AIR HEATG EQUIP & COMM & INDL REFRIG EQUIP"),
+    (3590, MANUFACTURING, "MISC INDUSTRIAL & COMMERCIAL MACHINERY & EQUIPMENT"),
+    (3600, MANUFACTURING, "ELECTRONIC & OTHER ELECTRICAL EQUIPMENT (NO COMPUTER EQUIP)"),
+    (3612, MANUFACTURING, "POWER, DISTRIBUTION & SPECIALTY TRANSFORMERS"),
+    (3613, MANUFACTURING, "SWITCHGEAR & SWITCHBOARD APPARATUS"),
+    (3620, MANUFACTURING, "ELECTRICAL INDUSTRIAL APPARATUS"),
+    (3621, MANUFACTURING, "MOTORS & GENERATORS"),
+    (3630, MANUFACTURING, "HOUSEHOLD APPLIANCES"),
+    (3634, MANUFACTURING, "ELECTRIC HOUSEWARES & FANS"),
+    (3640, MANUFACTURING, "ELECTRIC LIGHTING & WIRING EQUIPMENT"),
+    (3651, MANUFACTURING, "HOUSEHOLD AUDIO & VIDEO EQUIPMENT"),
+    (3652, TECHNOLOGY, "PHONOGRAPH RECORDS & PRERECORDED AUDIO TAPES & DISKS"),
+    (3661, TECHNOLOGY, "TELEPHONE & TELEGRAPH APPARATUS"),
+    (3663, TECHNOLOGY, "RADIO & TV BROADCASTING & COMMUNICATIONS EQUIPMENT"),
+    (3669, TECHNOLOGY, "COMMUNICATIONS EQUIPMENT, NEC"),
+    (3670, TECHNOLOGY, "ELECTRONIC COMPONENTS & ACCESSORIES"),
+    (3672, TECHNOLOGY, "PRINTED CIRCUIT BOARDS"),
+    (3674, TECHNOLOGY, "SEMICONDUCTORS & RELATED DEVICES"),
+    (3677, TECHNOLOGY, "ELECTRONIC COILS, TRANSFORMERS & OTHER INDUCTORS"),
+    (3678, TECHNOLOGY, "ELECTRONIC CONNECTORS"),
+    (3679, TECHNOLOGY, "ELECTRONIC COMPONENTS, NEC"),
+    (3690, MANUFACTURING, "MISCELLANEOUS ELECTRICAL MACHINERY, EQUIPMENT & SUPPLIES"),
+    (3695, TECHNOLOGY, "MAGNETIC & OPTICAL RECORDING MEDIA"),
+    (3711, MANUFACTURING, "MOTOR VEHICLES & PASSENGER CAR BODIES"),
+    (3713, MANUFACTURING, "TRUCK & BUS BODIES"),
+    (3714, MANUFACTURING, "MOTOR VEHICLE PARTS & ACCESSORIES"),
+    (3715, MANUFACTURING, "TRUCK TRAILERS"),
+    (3716, MANUFACTURING, "MOTOR HOMES"),
+    (3720, MANUFACTURING, "AIRCRAFT & PARTS"),
+    (3721, MANUFACTURING, "AIRCRAFT"),
+    (3724, MANUFACTURING, "AIRCRAFT ENGINES & ENGINE PARTS"),
+    (3728, MANUFACTURING, "AIRCRAFT PARTS & AUXILIARY EQUIPMENT, NEC"),
+    (3730, MANUFACTURING, "SHIP & BOAT BUILDING & REPAIRING"),
+    (3743, MANUFACTURING, "RAILROAD EQUIPMENT"),
+    (3751, MANUFACTURING, "MOTORCYCLES, BICYCLES & PARTS"),
+    (3760, MANUFACTURING, "GUIDED MISSILES & SPACE VEHICLES & PARTS"),
+    (3790, MANUFACTURING, "MISCELLANEOUS TRANSPORTATION EQUIPMENT"),
+    (3812, MANUFACTURING, "SEARCH, DETECTION, NAVIGATION, GUIDANCE, AERONAUTICAL SYS"),
+    (3821, MANUFACTURING, "LABORATORY APPARATUS & FURNITURE"),
+    (3822, MANUFACTURING, "AUTO CONTROLS FOR REGULATING RESIDENTIAL & COMML ENVIRONMENTS"),
+    (3823, MANUFACTURING, "INDUSTRIAL INSTRUMENTS FOR MEASUREMENT, DISPLAY, AND CONTROL"),
+    (3824, MANUFACTURING, "TOTALIZING FLUID METERS & COUNTING DEVICES"),
+    (3825, MANUFACTURING, "INSTRUMENTS FOR MEAS & TESTING OF ELECTRICITY & ELEC SIGNALS"),
+    (3826, MANUFACTURING, "LABORATORY ANALYTICAL INSTRUMENTS"),
+    (3827, MANUFACTURING, "OPTICAL INSTRUMENTS & LENSES"),
+    (3829, MANUFACTURING, "MEASURING & CONTROLLING DEVICES, NEC"),
+    (3841, LIFE_SCIENCES, "SURGICAL & MEDICAL INSTRUMENTS & APPARATUS"),
+    (3842, LIFE_SCIENCES, "ORTHOPEDIC, PROSTHETIC & SURGICAL APPLIANCES & SUPPLIES"),
+    (3843, LIFE_SCIENCES, "DENTAL EQUIPMENT & SUPPLIES"),
+    (3844, LIFE_SCIENCES, "X-RAY APPARATUS & TUBES & RELATED IRRADIATION APPARATUS"),
+    (3845, LIFE_SCIENCES, "ELECTROMEDICAL & ELECTROTHERAPEUTIC APPARATUS"),
+    (3851, LIFE_SCIENCES, "OPHTHALMIC GOODS"),
+    (3861, MANUFACTURING, "PHOTOGRAPHIC EQUIPMENT & SUPPLIES"),
+    (3873, MANUFACTURING, "WATCHES, CLOCKS, CLOCKWORK OPERATED DEVICES/PARTS"),
+    (3910, MANUFACTURING, "JEWELRY, SILVERWARE & PLATED WARE"),
+    (3911, MANUFACTURING, "JEWELRY, PRECIOUS METAL"),
+    (3942, MANUFACTURING, "DOLLS & STUFFED TOYS"),
+    (3944, MANUFACTURING, "GAMES, TOYS & CHILDREN'S VEHICLES (NO DOLLS & BICYCLES)"),
+    (3949, MANUFACTURING, "SPORTING & ATHLETIC GOODS, NEC"),
+    (3950, MANUFACTURING, "PENS, PENCILS & OTHER ARTISTS' MATERIALS"),
+    (3960, MANUFACTURING, "COSTUME JEWELRY & NOVELTIES"),
+    (3990, MANUFACTURING, "MISCELLANEOUS MANUFACTURING INDUSTRIES"),
+    (4011, ENERGY, "RAILROADS, LINE-HAUL OPERATING"),
+    (4013, ENERGY, "RAILROAD SWITCHING & TERMINAL ESTABLISHMENTS"),
+    (4100, ENERGY, "LOCAL & SUBURBAN TRANSIT & INTERURBAN HWY PASSENGER TRANS"),
+    (4213, ENERGY, "TRUCKING (NO LOCAL)"),
+    (4231, ENERGY, "TERMINAL MAINTENANCE FACILITIES FOR MOTOR FREIGHT TRANSPORT"),
+    (4400, ENERGY, "WATER TRANSPORTATION"),
+    (4412, ENERGY, "DEEP SEA FOREIGN TRANSPORTATION OF FREIGHT"),
+    (4512, ENERGY, "AIR TRANSPORTATION, SCHEDULED"),
+    (4513, ENERGY, "AIR COURIER SERVICES"),
+    (4522, ENERGY, "AIR TRANSPORTATION, NONSCHEDULED"),
+    (4581, ENERGY, "AIRPORTS, FLYING FIELDS & AIRPORT TERMINAL SERVICES"),
+    (4610, ENERGY, "PIPE LINES (NO NATURAL GAS)"),
+    (4700, ENERGY, "TRANSPORTATION SERVICES"),
+    (4731, ENERGY, "ARRANGEMENT OF TRANSPORTATION OF FREIGHT & CARGO"),
+    (4812, TECHNOLOGY, "RADIOTELEPHONE COMMUNICATIONS"),
+    (4813, TECHNOLOGY, "TELEPHONE COMMUNICATIONS (NO RADIOTELEPHONE)"),
+    (4822, TECHNOLOGY, "TELEGRAPH & OTHER MESSAGE COMMUNICATIONS"),
+    (4832, TECHNOLOGY, "RADIO BROADCASTING STATIONS"),
+    (4833, TECHNOLOGY, "TELEVISION BROADCASTING STATIONS"),
+    (4841, TECHNOLOGY, "CABLE & OTHER PAY TELEVISION SERVICES"),
+    (4899, TECHNOLOGY, "COMMUNICATIONS SERVICES, NEC"),
+    (4900, ENERGY, "ELECTRIC, GAS & SANITARY SERVICES"),
+    (4911, ENERGY, "ELECTRIC SERVICES"),
+    (4922, ENERGY, "NATURAL GAS TRANSMISSION"),
+    (4923, ENERGY, "NATURAL GAS TRANSMISSION & DISTRIBUTION"),
+    (4924, ENERGY, "NATURAL GAS DISTRIBUTION"),
+    (4931, ENERGY, "ELECTRIC & OTHER SERVICES COMBINED"),
+    (4932, ENERGY, "GAS & OTHER SERVICES COMBINED"),
+    (4940, ENERGY, "WATER SUPPLY"),
+    (4941, ENERGY, "WATER SUPPLY"),
+    (4950, ENERGY, "SANITARY SERVICES"),
+    (4953, ENERGY, "REFUSE SYSTEMS"),
+    (4955, ENERGY, "HAZARDOUS WASTE MANAGEMENT"),
+    (4961, ENERGY, "STEAM & AIR-CONDITIONING SUPPLY"),
+    (4991, ENERGY, "COGENERATION SERVICES & SMALL POWER PRODUCERS"),
+    (5000, TRADE, "WHOLESALE-DURABLE GOODS"),
+    (5010, TRADE, "WHOLESALE-MOTOR VEHICLES & MOTOR VEHICLE PARTS & SUPPLIES"),
+    (5013, TRADE, "WHOLESALE-MOTOR VEHICLE SUPPLIES & NEW PARTS"),
+    (5020, TRADE, "WHOLESALE-FURNITURE & HOME FURNISHINGS"),
+    (5030, TRADE, "WHOLESALE-LUMBER & OTHER CONSTRUCTION MATERIALS"),
+    (5031, TRADE, "WHOLESALE-LUMBER, PLYWOOD, MILLWORK & WOOD PANELS"),
+    (5040, TRADE, "WHOLESALE-PROFESSIONAL & COMMERCIAL EQUIPMENT & SUPPLIES"),
+    (5045, TRADE, "WHOLESALE-COMPUTERS & PERIPHERAL EQUIPMENT & SOFTWARE"),
+    (5047, TRADE, "WHOLESALE-MEDICAL, DENTAL & HOSPITAL EQUIPMENT & SUPPLIES"),
+    (5050, TRADE, "WHOLESALE-METALS SERVICE CENTERS & OFFICES"),
+    (5051, TRADE, "WHOLESALE-METALS SERVICE CENTERS & OFFICES"),
+    (5063, TRADE, "WHOLESALE-ELECTRICAL APPARATUS & EQUIPMENT, WIRING SUPPLIES"),
+    (5064, TRADE, "WHOLESALE-ELECTRICAL APPLIANCES, TV & RADIO SETS"),
+    (5065, TRADE, "WHOLESALE-ELECTRONIC PARTS & EQUIPMENT, NEC"),
+    (5070, TRADE, "WHOLESALE-HARDWARE & PLUMBING & HEATING EQUIPMENT & SUPPLIES"),
+    (5072, TRADE, "WHOLESALE-HARDWARE"),
+    (5080, TRADE, "WHOLESALE-MACHINERY, EQUIPMENT & SUPPLIES"),
+    (5082, TRADE, "WHOLESALE-CONSTRUCTION & MINING (NO PETRO) MACHINERY & EQUIP"),
+    (5084, TRADE, "WHOLESALE-INDUSTRIAL MACHINERY & EQUIPMENT"),
+    (5090, TRADE, "WHOLESALE-MISC DURABLE GOODS"),
+    (5094, TRADE, "WHOLESALE-JEWELRY, WATCHES, PRECIOUS STONES & METALS"),
+    (5099, TRADE, "WHOLESALE-DURABLE GOODS, NEC"),
+    (5110, TRADE, "WHOLESALE-PAPER AND PAPER PRODUCTS"),
+    (5122, LIFE_SCIENCES, "WHOLESALE-DRUGS, PROPRIETARIES & DRUGGISTS' SUNDRIES"),
+    (5130, TRADE, "WHOLESALE-APPAREL, PIECE GOODS & NOTIONS"),
+    (5140, TRADE, "WHOLESALE-GROCERIES & RELATED PRODUCTS"),
+    (5141, TRADE, "WHOLESALE-GROCERIES, GENERAL LINE"),
+    (5150, TRADE, "WHOLESALE-FARM PRODUCT RAW MATERIALS"),
+    (5160, TRADE, "WHOLESALE-CHEMICALS & ALLIED PRODUCTS"),
+    (5171, ENERGY, "WHOLESALE-PETROLEUM BULK STATIONS & TERMINALS"),
+    (5172, ENERGY, "WHOLESALE-PETROLEUM & PETROLEUM PRODUCTS (NO BULK STATIONS)"),
+    (5180, TRADE, "WHOLESALE-BEER, WINE & DISTILLED ALCOHOLIC BEVERAGES"),
+    (5190, TRADE, "WHOLESALE-MISCELLANEOUS NONDURABLE GOODS"),
+    (5200, TRADE, "RETAIL-BUILDING MATERIALS, HARDWARE, GARDEN SUPPLY"),
+    (5211, TRADE, "RETAIL-LUMBER & OTHER BUILDING MATERIALS DEALERS"),
+    (5271, TRADE, "RETAIL-MOBILE HOME DEALERS"),
+    (5311, TRADE, "RETAIL-DEPARTMENT STORES"),
+    (5331, TRADE, "RETAIL-VARIETY STORES"),
+    (5399, TRADE, "RETAIL-MISC GENERAL MERCHANDISE STORES"),
+    (5400, TRADE, "RETAIL-FOOD STORES"),
+    (5411, TRADE, "RETAIL-GROCERY STORES"),
+    (5412, TRADE, "RETAIL-CONVENIENCE STORES"),
+    (5500, TRADE, "RETAIL-AUTO DEALERS & GASOLINE STATIONS"),
+    (5531, TRADE, "RETAIL-AUTO & HOME SUPPLY STORES"),
+    (5600, TRADE, "RETAIL-APPAREL & ACCESSORY STORES"),
+    (5621, TRADE, "RETAIL-WOMEN'S CLOTHING STORES"),
+    (5651, TRADE, "RETAIL-FAMILY CLOTHING STORES"),
+    (5661, TRADE, "RETAIL-SHOE STORES"),
+    (5700, TRADE, "RETAIL-HOME FURNITURE, FURNISHINGS & EQUIPMENT STORES"),
+    (5712, TRADE, "RETAIL-FURNITURE STORES"),
+    (5731, TRADE, "RETAIL-RADIO, TV & CONSUMER ELECTRONICS STORES"),
+    (5734, TRADE, "RETAIL-COMPUTER & COMPUTER SOFTWARE STORES"),
+    (5735, TRADE, "RETAIL-RECORD & PRERECORDED TAPE STORES"),
+    (5810, TRADE, "RETAIL-EATING & DRINKING PLACES"),
+    (5812, TRADE, "RETAIL-EATING PLACES"),
+    (5900, TRADE, "RETAIL-MISCELLANEOUS RETAIL"),
+    (5912, TRADE, "RETAIL-DRUG STORES AND PROPRIETARY STORES"),
+    (5940, TRADE, "RETAIL-MISCELLANEOUS SHOPPING GOODS STORES"),
+    (5944, TRADE, "RETAIL-JEWELRY STORES"),
+    (5945, TRADE, "RETAIL-HOBBY, TOY & GAME SHOPS"),
+    (5960, TRADE, "RETAIL-NONSTORE RETAILERS"),
+    (5961, TRADE, "RETAIL-CATALOG & MAIL-ORDER HOUSES"),
+    (5990, TRADE, "RETAIL-RETAIL STORES, NEC"),
+    (6021, FINANCE, "NATIONAL COMMERCIAL BANKS"),
+    (6022, FINANCE, "STATE COMMERCIAL BANKS"),
+    (6029, FINANCE, "COMMERCIAL BANKS, NEC"),
+    (6035, FINANCE, "SAVINGS INSTITUTION, FEDERALLY CHARTERED"),
+    (6036, FINANCE, "SAVINGS INSTITUTIONS, NOT FEDERALLY CHARTERED"),
+    (6099, FINANCE, "FUNCTIONS RELATED TO DEPOSITORY BANKING, NEC"),
+    (6111, FINANCE, "FEDERAL & FEDERALLY-SPONSORED CREDIT AGENCIES"),
+    (6141, FINANCE, "PERSONAL CREDIT INSTITUTIONS"),
+    (6153, FINANCE, "SHORT-TERM BUSINESS CREDIT INSTITUTIONS"),
+    (6159, FINANCE, "MISCELLANEOUS BUSINESS CREDIT INSTITUTION"),
+    (6162, FINANCE, "MORTGAGE BANKERS & LOAN CORRESPONDENTS"),
+    (6163, FINANCE, "LOAN BROKERS"),
+    (6172, FINANCE, "FINANCE LESSORS"),
+    (6189, STRUCTURED, "ASSET-BACKED SECURITIES"),
+    (6199, FINANCE, "FINANCE SERVICES"),
+    (6200, FINANCE, "SECURITY & COMMODITY BROKERS, DEALERS, EXCHANGES & SERVICES"),
+    (6211, FINANCE, "SECURITY BROKERS, DEALERS & FLOTATION COMPANIES"),
+    (6221, FINANCE, "COMMODITY CONTRACTS BROKERS & DEALERS"),
+    (6282, FINANCE, "INVESTMENT ADVICE"),
+    (6311, FINANCE, "LIFE INSURANCE"),
+    (6321, FINANCE, "ACCIDENT & HEALTH INSURANCE"),
+    (6324, FINANCE, "HOSPITAL & MEDICAL SERVICE PLANS"),
+    (6331, FINANCE, "FIRE, MARINE & CASUALTY INSURANCE"),
+    (6351, FINANCE, "SURETY INSURANCE"),
+    (6361, FINANCE, "TITLE INSURANCE"),
+    (6399, FINANCE, "INSURANCE CARRIERS, NEC"),
+    (6411, FINANCE, "INSURANCE AGENTS, BROKERS & SERVICE"),
+    (6500, REAL_ESTATE, "REAL ESTATE"),
+    (6510, REAL_ESTATE, "REAL ESTATE OPERATORS (NO DEVELOPERS) & LESSORS"),
+    (6512, REAL_ESTATE, "OPERATORS OF NONRESIDENTIAL BUILDINGS"),
+    (6513, REAL_ESTATE, "OPERATORS OF APARTMENT BUILDINGS"),
+    (6519, REAL_ESTATE, "LESSORS OF REAL PROPERTY, NEC"),
+    (6531, REAL_ESTATE, "REAL ESTATE AGENTS & MANAGERS (FOR OTHERS)"),
+    (6532, REAL_ESTATE, "REAL ESTATE DEALERS (FOR THEIR OWN ACCOUNT)"),
+    (6552, REAL_ESTATE, "LAND SUBDIVIDERS & DEVELOPERS (NO CEMETERIES)"),
+    (6770, REAL_ESTATE, "BLANK CHECKS"),
+    (6792, ENERGY, "OIL ROYALTY TRADERS"),
+    (6794, TRADE, "PATENT OWNERS & LESSORS"),
+    (6795, ENERGY, "MINERAL ROYALTY TRADERS"),
+    (6798, REAL_ESTATE, "REAL ESTATE INVESTMENT TRUSTS"),
+    (6799, FINANCE, "INVESTORS, NEC"),
+    (7000, REAL_ESTATE, "HOTELS, ROOMING HOUSES, CAMPS & OTHER LODGING PLACES"),
+    (7011, REAL_ESTATE, "HOTELS & MOTELS"),
+    (7200, TRADE, "SERVICES-PERSONAL SERVICES"),
+    (7310, TRADE, "SERVICES-ADVERTISING"),
+    (7311, TRADE, "SERVICES-ADVERTISING AGENCIES"),
+    (7320, TRADE, "SERVICES-CONSUMER CREDIT REPORTING, COLLECTION AGENCIES"),
+    (7330, TRADE, "SERVICES-MAILING, REPRODUCTION, COMMERCIAL ART & PHOTOGRAPHY"),
+    (7331, TRADE, "SERVICES-DIRECT MAIL ADVERTISING SERVICES"),
+    (7340, TRADE, "SERVICES-TO DWELLINGS & OTHER BUILDINGS"),
+    (7350, TRADE, "SERVICES-MISCELLANEOUS EQUIPMENT RENTAL & LEASING"),
+    (7359, TRADE, "SERVICES-EQUIPMENT RENTAL & LEASING, NEC"),
+    (7361, TRADE, "SERVICES-EMPLOYMENT AGENCIES"),
+    (7363, TRADE, "SERVICES-HELP SUPPLY SERVICES"),
+    (7370, TECHNOLOGY, "SERVICES-COMPUTER PROGRAMMING, DATA PROCESSING, ETC."),
+    (7371, TECHNOLOGY, "SERVICES-COMPUTER PROGRAMMING SERVICES"),
+    (7372, TECHNOLOGY, "SERVICES-PREPACKAGED SOFTWARE"),
+    (7373, TECHNOLOGY, "SERVICES-COMPUTER INTEGRATED SYSTEMS DESIGN"),
+    (7374, TECHNOLOGY, "SERVICES-COMPUTER PROCESSING & DATA PREPARATION"),
+    (7377, TECHNOLOGY, "SERVICES-COMPUTER RENTAL & LEASING"),
+    (7380, TRADE, "SERVICES-MISCELLANEOUS BUSINESS SERVICES"),
+    (7381, TRADE, "SERVICES-DETECTIVE, GUARD & ARMORED CAR SERVICES"),
+    (7384, TRADE, "SERVICES-PHOTOFINISHING LABORATORIES"),
+    (7385, TECHNOLOGY, "SERVICES-TELEPHONE INTERCONNECT SYSTEMS"),
+    (7389, TRADE, "SERVICES-BUSINESS SERVICES, NEC"),
+    (7500, TRADE, "SERVICES-AUTOMOTIVE REPAIR, SERVICES & PARKING"),
+    (7510, TRADE, "SERVICES-AUTO RENTAL & LEASING (NO DRIVERS)"),
+    (7600, TRADE, "SERVICES-MISCELLANEOUS REPAIR SERVICES"),
+    (7812, TRADE, "SERVICES-MOTION PICTURE & VIDEO TAPE PRODUCTION"),
+    (7819, TRADE, "SERVICES-ALLIED TO MOTION PICTURE PRODUCTION"),
+    (7822, TRADE, "SERVICES-MOTION PICTURE & VIDEO TAPE DISTRIBUTION"),
+    (7829, TRADE, "SERVICES-ALLIED TO MOTION PICTURE DISTRIBUTION"),
+    (7830, TRADE, "SERVICES-MOTION PICTURE THEATERS"),
+    (7841, TRADE, "SERVICES-VIDEO TAPE RENTAL"),
+    (7900, TRADE, "SERVICES-AMUSEMENT & RECREATION SERVICES"),
+    (7948, TRADE, "SERVICES-RACING, INCLUDING TRACK OPERATION"),
+    (7990, TRADE, "SERVICES-MISCELLANEOUS AMUSEMENT & RECREATION"),
+    (7995, TRADE, "SERVICES-GAMBLING"),
+    (7997, TRADE, "SERVICES-MEMBERSHIP SPORTS & RECREATION CLUBS"),
+    (8000, LIFE_SCIENCES, "SERVICES-HEALTH SERVICES"),
+    (8011, LIFE_SCIENCES, "SERVICES-OFFICES & CLINICS OF DOCTORS OF MEDICINE"),
+    (8050, LIFE_SCIENCES, "SERVICES-NURSING & PERSONAL CARE FACILITIES"),
+    (8051, LIFE_SCIENCES, "SERVICES-SKILLED NURSING CARE FACILITIES"),
+    (8060, LIFE_SCIENCES, "SERVICES-HOSPITALS"),
+    (8062, LIFE_SCIENCES, "SERVICES-GENERAL MEDICAL & SURGICAL HOSPITALS, NEC"),
+    (8071, LIFE_SCIENCES, "SERVICES-MEDICAL LABORATORIES"),
+    (8082, LIFE_SCIENCES, "SERVICES-HOME HEALTH CARE SERVICES"),
+    (8090, LIFE_SCIENCES, "SERVICES-MISC HEALTH & ALLIED SERVICES, NEC"),
+    (8093, LIFE_SCIENCES, "SERVICES-SPECIALTY OUTPATIENT FACILITIES, NEC"),
+    (8111, TRADE, "SERVICES-LEGAL SERVICES"),
+    (8200, TRADE, "SERVICES-EDUCATIONAL SERVICES"),
+    (8300, TRADE, "SERVICES-SOCIAL SERVICES"),
+    (8351, TRADE, "SERVICES-CHILD DAY CARE SERVICES"),
+    (8600, TRADE, "SERVICES-MEMBERSHIP ORGANIZATIONS"),
+    (8700, TRADE, "SERVICES-ENGINEERING, ACCOUNTING, RESEARCH, MANAGEMENT"),
+    (8711, TRADE, "SERVICES-ENGINEERING SERVICES"),
+    (8731, LIFE_SCIENCES, "SERVICES-COMMERCIAL PHYSICAL & BIOLOGICAL RESEARCH"),
+    (8734, TRADE, "SERVICES-TESTING LABORATORIES"),
+    (8741, TRADE, "SERVICES-MANAGEMENT SERVICES"),
+    (8742, TRADE, "SERVICES-MANAGEMENT CONSULTING SERVICES"),
+    (8744, TRADE, "SERVICES-FACILITIES SUPPORT MANAGEMENT SERVICES"),
+    (8880, INTERNATIONAL, "AMERICAN DEPOSITARY RECEIPTS"),
+    (8888, INTERNATIONAL, "FOREIGN GOVERNMENTS"),
+    (8900, TRADE, "SERVICES-SERVICES, NEC"),
+    (9721, INTERNATIONAL, "INTERNATIONAL AFFAIRS"),
+    (9995, REAL_ESTATE, "NON-OPERATING ESTABLISHMENTS"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(SIC_CODES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_parse_and_lookup() {
+        let sic: SicCode = "0100".parse().unwrap();
+        assert_eq!(sic, " 100 ".parse().unwrap());
+        assert_eq!(sic.to_string(), "0100");
+        assert_eq!(sic.industry_group(), Some(IndustryGroup::Agriculture));
+
+        let blank_check = SicCode::new(6770);
+        assert_eq!(blank_check.description(), Some("BLANK CHECKS"));
+        assert_eq!(blank_check.industry_group(), Some(IndustryGroup::Finance));
+
+        let unlisted = SicCode::new(3999);
+        assert!(!unlisted.is_known());
+        assert_eq!(unlisted.office(), None);
+        assert_eq!(
+            unlisted.industry_group(),
+            Some(IndustryGroup::Manufacturing)
+        );
+
+        assert!("35711".parse::<SicCode>().is_err());
+        assert!("".parse::<SicCode>().is_err());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let codes: Vec<SicCode> = serde_json::from_str(r#"["2834", 100]"#).unwrap();
+        assert_eq!(codes, vec![SicCode::new(2834), SicCode::new(100)]);
+        assert_eq!(serde_json::to_string(&codes).unwrap(), r#"["2834","0100"]"#);
+        assert!(serde_json::from_str::<SicCode>("12345").is_err());
+    }
+}
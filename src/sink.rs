@@ -0,0 +1,263 @@
+//! Bounded, backpressure-aware delivery of records to consumers.
+//!
+//! Long-running producers (bulk downloads, index walks, paginated searches) can generate
+//! records much faster than a consumer writes them to a database. Buffering everything in an
+//! unbounded queue hides that mismatch until the process runs out of memory.
+//!
+//! A [`Sink`] is the destination a producer pushes into. [`bounded_sink`] creates the standard
+//! one: a fixed-capacity channel whose `send` waits while the buffer is full, so a slow
+//! consumer slows the producer down instead of growing the queue. Both ends expose
+//! [`SinkMetrics`] describing how far behind the consumer is and how long the producer spent
+//! blocked.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edgarkit::{Sink, bounded_sink};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> edgarkit::Result<()> {
+//! let (mut tx, mut rx) = bounded_sink::<u32>(16);
+//!
+//! let producer = tokio::spawn(async move {
+//!     for i in 0..100 {
+//!         tx.send(i).await?;
+//!     }
+//!     Ok::<_, edgarkit::EdgarError>(())
+//! });
+//!
+//! let mut total = 0;
+//! while let Some(item) = rx.recv().await {
+//!     total += item;
+//! }
+//! producer.await.unwrap()?;
+//!
+//! assert_eq!(total, 4950);
+//! assert!(rx.metrics().high_water <= 16);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::rt::Instant;
+use crate::error::{EdgarError, Result};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// A destination for records produced by edgarkit.
+///
+/// Implementations may wait inside `send` to apply backpressure. Returning an error tells the
+/// producer to stop.
+#[async_trait]
+pub trait Sink<T: Send>: Send {
+    /// Delivers one record, waiting if the sink is at capacity.
+    async fn send(&mut self, item: T) -> Result<()>;
+}
+
+/// Collects every record in memory. Never applies backpressure.
+#[async_trait]
+impl<T: Send> Sink<T> for Vec<T> {
+    async fn send(&mut self, item: T) -> Result<()> {
+        self.push(item);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Send> Sink<T> for mpsc::Sender<T> {
+    async fn send(&mut self, item: T) -> Result<()> {
+        mpsc::Sender::send(self, item)
+            .await
+            .map_err(|_| EdgarError::SinkClosed)
+    }
+}
+
+/// Point-in-time statistics for a [`bounded_sink`] channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SinkMetrics {
+    /// Maximum number of buffered records.
+    pub capacity: usize,
+
+    /// Records accepted by the sender so far.
+    pub sent: u64,
+
+    /// Records handed to the consumer so far.
+    pub received: u64,
+
+    /// Records currently buffered (sent but not yet received).
+    pub lag: u64,
+
+    /// Largest number of records ever buffered at once.
+    pub high_water: usize,
+
+    /// Total time senders spent waiting for buffer space.
+    pub blocked: Duration,
+
+    /// Longest time a record sat in the buffer before being received.
+    pub max_latency: Duration,
+}
+
+#[derive(Debug)]
+struct Counters {
+    capacity: usize,
+    sent: AtomicU64,
+    received: AtomicU64,
+    high_water: AtomicUsize,
+    blocked_nanos: AtomicU64,
+    max_latency_nanos: AtomicU64,
+}
+
+impl Counters {
+    fn snapshot(&self) -> SinkMetrics {
+        let sent = self.sent.load(Ordering::Relaxed);
+        let received = self.received.load(Ordering::Relaxed);
+        SinkMetrics {
+            capacity: self.capacity,
+            sent,
+            received,
+            lag: sent.saturating_sub(received),
+            high_water: self.high_water.load(Ordering::Relaxed),
+            blocked: Duration::from_nanos(self.blocked_nanos.load(Ordering::Relaxed)),
+            max_latency: Duration::from_nanos(self.max_latency_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+/// Sending half of a [`bounded_sink`]. Cloning creates another producer for the same channel.
+#[derive(Debug)]
+pub struct SinkSender<T> {
+    tx: mpsc::Sender<(Instant, T)>,
+    counters: Arc<Counters>,
+}
+
+impl<T> Clone for SinkSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+            counters: self.counters.clone(),
+        }
+    }
+}
+
+/// Receiving half of a [`bounded_sink`].
+#[derive(Debug)]
+pub struct SinkReceiver<T> {
+    rx: mpsc::Receiver<(Instant, T)>,
+    counters: Arc<Counters>,
+}
+
+/// Creates a bounded sink holding at most `capacity` records (minimum 1).
+pub fn bounded_sink<T>(capacity: usize) -> (SinkSender<T>, SinkReceiver<T>) {
+    let capacity = capacity.max(1);
+    let (tx, rx) = mpsc::channel(capacity);
+    let counters = Arc::new(Counters {
+        capacity,
+        sent: AtomicU64::new(0),
+        received: AtomicU64::new(0),
+        high_water: AtomicUsize::new(0),
+        blocked_nanos: AtomicU64::new(0),
+        max_latency_nanos: AtomicU64::new(0),
+    });
+    (
+        SinkSender {
+            tx,
+            counters: counters.clone(),
+        },
+        SinkReceiver { rx, counters },
+    )
+}
+
+impl<T> SinkSender<T> {
+    /// Current channel statistics.
+    pub fn metrics(&self) -> SinkMetrics {
+        self.counters.snapshot()
+    }
+
+    async fn push(&self, item: T) -> Result<()> {
+        let permit = match self.tx.try_reserve() {
+            Ok(permit) => permit,
+            Err(mpsc::error::TrySendError::Full(())) => {
+                let waiting = Instant::now();
+                let permit = self
+                    .tx
+                    .reserve()
+                    .await
+                    .map_err(|_| EdgarError::SinkClosed)?;
+                self.counters
+                    .blocked_nanos
+                    .fetch_add(waiting.elapsed().as_nanos() as u64, Ordering::Relaxed);
+                permit
+            }
+            Err(mpsc::error::TrySendError::Closed(())) => return Err(EdgarError::SinkClosed),
+        };
+
+        permit.send((Instant::now(), item));
+        self.counters.sent.fetch_add(1, Ordering::Relaxed);
+        let buffered = self.counters.capacity - self.tx.capacity();
+        self.counters
+            .high_water
+            .fetch_max(buffered, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Send> Sink<T> for SinkSender<T> {
+    async fn send(&mut self, item: T) -> Result<()> {
+        self.push(item).await
+    }
+}
+
+impl<T> SinkReceiver<T> {
+    /// Receives the next record, or `None` once every sender has been dropped and the
+    /// buffer is drained.
+    pub async fn recv(&mut self) -> Option<T> {
+        let (queued_at, item) = self.rx.recv().await?;
+        self.counters.received.fetch_add(1, Ordering::Relaxed);
+        self.counters
+            .max_latency_nanos
+            .fetch_max(queued_at.elapsed().as_nanos() as u64, Ordering::Relaxed);
+        Some(item)
+    }
+
+    /// Current channel statistics.
+    pub fn metrics(&self) -> SinkMetrics {
+        self.counters.snapshot()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_full_sink_blocks_producer() {
+        let (mut tx, mut rx) = bounded_sink::<u32>(2);
+        tx.send(1).await.unwrap();
+        tx.send(2).await.unwrap();
+
+        let blocked = tokio::time::timeout(Duration::from_millis(20), tx.send(3)).await;
+        assert!(
+            blocked.is_err(),
+            "send should wait while the buffer is full"
+        );
+
+        assert_eq!(rx.recv().await, Some(1));
+        tx.send(3).await.unwrap();
+
+        let metrics = rx.metrics();
+        assert_eq!(metrics.sent, 3);
+        assert_eq!(metrics.received, 1);
+        assert_eq!(metrics.lag, 2);
+        assert_eq!(metrics.high_water, 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_after_receiver_dropped() {
+        let (mut tx, rx) = bounded_sink::<u32>(1);
+        drop(rx);
+        assert!(matches!(tx.send(1).await, Err(EdgarError::SinkClosed)));
+    }
+}
@@ -0,0 +1,514 @@
+//! SQLite store for filing metadata.
+//!
+//! Applications that keep their own copy of EDGAR metadata tend to build the same handful of
+//! tables: companies, their filings, index entries, and XBRL facts. [`Store`] creates that
+//! schema in an SQLite database and upserts the crate's own types into it, keyed so that
+//! writing the same data twice changes nothing.
+//!
+//! The `sync_*` methods fetch and store in one step and report how many rows are new, so a
+//! scheduled job can run them repeatedly and pick up only what changed since the last run.
+//! Daily index syncs remember the last day they stored in a `sync_cursors` table.
+//!
+//! | Table             | Key                                                            |
+//! |-------------------|----------------------------------------------------------------|
+//! | `companies`       | `cik`                                                          |
+//! | `company_filings` | `accession_number`                                             |
+//! | `index_entries`   | `cik`, `url`                                                   |
+//! | `facts`           | `cik`, `taxonomy`, `concept`, `unit`, `start`, `end`, `accn`   |
+//! | `sync_cursors`    | `name`                                                         |
+//!
+//! Table names do not overlap with [`SqliteSink`](crate::SqliteSink) or
+//! [`SeenSet::open_sqlite`](crate::SeenSet::open_sqlite), so all three can share one file.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{Edgar, EdgarDay, Store};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let edgar = Edgar::new("MyApp contact@example.com")?;
+//! let mut store = Store::open("edgar.db")?;
+//!
+//! let new_filings = store.sync_company(&edgar, "AAPL").await?;
+//! let new_facts = store.sync_company_facts(&edgar, "AAPL").await?;
+//! let new_entries = store
+//!     .sync_daily_index(&edgar, EdgarDay::new(2024, 1, 2)?, EdgarDay::new(2024, 3, 28)?)
+//!     .await?;
+//! println!("{} filings, {} facts, {} index entries", new_filings, new_facts, new_entries);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::Edgar;
+use super::company::{CompanyFacts, FactValue};
+use super::entity::EntityId;
+use super::error::{EdgarError, Result};
+use super::filings::{DetailedFiling, Submission};
+use super::index::EdgarDay;
+use super::traits::{CompanyOperations, FilingOperations, IndexOperations};
+use crate::parsing::index::IndexEntry;
+use chrono::{Datelike, NaiveDate};
+use rusqlite::types::Value;
+use rusqlite::{Connection, OptionalExtension, params};
+use std::path::Path;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS companies (
+    cik INTEGER PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    entity_type TEXT NOT NULL,
+    sic TEXT NOT NULL,
+    sic_description TEXT NOT NULL,
+    state_of_incorporation TEXT,
+    fiscal_year_end TEXT,
+    tickers TEXT NOT NULL,
+    exchanges TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS company_filings (
+    accession_number TEXT PRIMARY KEY NOT NULL,
+    cik INTEGER NOT NULL,
+    form TEXT NOT NULL,
+    filing_date TEXT NOT NULL,
+    report_date TEXT,
+    acceptance_date_time TEXT NOT NULL,
+    act TEXT,
+    file_number TEXT,
+    film_number TEXT,
+    items TEXT,
+    size INTEGER NOT NULL,
+    is_xbrl INTEGER NOT NULL,
+    is_inline_xbrl INTEGER NOT NULL,
+    primary_document TEXT,
+    primary_doc_description TEXT
+);
+CREATE INDEX IF NOT EXISTS company_filings_cik ON company_filings (cik, filing_date);
+CREATE TABLE IF NOT EXISTS index_entries (
+    cik INTEGER NOT NULL,
+    url TEXT NOT NULL,
+    accession_number TEXT,
+    company_name TEXT NOT NULL,
+    form_type TEXT NOT NULL,
+    date_filed TEXT NOT NULL,
+    PRIMARY KEY (cik, url)
+) WITHOUT ROWID;
+CREATE INDEX IF NOT EXISTS index_entries_date ON index_entries (date_filed);
+CREATE TABLE IF NOT EXISTS facts (
+    cik INTEGER NOT NULL,
+    taxonomy TEXT NOT NULL,
+    concept TEXT NOT NULL,
+    unit TEXT NOT NULL,
+    start TEXT NOT NULL,
+    end TEXT NOT NULL,
+    accn TEXT NOT NULL,
+    value,
+    fy INTEGER,
+    fp TEXT,
+    form TEXT NOT NULL,
+    filed TEXT NOT NULL,
+    frame TEXT,
+    PRIMARY KEY (cik, taxonomy, concept, unit, start, end, accn)
+) WITHOUT ROWID;
+CREATE TABLE IF NOT EXISTS sync_cursors (
+    name TEXT PRIMARY KEY NOT NULL,
+    value TEXT NOT NULL
+);
+";
+
+/// Cursor name for [`Store::sync_daily_index`].
+const DAILY_INDEX_CURSOR: &str = "daily-index";
+
+/// An SQLite database of companies, filings, index entries, and facts.
+///
+/// Every `upsert_*` method runs in one transaction and returns the number of rows that
+/// were not in the store before; rows already present are updated in place.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Opens (or creates) the database at `path` and creates the tables if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::Database` if the database cannot be opened or initialized.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Uses an existing connection, creating the tables if needed.
+    pub fn from_connection(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// The underlying connection, for queries.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Stores a company's submission record and its recent filings.
+    ///
+    /// Returns the number of new filings.
+    pub fn upsert_submission(&mut self, submission: &Submission) -> Result<usize> {
+        let cik: u64 = submission.cik.trim().parse().map_err(|_| {
+            EdgarError::InvalidFormat(format!("submission CIK {:?}", submission.cik))
+        })?;
+        self.conn.execute(
+            "INSERT INTO companies
+                (cik, name, entity_type, sic, sic_description, state_of_incorporation,
+                 fiscal_year_end, tickers, exchanges)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+             ON CONFLICT (cik) DO UPDATE SET
+                name = excluded.name, entity_type = excluded.entity_type, sic = excluded.sic,
+                sic_description = excluded.sic_description,
+                state_of_incorporation = excluded.state_of_incorporation,
+                fiscal_year_end = excluded.fiscal_year_end, tickers = excluded.tickers,
+                exchanges = excluded.exchanges",
+            params![
+                cik as i64,
+                submission.name,
+                submission.entity_type,
+                submission.sic,
+                submission.sic_description,
+                submission.state_of_incorporation,
+                submission.fiscal_year_end,
+                submission.tickers.join(","),
+                submission
+                    .exchanges
+                    .iter()
+                    .map(|exchange| exchange.as_deref().unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ],
+        )?;
+
+        let recent = &submission.filings.recent;
+        let filings: Vec<DetailedFiling> = (0..recent.accession_number.len())
+            .filter_map(|idx| DetailedFiling::try_from((recent, idx)).ok())
+            .collect();
+        self.upsert_filings(cik, &filings)
+    }
+
+    /// Stores filings of the company `cik`. Returns the number of new filings.
+    pub fn upsert_filings(&mut self, cik: u64, filings: &[DetailedFiling]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+        {
+            let mut exists =
+                tx.prepare_cached("SELECT 1 FROM company_filings WHERE accession_number = ?1")?;
+            let mut upsert = tx.prepare_cached(
+                "INSERT OR REPLACE INTO company_filings
+                    (accession_number, cik, form, filing_date, report_date, acceptance_date_time,
+                     act, file_number, film_number, items, size, is_xbrl, is_inline_xbrl,
+                     primary_document, primary_doc_description)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+            )?;
+            for filing in filings {
+                if !exists.exists([&filing.accession_number])? {
+                    added += 1;
+                }
+                upsert.execute(params![
+                    filing.accession_number,
+                    cik as i64,
+                    filing.form,
+                    filing.filing_date.to_string(),
+                    filing.report_date.map(|date| date.to_string()),
+                    filing.acceptance_date_time.to_rfc3339(),
+                    filing.act,
+                    filing.file_number,
+                    filing.film_number,
+                    filing.items,
+                    filing.size as i64,
+                    filing.is_xbrl,
+                    filing.is_inline_xbrl,
+                    filing.primary_document,
+                    filing.primary_doc_description,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+
+    /// Stores index entries. Returns the number of new entries.
+    pub fn upsert_index_entries(&mut self, entries: &[IndexEntry]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+        {
+            let mut exists =
+                tx.prepare_cached("SELECT 1 FROM index_entries WHERE cik = ?1 AND url = ?2")?;
+            let mut upsert = tx.prepare_cached(
+                "INSERT OR REPLACE INTO index_entries
+                    (cik, url, accession_number, company_name, form_type, date_filed)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for entry in entries {
+                if !exists.exists(params![entry.cik as i64, entry.url])? {
+                    added += 1;
+                }
+                upsert.execute(params![
+                    entry.cik as i64,
+                    entry.url,
+                    entry.accession_number(),
+                    entry.company_name,
+                    entry.form_type,
+                    entry.date_filed.to_string(),
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+
+    /// Stores every data point of a company's facts. Returns the number of new data points.
+    pub fn upsert_company_facts(&mut self, facts: &CompanyFacts) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+        {
+            let mut exists = tx.prepare_cached(
+                "SELECT 1 FROM facts WHERE cik = ?1 AND taxonomy = ?2 AND concept = ?3
+                    AND unit = ?4 AND start = ?5 AND end = ?6 AND accn = ?7",
+            )?;
+            let mut upsert = tx.prepare_cached(
+                "INSERT OR REPLACE INTO facts
+                    (cik, taxonomy, concept, unit, start, end, accn, value, fy, fp, form, filed, frame)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            )?;
+            for (taxonomy, concept, unit, point) in facts.iter_facts() {
+                let start = point.start.as_deref().unwrap_or_default();
+                let key = params![
+                    facts.cik as i64,
+                    taxonomy,
+                    concept,
+                    unit,
+                    start,
+                    point.end,
+                    point.accn
+                ];
+                if !exists.exists(key)? {
+                    added += 1;
+                }
+                let value = match &point.val {
+                    FactValue::Integer(i) => Value::Integer(*i),
+                    FactValue::Decimal(d) => Value::Real(*d),
+                    FactValue::Text(t) => Value::Text(t.clone()),
+                };
+                upsert.execute(params![
+                    facts.cik as i64,
+                    taxonomy,
+                    concept,
+                    unit,
+                    start,
+                    point.end,
+                    point.accn,
+                    value,
+                    point.fy,
+                    point.fp,
+                    point.form,
+                    point.filed,
+                    point.frame,
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(added)
+    }
+
+    /// Value of a sync cursor, if it was ever set.
+    pub fn cursor(&self, name: &str) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM sync_cursors WHERE name = ?1",
+                [name],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Sets a sync cursor, for incremental jobs of your own.
+    pub fn set_cursor(&self, name: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_cursors (name, value) VALUES (?1, ?2)",
+            [name, value],
+        )?;
+        Ok(())
+    }
+
+    /// Fetches a company's submissions and stores the company and its recent filings.
+    ///
+    /// Returns the number of filings that were not stored before.
+    pub async fn sync_company(
+        &mut self,
+        edgar: &Edgar,
+        company: impl Into<EntityId> + Send,
+    ) -> Result<usize> {
+        let submission = edgar.submissions(company).await?;
+        self.upsert_submission(&submission)
+    }
+
+    /// Fetches a company's XBRL facts and stores every data point.
+    ///
+    /// Returns the number of data points that were not stored before.
+    pub async fn sync_company_facts(
+        &mut self,
+        edgar: &Edgar,
+        company: impl Into<EntityId> + Send,
+    ) -> Result<usize> {
+        let facts = edgar.company_facts(company).await?;
+        self.upsert_company_facts(&facts)
+    }
+
+    /// Stores the daily index entries from `from` through `to`, resuming after the last day
+    /// a previous sync stored.
+    ///
+    /// The cursor only advances to the latest day that had an index, so days the SEC has
+    /// not published yet are picked up by the next run. Returns the number of new entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EdgarError::InvalidDay` if `from` is after `to`, and request errors from
+    /// [`IndexOperations::get_filings_between`].
+    pub async fn sync_daily_index(
+        &mut self,
+        edgar: &Edgar,
+        from: EdgarDay,
+        to: EdgarDay,
+    ) -> Result<usize> {
+        let mut first = from.to_naive_date()?;
+        let last = to.to_naive_date()?;
+        if first > last {
+            return Err(EdgarError::InvalidDay);
+        }
+        if let Some(synced) = self
+            .cursor(DAILY_INDEX_CURSOR)?
+            .and_then(|day| NaiveDate::parse_from_str(&day, "%Y-%m-%d").ok())
+            .and_then(|day| day.succ_opt())
+        {
+            first = first.max(synced);
+        }
+        if first > last {
+            return Ok(0);
+        }
+
+        let start = EdgarDay::new(first.year(), first.month(), first.day())?;
+        let entries = edgar.get_filings_between(start, to, None).await?;
+        let added = self.upsert_index_entries(&entries)?;
+        if let Some(latest) = entries.iter().map(|entry| entry.date_filed).max() {
+            self.set_cursor(DAILY_INDEX_CURSOR, &latest.to_string())?;
+        }
+        Ok(added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> Store {
+        Store::from_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    fn entry(cik: u64, day: u32) -> IndexEntry {
+        IndexEntry {
+            company_name: "Apple Inc.".to_string(),
+            form_type: "8-K".to_string(),
+            cik,
+            date_filed: NaiveDate::from_ymd_opt(2024, 1, day).unwrap(),
+            date_filed_raw: format!("202401{:02}", day),
+            url: format!(
+                "https://www.sec.gov/Archives/edgar/data/{}/0000320193-24-0000{:02}.txt",
+                cik, day
+            ),
+        }
+    }
+
+    #[test]
+    fn test_upsert_index_entries_counts_new_rows() {
+        let mut store = store();
+        assert_eq!(
+            store
+                .upsert_index_entries(&[entry(320193, 2), entry(320193, 3)])
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            store
+                .upsert_index_entries(&[entry(320193, 3), entry(320193, 4)])
+                .unwrap(),
+            1
+        );
+        let accession: String = store
+            .connection()
+            .query_row(
+                "SELECT accession_number FROM index_entries WHERE date_filed = '2024-01-04'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(accession, "0000320193-24-000004");
+    }
+
+    #[test]
+    fn test_upsert_company_facts() {
+        let facts: CompanyFacts = serde_json::from_str(
+            r#"{"cik": 320193, "entityName": "Apple Inc.", "facts": {
+                "us-gaap": {"EarningsPerShareBasic": {"units": {"USD/shares": [
+                    {"start": "2022-09-25", "end": "2023-09-30", "val": 6.16, "accn": "0000320193-23-000106",
+                     "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                ]}}},
+                "dei": {"EntityCommonStockSharesOutstanding": {"units": {"shares": [
+                    {"end": "2023-10-20", "val": 15552752000, "accn": "0000320193-23-000106",
+                     "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"}
+                ]}}}
+            }}"#,
+        )
+        .unwrap();
+
+        let mut store = store();
+        assert_eq!(store.upsert_company_facts(&facts).unwrap(), 2);
+        assert_eq!(store.upsert_company_facts(&facts).unwrap(), 0);
+        let eps: f64 = store
+            .connection()
+            .query_row(
+                "SELECT value FROM facts WHERE concept = 'EarningsPerShareBasic'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(eps, 6.16);
+    }
+
+    #[test]
+    fn test_upsert_submission() {
+        let submission: Submission = serde_json::from_str(include_str!(
+            "../tests/fixtures/submissions/submission.json"
+        ))
+        .unwrap();
+
+        let mut store = store();
+        let added = store.upsert_submission(&submission).unwrap();
+        assert!(added > 0);
+        assert_eq!(store.upsert_submission(&submission).unwrap(), 0);
+        let (companies, filings): (i64, i64) = store
+            .connection()
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM companies), (SELECT COUNT(*) FROM company_filings)",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((companies, filings as usize), (1, added));
+    }
+
+    #[test]
+    fn test_cursors() {
+        let store = store();
+        assert_eq!(store.cursor(DAILY_INDEX_CURSOR).unwrap(), None);
+        store.set_cursor(DAILY_INDEX_CURSOR, "2024-01-05").unwrap();
+        assert_eq!(
+            store.cursor(DAILY_INDEX_CURSOR).unwrap().as_deref(),
+            Some("2024-01-05")
+        );
+    }
+}
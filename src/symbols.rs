@@ -0,0 +1,327 @@
+//! Ticker symbol normalization.
+//!
+//! Data vendors spell the same listing differently. Berkshire's class B shares show up as
+//! `BRK.B`, `BRK/B`, `BRK B`, `BRKB`, or `BRK-B` (the form SEC uses), and SPAC units, warrants,
+//! and rights carry suffixes that vary by venue: Nasdaq appends a fifth letter (`ACAHU`,
+//! `ACAHW`, `ACAHR`) while NYSE data often uses `.U`, `.WS`, or `-WT`. Looking any of these
+//! up verbatim in the SEC ticker file fails more often than it succeeds.
+//!
+//! [`Symbol::parse`] splits a raw symbol into its root, share class, and
+//! [`SecurityKind`], and [`SymbolConventions::candidates`] turns it into the ordered list
+//! of spellings worth trying. `company_cik` and [`Bootstrap`](crate::Bootstrap) lookups go
+//! through the conventions configured with
+//! [`EdgarConfig::with_symbol_conventions`](crate::EdgarConfig::with_symbol_conventions), so
+//! a watchlist exported from a broker resolves without hand-editing.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::{SecurityKind, Symbol, normalize_ticker};
+//!
+//! assert_eq!(normalize_ticker("brk.b"), "BRK-B");
+//!
+//! let warrant = Symbol::parse("ACAHW");
+//! assert_eq!(warrant.root, "ACAH");
+//! assert_eq!(warrant.kind, SecurityKind::Warrant);
+//! ```
+
+/// Suffixes that mark units, warrants, and rights when written after a separator.
+const DERIVATIVE_SUFFIXES: &[(&str, SecurityKind)] = &[
+    ("U", SecurityKind::Unit),
+    ("UN", SecurityKind::Unit),
+    ("UT", SecurityKind::Unit),
+    ("W", SecurityKind::Warrant),
+    ("WS", SecurityKind::Warrant),
+    ("WT", SecurityKind::Warrant),
+    ("R", SecurityKind::Right),
+    ("RT", SecurityKind::Right),
+];
+
+/// Separators accepted between a root symbol and its share class by default.
+const DEFAULT_CLASS_SEPARATORS: &str = ".-/ _";
+
+/// What a ticker symbol represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SecurityKind {
+    /// Common or preferred shares, including share classes such as `BRK-B`.
+    Shares,
+
+    /// A unit bundling shares and warrants or rights, typically issued by a SPAC.
+    Unit,
+
+    /// A warrant to buy shares of the root symbol.
+    Warrant,
+
+    /// A right to receive shares of the root symbol.
+    Right,
+}
+
+impl SecurityKind {
+    /// Suffixes tried for this kind, Nasdaq style first.
+    fn suffixes(self) -> &'static [&'static str] {
+        match self {
+            SecurityKind::Shares => &[],
+            SecurityKind::Unit => &["U", "UN"],
+            SecurityKind::Warrant => &["W", "WS", "WT"],
+            SecurityKind::Right => &["R", "RT"],
+        }
+    }
+}
+
+/// A ticker symbol split into its parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    /// The issuer's base symbol, such as `BRK` or `ACAH`.
+    pub root: String,
+
+    /// Share class letter(s), such as `B` for `BRK-B`.
+    pub class: Option<String>,
+
+    /// Whether the symbol is a share, unit, warrant, or right.
+    pub kind: SecurityKind,
+}
+
+impl Symbol {
+    /// Parses a symbol with the default [`SymbolConventions`].
+    pub fn parse(input: &str) -> Self {
+        SymbolConventions::default().parse(input)
+    }
+
+    /// The symbol in SEC form: upper case with `-` before the share class (`BRK-B`).
+    ///
+    /// Units, warrants, and rights keep the Nasdaq-style suffix (`ACAHW`).
+    pub fn canonical(&self) -> String {
+        let mut out = self.root.clone();
+        if let Some(class) = &self.class {
+            out.push('-');
+            out.push_str(class);
+        }
+        if let Some(suffix) = self.kind.suffixes().first() {
+            out.push_str(suffix);
+        }
+        out
+    }
+}
+
+/// How raw ticker symbols are interpreted and matched against SEC tickers.
+#[derive(Debug, Clone)]
+pub struct SymbolConventions {
+    class_separators: String,
+    nasdaq_suffixes: bool,
+    infer_class: bool,
+    resolve_to_issuer: bool,
+}
+
+impl Default for SymbolConventions {
+    fn default() -> Self {
+        Self {
+            class_separators: DEFAULT_CLASS_SEPARATORS.to_string(),
+            nasdaq_suffixes: true,
+            infer_class: true,
+            resolve_to_issuer: true,
+        }
+    }
+}
+
+impl SymbolConventions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Characters treated as a separator between root and share class (default `.-/ _`).
+    pub fn with_class_separators(mut self, separators: impl Into<String>) -> Self {
+        self.class_separators = separators.into();
+        self
+    }
+
+    /// Reads a fifth letter `U`, `W`, or `R` as a unit, warrant, or right (default: true).
+    ///
+    /// Nasdaq reserves those letters for derivatives, but five-letter symbols from other
+    /// venues or OTC markets can end in them legitimately.
+    pub fn with_nasdaq_suffixes(mut self, enabled: bool) -> Self {
+        self.nasdaq_suffixes = enabled;
+        self
+    }
+
+    /// Also tries the last letter of an unseparated symbol as a share class, so `BRKB`
+    /// matches `BRK-B` when `BRKB` itself is unknown (default: true).
+    pub fn with_class_inference(mut self, enabled: bool) -> Self {
+        self.infer_class = enabled;
+        self
+    }
+
+    /// Falls back to the root symbol for units, warrants, and rights that SEC does not
+    /// list, resolving them to the issuer's CIK (default: true).
+    pub fn with_issuer_fallback(mut self, enabled: bool) -> Self {
+        self.resolve_to_issuer = enabled;
+        self
+    }
+
+    /// Splits a raw symbol into root, share class, and kind.
+    pub fn parse(&self, input: &str) -> Symbol {
+        let upper = input.trim().to_uppercase();
+
+        // `+` (warrant) and `=` (unit) are common vendor shorthands with nothing after them.
+        if let Some(root) = upper.strip_suffix('+') {
+            return self.derivative(root, SecurityKind::Warrant);
+        }
+        if let Some(root) = upper.strip_suffix('=') {
+            return self.derivative(root, SecurityKind::Unit);
+        }
+        if let Some(root) = upper.strip_suffix('^') {
+            return self.derivative(root, SecurityKind::Right);
+        }
+
+        let parts: Vec<&str> = upper
+            .split(|c: char| self.class_separators.contains(c))
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        match parts.as_slice() {
+            [] => Symbol {
+                root: String::new(),
+                class: None,
+                kind: SecurityKind::Shares,
+            },
+            [single] => self.unseparated(single),
+            [root, rest @ ..] => {
+                let mut class = None;
+                let mut kind = SecurityKind::Shares;
+                for part in rest {
+                    match DERIVATIVE_SUFFIXES.iter().find(|(s, _)| s == part) {
+                        Some((_, k)) => kind = *k,
+                        None => class = Some(part.to_string()),
+                    }
+                }
+                Symbol {
+                    root: root.to_string(),
+                    class,
+                    kind,
+                }
+            }
+        }
+    }
+
+    fn derivative(&self, root: &str, kind: SecurityKind) -> Symbol {
+        Symbol {
+            kind,
+            ..self.parse(root)
+        }
+    }
+
+    fn unseparated(&self, symbol: &str) -> Symbol {
+        let kind = match symbol.chars().last() {
+            Some('U') => SecurityKind::Unit,
+            Some('W') => SecurityKind::Warrant,
+            Some('R') => SecurityKind::Right,
+            _ => SecurityKind::Shares,
+        };
+        if self.nasdaq_suffixes
+            && symbol.is_ascii()
+            && symbol.len() == 5
+            && kind != SecurityKind::Shares
+        {
+            return Symbol {
+                root: symbol[..4].to_string(),
+                class: None,
+                kind,
+            };
+        }
+        Symbol {
+            root: symbol.to_string(),
+            class: None,
+            kind: SecurityKind::Shares,
+        }
+    }
+
+    /// The symbol in SEC form; see [`Symbol::canonical`].
+    pub fn normalize(&self, input: &str) -> String {
+        self.parse(input).canonical()
+    }
+
+    /// Spellings to look up, most specific first.
+    ///
+    /// The list starts with the upper-cased input and its SEC form, then the other suffix
+    /// spellings for units, warrants, and rights, an inferred share class for unseparated
+    /// symbols, and finally the issuer's root symbol when that fallback is enabled.
+    pub fn candidates(&self, input: &str) -> Vec<String> {
+        let upper = input.trim().to_uppercase();
+        let symbol = self.parse(&upper);
+        let base = match &symbol.class {
+            Some(class) => format!("{}-{}", symbol.root, class),
+            None => symbol.root.clone(),
+        };
+
+        let mut out = vec![upper.clone(), symbol.canonical()];
+        for suffix in symbol.kind.suffixes() {
+            out.push(format!("{}{}", base, suffix));
+            out.push(format!("{}-{}", base, suffix));
+        }
+        if self.infer_class
+            && symbol.kind == SecurityKind::Shares
+            && symbol.class.is_none()
+            && symbol.root.is_ascii()
+            && (3..=5).contains(&symbol.root.len())
+        {
+            let (root, class) = symbol.root.split_at(symbol.root.len() - 1);
+            out.push(format!("{}-{}", root, class));
+        }
+        if self.resolve_to_issuer && symbol.kind != SecurityKind::Shares {
+            out.push(base);
+            if symbol.class.is_some() {
+                out.push(symbol.root.clone());
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        out.retain(|s| !s.is_empty() && seen.insert(s.clone()));
+        out
+    }
+}
+
+/// Normalizes a ticker to SEC form (`brk.b` becomes `BRK-B`) with the default conventions.
+pub fn normalize_ticker(input: &str) -> String {
+    SymbolConventions::default().normalize(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_class_shares() {
+        for input in ["BRK.B", "brk-b", "BRK/B", "BRK B", " BRK_B "] {
+            assert_eq!(normalize_ticker(input), "BRK-B", "{}", input);
+        }
+        let candidates = SymbolConventions::default().candidates("BRKB");
+        assert_eq!(candidates, vec!["BRKB", "BRK-B"]);
+
+        let strict = SymbolConventions::default().with_class_inference(false);
+        assert_eq!(strict.candidates("BRKB"), vec!["BRKB"]);
+    }
+
+    #[test]
+    fn test_units_and_warrants() {
+        let unit = Symbol::parse("ACAHU");
+        assert_eq!(unit.root, "ACAH");
+        assert_eq!(unit.kind, SecurityKind::Unit);
+
+        let warrant = Symbol::parse("PSTH.WS");
+        assert_eq!(warrant.root, "PSTH");
+        assert_eq!(warrant.kind, SecurityKind::Warrant);
+        assert_eq!(warrant.canonical(), "PSTHW");
+
+        assert_eq!(Symbol::parse("IPOD+").kind, SecurityKind::Warrant);
+        assert_eq!(Symbol::parse("IPOD=").kind, SecurityKind::Unit);
+
+        let candidates = SymbolConventions::default().candidates("psth.ws");
+        assert_eq!(candidates.first().map(String::as_str), Some("PSTH.WS"));
+        assert!(candidates.contains(&"PSTH-WT".to_string()));
+        assert_eq!(candidates.last().map(String::as_str), Some("PSTH"));
+
+        // Plain five-letter symbols are left alone when Nasdaq suffixes are off.
+        let otc = SymbolConventions::default().with_nasdaq_suffixes(false);
+        assert_eq!(otc.parse("ABCDW").kind, SecurityKind::Shares);
+        assert_eq!(Symbol::parse("AAPL").kind, SecurityKind::Shares);
+    }
+}
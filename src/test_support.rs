@@ -0,0 +1,399 @@
+//! Compatibility checks for live SEC payloads.
+//!
+//! The SEC changes its JSON and XML payloads without notice: a field becomes nullable, a
+//! number turns into a string, a feed gains a new element. This module makes detecting that
+//! drift a routine check instead of a user bug report.
+//!
+//! A [`SnapshotHarness`] holds a list of [`PayloadCase`]s (a URL plus the model it should
+//! deserialize into). Running it downloads each payload, normalizes it, tries to deserialize it
+//! into the current model, and collects the outcome in a [`CompatibilityReport`]. Optionally
+//! the normalized payloads are written to a directory so they can be diffed against the
+//! checked-in fixtures.
+//!
+//! Live downloads only happen when the `EDGARKIT_LIVE_SNAPSHOTS` environment variable is set to
+//! `1`; otherwise every case is reported as skipped, so the harness is safe to call from a
+//! regular test run.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::Edgar;
+//! use edgarkit::test_support::SnapshotHarness;
+//!
+//! #[tokio::test]
+//! async fn sec_payloads_still_deserialize() {
+//!     let edgar = Edgar::new("MyApp contact@example.com").unwrap();
+//!     let report = SnapshotHarness::with_default_cases(&edgar)
+//!         .with_snapshot_dir("target/snapshots")
+//!         .run()
+//!         .await;
+//!     report.assert_compatible();
+//! }
+//! ```
+
+use crate::error::{EdgarError, Result};
+use crate::parsing::atom::{AtomConfig, AtomParser};
+use crate::parsing::rss::{RssConfig, RssParser};
+use crate::{
+    CompanyConcept, CompanyFacts, DirectoryResponse, Edgar, Frame, IndexResponse, SearchResponse,
+    Submission,
+};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Environment variable that enables live downloads when set to `1`.
+pub const LIVE_SNAPSHOTS_ENV: &str = "EDGARKIT_LIVE_SNAPSHOTS";
+
+/// The model a payload is expected to deserialize into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadKind {
+    /// `/submissions/CIK##########.json` → [`Submission`]
+    Submissions,
+    /// `/api/xbrl/companyfacts/...` → [`CompanyFacts`]
+    CompanyFacts,
+    /// `/api/xbrl/companyconcept/...` → [`CompanyConcept`]
+    CompanyConcept,
+    /// `/api/xbrl/frames/...` → [`Frame`]
+    Frames,
+    /// Archive `index.json` listings for a filer or filing → [`DirectoryResponse`]
+    Directory,
+    /// Daily/full index `index.json` listings → [`IndexResponse`]
+    IndexListing,
+    /// Full-text search responses → [`SearchResponse`]
+    Search,
+    /// Atom feeds from `browse-edgar`
+    AtomFeed,
+    /// RSS feeds (news and XBRL)
+    RssFeed,
+}
+
+impl PayloadKind {
+    fn is_json(&self) -> bool {
+        !matches!(self, PayloadKind::AtomFeed | PayloadKind::RssFeed)
+    }
+}
+
+/// A single payload to download and check.
+#[derive(Debug, Clone)]
+pub struct PayloadCase {
+    /// Short, file-name friendly identifier (e.g. "submissions-aapl").
+    pub name: String,
+
+    /// URL to download.
+    pub url: String,
+
+    /// Model the payload must deserialize into.
+    pub kind: PayloadKind,
+}
+
+impl PayloadCase {
+    pub fn new(name: impl Into<String>, url: impl Into<String>, kind: PayloadKind) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
+            kind,
+        }
+    }
+}
+
+/// Result of checking one payload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaseOutcome {
+    /// The payload deserialized into the current model.
+    Compatible,
+    /// The payload was downloaded but no longer matches the model.
+    Incompatible(String),
+    /// The payload could not be downloaded.
+    FetchFailed(String),
+    /// Live checks are disabled.
+    Skipped,
+}
+
+/// Outcome for a single [`PayloadCase`].
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    /// Case name.
+    pub name: String,
+
+    /// URL that was checked.
+    pub url: String,
+
+    /// What happened.
+    pub outcome: CaseOutcome,
+}
+
+/// Summary of a harness run.
+#[derive(Debug, Clone, Default)]
+pub struct CompatibilityReport {
+    /// One entry per case, in the order the cases were registered.
+    pub results: Vec<CaseResult>,
+}
+
+impl CompatibilityReport {
+    /// Cases whose payload no longer deserializes.
+    pub fn incompatible(&self) -> impl Iterator<Item = &CaseResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, CaseOutcome::Incompatible(_)))
+    }
+
+    /// Returns true if no downloaded payload failed to deserialize.
+    ///
+    /// Fetch failures and skipped cases do not count as incompatibilities.
+    pub fn is_compatible(&self) -> bool {
+        self.incompatible().next().is_none()
+    }
+
+    /// Panics with the full report if any payload failed to deserialize.
+    pub fn assert_compatible(&self) {
+        assert!(
+            self.is_compatible(),
+            "SEC payload drift detected:\n{}",
+            self
+        );
+    }
+}
+
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            let status = match &result.outcome {
+                CaseOutcome::Compatible => "ok".to_string(),
+                CaseOutcome::Incompatible(e) => format!("INCOMPATIBLE: {}", e),
+                CaseOutcome::FetchFailed(e) => format!("fetch failed: {}", e),
+                CaseOutcome::Skipped => "skipped".to_string(),
+            };
+            writeln!(f, "{:<28} {} ({})", result.name, status, result.url)?;
+        }
+        Ok(())
+    }
+}
+
+/// Downloads live payloads and checks them against the current models.
+pub struct SnapshotHarness {
+    edgar: Edgar,
+    cases: Vec<PayloadCase>,
+    snapshot_dir: Option<PathBuf>,
+}
+
+impl SnapshotHarness {
+    /// Creates a harness with no cases.
+    pub fn new(edgar: &Edgar) -> Self {
+        Self {
+            edgar: edgar.clone(),
+            cases: Vec::new(),
+            snapshot_dir: None,
+        }
+    }
+
+    /// Creates a harness covering one payload of every [`PayloadKind`].
+    pub fn with_default_cases(edgar: &Edgar) -> Self {
+        let data = edgar.data_url();
+        let archives = edgar.archives_url();
+        let cases = vec![
+            PayloadCase::new(
+                "submissions",
+                format!("{}/submissions/CIK0000320193.json", data),
+                PayloadKind::Submissions,
+            ),
+            PayloadCase::new(
+                "companyfacts",
+                format!("{}/api/xbrl/companyfacts/CIK0000320193.json", data),
+                PayloadKind::CompanyFacts,
+            ),
+            PayloadCase::new(
+                "companyconcept",
+                format!(
+                    "{}/api/xbrl/companyconcept/CIK0000320193/us-gaap/AccountsPayableCurrent.json",
+                    data
+                ),
+                PayloadKind::CompanyConcept,
+            ),
+            PayloadCase::new(
+                "frames",
+                format!(
+                    "{}/api/xbrl/frames/us-gaap/AccountsPayableCurrent/USD/CY2019Q1I.json",
+                    data
+                ),
+                PayloadKind::Frames,
+            ),
+            PayloadCase::new(
+                "entity-directory",
+                format!("{}/data/320193/index.json", archives),
+                PayloadKind::Directory,
+            ),
+            PayloadCase::new(
+                "full-index",
+                format!("{}/full-index/index.json", archives),
+                PayloadKind::IndexListing,
+            ),
+            PayloadCase::new(
+                "search",
+                format!("{}?forms=10-K", edgar.search_url()),
+                PayloadKind::Search,
+            ),
+            PayloadCase::new(
+                "current-feed",
+                "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&output=atom",
+                PayloadKind::AtomFeed,
+            ),
+            PayloadCase::new(
+                "press-releases",
+                "https://www.sec.gov/news/pressreleases.rss",
+                PayloadKind::RssFeed,
+            ),
+        ];
+
+        Self {
+            cases,
+            ..Self::new(edgar)
+        }
+    }
+
+    /// Adds a case to the harness.
+    pub fn with_case(mut self, case: PayloadCase) -> Self {
+        self.cases.push(case);
+        self
+    }
+
+    /// Writes each normalized payload to `<dir>/<case name>.json|.xml`.
+    pub fn with_snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = Some(dir.into());
+        self
+    }
+
+    /// Returns true if live downloads are enabled via [`LIVE_SNAPSHOTS_ENV`].
+    pub fn live_enabled() -> bool {
+        std::env::var(LIVE_SNAPSHOTS_ENV).is_ok_and(|v| v == "1")
+    }
+
+    /// Downloads and checks every case, one at a time.
+    ///
+    /// When live checks are disabled every case is reported as [`CaseOutcome::Skipped`]
+    /// without touching the network.
+    pub async fn run(&self) -> CompatibilityReport {
+        let live = Self::live_enabled();
+        let mut report = CompatibilityReport::default();
+
+        for case in &self.cases {
+            let outcome = if live {
+                self.run_case(case).await
+            } else {
+                CaseOutcome::Skipped
+            };
+            report.results.push(CaseResult {
+                name: case.name.clone(),
+                url: case.url.clone(),
+                outcome,
+            });
+        }
+
+        report
+    }
+
+    async fn run_case(&self, case: &PayloadCase) -> CaseOutcome {
+        let content = match self.edgar.get(&case.url).await {
+            Ok(content) => content,
+            Err(e) => return CaseOutcome::FetchFailed(e.to_string()),
+        };
+
+        let normalized = normalize_payload(case.kind, &content);
+
+        if let Some(dir) = &self.snapshot_dir {
+            let extension = if case.kind.is_json() { "json" } else { "xml" };
+            let path = dir.join(format!("{}.{}", case.name, extension));
+            let written =
+                std::fs::create_dir_all(dir).and_then(|_| std::fs::write(path, &normalized));
+            if let Err(e) = written {
+                return CaseOutcome::FetchFailed(format!("could not write snapshot: {}", e));
+            }
+        }
+
+        match check_payload(case.kind, &normalized) {
+            Ok(()) => CaseOutcome::Compatible,
+            Err(e) => CaseOutcome::Incompatible(e.to_string()),
+        }
+    }
+}
+
+/// Normalizes a payload so snapshots of the same data compare equal.
+///
+/// JSON is re-serialized with sorted keys and stable indentation; XML has its byte-order mark
+/// removed and line endings converted to `\n`. Content that fails to parse as JSON is returned
+/// with only the XML-style cleanup applied, leaving the error for [`check_payload`] to report.
+pub fn normalize_payload(kind: PayloadKind, content: &str) -> String {
+    let cleaned = content
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .trim()
+        .to_string();
+
+    if kind.is_json()
+        && let Ok(value) = serde_json::from_str::<serde_json::Value>(&cleaned)
+        && let Ok(pretty) = serde_json::to_string_pretty(&value)
+    {
+        return pretty;
+    }
+    cleaned
+}
+
+/// Checks that `content` deserializes into the model for `kind`.
+pub fn check_payload(kind: PayloadKind, content: &str) -> Result<()> {
+    match kind {
+        PayloadKind::Submissions => check_json::<Submission>(content),
+        PayloadKind::CompanyFacts => check_json::<CompanyFacts>(content),
+        PayloadKind::CompanyConcept => check_json::<CompanyConcept>(content),
+        PayloadKind::Frames => check_json::<Frame>(content),
+        PayloadKind::Directory => check_json::<DirectoryResponse>(content),
+        PayloadKind::IndexListing => check_json::<IndexResponse>(content),
+        PayloadKind::Search => check_json::<SearchResponse>(content),
+        PayloadKind::AtomFeed => AtomParser::new(AtomConfig::default())
+            .parse(content)
+            .map(|_| ()),
+        PayloadKind::RssFeed => RssParser::new(RssConfig::default())
+            .parse(content)
+            .map(|_| ()),
+    }
+}
+
+fn check_json<T: DeserializeOwned>(content: &str) -> Result<()> {
+    serde_json::from_str::<T>(content)
+        .map(|_| ())
+        .map_err(EdgarError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_json_sorts_keys() {
+        let normalized = normalize_payload(PayloadKind::Frames, "\u{feff}{\"b\":1,\"a\":2}\r\n");
+        assert_eq!(normalized, "{\n  \"a\": 2,\n  \"b\": 1\n}");
+    }
+
+    #[test]
+    fn test_check_payload_reports_drift() {
+        let ok = r#"{"cik": 1, "taxonomy": "us-gaap", "tag": "Assets", "label": null,
+            "description": null, "entityName": "X", "units": {}}"#;
+        assert!(check_payload(PayloadKind::CompanyConcept, ok).is_ok());
+        assert!(check_payload(PayloadKind::CompanyConcept, r#"{"cik": "1"}"#).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_skips_without_env_flag() {
+        if SnapshotHarness::live_enabled() {
+            return;
+        }
+        let edgar = Edgar::new("test_agent example@example.com").unwrap();
+        let report = SnapshotHarness::with_default_cases(&edgar).run().await;
+        assert!(
+            report
+                .results
+                .iter()
+                .all(|r| r.outcome == CaseOutcome::Skipped)
+        );
+        assert!(report.is_compatible());
+    }
+}
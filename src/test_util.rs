@@ -0,0 +1,108 @@
+//! Offline test doubles for code that takes an [`Edgar`](crate::Edgar).
+//!
+//! [`MockTransport`] answers requests from responses registered per URL, either inline or
+//! from fixture files, and records every URL it was asked for. Plug it into a client with
+//! [`Edgar::with_transport`](crate::Edgar::with_transport) and every `*Operations` trait runs
+//! without network access. Unlike a local mirror, URLs with a query string (full-text search,
+//! browse-edgar feeds) can be mocked too, since responses are matched on the full URL.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use edgarkit::test_util::MockTransport;
+//! use edgarkit::{Edgar, FilingOperations};
+//! use std::sync::Arc;
+//!
+//! #[tokio::test]
+//! async fn reads_latest_10k() {
+//!     let transport = Arc::new(MockTransport::new().with_fixture(
+//!         "https://data.sec.gov/submissions/CIK0000320193.json",
+//!         "tests/fixtures/submissions/submission.json",
+//!     ));
+//!     let edgar = Edgar::with_transport(transport.clone()).unwrap();
+//!
+//!     let submission = edgar.submissions(320193).await.unwrap();
+//!     assert_eq!(submission.name, "Apple Inc.");
+//!     assert_eq!(transport.requests().len(), 1);
+//! }
+//! ```
+
+use crate::error::Result;
+use crate::transport::{Transport, TransportResponse};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+#[derive(Debug, Clone)]
+enum Body {
+    Inline(Vec<u8>),
+    File(PathBuf),
+}
+
+/// A [`Transport`] that serves canned responses.
+///
+/// URLs without a registered response get an empty 404, which surfaces as
+/// `EdgarError::NotFound`.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: HashMap<String, (u16, Body)>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `url` with `200 OK` and `body`.
+    pub fn with_response(self, url: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        self.with_status(url, 200, body)
+    }
+
+    /// Answers `url` with `status` and `body`, for exercising error handling.
+    pub fn with_status(
+        mut self,
+        url: impl Into<String>,
+        status: u16,
+        body: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.responses
+            .insert(url.into(), (status, Body::Inline(body.into())));
+        self
+    }
+
+    /// Answers `url` with `200 OK` and the contents of the file at `path`.
+    ///
+    /// The file is read when the URL is requested, so a missing fixture fails the request
+    /// with `EdgarError::FileError` rather than the builder.
+    pub fn with_fixture(mut self, url: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.responses
+            .insert(url.into(), (200, Body::File(path.into())));
+        self
+    }
+
+    /// Every URL requested so far, in order.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Transport for MockTransport {
+    async fn get(&self, url: &str) -> Result<TransportResponse> {
+        self.requests.lock().unwrap().push(url.to_string());
+        match self.responses.get(url) {
+            Some((status, Body::Inline(body))) => {
+                Ok(TransportResponse::new(url, *status, body.clone()))
+            }
+            Some((status, Body::File(path))) => Ok(TransportResponse::new(
+                url,
+                *status,
+                tokio::fs::read(path).await?,
+            )),
+            None => Ok(TransportResponse::not_found(url)),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Shared throttle state behind [`ThrottlePolicy`].
+//!
+//! Every clone of a client shares one [`ThrottleState`]. It holds the pause imposed by a
+//! 403 rate-threshold page and, in adaptive mode, the reduced-rate token bucket that every
+//! request waits on in addition to the configured one.
+
+use super::config::ThrottlePolicy;
+use super::limiter::{Governor, shared_limiter};
+use super::rt::{Instant, sleep};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Text of the page SEC.gov serves (with status 403) to clients over its request rate.
+const RATE_THRESHOLD_MARKER: &str = "Request Rate Threshold Exceeded";
+
+/// Returns true if a 403 body is SEC.gov's rate-threshold page rather than a real denial.
+pub(crate) fn is_rate_threshold_page(body: &str) -> bool {
+    body.contains(RATE_THRESHOLD_MARKER)
+}
+
+#[derive(Debug)]
+struct Slowdown {
+    rate: u32,
+    limiter: Arc<Governor>,
+    until: Instant,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    paused_until: Option<Instant>,
+    recent: VecDeque<Instant>,
+    slowdown: Option<Slowdown>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ThrottleState {
+    policy: ThrottlePolicy,
+    base_rate: u32,
+    inner: Mutex<Inner>,
+}
+
+impl ThrottleState {
+    pub(crate) fn new(policy: ThrottlePolicy, base_rate: u32) -> Self {
+        Self {
+            policy,
+            base_rate,
+            inner: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn cooldown(&self) -> Duration {
+        self.policy.cooldown
+    }
+
+    /// The request rate currently in effect, in requests per second.
+    pub(crate) fn current_rate(&self) -> u32 {
+        let mut inner = self.inner.lock().unwrap();
+        Self::expire(&mut inner, Instant::now());
+        inner
+            .slowdown
+            .as_ref()
+            .map_or(self.base_rate, |slowdown| slowdown.rate)
+    }
+
+    /// Waits out an active pause, then takes a token from the reduced-rate bucket if the
+    /// client is slowed down.
+    pub(crate) async fn wait(&self) {
+        let (pause, limiter) = {
+            let mut inner = self.inner.lock().unwrap();
+            let now = Instant::now();
+            Self::expire(&mut inner, now);
+            let pause = inner
+                .paused_until
+                .and_then(|until| until.checked_duration_since(now));
+            let limiter = inner.slowdown.as_ref().map(|s| s.limiter.clone());
+            (pause, limiter)
+        };
+
+        if let Some(pause) = pause {
+            tracing::warn!(
+                "SEC.gov rate threshold exceeded; pausing requests for {:?}",
+                pause
+            );
+            sleep(pause).await;
+        }
+        if let Some(limiter) = limiter {
+            limiter.until_ready().await;
+        }
+    }
+
+    /// Records a throttle response, pausing all requests for `pause` if given.
+    pub(crate) fn record(&self, pause: Option<Duration>) {
+        let mut inner = self.inner.lock().unwrap();
+        let now = Instant::now();
+        Self::expire(&mut inner, now);
+
+        if let Some(pause) = pause {
+            let until = now + pause;
+            inner.paused_until = Some(inner.paused_until.map_or(until, |u| u.max(until)));
+        }
+        if !self.policy.adaptive {
+            return;
+        }
+
+        inner.recent.push_back(now);
+        while inner
+            .recent
+            .front()
+            .is_some_and(|&at| now.duration_since(at) > self.policy.window)
+        {
+            inner.recent.pop_front();
+        }
+        if (inner.recent.len() as u32) < self.policy.threshold {
+            return;
+        }
+
+        inner.recent.clear();
+        let current = inner.slowdown.as_ref().map_or(self.base_rate, |s| s.rate);
+        let rate = (current / 2).max(self.policy.min_rate).max(1);
+        tracing::warn!(
+            "Repeated throttling; reducing the request rate from {}/s to {}/s for {:?}",
+            current,
+            rate,
+            self.policy.recovery
+        );
+        let limiter = shared_limiter(rate).expect("rate is at least one");
+        inner.slowdown = Some(Slowdown {
+            rate,
+            limiter,
+            until: now + self.policy.recovery,
+        });
+    }
+
+    fn expire(inner: &mut Inner, now: Instant) {
+        if inner.paused_until.is_some_and(|until| until <= now) {
+            inner.paused_until = None;
+        }
+        if inner.slowdown.as_ref().is_some_and(|s| s.until <= now) {
+            inner.slowdown = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_rate_reduction() {
+        let state = ThrottleState::new(ThrottlePolicy::adaptive().with_threshold(2), 10);
+        state.record(None);
+        assert_eq!(state.current_rate(), 10);
+        state.record(None);
+        assert_eq!(state.current_rate(), 5);
+        state.record(None);
+        state.record(None);
+        assert_eq!(state.current_rate(), 2);
+
+        let fixed = ThrottleState::new(ThrottlePolicy::default().with_threshold(1), 10);
+        fixed.record(None);
+        assert_eq!(fixed.current_rate(), 10);
+
+        assert!(is_rate_threshold_page(
+            "<html><title>SEC.gov | Request Rate Threshold Exceeded</title></html>"
+        ));
+        assert!(!is_rate_threshold_page("<html>Forbidden</html>"));
+    }
+}
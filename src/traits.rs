@@ -8,33 +8,84 @@
 //! This design allows for:
 //! - Clear separation of concerns
 //! - Feature-gated compilation (only include what you need)
-//! - Easy mocking and testing
 //! - Discoverable API through trait methods
 //!
-//! Users typically interact with the `Edgar` struct directly rather than through
-//! trait objects, but the traits are useful for understanding the API surface and
-//! for testing scenarios where you want to provide alternative implementations.
+//! The traits are not dyn-compatible: most methods take generic `impl Into<EntityId>` or
+//! `impl Into<Cik>` arguments, so there is no `dyn FilingOperations`. Code that should
+//! accept another implementation can be generic over the trait instead
+//! (`fn report(edgar: &impl FilingOperations)`). To test code against canned responses,
+//! keep the real `Edgar` client and replace its HTTP layer with a
+//! [`Transport`](crate::Transport), such as `test_util::MockTransport` (`test-util`
+//! feature).
 
+#[cfg(any(feature = "company", feature = "filings", feature = "feeds"))]
+use super::cik::Cik;
 #[cfg(feature = "company")]
 use super::company::{
-    CompanyConcept, CompanyFacts, CompanyTicker, CompanyTickerExchange, Frame, MutualFundTicker,
+    CompanyConcept, CompanyFacts, CompanyTicker, CompanyTickerExchange, Frame, FramePanel,
+    FramePeriod, MutualFundTicker,
 };
+#[cfg(all(feature = "company", feature = "search"))]
+use super::enrich::CompanyMetadata;
+use super::entity::EntityId;
 use super::error::Result;
+#[cfg(all(feature = "feeds", feature = "filings"))]
+use super::feeds::RecentFiling;
+#[cfg(feature = "feeds")]
+use super::feeds::{CompanyUpdate, FeedItem, FeedKind, NewsItem};
+#[cfg(all(feature = "filings", not(target_arch = "wasm32")))]
+use super::filings::FilingManifest;
 #[cfg(feature = "filings")]
-use super::filings::{DetailedFiling, DirectoryResponse, Submission};
+use super::filings::{
+    AmendmentChain, DetailedFiling, DirectoryResponse, ProxyStatement, RecentFilings, Submission,
+};
+#[cfg(feature = "funds")]
+use super::funds::{FundClass, FundSeries};
 #[cfg(feature = "index")]
-use super::index::{EdgarDay, EdgarPeriod, IndexResponse};
+use super::index::{EdgarDay, EdgarPeriod, IndexCoverage, IndexDay, IndexResponse, IndexScope};
+#[cfg(feature = "company")]
+use super::names::CikCandidate;
+#[cfg(all(feature = "feeds", feature = "filings"))]
+use super::options::CurrentFeedOptions;
+#[cfg(feature = "feeds")]
+use super::options::FeedOptions;
 #[cfg(any(feature = "filings", feature = "index", feature = "feeds"))]
-use super::options::{FeedOptions, FilingOptions};
+use super::options::FilingOptions;
+#[cfg(feature = "feeds")]
+use super::polling::PollBudget;
+#[cfg(any(feature = "feeds", feature = "index", feature = "search"))]
+use super::rt::BoxStream;
 #[cfg(feature = "search")]
-use super::search::{Hit, SearchOptions, SearchResponse};
+use super::search::{EntityMatch, Hit, SearchOptions, SearchResponse};
 #[cfg(feature = "feeds")]
-use crate::parsing::atom::AtomDocument;
+use super::seen::SeenSet;
+#[cfg(feature = "feeds")]
+use crate::parsing::atom::{AtomDocument, AtomEntry};
+#[cfg(feature = "filings")]
+use crate::parsing::filing_summary::FinancialReport;
+#[cfg(feature = "filings")]
+use crate::parsing::form_d::FormD;
 #[cfg(feature = "index")]
 use crate::parsing::index::IndexEntry;
+#[cfg(any(feature = "company", feature = "filings"))]
+use crate::parsing::json::ParseReport;
+#[cfg(feature = "funds")]
+use crate::parsing::nport::NportReport;
+#[cfg(feature = "filings")]
+use crate::parsing::ownership::BeneficialOwnership;
 #[cfg(feature = "feeds")]
 use crate::parsing::rss::RssDocument;
+#[cfg(feature = "filings")]
+use crate::parsing::sections::{Item, Section};
+#[cfg(feature = "filings")]
+use crate::parsing::submission::SubmissionDocument;
 use async_trait::async_trait;
+#[cfg(feature = "filings")]
+use std::collections::BTreeMap;
+#[cfg(all(feature = "filings", not(target_arch = "wasm32")))]
+use std::path::Path;
+#[cfg(feature = "feeds")]
+use std::time::Duration;
 
 /// Operations for retrieving company information and financial data.
 ///
@@ -46,12 +97,17 @@ use async_trait::async_trait;
 /// company facts based on XBRL filings. This is particularly useful for financial
 /// analysis and building company databases.
 #[cfg(feature = "company")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait CompanyOperations {
     /// Retrieves a list of all company tickers from EDGAR.
     async fn company_tickers(&self) -> Result<Vec<CompanyTicker>>;
     /// Retrieves the Central Index Key (CIK) for a given company ticker symbol.
     async fn company_cik(&self, ticker: &str) -> Result<u64>;
+    /// Ranks companies whose registered name is close to `name`, best match first.
+    async fn company_cik_by_name(&self, name: &str) -> Result<Vec<CikCandidate>>;
+    /// Re-downloads the cached ticker map and returns the number of tickers.
+    async fn refresh_tickers(&self) -> Result<usize>;
     /// Retrieves the CIK for a given mutual fund ticker symbol.
     async fn mutual_fund_cik(&self, ticker: &str) -> Result<u64>;
     /// Retrieves a list of company tickers along with their exchange information.
@@ -59,11 +115,49 @@ pub trait CompanyOperations {
     /// Retrieves a list of mutual fund tickers from the SEC EDGAR database.
     async fn mutual_fund_tickers(&self) -> Result<Vec<MutualFundTicker>>;
     /// Retrieves company facts and financial data for a given CIK.
-    async fn company_facts(&self, cik: u64) -> Result<CompanyFacts>;
+    async fn company_facts(&self, cik: impl Into<EntityId> + Send) -> Result<CompanyFacts>;
+    /// Retrieves company facts for several companies, at most `max_concurrency` at a time,
+    /// each with its own result
+    async fn company_facts_for_ciks<I>(
+        &self,
+        ciks: I,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<CompanyFacts>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>;
+    /// Retrieves company facts and the repairs lenient parsing made to them.
+    async fn company_facts_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(CompanyFacts, ParseReport)>;
     /// Retrieves specific concept data for a company using taxonomy and tag.
-    async fn company_concept(&self, cik: u64, taxonomy: &str, tag: &str) -> Result<CompanyConcept>;
+    async fn company_concept(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        taxonomy: &str,
+        tag: &str,
+    ) -> Result<CompanyConcept>;
     /// Retrieves frames for a given taxonomy, concept, unit, and period.
-    async fn frames(&self, taxonomy: &str, tag: &str, unit: &str, period: &str) -> Result<Frame>;
+    async fn frames(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        period: FramePeriod,
+    ) -> Result<Frame>;
+    /// Retrieves frames for every period from `from_period` to `to_period` as one panel.
+    async fn frames_range(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        from_period: FramePeriod,
+        to_period: FramePeriod,
+    ) -> Result<FramePanel>;
+    /// Lists companies that filed an annual report under a four-digit SIC code recently.
+    #[cfg(feature = "search")]
+    async fn peers_by_sic(&self, sic_code: &str) -> Result<Vec<CompanyMetadata>>;
 }
 
 /// Operations for accessing SEC filings and related documents.
@@ -76,43 +170,190 @@ pub trait CompanyOperations {
 /// Filing operations are the core of most EDGAR use cases, enabling you to discover
 /// what a company has filed and retrieve the actual filing documents for analysis.
 #[cfg(feature = "filings")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait FilingOperations {
     /// Retrieves all submissions for a specific company identified by CIK.
-    async fn submissions(&self, cik: &str) -> Result<Submission>;
+    async fn submissions(&self, cik: impl Into<EntityId> + Send) -> Result<Submission>;
+    /// Retrieves all submissions and the repairs lenient parsing made to them.
+    async fn submissions_with_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<(Submission, ParseReport)>;
+    /// Retrieves an older filings page (`FilingFile::name`) as a `RecentFilings` table.
+    async fn get_submission_file(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        name: &str,
+    ) -> Result<RecentFilings>;
     /// Helper function to get recent filings in a form of a Vec.
-    async fn get_recent_filings(&self, cik: &str) -> Result<Vec<DetailedFiling>>;
+    async fn get_recent_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>>;
+    /// Retrieves every filing, older submission pages included, newest first.
+    async fn all_filings(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<DetailedFiling>>;
+    /// Finds the earliest filing of a form across the full filing history.
+    async fn first_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>>;
+    /// Finds the most recent filing of a form across the full filing history.
+    async fn latest_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form: &str,
+    ) -> Result<Option<DetailedFiling>>;
+    /// Counts filings by form type across the full filing history.
+    async fn filing_count_by_form(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<BTreeMap<String, usize>>;
     /// Retrieves a list of filings for a specific company identified by CIK.
-    async fn filings(&self, cik: &str, opts: Option<FilingOptions>) -> Result<Vec<DetailedFiling>>;
+    async fn filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FilingOptions>,
+    ) -> Result<Vec<DetailedFiling>>;
+    /// Retrieves the most recent filings across several companies, newest accepted first.
+    async fn latest_across<I>(
+        &self,
+        ciks: I,
+        form_types: &[&str],
+        limit: usize,
+    ) -> Result<Vec<(Cik, DetailedFiling)>>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>;
+    /// Retrieves filings for several companies, at most `max_concurrency` at a time, each
+    /// with its own result
+    async fn filings_for_ciks<I>(
+        &self,
+        ciks: I,
+        opts: Option<FilingOptions>,
+        max_concurrency: usize,
+    ) -> Vec<(Cik, Result<Vec<DetailedFiling>>)>
+    where
+        I: IntoIterator + Send,
+        I::Item: Into<Cik>;
     /// Retrieves the directory structure for a specific filing.
     async fn filing_directory(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         accession_number: &str,
     ) -> Result<DirectoryResponse>;
+    /// Downloads every file of a filing, optionally only some extensions, plus a manifest
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn download_filing(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        dest_dir: impl AsRef<Path> + Send,
+        extensions: &[&str],
+    ) -> Result<FilingManifest>;
     /// Retrieves the directory structure for a specific entity.
-    async fn entity_directory(&self, cik: &str) -> Result<DirectoryResponse>;
+    async fn entity_directory(&self, cik: impl Into<EntityId> + Send) -> Result<DirectoryResponse>;
     /// Constructs a filing URL from a combined filing ID (format: "accession_number:filename")
-    fn get_filing_url_from_id(&self, cik: &str, filing_id: &str) -> Result<String>;
+    fn get_filing_url_from_id(&self, cik: impl Into<EntityId>, filing_id: &str) -> Result<String>;
     /// Fetches a filing's content directly using its URL
-    async fn get_filing_content_by_id(&self, cik: &str, filing_id: &str) -> Result<String>;
+    async fn get_filing_content_by_id(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        filing_id: &str,
+    ) -> Result<String>;
     /// Fetches the latest filing for a company matching one of the requested form types.
     ///
     /// Use this when you want “latest 10-Q **or** 10-K”, etc. The forms are applied as a filter,
     /// and the newest matching filing (as returned by the SEC) is downloaded.
-    async fn get_latest_filing_content(&self, cik: &str, form_types: &[&str]) -> Result<String>;
+    async fn get_latest_filing_content(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        form_types: &[&str],
+    ) -> Result<String>;
     /// Generates URLs for text filings with original SEC.gov links based on specified options without downloading content
     async fn get_text_filing_links(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         opts: Option<FilingOptions>,
     ) -> Result<Vec<(DetailedFiling, String, String)>>;
     /// Generates URLs for SGML header files with original SEC.gov links based on specified options without downloading content
     async fn get_sgml_header_links(
         &self,
-        cik: &str,
+        cik: impl Into<EntityId> + Send,
         opts: Option<FilingOptions>,
     ) -> Result<Vec<(DetailedFiling, String, String)>>;
+    /// Downloads a complete submission text file and splits it into its individual documents
+    async fn get_submission_documents(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<SubmissionDocument>>;
+    /// Downloads a 10-K or 10-Q primary document and extracts one item section from it
+    async fn get_filing_section(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        item: Item,
+    ) -> Result<Section>;
+    /// Downloads the company's most recent definitive proxy statement (`DEF 14A`)
+    async fn get_latest_proxy(&self, cik: impl Into<EntityId> + Send) -> Result<ProxyStatement>;
+    /// Lists the company's Schedule 13D and 13G filings, amendments included
+    async fn get_beneficial_ownership_filings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+    ) -> Result<Vec<DetailedFiling>>;
+    /// Downloads a Schedule 13D or 13G and parses its cover pages
+    async fn get_beneficial_ownership(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<BeneficialOwnership>;
+    /// Downloads a Form D and parses the issuer, related persons, and offering details
+    async fn get_form_d(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<FormD>;
+    /// Finds a filing's original and amendments, ordered by acceptance time
+    async fn amendment_chain(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<AmendmentChain>;
+    /// Lists the XBRL report renderings (`R1.htm`, ...) named in a filing's `FilingSummary.xml`
+    async fn get_financial_reports(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<Vec<FinancialReport>>;
+    /// Downloads the HTML rendering of the financial report with the given name
+    async fn get_financial_report(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+        name: &str,
+    ) -> Result<String>;
+}
+
+/// Operations for registered investment company (mutual fund and ETF) reports.
+///
+/// Funds file under their registrant's CIK; one filing covers one series of the trust.
+/// N-PORT reports are parsed from the XML EDGAR keeps alongside the rendered form.
+#[cfg(feature = "funds")]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait FundOperations {
+    /// Downloads an N-PORT filing and parses its fund information and portfolio holdings
+    async fn get_nport_holdings(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        accession_number: &str,
+    ) -> Result<NportReport>;
+    /// Lists a registrant's fund series with their share classes and tickers
+    async fn fund_series(&self, cik: impl Into<EntityId> + Send) -> Result<Vec<FundSeries>>;
+    /// Looks up a fund share class by its class identifier
+    async fn fund_class(&self, class_id: &str) -> Result<FundClass>;
 }
 
 /// Operations for accessing EDGAR Atom and RSS feeds.
@@ -125,14 +366,25 @@ pub trait FilingOperations {
 /// The SEC provides both Atom feeds (for filings) and RSS feeds (for news and alerts).
 /// This trait abstracts the differences and provides a consistent interface to both.
 #[cfg(feature = "feeds")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait FeedOperations {
     /// Retrieves the current EDGAR feed with optional parameters.
     async fn current_feed(&self, opts: Option<FeedOptions>) -> Result<AtomDocument>;
     /// Parses the current feed from a string
     fn current_feed_from_string(&self, content: &str) -> Result<AtomDocument>;
-    /// Retrieves the feed for a specific company identified by CIK.
-    async fn company_feed(&self, cik: &str, opts: Option<FeedOptions>) -> Result<AtomDocument>;
+    /// Retrieves the feed for a specific company identified by CIK or ticker.
+    async fn company_feed(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FeedOptions>,
+    ) -> Result<AtomDocument>;
+    /// Retrieves every entry of a company feed by following `start` offsets.
+    async fn company_feed_all(
+        &self,
+        cik: impl Into<EntityId> + Send,
+        opts: Option<FeedOptions>,
+    ) -> Result<Vec<AtomEntry>>;
     /// Parses the company feed from a string
     fn company_feed_from_string(&self, content: &str) -> Result<AtomDocument>;
     /// Retrieves an RSS feed from a specified URL.
@@ -167,6 +419,34 @@ pub trait FeedOperations {
     async fn inline_xbrl_feed(&self) -> Result<RssDocument>;
     /// Fetches the historical XBRL feed
     async fn historical_xbrl_feed(&self, year: i32, month: i32) -> Result<RssDocument>;
+    /// Polls the selected SEC news feeds and yields new items as one chronological stream
+    fn sec_news_stream(
+        &self,
+        kinds: &[FeedKind],
+        interval: Duration,
+    ) -> BoxStream<'_, Result<NewsItem>>;
+    /// Polls an Atom or RSS feed and yields entries not seen in earlier polls
+    fn poll_feed(&self, url: &str, interval: Duration) -> BoxStream<'_, Result<FeedItem>>;
+    /// Polls a feed like `poll_feed`, keeping the entries already yielded in `cursor`
+    fn poll_feed_with_cursor(
+        &self,
+        url: &str,
+        interval: Duration,
+        cursor: SeenSet,
+    ) -> BoxStream<'_, Result<FeedItem>>;
+    /// Polls company feeds on an adaptive schedule and yields new filings per company
+    fn company_filings_stream(
+        &self,
+        ciks: Vec<Cik>,
+        budget: PollBudget,
+    ) -> BoxStream<'_, Result<CompanyUpdate>>;
+    /// Polls the current filings feed and yields filings not seen before, deduplicated by accession
+    #[cfg(feature = "filings")]
+    fn recent_filings_stream(
+        &self,
+        options: CurrentFeedOptions,
+        interval: Duration,
+    ) -> BoxStream<'_, Result<RecentFiling>>;
 }
 
 /// Operations for retrieving daily and quarterly filing indices.
@@ -179,7 +459,8 @@ pub trait FeedOperations {
 /// Indices provide a lightweight way to get filing metadata without downloading full
 /// documents, making them ideal for building filing databases or monitoring systems.
 #[cfg(feature = "index")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait IndexOperations {
     /// Retrieves the full index file for a specific year and quarter.
     async fn full_index(&self, period: Option<EdgarPeriod>) -> Result<IndexResponse>;
@@ -197,6 +478,23 @@ pub trait IndexOperations {
         period: EdgarPeriod,
         options: Option<FilingOptions>,
     ) -> Result<Vec<IndexEntry>>;
+    /// Streams the quarterly index for a period, parsing and filtering entries lazily
+    async fn stream_period_filings(
+        &self,
+        period: EdgarPeriod,
+        options: Option<FilingOptions>,
+    ) -> Result<BoxStream<'static, Result<IndexEntry>>>;
+    /// Reports which business days in a range have a daily index available
+    async fn index_coverage(&self, start: EdgarDay, end: EdgarDay) -> Result<IndexCoverage>;
+    /// Lists the days of a year or quarter that have daily index files
+    async fn available_days(&self, scope: impl Into<IndexScope> + Send) -> Result<Vec<IndexDay>>;
+    /// Gets and combines the daily indices for a date range, skipping days without one
+    async fn get_filings_between(
+        &self,
+        start: EdgarDay,
+        end: EdgarDay,
+        options: Option<FilingOptions>,
+    ) -> Result<Vec<IndexEntry>>;
 }
 
 /// Operations for searching EDGAR filings with flexible criteria.
@@ -209,10 +507,15 @@ pub trait IndexOperations {
 /// when you don't know exact identifiers. The search system indexes filing text,
 /// metadata, and company information for comprehensive discoverability.
 #[cfg(feature = "search")]
-#[async_trait]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 pub trait SearchOperations {
     /// Performs a search query on EDGAR
     async fn search(&self, options: SearchOptions) -> Result<SearchResponse>;
     /// Performs a search query and fetches all available pages
     async fn search_all(&self, options: SearchOptions) -> Result<Vec<Hit>>;
+    /// Streams every matching hit, fetching pages as the stream is consumed
+    fn search_stream(&self, options: SearchOptions) -> BoxStream<'_, Result<Hit>>;
+    /// Resolves a free-text company or person name to matching entities (CIK, name, tickers)
+    async fn entity_search(&self, name: &str) -> Result<Vec<EntityMatch>>;
 }
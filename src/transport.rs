@@ -0,0 +1,60 @@
+//! Pluggable HTTP transport.
+//!
+//! By default every request goes to sec.gov through the client's built-in `reqwest` stack.
+//! A [`Transport`] set with [`EdgarConfig::with_transport`](crate::EdgarConfig::with_transport)
+//! or [`Edgar::with_transport`](crate::Edgar::with_transport) answers requests instead, which
+//! lets code that takes an `Edgar` run against canned responses. The `test-util` feature
+//! provides a ready-made [`MockTransport`](crate::test_util::MockTransport).
+//!
+//! Responses from a transport go through the same status handling as HTTP responses: 404
+//! becomes `EdgarError::NotFound`, 429 `EdgarError::RateLimitExceeded`, and other non-2xx
+//! statuses the usual status errors. They are not rate limited or retried, so tests run at full
+//! speed and a transport sees every request exactly once.
+
+use super::error::Result;
+use async_trait::async_trait;
+use std::fmt;
+
+/// Answers GET requests in place of HTTP.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Fetches `url`.
+    ///
+    /// Return `Ok` with the status the server would have sent, including error statuses;
+    /// `Err` is reserved for failures to produce a response at all.
+    async fn get(&self, url: &str) -> Result<TransportResponse>;
+}
+
+/// A response produced by a [`Transport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportResponse {
+    /// HTTP status code
+    pub status: u16,
+
+    /// URL the body was served from, reported by `Edgar::get_with_url` and in errors
+    pub url: String,
+
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    pub fn new(url: impl Into<String>, status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            url: url.into(),
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response.
+    pub fn ok(url: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self::new(url, 200, body)
+    }
+
+    /// An empty `404 Not Found` response.
+    pub fn not_found(url: impl Into<String>) -> Self {
+        Self::new(url, 404, Vec::new())
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(all(feature = "blocking", feature = "test-util"))]
+
+mod common;
+
+use common::{fixture_path, read_fixture};
+use edgarkit::blocking::Edgar;
+use edgarkit::test_util::MockTransport;
+use edgarkit::{EdgarError, SearchOptions};
+use std::sync::Arc;
+
+#[test]
+fn blocking_client_drives_async_operations() {
+    let transport = MockTransport::new()
+        .with_fixture(
+            "https://data.sec.gov/submissions/CIK0000320193.json",
+            fixture_path("submissions/submission.json"),
+        )
+        .with_response(
+            "https://efts.sec.gov/LATEST/search-index/?q=merger",
+            read_fixture("search/search-index.json"),
+        );
+    let edgar =
+        Edgar::from_async(edgarkit::Edgar::with_transport(Arc::new(transport)).unwrap()).unwrap();
+
+    let filings = edgar.get_recent_filings(320193).unwrap();
+    assert!(!filings.is_empty());
+
+    let results = edgar
+        .search(SearchOptions::new().with_query("merger"))
+        .unwrap();
+    assert_eq!(results.hits.total.value, 146);
+
+    // Paged requests are not mocked: the iterator yields the error once, then ends.
+    let mut hits = edgar.search_stream(SearchOptions::new().with_query("merger"));
+    assert!(matches!(hits.next(), Some(Err(EdgarError::NotFound(_)))));
+    assert!(hits.next().is_none());
+}
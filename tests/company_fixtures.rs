@@ -1,7 +1,7 @@
 mod common;
 
 use common::read_fixture;
-use edgarkit::{CompanyConcept, CompanyFacts, Frame};
+use edgarkit::{CompanyConcept, CompanyFacts, Frame, PeriodKind};
 
 #[test]
 fn parse_company_facts() {
@@ -13,8 +13,7 @@ fn parse_company_facts() {
 
     let income_tax = facts
         .taxonomies
-        .us_gaap
-        .get("IncomeTaxExpenseBenefit")
+        .fact("us-gaap", "IncomeTaxExpenseBenefit")
         .unwrap();
     assert_eq!(
         income_tax.label,
@@ -29,6 +28,58 @@ fn parse_company_facts() {
     assert!(point.frame.is_none());
 }
 
+#[test]
+fn company_facts_financial_statements() {
+    let content = read_fixture("tickers/companyfacts.json");
+    let facts: CompanyFacts = serde_json::from_str(&content).unwrap();
+    let financials = facts.financials(PeriodKind::Annual);
+
+    let fy2024 = financials
+        .income_statements
+        .iter()
+        .find(|s| s.period.end.to_string() == "2024-09-28")
+        .unwrap();
+    assert_eq!(fy2024.period.fiscal_year, Some(2024));
+    assert_eq!(fy2024.period.fiscal_period.as_deref(), Some("FY"));
+    assert_eq!(fy2024.revenue, Some(391035000000.0));
+    assert_eq!(fy2024.net_income, Some(93736000000.0));
+    assert_eq!(fy2024.eps_diluted, Some(6.08));
+
+    // Revenue before ASC 606 was tagged differently; the synonym fills the gap.
+    let fy2010 = financials
+        .income_statements
+        .iter()
+        .find(|s| s.period.fiscal_year == Some(2010))
+        .unwrap();
+    assert!(fy2010.revenue.is_some());
+
+    let balance = financials.balance_sheets.last().unwrap();
+    assert_eq!(balance.period.end.to_string(), "2024-09-28");
+    assert_eq!(balance.total_assets, Some(364980000000.0));
+    assert_eq!(balance.total_liabilities, Some(308030000000.0));
+
+    let cash_flow = financials.cash_flow_statements.last().unwrap();
+    assert_eq!(cash_flow.operating_cash_flow, Some(118254000000.0));
+    assert_eq!(
+        cash_flow.free_cash_flow(),
+        Some(118254000000.0 - 9447000000.0)
+    );
+
+    let ratios = facts.ratios(PeriodKind::Annual);
+    let latest = ratios.last().unwrap();
+    assert_eq!(latest.period.end.to_string(), "2024-09-28");
+    let net_margin = latest.net_margin.as_ref().unwrap();
+    assert_eq!(net_margin.value, 93736000000.0 / 391035000000.0);
+    assert_eq!(net_margin.inputs[0].tag, "NetIncomeLoss");
+    assert_eq!(
+        net_margin.inputs[1].tag,
+        "RevenueFromContractWithCustomerExcludingAssessedTax"
+    );
+    assert_eq!(net_margin.inputs[0].form, "10-K");
+    // Opening and closing equity both feed return on equity.
+    assert_eq!(latest.return_on_equity.as_ref().unwrap().inputs.len(), 3);
+}
+
 #[test]
 fn parse_company_concept() {
     let content = read_fixture("tickers/companyconcept.json");
@@ -1,7 +1,7 @@
 mod common;
 
 use common::{edgar, read_fixture};
-use edgarkit::FeedOperations;
+use edgarkit::{DetailedFiling, FeedOperations, ItemCode};
 
 #[test]
 fn parse_testimony_feed() {
@@ -114,3 +114,49 @@ fn parse_atom_feed() {
     assert!(!feed.entries.is_empty());
     assert!(feed.company_info.is_some());
 }
+
+#[test]
+fn atom_entry_to_detailed_filing() {
+    let edgar = edgar();
+    let content = read_fixture("atom/atom1.xml");
+    let feed = edgar.company_feed_from_string(&content).unwrap();
+
+    let entry = &feed.entries[0];
+    let filing = DetailedFiling::try_from(entry).unwrap();
+
+    assert_eq!(filing.accession_number, "0001213900-25-006497");
+    assert_eq!(filing.form, "8-K");
+    assert_eq!(filing.filing_date.to_string(), "2025-01-24");
+    assert_eq!(filing.items.as_deref(), Some("1.01,2.03,8.01,9.01"));
+    assert_eq!(
+        filing.item_codes(),
+        vec![
+            ItemCode::EntryIntoMaterialAgreement,
+            ItemCode::DirectFinancialObligation,
+            ItemCode::OtherEvents,
+            ItemCode::FinancialStatementsAndExhibits,
+        ]
+    );
+    assert_eq!(filing.size, 302 * 1024);
+    assert!(filing.is_xbrl);
+    assert_eq!(entry.cik(), Some(1889983));
+}
+
+#[test]
+fn current_feed_titles_name_the_filer() {
+    let edgar = edgar();
+    let content = read_fixture("atom/current.xml");
+    let feed = edgar.current_feed_from_string(&content).unwrap();
+
+    let entry = &feed.entries[1];
+    assert_eq!(entry.company_name().as_deref(), Some("Rawlinson Peter"));
+    assert_eq!(entry.cik(), Some(1843213));
+    assert_eq!(
+        feed.entries[0].company_name().as_deref(),
+        Some("Lucid Group, Inc.")
+    );
+
+    let filing = DetailedFiling::try_from(entry).unwrap();
+    assert_eq!(filing.form, "4");
+    assert_eq!(filing.accession_number, "0001811210-25-000011");
+}
@@ -1,7 +1,14 @@
 mod common;
 
+use chrono::NaiveDate;
 use common::read_fixture;
-use edgarkit::{DetailedFiling, DirectoryResponse, Submission};
+use edgarkit::parsing::html::to_markdown;
+use edgarkit::parsing::ixbrl::{InlinePeriod, parse_inline_xbrl};
+use edgarkit::parsing::sections::{Item, split_sections};
+use edgarkit::{
+    CorporateEventKind, CorporateHistory, DetailedFiling, DirectoryResponse, IndustryGroup,
+    RecentFilings, SicOffice, StateOrCountry, Submission,
+};
 
 #[test]
 fn parse_submission() {
@@ -12,6 +19,21 @@ fn parse_submission() {
     assert_eq!(submission.cik, "0000320193");
     assert_eq!(submission.tickers, vec!["AAPL"]);
     assert!(!submission.filings.recent.accession_number.is_empty());
+
+    let sic = submission.sic_code().unwrap();
+    assert_eq!(sic.description(), Some("ELECTRONIC COMPUTERS"));
+    assert_eq!(sic.office(), Some(SicOffice::Technology));
+    assert_eq!(sic.industry_group(), Some(IndustryGroup::Manufacturing));
+
+    let incorporated = submission.incorporation_state().unwrap();
+    assert_eq!(incorporated, StateOrCountry::UsState("CA"));
+    assert_eq!(incorporated.name(), Some("California"));
+    assert_eq!(
+        submission
+            .business_address()
+            .and_then(|address| address.state_or_country_code()),
+        Some(StateOrCountry::UsState("CA"))
+    );
 }
 
 #[test]
@@ -23,7 +45,80 @@ fn detailed_filing_conversion() {
 
     assert!(filing.acceptance_date_time.timestamp() > 0);
     assert!(!filing.accession_number.is_empty());
-    assert!(!filing.filing_date.is_empty());
+    assert_eq!(
+        filing.filing_date.to_string(),
+        submission.filings.recent.filing_date[0]
+    );
+
+    // Every row converts, including ones with an empty report date.
+    let recent = &submission.filings.recent;
+    for idx in 0..recent.accession_number.len() {
+        DetailedFiling::try_from((recent, idx)).unwrap();
+    }
+}
+
+#[test]
+fn parse_insider_submission() {
+    let content = read_fixture("submissions/submission-insider.json");
+    let submission: Submission = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(submission.name, "Cook Timothy D");
+    assert_eq!(submission.phone, None);
+    assert_eq!(submission.flags, None);
+    assert_eq!(submission.state_of_incorporation, None);
+    assert_eq!(submission.incorporation_state(), None);
+    assert_eq!(submission.sic_code(), None);
+
+    let business = submission.business_address().unwrap();
+    assert_eq!(business.street1, None);
+    assert_eq!(business.state_or_country_code(), None);
+    assert_eq!(
+        submission.mailing_address().unwrap().city.as_deref(),
+        Some("CUPERTINO")
+    );
+    assert_eq!(submission.filings.recent.form, vec!["4", "144"]);
+}
+
+#[test]
+fn parse_foreign_submission() {
+    let content = read_fixture("submissions/submission-foreign.json");
+    let submission: Submission = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(submission.phone, None);
+    assert_eq!(submission.flags, None);
+    assert!(submission.mailing_address().is_none());
+    assert_eq!(
+        submission.incorporation_state().unwrap().name(),
+        Some("Japan")
+    );
+    assert_eq!(
+        submission
+            .business_address()
+            .unwrap()
+            .country_code
+            .as_deref(),
+        Some("JP")
+    );
+    assert_eq!(
+        DetailedFiling::try_from((&submission.filings.recent, 1))
+            .unwrap()
+            .form,
+        "20-F"
+    );
+}
+
+#[test]
+fn parse_submission_file() {
+    let content = read_fixture("submissions/submission-file.json");
+    let page: RecentFilings = serde_json::from_str(&content).unwrap();
+
+    assert_eq!(page.accession_number.len(), 2);
+    let filing = DetailedFiling::try_from((&page, 0)).unwrap();
+    assert_eq!(filing.form, "10-K");
+    assert_eq!(
+        filing.filing_date,
+        NaiveDate::from_ymd_opt(1994, 12, 13).unwrap()
+    );
 }
 
 #[test]
@@ -35,4 +130,151 @@ fn parse_directory_response() {
     let first_item = &dir.directory.item[0];
     assert_eq!(first_item.name, "0001140361-25-000228-index-headers.html");
     assert_eq!(first_item.type_, "text.gif");
+    assert_eq!(first_item.size, 0);
+
+    let zip = dir
+        .directory
+        .item
+        .iter()
+        .find(|item| item.name.ends_with("-xbrl.zip"))
+        .unwrap();
+    assert_eq!(zip.size, 16267);
+}
+
+#[test]
+fn submission_renames_from_former_names() {
+    let content = read_fixture("submissions/submission.json");
+    let submission: Submission = serde_json::from_str(&content).unwrap();
+
+    let renames: Vec<(String, String)> = submission
+        .corporate_events()
+        .into_iter()
+        .map(|event| match event.kind {
+            CorporateEventKind::Renamed { from, to } => (from, to),
+            other => panic!("unexpected event {:?}", other),
+        })
+        .collect();
+    assert_eq!(
+        renames.last().unwrap(),
+        &("APPLE INC".to_string(), "Apple Inc.".to_string())
+    );
+    assert!(renames.contains(&("APPLE COMPUTER INC".to_string(), "APPLE INC".to_string())));
+
+    let mut history = CorporateHistory::new();
+    let first = history.record(&submission).unwrap();
+    assert_eq!(first.len(), renames.len());
+    assert!(history.record(&submission).unwrap().is_empty());
+    assert_eq!(history.events(320193).len(), renames.len());
+}
+
+#[test]
+fn split_annual_report_sections() {
+    let content = read_fixture("documents/10-k.htm");
+    let sections = split_sections(&content, "10-K");
+
+    let items: Vec<&Item> = sections.iter().map(|s| &s.item).collect();
+    assert_eq!(
+        items,
+        vec![
+            &Item::Business,
+            &Item::RiskFactors,
+            &Item::UnresolvedStaffComments,
+            &Item::ManagementDiscussion,
+            &Item::MarketRisk,
+        ]
+    );
+
+    let risks = &sections[1];
+    assert_eq!(risks.part, Some(1));
+    assert_eq!(risks.title, "Risk Factors");
+    assert!(
+        risks
+            .text
+            .starts_with("The Company\u{2019}s business, reputation")
+    );
+    assert!(
+        risks
+            .text
+            .contains("As discussed in Item 7 of this Form 10-K")
+    );
+
+    let market_risk = &sections[4];
+    assert_eq!(market_risk.code, "7A");
+    assert_eq!(
+        market_risk.title,
+        "Quantitative and Qualitative Disclosures About Market Risk"
+    );
+    assert_eq!(
+        market_risk.text,
+        "The Company is exposed to economic risk from interest rates and foreign exchange rates."
+    );
+}
+
+#[test]
+fn annual_report_to_markdown() {
+    let content = read_fixture("documents/10-k.htm");
+    let markdown = to_markdown(&content);
+
+    assert!(markdown.starts_with("UNITED STATES SECURITIES AND EXCHANGE COMMISSION\n\nFORM 10-K"));
+    assert!(!markdown.contains("AmendmentFlag"));
+    assert!(!markdown.contains("padding"));
+    assert!(markdown.contains("| Item 1A. | Risk Factors | 5 |"));
+    assert!(markdown.contains("\n\nItem 1A. Risk Factors\n\nThe Company\u{2019}s business"));
+}
+
+#[test]
+fn parse_inline_xbrl_facts() {
+    let content = read_fixture("documents/ixbrl-10q.htm");
+    let facts = parse_inline_xbrl(&content);
+    assert_eq!(facts.len(), 9);
+
+    assert_eq!(facts[0].name, "dei:AmendmentFlag");
+    assert_eq!(facts[0].text, "false");
+    assert!(!facts[0].numeric);
+
+    let product = &facts[2];
+    assert_eq!(
+        product.local_name(),
+        "RevenueFromContractWithCustomerExcludingAssessedTax"
+    );
+    assert_eq!(product.value, Some(96_458_000_000.0));
+    assert_eq!(
+        product.dimensions,
+        vec![(
+            "srt:ProductOrServiceAxis".to_string(),
+            "us-gaap:ProductMember".to_string()
+        )]
+    );
+    assert_eq!(
+        product.period,
+        Some(InlinePeriod::Duration {
+            start: NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+            end: NaiveDate::from_ymd_opt(2023, 12, 30).unwrap(),
+        })
+    );
+    assert!(facts[3].dimensions.is_empty());
+    assert_eq!(facts[4].value, Some(-50_000_000.0));
+    assert_eq!(facts[5].value, Some(2.18));
+    assert_eq!(facts[5].unit.as_deref(), Some("USD/shares"));
+    assert_eq!(facts[6].value, Some(0.0));
+
+    let shares = &facts[7];
+    assert!(shares.is_nil);
+    assert_eq!(shares.value, None);
+    assert_eq!(shares.unit.as_deref(), Some("shares"));
+    assert_eq!(
+        shares.period,
+        Some(InlinePeriod::Instant(
+            NaiveDate::from_ymd_opt(2023, 12, 30).unwrap()
+        ))
+    );
+
+    let policy = &facts[8];
+    assert!(policy.text.starts_with("Basis of Presentation"));
+    assert!(
+        policy
+            .text
+            .ends_with("current period\u{2019}s presentation.")
+    );
+    assert!(!policy.text.contains("page 7"));
 }
@@ -37,7 +37,7 @@ fn parse_quarter_index() {
         .unwrap();
 
     assert_eq!(format!("{:?}", item.type_), "File");
-    assert_eq!(item.size, "52453 KB");
+    assert_eq!(item.size, 52453 * 1024);
 }
 
 #[test]
@@ -56,7 +56,7 @@ fn parse_daily_index() {
 
     assert_eq!(format!("{:?}", year_2023.type_), "Dir");
     assert_eq!(year_2023.href, "2023/");
-    assert_eq!(year_2023.size, "743909 KB");
+    assert_eq!(year_2023.size, 743909 * 1024);
 }
 
 #[test]
@@ -82,5 +82,5 @@ fn parse_daily_index_year() {
 
     assert_eq!(format!("{:?}", qtr1.type_), "Dir");
     assert_eq!(qtr1.href, "QTR1/");
-    assert_eq!(qtr1.size, "16 KB");
+    assert_eq!(qtr1.size, 16 * 1024);
 }
@@ -1,5 +1,6 @@
 mod common;
 
+use chrono::NaiveDate;
 use common::read_fixture;
 use edgarkit::parsing::index::{IndexConfig, IndexParser, IndexType};
 use std::io::BufReader;
@@ -8,6 +9,7 @@ const MASTER_INDEX_FIXTURE: &str = "indexes/master.idx";
 const COMPANY_INDEX_FIXTURE: &str = "indexes/company.idx";
 const XBRL_INDEX_FIXTURE: &str = "indexes/xbrl.idx";
 const CRAWLER_INDEX_FIXTURE: &str = "indexes/crawler.idx";
+const FORM_INDEX_FIXTURE: &str = "indexes/form.idx";
 
 #[test]
 fn parse_company_index_fixture() {
@@ -21,7 +23,11 @@ fn parse_company_index_fixture() {
     assert_eq!(first.company_name.trim(), "3J LLC");
     assert_eq!(first.form_type.trim(), "D");
     assert_eq!(first.cik, 1975393);
-    assert_eq!(first.date_filed.trim(), "20230703");
+    assert_eq!(
+        first.date_filed,
+        NaiveDate::from_ymd_opt(2023, 7, 3).unwrap()
+    );
+    assert_eq!(first.date_filed_raw, "20230703");
 
     assert!(
         first
@@ -43,7 +49,11 @@ fn parse_crawler_index_fixture() {
     assert_eq!(first.company_name.trim(), "3J LLC");
     assert_eq!(first.form_type.trim(), "D");
     assert_eq!(first.cik, 1975393);
-    assert_eq!(first.date_filed.trim(), "20230703");
+    assert_eq!(
+        first.date_filed,
+        NaiveDate::from_ymd_opt(2023, 7, 3).unwrap()
+    );
+    assert_eq!(first.date_filed_raw, "20230703");
 }
 
 #[test]
@@ -59,7 +69,11 @@ fn parse_xbrl_index_fixture() {
     assert_eq!(first.cik, 1000045);
     assert_eq!(first.company_name.trim(), "NICHOLAS FINANCIAL INC");
     assert_eq!(first.form_type.trim(), "10-Q");
-    assert_eq!(first.date_filed.trim(), "2023-02-14");
+    assert_eq!(
+        first.date_filed,
+        NaiveDate::from_ymd_opt(2023, 2, 14).unwrap()
+    );
+    assert_eq!(first.date_filed_raw, "2023-02-14");
 }
 
 #[test]
@@ -103,3 +117,61 @@ fn parse_master_index_fixture() {
             .starts_with("https://www.sec.gov/Archives/edgar/data/")
     );
 }
+
+#[test]
+fn parse_form_index_fixture() {
+    let content = read_fixture(FORM_INDEX_FIXTURE);
+    let parser = IndexParser::new(IndexConfig {
+        max_entries: Some(5),
+        ..Default::default()
+    });
+
+    let entries = parser.parse(BufReader::new(content.as_bytes())).unwrap();
+    let first = &entries[0];
+    assert_eq!(first.form_type, "1-A");
+    assert_eq!(first.company_name, "Almco Plumbing Inc");
+    assert_eq!(first.cik, 1956237);
+    assert_eq!(
+        first.date_filed,
+        NaiveDate::from_ymd_opt(2023, 3, 24).unwrap()
+    );
+    assert_eq!(
+        first.url,
+        "https://www.sec.gov/Archives/edgar/data/1956237/0001607062-23-000146.txt"
+    );
+}
+
+#[test]
+fn parse_form_index_with_form_filter() {
+    let content = read_fixture(FORM_INDEX_FIXTURE);
+    let parser = IndexParser::new(IndexConfig {
+        index_type: Some(IndexType::Form),
+        form_types: Some(vec!["10-K".to_string(), "10-K/A".to_string()]),
+        ..Default::default()
+    });
+
+    let entries = parser.parse(BufReader::new(content.as_bytes())).unwrap();
+    assert_eq!(entries.len(), 5731 + 167);
+    assert!(
+        entries
+            .iter()
+            .all(|e| e.form_type == "10-K" || e.form_type == "10-K/A")
+    );
+    assert_eq!(entries[0].company_name, "10x Genomics, Inc.");
+}
+
+#[test]
+fn parse_iter_yields_entries_lazily() {
+    let content = read_fixture(MASTER_INDEX_FIXTURE);
+    let parser = IndexParser::new(IndexConfig::default());
+
+    let first: Vec<_> = parser
+        .parse_iter(BufReader::new(content.as_bytes()))
+        .unwrap()
+        .take(3)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    let all = parser.parse(BufReader::new(content.as_bytes())).unwrap();
+    assert_eq!(first.len(), 3);
+    assert_eq!(first[2].url, all[2].url);
+}
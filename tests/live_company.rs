@@ -16,6 +16,15 @@ async fn company_cik_not_found() {
     assert!(matches!(result, Err(EdgarError::TickerNotFound)));
 }
 
+#[tokio::test]
+#[ignore]
+async fn refresh_tickers() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let count = edgar.refresh_tickers().await.unwrap();
+    assert!(count > 1000);
+    assert_eq!(edgar.company_cik("MSFT").await.unwrap(), 789019);
+}
+
 #[tokio::test]
 #[ignore]
 async fn mutual_fund_cik() {
@@ -37,7 +46,7 @@ async fn mutual_fund_cik_not_found() {
 async fn company_facts_not_found() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
     let result = edgar.company_facts(0).await;
-    assert!(matches!(result, Err(EdgarError::NotFound)));
+    assert!(matches!(result, Err(EdgarError::NotFound(_))));
 }
 
 #[tokio::test]
@@ -51,3 +60,15 @@ async fn company_concept() {
     assert_eq!(concept.taxonomy, "dei");
     assert_eq!(concept.tag, "EntityCommonStockSharesOutstanding");
 }
+
+#[tokio::test]
+#[ignore]
+async fn bootstrap_resolves_offline() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let boot = edgar.bootstrap().await.unwrap();
+
+    assert!(!boot.tickers().is_empty());
+    assert!(!boot.mutual_fund_tickers().is_empty());
+    assert_eq!(boot.resolve("AAPL").unwrap().as_u64(), 320193);
+    assert!(boot.exchange("AAPL").is_some());
+}
@@ -10,7 +10,7 @@ async fn rate_limiting_and_backoff() {
         let result = edgar.get(url).await;
         match result {
             Ok(_) => {}
-            Err(EdgarError::RateLimitExceeded) => {
+            Err(EdgarError::RateLimitExceeded(_)) => {
                 assert!(i > 5);
                 break;
             }
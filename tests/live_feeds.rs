@@ -24,7 +24,7 @@ async fn current_feed_with_options() {
 #[ignore]
 async fn company_feed() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
-    let feed = edgar.company_feed("320193", None).await.unwrap();
+    let feed = edgar.company_feed(320193, None).await.unwrap();
     assert!(!feed.entries.is_empty());
 }
 
@@ -36,7 +36,7 @@ async fn company_feed_with_options() {
         .with_param("count", "10")
         .with_param("type", "10-K");
 
-    let feed = edgar.company_feed("320193", Some(params)).await.unwrap();
+    let feed = edgar.company_feed(320193, Some(params)).await.unwrap();
     assert!(!feed.entries.is_empty());
 }
 
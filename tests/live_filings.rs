@@ -6,20 +6,18 @@ async fn latest_filing_content() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
 
     let filing_content = edgar
-        .get_latest_filing_content("320193", &["10-K"])
+        .get_latest_filing_content(320193, &["10-K"])
         .await
         .unwrap();
 
     assert!(!filing_content.is_empty());
     assert!(filing_content.len() > 1000);
 
-    let invalid_result = edgar.get_latest_filing_content("000000", &["10-K"]).await;
-    assert!(matches!(invalid_result, Err(EdgarError::NotFound)));
+    let invalid_result = edgar.get_latest_filing_content(0, &["10-K"]).await;
+    assert!(matches!(invalid_result, Err(EdgarError::NotFound(_))));
 
-    let invalid_form = edgar
-        .get_latest_filing_content("320193", &["INVALID"])
-        .await;
-    assert!(matches!(invalid_form, Err(EdgarError::NotFound)));
+    let invalid_form = edgar.get_latest_filing_content(320193, &["INVALID"]).await;
+    assert!(matches!(invalid_form, Err(EdgarError::NotFound(_))));
 }
 
 #[tokio::test]
@@ -29,7 +27,7 @@ async fn get_text_filing_links() {
 
     let opts = FilingOptions::new().with_limit(3);
     let filing_links = edgar
-        .get_text_filing_links("320193", Some(opts))
+        .get_text_filing_links(320193, Some(opts))
         .await
         .unwrap();
 
@@ -56,7 +54,7 @@ async fn get_text_filing_links() {
 
     let form_opts = FilingOptions::new().with_form_type("10-K").with_limit(2);
     let form_filing_links = edgar
-        .get_text_filing_links("320193", Some(form_opts))
+        .get_text_filing_links(320193, Some(form_opts))
         .await
         .unwrap();
 
@@ -66,7 +64,7 @@ async fn get_text_filing_links() {
 
     let invalid_form_opts = FilingOptions::new().with_form_type("INVALID_FORM_TYPE");
     let invalid_form_result = edgar
-        .get_text_filing_links("320193", Some(invalid_form_opts))
+        .get_text_filing_links(320193, Some(invalid_form_opts))
         .await
         .unwrap();
     assert!(invalid_form_result.is_empty());
@@ -79,7 +77,7 @@ async fn get_sgml_header_links() {
 
     let opts = FilingOptions::new().with_limit(3);
     let filing_links = edgar
-        .get_sgml_header_links("320193", Some(opts))
+        .get_sgml_header_links(320193, Some(opts))
         .await
         .unwrap();
 
@@ -99,7 +97,7 @@ async fn get_sgml_header_links() {
 async fn filings_with_form_type() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
     let opts = FilingOptions::new().with_form_type("10-K");
-    let filings = edgar.filings("320193", Some(opts)).await.unwrap();
+    let filings = edgar.filings(320193, Some(opts)).await.unwrap();
     assert!(filings.iter().all(|f| f.form == "10-K"));
 }
 
@@ -108,7 +106,7 @@ async fn filings_with_form_type() {
 async fn filings_with_limit() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
     let opts = FilingOptions::new().with_limit(1);
-    let filings = edgar.filings("320193", Some(opts)).await.unwrap();
+    let filings = edgar.filings(320193, Some(opts)).await.unwrap();
     assert_eq!(filings.len(), 1);
 }
 
@@ -116,9 +114,9 @@ async fn filings_with_limit() {
 #[ignore]
 async fn filings_with_offset() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
-    let all_filings = edgar.filings("320193", None).await.unwrap();
+    let all_filings = edgar.filings(320193, None).await.unwrap();
     let opts = FilingOptions::new().with_offset(1);
-    let offset_filings = edgar.filings("320193", Some(opts)).await.unwrap();
+    let offset_filings = edgar.filings(320193, Some(opts)).await.unwrap();
     assert_eq!(offset_filings.len(), all_filings.len() - 1);
 }
 
@@ -126,14 +124,53 @@ async fn filings_with_offset() {
 #[ignore]
 async fn submissions_live() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
-    let submissions = edgar.submissions("320193").await.unwrap();
+    let submissions = edgar.submissions(320193).await.unwrap();
     assert_eq!(submissions.name, "Apple Inc.");
 }
 
+#[tokio::test]
+#[ignore]
+async fn submission_file_live() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let submission = edgar.submissions(320193).await.unwrap();
+    let file = &submission.filings.files[0];
+    let page = edgar.get_submission_file(320193, &file.name).await.unwrap();
+    assert_eq!(page.accession_number.len() as u64, file.filing_count);
+}
+
 #[tokio::test]
 #[ignore]
 async fn submissions_not_found() {
     let edgar = Edgar::new("test_agent example@example.com").unwrap();
-    let result = edgar.submissions("0").await;
-    assert!(matches!(result, Err(EdgarError::NotFound)));
+    let result = edgar.submissions(0).await;
+    assert!(matches!(result, Err(EdgarError::NotFound(_))));
+}
+
+#[tokio::test]
+#[ignore]
+async fn filing_handle_documents() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let filing = edgar.filing(320193, "0000320193-24-000123").await.unwrap();
+    assert_eq!(filing.form(), "10-K");
+
+    let html = filing.primary_document().await.unwrap();
+    assert!(html.contains("Apple"));
+    assert!(!filing.exhibits().await.unwrap().is_empty());
+    assert!(filing.xbrl().await.unwrap().is_some());
+}
+
+#[tokio::test]
+#[ignore]
+async fn company_handle() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let apple = edgar.company("AAPL").await.unwrap();
+    assert_eq!(apple.cik().as_u64(), 320193);
+
+    let latest = apple.latest("10-K").await.unwrap().unwrap();
+    assert!(latest.form().starts_with("10-K"));
+
+    let revenue = apple
+        .concept("RevenueFromContractWithCustomerExcludingAssessedTax")
+        .await;
+    assert!(revenue.is_ok());
 }
@@ -69,3 +69,42 @@ async fn daily_index_current_year() {
     let result = edgar.daily_index(None).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+#[ignore]
+async fn get_filings_between_skips_weekends_and_holidays() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+
+    // Spans a weekend and Independence Day, and crosses into Q3.
+    let start = EdgarDay::new(2023, 6, 30).unwrap();
+    let end = EdgarDay::new(2023, 7, 5).unwrap();
+    let entries = edgar.get_filings_between(start, end, None).await.unwrap();
+    assert!(!entries.is_empty());
+    let range = start.to_naive_date().unwrap()..=end.to_naive_date().unwrap();
+    assert!(entries.iter().all(|e| range.contains(&e.date_filed)));
+
+    let limited = edgar
+        .get_filings_between(start, end, Some(FilingOptions::new().with_limit(5)))
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 5);
+}
+
+#[tokio::test]
+#[ignore]
+async fn index_entry_directory_lists_filing_folder() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+
+    let entries = edgar
+        .get_daily_filings(
+            EdgarDay::new(2023, 8, 1).unwrap(),
+            Some(FilingOptions::new().with_limit(1)),
+        )
+        .await
+        .unwrap();
+    let entry = &entries[0];
+    let accession = entry.accession_number().unwrap();
+    let directory = edgar.index_entry_directory(entry).await.unwrap();
+    assert!(directory.name.contains(&accession.replace('-', "")));
+    assert!(!directory.item.is_empty());
+}
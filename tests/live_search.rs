@@ -30,8 +30,7 @@ async fn search_with_date_range() {
     let response = edgar.search(options).await.unwrap();
 
     for hit in response.hits.hits {
-        let file_date =
-            chrono::NaiveDate::parse_from_str(&hit._source.file_date, "%Y-%m-%d").unwrap();
+        let file_date = hit._source.file_date;
         assert!(file_date >= chrono::NaiveDate::from_ymd_opt(2023, 1, 1).unwrap());
         assert!(file_date <= chrono::NaiveDate::from_ymd_opt(2023, 12, 31).unwrap());
     }
@@ -86,3 +85,24 @@ async fn search_all_with_small_result_set() {
     let results = edgar.search_all(options).await.unwrap();
     assert!(!results.is_empty());
 }
+
+#[tokio::test]
+#[ignore]
+async fn entity_search_live() {
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let matches = edgar.entity_search("Berkshire Hathaway").await.unwrap();
+    assert!(matches.iter().any(|m| m.cik == 1067983));
+}
+
+#[tokio::test]
+#[ignore]
+async fn search_stream_stops_early() {
+    use futures_util::StreamExt;
+
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+    let options = SearchOptions::new().with_forms(vec!["8-K".to_string()]);
+
+    let hits: Vec<_> = edgar.search_stream(options).take(150).collect().await;
+    assert_eq!(hits.len(), 150);
+    assert!(hits.iter().all(|hit| hit.is_ok()));
+}
@@ -0,0 +1,168 @@
+#![cfg(feature = "mirror")]
+
+use edgarkit::{
+    Edgar, EdgarConfig, EdgarDay, EdgarError, EdgarPeriod, FilingOptions, IndexOperations, Quarter,
+};
+use futures_util::StreamExt;
+use std::fs;
+use std::path::PathBuf;
+
+/// Master index rows in the daily file format.
+const DAILY_MASTER: &str = "\
+Description:           Daily Index of EDGAR Dissemination Feed by Company Name
+Last Data Received:    July 3, 2023
+
+CIK|Company Name|Form Type|Date Filed|File Name
+--------------------------------------------------------------------------------
+1000045|NICHOLAS FINANCIAL INC|8-K|20230703|edgar/data/1000045/0000950170-23-031640.txt
+1000209|MEDALLION FINANCIAL CORP|4|20230703|edgar/data/1000209/0001209191-23-040147.txt
+320193|Apple Inc.|8-K|20230703|edgar/data/320193/0000320193-23-000070.txt
+";
+
+fn mirror_root(name: &str) -> PathBuf {
+    let root =
+        std::env::temp_dir().join(format!("edgarkit-mirror-{}-{}", name, std::process::id()));
+    let quarter = root.join("www.sec.gov/Archives/edgar/daily-index/2023/QTR3");
+    fs::create_dir_all(&quarter).unwrap();
+    fs::write(quarter.join("master.20230703.idx"), DAILY_MASTER).unwrap();
+    let full = root.join("www.sec.gov/Archives/edgar/full-index/2023/QTR3");
+    fs::create_dir_all(&full).unwrap();
+    fs::write(full.join("master.idx"), DAILY_MASTER).unwrap();
+    root
+}
+
+#[tokio::test]
+async fn daily_filings_from_mirror() {
+    let root = mirror_root("daily");
+    let edgar = Edgar::with_config(EdgarConfig::default().with_mirror(&root)).unwrap();
+
+    let day = EdgarDay::new(2023, 7, 3).unwrap();
+    let entries = edgar
+        .get_daily_filings(day, Some(FilingOptions::new().with_form_type("8-K")))
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 2);
+    assert!(entries.iter().all(|e| e.form_type.trim() == "8-K"));
+
+    let missing = edgar
+        .get_daily_filings(EdgarDay::new(2023, 7, 5).unwrap(), None)
+        .await;
+    assert!(matches!(missing, Err(EdgarError::NotFound(_))));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn query_urls_are_not_mirrored() {
+    let root = mirror_root("query");
+    let edgar = Edgar::with_config(EdgarConfig::default().with_mirror(&root)).unwrap();
+
+    let result = edgar
+        .get("https://efts.sec.gov/LATEST/search-index/?q=revenue")
+        .await;
+    assert!(matches!(result, Err(EdgarError::NotFound(_))));
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn stream_period_filings_from_mirror() {
+    let root = mirror_root("stream");
+    let edgar = Edgar::with_config(EdgarConfig::default().with_mirror(&root)).unwrap();
+
+    let period = EdgarPeriod::new(2023, Quarter::Q3).unwrap();
+    let opts = FilingOptions::new().with_form_type("8-K").with_limit(1);
+    let entries: Vec<_> = edgar
+        .stream_period_filings(period, Some(opts))
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].as_ref().unwrap().cik, 1000045);
+
+    let all = edgar
+        .stream_period_filings(period, Some(FilingOptions::new().with_cik(320193)))
+        .await
+        .unwrap()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(all.len(), 1);
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[cfg(feature = "pipeline")]
+#[tokio::test]
+async fn pipeline_backfill_from_mirror() {
+    use edgarkit::{FilingRecord, Pipeline};
+
+    let root = mirror_root("pipeline");
+    let files = root.join("www.sec.gov/files");
+    fs::create_dir_all(&files).unwrap();
+    fs::write(
+        files.join("company_tickers_exchange.json"),
+        r#"{"fields":["cik","name","ticker","exchange"],"data":[[320193,"Apple Inc.","AAPL","Nasdaq"]]}"#,
+    )
+    .unwrap();
+    let edgar = Edgar::with_config(EdgarConfig::default().with_mirror(&root)).unwrap();
+
+    let period = EdgarPeriod::new(2023, Quarter::Q3).unwrap();
+    let mut pipeline =
+        Pipeline::new(&edgar).with_options(FilingOptions::new().with_form_type("8-K"));
+    let mut sink: Vec<FilingRecord> = Vec::new();
+    let summary = pipeline.backfill(period, period, &mut sink).await.unwrap();
+    assert_eq!(summary.delivered, 2);
+    assert_eq!(sink[1].accession_number, "0000320193-23-000070");
+    assert_eq!(sink[1].tickers, vec!["AAPL"]);
+
+    // A second run delivers nothing new.
+    let again = pipeline.backfill(period, period, &mut sink).await.unwrap();
+    assert_eq!((again.delivered, again.skipped), (0, 2));
+    assert_eq!(sink.len(), 2);
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[tokio::test]
+async fn frames_range_from_mirror() {
+    use edgarkit::{CompanyOperations, FramePeriod};
+
+    let root = mirror_root("frames");
+    let frames = root.join("data.sec.gov/api/xbrl/frames/us-gaap/AccountsPayableCurrent/USD");
+    fs::create_dir_all(&frames).unwrap();
+    let frame = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/tickers/frames.json"
+    ))
+    .unwrap();
+    fs::write(frames.join("CY2019Q1I.json"), &frame).unwrap();
+    fs::write(
+        frames.join("CY2019Q3I.json"),
+        frame.replace("CY2019Q1I", "CY2019Q3I"),
+    )
+    .unwrap();
+    let edgar = Edgar::with_config(EdgarConfig::default().with_mirror(&root)).unwrap();
+
+    let panel = edgar
+        .frames_range(
+            "us-gaap",
+            "AccountsPayableCurrent",
+            "USD",
+            FramePeriod::quarterly(2019, Quarter::Q1).instantaneous(),
+            FramePeriod::quarterly(2019, Quarter::Q3).instantaneous(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(panel.periods, vec!["CY2019Q1I", "CY2019Q2I", "CY2019Q3I"]);
+    assert_eq!(panel.missing, vec!["CY2019Q2I"]);
+    let series = panel.series(1750u64);
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[1].0, "CY2019Q3I");
+    assert_eq!(
+        panel.get("CY2019Q1I", 1750u64).unwrap().accn,
+        "0001104659-19-016320"
+    );
+
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,837 @@
+#![cfg(feature = "test-util")]
+
+mod common;
+
+use common::{fixture_path, read_fixture};
+use edgarkit::parsing::filing_summary::{ReportCategory, find_report};
+use edgarkit::parsing::rss::{RssConfig, RssParser};
+use edgarkit::test_util::MockTransport;
+use edgarkit::{
+    Cik, CompanyOperations, CurrentFeedOptions, Edgar, EdgarConfig, EdgarError, EdgarPeriod,
+    EnrichStream, Enricher, EntityId, FeedOperations, FeedOptions, FilingManifest,
+    FilingOperations, FilingOptions, FundOperations, IndexOperations, ItemCode, MetricsRegistry,
+    Quarter, SearchOperations, SearchOptions, SeenSet,
+};
+use futures_util::StreamExt;
+use std::sync::Arc;
+use std::time::Duration;
+
+const SUBMISSIONS_URL: &str = "https://data.sec.gov/submissions/CIK0000320193.json";
+const FACTS_URL: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json";
+const SEARCH_URL: &str = "https://efts.sec.gov/LATEST/search-index/?q=merger";
+const PROXY_URL: &str =
+    "https://www.sec.gov/Archives/edgar/data/320193/000130817925000008/aapl4359751-def14a.htm";
+const SCHEDULE_13G_URL: &str =
+    "https://www.sec.gov/Archives/edgar/data/320193/000119312524036431/d751537dsc13ga.htm";
+const FORM_D_URL: &str =
+    "https://www.sec.gov/Archives/edgar/data/1819404/000181940424000002/primary_doc.xml";
+const TICKERS_URL: &str = "https://www.sec.gov/files/company_tickers.json";
+const NPORT_URL: &str =
+    "https://www.sec.gov/Archives/edgar/data/36405/000175272424270839/primary_doc.xml";
+
+fn transport() -> Arc<MockTransport> {
+    Arc::new(
+        MockTransport::new()
+            .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json"))
+            .with_fixture(FACTS_URL, fixture_path("tickers/companyfacts.json"))
+            .with_response(SEARCH_URL, read_fixture("search/search-index.json")),
+    )
+}
+
+#[tokio::test]
+async fn operations_run_against_mock_transport() {
+    let transport = transport();
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let submission = edgar.submissions(320193).await.unwrap();
+    assert_eq!(submission.name, "Apple Inc.");
+
+    let facts = edgar.company_facts(320193).await.unwrap();
+    assert_eq!(facts.entity_name, "Apple Inc.");
+
+    let results = edgar
+        .search(SearchOptions::new().with_query("merger"))
+        .await
+        .unwrap();
+    assert_eq!(results.hits.total.value, 146);
+
+    assert_eq!(
+        transport.requests(),
+        vec![SUBMISSIONS_URL, FACTS_URL, SEARCH_URL]
+    );
+}
+
+#[tokio::test]
+async fn mock_statuses_map_to_errors() {
+    let transport = MockTransport::new()
+        .with_status(SUBMISSIONS_URL, 503, "Service Unavailable")
+        .with_status(FACTS_URL, 429, "");
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    assert!(matches!(
+        edgar.submissions(320193).await,
+        Err(EdgarError::UnexpectedStatus { status: 503, .. })
+    ));
+    assert!(matches!(
+        edgar.company_facts(320193).await,
+        Err(EdgarError::RateLimitExceeded(_))
+    ));
+    let missing = edgar
+        .company_concept(320193, "us-gaap", "Assets")
+        .await
+        .unwrap_err();
+    assert!(matches!(missing, EdgarError::NotFound(_)));
+    let context = missing.context();
+    assert_eq!(context.status, Some(404));
+    assert_eq!(context.endpoint, Some("companyconcept"));
+    assert_eq!(context.cik, Some(Cik::new(320193)));
+    assert!(
+        context
+            .url
+            .unwrap()
+            .ends_with("CIK0000320193/us-gaap/Assets.json")
+    );
+}
+
+#[tokio::test]
+async fn rate_threshold_page_is_a_throttle() {
+    let transport = MockTransport::new()
+        .with_status(
+            SUBMISSIONS_URL,
+            403,
+            "<html><head><title>SEC.gov | Request Rate Threshold Exceeded</title></head></html>",
+        )
+        .with_status(FACTS_URL, 403, "<html><title>Access Denied</title></html>");
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    assert!(matches!(
+        edgar.submissions(320193).await,
+        Err(EdgarError::RateLimitExceeded(_))
+    ));
+    assert!(matches!(
+        edgar.company_facts(320193).await,
+        Err(EdgarError::UnexpectedStatus { status: 403, .. })
+    ));
+}
+
+#[tokio::test]
+async fn metrics_count_transport_requests() {
+    let registry = Arc::new(MetricsRegistry::new());
+    let config = EdgarConfig::default()
+        .with_transport(transport())
+        .with_metrics(registry.clone());
+    let edgar = Edgar::with_config(config).unwrap();
+
+    edgar.submissions(320193).await.unwrap();
+    assert!(
+        edgar
+            .company_concept(320193, "us-gaap", "Assets")
+            .await
+            .is_err()
+    );
+
+    let snapshot = registry.snapshot();
+    assert_eq!(snapshot.requests, 2);
+    let submissions = &snapshot.endpoints["submissions"];
+    assert_eq!(submissions.statuses[&200], 1);
+    assert_eq!(submissions.latency.count(), 1);
+    assert!(submissions.bytes > 0);
+    assert_eq!(snapshot.endpoints["companyconcept"].statuses[&404], 1);
+}
+
+#[tokio::test]
+async fn download_to_file_writes_complete_file_only() {
+    let edgar = Edgar::with_transport(transport()).unwrap();
+    let dir = std::env::temp_dir().join(format!("edgarkit-download-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = dir.join("submission.json");
+    let written = edgar
+        .download_to_file(SUBMISSIONS_URL, &path)
+        .await
+        .unwrap();
+    assert_eq!(written, std::fs::metadata(&path).unwrap().len());
+    assert!(!dir.join("submission.json.part").exists());
+
+    let missing = dir.join("concept.json");
+    assert!(matches!(
+        edgar
+            .download_to_file(
+                "https://data.sec.gov/api/xbrl/companyconcept/missing.json",
+                &missing
+            )
+            .await,
+        Err(EdgarError::NotFound(_))
+    ));
+    assert!(!missing.exists());
+    assert!(!dir.join("concept.json.part").exists());
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn filings_filter_by_8k_item() {
+    let edgar = Edgar::with_transport(transport()).unwrap();
+
+    let opts = FilingOptions::new()
+        .with_form_type("8-K")
+        .with_items(["5.02"]);
+    let officer_changes = edgar.filings(320193, Some(opts)).await.unwrap();
+    assert_eq!(officer_changes.len(), 14);
+    assert!(officer_changes.iter().all(|filing| {
+        filing
+            .item_codes()
+            .contains(&ItemCode::OfficerOrDirectorChange)
+    }));
+
+    let opts = FilingOptions::new().with_items([ItemCode::ResultsOfOperations]);
+    let earnings = edgar.filings(320193, Some(opts)).await.unwrap();
+    assert_eq!(earnings.len(), 44);
+    assert!(earnings.iter().all(|filing| filing.form == "8-K"));
+}
+
+#[tokio::test]
+async fn latest_proxy_compensation_table() {
+    let transport = MockTransport::new()
+        .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json"))
+        .with_fixture(PROXY_URL, fixture_path("documents/def14a.htm"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let proxy = edgar.get_latest_proxy(320193).await.unwrap();
+    assert_eq!(proxy.filing.accession_number, "0001308179-25-000008");
+    assert_eq!(proxy.url, PROXY_URL);
+
+    let rows = proxy.summary_compensation();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1].name, "Tim Cook");
+    assert_eq!(rows[1].title.as_deref(), Some("Chief Executive Officer"));
+    assert_eq!(rows[1].year, 2023);
+    assert_eq!(rows[1].salary, Some(3_000_000.0));
+    assert_eq!(rows[1].bonus, None);
+    assert_eq!(rows[1].stock_awards, Some(46_970_283.0));
+    assert_eq!(rows[1].non_equity_incentive, Some(10_713_450.0));
+    assert_eq!(rows[1].total, Some(63_209_845.0));
+    assert_eq!(rows[2].name, "Luca Maestri");
+    assert_eq!(
+        rows[2].title.as_deref(),
+        Some("Senior Vice President, Chief Financial Officer")
+    );
+}
+
+#[tokio::test]
+async fn beneficial_ownership_cover_pages() {
+    let transport = MockTransport::new()
+        .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json"))
+        .with_fixture(SCHEDULE_13G_URL, fixture_path("documents/sc13ga.htm"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let filings = edgar
+        .get_beneficial_ownership_filings(320193)
+        .await
+        .unwrap();
+    assert_eq!(filings.len(), 25);
+    assert!(filings.iter().all(|f| f.form.starts_with("SC 13G")));
+
+    let report = edgar
+        .get_beneficial_ownership(320193, "0001193125-24-036431")
+        .await
+        .unwrap();
+    assert_eq!(report.issuer.as_deref(), Some("Apple Inc."));
+    assert_eq!(report.cusip.as_deref(), Some("037833100"));
+    assert_eq!(report.purpose, None);
+    assert_eq!(report.reporting_persons.len(), 2);
+
+    let berkshire = &report.reporting_persons[1];
+    assert_eq!(berkshire.name, "Berkshire Hathaway Inc.");
+    assert_eq!(berkshire.citizenship.as_deref(), Some("State of Delaware"));
+    assert_eq!(berkshire.sole_voting_power, None);
+    assert_eq!(berkshire.shared_voting_power, Some(905_560_000.0));
+    assert_eq!(berkshire.sole_dispositive_power, None);
+    assert_eq!(berkshire.aggregate_amount, Some(905_560_000.0));
+    assert_eq!(berkshire.percent_of_class, Some(5.9));
+    assert_eq!(berkshire.person_type.as_deref(), Some("HC, CO"));
+    assert_eq!(
+        report.reporting_persons[0].person_type.as_deref(),
+        Some("IN")
+    );
+
+    assert!(matches!(
+        edgar
+            .get_beneficial_ownership(320193, "0001308179-25-000008")
+            .await,
+        Err(EdgarError::InvalidFormat(_))
+    ));
+}
+
+#[tokio::test]
+async fn nport_holdings() {
+    let transport =
+        MockTransport::new().with_fixture(NPORT_URL, fixture_path("nport/primary_doc.xml"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let report = edgar
+        .get_nport_holdings(36405, "0001752724-24-270839")
+        .await
+        .unwrap();
+    assert_eq!(
+        report.fund.series_name.as_deref(),
+        Some("Vanguard 500 Index Fund")
+    );
+    assert_eq!(report.fund.series_id.as_deref(), Some("S000002839"));
+    assert_eq!(report.fund.net_assets, Some(1_360_947_167_974.45));
+    assert_eq!(
+        report.fund.period_date,
+        chrono::NaiveDate::from_ymd_opt(2024, 9, 30)
+    );
+
+    assert_eq!(report.holdings.len(), 3);
+    let apple = &report.holdings[0];
+    assert_eq!(apple.name, "Apple Inc");
+    assert_eq!(apple.cusip.as_deref(), Some("037833100"));
+    assert_eq!(apple.isin.as_deref(), Some("US0378331005"));
+    assert_eq!(apple.balance, Some(415_946_215.0));
+    assert_eq!(apple.percent_value, Some(7.121178052931));
+
+    let future = &report.holdings[2];
+    assert_eq!(future.name, "S&P 500 E-MINI FUTURES");
+    assert_eq!(future.lei, None);
+    assert_eq!(future.cusip, None);
+    assert_eq!(future.units.as_deref(), Some("NC"));
+}
+
+#[tokio::test]
+async fn fund_series_and_classes() {
+    let transport = MockTransport::new()
+        .with_fixture(
+            "https://www.sec.gov/files/company_tickers_mf.json",
+            fixture_path("tickers/company_tickers_mf.json"),
+        )
+        .with_fixture(
+            "https://data.sec.gov/submissions/CIK0000002110.json",
+            fixture_path("submissions/submission-fund.json"),
+        );
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let series = edgar.fund_series(2110).await.unwrap();
+    assert_eq!(series.len(), 6);
+    assert_eq!(series[0].series_id, "S000009184");
+    assert_eq!(series[0].registrant_name, "COLUMBIA ACORN TRUST");
+    assert!(series[0].tickers().any(|ticker| ticker == "LACAX"));
+
+    let class = edgar.fund_class("c000024954").await.unwrap();
+    assert_eq!(class.series_id, "S000009184");
+    assert_eq!(class.cik, Cik::new(2110));
+    assert_eq!(class.ticker, "LACAX");
+    let missing = edgar.fund_class("C999999999").await.unwrap_err();
+    assert!(matches!(missing, EdgarError::NotFound(_)));
+    assert_eq!(
+        missing.context().lookup.as_deref(),
+        Some("class C999999999")
+    );
+}
+
+#[tokio::test]
+async fn form_d_offering() {
+    let transport =
+        MockTransport::new().with_fixture(FORM_D_URL, fixture_path("form_d/primary_doc.xml"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let form = edgar
+        .get_form_d(1_819_404, "0001819404-24-000002")
+        .await
+        .unwrap();
+    assert_eq!(form.submission_type, "D/A");
+    assert_eq!(form.issuer.name, "Northwind Robotics, Inc.");
+    assert_eq!(form.issuer.state_or_country.as_deref(), Some("TX"));
+    assert_eq!(form.issuer.year_of_incorporation, Some(2020));
+
+    assert_eq!(form.related_persons.len(), 2);
+    assert_eq!(form.related_persons[0].last_name, "Alvarez");
+    assert_eq!(
+        form.related_persons[0].relationships,
+        vec!["Executive Officer", "Director"]
+    );
+
+    let offering = &form.offering;
+    assert_eq!(offering.exemptions, vec!["06b", "3C"]);
+    assert!(offering.is_amendment);
+    assert_eq!(
+        offering.date_of_first_sale,
+        chrono::NaiveDate::from_ymd_opt(2023, 3, 14)
+    );
+    assert_eq!(offering.total_offering_amount, Some(12_000_000.0));
+    assert!(!offering.indefinite_amount);
+    assert_eq!(offering.total_amount_sold, Some(8_750_000.0));
+    assert_eq!(offering.non_accredited_investors, Some(2));
+    assert_eq!(offering.total_investors, Some(31));
+    assert_eq!(offering.finders_fees, Some(87_500.0));
+}
+
+#[tokio::test]
+async fn entity_ids_resolve_through_ticker_file() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_fixture(TICKERS_URL, fixture_path("tickers/company_tickers.json"))
+            .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let submission = edgar.submissions("AAPL").await.unwrap();
+    assert_eq!(submission.name, "Apple Inc.");
+    assert_eq!(
+        edgar.resolve_entity(EntityId::name("apple")).await.unwrap(),
+        Cik::new(320193)
+    );
+    assert_eq!(
+        edgar.resolve_entity("CIK0000789019").await.unwrap(),
+        Cik::new(789019)
+    );
+    assert!(matches!(
+        edgar.resolve_entity(EntityId::name("bank")).await,
+        Err(EdgarError::AmbiguousName { candidates, .. }) if candidates.len() > 1
+    ));
+    assert!(matches!(
+        edgar
+            .resolve_entity(EntityId::name("qqqq zzzz xxxx wwww"))
+            .await,
+        Err(EdgarError::NotFound(_))
+    ));
+
+    // The ticker file is downloaded once for both lookups.
+    assert_eq!(transport.requests(), vec![TICKERS_URL, SUBMISSIONS_URL]);
+}
+
+#[tokio::test]
+async fn company_handles_take_ciks_and_tickers() {
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_fixture(TICKERS_URL, fixture_path("tickers/company_tickers.json"))
+            .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let apple = edgar.company_by_cik(320193);
+    assert_eq!(apple.cik(), Cik::new(320193));
+    assert_eq!(edgar.company("AAPL").await.unwrap().cik(), apple.cik());
+    assert_eq!(edgar.company(320193).await.unwrap().cik(), apple.cik());
+
+    let opts = FilingOptions::new().with_form_type("10-K").with_limit(1);
+    let by_ticker = edgar
+        .filing_handles("AAPL", Some(opts.clone()))
+        .await
+        .unwrap();
+    let by_cik = edgar.filing_handles(320193, Some(opts)).await.unwrap();
+    assert_eq!(by_ticker.len(), 1);
+    assert_eq!(
+        by_ticker[0].accession_number(),
+        by_cik[0].accession_number()
+    );
+    assert_eq!(by_cik[0].cik(), apple.cik());
+}
+
+#[tokio::test]
+async fn corporate_history_is_recorded_only_when_enabled() {
+    let edgar = Edgar::with_transport(transport()).unwrap();
+    let renames = edgar
+        .company_by_cik(320193)
+        .corporate_events()
+        .await
+        .unwrap();
+    assert!(!renames.is_empty());
+    assert!(edgar.corporate_events(320193).is_empty());
+
+    let config = EdgarConfig::default()
+        .with_transport(transport())
+        .with_corporate_history(true);
+    let recording = Edgar::with_config(config).unwrap();
+    recording.submissions(320193).await.unwrap();
+    assert_eq!(recording.corporate_events(320193), renames);
+}
+
+#[tokio::test]
+async fn aggregations_only_search_drops_hits() {
+    let url = "https://efts.sec.gov/LATEST/search-index/?q=merger&count=1";
+    let transport =
+        MockTransport::new().with_response(url, read_fixture("search/search-index.json"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let response = edgar
+        .search(
+            SearchOptions::new()
+                .with_query("merger")
+                .with_aggregations_only(true),
+        )
+        .await
+        .unwrap();
+    assert!(response.hits.hits.is_empty());
+    assert_eq!(response.hits.total.value, 146);
+    assert_eq!(response.aggregations.unwrap().forms.count("8-K"), Some(55));
+}
+
+#[tokio::test]
+async fn company_feed_all_follows_start_offsets() {
+    let page = |start: u32| {
+        format!(
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcompany\
+             &CIK=0001889983&count=40&output=atom&start={}",
+            start
+        )
+    };
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_fixture(page(0), fixture_path("atom/atom1.xml"))
+            .with_fixture(page(40), fixture_path("atom/atom.xml")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let options = FeedOptions::new(None).with_count(40).unwrap();
+    let entries = edgar
+        .company_feed_all(1889983, Some(options))
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 60);
+    assert_eq!(
+        entries[0].id,
+        "urn:tag:sec.gov,2008:accession-number=0001213900-25-006497"
+    );
+    assert_eq!(transport.requests(), vec![page(0), page(40)]);
+}
+
+#[tokio::test]
+async fn recent_filings_stream_dedups_by_accession() {
+    let url = "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent\
+               &count=10&output=atom&start=0";
+    let transport =
+        Arc::new(MockTransport::new().with_fixture(url, fixture_path("atom/current.xml")));
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let options = CurrentFeedOptions::new().with_count(10).unwrap();
+    let mut filings = edgar.recent_filings_stream(options, Duration::from_millis(10));
+    let mut recent = Vec::new();
+    for _ in 0..3 {
+        recent.push(filings.next().await.unwrap().unwrap());
+    }
+    let forms: Vec<_> = recent.iter().map(|r| r.filing.form.as_str()).collect();
+    assert_eq!(forms, ["S-1", "4", "8-K"]);
+    assert_eq!(recent[1].cik, Cik::new(1843213));
+    assert_eq!(recent[1].company_name, "Rawlinson Peter");
+    assert_eq!(recent[2].filing.accession_number, "0001811210-25-000012");
+
+    // Later polls see the same page and yield nothing.
+    let next = tokio::time::timeout(Duration::from_millis(100), filings.next()).await;
+    assert!(next.is_err());
+    assert!(transport.requests().len() > 1);
+}
+
+#[tokio::test]
+async fn poll_feed_records_yielded_items_in_cursor() {
+    let url = "https://www.sec.gov/news/pressreleases.rss";
+    let transport = MockTransport::new().with_fixture(url, fixture_path("rss/pressreleases.rss"));
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+    let path = std::env::temp_dir().join(format!("edgarkit-poll-{}.seen", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let cursor = SeenSet::open(&path).unwrap();
+    let items: Vec<_> = edgar
+        .poll_feed_with_cursor(url, Duration::from_secs(3600), cursor)
+        .take(25)
+        .collect()
+        .await;
+    assert!(items.iter().all(|item| item.is_ok()));
+    let first = items[0].as_ref().unwrap().updated.unwrap();
+    let last = items[24].as_ref().unwrap().updated.unwrap();
+    assert!(first <= last);
+
+    let cursor = SeenSet::open(&path).unwrap();
+    assert_eq!(cursor.len().unwrap(), 25);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn xbrl_feed_files_download_by_type() {
+    let doc = RssParser::new(RssConfig::default())
+        .parse(&read_fixture("rss/usgaap.rss"))
+        .unwrap();
+    let filing = doc.channel.items[0].xbrl_filing.as_ref().unwrap();
+    let base = "https://www.sec.gov/Archives/edgar/data/1915657/000119312525012309/";
+    let transport = MockTransport::new()
+        .with_response(format!("{}dino-20250123.xsd", base), "<schema/>")
+        .with_response(format!("{}dino-20250123_lab.xml", base), "<linkbase/>");
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+    let dir = std::env::temp_dir().join(format!("edgarkit-xbrl-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let paths = filing
+        .download_types(&edgar, &dir, &["EX-101.SCH", "EX-101.LAB"])
+        .await
+        .unwrap();
+    assert_eq!(
+        paths,
+        vec![
+            dir.join("dino-20250123.xsd"),
+            dir.join("dino-20250123_lab.xml")
+        ]
+    );
+    assert_eq!(std::fs::read_to_string(&paths[0]).unwrap(), "<schema/>");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn available_days_lists_daily_index_files() {
+    let qtr1 = "https://www.sec.gov/Archives/edgar/daily-index/2023/QTR1/index.json";
+    let transport = Arc::new(
+        MockTransport::new().with_fixture(qtr1, fixture_path("index/daily-index-qtr.json")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let days = edgar.available_days(2023).await.unwrap();
+    assert_eq!(transport.requests().len(), 4);
+    let dates: Vec<String> = days.iter().map(|day| day.date.to_string()).collect();
+    assert_eq!(dates[0], "2023-01-03");
+    assert!(dates.contains(&"2023-01-13".to_string()));
+    assert!(!dates.contains(&"2023-01-16".to_string()));
+    assert_eq!(
+        days[0].file("company").unwrap().url,
+        "https://www.sec.gov/Archives/edgar/daily-index/2023/QTR1/company.20230103.idx"
+    );
+
+    let period = EdgarPeriod::new(2023, Quarter::Q1).unwrap();
+    assert_eq!(edgar.available_days(period).await.unwrap(), days);
+    assert!(matches!(
+        edgar.available_days(1990).await,
+        Err(EdgarError::InvalidYear)
+    ));
+}
+
+#[tokio::test]
+async fn full_history_queries_read_older_pages() {
+    let page = "https://data.sec.gov/submissions/CIK0000320193-submissions-001.json";
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_fixture(SUBMISSIONS_URL, fixture_path("submissions/submission.json"))
+            .with_fixture(page, fixture_path("submissions/submission-file.json")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let first = edgar.first_filing(320193, "10-K").await.unwrap().unwrap();
+    assert_eq!(first.accession_number, "0000320193-94-000016");
+    assert_eq!(transport.requests(), vec![SUBMISSIONS_URL, page]);
+
+    let latest = edgar.latest_filing(320193, "10-K").await.unwrap().unwrap();
+    assert!(latest.filing_date.to_string().as_str() > "2024-01-01");
+    assert_eq!(transport.requests().len(), 3);
+    assert!(edgar.latest_filing(320193, "N-1A").await.unwrap().is_none());
+
+    let all = edgar.all_filings(320193).await.unwrap();
+    assert_eq!(all.last().unwrap().accession_number, "0000320193-94-000014");
+    let counts = edgar.filing_count_by_form(320193).await.unwrap();
+    assert_eq!(counts.values().sum::<usize>(), all.len());
+    assert!(counts["10-Q"] > 1);
+}
+
+#[tokio::test]
+async fn download_filing_saves_files_and_manifest() {
+    let folder = "https://www.sec.gov/Archives/edgar/data/320193/000114036125000228";
+    let mut transport = MockTransport::new().with_fixture(
+        format!("{}/index.json", folder),
+        fixture_path("submissions/directory.json"),
+    );
+    for name in [
+        "aapl-20241230.xsd",
+        "aapl-20241230_def.xml",
+        "aapl-20241230_lab.xml",
+        "aapl-20241230_pre.xml",
+        "ef20040370_8k_htm.xml",
+        "FilingSummary.xml",
+    ] {
+        transport = transport.with_response(format!("{}/{}", folder, name), name);
+    }
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+    let dir = std::env::temp_dir().join(format!("edgarkit-filing-{}", std::process::id()));
+
+    let manifest = edgar
+        .download_filing(320193, "0001140361-25-000228", &dir, &[".XML", "xsd"])
+        .await
+        .unwrap();
+    let names: Vec<&str> = manifest.files.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names[0], "FilingSummary.xml");
+    assert_eq!(names.len(), 6);
+    assert_eq!(
+        std::fs::read_to_string(dir.join("aapl-20241230.xsd")).unwrap(),
+        "aapl-20241230.xsd"
+    );
+    assert_eq!(
+        manifest.total_size(),
+        names.iter().map(|n| n.len() as u64).sum::<u64>()
+    );
+    assert_eq!(FilingManifest::read(&dir).unwrap(), manifest);
+
+    assert!(matches!(
+        edgar
+            .download_filing(320193, "0001140361-25-000228", &dir, &[])
+            .await,
+        Err(EdgarError::NotFound(_))
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn financial_reports_from_filing_summary() {
+    let folder = "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106";
+    let transport = MockTransport::new()
+        .with_fixture(
+            format!("{}/FilingSummary.xml", folder),
+            fixture_path("documents/filing-summary.xml"),
+        )
+        .with_response(format!("{}/R5.htm", folder), "<table>balance sheet</table>");
+    let edgar = Edgar::with_transport(Arc::new(transport)).unwrap();
+
+    let reports = edgar
+        .get_financial_reports(320193, "0000320193-23-000106")
+        .await
+        .unwrap();
+    assert_eq!(reports.len(), 7);
+    assert_eq!(reports[0].category, ReportCategory::Cover);
+    assert_eq!(reports[0].position, Some(1));
+    let statements: Vec<&str> = reports
+        .iter()
+        .filter(|report| report.is_statement())
+        .map(|report| report.file_name.as_str())
+        .collect();
+    assert_eq!(statements, vec!["R3.htm", "R5.htm", "R6.htm"]);
+    assert_eq!(
+        reports[3].url.as_deref(),
+        Some(format!("{}/R5.htm", folder).as_str())
+    );
+    assert_eq!(
+        find_report(&reports, "balance sheet").unwrap().file_name,
+        "R5.htm"
+    );
+    assert_eq!(
+        find_report(&reports, "balance sheet components (details)")
+            .unwrap()
+            .file_name,
+        "R44.htm"
+    );
+
+    let html = edgar
+        .get_financial_report(320193, "0000320193-23-000106", "Balance Sheet")
+        .await
+        .unwrap();
+    assert_eq!(html, "<table>balance sheet</table>");
+    assert!(matches!(
+        edgar
+            .get_financial_report(320193, "0000320193-23-000106", "segment revenue")
+            .await,
+        Err(EdgarError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn fan_out_keeps_input_order_and_per_item_errors() {
+    let transport = transport();
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+    let missing = "https://data.sec.gov/submissions/CIK0000789019.json";
+
+    let bodies = edgar
+        .get_many([SUBMISSIONS_URL, missing, FACTS_URL], 2)
+        .await;
+    assert_eq!(bodies.len(), 3);
+    assert!(bodies[0].as_ref().unwrap().contains("Apple Inc."));
+    assert!(matches!(bodies[1], Err(EdgarError::NotFound(_))));
+    assert!(bodies[2].as_ref().unwrap().contains("entityName"));
+
+    let opts = FilingOptions::new().with_form_type("10-K");
+    let filings = edgar
+        .filings_for_ciks([789019u64, 320193], Some(opts), 4)
+        .await;
+    assert_eq!(filings[0].0, Cik::new(789019));
+    assert!(matches!(filings[0].1, Err(EdgarError::NotFound(_))));
+    assert_eq!(filings[1].0, Cik::new(320193));
+    let annual = filings[1].1.as_ref().unwrap();
+    assert!(!annual.is_empty());
+    assert!(annual.iter().all(|filing| filing.form.starts_with("10-K")));
+
+    let facts = edgar.company_facts_for_ciks([320193u64], 0).await;
+    assert_eq!(facts[0].1.as_ref().unwrap().entity_name, "Apple Inc.");
+}
+
+#[tokio::test]
+async fn search_stream_enriches_hits() {
+    let tickers_url = "https://www.sec.gov/files/company_tickers_exchange.json";
+    let page_url = "https://efts.sec.gov/LATEST/search-index/?q=merger&page=1&from=0&count=100&reverse_order=FALSE";
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_fixture(
+                tickers_url,
+                fixture_path("tickers/company_tickers_exchange.json"),
+            )
+            .with_response(page_url, read_fixture("search/search-index.json")),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let hits: Vec<_> = edgar
+        .search_stream(SearchOptions::new().with_query("merger"))
+        .enrich(Enricher::new(&edgar))
+        .take(3)
+        .collect()
+        .await;
+    assert_eq!(hits.len(), 3);
+    let first = hits[0].as_ref().unwrap();
+    assert_eq!(first.record.accession(), "0001104659-21-077166");
+    assert_eq!(first.companies[0].cik, 1844419);
+    assert_eq!(first.companies[0].tickers, vec!["MAQC", "MAQCU", "MAQCW"]);
+    assert!(hits.iter().all(|hit| hit.is_ok()));
+
+    // The ticker mapping is downloaded once for the whole stream.
+    assert_eq!(transport.requests(), vec![page_url, tickers_url]);
+}
+
+#[tokio::test]
+async fn search_all_reuses_the_first_response_when_splitting() {
+    let page = |total: serde_json::Value| {
+        let mut body: serde_json::Value =
+            serde_json::from_str(&read_fixture("search/search-index.json")).unwrap();
+        body["hits"]["total"] = total;
+        body.to_string()
+    };
+    let capped = page(serde_json::json!({ "value": 10000, "relation": "gte" }));
+    let single = page(serde_json::json!({ "value": 100, "relation": "eq" }));
+    let url = |start: &str, end: &str| {
+        format!(
+            "https://efts.sec.gov/LATEST/search-index/?q=merger&page=1&count=100&reverse_order=FALSE&startdt={start}&enddt={end}"
+        )
+    };
+    let transport = Arc::new(
+        MockTransport::new()
+            .with_response(url("2024-01-01", "2024-01-02"), capped)
+            .with_response(url("2024-01-02", "2024-01-02"), single.clone())
+            .with_response(url("2024-01-01", "2024-01-01"), single),
+    );
+    let edgar = Edgar::with_transport(transport.clone()).unwrap();
+
+    let options = SearchOptions::new()
+        .with_query("merger")
+        .with_date_range("2024-01-01".into(), "2024-01-02".into());
+    let hits = edgar.search_all(options).await.unwrap();
+    assert_eq!(hits.len(), 100);
+    assert_eq!(
+        transport.requests(),
+        vec![
+            url("2024-01-01", "2024-01-02"),
+            url("2024-01-02", "2024-01-02"),
+            url("2024-01-01", "2024-01-01"),
+        ]
+    );
+
+    let inverted = SearchOptions::new()
+        .with_query("merger")
+        .with_date_range("2024-01-02".into(), "2024-01-01".into());
+    assert!(matches!(
+        edgar.search_all(inverted).await,
+        Err(EdgarError::InvalidFormat(_))
+    ));
+    assert_eq!(transport.requests().len(), 3);
+}
@@ -38,7 +38,7 @@ fn test_rss_usgaap_feed() {
     );
     assert!(doc.channel.link.contains("sec.gov"));
     assert_eq!(doc.channel.language.as_deref().unwrap(), "en-us");
-    assert!(doc.channel.items.len() > 0);
+    assert!(!doc.channel.items.is_empty());
 
     // Check first item
     let first_item = &doc.channel.items[0];
@@ -108,10 +108,31 @@ fn test_multiple_filings() {
         .items
         .iter()
         .filter(|item| {
-            item.xbrl_filing.as_ref().map_or(false, |filing| {
-                filing.xmlns.is_some() || filing.form_type.is_some()
-            })
+            item.xbrl_filing
+                .as_ref()
+                .is_some_and(|filing| filing.xmlns.is_some() || filing.form_type.is_some())
         })
         .count();
     assert!(items_with_xbrl > 0);
 }
+
+#[test]
+fn test_xbrl_file_selection() {
+    let parser = setup_rss_parser();
+    let content = read_fixture(USGAAP_FIXTURE);
+    let doc = parser.parse(&content).unwrap();
+
+    let item = &doc.channel.items[0];
+    let instance = item.instance_document().unwrap();
+    assert!(instance.inline_xbrl);
+    assert_eq!(instance.file_name(), Some("d904491d8k.htm"));
+
+    let filing = item.xbrl_filing.as_ref().unwrap();
+    assert_eq!(filing.files().len(), 6);
+    assert_eq!(
+        filing.schema().and_then(|file| file.file_name()),
+        Some("dino-20250123.xsd")
+    );
+    assert_eq!(filing.files_of_type("ex-101.lab").count(), 1);
+    assert!(!filing.files()[1].inline_xbrl);
+}
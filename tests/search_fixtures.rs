@@ -1,7 +1,7 @@
 mod common;
 
 use common::read_fixture;
-use edgarkit::SearchResponse;
+use edgarkit::{Cik, Edgar, ItemCode, SearchResponse, StateOrCountry};
 
 #[test]
 fn parse_search_response() {
@@ -28,3 +28,78 @@ fn parse_search_response_with_null_fields() {
         let _ = hit._source.file_description;
     }
 }
+
+#[test]
+fn hit_document_helpers() {
+    let content = read_fixture("search/search-index.json");
+    let response: SearchResponse = serde_json::from_str(&content).unwrap();
+    let edgar = Edgar::new("test_agent example@example.com").unwrap();
+
+    let hit = &response.hits.hits[0];
+    assert_eq!(hit.accession(), "0001104659-21-077166");
+    assert_eq!(hit.primary_cik(), Some(Cik::from(1844419)));
+    assert_eq!(hit.filename(), Some("tm2118859d1_8k.htm"));
+    assert_eq!(hit._source.adsh.to_string(), hit._source.adsh_raw);
+    assert_eq!(hit._source.adsh.filer_id(), Cik::from(1104659));
+    assert_eq!(hit._source.ciks, vec![Cik::from(1844419)]);
+    assert_eq!(hit._source.ciks_raw, vec!["0001844419"]);
+    assert_eq!(hit._source.file_date.to_string(), hit._source.file_date_raw);
+    assert_eq!(hit._source.sics.len(), hit._source.sics_raw.len());
+    assert_eq!(
+        hit._source.item_codes(),
+        vec![
+            ItemCode::OtherEvents,
+            ItemCode::FinancialStatementsAndExhibits
+        ]
+    );
+    assert_eq!(
+        hit.document_url(&edgar).unwrap(),
+        "https://www.sec.gov/Archives/edgar/data/1844419/000110465921077166/tm2118859d1_8k.htm"
+    );
+}
+
+#[test]
+fn hit_state_and_country_codes() {
+    let content = read_fixture("search/search-s-1.json");
+    let response: SearchResponse = serde_json::from_str(&content).unwrap();
+
+    let cayman = response
+        .hits
+        .hits
+        .iter()
+        .find(|hit| hit._source.inc_states == ["E9"])
+        .unwrap();
+    let incorporated = cayman._source.incorporation_states();
+    assert_eq!(incorporated, vec![StateOrCountry::Country("E9")]);
+    assert!(!incorporated[0].is_us());
+    assert_eq!(incorporated[0].iso_country(), Some("KY"));
+
+    let domestic = response
+        .hits
+        .hits
+        .iter()
+        .filter(|hit| {
+            hit._source
+                .business_states()
+                .iter()
+                .any(StateOrCountry::is_us)
+        })
+        .count();
+    assert!(domestic > 0);
+}
+
+#[test]
+fn parse_search_aggregations() {
+    let content = read_fixture("search/search-index.json");
+    let response: SearchResponse = serde_json::from_str(&content).unwrap();
+    let aggregations = response.aggregations.unwrap();
+
+    assert_eq!(aggregations.forms.count("8-K"), Some(55));
+    assert_eq!(aggregations.sics.count("6770"), Some(88));
+    assert_eq!(aggregations.biz_states.buckets[0].key, "FL");
+    assert_eq!(
+        aggregations.entities.buckets[0].cik(),
+        Some(Cik::from(1844419))
+    );
+    assert_eq!(aggregations.forms.other_count, 0);
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "test-support")]
+
+mod common;
+
+use common::{edgar, read_fixture};
+use edgarkit::test_support::{PayloadKind, SnapshotHarness, check_payload, normalize_payload};
+
+#[test]
+fn fixtures_match_current_models() {
+    let cases = [
+        ("submissions/submission.json", PayloadKind::Submissions),
+        ("submissions/directory.json", PayloadKind::Directory),
+        ("tickers/companyfacts.json", PayloadKind::CompanyFacts),
+        ("tickers/companyconcept.json", PayloadKind::CompanyConcept),
+        ("tickers/frames.json", PayloadKind::Frames),
+        ("index/full-index.json", PayloadKind::IndexListing),
+        ("search/search-index.json", PayloadKind::Search),
+        ("atom/atom.xml", PayloadKind::AtomFeed),
+        ("rss/pressreleases.rss", PayloadKind::RssFeed),
+    ];
+
+    for (fixture, kind) in cases {
+        let content = normalize_payload(kind, &read_fixture(fixture));
+        if let Err(e) = check_payload(kind, &content) {
+            panic!("{fixture} no longer matches {kind:?}: {e}");
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore]
+async fn live_payloads_match_current_models() {
+    // Run with EDGARKIT_LIVE_SNAPSHOTS=1; without it every case is skipped.
+    let report = SnapshotHarness::with_default_cases(&edgar()).run().await;
+    println!("{report}");
+    report.assert_compatible();
+}